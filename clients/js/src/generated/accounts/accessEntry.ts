@@ -0,0 +1,145 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  assertAccountExists,
+  assertAccountsExist,
+  combineCodec,
+  decodeAccount,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  fixDecoderSize,
+  fixEncoderSize,
+  getBytesDecoder,
+  getBytesEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Account,
+  type Address,
+  type Codec,
+  type Decoder,
+  type EncodedAccount,
+  type Encoder,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+  type ReadonlyUint8Array,
+} from '@solana/web3.js';
+import { AccessEntrySeeds, findAccessEntryPda } from '../pdas';
+
+export type AccessEntry = {
+  status: number;
+  bumpSeed: number;
+  padding: ReadonlyUint8Array;
+};
+
+export type AccessEntryArgs = {
+  status: number;
+  bumpSeed: number;
+  padding: ReadonlyUint8Array;
+};
+
+export function getAccessEntryEncoder(): Encoder<AccessEntryArgs> {
+  return getStructEncoder([
+    ['status', getU8Encoder()],
+    ['bumpSeed', getU8Encoder()],
+    ['padding', fixEncoderSize(getBytesEncoder(), 6)],
+  ]);
+}
+
+export function getAccessEntryDecoder(): Decoder<AccessEntry> {
+  return getStructDecoder([
+    ['status', getU8Decoder()],
+    ['bumpSeed', getU8Decoder()],
+    ['padding', fixDecoderSize(getBytesDecoder(), 6)],
+  ]);
+}
+
+export function getAccessEntryCodec(): Codec<AccessEntryArgs, AccessEntry> {
+  return combineCodec(getAccessEntryEncoder(), getAccessEntryDecoder());
+}
+
+export function decodeAccessEntry<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<AccessEntry, TAddress>;
+export function decodeAccessEntry<TAddress extends string = string>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<AccessEntry, TAddress>;
+export function decodeAccessEntry<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+): Account<AccessEntry, TAddress> | MaybeAccount<AccessEntry, TAddress> {
+  return decodeAccount(
+    encodedAccount as MaybeEncodedAccount<TAddress>,
+    getAccessEntryDecoder()
+  );
+}
+
+export async function fetchAccessEntry<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<AccessEntry, TAddress>> {
+  const maybeAccount = await fetchMaybeAccessEntry(rpc, address, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeAccessEntry<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<AccessEntry, TAddress>> {
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decodeAccessEntry(maybeAccount);
+}
+
+export async function fetchAllAccessEntry(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<AccessEntry>[]> {
+  const maybeAccounts = await fetchAllMaybeAccessEntry(rpc, addresses, config);
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}
+
+export async function fetchAllMaybeAccessEntry(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<AccessEntry>[]> {
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) => decodeAccessEntry(maybeAccount));
+}
+
+export function getAccessEntrySize(): number {
+  return 8;
+}
+
+export async function fetchAccessEntryFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: AccessEntrySeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<Account<AccessEntry>> {
+  const maybeAccount = await fetchMaybeAccessEntryFromSeeds(rpc, seeds, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeAccessEntryFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: AccessEntrySeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<MaybeAccount<AccessEntry>> {
+  const { programAddress, ...fetchConfig } = config;
+  const [address] = await findAccessEntryPda(seeds, { programAddress });
+  return await fetchMaybeAccessEntry(rpc, address, fetchConfig);
+}
@@ -0,0 +1,181 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  assertAccountExists,
+  assertAccountsExist,
+  combineCodec,
+  decodeAccount,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  fixDecoderSize,
+  fixEncoderSize,
+  getAddressDecoder,
+  getAddressEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Account,
+  type Address,
+  type Codec,
+  type Decoder,
+  type EncodedAccount,
+  type Encoder,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+  type ReadonlyUint8Array,
+} from '@solana/web3.js';
+import { CampaignSeeds, findCampaignPda } from '../pdas';
+
+export type Campaign = {
+  holderRewardsPool: Address;
+  funder: Address;
+  amount: bigint;
+  startSlot: bigint;
+  endSlot: bigint;
+  released: bigint;
+  unspent: bigint;
+  lastReleaseSlot: bigint;
+  zeroWeightSlots: bigint;
+  bumpSeed: number;
+  padding: ReadonlyUint8Array;
+};
+
+export type CampaignArgs = {
+  holderRewardsPool: Address;
+  funder: Address;
+  amount: number | bigint;
+  startSlot: number | bigint;
+  endSlot: number | bigint;
+  released: number | bigint;
+  unspent: number | bigint;
+  lastReleaseSlot: number | bigint;
+  zeroWeightSlots: number | bigint;
+  bumpSeed: number;
+  padding: ReadonlyUint8Array;
+};
+
+export function getCampaignEncoder(): Encoder<CampaignArgs> {
+  return getStructEncoder([
+    ['holderRewardsPool', getAddressEncoder()],
+    ['funder', getAddressEncoder()],
+    ['amount', getU64Encoder()],
+    ['startSlot', getU64Encoder()],
+    ['endSlot', getU64Encoder()],
+    ['released', getU64Encoder()],
+    ['unspent', getU64Encoder()],
+    ['lastReleaseSlot', getU64Encoder()],
+    ['zeroWeightSlots', getU64Encoder()],
+    ['bumpSeed', getU8Encoder()],
+    ['padding', fixEncoderSize(getBytesEncoder(), 7)],
+  ]);
+}
+
+export function getCampaignDecoder(): Decoder<Campaign> {
+  return getStructDecoder([
+    ['holderRewardsPool', getAddressDecoder()],
+    ['funder', getAddressDecoder()],
+    ['amount', getU64Decoder()],
+    ['startSlot', getU64Decoder()],
+    ['endSlot', getU64Decoder()],
+    ['released', getU64Decoder()],
+    ['unspent', getU64Decoder()],
+    ['lastReleaseSlot', getU64Decoder()],
+    ['zeroWeightSlots', getU64Decoder()],
+    ['bumpSeed', getU8Decoder()],
+    ['padding', fixDecoderSize(getBytesDecoder(), 7)],
+  ]);
+}
+
+export function getCampaignCodec(): Codec<CampaignArgs, Campaign> {
+  return combineCodec(getCampaignEncoder(), getCampaignDecoder());
+}
+
+export function decodeCampaign<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<Campaign, TAddress>;
+export function decodeCampaign<TAddress extends string = string>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<Campaign, TAddress>;
+export function decodeCampaign<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+): Account<Campaign, TAddress> | MaybeAccount<Campaign, TAddress> {
+  return decodeAccount(
+    encodedAccount as MaybeEncodedAccount<TAddress>,
+    getCampaignDecoder()
+  );
+}
+
+export async function fetchCampaign<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<Campaign, TAddress>> {
+  const maybeAccount = await fetchMaybeCampaign(rpc, address, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeCampaign<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<Campaign, TAddress>> {
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decodeCampaign(maybeAccount);
+}
+
+export async function fetchAllCampaign(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<Campaign>[]> {
+  const maybeAccounts = await fetchAllMaybeCampaign(rpc, addresses, config);
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}
+
+export async function fetchAllMaybeCampaign(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<Campaign>[]> {
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) => decodeCampaign(maybeAccount));
+}
+
+export function getCampaignSize(): number {
+  return 128;
+}
+
+export async function fetchCampaignFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: CampaignSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<Account<Campaign>> {
+  const maybeAccount = await fetchMaybeCampaignFromSeeds(rpc, seeds, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeCampaignFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: CampaignSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<MaybeAccount<Campaign>> {
+  const { programAddress, ...fetchConfig } = config;
+  const [address] = await findCampaignPda(seeds, { programAddress });
+  return await fetchMaybeCampaign(rpc, address, fetchConfig);
+}
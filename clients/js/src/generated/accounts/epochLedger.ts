@@ -0,0 +1,173 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  assertAccountExists,
+  assertAccountsExist,
+  combineCodec,
+  decodeAccount,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  fixDecoderSize,
+  fixEncoderSize,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU128Decoder,
+  getU128Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Account,
+  type Address,
+  type Codec,
+  type Decoder,
+  type EncodedAccount,
+  type Encoder,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+  type ReadonlyUint8Array,
+} from '@solana/web3.js';
+import {
+  getEpochLedgerEntryDecoder,
+  getEpochLedgerEntryEncoder,
+  type EpochLedgerEntry,
+  type EpochLedgerEntryArgs,
+} from '../types';
+import { EpochLedgerSeeds, findEpochLedgerPda } from '../pdas';
+
+export type EpochLedger = {
+  lastAccumulatedRewardsPerToken: bigint;
+  lastTotalRewardsReceived: bigint;
+  nextIndex: bigint;
+  len: bigint;
+  bumpSeed: number;
+  padding: ReadonlyUint8Array;
+  entries: Array<EpochLedgerEntry>;
+};
+
+export type EpochLedgerArgs = {
+  lastAccumulatedRewardsPerToken: number | bigint;
+  lastTotalRewardsReceived: number | bigint;
+  nextIndex: number | bigint;
+  len: number | bigint;
+  bumpSeed: number;
+  padding: ReadonlyUint8Array;
+  entries: Array<EpochLedgerEntryArgs>;
+};
+
+export function getEpochLedgerEncoder(): Encoder<EpochLedgerArgs> {
+  return getStructEncoder([
+    ['lastAccumulatedRewardsPerToken', getU128Encoder()],
+    ['lastTotalRewardsReceived', getU64Encoder()],
+    ['nextIndex', getU64Encoder()],
+    ['len', getU64Encoder()],
+    ['bumpSeed', getU8Encoder()],
+    ['padding', fixEncoderSize(getBytesEncoder(), 7)],
+    ['entries', getArrayEncoder(getEpochLedgerEntryEncoder(), { size: 64 })],
+  ]);
+}
+
+export function getEpochLedgerDecoder(): Decoder<EpochLedger> {
+  return getStructDecoder([
+    ['lastAccumulatedRewardsPerToken', getU128Decoder()],
+    ['lastTotalRewardsReceived', getU64Decoder()],
+    ['nextIndex', getU64Decoder()],
+    ['len', getU64Decoder()],
+    ['bumpSeed', getU8Decoder()],
+    ['padding', fixDecoderSize(getBytesDecoder(), 7)],
+    ['entries', getArrayDecoder(getEpochLedgerEntryDecoder(), { size: 64 })],
+  ]);
+}
+
+export function getEpochLedgerCodec(): Codec<EpochLedgerArgs, EpochLedger> {
+  return combineCodec(getEpochLedgerEncoder(), getEpochLedgerDecoder());
+}
+
+export function decodeEpochLedger<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<EpochLedger, TAddress>;
+export function decodeEpochLedger<TAddress extends string = string>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<EpochLedger, TAddress>;
+export function decodeEpochLedger<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+): Account<EpochLedger, TAddress> | MaybeAccount<EpochLedger, TAddress> {
+  return decodeAccount(
+    encodedAccount as MaybeEncodedAccount<TAddress>,
+    getEpochLedgerDecoder()
+  );
+}
+
+export async function fetchEpochLedger<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<EpochLedger, TAddress>> {
+  const maybeAccount = await fetchMaybeEpochLedger(rpc, address, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeEpochLedger<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<EpochLedger, TAddress>> {
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decodeEpochLedger(maybeAccount);
+}
+
+export async function fetchAllEpochLedger(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<EpochLedger>[]> {
+  const maybeAccounts = await fetchAllMaybeEpochLedger(rpc, addresses, config);
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}
+
+export async function fetchAllMaybeEpochLedger(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<EpochLedger>[]> {
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) => decodeEpochLedger(maybeAccount));
+}
+
+export function getEpochLedgerSize(): number {
+  return 2096;
+}
+
+export async function fetchEpochLedgerFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: EpochLedgerSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<Account<EpochLedger>> {
+  const maybeAccount = await fetchMaybeEpochLedgerFromSeeds(rpc, seeds, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeEpochLedgerFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: EpochLedgerSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<MaybeAccount<EpochLedger>> {
+  const { programAddress, ...fetchConfig } = config;
+  const [address] = await findEpochLedgerPda(seeds, { programAddress });
+  return await fetchMaybeEpochLedger(rpc, address, fetchConfig);
+}
@@ -13,12 +13,22 @@ import {
   decodeAccount,
   fetchEncodedAccount,
   fetchEncodedAccounts,
+  fixDecoderSize,
+  fixEncoderSize,
+  getAddressDecoder,
+  getAddressEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getStructDecoder,
   getStructEncoder,
   getU128Decoder,
   getU128Encoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   type Account,
   type Address,
   type Codec,
@@ -29,26 +39,78 @@ import {
   type FetchAccountsConfig,
   type MaybeAccount,
   type MaybeEncodedAccount,
+  type ReadonlyUint8Array,
 } from '@solana/web3.js';
 import { HolderRewardsSeeds, findHolderRewardsPda } from '../pdas';
 
 export type HolderRewards = {
   lastAccumulatedRewardsPerToken: bigint;
   deposited: bigint;
-  padding: bigint;
+  rentPayer: Address;
+  bumpSeed: number;
+  stakeCheckpoints: number;
+  padding: ReadonlyUint8Array;
+  totalRewardsHarvested: bigint;
+  firstDepositSlot: bigint;
+  lastActivitySlot: bigint;
+  cumulativeDeposited: bigint;
+  cumulativeWithdrawn: bigint;
+  lockedAmount: bigint;
+  unlockTimestamp: bigint;
+  lockupMultiplierBps: bigint;
+  votingPowerLocked: bigint;
+  votingPowerEnd: bigint;
+  lastDepositSlot: bigint;
+  earlyWithdrawalPenalties: bigint;
+  unharvestedRewards: bigint;
+  slashed: bigint;
 };
 
 export type HolderRewardsArgs = {
   lastAccumulatedRewardsPerToken: number | bigint;
   deposited: number | bigint;
-  padding: number | bigint;
+  rentPayer: Address;
+  bumpSeed: number;
+  stakeCheckpoints: number;
+  padding: ReadonlyUint8Array;
+  totalRewardsHarvested: number | bigint;
+  firstDepositSlot: number | bigint;
+  lastActivitySlot: number | bigint;
+  cumulativeDeposited: number | bigint;
+  cumulativeWithdrawn: number | bigint;
+  lockedAmount: number | bigint;
+  unlockTimestamp: number | bigint;
+  lockupMultiplierBps: number | bigint;
+  votingPowerLocked: number | bigint;
+  votingPowerEnd: number | bigint;
+  lastDepositSlot: number | bigint;
+  earlyWithdrawalPenalties: number | bigint;
+  unharvestedRewards: number | bigint;
+  slashed: number | bigint;
 };
 
 export function getHolderRewardsEncoder(): Encoder<HolderRewardsArgs> {
   return getStructEncoder([
     ['lastAccumulatedRewardsPerToken', getU128Encoder()],
     ['deposited', getU64Encoder()],
-    ['padding', getU64Encoder()],
+    ['rentPayer', getAddressEncoder()],
+    ['bumpSeed', getU8Encoder()],
+    ['stakeCheckpoints', getU8Encoder()],
+    ['padding', fixEncoderSize(getBytesEncoder(), 6)],
+    ['totalRewardsHarvested', getU64Encoder()],
+    ['firstDepositSlot', getU64Encoder()],
+    ['lastActivitySlot', getU64Encoder()],
+    ['cumulativeDeposited', getU64Encoder()],
+    ['cumulativeWithdrawn', getU64Encoder()],
+    ['lockedAmount', getU64Encoder()],
+    ['unlockTimestamp', getI64Encoder()],
+    ['lockupMultiplierBps', getU64Encoder()],
+    ['votingPowerLocked', getU64Encoder()],
+    ['votingPowerEnd', getI64Encoder()],
+    ['lastDepositSlot', getU64Encoder()],
+    ['earlyWithdrawalPenalties', getU64Encoder()],
+    ['unharvestedRewards', getU64Encoder()],
+    ['slashed', getU64Encoder()],
   ]);
 }
 
@@ -56,7 +118,24 @@ export function getHolderRewardsDecoder(): Decoder<HolderRewards> {
   return getStructDecoder([
     ['lastAccumulatedRewardsPerToken', getU128Decoder()],
     ['deposited', getU64Decoder()],
-    ['padding', getU64Decoder()],
+    ['rentPayer', getAddressDecoder()],
+    ['bumpSeed', getU8Decoder()],
+    ['stakeCheckpoints', getU8Decoder()],
+    ['padding', fixDecoderSize(getBytesDecoder(), 6)],
+    ['totalRewardsHarvested', getU64Decoder()],
+    ['firstDepositSlot', getU64Decoder()],
+    ['lastActivitySlot', getU64Decoder()],
+    ['cumulativeDeposited', getU64Decoder()],
+    ['cumulativeWithdrawn', getU64Decoder()],
+    ['lockedAmount', getU64Decoder()],
+    ['unlockTimestamp', getI64Decoder()],
+    ['lockupMultiplierBps', getU64Decoder()],
+    ['votingPowerLocked', getU64Decoder()],
+    ['votingPowerEnd', getI64Decoder()],
+    ['lastDepositSlot', getU64Decoder()],
+    ['earlyWithdrawalPenalties', getU64Decoder()],
+    ['unharvestedRewards', getU64Decoder()],
+    ['slashed', getU64Decoder()],
   ]);
 }
 
@@ -125,7 +204,7 @@ export async function fetchAllMaybeHolderRewards(
 }
 
 export function getHolderRewardsSize(): number {
-  return 32;
+  return 176;
 }
 
 export async function fetchHolderRewardsFromSeeds(
@@ -15,14 +15,22 @@ import {
   fetchEncodedAccounts,
   fixDecoderSize,
   fixEncoderSize,
+  getAddressDecoder,
+  getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
   getU128Decoder,
   getU128Encoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   type Account,
   type Address,
   type Codec,
@@ -41,14 +49,58 @@ export type HolderRewardsPool = {
   accumulatedRewardsPerToken: bigint;
   lamportsLast: bigint;
   dunaDocumentHash: ReadonlyUint8Array;
-  padding: bigint;
+  totalDeposited: bigint;
+  authority: Address;
+  bumpSeed: number;
+  accessList: number;
+  earlyWithdrawalPenaltyBps: number;
+  earlyWithdrawalPeriodSlots: number;
+  lastUpdateSlot: bigint;
+  totalRewardsReceived: bigint;
+  totalRewardsHarvested: bigint;
+  activeHolderCount: bigint;
+  totalLockupBonusWeight: bigint;
+  maxTotalDeposit: bigint;
+  maxHolderDeposit: bigint;
+  minDeposit: bigint;
+  receipts: number;
+  receiptMintBumpSeed: number;
+  rateHistory: number;
+  epochLedger: number;
+  padding: ReadonlyUint8Array;
+  slashingAuthority: Address;
+  slashDestination: Address;
+  totalSlashed: bigint;
+  zeroWeightSlots: bigint;
 };
 
 export type HolderRewardsPoolArgs = {
   accumulatedRewardsPerToken: number | bigint;
   lamportsLast: number | bigint;
   dunaDocumentHash: ReadonlyUint8Array;
-  padding: number | bigint;
+  totalDeposited: number | bigint;
+  authority: Address;
+  bumpSeed: number;
+  accessList: number;
+  earlyWithdrawalPenaltyBps: number;
+  earlyWithdrawalPeriodSlots: number;
+  lastUpdateSlot: number | bigint;
+  totalRewardsReceived: number | bigint;
+  totalRewardsHarvested: number | bigint;
+  activeHolderCount: number | bigint;
+  totalLockupBonusWeight: number | bigint;
+  maxTotalDeposit: number | bigint;
+  maxHolderDeposit: number | bigint;
+  minDeposit: number | bigint;
+  receipts: number;
+  receiptMintBumpSeed: number;
+  rateHistory: number;
+  epochLedger: number;
+  padding: ReadonlyUint8Array;
+  slashingAuthority: Address;
+  slashDestination: Address;
+  totalSlashed: number | bigint;
+  zeroWeightSlots: number | bigint;
 };
 
 export function getHolderRewardsPoolEncoder(): Encoder<HolderRewardsPoolArgs> {
@@ -56,7 +108,29 @@ export function getHolderRewardsPoolEncoder(): Encoder<HolderRewardsPoolArgs> {
     ['accumulatedRewardsPerToken', getU128Encoder()],
     ['lamportsLast', getU64Encoder()],
     ['dunaDocumentHash', fixEncoderSize(getBytesEncoder(), 32)],
-    ['padding', getU64Encoder()],
+    ['totalDeposited', getU64Encoder()],
+    ['authority', getAddressEncoder()],
+    ['bumpSeed', getU8Encoder()],
+    ['accessList', getU8Encoder()],
+    ['earlyWithdrawalPenaltyBps', getU16Encoder()],
+    ['earlyWithdrawalPeriodSlots', getU32Encoder()],
+    ['lastUpdateSlot', getU64Encoder()],
+    ['totalRewardsReceived', getU64Encoder()],
+    ['totalRewardsHarvested', getU64Encoder()],
+    ['activeHolderCount', getU64Encoder()],
+    ['totalLockupBonusWeight', getU64Encoder()],
+    ['maxTotalDeposit', getU64Encoder()],
+    ['maxHolderDeposit', getU64Encoder()],
+    ['minDeposit', getU64Encoder()],
+    ['receipts', getU8Encoder()],
+    ['receiptMintBumpSeed', getU8Encoder()],
+    ['rateHistory', getU8Encoder()],
+    ['epochLedger', getU8Encoder()],
+    ['padding', fixEncoderSize(getBytesEncoder(), 4)],
+    ['slashingAuthority', getAddressEncoder()],
+    ['slashDestination', getAddressEncoder()],
+    ['totalSlashed', getU64Encoder()],
+    ['zeroWeightSlots', getU64Encoder()],
   ]);
 }
 
@@ -65,7 +139,29 @@ export function getHolderRewardsPoolDecoder(): Decoder<HolderRewardsPool> {
     ['accumulatedRewardsPerToken', getU128Decoder()],
     ['lamportsLast', getU64Decoder()],
     ['dunaDocumentHash', fixDecoderSize(getBytesDecoder(), 32)],
-    ['padding', getU64Decoder()],
+    ['totalDeposited', getU64Decoder()],
+    ['authority', getAddressDecoder()],
+    ['bumpSeed', getU8Decoder()],
+    ['accessList', getU8Decoder()],
+    ['earlyWithdrawalPenaltyBps', getU16Decoder()],
+    ['earlyWithdrawalPeriodSlots', getU32Decoder()],
+    ['lastUpdateSlot', getU64Decoder()],
+    ['totalRewardsReceived', getU64Decoder()],
+    ['totalRewardsHarvested', getU64Decoder()],
+    ['activeHolderCount', getU64Decoder()],
+    ['totalLockupBonusWeight', getU64Decoder()],
+    ['maxTotalDeposit', getU64Decoder()],
+    ['maxHolderDeposit', getU64Decoder()],
+    ['minDeposit', getU64Decoder()],
+    ['receipts', getU8Decoder()],
+    ['receiptMintBumpSeed', getU8Decoder()],
+    ['rateHistory', getU8Decoder()],
+    ['epochLedger', getU8Decoder()],
+    ['padding', fixDecoderSize(getBytesDecoder(), 4)],
+    ['slashingAuthority', getAddressDecoder()],
+    ['slashDestination', getAddressDecoder()],
+    ['totalSlashed', getU64Decoder()],
+    ['zeroWeightSlots', getU64Decoder()],
   ]);
 }
 
@@ -143,7 +239,7 @@ export async function fetchAllMaybeHolderRewardsPool(
 }
 
 export function getHolderRewardsPoolSize(): number {
-  return 64;
+  return 256;
 }
 
 export async function fetchHolderRewardsPoolFromSeeds(
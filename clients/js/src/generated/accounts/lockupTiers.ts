@@ -0,0 +1,157 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  assertAccountExists,
+  assertAccountsExist,
+  combineCodec,
+  decodeAccount,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  fixDecoderSize,
+  fixEncoderSize,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Account,
+  type Address,
+  type Codec,
+  type Decoder,
+  type EncodedAccount,
+  type Encoder,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+  type ReadonlyUint8Array,
+} from '@solana/web3.js';
+import {
+  getLockupTierDecoder,
+  getLockupTierEncoder,
+  type LockupTier,
+  type LockupTierArgs,
+} from '../types';
+import { LockupTiersSeeds, findLockupTiersPda } from '../pdas';
+
+export type LockupTiers = {
+  tiers: Array<LockupTier>;
+  bumpSeed: number;
+  voteEscrow: number;
+  padding: ReadonlyUint8Array;
+};
+
+export type LockupTiersArgs = {
+  tiers: Array<LockupTierArgs>;
+  bumpSeed: number;
+  voteEscrow: number;
+  padding: ReadonlyUint8Array;
+};
+
+export function getLockupTiersEncoder(): Encoder<LockupTiersArgs> {
+  return getStructEncoder([
+    ['tiers', getArrayEncoder(getLockupTierEncoder(), { size: 4 })],
+    ['bumpSeed', getU8Encoder()],
+    ['voteEscrow', getU8Encoder()],
+    ['padding', fixEncoderSize(getBytesEncoder(), 6)],
+  ]);
+}
+
+export function getLockupTiersDecoder(): Decoder<LockupTiers> {
+  return getStructDecoder([
+    ['tiers', getArrayDecoder(getLockupTierDecoder(), { size: 4 })],
+    ['bumpSeed', getU8Decoder()],
+    ['voteEscrow', getU8Decoder()],
+    ['padding', fixDecoderSize(getBytesDecoder(), 6)],
+  ]);
+}
+
+export function getLockupTiersCodec(): Codec<LockupTiersArgs, LockupTiers> {
+  return combineCodec(getLockupTiersEncoder(), getLockupTiersDecoder());
+}
+
+export function decodeLockupTiers<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<LockupTiers, TAddress>;
+export function decodeLockupTiers<TAddress extends string = string>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<LockupTiers, TAddress>;
+export function decodeLockupTiers<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+): Account<LockupTiers, TAddress> | MaybeAccount<LockupTiers, TAddress> {
+  return decodeAccount(
+    encodedAccount as MaybeEncodedAccount<TAddress>,
+    getLockupTiersDecoder()
+  );
+}
+
+export async function fetchLockupTiers<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<LockupTiers, TAddress>> {
+  const maybeAccount = await fetchMaybeLockupTiers(rpc, address, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeLockupTiers<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<LockupTiers, TAddress>> {
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decodeLockupTiers(maybeAccount);
+}
+
+export async function fetchAllLockupTiers(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<LockupTiers>[]> {
+  const maybeAccounts = await fetchAllMaybeLockupTiers(rpc, addresses, config);
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}
+
+export async function fetchAllMaybeLockupTiers(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<LockupTiers>[]> {
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) => decodeLockupTiers(maybeAccount));
+}
+
+export function getLockupTiersSize(): number {
+  return 72;
+}
+
+export async function fetchLockupTiersFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: LockupTiersSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<Account<LockupTiers>> {
+  const maybeAccount = await fetchMaybeLockupTiersFromSeeds(rpc, seeds, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeLockupTiersFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: LockupTiersSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<MaybeAccount<LockupTiers>> {
+  const { programAddress, ...fetchConfig } = config;
+  const [address] = await findLockupTiersPda(seeds, { programAddress });
+  return await fetchMaybeLockupTiers(rpc, address, fetchConfig);
+}
@@ -0,0 +1,163 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  assertAccountExists,
+  assertAccountsExist,
+  combineCodec,
+  decodeAccount,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  fixDecoderSize,
+  fixEncoderSize,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Account,
+  type Address,
+  type Codec,
+  type Decoder,
+  type EncodedAccount,
+  type Encoder,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+  type ReadonlyUint8Array,
+} from '@solana/web3.js';
+import {
+  getRateHistoryEntryDecoder,
+  getRateHistoryEntryEncoder,
+  type RateHistoryEntry,
+  type RateHistoryEntryArgs,
+} from '../types';
+import { RateHistorySeeds, findRateHistoryPda } from '../pdas';
+
+export type RateHistory = {
+  nextIndex: bigint;
+  len: bigint;
+  bumpSeed: number;
+  padding: ReadonlyUint8Array;
+  entries: Array<RateHistoryEntry>;
+};
+
+export type RateHistoryArgs = {
+  nextIndex: number | bigint;
+  len: number | bigint;
+  bumpSeed: number;
+  padding: ReadonlyUint8Array;
+  entries: Array<RateHistoryEntryArgs>;
+};
+
+export function getRateHistoryEncoder(): Encoder<RateHistoryArgs> {
+  return getStructEncoder([
+    ['nextIndex', getU64Encoder()],
+    ['len', getU64Encoder()],
+    ['bumpSeed', getU8Encoder()],
+    ['padding', fixEncoderSize(getBytesEncoder(), 15)],
+    ['entries', getArrayEncoder(getRateHistoryEntryEncoder(), { size: 128 })],
+  ]);
+}
+
+export function getRateHistoryDecoder(): Decoder<RateHistory> {
+  return getStructDecoder([
+    ['nextIndex', getU64Decoder()],
+    ['len', getU64Decoder()],
+    ['bumpSeed', getU8Decoder()],
+    ['padding', fixDecoderSize(getBytesDecoder(), 15)],
+    ['entries', getArrayDecoder(getRateHistoryEntryDecoder(), { size: 128 })],
+  ]);
+}
+
+export function getRateHistoryCodec(): Codec<RateHistoryArgs, RateHistory> {
+  return combineCodec(getRateHistoryEncoder(), getRateHistoryDecoder());
+}
+
+export function decodeRateHistory<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<RateHistory, TAddress>;
+export function decodeRateHistory<TAddress extends string = string>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<RateHistory, TAddress>;
+export function decodeRateHistory<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+): Account<RateHistory, TAddress> | MaybeAccount<RateHistory, TAddress> {
+  return decodeAccount(
+    encodedAccount as MaybeEncodedAccount<TAddress>,
+    getRateHistoryDecoder()
+  );
+}
+
+export async function fetchRateHistory<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<RateHistory, TAddress>> {
+  const maybeAccount = await fetchMaybeRateHistory(rpc, address, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeRateHistory<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<RateHistory, TAddress>> {
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decodeRateHistory(maybeAccount);
+}
+
+export async function fetchAllRateHistory(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<RateHistory>[]> {
+  const maybeAccounts = await fetchAllMaybeRateHistory(rpc, addresses, config);
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}
+
+export async function fetchAllMaybeRateHistory(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<RateHistory>[]> {
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) => decodeRateHistory(maybeAccount));
+}
+
+export function getRateHistorySize(): number {
+  return 6176;
+}
+
+export async function fetchRateHistoryFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: RateHistorySeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<Account<RateHistory>> {
+  const maybeAccount = await fetchMaybeRateHistoryFromSeeds(rpc, seeds, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeRateHistoryFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: RateHistorySeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<MaybeAccount<RateHistory>> {
+  const { programAddress, ...fetchConfig } = config;
+  const [address] = await findRateHistoryPda(seeds, { programAddress });
+  return await fetchMaybeRateHistory(rpc, address, fetchConfig);
+}
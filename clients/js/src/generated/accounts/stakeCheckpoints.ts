@@ -0,0 +1,183 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  assertAccountExists,
+  assertAccountsExist,
+  combineCodec,
+  decodeAccount,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  fixDecoderSize,
+  fixEncoderSize,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Account,
+  type Address,
+  type Codec,
+  type Decoder,
+  type EncodedAccount,
+  type Encoder,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+  type ReadonlyUint8Array,
+} from '@solana/web3.js';
+import {
+  getStakeCheckpointDecoder,
+  getStakeCheckpointEncoder,
+  type StakeCheckpoint,
+  type StakeCheckpointArgs,
+} from '../types';
+import { StakeCheckpointsSeeds, findStakeCheckpointsPda } from '../pdas';
+
+export type StakeCheckpoints = {
+  nextIndex: bigint;
+  len: bigint;
+  bumpSeed: number;
+  padding: ReadonlyUint8Array;
+  checkpoints: Array<StakeCheckpoint>;
+};
+
+export type StakeCheckpointsArgs = {
+  nextIndex: number | bigint;
+  len: number | bigint;
+  bumpSeed: number;
+  padding: ReadonlyUint8Array;
+  checkpoints: Array<StakeCheckpointArgs>;
+};
+
+export function getStakeCheckpointsEncoder(): Encoder<StakeCheckpointsArgs> {
+  return getStructEncoder([
+    ['nextIndex', getU64Encoder()],
+    ['len', getU64Encoder()],
+    ['bumpSeed', getU8Encoder()],
+    ['padding', fixEncoderSize(getBytesEncoder(), 15)],
+    ['checkpoints', getArrayEncoder(getStakeCheckpointEncoder(), { size: 64 })],
+  ]);
+}
+
+export function getStakeCheckpointsDecoder(): Decoder<StakeCheckpoints> {
+  return getStructDecoder([
+    ['nextIndex', getU64Decoder()],
+    ['len', getU64Decoder()],
+    ['bumpSeed', getU8Decoder()],
+    ['padding', fixDecoderSize(getBytesDecoder(), 15)],
+    ['checkpoints', getArrayDecoder(getStakeCheckpointDecoder(), { size: 64 })],
+  ]);
+}
+
+export function getStakeCheckpointsCodec(): Codec<
+  StakeCheckpointsArgs,
+  StakeCheckpoints
+> {
+  return combineCodec(
+    getStakeCheckpointsEncoder(),
+    getStakeCheckpointsDecoder()
+  );
+}
+
+export function decodeStakeCheckpoints<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<StakeCheckpoints, TAddress>;
+export function decodeStakeCheckpoints<TAddress extends string = string>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<StakeCheckpoints, TAddress>;
+export function decodeStakeCheckpoints<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+):
+  | Account<StakeCheckpoints, TAddress>
+  | MaybeAccount<StakeCheckpoints, TAddress> {
+  return decodeAccount(
+    encodedAccount as MaybeEncodedAccount<TAddress>,
+    getStakeCheckpointsDecoder()
+  );
+}
+
+export async function fetchStakeCheckpoints<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<StakeCheckpoints, TAddress>> {
+  const maybeAccount = await fetchMaybeStakeCheckpoints(rpc, address, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeStakeCheckpoints<
+  TAddress extends string = string,
+>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<StakeCheckpoints, TAddress>> {
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decodeStakeCheckpoints(maybeAccount);
+}
+
+export async function fetchAllStakeCheckpoints(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<StakeCheckpoints>[]> {
+  const maybeAccounts = await fetchAllMaybeStakeCheckpoints(
+    rpc,
+    addresses,
+    config
+  );
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}
+
+export async function fetchAllMaybeStakeCheckpoints(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<StakeCheckpoints>[]> {
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) =>
+    decodeStakeCheckpoints(maybeAccount)
+  );
+}
+
+export function getStakeCheckpointsSize(): number {
+  return 1056;
+}
+
+export async function fetchStakeCheckpointsFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: StakeCheckpointsSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<Account<StakeCheckpoints>> {
+  const maybeAccount = await fetchMaybeStakeCheckpointsFromSeeds(
+    rpc,
+    seeds,
+    config
+  );
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeStakeCheckpointsFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: StakeCheckpointsSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<MaybeAccount<StakeCheckpoints>> {
+  const { programAddress, ...fetchConfig } = config;
+  const [address] = await findStakeCheckpointsPda(seeds, { programAddress });
+  return await fetchMaybeStakeCheckpoints(rpc, address, fetchConfig);
+}
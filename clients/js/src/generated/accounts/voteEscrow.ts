@@ -0,0 +1,169 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  assertAccountExists,
+  assertAccountsExist,
+  combineCodec,
+  decodeAccount,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  fixDecoderSize,
+  fixEncoderSize,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU128Decoder,
+  getU128Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Account,
+  type Address,
+  type Codec,
+  type Decoder,
+  type EncodedAccount,
+  type Encoder,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+  type ReadonlyUint8Array,
+} from '@solana/web3.js';
+import { VoteEscrowSeeds, findVoteEscrowPda } from '../pdas';
+
+export type VoteEscrow = {
+  lockedEndSum: bigint;
+  lastCheckpoint: bigint;
+  locked: bigint;
+  bumpSeed: number;
+  rewardWeighting: number;
+  padding: ReadonlyUint8Array;
+  expiring: Array<bigint>;
+};
+
+export type VoteEscrowArgs = {
+  lockedEndSum: number | bigint;
+  lastCheckpoint: number | bigint;
+  locked: number | bigint;
+  bumpSeed: number;
+  rewardWeighting: number;
+  padding: ReadonlyUint8Array;
+  expiring: Array<number | bigint>;
+};
+
+export function getVoteEscrowEncoder(): Encoder<VoteEscrowArgs> {
+  return getStructEncoder([
+    ['lockedEndSum', getU128Encoder()],
+    ['lastCheckpoint', getI64Encoder()],
+    ['locked', getU64Encoder()],
+    ['bumpSeed', getU8Encoder()],
+    ['rewardWeighting', getU8Encoder()],
+    ['padding', fixEncoderSize(getBytesEncoder(), 14)],
+    ['expiring', getArrayEncoder(getU64Encoder(), { size: 256 })],
+  ]);
+}
+
+export function getVoteEscrowDecoder(): Decoder<VoteEscrow> {
+  return getStructDecoder([
+    ['lockedEndSum', getU128Decoder()],
+    ['lastCheckpoint', getI64Decoder()],
+    ['locked', getU64Decoder()],
+    ['bumpSeed', getU8Decoder()],
+    ['rewardWeighting', getU8Decoder()],
+    ['padding', fixDecoderSize(getBytesDecoder(), 14)],
+    ['expiring', getArrayDecoder(getU64Decoder(), { size: 256 })],
+  ]);
+}
+
+export function getVoteEscrowCodec(): Codec<VoteEscrowArgs, VoteEscrow> {
+  return combineCodec(getVoteEscrowEncoder(), getVoteEscrowDecoder());
+}
+
+export function decodeVoteEscrow<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<VoteEscrow, TAddress>;
+export function decodeVoteEscrow<TAddress extends string = string>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<VoteEscrow, TAddress>;
+export function decodeVoteEscrow<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+): Account<VoteEscrow, TAddress> | MaybeAccount<VoteEscrow, TAddress> {
+  return decodeAccount(
+    encodedAccount as MaybeEncodedAccount<TAddress>,
+    getVoteEscrowDecoder()
+  );
+}
+
+export async function fetchVoteEscrow<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<VoteEscrow, TAddress>> {
+  const maybeAccount = await fetchMaybeVoteEscrow(rpc, address, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeVoteEscrow<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<VoteEscrow, TAddress>> {
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decodeVoteEscrow(maybeAccount);
+}
+
+export async function fetchAllVoteEscrow(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<VoteEscrow>[]> {
+  const maybeAccounts = await fetchAllMaybeVoteEscrow(rpc, addresses, config);
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}
+
+export async function fetchAllMaybeVoteEscrow(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<VoteEscrow>[]> {
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) => decodeVoteEscrow(maybeAccount));
+}
+
+export function getVoteEscrowSize(): number {
+  return 2096;
+}
+
+export async function fetchVoteEscrowFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: VoteEscrowSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<Account<VoteEscrow>> {
+  const maybeAccount = await fetchMaybeVoteEscrowFromSeeds(rpc, seeds, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeVoteEscrowFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: VoteEscrowSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<MaybeAccount<VoteEscrow>> {
+  const { programAddress, ...fetchConfig } = config;
+  const [address] = await findVoteEscrowPda(seeds, { programAddress });
+  return await fetchMaybeVoteEscrow(rpc, address, fetchConfig);
+}
@@ -48,48 +48,272 @@ export const PALADIN_REWARDS_ERROR__WITHDRAW_EXCEEDS_DEPOSITED = 0xe; // 14
 export const PALADIN_REWARDS_ERROR__DUNA_DOCUMENT_NOT_INITIALIZED = 0xf; // 15
 /** InvalidDunaPdaSeeds: DUNA PDA invalid seeds */
 export const PALADIN_REWARDS_ERROR__INVALID_DUNA_PDA_SEEDS = 0x10; // 16
+/** IncorrectPoolAuthority: Incorrect holder rewards pool authority */
+export const PALADIN_REWARDS_ERROR__INCORRECT_POOL_AUTHORITY = 0x11; // 17
+/** NoExcessTokens: Pool token account has no excess tokens to recover */
+export const PALADIN_REWARDS_ERROR__NO_EXCESS_TOKENS = 0x12; // 18
+/** InvalidMintOwner: Mint is not owned by the SPL Token program */
+export const PALADIN_REWARDS_ERROR__INVALID_MINT_OWNER = 0x13; // 19
+/** InvalidTokenProgram: Token account is not owned by the SPL Token program */
+export const PALADIN_REWARDS_ERROR__INVALID_TOKEN_PROGRAM = 0x14; // 20
+/** AccountNotWritable: Account must be writable */
+export const PALADIN_REWARDS_ERROR__ACCOUNT_NOT_WRITABLE = 0x15; // 21
+/** IncorrectTokenProgram: Incorrect token program account */
+export const PALADIN_REWARDS_ERROR__INCORRECT_TOKEN_PROGRAM = 0x16; // 22
+/** IncorrectSystemProgram: Incorrect system program account */
+export const PALADIN_REWARDS_ERROR__INCORRECT_SYSTEM_PROGRAM = 0x17; // 23
+/** IncorrectRentPayer: Incorrect rent payer account */
+export const PALADIN_REWARDS_ERROR__INCORRECT_RENT_PAYER = 0x18; // 24
+/** PoolAlreadyMigrated: Holder rewards pool has already been migrated */
+export const PALADIN_REWARDS_ERROR__POOL_ALREADY_MIGRATED = 0x19; // 25
+/** IncorrectRateHistoryAddress: Incorrect rate history address */
+export const PALADIN_REWARDS_ERROR__INCORRECT_RATE_HISTORY_ADDRESS = 0x1a; // 26
+/** IncorrectLockupTiersAddress: Incorrect lockup tiers address */
+export const PALADIN_REWARDS_ERROR__INCORRECT_LOCKUP_TIERS_ADDRESS = 0x1b; // 27
+/** InvalidLockupTiers: Invalid lockup tiers */
+export const PALADIN_REWARDS_ERROR__INVALID_LOCKUP_TIERS = 0x1c; // 28
+/** LockupDurationTooShort: Lockup duration is shorter than the shortest lockup tier */
+export const PALADIN_REWARDS_ERROR__LOCKUP_DURATION_TOO_SHORT = 0x1d; // 29
+/** LockupCannotBeShortened: Lockup cannot be shortened */
+export const PALADIN_REWARDS_ERROR__LOCKUP_CANNOT_BE_SHORTENED = 0x1e; // 30
+/** LockExceedsUnlocked: Lock amount exceeds unlocked deposited tokens */
+export const PALADIN_REWARDS_ERROR__LOCK_EXCEEDS_UNLOCKED = 0x1f; // 31
+/** WithdrawExceedsUnlocked: Withdraw amount exceeds unlocked deposited tokens */
+export const PALADIN_REWARDS_ERROR__WITHDRAW_EXCEEDS_UNLOCKED = 0x20; // 32
+/** LockupNotExpired: Lockup has not expired */
+export const PALADIN_REWARDS_ERROR__LOCKUP_NOT_EXPIRED = 0x21; // 33
+/** LockupDurationTooLong: Lockup duration exceeds the maximum lockup duration */
+export const PALADIN_REWARDS_ERROR__LOCKUP_DURATION_TOO_LONG = 0x22; // 34
+/** IncorrectVoteEscrowAddress: Incorrect vote escrow address */
+export const PALADIN_REWARDS_ERROR__INCORRECT_VOTE_ESCROW_ADDRESS = 0x23; // 35
+/** VoteEscrowRequired: The pool's vote escrow account is required */
+export const PALADIN_REWARDS_ERROR__VOTE_ESCROW_REQUIRED = 0x24; // 36
+/** IncorrectStakeCheckpointsAddress: Incorrect stake checkpoints address */
+export const PALADIN_REWARDS_ERROR__INCORRECT_STAKE_CHECKPOINTS_ADDRESS = 0x25; // 37
+/** StakeCheckpointsRequired: The holder's stake checkpoints account is required */
+export const PALADIN_REWARDS_ERROR__STAKE_CHECKPOINTS_REQUIRED = 0x26; // 38
+/** StakeCheckpointNotFound: No stake checkpoint covers the slot */
+export const PALADIN_REWARDS_ERROR__STAKE_CHECKPOINT_NOT_FOUND = 0x27; // 39
+/** SnapshotSlotNotPast: Snapshot slot must be in the past */
+export const PALADIN_REWARDS_ERROR__SNAPSHOT_SLOT_NOT_PAST = 0x28; // 40
+/** InvalidEarlyWithdrawalPenalty: Early withdrawal penalty exceeds 100% */
+export const PALADIN_REWARDS_ERROR__INVALID_EARLY_WITHDRAWAL_PENALTY = 0x29; // 41
+/** InvalidDepositLimits: Minimum deposit exceeds the maximum holder deposit */
+export const PALADIN_REWARDS_ERROR__INVALID_DEPOSIT_LIMITS = 0x2a; // 42
+/** DepositBelowMinimum: Deposit is below the pool's minimum deposit */
+export const PALADIN_REWARDS_ERROR__DEPOSIT_BELOW_MINIMUM = 0x2b; // 43
+/** HolderDepositCapExceeded: Deposit exceeds the pool's maximum holder deposit */
+export const PALADIN_REWARDS_ERROR__HOLDER_DEPOSIT_CAP_EXCEEDED = 0x2c; // 44
+/** PoolDepositCapExceeded: Deposit exceeds the pool's maximum total deposit */
+export const PALADIN_REWARDS_ERROR__POOL_DEPOSIT_CAP_EXCEEDED = 0x2d; // 45
+/** IncorrectAccessEntryAddress: Incorrect access entry address */
+export const PALADIN_REWARDS_ERROR__INCORRECT_ACCESS_ENTRY_ADDRESS = 0x2e; // 46
+/** AccessEntryRequired: The wallet's access entry is required by the pool's access list */
+export const PALADIN_REWARDS_ERROR__ACCESS_ENTRY_REQUIRED = 0x2f; // 47
+/** AccessDenied: The pool's access list does not permit the wallet to deposit */
+export const PALADIN_REWARDS_ERROR__ACCESS_DENIED = 0x30; // 48
+/** InvalidAccessListMode: Invalid access list mode */
+export const PALADIN_REWARDS_ERROR__INVALID_ACCESS_LIST_MODE = 0x31; // 49
+/** InvalidAccessStatus: Invalid access status */
+export const PALADIN_REWARDS_ERROR__INVALID_ACCESS_STATUS = 0x32; // 50
+/** IncorrectReceiptMintAddress: Incorrect receipt mint address */
+export const PALADIN_REWARDS_ERROR__INCORRECT_RECEIPT_MINT_ADDRESS = 0x33; // 51
+/** ReceiptAccountsRequired: The receipt accounts are required by the pool's receipt mint */
+export const PALADIN_REWARDS_ERROR__RECEIPT_ACCOUNTS_REQUIRED = 0x34; // 52
+/** ReceiptMintAlreadyInitialized: Receipt mint already initialized */
+export const PALADIN_REWARDS_ERROR__RECEIPT_MINT_ALREADY_INITIALIZED = 0x35; // 53
+/** ReceiptMintRequiresEmptyPool: Receipt mint can only be initialized before any deposits */
+export const PALADIN_REWARDS_ERROR__RECEIPT_MINT_REQUIRES_EMPTY_POOL = 0x36; // 54
+/** ReceiptNotTransferring: Receipt tokens are not being transferred */
+export const PALADIN_REWARDS_ERROR__RECEIPT_NOT_TRANSFERRING = 0x37; // 55
+/** ReceiptTransferExceedsUnlocked: Receipt transfer exceeds the holder's unlocked deposit */
+export const PALADIN_REWARDS_ERROR__RECEIPT_TRANSFER_EXCEEDS_UNLOCKED = 0x38; // 56
+/** IncorrectExtraAccountMetasAddress: Incorrect extra account metas address */
+export const PALADIN_REWARDS_ERROR__INCORRECT_EXTRA_ACCOUNT_METAS_ADDRESS = 0x39; // 57
+/** IncorrectSlashingAuthority: Incorrect slashing authority */
+export const PALADIN_REWARDS_ERROR__INCORRECT_SLASHING_AUTHORITY = 0x3a; // 58
+/** IncorrectSlashDestination: Incorrect slash destination */
+export const PALADIN_REWARDS_ERROR__INCORRECT_SLASH_DESTINATION = 0x3b; // 59
+/** SlashExceedsDeposited: Slash exceeds the holder's deposit */
+export const PALADIN_REWARDS_ERROR__SLASH_EXCEEDS_DEPOSITED = 0x3c; // 60
+/** InvalidCampaignSchedule: Campaign must end after it starts and after the current slot */
+export const PALADIN_REWARDS_ERROR__INVALID_CAMPAIGN_SCHEDULE = 0x3d; // 61
+/** IncorrectCampaignAddress: Incorrect campaign address */
+export const PALADIN_REWARDS_ERROR__INCORRECT_CAMPAIGN_ADDRESS = 0x3e; // 62
+/** CampaignNotEnded: Campaign has not ended */
+export const PALADIN_REWARDS_ERROR__CAMPAIGN_NOT_ENDED = 0x3f; // 63
+/** IncorrectCampaignFunder: Incorrect campaign funder */
+export const PALADIN_REWARDS_ERROR__INCORRECT_CAMPAIGN_FUNDER = 0x40; // 64
+/** IncorrectEpochLedgerAddress: Incorrect epoch ledger address */
+export const PALADIN_REWARDS_ERROR__INCORRECT_EPOCH_LEDGER_ADDRESS = 0x41; // 65
+/** HolderRewardsAlreadyMigrated: Holder rewards account has already been migrated */
+export const PALADIN_REWARDS_ERROR__HOLDER_REWARDS_ALREADY_MIGRATED = 0x42; // 66
+/** PoolHasNoWeight: Holder rewards pool has no weight to distribute rewards to */
+export const PALADIN_REWARDS_ERROR__POOL_HAS_NO_WEIGHT = 0x43; // 67
+/** ReceiptPoolNotSlashable: Deposits in a pool with a receipt mint cannot be slashed */
+export const PALADIN_REWARDS_ERROR__RECEIPT_POOL_NOT_SLASHABLE = 0x44; // 68
+/** RateHistoryRequired: The pool's rate history account is required */
+export const PALADIN_REWARDS_ERROR__RATE_HISTORY_REQUIRED = 0x45; // 69
+/** IncorrectPoolTokenAccountAddress: Pool token account is not the pool's associated token account */
+export const PALADIN_REWARDS_ERROR__INCORRECT_POOL_TOKEN_ACCOUNT_ADDRESS = 0x46; // 70
+/** EpochLedgerRequired: The pool's epoch ledger account is required */
+export const PALADIN_REWARDS_ERROR__EPOCH_LEDGER_REQUIRED = 0x47; // 71
+/** ReceiptRecipientNotInitialized: Receipt recipient has no holder rewards account */
+export const PALADIN_REWARDS_ERROR__RECEIPT_RECIPIENT_NOT_INITIALIZED = 0x48; // 72
 
 export type PaladinRewardsError =
+  | typeof PALADIN_REWARDS_ERROR__ACCESS_DENIED
+  | typeof PALADIN_REWARDS_ERROR__ACCESS_ENTRY_REQUIRED
+  | typeof PALADIN_REWARDS_ERROR__ACCOUNT_NOT_WRITABLE
+  | typeof PALADIN_REWARDS_ERROR__CAMPAIGN_NOT_ENDED
   | typeof PALADIN_REWARDS_ERROR__CLOSE_WITH_DEPOSITED_TOKENS
   | typeof PALADIN_REWARDS_ERROR__CLOSE_WITH_UNCLAIMED_REWARDS
+  | typeof PALADIN_REWARDS_ERROR__DEPOSIT_BELOW_MINIMUM
   | typeof PALADIN_REWARDS_ERROR__DUNA_DOCUMENT_NOT_INITIALIZED
+  | typeof PALADIN_REWARDS_ERROR__EPOCH_LEDGER_REQUIRED
+  | typeof PALADIN_REWARDS_ERROR__HOLDER_DEPOSIT_CAP_EXCEEDED
+  | typeof PALADIN_REWARDS_ERROR__HOLDER_REWARDS_ALREADY_MIGRATED
+  | typeof PALADIN_REWARDS_ERROR__INCORRECT_ACCESS_ENTRY_ADDRESS
+  | typeof PALADIN_REWARDS_ERROR__INCORRECT_CAMPAIGN_ADDRESS
+  | typeof PALADIN_REWARDS_ERROR__INCORRECT_CAMPAIGN_FUNDER
+  | typeof PALADIN_REWARDS_ERROR__INCORRECT_EPOCH_LEDGER_ADDRESS
+  | typeof PALADIN_REWARDS_ERROR__INCORRECT_EXTRA_ACCOUNT_METAS_ADDRESS
   | typeof PALADIN_REWARDS_ERROR__INCORRECT_HOLDER_REWARDS_ADDRESS
   | typeof PALADIN_REWARDS_ERROR__INCORRECT_HOLDER_REWARDS_POOL_ADDRESS
+  | typeof PALADIN_REWARDS_ERROR__INCORRECT_LOCKUP_TIERS_ADDRESS
+  | typeof PALADIN_REWARDS_ERROR__INCORRECT_POOL_AUTHORITY
+  | typeof PALADIN_REWARDS_ERROR__INCORRECT_POOL_TOKEN_ACCOUNT_ADDRESS
+  | typeof PALADIN_REWARDS_ERROR__INCORRECT_RATE_HISTORY_ADDRESS
+  | typeof PALADIN_REWARDS_ERROR__INCORRECT_RECEIPT_MINT_ADDRESS
+  | typeof PALADIN_REWARDS_ERROR__INCORRECT_RENT_PAYER
+  | typeof PALADIN_REWARDS_ERROR__INCORRECT_SLASH_DESTINATION
+  | typeof PALADIN_REWARDS_ERROR__INCORRECT_SLASHING_AUTHORITY
+  | typeof PALADIN_REWARDS_ERROR__INCORRECT_STAKE_CHECKPOINTS_ADDRESS
+  | typeof PALADIN_REWARDS_ERROR__INCORRECT_SYSTEM_PROGRAM
+  | typeof PALADIN_REWARDS_ERROR__INCORRECT_TOKEN_PROGRAM
+  | typeof PALADIN_REWARDS_ERROR__INCORRECT_VOTE_ESCROW_ADDRESS
+  | typeof PALADIN_REWARDS_ERROR__INVALID_ACCESS_LIST_MODE
+  | typeof PALADIN_REWARDS_ERROR__INVALID_ACCESS_STATUS
+  | typeof PALADIN_REWARDS_ERROR__INVALID_CAMPAIGN_SCHEDULE
   | typeof PALADIN_REWARDS_ERROR__INVALID_CLOSING_BALANCE
+  | typeof PALADIN_REWARDS_ERROR__INVALID_DEPOSIT_LIMITS
   | typeof PALADIN_REWARDS_ERROR__INVALID_DUNA_PDA_SEEDS
+  | typeof PALADIN_REWARDS_ERROR__INVALID_EARLY_WITHDRAWAL_PENALTY
+  | typeof PALADIN_REWARDS_ERROR__INVALID_LOCKUP_TIERS
+  | typeof PALADIN_REWARDS_ERROR__INVALID_MINT_OWNER
+  | typeof PALADIN_REWARDS_ERROR__INVALID_TOKEN_PROGRAM
+  | typeof PALADIN_REWARDS_ERROR__LOCK_EXCEEDS_UNLOCKED
+  | typeof PALADIN_REWARDS_ERROR__LOCKUP_CANNOT_BE_SHORTENED
+  | typeof PALADIN_REWARDS_ERROR__LOCKUP_DURATION_TOO_LONG
+  | typeof PALADIN_REWARDS_ERROR__LOCKUP_DURATION_TOO_SHORT
+  | typeof PALADIN_REWARDS_ERROR__LOCKUP_NOT_EXPIRED
   | typeof PALADIN_REWARDS_ERROR__NO_DEPOSITED_TOKENS_TO_WITHDRAW
+  | typeof PALADIN_REWARDS_ERROR__NO_EXCESS_TOKENS
   | typeof PALADIN_REWARDS_ERROR__NOT_ENOUGH_TOKEN_TO_DEPOSIT
   | typeof PALADIN_REWARDS_ERROR__NOT_OWNER_TOKEN_ACCOUNT
   | typeof PALADIN_REWARDS_ERROR__OWNER_NOT_SIGNER
+  | typeof PALADIN_REWARDS_ERROR__POOL_ALREADY_MIGRATED
+  | typeof PALADIN_REWARDS_ERROR__POOL_DEPOSIT_CAP_EXCEEDED
+  | typeof PALADIN_REWARDS_ERROR__POOL_HAS_NO_WEIGHT
+  | typeof PALADIN_REWARDS_ERROR__RATE_HISTORY_REQUIRED
+  | typeof PALADIN_REWARDS_ERROR__RECEIPT_ACCOUNTS_REQUIRED
+  | typeof PALADIN_REWARDS_ERROR__RECEIPT_MINT_ALREADY_INITIALIZED
+  | typeof PALADIN_REWARDS_ERROR__RECEIPT_MINT_REQUIRES_EMPTY_POOL
+  | typeof PALADIN_REWARDS_ERROR__RECEIPT_NOT_TRANSFERRING
+  | typeof PALADIN_REWARDS_ERROR__RECEIPT_POOL_NOT_SLASHABLE
+  | typeof PALADIN_REWARDS_ERROR__RECEIPT_RECIPIENT_NOT_INITIALIZED
+  | typeof PALADIN_REWARDS_ERROR__RECEIPT_TRANSFER_EXCEEDS_UNLOCKED
   | typeof PALADIN_REWARDS_ERROR__REWARDS_EXCESS_POOL_BALANCE
+  | typeof PALADIN_REWARDS_ERROR__SLASH_EXCEEDS_DEPOSITED
+  | typeof PALADIN_REWARDS_ERROR__SNAPSHOT_SLOT_NOT_PAST
+  | typeof PALADIN_REWARDS_ERROR__STAKE_CHECKPOINT_NOT_FOUND
+  | typeof PALADIN_REWARDS_ERROR__STAKE_CHECKPOINTS_REQUIRED
   | typeof PALADIN_REWARDS_ERROR__TOKEN_ACCOUNT_FROZEN
   | typeof PALADIN_REWARDS_ERROR__TOKEN_ACCOUNT_MINT_MISMATCH
   | typeof PALADIN_REWARDS_ERROR__TOKEN_ACCOUNT_OWNER_MISSMATCH
+  | typeof PALADIN_REWARDS_ERROR__VOTE_ESCROW_REQUIRED
   | typeof PALADIN_REWARDS_ERROR__WITHDRAW_EXCEEDS_DEPOSITED
-  | typeof PALADIN_REWARDS_ERROR__WITHDRAW_EXCEEDS_POOL_BALANCE;
+  | typeof PALADIN_REWARDS_ERROR__WITHDRAW_EXCEEDS_POOL_BALANCE
+  | typeof PALADIN_REWARDS_ERROR__WITHDRAW_EXCEEDS_UNLOCKED;
 
 let paladinRewardsErrorMessages:
   | Record<PaladinRewardsError, string>
   | undefined;
 if (process.env.NODE_ENV !== 'production') {
   paladinRewardsErrorMessages = {
+    [PALADIN_REWARDS_ERROR__ACCESS_DENIED]: `The pool's access list does not permit the wallet to deposit`,
+    [PALADIN_REWARDS_ERROR__ACCESS_ENTRY_REQUIRED]: `The wallet's access entry is required by the pool's access list`,
+    [PALADIN_REWARDS_ERROR__ACCOUNT_NOT_WRITABLE]: `Account must be writable`,
+    [PALADIN_REWARDS_ERROR__CAMPAIGN_NOT_ENDED]: `Campaign has not ended`,
     [PALADIN_REWARDS_ERROR__CLOSE_WITH_DEPOSITED_TOKENS]: `Holder rewards has deposited tokens`,
     [PALADIN_REWARDS_ERROR__CLOSE_WITH_UNCLAIMED_REWARDS]: `Holder rewards has unclaimed rewards`,
+    [PALADIN_REWARDS_ERROR__DEPOSIT_BELOW_MINIMUM]: `Deposit is below the pool's minimum deposit`,
     [PALADIN_REWARDS_ERROR__DUNA_DOCUMENT_NOT_INITIALIZED]: `DUNA document is not initialized`,
+    [PALADIN_REWARDS_ERROR__EPOCH_LEDGER_REQUIRED]: `The pool's epoch ledger account is required`,
+    [PALADIN_REWARDS_ERROR__HOLDER_DEPOSIT_CAP_EXCEEDED]: `Deposit exceeds the pool's maximum holder deposit`,
+    [PALADIN_REWARDS_ERROR__HOLDER_REWARDS_ALREADY_MIGRATED]: `Holder rewards account has already been migrated`,
+    [PALADIN_REWARDS_ERROR__INCORRECT_ACCESS_ENTRY_ADDRESS]: `Incorrect access entry address`,
+    [PALADIN_REWARDS_ERROR__INCORRECT_CAMPAIGN_ADDRESS]: `Incorrect campaign address`,
+    [PALADIN_REWARDS_ERROR__INCORRECT_CAMPAIGN_FUNDER]: `Incorrect campaign funder`,
+    [PALADIN_REWARDS_ERROR__INCORRECT_EPOCH_LEDGER_ADDRESS]: `Incorrect epoch ledger address`,
+    [PALADIN_REWARDS_ERROR__INCORRECT_EXTRA_ACCOUNT_METAS_ADDRESS]: `Incorrect extra account metas address`,
     [PALADIN_REWARDS_ERROR__INCORRECT_HOLDER_REWARDS_ADDRESS]: `Incorrect holder rewards address`,
     [PALADIN_REWARDS_ERROR__INCORRECT_HOLDER_REWARDS_POOL_ADDRESS]: `Incorrect holder rewards pool address`,
+    [PALADIN_REWARDS_ERROR__INCORRECT_LOCKUP_TIERS_ADDRESS]: `Incorrect lockup tiers address`,
+    [PALADIN_REWARDS_ERROR__INCORRECT_POOL_AUTHORITY]: `Incorrect holder rewards pool authority`,
+    [PALADIN_REWARDS_ERROR__INCORRECT_POOL_TOKEN_ACCOUNT_ADDRESS]: `Pool token account is not the pool's associated token account`,
+    [PALADIN_REWARDS_ERROR__INCORRECT_RATE_HISTORY_ADDRESS]: `Incorrect rate history address`,
+    [PALADIN_REWARDS_ERROR__INCORRECT_RECEIPT_MINT_ADDRESS]: `Incorrect receipt mint address`,
+    [PALADIN_REWARDS_ERROR__INCORRECT_RENT_PAYER]: `Incorrect rent payer account`,
+    [PALADIN_REWARDS_ERROR__INCORRECT_SLASH_DESTINATION]: `Incorrect slash destination`,
+    [PALADIN_REWARDS_ERROR__INCORRECT_SLASHING_AUTHORITY]: `Incorrect slashing authority`,
+    [PALADIN_REWARDS_ERROR__INCORRECT_STAKE_CHECKPOINTS_ADDRESS]: `Incorrect stake checkpoints address`,
+    [PALADIN_REWARDS_ERROR__INCORRECT_SYSTEM_PROGRAM]: `Incorrect system program account`,
+    [PALADIN_REWARDS_ERROR__INCORRECT_TOKEN_PROGRAM]: `Incorrect token program account`,
+    [PALADIN_REWARDS_ERROR__INCORRECT_VOTE_ESCROW_ADDRESS]: `Incorrect vote escrow address`,
+    [PALADIN_REWARDS_ERROR__INVALID_ACCESS_LIST_MODE]: `Invalid access list mode`,
+    [PALADIN_REWARDS_ERROR__INVALID_ACCESS_STATUS]: `Invalid access status`,
+    [PALADIN_REWARDS_ERROR__INVALID_CAMPAIGN_SCHEDULE]: `Campaign must end after it starts and after the current slot`,
     [PALADIN_REWARDS_ERROR__INVALID_CLOSING_BALANCE]: `Cannot close holder rewards with current balance`,
+    [PALADIN_REWARDS_ERROR__INVALID_DEPOSIT_LIMITS]: `Minimum deposit exceeds the maximum holder deposit`,
     [PALADIN_REWARDS_ERROR__INVALID_DUNA_PDA_SEEDS]: `DUNA PDA invalid seeds`,
+    [PALADIN_REWARDS_ERROR__INVALID_EARLY_WITHDRAWAL_PENALTY]: `Early withdrawal penalty exceeds 100%`,
+    [PALADIN_REWARDS_ERROR__INVALID_LOCKUP_TIERS]: `Invalid lockup tiers`,
+    [PALADIN_REWARDS_ERROR__INVALID_MINT_OWNER]: `Mint is not owned by the SPL Token program`,
+    [PALADIN_REWARDS_ERROR__INVALID_TOKEN_PROGRAM]: `Token account is not owned by the SPL Token program`,
+    [PALADIN_REWARDS_ERROR__LOCK_EXCEEDS_UNLOCKED]: `Lock amount exceeds unlocked deposited tokens`,
+    [PALADIN_REWARDS_ERROR__LOCKUP_CANNOT_BE_SHORTENED]: `Lockup cannot be shortened`,
+    [PALADIN_REWARDS_ERROR__LOCKUP_DURATION_TOO_LONG]: `Lockup duration exceeds the maximum lockup duration`,
+    [PALADIN_REWARDS_ERROR__LOCKUP_DURATION_TOO_SHORT]: `Lockup duration is shorter than the shortest lockup tier`,
+    [PALADIN_REWARDS_ERROR__LOCKUP_NOT_EXPIRED]: `Lockup has not expired`,
     [PALADIN_REWARDS_ERROR__NO_DEPOSITED_TOKENS_TO_WITHDRAW]: `Holder doesn't have any deposited tokens to withdraw`,
+    [PALADIN_REWARDS_ERROR__NO_EXCESS_TOKENS]: `Pool token account has no excess tokens to recover`,
     [PALADIN_REWARDS_ERROR__NOT_ENOUGH_TOKEN_TO_DEPOSIT]: `Owner doesn'thave enough tokens to deposit`,
     [PALADIN_REWARDS_ERROR__NOT_OWNER_TOKEN_ACCOUNT]: `Signer not owner of token account`,
     [PALADIN_REWARDS_ERROR__OWNER_NOT_SIGNER]: `Owner is not the signer`,
+    [PALADIN_REWARDS_ERROR__POOL_ALREADY_MIGRATED]: `Holder rewards pool has already been migrated`,
+    [PALADIN_REWARDS_ERROR__POOL_DEPOSIT_CAP_EXCEEDED]: `Deposit exceeds the pool's maximum total deposit`,
+    [PALADIN_REWARDS_ERROR__POOL_HAS_NO_WEIGHT]: `Holder rewards pool has no weight to distribute rewards to`,
+    [PALADIN_REWARDS_ERROR__RATE_HISTORY_REQUIRED]: `The pool's rate history account is required`,
+    [PALADIN_REWARDS_ERROR__RECEIPT_ACCOUNTS_REQUIRED]: `The receipt accounts are required by the pool's receipt mint`,
+    [PALADIN_REWARDS_ERROR__RECEIPT_MINT_ALREADY_INITIALIZED]: `Receipt mint already initialized`,
+    [PALADIN_REWARDS_ERROR__RECEIPT_MINT_REQUIRES_EMPTY_POOL]: `Receipt mint can only be initialized before any deposits`,
+    [PALADIN_REWARDS_ERROR__RECEIPT_NOT_TRANSFERRING]: `Receipt tokens are not being transferred`,
+    [PALADIN_REWARDS_ERROR__RECEIPT_POOL_NOT_SLASHABLE]: `Deposits in a pool with a receipt mint cannot be slashed`,
+    [PALADIN_REWARDS_ERROR__RECEIPT_RECIPIENT_NOT_INITIALIZED]: `Receipt recipient has no holder rewards account`,
+    [PALADIN_REWARDS_ERROR__RECEIPT_TRANSFER_EXCEEDS_UNLOCKED]: `Receipt transfer exceeds the holder's unlocked deposit`,
     [PALADIN_REWARDS_ERROR__REWARDS_EXCESS_POOL_BALANCE]: `Rewards amount exceeds pool balance`,
+    [PALADIN_REWARDS_ERROR__SLASH_EXCEEDS_DEPOSITED]: `Slash exceeds the holder's deposit`,
+    [PALADIN_REWARDS_ERROR__SNAPSHOT_SLOT_NOT_PAST]: `Snapshot slot must be in the past`,
+    [PALADIN_REWARDS_ERROR__STAKE_CHECKPOINT_NOT_FOUND]: `No stake checkpoint covers the slot`,
+    [PALADIN_REWARDS_ERROR__STAKE_CHECKPOINTS_REQUIRED]: `The holder's stake checkpoints account is required`,
     [PALADIN_REWARDS_ERROR__TOKEN_ACCOUNT_FROZEN]: `Token account is frozen`,
     [PALADIN_REWARDS_ERROR__TOKEN_ACCOUNT_MINT_MISMATCH]: `Token account mint mismatch`,
     [PALADIN_REWARDS_ERROR__TOKEN_ACCOUNT_OWNER_MISSMATCH]: `Token account owner mismatch`,
+    [PALADIN_REWARDS_ERROR__VOTE_ESCROW_REQUIRED]: `The pool's vote escrow account is required`,
     [PALADIN_REWARDS_ERROR__WITHDRAW_EXCEEDS_DEPOSITED]: `Withdraw amount exceeds deposited`,
     [PALADIN_REWARDS_ERROR__WITHDRAW_EXCEEDS_POOL_BALANCE]: `Pool doesn't have enough balance to withdraw`,
+    [PALADIN_REWARDS_ERROR__WITHDRAW_EXCEEDS_UNLOCKED]: `Withdraw amount exceeds unlocked deposited tokens`,
   };
 }
 
@@ -0,0 +1,264 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { PALADIN_REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const CHECKPOINT_VOTING_POWER_DISCRIMINATOR = 14;
+
+export function getCheckpointVotingPowerDiscriminatorBytes() {
+  return getU8Encoder().encode(CHECKPOINT_VOTING_POWER_DISCRIMINATOR);
+}
+
+export type CheckpointVotingPowerInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountHolderRewards extends string | IAccountMeta<string> = string,
+  TAccountVoteEscrow extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountOwner extends string | IAccountMeta<string> = string,
+  TAccountRateHistory extends string | IAccountMeta<string> = string,
+  TAccountEpochLedger extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewardsPool extends string
+        ? WritableAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountHolderRewards extends string
+        ? WritableAccount<TAccountHolderRewards>
+        : TAccountHolderRewards,
+      TAccountVoteEscrow extends string
+        ? WritableAccount<TAccountVoteEscrow>
+        : TAccountVoteEscrow,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountOwner extends string
+        ? WritableAccount<TAccountOwner>
+        : TAccountOwner,
+      TAccountRateHistory extends string
+        ? WritableAccount<TAccountRateHistory>
+        : TAccountRateHistory,
+      TAccountEpochLedger extends string
+        ? WritableAccount<TAccountEpochLedger>
+        : TAccountEpochLedger,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type CheckpointVotingPowerInstructionData = { discriminator: number };
+
+export type CheckpointVotingPowerInstructionDataArgs = {};
+
+export function getCheckpointVotingPowerInstructionDataEncoder(): Encoder<CheckpointVotingPowerInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: CHECKPOINT_VOTING_POWER_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getCheckpointVotingPowerInstructionDataDecoder(): Decoder<CheckpointVotingPowerInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getCheckpointVotingPowerInstructionDataCodec(): Codec<
+  CheckpointVotingPowerInstructionDataArgs,
+  CheckpointVotingPowerInstructionData
+> {
+  return combineCodec(
+    getCheckpointVotingPowerInstructionDataEncoder(),
+    getCheckpointVotingPowerInstructionDataDecoder()
+  );
+}
+
+export type CheckpointVotingPowerInput<
+  TAccountHolderRewardsPool extends string = string,
+  TAccountHolderRewards extends string = string,
+  TAccountVoteEscrow extends string = string,
+  TAccountMint extends string = string,
+  TAccountOwner extends string = string,
+  TAccountRateHistory extends string = string,
+  TAccountEpochLedger extends string = string,
+> = {
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Holder rewards account. */
+  holderRewards: Address<TAccountHolderRewards>;
+  /** Vote escrow account. */
+  voteEscrow: Address<TAccountVoteEscrow>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Owner of the holder rewards account. */
+  owner: Address<TAccountOwner>;
+  /** Rate history account, if the pool records one. */
+  rateHistory?: Address<TAccountRateHistory>;
+  /** Epoch ledger account, if the pool records one. */
+  epochLedger?: Address<TAccountEpochLedger>;
+};
+
+export function getCheckpointVotingPowerInstruction<
+  TAccountHolderRewardsPool extends string,
+  TAccountHolderRewards extends string,
+  TAccountVoteEscrow extends string,
+  TAccountMint extends string,
+  TAccountOwner extends string,
+  TAccountRateHistory extends string,
+  TAccountEpochLedger extends string,
+  TProgramAddress extends Address = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+>(
+  input: CheckpointVotingPowerInput<
+    TAccountHolderRewardsPool,
+    TAccountHolderRewards,
+    TAccountVoteEscrow,
+    TAccountMint,
+    TAccountOwner,
+    TAccountRateHistory,
+    TAccountEpochLedger
+  >,
+  config?: { programAddress?: TProgramAddress }
+): CheckpointVotingPowerInstruction<
+  TProgramAddress,
+  TAccountHolderRewardsPool,
+  TAccountHolderRewards,
+  TAccountVoteEscrow,
+  TAccountMint,
+  TAccountOwner,
+  TAccountRateHistory,
+  TAccountEpochLedger
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? PALADIN_REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: true,
+    },
+    holderRewards: { value: input.holderRewards ?? null, isWritable: true },
+    voteEscrow: { value: input.voteEscrow ?? null, isWritable: true },
+    mint: { value: input.mint ?? null, isWritable: false },
+    owner: { value: input.owner ?? null, isWritable: true },
+    rateHistory: { value: input.rateHistory ?? null, isWritable: true },
+    epochLedger: { value: input.epochLedger ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.holderRewards),
+      getAccountMeta(accounts.voteEscrow),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.owner),
+      getAccountMeta(accounts.rateHistory),
+      getAccountMeta(accounts.epochLedger),
+    ],
+    programAddress,
+    data: getCheckpointVotingPowerInstructionDataEncoder().encode({}),
+  } as CheckpointVotingPowerInstruction<
+    TProgramAddress,
+    TAccountHolderRewardsPool,
+    TAccountHolderRewards,
+    TAccountVoteEscrow,
+    TAccountMint,
+    TAccountOwner,
+    TAccountRateHistory,
+    TAccountEpochLedger
+  >;
+
+  return instruction;
+}
+
+export type ParsedCheckpointVotingPowerInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[0];
+    /** Holder rewards account. */
+    holderRewards: TAccountMetas[1];
+    /** Vote escrow account. */
+    voteEscrow: TAccountMetas[2];
+    /** Token mint. */
+    mint: TAccountMetas[3];
+    /** Owner of the holder rewards account. */
+    owner: TAccountMetas[4];
+    /** Rate history account, if the pool records one. */
+    rateHistory: TAccountMetas[5];
+    /** Epoch ledger account, if the pool records one. */
+    epochLedger: TAccountMetas[6];
+  };
+  data: CheckpointVotingPowerInstructionData;
+};
+
+export function parseCheckpointVotingPowerInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedCheckpointVotingPowerInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 7) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewardsPool: getNextAccount(),
+      holderRewards: getNextAccount(),
+      voteEscrow: getNextAccount(),
+      mint: getNextAccount(),
+      owner: getNextAccount(),
+      rateHistory: getNextAccount(),
+      epochLedger: getNextAccount(),
+    },
+    data: getCheckpointVotingPowerInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -45,6 +45,9 @@ export type CloseHolderRewardsInstruction<
   TAccountHolderRewards extends string | IAccountMeta<string> = string,
   TAccountMint extends string | IAccountMeta<string> = string,
   TAccountOwner extends string | IAccountMeta<string> = string,
+  TAccountRentPayer extends string | IAccountMeta<string> = string,
+  TAccountRateHistory extends string | IAccountMeta<string> = string,
+  TAccountEpochLedger extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -66,6 +69,15 @@ export type CloseHolderRewardsInstruction<
         ? WritableSignerAccount<TAccountOwner> &
             IAccountSignerMeta<TAccountOwner>
         : TAccountOwner,
+      TAccountRentPayer extends string
+        ? WritableAccount<TAccountRentPayer>
+        : TAccountRentPayer,
+      TAccountRateHistory extends string
+        ? WritableAccount<TAccountRateHistory>
+        : TAccountRateHistory,
+      TAccountEpochLedger extends string
+        ? WritableAccount<TAccountEpochLedger>
+        : TAccountEpochLedger,
       ...TRemainingAccounts,
     ]
   >;
@@ -101,6 +113,9 @@ export type CloseHolderRewardsInput<
   TAccountHolderRewards extends string = string,
   TAccountMint extends string = string,
   TAccountOwner extends string = string,
+  TAccountRentPayer extends string = string,
+  TAccountRateHistory extends string = string,
+  TAccountEpochLedger extends string = string,
 > = {
   /** Holder rewards pool account. */
   holderRewardsPool: Address<TAccountHolderRewardsPool>;
@@ -112,6 +127,12 @@ export type CloseHolderRewardsInput<
   mint: Address<TAccountMint>;
   /** Owner of the account. */
   owner: TransactionSigner<TAccountOwner>;
+  /** Payer that funded the holder rewards account. */
+  rentPayer: Address<TAccountRentPayer>;
+  /** Rate history account, if the pool records one. */
+  rateHistory?: Address<TAccountRateHistory>;
+  /** Epoch ledger account, if the pool records one. */
+  epochLedger?: Address<TAccountEpochLedger>;
 };
 
 export function getCloseHolderRewardsInstruction<
@@ -120,6 +141,9 @@ export function getCloseHolderRewardsInstruction<
   TAccountHolderRewards extends string,
   TAccountMint extends string,
   TAccountOwner extends string,
+  TAccountRentPayer extends string,
+  TAccountRateHistory extends string,
+  TAccountEpochLedger extends string,
   TProgramAddress extends Address = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
 >(
   input: CloseHolderRewardsInput<
@@ -127,7 +151,10 @@ export function getCloseHolderRewardsInstruction<
     TAccountHolderRewardsPoolTokenAccount,
     TAccountHolderRewards,
     TAccountMint,
-    TAccountOwner
+    TAccountOwner,
+    TAccountRentPayer,
+    TAccountRateHistory,
+    TAccountEpochLedger
   >,
   config?: { programAddress?: TProgramAddress }
 ): CloseHolderRewardsInstruction<
@@ -136,7 +163,10 @@ export function getCloseHolderRewardsInstruction<
   TAccountHolderRewardsPoolTokenAccount,
   TAccountHolderRewards,
   TAccountMint,
-  TAccountOwner
+  TAccountOwner,
+  TAccountRentPayer,
+  TAccountRateHistory,
+  TAccountEpochLedger
 > {
   // Program address.
   const programAddress =
@@ -155,6 +185,9 @@ export function getCloseHolderRewardsInstruction<
     holderRewards: { value: input.holderRewards ?? null, isWritable: true },
     mint: { value: input.mint ?? null, isWritable: false },
     owner: { value: input.owner ?? null, isWritable: true },
+    rentPayer: { value: input.rentPayer ?? null, isWritable: true },
+    rateHistory: { value: input.rateHistory ?? null, isWritable: true },
+    epochLedger: { value: input.epochLedger ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -169,6 +202,9 @@ export function getCloseHolderRewardsInstruction<
       getAccountMeta(accounts.holderRewards),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.owner),
+      getAccountMeta(accounts.rentPayer),
+      getAccountMeta(accounts.rateHistory),
+      getAccountMeta(accounts.epochLedger),
     ],
     programAddress,
     data: getCloseHolderRewardsInstructionDataEncoder().encode({}),
@@ -178,7 +214,10 @@ export function getCloseHolderRewardsInstruction<
     TAccountHolderRewardsPoolTokenAccount,
     TAccountHolderRewards,
     TAccountMint,
-    TAccountOwner
+    TAccountOwner,
+    TAccountRentPayer,
+    TAccountRateHistory,
+    TAccountEpochLedger
   >;
 
   return instruction;
@@ -200,6 +239,12 @@ export type ParsedCloseHolderRewardsInstruction<
     mint: TAccountMetas[3];
     /** Owner of the account. */
     owner: TAccountMetas[4];
+    /** Payer that funded the holder rewards account. */
+    rentPayer: TAccountMetas[5];
+    /** Rate history account, if the pool records one. */
+    rateHistory: TAccountMetas[6];
+    /** Epoch ledger account, if the pool records one. */
+    epochLedger: TAccountMetas[7];
   };
   data: CloseHolderRewardsInstructionData;
 };
@@ -212,7 +257,7 @@ export function parseCloseHolderRewardsInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedCloseHolderRewardsInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -230,6 +275,9 @@ export function parseCloseHolderRewardsInstruction<
       holderRewards: getNextAccount(),
       mint: getNextAccount(),
       owner: getNextAccount(),
+      rentPayer: getNextAccount(),
+      rateHistory: getNextAccount(),
+      epochLedger: getNextAccount(),
     },
     data: getCloseHolderRewardsInstructionDataDecoder().decode(
       instruction.data
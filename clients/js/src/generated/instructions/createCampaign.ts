@@ -0,0 +1,268 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { PALADIN_REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const CREATE_CAMPAIGN_DISCRIMINATOR = 26;
+
+export function getCreateCampaignDiscriminatorBytes() {
+  return getU8Encoder().encode(CREATE_CAMPAIGN_DISCRIMINATOR);
+}
+
+export type CreateCampaignInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountCampaign extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountFunder extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewardsPool extends string
+        ? ReadonlyAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountCampaign extends string
+        ? WritableAccount<TAccountCampaign>
+        : TAccountCampaign,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountFunder extends string
+        ? WritableSignerAccount<TAccountFunder> &
+            IAccountSignerMeta<TAccountFunder>
+        : TAccountFunder,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type CreateCampaignInstructionData = {
+  discriminator: number;
+  amount: bigint;
+  startSlot: bigint;
+  endSlot: bigint;
+};
+
+export type CreateCampaignInstructionDataArgs = {
+  amount: number | bigint;
+  startSlot: number | bigint;
+  endSlot: number | bigint;
+};
+
+export function getCreateCampaignInstructionDataEncoder(): Encoder<CreateCampaignInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['amount', getU64Encoder()],
+      ['startSlot', getU64Encoder()],
+      ['endSlot', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: CREATE_CAMPAIGN_DISCRIMINATOR })
+  );
+}
+
+export function getCreateCampaignInstructionDataDecoder(): Decoder<CreateCampaignInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['amount', getU64Decoder()],
+    ['startSlot', getU64Decoder()],
+    ['endSlot', getU64Decoder()],
+  ]);
+}
+
+export function getCreateCampaignInstructionDataCodec(): Codec<
+  CreateCampaignInstructionDataArgs,
+  CreateCampaignInstructionData
+> {
+  return combineCodec(
+    getCreateCampaignInstructionDataEncoder(),
+    getCreateCampaignInstructionDataDecoder()
+  );
+}
+
+export type CreateCampaignInput<
+  TAccountHolderRewardsPool extends string = string,
+  TAccountCampaign extends string = string,
+  TAccountMint extends string = string,
+  TAccountFunder extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Campaign account. */
+  campaign: Address<TAccountCampaign>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Funder escrowing the rewards and the rent for the campaign account. */
+  funder: TransactionSigner<TAccountFunder>;
+  /** System program. */
+  systemProgram?: Address<TAccountSystemProgram>;
+  amount: CreateCampaignInstructionDataArgs['amount'];
+  startSlot: CreateCampaignInstructionDataArgs['startSlot'];
+  endSlot: CreateCampaignInstructionDataArgs['endSlot'];
+};
+
+export function getCreateCampaignInstruction<
+  TAccountHolderRewardsPool extends string,
+  TAccountCampaign extends string,
+  TAccountMint extends string,
+  TAccountFunder extends string,
+  TAccountSystemProgram extends string,
+  TProgramAddress extends Address = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+>(
+  input: CreateCampaignInput<
+    TAccountHolderRewardsPool,
+    TAccountCampaign,
+    TAccountMint,
+    TAccountFunder,
+    TAccountSystemProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): CreateCampaignInstruction<
+  TProgramAddress,
+  TAccountHolderRewardsPool,
+  TAccountCampaign,
+  TAccountMint,
+  TAccountFunder,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? PALADIN_REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: false,
+    },
+    campaign: { value: input.campaign ?? null, isWritable: true },
+    mint: { value: input.mint ?? null, isWritable: false },
+    funder: { value: input.funder ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.campaign),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.funder),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getCreateCampaignInstructionDataEncoder().encode(
+      args as CreateCampaignInstructionDataArgs
+    ),
+  } as CreateCampaignInstruction<
+    TProgramAddress,
+    TAccountHolderRewardsPool,
+    TAccountCampaign,
+    TAccountMint,
+    TAccountFunder,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedCreateCampaignInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[0];
+    /** Campaign account. */
+    campaign: TAccountMetas[1];
+    /** Token mint. */
+    mint: TAccountMetas[2];
+    /** Funder escrowing the rewards and the rent for the campaign account. */
+    funder: TAccountMetas[3];
+    /** System program. */
+    systemProgram: TAccountMetas[4];
+  };
+  data: CreateCampaignInstructionData;
+};
+
+export function parseCreateCampaignInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedCreateCampaignInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 5) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewardsPool: getNextAccount(),
+      campaign: getNextAccount(),
+      mint: getNextAccount(),
+      funder: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getCreateCampaignInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -51,6 +51,13 @@ export type DepositInstruction<
   TAccountTokenProgram extends
     | string
     | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountRateHistory extends string | IAccountMeta<string> = string,
+  TAccountStakeCheckpoints extends string | IAccountMeta<string> = string,
+  TAccountAccessEntry extends string | IAccountMeta<string> = string,
+  TAccountReceiptMint extends string | IAccountMeta<string> = string,
+  TAccountReceiptTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountReceiptTokenProgram extends string | IAccountMeta<string> = string,
+  TAccountEpochLedger extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -78,6 +85,27 @@ export type DepositInstruction<
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
+      TAccountRateHistory extends string
+        ? WritableAccount<TAccountRateHistory>
+        : TAccountRateHistory,
+      TAccountStakeCheckpoints extends string
+        ? WritableAccount<TAccountStakeCheckpoints>
+        : TAccountStakeCheckpoints,
+      TAccountAccessEntry extends string
+        ? ReadonlyAccount<TAccountAccessEntry>
+        : TAccountAccessEntry,
+      TAccountReceiptMint extends string
+        ? WritableAccount<TAccountReceiptMint>
+        : TAccountReceiptMint,
+      TAccountReceiptTokenAccount extends string
+        ? WritableAccount<TAccountReceiptTokenAccount>
+        : TAccountReceiptTokenAccount,
+      TAccountReceiptTokenProgram extends string
+        ? ReadonlyAccount<TAccountReceiptTokenProgram>
+        : TAccountReceiptTokenProgram,
+      TAccountEpochLedger extends string
+        ? WritableAccount<TAccountEpochLedger>
+        : TAccountEpochLedger,
       ...TRemainingAccounts,
     ]
   >;
@@ -121,6 +149,13 @@ export type DepositInput<
   TAccountMint extends string = string,
   TAccountOwner extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountRateHistory extends string = string,
+  TAccountStakeCheckpoints extends string = string,
+  TAccountAccessEntry extends string = string,
+  TAccountReceiptMint extends string = string,
+  TAccountReceiptTokenAccount extends string = string,
+  TAccountReceiptTokenProgram extends string = string,
+  TAccountEpochLedger extends string = string,
 > = {
   /** Holder rewards pool account. */
   holderRewardsPool: Address<TAccountHolderRewardsPool>;
@@ -136,6 +171,20 @@ export type DepositInput<
   owner: TransactionSigner<TAccountOwner>;
   /** token program */
   tokenProgram?: Address<TAccountTokenProgram>;
+  /** Rate history account, if the pool records one. */
+  rateHistory?: Address<TAccountRateHistory>;
+  /** Stake checkpoints account, required if the holder records one. */
+  stakeCheckpoints?: Address<TAccountStakeCheckpoints>;
+  /** Owner's access entry account, required if the pool has an access list. */
+  accessEntry?: Address<TAccountAccessEntry>;
+  /** Receipt token mint, required if the pool mints receipt tokens. */
+  receiptMint?: Address<TAccountReceiptMint>;
+  /** Owner's receipt token account, required if the pool mints receipt tokens. */
+  receiptTokenAccount?: Address<TAccountReceiptTokenAccount>;
+  /** Token-2022 program, required if the pool mints receipt tokens. */
+  receiptTokenProgram?: Address<TAccountReceiptTokenProgram>;
+  /** Epoch ledger account, if the pool records one. */
+  epochLedger?: Address<TAccountEpochLedger>;
   amount: DepositInstructionDataArgs['amount'];
 };
 
@@ -147,6 +196,13 @@ export function getDepositInstruction<
   TAccountMint extends string,
   TAccountOwner extends string,
   TAccountTokenProgram extends string,
+  TAccountRateHistory extends string,
+  TAccountStakeCheckpoints extends string,
+  TAccountAccessEntry extends string,
+  TAccountReceiptMint extends string,
+  TAccountReceiptTokenAccount extends string,
+  TAccountReceiptTokenProgram extends string,
+  TAccountEpochLedger extends string,
   TProgramAddress extends Address = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
 >(
   input: DepositInput<
@@ -156,7 +212,14 @@ export function getDepositInstruction<
     TAccountTokenAccount,
     TAccountMint,
     TAccountOwner,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountRateHistory,
+    TAccountStakeCheckpoints,
+    TAccountAccessEntry,
+    TAccountReceiptMint,
+    TAccountReceiptTokenAccount,
+    TAccountReceiptTokenProgram,
+    TAccountEpochLedger
   >,
   config?: { programAddress?: TProgramAddress }
 ): DepositInstruction<
@@ -167,7 +230,14 @@ export function getDepositInstruction<
   TAccountTokenAccount,
   TAccountMint,
   TAccountOwner,
-  TAccountTokenProgram
+  TAccountTokenProgram,
+  TAccountRateHistory,
+  TAccountStakeCheckpoints,
+  TAccountAccessEntry,
+  TAccountReceiptMint,
+  TAccountReceiptTokenAccount,
+  TAccountReceiptTokenProgram,
+  TAccountEpochLedger
 > {
   // Program address.
   const programAddress =
@@ -188,6 +258,22 @@ export function getDepositInstruction<
     mint: { value: input.mint ?? null, isWritable: false },
     owner: { value: input.owner ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    rateHistory: { value: input.rateHistory ?? null, isWritable: true },
+    stakeCheckpoints: {
+      value: input.stakeCheckpoints ?? null,
+      isWritable: true,
+    },
+    accessEntry: { value: input.accessEntry ?? null, isWritable: false },
+    receiptMint: { value: input.receiptMint ?? null, isWritable: true },
+    receiptTokenAccount: {
+      value: input.receiptTokenAccount ?? null,
+      isWritable: true,
+    },
+    receiptTokenProgram: {
+      value: input.receiptTokenProgram ?? null,
+      isWritable: false,
+    },
+    epochLedger: { value: input.epochLedger ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -213,6 +299,13 @@ export function getDepositInstruction<
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.rateHistory),
+      getAccountMeta(accounts.stakeCheckpoints),
+      getAccountMeta(accounts.accessEntry),
+      getAccountMeta(accounts.receiptMint),
+      getAccountMeta(accounts.receiptTokenAccount),
+      getAccountMeta(accounts.receiptTokenProgram),
+      getAccountMeta(accounts.epochLedger),
     ],
     programAddress,
     data: getDepositInstructionDataEncoder().encode(
@@ -226,7 +319,14 @@ export function getDepositInstruction<
     TAccountTokenAccount,
     TAccountMint,
     TAccountOwner,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountRateHistory,
+    TAccountStakeCheckpoints,
+    TAccountAccessEntry,
+    TAccountReceiptMint,
+    TAccountReceiptTokenAccount,
+    TAccountReceiptTokenProgram,
+    TAccountEpochLedger
   >;
 
   return instruction;
@@ -252,6 +352,20 @@ export type ParsedDepositInstruction<
     owner: TAccountMetas[5];
     /** token program */
     tokenProgram: TAccountMetas[6];
+    /** Rate history account, if the pool records one. */
+    rateHistory: TAccountMetas[7];
+    /** Stake checkpoints account, required if the holder records one. */
+    stakeCheckpoints: TAccountMetas[8];
+    /** Owner's access entry account, required if the pool has an access list. */
+    accessEntry: TAccountMetas[9];
+    /** Receipt token mint, required if the pool mints receipt tokens. */
+    receiptMint: TAccountMetas[10];
+    /** Owner's receipt token account, required if the pool mints receipt tokens. */
+    receiptTokenAccount: TAccountMetas[11];
+    /** Token-2022 program, required if the pool mints receipt tokens. */
+    receiptTokenProgram: TAccountMetas[12];
+    /** Epoch ledger account, if the pool records one. */
+    epochLedger: TAccountMetas[13];
   };
   data: DepositInstructionData;
 };
@@ -264,7 +378,7 @@ export function parseDepositInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedDepositInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 14) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -284,6 +398,13 @@ export function parseDepositInstruction<
       mint: getNextAccount(),
       owner: getNextAccount(),
       tokenProgram: getNextAccount(),
+      rateHistory: getNextAccount(),
+      stakeCheckpoints: getNextAccount(),
+      accessEntry: getNextAccount(),
+      receiptMint: getNextAccount(),
+      receiptTokenAccount: getNextAccount(),
+      receiptTokenProgram: getNextAccount(),
+      epochLedger: getNextAccount(),
     },
     data: getDepositInstructionDataDecoder().decode(instruction.data),
   };
@@ -0,0 +1,284 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
+  getBytesDecoder,
+  getBytesEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlyUint8Array,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { PALADIN_REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const DISTRIBUTE_REWARDS_DISCRIMINATOR = 29;
+
+export function getDistributeRewardsDiscriminatorBytes() {
+  return getU8Encoder().encode(DISTRIBUTE_REWARDS_DISCRIMINATOR);
+}
+
+export type DistributeRewardsInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountFunder extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountRateHistory extends string | IAccountMeta<string> = string,
+  TAccountEpochLedger extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewardsPool extends string
+        ? WritableAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountFunder extends string
+        ? WritableSignerAccount<TAccountFunder> &
+            IAccountSignerMeta<TAccountFunder>
+        : TAccountFunder,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      TAccountRateHistory extends string
+        ? WritableAccount<TAccountRateHistory>
+        : TAccountRateHistory,
+      TAccountEpochLedger extends string
+        ? WritableAccount<TAccountEpochLedger>
+        : TAccountEpochLedger,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type DistributeRewardsInstructionData = {
+  discriminator: number;
+  amount: bigint;
+  memo: ReadonlyUint8Array;
+};
+
+export type DistributeRewardsInstructionDataArgs = {
+  amount: number | bigint;
+  memo: ReadonlyUint8Array;
+};
+
+export function getDistributeRewardsInstructionDataEncoder(): Encoder<DistributeRewardsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['amount', getU64Encoder()],
+      ['memo', fixEncoderSize(getBytesEncoder(), 32)],
+    ]),
+    (value) => ({ ...value, discriminator: DISTRIBUTE_REWARDS_DISCRIMINATOR })
+  );
+}
+
+export function getDistributeRewardsInstructionDataDecoder(): Decoder<DistributeRewardsInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['amount', getU64Decoder()],
+    ['memo', fixDecoderSize(getBytesDecoder(), 32)],
+  ]);
+}
+
+export function getDistributeRewardsInstructionDataCodec(): Codec<
+  DistributeRewardsInstructionDataArgs,
+  DistributeRewardsInstructionData
+> {
+  return combineCodec(
+    getDistributeRewardsInstructionDataEncoder(),
+    getDistributeRewardsInstructionDataDecoder()
+  );
+}
+
+export type DistributeRewardsInput<
+  TAccountHolderRewardsPool extends string = string,
+  TAccountMint extends string = string,
+  TAccountFunder extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountRateHistory extends string = string,
+  TAccountEpochLedger extends string = string,
+> = {
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Funder of the rewards. */
+  funder: TransactionSigner<TAccountFunder>;
+  /** System program. */
+  systemProgram?: Address<TAccountSystemProgram>;
+  /** Rate history account, if the pool records one. */
+  rateHistory?: Address<TAccountRateHistory>;
+  /** Epoch ledger account, if the pool records one. */
+  epochLedger?: Address<TAccountEpochLedger>;
+  amount: DistributeRewardsInstructionDataArgs['amount'];
+  memo: DistributeRewardsInstructionDataArgs['memo'];
+};
+
+export function getDistributeRewardsInstruction<
+  TAccountHolderRewardsPool extends string,
+  TAccountMint extends string,
+  TAccountFunder extends string,
+  TAccountSystemProgram extends string,
+  TAccountRateHistory extends string,
+  TAccountEpochLedger extends string,
+  TProgramAddress extends Address = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+>(
+  input: DistributeRewardsInput<
+    TAccountHolderRewardsPool,
+    TAccountMint,
+    TAccountFunder,
+    TAccountSystemProgram,
+    TAccountRateHistory,
+    TAccountEpochLedger
+  >,
+  config?: { programAddress?: TProgramAddress }
+): DistributeRewardsInstruction<
+  TProgramAddress,
+  TAccountHolderRewardsPool,
+  TAccountMint,
+  TAccountFunder,
+  TAccountSystemProgram,
+  TAccountRateHistory,
+  TAccountEpochLedger
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? PALADIN_REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: true,
+    },
+    mint: { value: input.mint ?? null, isWritable: false },
+    funder: { value: input.funder ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    rateHistory: { value: input.rateHistory ?? null, isWritable: true },
+    epochLedger: { value: input.epochLedger ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.funder),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.rateHistory),
+      getAccountMeta(accounts.epochLedger),
+    ],
+    programAddress,
+    data: getDistributeRewardsInstructionDataEncoder().encode(
+      args as DistributeRewardsInstructionDataArgs
+    ),
+  } as DistributeRewardsInstruction<
+    TProgramAddress,
+    TAccountHolderRewardsPool,
+    TAccountMint,
+    TAccountFunder,
+    TAccountSystemProgram,
+    TAccountRateHistory,
+    TAccountEpochLedger
+  >;
+
+  return instruction;
+}
+
+export type ParsedDistributeRewardsInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[0];
+    /** Token mint. */
+    mint: TAccountMetas[1];
+    /** Funder of the rewards. */
+    funder: TAccountMetas[2];
+    /** System program. */
+    systemProgram: TAccountMetas[3];
+    /** Rate history account, if the pool records one. */
+    rateHistory: TAccountMetas[4];
+    /** Epoch ledger account, if the pool records one. */
+    epochLedger: TAccountMetas[5];
+  };
+  data: DistributeRewardsInstructionData;
+};
+
+export function parseDistributeRewardsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedDistributeRewardsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 6) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewardsPool: getNextAccount(),
+      mint: getNextAccount(),
+      funder: getNextAccount(),
+      systemProgram: getNextAccount(),
+      rateHistory: getNextAccount(),
+      epochLedger: getNextAccount(),
+    },
+    data: getDistributeRewardsInstructionDataDecoder().decode(instruction.data),
+  };
+}
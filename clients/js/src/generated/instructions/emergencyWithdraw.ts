@@ -0,0 +1,398 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { PALADIN_REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const EMERGENCY_WITHDRAW_DISCRIMINATOR = 23;
+
+export function getEmergencyWithdrawDiscriminatorBytes() {
+  return getU8Encoder().encode(EMERGENCY_WITHDRAW_DISCRIMINATOR);
+}
+
+export type EmergencyWithdrawInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountHolderRewardsPoolTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountHolderRewards extends string | IAccountMeta<string> = string,
+  TAccountTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountOwner extends string | IAccountMeta<string> = string,
+  TAccountTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountRateHistory extends string | IAccountMeta<string> = string,
+  TAccountStakeCheckpoints extends string | IAccountMeta<string> = string,
+  TAccountReceiptMint extends string | IAccountMeta<string> = string,
+  TAccountReceiptTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountReceiptTokenProgram extends string | IAccountMeta<string> = string,
+  TAccountEpochLedger extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewardsPool extends string
+        ? WritableAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountHolderRewardsPoolTokenAccount extends string
+        ? WritableAccount<TAccountHolderRewardsPoolTokenAccount>
+        : TAccountHolderRewardsPoolTokenAccount,
+      TAccountHolderRewards extends string
+        ? WritableAccount<TAccountHolderRewards>
+        : TAccountHolderRewards,
+      TAccountTokenAccount extends string
+        ? WritableAccount<TAccountTokenAccount>
+        : TAccountTokenAccount,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountOwner extends string
+        ? WritableSignerAccount<TAccountOwner> &
+            IAccountSignerMeta<TAccountOwner>
+        : TAccountOwner,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      TAccountRateHistory extends string
+        ? WritableAccount<TAccountRateHistory>
+        : TAccountRateHistory,
+      TAccountStakeCheckpoints extends string
+        ? WritableAccount<TAccountStakeCheckpoints>
+        : TAccountStakeCheckpoints,
+      TAccountReceiptMint extends string
+        ? WritableAccount<TAccountReceiptMint>
+        : TAccountReceiptMint,
+      TAccountReceiptTokenAccount extends string
+        ? WritableAccount<TAccountReceiptTokenAccount>
+        : TAccountReceiptTokenAccount,
+      TAccountReceiptTokenProgram extends string
+        ? ReadonlyAccount<TAccountReceiptTokenProgram>
+        : TAccountReceiptTokenProgram,
+      TAccountEpochLedger extends string
+        ? WritableAccount<TAccountEpochLedger>
+        : TAccountEpochLedger,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type EmergencyWithdrawInstructionData = {
+  discriminator: number;
+  amount: bigint;
+};
+
+export type EmergencyWithdrawInstructionDataArgs = { amount: number | bigint };
+
+export function getEmergencyWithdrawInstructionDataEncoder(): Encoder<EmergencyWithdrawInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['amount', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: EMERGENCY_WITHDRAW_DISCRIMINATOR })
+  );
+}
+
+export function getEmergencyWithdrawInstructionDataDecoder(): Decoder<EmergencyWithdrawInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['amount', getU64Decoder()],
+  ]);
+}
+
+export function getEmergencyWithdrawInstructionDataCodec(): Codec<
+  EmergencyWithdrawInstructionDataArgs,
+  EmergencyWithdrawInstructionData
+> {
+  return combineCodec(
+    getEmergencyWithdrawInstructionDataEncoder(),
+    getEmergencyWithdrawInstructionDataDecoder()
+  );
+}
+
+export type EmergencyWithdrawInput<
+  TAccountHolderRewardsPool extends string = string,
+  TAccountHolderRewardsPoolTokenAccount extends string = string,
+  TAccountHolderRewards extends string = string,
+  TAccountTokenAccount extends string = string,
+  TAccountMint extends string = string,
+  TAccountOwner extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountRateHistory extends string = string,
+  TAccountStakeCheckpoints extends string = string,
+  TAccountReceiptMint extends string = string,
+  TAccountReceiptTokenAccount extends string = string,
+  TAccountReceiptTokenProgram extends string = string,
+  TAccountEpochLedger extends string = string,
+> = {
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Holder rewards pool token account. */
+  holderRewardsPoolTokenAccount: Address<TAccountHolderRewardsPoolTokenAccount>;
+  /** Holder rewards account. */
+  holderRewards: Address<TAccountHolderRewards>;
+  /** Token account. */
+  tokenAccount: Address<TAccountTokenAccount>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Owner of the account. */
+  owner: TransactionSigner<TAccountOwner>;
+  /** token program */
+  tokenProgram?: Address<TAccountTokenProgram>;
+  /** Rate history account, if the pool records one. */
+  rateHistory?: Address<TAccountRateHistory>;
+  /** Stake checkpoints account, required if the holder records one. */
+  stakeCheckpoints?: Address<TAccountStakeCheckpoints>;
+  /** Receipt token mint, required if the pool mints receipt tokens. */
+  receiptMint?: Address<TAccountReceiptMint>;
+  /** Owner's receipt token account, required if the pool mints receipt tokens. */
+  receiptTokenAccount?: Address<TAccountReceiptTokenAccount>;
+  /** Token-2022 program, required if the pool mints receipt tokens. */
+  receiptTokenProgram?: Address<TAccountReceiptTokenProgram>;
+  /** Epoch ledger account, if the pool records one. */
+  epochLedger?: Address<TAccountEpochLedger>;
+  amount: EmergencyWithdrawInstructionDataArgs['amount'];
+};
+
+export function getEmergencyWithdrawInstruction<
+  TAccountHolderRewardsPool extends string,
+  TAccountHolderRewardsPoolTokenAccount extends string,
+  TAccountHolderRewards extends string,
+  TAccountTokenAccount extends string,
+  TAccountMint extends string,
+  TAccountOwner extends string,
+  TAccountTokenProgram extends string,
+  TAccountRateHistory extends string,
+  TAccountStakeCheckpoints extends string,
+  TAccountReceiptMint extends string,
+  TAccountReceiptTokenAccount extends string,
+  TAccountReceiptTokenProgram extends string,
+  TAccountEpochLedger extends string,
+  TProgramAddress extends Address = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+>(
+  input: EmergencyWithdrawInput<
+    TAccountHolderRewardsPool,
+    TAccountHolderRewardsPoolTokenAccount,
+    TAccountHolderRewards,
+    TAccountTokenAccount,
+    TAccountMint,
+    TAccountOwner,
+    TAccountTokenProgram,
+    TAccountRateHistory,
+    TAccountStakeCheckpoints,
+    TAccountReceiptMint,
+    TAccountReceiptTokenAccount,
+    TAccountReceiptTokenProgram,
+    TAccountEpochLedger
+  >,
+  config?: { programAddress?: TProgramAddress }
+): EmergencyWithdrawInstruction<
+  TProgramAddress,
+  TAccountHolderRewardsPool,
+  TAccountHolderRewardsPoolTokenAccount,
+  TAccountHolderRewards,
+  TAccountTokenAccount,
+  TAccountMint,
+  TAccountOwner,
+  TAccountTokenProgram,
+  TAccountRateHistory,
+  TAccountStakeCheckpoints,
+  TAccountReceiptMint,
+  TAccountReceiptTokenAccount,
+  TAccountReceiptTokenProgram,
+  TAccountEpochLedger
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? PALADIN_REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: true,
+    },
+    holderRewardsPoolTokenAccount: {
+      value: input.holderRewardsPoolTokenAccount ?? null,
+      isWritable: true,
+    },
+    holderRewards: { value: input.holderRewards ?? null, isWritable: true },
+    tokenAccount: { value: input.tokenAccount ?? null, isWritable: true },
+    mint: { value: input.mint ?? null, isWritable: false },
+    owner: { value: input.owner ?? null, isWritable: true },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    rateHistory: { value: input.rateHistory ?? null, isWritable: true },
+    stakeCheckpoints: {
+      value: input.stakeCheckpoints ?? null,
+      isWritable: true,
+    },
+    receiptMint: { value: input.receiptMint ?? null, isWritable: true },
+    receiptTokenAccount: {
+      value: input.receiptTokenAccount ?? null,
+      isWritable: true,
+    },
+    receiptTokenProgram: {
+      value: input.receiptTokenProgram ?? null,
+      isWritable: false,
+    },
+    epochLedger: { value: input.epochLedger ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.holderRewardsPoolTokenAccount),
+      getAccountMeta(accounts.holderRewards),
+      getAccountMeta(accounts.tokenAccount),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.owner),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.rateHistory),
+      getAccountMeta(accounts.stakeCheckpoints),
+      getAccountMeta(accounts.receiptMint),
+      getAccountMeta(accounts.receiptTokenAccount),
+      getAccountMeta(accounts.receiptTokenProgram),
+      getAccountMeta(accounts.epochLedger),
+    ],
+    programAddress,
+    data: getEmergencyWithdrawInstructionDataEncoder().encode(
+      args as EmergencyWithdrawInstructionDataArgs
+    ),
+  } as EmergencyWithdrawInstruction<
+    TProgramAddress,
+    TAccountHolderRewardsPool,
+    TAccountHolderRewardsPoolTokenAccount,
+    TAccountHolderRewards,
+    TAccountTokenAccount,
+    TAccountMint,
+    TAccountOwner,
+    TAccountTokenProgram,
+    TAccountRateHistory,
+    TAccountStakeCheckpoints,
+    TAccountReceiptMint,
+    TAccountReceiptTokenAccount,
+    TAccountReceiptTokenProgram,
+    TAccountEpochLedger
+  >;
+
+  return instruction;
+}
+
+export type ParsedEmergencyWithdrawInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[0];
+    /** Holder rewards pool token account. */
+    holderRewardsPoolTokenAccount: TAccountMetas[1];
+    /** Holder rewards account. */
+    holderRewards: TAccountMetas[2];
+    /** Token account. */
+    tokenAccount: TAccountMetas[3];
+    /** Token mint. */
+    mint: TAccountMetas[4];
+    /** Owner of the account. */
+    owner: TAccountMetas[5];
+    /** token program */
+    tokenProgram: TAccountMetas[6];
+    /** Rate history account, if the pool records one. */
+    rateHistory: TAccountMetas[7];
+    /** Stake checkpoints account, required if the holder records one. */
+    stakeCheckpoints: TAccountMetas[8];
+    /** Receipt token mint, required if the pool mints receipt tokens. */
+    receiptMint: TAccountMetas[9];
+    /** Owner's receipt token account, required if the pool mints receipt tokens. */
+    receiptTokenAccount: TAccountMetas[10];
+    /** Token-2022 program, required if the pool mints receipt tokens. */
+    receiptTokenProgram: TAccountMetas[11];
+    /** Epoch ledger account, if the pool records one. */
+    epochLedger: TAccountMetas[12];
+  };
+  data: EmergencyWithdrawInstructionData;
+};
+
+export function parseEmergencyWithdrawInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedEmergencyWithdrawInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 13) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewardsPool: getNextAccount(),
+      holderRewardsPoolTokenAccount: getNextAccount(),
+      holderRewards: getNextAccount(),
+      tokenAccount: getNextAccount(),
+      mint: getNextAccount(),
+      owner: getNextAccount(),
+      tokenProgram: getNextAccount(),
+      rateHistory: getNextAccount(),
+      stakeCheckpoints: getNextAccount(),
+      receiptMint: getNextAccount(),
+      receiptTokenAccount: getNextAccount(),
+      receiptTokenProgram: getNextAccount(),
+      epochLedger: getNextAccount(),
+    },
+    data: getEmergencyWithdrawInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -0,0 +1,210 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+} from '@solana/web3.js';
+import { PALADIN_REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const GET_PENDING_REWARDS_DISCRIMINATOR = 7;
+
+export function getGetPendingRewardsDiscriminatorBytes() {
+  return getU8Encoder().encode(GET_PENDING_REWARDS_DISCRIMINATOR);
+}
+
+export type GetPendingRewardsInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountHolderRewards extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountOwner extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewardsPool extends string
+        ? ReadonlyAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountHolderRewards extends string
+        ? ReadonlyAccount<TAccountHolderRewards>
+        : TAccountHolderRewards,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountOwner extends string
+        ? ReadonlyAccount<TAccountOwner>
+        : TAccountOwner,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type GetPendingRewardsInstructionData = { discriminator: number };
+
+export type GetPendingRewardsInstructionDataArgs = {};
+
+export function getGetPendingRewardsInstructionDataEncoder(): Encoder<GetPendingRewardsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: GET_PENDING_REWARDS_DISCRIMINATOR })
+  );
+}
+
+export function getGetPendingRewardsInstructionDataDecoder(): Decoder<GetPendingRewardsInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getGetPendingRewardsInstructionDataCodec(): Codec<
+  GetPendingRewardsInstructionDataArgs,
+  GetPendingRewardsInstructionData
+> {
+  return combineCodec(
+    getGetPendingRewardsInstructionDataEncoder(),
+    getGetPendingRewardsInstructionDataDecoder()
+  );
+}
+
+export type GetPendingRewardsInput<
+  TAccountHolderRewardsPool extends string = string,
+  TAccountHolderRewards extends string = string,
+  TAccountMint extends string = string,
+  TAccountOwner extends string = string,
+> = {
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Holder rewards account. */
+  holderRewards: Address<TAccountHolderRewards>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Owner of the holder rewards account. */
+  owner: Address<TAccountOwner>;
+};
+
+export function getGetPendingRewardsInstruction<
+  TAccountHolderRewardsPool extends string,
+  TAccountHolderRewards extends string,
+  TAccountMint extends string,
+  TAccountOwner extends string,
+  TProgramAddress extends Address = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+>(
+  input: GetPendingRewardsInput<
+    TAccountHolderRewardsPool,
+    TAccountHolderRewards,
+    TAccountMint,
+    TAccountOwner
+  >,
+  config?: { programAddress?: TProgramAddress }
+): GetPendingRewardsInstruction<
+  TProgramAddress,
+  TAccountHolderRewardsPool,
+  TAccountHolderRewards,
+  TAccountMint,
+  TAccountOwner
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? PALADIN_REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: false,
+    },
+    holderRewards: { value: input.holderRewards ?? null, isWritable: false },
+    mint: { value: input.mint ?? null, isWritable: false },
+    owner: { value: input.owner ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.holderRewards),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.owner),
+    ],
+    programAddress,
+    data: getGetPendingRewardsInstructionDataEncoder().encode({}),
+  } as GetPendingRewardsInstruction<
+    TProgramAddress,
+    TAccountHolderRewardsPool,
+    TAccountHolderRewards,
+    TAccountMint,
+    TAccountOwner
+  >;
+
+  return instruction;
+}
+
+export type ParsedGetPendingRewardsInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[0];
+    /** Holder rewards account. */
+    holderRewards: TAccountMetas[1];
+    /** Token mint. */
+    mint: TAccountMetas[2];
+    /** Owner of the holder rewards account. */
+    owner: TAccountMetas[3];
+  };
+  data: GetPendingRewardsInstructionData;
+};
+
+export function parseGetPendingRewardsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedGetPendingRewardsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewardsPool: getNextAccount(),
+      holderRewards: getNextAccount(),
+      mint: getNextAccount(),
+      owner: getNextAccount(),
+    },
+    data: getGetPendingRewardsInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -0,0 +1,192 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+} from '@solana/web3.js';
+import { PALADIN_REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const GET_STAKE_AT_DISCRIMINATOR = 17;
+
+export function getGetStakeAtDiscriminatorBytes() {
+  return getU8Encoder().encode(GET_STAKE_AT_DISCRIMINATOR);
+}
+
+export type GetStakeAtInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountStakeCheckpoints extends string | IAccountMeta<string> = string,
+  TAccountOwner extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountStakeCheckpoints extends string
+        ? ReadonlyAccount<TAccountStakeCheckpoints>
+        : TAccountStakeCheckpoints,
+      TAccountOwner extends string
+        ? ReadonlyAccount<TAccountOwner>
+        : TAccountOwner,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type GetStakeAtInstructionData = { discriminator: number; slot: bigint };
+
+export type GetStakeAtInstructionDataArgs = { slot: number | bigint };
+
+export function getGetStakeAtInstructionDataEncoder(): Encoder<GetStakeAtInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['slot', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: GET_STAKE_AT_DISCRIMINATOR })
+  );
+}
+
+export function getGetStakeAtInstructionDataDecoder(): Decoder<GetStakeAtInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['slot', getU64Decoder()],
+  ]);
+}
+
+export function getGetStakeAtInstructionDataCodec(): Codec<
+  GetStakeAtInstructionDataArgs,
+  GetStakeAtInstructionData
+> {
+  return combineCodec(
+    getGetStakeAtInstructionDataEncoder(),
+    getGetStakeAtInstructionDataDecoder()
+  );
+}
+
+export type GetStakeAtInput<
+  TAccountStakeCheckpoints extends string = string,
+  TAccountOwner extends string = string,
+> = {
+  /** Stake checkpoints account. */
+  stakeCheckpoints: Address<TAccountStakeCheckpoints>;
+  /** Owner of the stake checkpoints. */
+  owner: Address<TAccountOwner>;
+  slot: GetStakeAtInstructionDataArgs['slot'];
+};
+
+export function getGetStakeAtInstruction<
+  TAccountStakeCheckpoints extends string,
+  TAccountOwner extends string,
+  TProgramAddress extends Address = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+>(
+  input: GetStakeAtInput<
+    TAccountStakeCheckpoints,
+    TAccountOwner
+  >,
+  config?: { programAddress?: TProgramAddress }
+): GetStakeAtInstruction<
+  TProgramAddress,
+  TAccountStakeCheckpoints,
+  TAccountOwner
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? PALADIN_REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    stakeCheckpoints: {
+      value: input.stakeCheckpoints ?? null,
+      isWritable: false,
+    },
+    owner: { value: input.owner ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.stakeCheckpoints),
+      getAccountMeta(accounts.owner),
+    ],
+    programAddress,
+    data: getGetStakeAtInstructionDataEncoder().encode(
+      args as GetStakeAtInstructionDataArgs
+    ),
+  } as GetStakeAtInstruction<
+    TProgramAddress,
+    TAccountStakeCheckpoints,
+    TAccountOwner
+  >;
+
+  return instruction;
+}
+
+export type ParsedGetStakeAtInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Stake checkpoints account. */
+    stakeCheckpoints: TAccountMetas[0];
+    /** Owner of the stake checkpoints. */
+    owner: TAccountMetas[1];
+  };
+  data: GetStakeAtInstructionData;
+};
+
+export function parseGetStakeAtInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedGetStakeAtInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      stakeCheckpoints: getNextAccount(),
+      owner: getNextAccount(),
+    },
+    data: getGetStakeAtInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -0,0 +1,226 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+} from '@solana/web3.js';
+import { PALADIN_REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const GET_VOTING_POWER_DISCRIMINATOR = 15;
+
+export function getGetVotingPowerDiscriminatorBytes() {
+  return getU8Encoder().encode(GET_VOTING_POWER_DISCRIMINATOR);
+}
+
+export type GetVotingPowerInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountVoteEscrow extends string | IAccountMeta<string> = string,
+  TAccountHolderRewards extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountOwner extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewardsPool extends string
+        ? ReadonlyAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountVoteEscrow extends string
+        ? ReadonlyAccount<TAccountVoteEscrow>
+        : TAccountVoteEscrow,
+      TAccountHolderRewards extends string
+        ? ReadonlyAccount<TAccountHolderRewards>
+        : TAccountHolderRewards,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountOwner extends string
+        ? ReadonlyAccount<TAccountOwner>
+        : TAccountOwner,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type GetVotingPowerInstructionData = { discriminator: number };
+
+export type GetVotingPowerInstructionDataArgs = {};
+
+export function getGetVotingPowerInstructionDataEncoder(): Encoder<GetVotingPowerInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: GET_VOTING_POWER_DISCRIMINATOR })
+  );
+}
+
+export function getGetVotingPowerInstructionDataDecoder(): Decoder<GetVotingPowerInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getGetVotingPowerInstructionDataCodec(): Codec<
+  GetVotingPowerInstructionDataArgs,
+  GetVotingPowerInstructionData
+> {
+  return combineCodec(
+    getGetVotingPowerInstructionDataEncoder(),
+    getGetVotingPowerInstructionDataDecoder()
+  );
+}
+
+export type GetVotingPowerInput<
+  TAccountHolderRewardsPool extends string = string,
+  TAccountVoteEscrow extends string = string,
+  TAccountHolderRewards extends string = string,
+  TAccountMint extends string = string,
+  TAccountOwner extends string = string,
+> = {
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Vote escrow account. */
+  voteEscrow: Address<TAccountVoteEscrow>;
+  /** Holder rewards account. */
+  holderRewards: Address<TAccountHolderRewards>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Owner of the holder rewards account. */
+  owner: Address<TAccountOwner>;
+};
+
+export function getGetVotingPowerInstruction<
+  TAccountHolderRewardsPool extends string,
+  TAccountVoteEscrow extends string,
+  TAccountHolderRewards extends string,
+  TAccountMint extends string,
+  TAccountOwner extends string,
+  TProgramAddress extends Address = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+>(
+  input: GetVotingPowerInput<
+    TAccountHolderRewardsPool,
+    TAccountVoteEscrow,
+    TAccountHolderRewards,
+    TAccountMint,
+    TAccountOwner
+  >,
+  config?: { programAddress?: TProgramAddress }
+): GetVotingPowerInstruction<
+  TProgramAddress,
+  TAccountHolderRewardsPool,
+  TAccountVoteEscrow,
+  TAccountHolderRewards,
+  TAccountMint,
+  TAccountOwner
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? PALADIN_REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: false,
+    },
+    voteEscrow: { value: input.voteEscrow ?? null, isWritable: false },
+    holderRewards: { value: input.holderRewards ?? null, isWritable: false },
+    mint: { value: input.mint ?? null, isWritable: false },
+    owner: { value: input.owner ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.voteEscrow),
+      getAccountMeta(accounts.holderRewards),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.owner),
+    ],
+    programAddress,
+    data: getGetVotingPowerInstructionDataEncoder().encode({}),
+  } as GetVotingPowerInstruction<
+    TProgramAddress,
+    TAccountHolderRewardsPool,
+    TAccountVoteEscrow,
+    TAccountHolderRewards,
+    TAccountMint,
+    TAccountOwner
+  >;
+
+  return instruction;
+}
+
+export type ParsedGetVotingPowerInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[0];
+    /** Vote escrow account. */
+    voteEscrow: TAccountMetas[1];
+    /** Holder rewards account. */
+    holderRewards: TAccountMetas[2];
+    /** Token mint. */
+    mint: TAccountMetas[3];
+    /** Owner of the holder rewards account. */
+    owner: TAccountMetas[4];
+  };
+  data: GetVotingPowerInstructionData;
+};
+
+export function parseGetVotingPowerInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedGetVotingPowerInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 5) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewardsPool: getNextAccount(),
+      voteEscrow: getNextAccount(),
+      holderRewards: getNextAccount(),
+      mint: getNextAccount(),
+      owner: getNextAccount(),
+    },
+    data: getGetVotingPowerInstructionDataDecoder().decode(instruction.data),
+  };
+}
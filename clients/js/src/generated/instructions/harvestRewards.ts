@@ -45,6 +45,8 @@ export type HarvestRewardsInstruction<
   TAccountHolderRewards extends string | IAccountMeta<string> = string,
   TAccountMint extends string | IAccountMeta<string> = string,
   TAccountOwner extends string | IAccountMeta<string> = string,
+  TAccountRateHistory extends string | IAccountMeta<string> = string,
+  TAccountEpochLedger extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -66,6 +68,12 @@ export type HarvestRewardsInstruction<
         ? WritableSignerAccount<TAccountOwner> &
             IAccountSignerMeta<TAccountOwner>
         : TAccountOwner,
+      TAccountRateHistory extends string
+        ? WritableAccount<TAccountRateHistory>
+        : TAccountRateHistory,
+      TAccountEpochLedger extends string
+        ? WritableAccount<TAccountEpochLedger>
+        : TAccountEpochLedger,
       ...TRemainingAccounts,
     ]
   >;
@@ -101,6 +109,8 @@ export type HarvestRewardsInput<
   TAccountHolderRewards extends string = string,
   TAccountMint extends string = string,
   TAccountOwner extends string = string,
+  TAccountRateHistory extends string = string,
+  TAccountEpochLedger extends string = string,
 > = {
   /** Holder rewards pool account. */
   holderRewardsPool: Address<TAccountHolderRewardsPool>;
@@ -112,6 +122,10 @@ export type HarvestRewardsInput<
   mint: Address<TAccountMint>;
   /** owner of token account */
   owner: TransactionSigner<TAccountOwner>;
+  /** Rate history account, if the pool records one. */
+  rateHistory?: Address<TAccountRateHistory>;
+  /** Epoch ledger account, if the pool records one. */
+  epochLedger?: Address<TAccountEpochLedger>;
 };
 
 export function getHarvestRewardsInstruction<
@@ -120,6 +134,8 @@ export function getHarvestRewardsInstruction<
   TAccountHolderRewards extends string,
   TAccountMint extends string,
   TAccountOwner extends string,
+  TAccountRateHistory extends string,
+  TAccountEpochLedger extends string,
   TProgramAddress extends Address = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
 >(
   input: HarvestRewardsInput<
@@ -127,7 +143,9 @@ export function getHarvestRewardsInstruction<
     TAccountHolderRewardsPoolTokenAccount,
     TAccountHolderRewards,
     TAccountMint,
-    TAccountOwner
+    TAccountOwner,
+    TAccountRateHistory,
+    TAccountEpochLedger
   >,
   config?: { programAddress?: TProgramAddress }
 ): HarvestRewardsInstruction<
@@ -136,7 +154,9 @@ export function getHarvestRewardsInstruction<
   TAccountHolderRewardsPoolTokenAccount,
   TAccountHolderRewards,
   TAccountMint,
-  TAccountOwner
+  TAccountOwner,
+  TAccountRateHistory,
+  TAccountEpochLedger
 > {
   // Program address.
   const programAddress =
@@ -155,6 +175,8 @@ export function getHarvestRewardsInstruction<
     holderRewards: { value: input.holderRewards ?? null, isWritable: true },
     mint: { value: input.mint ?? null, isWritable: false },
     owner: { value: input.owner ?? null, isWritable: true },
+    rateHistory: { value: input.rateHistory ?? null, isWritable: true },
+    epochLedger: { value: input.epochLedger ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -169,6 +191,8 @@ export function getHarvestRewardsInstruction<
       getAccountMeta(accounts.holderRewards),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.owner),
+      getAccountMeta(accounts.rateHistory),
+      getAccountMeta(accounts.epochLedger),
     ],
     programAddress,
     data: getHarvestRewardsInstructionDataEncoder().encode({}),
@@ -178,7 +202,9 @@ export function getHarvestRewardsInstruction<
     TAccountHolderRewardsPoolTokenAccount,
     TAccountHolderRewards,
     TAccountMint,
-    TAccountOwner
+    TAccountOwner,
+    TAccountRateHistory,
+    TAccountEpochLedger
   >;
 
   return instruction;
@@ -200,6 +226,10 @@ export type ParsedHarvestRewardsInstruction<
     mint: TAccountMetas[3];
     /** owner of token account */
     owner: TAccountMetas[4];
+    /** Rate history account, if the pool records one. */
+    rateHistory: TAccountMetas[5];
+    /** Epoch ledger account, if the pool records one. */
+    epochLedger: TAccountMetas[6];
   };
   data: HarvestRewardsInstructionData;
 };
@@ -212,7 +242,7 @@ export function parseHarvestRewardsInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedHarvestRewardsInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -230,6 +260,8 @@ export function parseHarvestRewardsInstruction<
       holderRewards: getNextAccount(),
       mint: getNextAccount(),
       owner: getNextAccount(),
+      rateHistory: getNextAccount(),
+      epochLedger: getNextAccount(),
     },
     data: getHarvestRewardsInstructionDataDecoder().decode(instruction.data),
   };
@@ -6,9 +6,35 @@
  * @see https://github.com/kinobi-so/kinobi
  */
 
+export * from './checkpointVotingPower';
 export * from './closeHolderRewards';
+export * from './createCampaign';
 export * from './deposit';
+export * from './distributeRewards';
+export * from './emergencyWithdraw';
+export * from './getPendingRewards';
+export * from './getStakeAt';
+export * from './getVotingPower';
 export * from './harvestRewards';
+export * from './initializeEpochLedger';
 export * from './initializeHolderRewards';
 export * from './initializeHolderRewardsPool';
+export * from './initializeRateHistory';
+export * from './initializeReceiptMint';
+export * from './initializeStakeCheckpoints';
+export * from './initializeVoteEscrow';
+export * from './lock';
+export * from './migrateHolderRewards';
+export * from './migrateHolderRewardsPool';
+export * from './reclaimCampaignRewards';
+export * from './recoverExcessTokens';
+export * from './releaseCampaignRewards';
+export * from './releaseLockup';
+export * from './setAccessEntry';
+export * from './setAccessList';
+export * from './setDepositLimits';
+export * from './setEarlyWithdrawalPenalty';
+export * from './setLockupTiers';
+export * from './setSlashingConfig';
+export * from './slash';
 export * from './withdraw';
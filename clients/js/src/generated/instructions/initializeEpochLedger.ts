@@ -0,0 +1,244 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { PALADIN_REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const INITIALIZE_EPOCH_LEDGER_DISCRIMINATOR = 30;
+
+export function getInitializeEpochLedgerDiscriminatorBytes() {
+  return getU8Encoder().encode(INITIALIZE_EPOCH_LEDGER_DISCRIMINATOR);
+}
+
+export type InitializeEpochLedgerInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountEpochLedger extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewardsPool extends string
+        ? WritableAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountEpochLedger extends string
+        ? WritableAccount<TAccountEpochLedger>
+        : TAccountEpochLedger,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type InitializeEpochLedgerInstructionData = { discriminator: number };
+
+export type InitializeEpochLedgerInstructionDataArgs = {};
+
+export function getInitializeEpochLedgerInstructionDataEncoder(): Encoder<InitializeEpochLedgerInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: INITIALIZE_EPOCH_LEDGER_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getInitializeEpochLedgerInstructionDataDecoder(): Decoder<InitializeEpochLedgerInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getInitializeEpochLedgerInstructionDataCodec(): Codec<
+  InitializeEpochLedgerInstructionDataArgs,
+  InitializeEpochLedgerInstructionData
+> {
+  return combineCodec(
+    getInitializeEpochLedgerInstructionDataEncoder(),
+    getInitializeEpochLedgerInstructionDataDecoder()
+  );
+}
+
+export type InitializeEpochLedgerInput<
+  TAccountHolderRewardsPool extends string = string,
+  TAccountEpochLedger extends string = string,
+  TAccountMint extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Epoch ledger account. */
+  epochLedger: Address<TAccountEpochLedger>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Payer funding the rent for the epoch ledger account. */
+  payer: TransactionSigner<TAccountPayer>;
+  /** System program. */
+  systemProgram?: Address<TAccountSystemProgram>;
+};
+
+export function getInitializeEpochLedgerInstruction<
+  TAccountHolderRewardsPool extends string,
+  TAccountEpochLedger extends string,
+  TAccountMint extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+  TProgramAddress extends Address = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+>(
+  input: InitializeEpochLedgerInput<
+    TAccountHolderRewardsPool,
+    TAccountEpochLedger,
+    TAccountMint,
+    TAccountPayer,
+    TAccountSystemProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): InitializeEpochLedgerInstruction<
+  TProgramAddress,
+  TAccountHolderRewardsPool,
+  TAccountEpochLedger,
+  TAccountMint,
+  TAccountPayer,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? PALADIN_REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: true,
+    },
+    epochLedger: { value: input.epochLedger ?? null, isWritable: true },
+    mint: { value: input.mint ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.epochLedger),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getInitializeEpochLedgerInstructionDataEncoder().encode({}),
+  } as InitializeEpochLedgerInstruction<
+    TProgramAddress,
+    TAccountHolderRewardsPool,
+    TAccountEpochLedger,
+    TAccountMint,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedInitializeEpochLedgerInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[0];
+    /** Epoch ledger account. */
+    epochLedger: TAccountMetas[1];
+    /** Token mint. */
+    mint: TAccountMetas[2];
+    /** Payer funding the rent for the epoch ledger account. */
+    payer: TAccountMetas[3];
+    /** System program. */
+    systemProgram: TAccountMetas[4];
+  };
+  data: InitializeEpochLedgerInstructionData;
+};
+
+export function parseInitializeEpochLedgerInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedInitializeEpochLedgerInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 5) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewardsPool: getNextAccount(),
+      epochLedger: getNextAccount(),
+      mint: getNextAccount(),
+      payer: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getInitializeEpochLedgerInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
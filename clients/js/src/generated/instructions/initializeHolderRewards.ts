@@ -46,9 +46,13 @@ export type InitializeHolderRewardsInstruction<
   TAccountHolderRewards extends string | IAccountMeta<string> = string,
   TAccountMint extends string | IAccountMeta<string> = string,
   TAccountDunaDocumentPda extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountRateHistory extends string | IAccountMeta<string> = string,
+  TAccountAccessEntry extends string | IAccountMeta<string> = string,
+  TAccountEpochLedger extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -73,9 +77,22 @@ export type InitializeHolderRewardsInstruction<
       TAccountDunaDocumentPda extends string
         ? ReadonlyAccount<TAccountDunaDocumentPda>
         : TAccountDunaDocumentPda,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountRateHistory extends string
+        ? WritableAccount<TAccountRateHistory>
+        : TAccountRateHistory,
+      TAccountAccessEntry extends string
+        ? ReadonlyAccount<TAccountAccessEntry>
+        : TAccountAccessEntry,
+      TAccountEpochLedger extends string
+        ? WritableAccount<TAccountEpochLedger>
+        : TAccountEpochLedger,
       ...TRemainingAccounts,
     ]
   >;
@@ -115,7 +132,11 @@ export type InitializeHolderRewardsInput<
   TAccountHolderRewards extends string = string,
   TAccountMint extends string = string,
   TAccountDunaDocumentPda extends string = string,
+  TAccountPayer extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountRateHistory extends string = string,
+  TAccountAccessEntry extends string = string,
+  TAccountEpochLedger extends string = string,
 > = {
   /** Holder rewards pool account. */
   holderRewardsPool: Address<TAccountHolderRewardsPool>;
@@ -129,8 +150,16 @@ export type InitializeHolderRewardsInput<
   mint: Address<TAccountMint>;
   /** DUNA document PDA account */
   dunaDocumentPda: Address<TAccountDunaDocumentPda>;
+  /** Payer funding the rent for the holder rewards account. */
+  payer: TransactionSigner<TAccountPayer>;
   /** System program. */
   systemProgram?: Address<TAccountSystemProgram>;
+  /** Rate history account, if the pool records one. */
+  rateHistory?: Address<TAccountRateHistory>;
+  /** Owner's access entry account, required if the pool has an access list. */
+  accessEntry?: Address<TAccountAccessEntry>;
+  /** Epoch ledger account, if the pool records one. */
+  epochLedger?: Address<TAccountEpochLedger>;
 };
 
 export function getInitializeHolderRewardsInstruction<
@@ -140,7 +169,11 @@ export function getInitializeHolderRewardsInstruction<
   TAccountHolderRewards extends string,
   TAccountMint extends string,
   TAccountDunaDocumentPda extends string,
+  TAccountPayer extends string,
   TAccountSystemProgram extends string,
+  TAccountRateHistory extends string,
+  TAccountAccessEntry extends string,
+  TAccountEpochLedger extends string,
   TProgramAddress extends Address = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
 >(
   input: InitializeHolderRewardsInput<
@@ -150,7 +183,11 @@ export function getInitializeHolderRewardsInstruction<
     TAccountHolderRewards,
     TAccountMint,
     TAccountDunaDocumentPda,
-    TAccountSystemProgram
+    TAccountPayer,
+    TAccountSystemProgram,
+    TAccountRateHistory,
+    TAccountAccessEntry,
+    TAccountEpochLedger
   >,
   config?: { programAddress?: TProgramAddress }
 ): InitializeHolderRewardsInstruction<
@@ -161,7 +198,11 @@ export function getInitializeHolderRewardsInstruction<
   TAccountHolderRewards,
   TAccountMint,
   TAccountDunaDocumentPda,
-  TAccountSystemProgram
+  TAccountPayer,
+  TAccountSystemProgram,
+  TAccountRateHistory,
+  TAccountAccessEntry,
+  TAccountEpochLedger
 > {
   // Program address.
   const programAddress =
@@ -184,7 +225,11 @@ export function getInitializeHolderRewardsInstruction<
       value: input.dunaDocumentPda ?? null,
       isWritable: false,
     },
+    payer: { value: input.payer ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    rateHistory: { value: input.rateHistory ?? null, isWritable: true },
+    accessEntry: { value: input.accessEntry ?? null, isWritable: false },
+    epochLedger: { value: input.epochLedger ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -206,7 +251,11 @@ export function getInitializeHolderRewardsInstruction<
       getAccountMeta(accounts.holderRewards),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.dunaDocumentPda),
+      getAccountMeta(accounts.payer),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.rateHistory),
+      getAccountMeta(accounts.accessEntry),
+      getAccountMeta(accounts.epochLedger),
     ],
     programAddress,
     data: getInitializeHolderRewardsInstructionDataEncoder().encode({}),
@@ -218,7 +267,11 @@ export function getInitializeHolderRewardsInstruction<
     TAccountHolderRewards,
     TAccountMint,
     TAccountDunaDocumentPda,
-    TAccountSystemProgram
+    TAccountPayer,
+    TAccountSystemProgram,
+    TAccountRateHistory,
+    TAccountAccessEntry,
+    TAccountEpochLedger
   >;
 
   return instruction;
@@ -242,8 +295,16 @@ export type ParsedInitializeHolderRewardsInstruction<
     mint: TAccountMetas[4];
     /** DUNA document PDA account */
     dunaDocumentPda: TAccountMetas[5];
+    /** Payer funding the rent for the holder rewards account. */
+    payer: TAccountMetas[6];
     /** System program. */
-    systemProgram: TAccountMetas[6];
+    systemProgram: TAccountMetas[7];
+    /** Rate history account, if the pool records one. */
+    rateHistory: TAccountMetas[8];
+    /** Owner's access entry account, required if the pool has an access list. */
+    accessEntry: TAccountMetas[9];
+    /** Epoch ledger account, if the pool records one. */
+    epochLedger: TAccountMetas[10];
   };
   data: InitializeHolderRewardsInstructionData;
 };
@@ -256,7 +317,7 @@ export function parseInitializeHolderRewardsInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedInitializeHolderRewardsInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 11) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -275,7 +336,11 @@ export function parseInitializeHolderRewardsInstruction<
       holderRewards: getNextAccount(),
       mint: getNextAccount(),
       dunaDocumentPda: getNextAccount(),
+      payer: getNextAccount(),
       systemProgram: getNextAccount(),
+      rateHistory: getNextAccount(),
+      accessEntry: getNextAccount(),
+      epochLedger: getNextAccount(),
     },
     data: getInitializeHolderRewardsInstructionDataDecoder().decode(
       instruction.data
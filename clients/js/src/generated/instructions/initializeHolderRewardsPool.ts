@@ -22,12 +22,16 @@ import {
   type Decoder,
   type Encoder,
   type IAccountMeta,
+  type IAccountSignerMeta,
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
   type ReadonlyAccount,
+  type ReadonlySignerAccount,
   type ReadonlyUint8Array,
+  type TransactionSigner,
   type WritableAccount,
+  type WritableSignerAccount,
 } from '@solana/web3.js';
 import { PALADIN_REWARDS_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
@@ -47,6 +51,8 @@ export type InitializeHolderRewardsPoolInstruction<
   TAccountMint extends string | IAccountMeta<string> = string,
   TAccountStakeVaultPda extends string | IAccountMeta<string> = string,
   TAccountVaultHolderRewards extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
@@ -70,6 +76,14 @@ export type InitializeHolderRewardsPoolInstruction<
       TAccountVaultHolderRewards extends string
         ? WritableAccount<TAccountVaultHolderRewards>
         : TAccountVaultHolderRewards,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -122,6 +136,8 @@ export type InitializeHolderRewardsPoolInput<
   TAccountMint extends string = string,
   TAccountStakeVaultPda extends string = string,
   TAccountVaultHolderRewards extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountPayer extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   /** Holder rewards pool account. */
@@ -134,6 +150,10 @@ export type InitializeHolderRewardsPoolInput<
   stakeVaultPda: Address<TAccountStakeVaultPda>;
   /** Token mint. */
   vaultHolderRewards: Address<TAccountVaultHolderRewards>;
+  /** Pool authority, the mint's mint authority. */
+  authority: TransactionSigner<TAccountAuthority>;
+  /** Payer funding the rent for the new accounts. */
+  payer: TransactionSigner<TAccountPayer>;
   /** System program. */
   systemProgram?: Address<TAccountSystemProgram>;
   dunaDocumentHash: InitializeHolderRewardsPoolInstructionDataArgs['dunaDocumentHash'];
@@ -145,6 +165,8 @@ export function getInitializeHolderRewardsPoolInstruction<
   TAccountMint extends string,
   TAccountStakeVaultPda extends string,
   TAccountVaultHolderRewards extends string,
+  TAccountAuthority extends string,
+  TAccountPayer extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
 >(
@@ -154,6 +176,8 @@ export function getInitializeHolderRewardsPoolInstruction<
     TAccountMint,
     TAccountStakeVaultPda,
     TAccountVaultHolderRewards,
+    TAccountAuthority,
+    TAccountPayer,
     TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
@@ -164,6 +188,8 @@ export function getInitializeHolderRewardsPoolInstruction<
   TAccountMint,
   TAccountStakeVaultPda,
   TAccountVaultHolderRewards,
+  TAccountAuthority,
+  TAccountPayer,
   TAccountSystemProgram
 > {
   // Program address.
@@ -186,6 +212,8 @@ export function getInitializeHolderRewardsPoolInstruction<
       value: input.vaultHolderRewards ?? null,
       isWritable: true,
     },
+    authority: { value: input.authority ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -210,6 +238,8 @@ export function getInitializeHolderRewardsPoolInstruction<
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.stakeVaultPda),
       getAccountMeta(accounts.vaultHolderRewards),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.payer),
       getAccountMeta(accounts.systemProgram),
     ],
     programAddress,
@@ -223,6 +253,8 @@ export function getInitializeHolderRewardsPoolInstruction<
     TAccountMint,
     TAccountStakeVaultPda,
     TAccountVaultHolderRewards,
+    TAccountAuthority,
+    TAccountPayer,
     TAccountSystemProgram
   >;
 
@@ -245,8 +277,12 @@ export type ParsedInitializeHolderRewardsPoolInstruction<
     stakeVaultPda: TAccountMetas[3];
     /** Token mint. */
     vaultHolderRewards: TAccountMetas[4];
+    /** Pool authority, the mint's mint authority. */
+    authority: TAccountMetas[5];
+    /** Payer funding the rent for the new accounts. */
+    payer: TAccountMetas[6];
     /** System program. */
-    systemProgram: TAccountMetas[5];
+    systemProgram: TAccountMetas[7];
   };
   data: InitializeHolderRewardsPoolInstructionData;
 };
@@ -259,7 +295,7 @@ export function parseInitializeHolderRewardsPoolInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedInitializeHolderRewardsPoolInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -277,6 +313,8 @@ export function parseInitializeHolderRewardsPoolInstruction<
       mint: getNextAccount(),
       stakeVaultPda: getNextAccount(),
       vaultHolderRewards: getNextAccount(),
+      authority: getNextAccount(),
+      payer: getNextAccount(),
       systemProgram: getNextAccount(),
     },
     data: getInitializeHolderRewardsPoolInstructionDataDecoder().decode(
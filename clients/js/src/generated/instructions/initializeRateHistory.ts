@@ -0,0 +1,244 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { PALADIN_REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const INITIALIZE_RATE_HISTORY_DISCRIMINATOR = 9;
+
+export function getInitializeRateHistoryDiscriminatorBytes() {
+  return getU8Encoder().encode(INITIALIZE_RATE_HISTORY_DISCRIMINATOR);
+}
+
+export type InitializeRateHistoryInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountRateHistory extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewardsPool extends string
+        ? WritableAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountRateHistory extends string
+        ? WritableAccount<TAccountRateHistory>
+        : TAccountRateHistory,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type InitializeRateHistoryInstructionData = { discriminator: number };
+
+export type InitializeRateHistoryInstructionDataArgs = {};
+
+export function getInitializeRateHistoryInstructionDataEncoder(): Encoder<InitializeRateHistoryInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: INITIALIZE_RATE_HISTORY_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getInitializeRateHistoryInstructionDataDecoder(): Decoder<InitializeRateHistoryInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getInitializeRateHistoryInstructionDataCodec(): Codec<
+  InitializeRateHistoryInstructionDataArgs,
+  InitializeRateHistoryInstructionData
+> {
+  return combineCodec(
+    getInitializeRateHistoryInstructionDataEncoder(),
+    getInitializeRateHistoryInstructionDataDecoder()
+  );
+}
+
+export type InitializeRateHistoryInput<
+  TAccountHolderRewardsPool extends string = string,
+  TAccountRateHistory extends string = string,
+  TAccountMint extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Rate history account. */
+  rateHistory: Address<TAccountRateHistory>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Payer funding the rent for the rate history account. */
+  payer: TransactionSigner<TAccountPayer>;
+  /** System program. */
+  systemProgram?: Address<TAccountSystemProgram>;
+};
+
+export function getInitializeRateHistoryInstruction<
+  TAccountHolderRewardsPool extends string,
+  TAccountRateHistory extends string,
+  TAccountMint extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+  TProgramAddress extends Address = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+>(
+  input: InitializeRateHistoryInput<
+    TAccountHolderRewardsPool,
+    TAccountRateHistory,
+    TAccountMint,
+    TAccountPayer,
+    TAccountSystemProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): InitializeRateHistoryInstruction<
+  TProgramAddress,
+  TAccountHolderRewardsPool,
+  TAccountRateHistory,
+  TAccountMint,
+  TAccountPayer,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? PALADIN_REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: true,
+    },
+    rateHistory: { value: input.rateHistory ?? null, isWritable: true },
+    mint: { value: input.mint ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.rateHistory),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getInitializeRateHistoryInstructionDataEncoder().encode({}),
+  } as InitializeRateHistoryInstruction<
+    TProgramAddress,
+    TAccountHolderRewardsPool,
+    TAccountRateHistory,
+    TAccountMint,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedInitializeRateHistoryInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[0];
+    /** Rate history account. */
+    rateHistory: TAccountMetas[1];
+    /** Token mint. */
+    mint: TAccountMetas[2];
+    /** Payer funding the rent for the rate history account. */
+    payer: TAccountMetas[3];
+    /** System program. */
+    systemProgram: TAccountMetas[4];
+  };
+  data: InitializeRateHistoryInstructionData;
+};
+
+export function parseInitializeRateHistoryInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedInitializeRateHistoryInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 5) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewardsPool: getNextAccount(),
+      rateHistory: getNextAccount(),
+      mint: getNextAccount(),
+      payer: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getInitializeRateHistoryInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,300 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { PALADIN_REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const INITIALIZE_RECEIPT_MINT_DISCRIMINATOR = 22;
+
+export function getInitializeReceiptMintDiscriminatorBytes() {
+  return getU8Encoder().encode(INITIALIZE_RECEIPT_MINT_DISCRIMINATOR);
+}
+
+export type InitializeReceiptMintInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountReceiptMint extends string | IAccountMeta<string> = string,
+  TAccountExtraAccountMetas extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountReceiptTokenProgram extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewardsPool extends string
+        ? WritableAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountReceiptMint extends string
+        ? WritableAccount<TAccountReceiptMint>
+        : TAccountReceiptMint,
+      TAccountExtraAccountMetas extends string
+        ? WritableAccount<TAccountExtraAccountMetas>
+        : TAccountExtraAccountMetas,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      TAccountReceiptTokenProgram extends string
+        ? ReadonlyAccount<TAccountReceiptTokenProgram>
+        : TAccountReceiptTokenProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type InitializeReceiptMintInstructionData = { discriminator: number };
+
+export type InitializeReceiptMintInstructionDataArgs = {};
+
+export function getInitializeReceiptMintInstructionDataEncoder(): Encoder<InitializeReceiptMintInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: INITIALIZE_RECEIPT_MINT_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getInitializeReceiptMintInstructionDataDecoder(): Decoder<InitializeReceiptMintInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getInitializeReceiptMintInstructionDataCodec(): Codec<
+  InitializeReceiptMintInstructionDataArgs,
+  InitializeReceiptMintInstructionData
+> {
+  return combineCodec(
+    getInitializeReceiptMintInstructionDataEncoder(),
+    getInitializeReceiptMintInstructionDataDecoder()
+  );
+}
+
+export type InitializeReceiptMintInput<
+  TAccountHolderRewardsPool extends string = string,
+  TAccountReceiptMint extends string = string,
+  TAccountExtraAccountMetas extends string = string,
+  TAccountMint extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountReceiptTokenProgram extends string = string,
+> = {
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Receipt token mint. */
+  receiptMint: Address<TAccountReceiptMint>;
+  /** Transfer hook extra account metas account. */
+  extraAccountMetas: Address<TAccountExtraAccountMetas>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Pool authority. */
+  authority: TransactionSigner<TAccountAuthority>;
+  /** Payer funding the rent for the receipt mint and extra account metas accounts. */
+  payer: TransactionSigner<TAccountPayer>;
+  /** System program. */
+  systemProgram?: Address<TAccountSystemProgram>;
+  /** Token-2022 program. */
+  receiptTokenProgram: Address<TAccountReceiptTokenProgram>;
+};
+
+export function getInitializeReceiptMintInstruction<
+  TAccountHolderRewardsPool extends string,
+  TAccountReceiptMint extends string,
+  TAccountExtraAccountMetas extends string,
+  TAccountMint extends string,
+  TAccountAuthority extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+  TAccountReceiptTokenProgram extends string,
+  TProgramAddress extends Address = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+>(
+  input: InitializeReceiptMintInput<
+    TAccountHolderRewardsPool,
+    TAccountReceiptMint,
+    TAccountExtraAccountMetas,
+    TAccountMint,
+    TAccountAuthority,
+    TAccountPayer,
+    TAccountSystemProgram,
+    TAccountReceiptTokenProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): InitializeReceiptMintInstruction<
+  TProgramAddress,
+  TAccountHolderRewardsPool,
+  TAccountReceiptMint,
+  TAccountExtraAccountMetas,
+  TAccountMint,
+  TAccountAuthority,
+  TAccountPayer,
+  TAccountSystemProgram,
+  TAccountReceiptTokenProgram
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? PALADIN_REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: true,
+    },
+    receiptMint: { value: input.receiptMint ?? null, isWritable: true },
+    extraAccountMetas: {
+      value: input.extraAccountMetas ?? null,
+      isWritable: true,
+    },
+    mint: { value: input.mint ?? null, isWritable: false },
+    authority: { value: input.authority ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    receiptTokenProgram: {
+      value: input.receiptTokenProgram ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.receiptMint),
+      getAccountMeta(accounts.extraAccountMetas),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.receiptTokenProgram),
+    ],
+    programAddress,
+    data: getInitializeReceiptMintInstructionDataEncoder().encode({}),
+  } as InitializeReceiptMintInstruction<
+    TProgramAddress,
+    TAccountHolderRewardsPool,
+    TAccountReceiptMint,
+    TAccountExtraAccountMetas,
+    TAccountMint,
+    TAccountAuthority,
+    TAccountPayer,
+    TAccountSystemProgram,
+    TAccountReceiptTokenProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedInitializeReceiptMintInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[0];
+    /** Receipt token mint. */
+    receiptMint: TAccountMetas[1];
+    /** Transfer hook extra account metas account. */
+    extraAccountMetas: TAccountMetas[2];
+    /** Token mint. */
+    mint: TAccountMetas[3];
+    /** Pool authority. */
+    authority: TAccountMetas[4];
+    /** Payer funding the rent for the receipt mint and extra account metas accounts. */
+    payer: TAccountMetas[5];
+    /** System program. */
+    systemProgram: TAccountMetas[6];
+    /** Token-2022 program. */
+    receiptTokenProgram: TAccountMetas[7];
+  };
+  data: InitializeReceiptMintInstructionData;
+};
+
+export function parseInitializeReceiptMintInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedInitializeReceiptMintInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 8) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewardsPool: getNextAccount(),
+      receiptMint: getNextAccount(),
+      extraAccountMetas: getNextAccount(),
+      mint: getNextAccount(),
+      authority: getNextAccount(),
+      payer: getNextAccount(),
+      systemProgram: getNextAccount(),
+      receiptTokenProgram: getNextAccount(),
+    },
+    data: getInitializeReceiptMintInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
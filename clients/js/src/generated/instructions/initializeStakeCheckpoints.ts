@@ -0,0 +1,248 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { PALADIN_REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const INITIALIZE_STAKE_CHECKPOINTS_DISCRIMINATOR = 16;
+
+export function getInitializeStakeCheckpointsDiscriminatorBytes() {
+  return getU8Encoder().encode(INITIALIZE_STAKE_CHECKPOINTS_DISCRIMINATOR);
+}
+
+export type InitializeStakeCheckpointsInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewards extends string | IAccountMeta<string> = string,
+  TAccountStakeCheckpoints extends string | IAccountMeta<string> = string,
+  TAccountOwner extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewards extends string
+        ? WritableAccount<TAccountHolderRewards>
+        : TAccountHolderRewards,
+      TAccountStakeCheckpoints extends string
+        ? WritableAccount<TAccountStakeCheckpoints>
+        : TAccountStakeCheckpoints,
+      TAccountOwner extends string
+        ? ReadonlySignerAccount<TAccountOwner> &
+            IAccountSignerMeta<TAccountOwner>
+        : TAccountOwner,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type InitializeStakeCheckpointsInstructionData = {
+  discriminator: number;
+};
+
+export type InitializeStakeCheckpointsInstructionDataArgs = {};
+
+export function getInitializeStakeCheckpointsInstructionDataEncoder(): Encoder<InitializeStakeCheckpointsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: INITIALIZE_STAKE_CHECKPOINTS_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getInitializeStakeCheckpointsInstructionDataDecoder(): Decoder<InitializeStakeCheckpointsInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getInitializeStakeCheckpointsInstructionDataCodec(): Codec<
+  InitializeStakeCheckpointsInstructionDataArgs,
+  InitializeStakeCheckpointsInstructionData
+> {
+  return combineCodec(
+    getInitializeStakeCheckpointsInstructionDataEncoder(),
+    getInitializeStakeCheckpointsInstructionDataDecoder()
+  );
+}
+
+export type InitializeStakeCheckpointsInput<
+  TAccountHolderRewards extends string = string,
+  TAccountStakeCheckpoints extends string = string,
+  TAccountOwner extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  /** Holder rewards account. */
+  holderRewards: Address<TAccountHolderRewards>;
+  /** Stake checkpoints account. */
+  stakeCheckpoints: Address<TAccountStakeCheckpoints>;
+  /** Owner of the holder rewards account. */
+  owner: TransactionSigner<TAccountOwner>;
+  /** Payer funding the rent for the stake checkpoints account. */
+  payer: TransactionSigner<TAccountPayer>;
+  /** System program. */
+  systemProgram?: Address<TAccountSystemProgram>;
+};
+
+export function getInitializeStakeCheckpointsInstruction<
+  TAccountHolderRewards extends string,
+  TAccountStakeCheckpoints extends string,
+  TAccountOwner extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+  TProgramAddress extends Address = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+>(
+  input: InitializeStakeCheckpointsInput<
+    TAccountHolderRewards,
+    TAccountStakeCheckpoints,
+    TAccountOwner,
+    TAccountPayer,
+    TAccountSystemProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): InitializeStakeCheckpointsInstruction<
+  TProgramAddress,
+  TAccountHolderRewards,
+  TAccountStakeCheckpoints,
+  TAccountOwner,
+  TAccountPayer,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? PALADIN_REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewards: { value: input.holderRewards ?? null, isWritable: true },
+    stakeCheckpoints: {
+      value: input.stakeCheckpoints ?? null,
+      isWritable: true,
+    },
+    owner: { value: input.owner ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewards),
+      getAccountMeta(accounts.stakeCheckpoints),
+      getAccountMeta(accounts.owner),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getInitializeStakeCheckpointsInstructionDataEncoder().encode({}),
+  } as InitializeStakeCheckpointsInstruction<
+    TProgramAddress,
+    TAccountHolderRewards,
+    TAccountStakeCheckpoints,
+    TAccountOwner,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedInitializeStakeCheckpointsInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards account. */
+    holderRewards: TAccountMetas[0];
+    /** Stake checkpoints account. */
+    stakeCheckpoints: TAccountMetas[1];
+    /** Owner of the holder rewards account. */
+    owner: TAccountMetas[2];
+    /** Payer funding the rent for the stake checkpoints account. */
+    payer: TAccountMetas[3];
+    /** System program. */
+    systemProgram: TAccountMetas[4];
+  };
+  data: InitializeStakeCheckpointsInstructionData;
+};
+
+export function parseInitializeStakeCheckpointsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedInitializeStakeCheckpointsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 5) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewards: getNextAccount(),
+      stakeCheckpoints: getNextAccount(),
+      owner: getNextAccount(),
+      payer: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getInitializeStakeCheckpointsInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
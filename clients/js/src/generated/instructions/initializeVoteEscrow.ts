@@ -0,0 +1,297 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getBooleanDecoder,
+  getBooleanEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { PALADIN_REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const INITIALIZE_VOTE_ESCROW_DISCRIMINATOR = 13;
+
+export function getInitializeVoteEscrowDiscriminatorBytes() {
+  return getU8Encoder().encode(INITIALIZE_VOTE_ESCROW_DISCRIMINATOR);
+}
+
+export type InitializeVoteEscrowInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountLockupTiers extends string | IAccountMeta<string> = string,
+  TAccountVoteEscrow extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewardsPool extends string
+        ? ReadonlyAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountLockupTiers extends string
+        ? WritableAccount<TAccountLockupTiers>
+        : TAccountLockupTiers,
+      TAccountVoteEscrow extends string
+        ? WritableAccount<TAccountVoteEscrow>
+        : TAccountVoteEscrow,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type InitializeVoteEscrowInstructionData = {
+  discriminator: number;
+  rewardWeighting: boolean;
+};
+
+export type InitializeVoteEscrowInstructionDataArgs = {
+  rewardWeighting: boolean;
+};
+
+export function getInitializeVoteEscrowInstructionDataEncoder(): Encoder<InitializeVoteEscrowInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['rewardWeighting', getBooleanEncoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: INITIALIZE_VOTE_ESCROW_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getInitializeVoteEscrowInstructionDataDecoder(): Decoder<InitializeVoteEscrowInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['rewardWeighting', getBooleanDecoder()],
+  ]);
+}
+
+export function getInitializeVoteEscrowInstructionDataCodec(): Codec<
+  InitializeVoteEscrowInstructionDataArgs,
+  InitializeVoteEscrowInstructionData
+> {
+  return combineCodec(
+    getInitializeVoteEscrowInstructionDataEncoder(),
+    getInitializeVoteEscrowInstructionDataDecoder()
+  );
+}
+
+export type InitializeVoteEscrowInput<
+  TAccountHolderRewardsPool extends string = string,
+  TAccountLockupTiers extends string = string,
+  TAccountVoteEscrow extends string = string,
+  TAccountMint extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Lockup tiers account. */
+  lockupTiers: Address<TAccountLockupTiers>;
+  /** Vote escrow account. */
+  voteEscrow: Address<TAccountVoteEscrow>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Pool authority. */
+  authority: TransactionSigner<TAccountAuthority>;
+  /** Payer funding the rent for the vote escrow account. */
+  payer: TransactionSigner<TAccountPayer>;
+  /** System program. */
+  systemProgram?: Address<TAccountSystemProgram>;
+  rewardWeighting: InitializeVoteEscrowInstructionDataArgs['rewardWeighting'];
+};
+
+export function getInitializeVoteEscrowInstruction<
+  TAccountHolderRewardsPool extends string,
+  TAccountLockupTiers extends string,
+  TAccountVoteEscrow extends string,
+  TAccountMint extends string,
+  TAccountAuthority extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+  TProgramAddress extends Address = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+>(
+  input: InitializeVoteEscrowInput<
+    TAccountHolderRewardsPool,
+    TAccountLockupTiers,
+    TAccountVoteEscrow,
+    TAccountMint,
+    TAccountAuthority,
+    TAccountPayer,
+    TAccountSystemProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): InitializeVoteEscrowInstruction<
+  TProgramAddress,
+  TAccountHolderRewardsPool,
+  TAccountLockupTiers,
+  TAccountVoteEscrow,
+  TAccountMint,
+  TAccountAuthority,
+  TAccountPayer,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? PALADIN_REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: false,
+    },
+    lockupTiers: { value: input.lockupTiers ?? null, isWritable: true },
+    voteEscrow: { value: input.voteEscrow ?? null, isWritable: true },
+    mint: { value: input.mint ?? null, isWritable: false },
+    authority: { value: input.authority ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.lockupTiers),
+      getAccountMeta(accounts.voteEscrow),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getInitializeVoteEscrowInstructionDataEncoder().encode(
+      args as InitializeVoteEscrowInstructionDataArgs
+    ),
+  } as InitializeVoteEscrowInstruction<
+    TProgramAddress,
+    TAccountHolderRewardsPool,
+    TAccountLockupTiers,
+    TAccountVoteEscrow,
+    TAccountMint,
+    TAccountAuthority,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedInitializeVoteEscrowInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[0];
+    /** Lockup tiers account. */
+    lockupTiers: TAccountMetas[1];
+    /** Vote escrow account. */
+    voteEscrow: TAccountMetas[2];
+    /** Token mint. */
+    mint: TAccountMetas[3];
+    /** Pool authority. */
+    authority: TAccountMetas[4];
+    /** Payer funding the rent for the vote escrow account. */
+    payer: TAccountMetas[5];
+    /** System program. */
+    systemProgram: TAccountMetas[6];
+  };
+  data: InitializeVoteEscrowInstructionData;
+};
+
+export function parseInitializeVoteEscrowInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedInitializeVoteEscrowInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 7) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewardsPool: getNextAccount(),
+      lockupTiers: getNextAccount(),
+      voteEscrow: getNextAccount(),
+      mint: getNextAccount(),
+      authority: getNextAccount(),
+      payer: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getInitializeVoteEscrowInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,305 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getI64Decoder,
+  getI64Encoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { PALADIN_REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const LOCK_DISCRIMINATOR = 11;
+
+export function getLockDiscriminatorBytes() {
+  return getU8Encoder().encode(LOCK_DISCRIMINATOR);
+}
+
+export type LockInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountHolderRewards extends string | IAccountMeta<string> = string,
+  TAccountLockupTiers extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountOwner extends string | IAccountMeta<string> = string,
+  TAccountRateHistory extends string | IAccountMeta<string> = string,
+  TAccountVoteEscrow extends string | IAccountMeta<string> = string,
+  TAccountEpochLedger extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewardsPool extends string
+        ? WritableAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountHolderRewards extends string
+        ? WritableAccount<TAccountHolderRewards>
+        : TAccountHolderRewards,
+      TAccountLockupTiers extends string
+        ? ReadonlyAccount<TAccountLockupTiers>
+        : TAccountLockupTiers,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountOwner extends string
+        ? WritableSignerAccount<TAccountOwner> &
+            IAccountSignerMeta<TAccountOwner>
+        : TAccountOwner,
+      TAccountRateHistory extends string
+        ? WritableAccount<TAccountRateHistory>
+        : TAccountRateHistory,
+      TAccountVoteEscrow extends string
+        ? WritableAccount<TAccountVoteEscrow>
+        : TAccountVoteEscrow,
+      TAccountEpochLedger extends string
+        ? WritableAccount<TAccountEpochLedger>
+        : TAccountEpochLedger,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type LockInstructionData = {
+  discriminator: number;
+  amount: bigint;
+  unlockTimestamp: bigint;
+};
+
+export type LockInstructionDataArgs = {
+  amount: number | bigint;
+  unlockTimestamp: number | bigint;
+};
+
+export function getLockInstructionDataEncoder(): Encoder<LockInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['amount', getU64Encoder()],
+      ['unlockTimestamp', getI64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: LOCK_DISCRIMINATOR })
+  );
+}
+
+export function getLockInstructionDataDecoder(): Decoder<LockInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['amount', getU64Decoder()],
+    ['unlockTimestamp', getI64Decoder()],
+  ]);
+}
+
+export function getLockInstructionDataCodec(): Codec<
+  LockInstructionDataArgs,
+  LockInstructionData
+> {
+  return combineCodec(
+    getLockInstructionDataEncoder(),
+    getLockInstructionDataDecoder()
+  );
+}
+
+export type LockInput<
+  TAccountHolderRewardsPool extends string = string,
+  TAccountHolderRewards extends string = string,
+  TAccountLockupTiers extends string = string,
+  TAccountMint extends string = string,
+  TAccountOwner extends string = string,
+  TAccountRateHistory extends string = string,
+  TAccountVoteEscrow extends string = string,
+  TAccountEpochLedger extends string = string,
+> = {
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Holder rewards account. */
+  holderRewards: Address<TAccountHolderRewards>;
+  /** Lockup tiers account. */
+  lockupTiers: Address<TAccountLockupTiers>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Owner of the account. */
+  owner: TransactionSigner<TAccountOwner>;
+  /** Rate history account, if the pool records one. */
+  rateHistory?: Address<TAccountRateHistory>;
+  /** Vote escrow account, required if the pool has one. */
+  voteEscrow?: Address<TAccountVoteEscrow>;
+  /** Epoch ledger account, if the pool records one. */
+  epochLedger?: Address<TAccountEpochLedger>;
+  amount: LockInstructionDataArgs['amount'];
+  unlockTimestamp: LockInstructionDataArgs['unlockTimestamp'];
+};
+
+export function getLockInstruction<
+  TAccountHolderRewardsPool extends string,
+  TAccountHolderRewards extends string,
+  TAccountLockupTiers extends string,
+  TAccountMint extends string,
+  TAccountOwner extends string,
+  TAccountRateHistory extends string,
+  TAccountVoteEscrow extends string,
+  TAccountEpochLedger extends string,
+  TProgramAddress extends Address = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+>(
+  input: LockInput<
+    TAccountHolderRewardsPool,
+    TAccountHolderRewards,
+    TAccountLockupTiers,
+    TAccountMint,
+    TAccountOwner,
+    TAccountRateHistory,
+    TAccountVoteEscrow,
+    TAccountEpochLedger
+  >,
+  config?: { programAddress?: TProgramAddress }
+): LockInstruction<
+  TProgramAddress,
+  TAccountHolderRewardsPool,
+  TAccountHolderRewards,
+  TAccountLockupTiers,
+  TAccountMint,
+  TAccountOwner,
+  TAccountRateHistory,
+  TAccountVoteEscrow,
+  TAccountEpochLedger
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? PALADIN_REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: true,
+    },
+    holderRewards: { value: input.holderRewards ?? null, isWritable: true },
+    lockupTiers: { value: input.lockupTiers ?? null, isWritable: false },
+    mint: { value: input.mint ?? null, isWritable: false },
+    owner: { value: input.owner ?? null, isWritable: true },
+    rateHistory: { value: input.rateHistory ?? null, isWritable: true },
+    voteEscrow: { value: input.voteEscrow ?? null, isWritable: true },
+    epochLedger: { value: input.epochLedger ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.holderRewards),
+      getAccountMeta(accounts.lockupTiers),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.owner),
+      getAccountMeta(accounts.rateHistory),
+      getAccountMeta(accounts.voteEscrow),
+      getAccountMeta(accounts.epochLedger),
+    ],
+    programAddress,
+    data: getLockInstructionDataEncoder().encode(
+      args as LockInstructionDataArgs
+    ),
+  } as LockInstruction<
+    TProgramAddress,
+    TAccountHolderRewardsPool,
+    TAccountHolderRewards,
+    TAccountLockupTiers,
+    TAccountMint,
+    TAccountOwner,
+    TAccountRateHistory,
+    TAccountVoteEscrow,
+    TAccountEpochLedger
+  >;
+
+  return instruction;
+}
+
+export type ParsedLockInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[0];
+    /** Holder rewards account. */
+    holderRewards: TAccountMetas[1];
+    /** Lockup tiers account. */
+    lockupTiers: TAccountMetas[2];
+    /** Token mint. */
+    mint: TAccountMetas[3];
+    /** Owner of the account. */
+    owner: TAccountMetas[4];
+    /** Rate history account, if the pool records one. */
+    rateHistory: TAccountMetas[5];
+    /** Vote escrow account, required if the pool has one. */
+    voteEscrow: TAccountMetas[6];
+    /** Epoch ledger account, if the pool records one. */
+    epochLedger: TAccountMetas[7];
+  };
+  data: LockInstructionData;
+};
+
+export function parseLockInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedLockInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 8) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewardsPool: getNextAccount(),
+      holderRewards: getNextAccount(),
+      lockupTiers: getNextAccount(),
+      mint: getNextAccount(),
+      owner: getNextAccount(),
+      rateHistory: getNextAccount(),
+      voteEscrow: getNextAccount(),
+      epochLedger: getNextAccount(),
+    },
+    data: getLockInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -0,0 +1,225 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { PALADIN_REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const MIGRATE_HOLDER_REWARDS_DISCRIMINATOR = 31;
+
+export function getMigrateHolderRewardsDiscriminatorBytes() {
+  return getU8Encoder().encode(MIGRATE_HOLDER_REWARDS_DISCRIMINATOR);
+}
+
+export type MigrateHolderRewardsInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewards extends string | IAccountMeta<string> = string,
+  TAccountOwner extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewards extends string
+        ? WritableAccount<TAccountHolderRewards>
+        : TAccountHolderRewards,
+      TAccountOwner extends string
+        ? ReadonlyAccount<TAccountOwner>
+        : TAccountOwner,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type MigrateHolderRewardsInstructionData = { discriminator: number };
+
+export type MigrateHolderRewardsInstructionDataArgs = {};
+
+export function getMigrateHolderRewardsInstructionDataEncoder(): Encoder<MigrateHolderRewardsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: MIGRATE_HOLDER_REWARDS_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getMigrateHolderRewardsInstructionDataDecoder(): Decoder<MigrateHolderRewardsInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getMigrateHolderRewardsInstructionDataCodec(): Codec<
+  MigrateHolderRewardsInstructionDataArgs,
+  MigrateHolderRewardsInstructionData
+> {
+  return combineCodec(
+    getMigrateHolderRewardsInstructionDataEncoder(),
+    getMigrateHolderRewardsInstructionDataDecoder()
+  );
+}
+
+export type MigrateHolderRewardsInput<
+  TAccountHolderRewards extends string = string,
+  TAccountOwner extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  /** Holder rewards account. */
+  holderRewards: Address<TAccountHolderRewards>;
+  /** Owner of the holder rewards account. */
+  owner: Address<TAccountOwner>;
+  /** Payer funding the rent for the larger account. */
+  payer: TransactionSigner<TAccountPayer>;
+  /** System program. */
+  systemProgram?: Address<TAccountSystemProgram>;
+};
+
+export function getMigrateHolderRewardsInstruction<
+  TAccountHolderRewards extends string,
+  TAccountOwner extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+  TProgramAddress extends Address = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+>(
+  input: MigrateHolderRewardsInput<
+    TAccountHolderRewards,
+    TAccountOwner,
+    TAccountPayer,
+    TAccountSystemProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): MigrateHolderRewardsInstruction<
+  TProgramAddress,
+  TAccountHolderRewards,
+  TAccountOwner,
+  TAccountPayer,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? PALADIN_REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewards: { value: input.holderRewards ?? null, isWritable: true },
+    owner: { value: input.owner ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewards),
+      getAccountMeta(accounts.owner),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getMigrateHolderRewardsInstructionDataEncoder().encode({}),
+  } as MigrateHolderRewardsInstruction<
+    TProgramAddress,
+    TAccountHolderRewards,
+    TAccountOwner,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedMigrateHolderRewardsInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards account. */
+    holderRewards: TAccountMetas[0];
+    /** Owner of the holder rewards account. */
+    owner: TAccountMetas[1];
+    /** Payer funding the rent for the larger account. */
+    payer: TAccountMetas[2];
+    /** System program. */
+    systemProgram: TAccountMetas[3];
+  };
+  data: MigrateHolderRewardsInstructionData;
+};
+
+export function parseMigrateHolderRewardsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedMigrateHolderRewardsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewards: getNextAccount(),
+      owner: getNextAccount(),
+      payer: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getMigrateHolderRewardsInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,252 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { PALADIN_REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const RECLAIM_CAMPAIGN_REWARDS_DISCRIMINATOR = 28;
+
+export function getReclaimCampaignRewardsDiscriminatorBytes() {
+  return getU8Encoder().encode(RECLAIM_CAMPAIGN_REWARDS_DISCRIMINATOR);
+}
+
+export type ReclaimCampaignRewardsInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountCampaign extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountFunder extends string | IAccountMeta<string> = string,
+  TAccountRateHistory extends string | IAccountMeta<string> = string,
+  TAccountEpochLedger extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewardsPool extends string
+        ? WritableAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountCampaign extends string
+        ? WritableAccount<TAccountCampaign>
+        : TAccountCampaign,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountFunder extends string
+        ? WritableSignerAccount<TAccountFunder> &
+            IAccountSignerMeta<TAccountFunder>
+        : TAccountFunder,
+      TAccountRateHistory extends string
+        ? WritableAccount<TAccountRateHistory>
+        : TAccountRateHistory,
+      TAccountEpochLedger extends string
+        ? WritableAccount<TAccountEpochLedger>
+        : TAccountEpochLedger,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type ReclaimCampaignRewardsInstructionData = { discriminator: number };
+
+export type ReclaimCampaignRewardsInstructionDataArgs = {};
+
+export function getReclaimCampaignRewardsInstructionDataEncoder(): Encoder<ReclaimCampaignRewardsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: RECLAIM_CAMPAIGN_REWARDS_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getReclaimCampaignRewardsInstructionDataDecoder(): Decoder<ReclaimCampaignRewardsInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getReclaimCampaignRewardsInstructionDataCodec(): Codec<
+  ReclaimCampaignRewardsInstructionDataArgs,
+  ReclaimCampaignRewardsInstructionData
+> {
+  return combineCodec(
+    getReclaimCampaignRewardsInstructionDataEncoder(),
+    getReclaimCampaignRewardsInstructionDataDecoder()
+  );
+}
+
+export type ReclaimCampaignRewardsInput<
+  TAccountHolderRewardsPool extends string = string,
+  TAccountCampaign extends string = string,
+  TAccountMint extends string = string,
+  TAccountFunder extends string = string,
+  TAccountRateHistory extends string = string,
+  TAccountEpochLedger extends string = string,
+> = {
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Campaign account. */
+  campaign: Address<TAccountCampaign>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Funder of the campaign. */
+  funder: TransactionSigner<TAccountFunder>;
+  /** Rate history account, if the pool records one. */
+  rateHistory?: Address<TAccountRateHistory>;
+  /** Epoch ledger account, if the pool records one. */
+  epochLedger?: Address<TAccountEpochLedger>;
+};
+
+export function getReclaimCampaignRewardsInstruction<
+  TAccountHolderRewardsPool extends string,
+  TAccountCampaign extends string,
+  TAccountMint extends string,
+  TAccountFunder extends string,
+  TAccountRateHistory extends string,
+  TAccountEpochLedger extends string,
+  TProgramAddress extends Address = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+>(
+  input: ReclaimCampaignRewardsInput<
+    TAccountHolderRewardsPool,
+    TAccountCampaign,
+    TAccountMint,
+    TAccountFunder,
+    TAccountRateHistory,
+    TAccountEpochLedger
+  >,
+  config?: { programAddress?: TProgramAddress }
+): ReclaimCampaignRewardsInstruction<
+  TProgramAddress,
+  TAccountHolderRewardsPool,
+  TAccountCampaign,
+  TAccountMint,
+  TAccountFunder,
+  TAccountRateHistory,
+  TAccountEpochLedger
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? PALADIN_REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: true,
+    },
+    campaign: { value: input.campaign ?? null, isWritable: true },
+    mint: { value: input.mint ?? null, isWritable: false },
+    funder: { value: input.funder ?? null, isWritable: true },
+    rateHistory: { value: input.rateHistory ?? null, isWritable: true },
+    epochLedger: { value: input.epochLedger ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.campaign),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.funder),
+      getAccountMeta(accounts.rateHistory),
+      getAccountMeta(accounts.epochLedger),
+    ],
+    programAddress,
+    data: getReclaimCampaignRewardsInstructionDataEncoder().encode({}),
+  } as ReclaimCampaignRewardsInstruction<
+    TProgramAddress,
+    TAccountHolderRewardsPool,
+    TAccountCampaign,
+    TAccountMint,
+    TAccountFunder,
+    TAccountRateHistory,
+    TAccountEpochLedger
+  >;
+
+  return instruction;
+}
+
+export type ParsedReclaimCampaignRewardsInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[0];
+    /** Campaign account. */
+    campaign: TAccountMetas[1];
+    /** Token mint. */
+    mint: TAccountMetas[2];
+    /** Funder of the campaign. */
+    funder: TAccountMetas[3];
+    /** Rate history account, if the pool records one. */
+    rateHistory: TAccountMetas[4];
+    /** Epoch ledger account, if the pool records one. */
+    epochLedger: TAccountMetas[5];
+  };
+  data: ReclaimCampaignRewardsInstructionData;
+};
+
+export function parseReclaimCampaignRewardsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedReclaimCampaignRewardsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 6) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewardsPool: getNextAccount(),
+      campaign: getNextAccount(),
+      mint: getNextAccount(),
+      funder: getNextAccount(),
+      rateHistory: getNextAccount(),
+      epochLedger: getNextAccount(),
+    },
+    data: getReclaimCampaignRewardsInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,232 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { PALADIN_REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const RELEASE_CAMPAIGN_REWARDS_DISCRIMINATOR = 27;
+
+export function getReleaseCampaignRewardsDiscriminatorBytes() {
+  return getU8Encoder().encode(RELEASE_CAMPAIGN_REWARDS_DISCRIMINATOR);
+}
+
+export type ReleaseCampaignRewardsInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountCampaign extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountRateHistory extends string | IAccountMeta<string> = string,
+  TAccountEpochLedger extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewardsPool extends string
+        ? WritableAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountCampaign extends string
+        ? WritableAccount<TAccountCampaign>
+        : TAccountCampaign,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountRateHistory extends string
+        ? WritableAccount<TAccountRateHistory>
+        : TAccountRateHistory,
+      TAccountEpochLedger extends string
+        ? WritableAccount<TAccountEpochLedger>
+        : TAccountEpochLedger,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type ReleaseCampaignRewardsInstructionData = { discriminator: number };
+
+export type ReleaseCampaignRewardsInstructionDataArgs = {};
+
+export function getReleaseCampaignRewardsInstructionDataEncoder(): Encoder<ReleaseCampaignRewardsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: RELEASE_CAMPAIGN_REWARDS_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getReleaseCampaignRewardsInstructionDataDecoder(): Decoder<ReleaseCampaignRewardsInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getReleaseCampaignRewardsInstructionDataCodec(): Codec<
+  ReleaseCampaignRewardsInstructionDataArgs,
+  ReleaseCampaignRewardsInstructionData
+> {
+  return combineCodec(
+    getReleaseCampaignRewardsInstructionDataEncoder(),
+    getReleaseCampaignRewardsInstructionDataDecoder()
+  );
+}
+
+export type ReleaseCampaignRewardsInput<
+  TAccountHolderRewardsPool extends string = string,
+  TAccountCampaign extends string = string,
+  TAccountMint extends string = string,
+  TAccountRateHistory extends string = string,
+  TAccountEpochLedger extends string = string,
+> = {
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Campaign account. */
+  campaign: Address<TAccountCampaign>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Rate history account, if the pool records one. */
+  rateHistory?: Address<TAccountRateHistory>;
+  /** Epoch ledger account, if the pool records one. */
+  epochLedger?: Address<TAccountEpochLedger>;
+};
+
+export function getReleaseCampaignRewardsInstruction<
+  TAccountHolderRewardsPool extends string,
+  TAccountCampaign extends string,
+  TAccountMint extends string,
+  TAccountRateHistory extends string,
+  TAccountEpochLedger extends string,
+  TProgramAddress extends Address = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+>(
+  input: ReleaseCampaignRewardsInput<
+    TAccountHolderRewardsPool,
+    TAccountCampaign,
+    TAccountMint,
+    TAccountRateHistory,
+    TAccountEpochLedger
+  >,
+  config?: { programAddress?: TProgramAddress }
+): ReleaseCampaignRewardsInstruction<
+  TProgramAddress,
+  TAccountHolderRewardsPool,
+  TAccountCampaign,
+  TAccountMint,
+  TAccountRateHistory,
+  TAccountEpochLedger
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? PALADIN_REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: true,
+    },
+    campaign: { value: input.campaign ?? null, isWritable: true },
+    mint: { value: input.mint ?? null, isWritable: false },
+    rateHistory: { value: input.rateHistory ?? null, isWritable: true },
+    epochLedger: { value: input.epochLedger ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.campaign),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.rateHistory),
+      getAccountMeta(accounts.epochLedger),
+    ],
+    programAddress,
+    data: getReleaseCampaignRewardsInstructionDataEncoder().encode({}),
+  } as ReleaseCampaignRewardsInstruction<
+    TProgramAddress,
+    TAccountHolderRewardsPool,
+    TAccountCampaign,
+    TAccountMint,
+    TAccountRateHistory,
+    TAccountEpochLedger
+  >;
+
+  return instruction;
+}
+
+export type ParsedReleaseCampaignRewardsInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[0];
+    /** Campaign account. */
+    campaign: TAccountMetas[1];
+    /** Token mint. */
+    mint: TAccountMetas[2];
+    /** Rate history account, if the pool records one. */
+    rateHistory: TAccountMetas[3];
+    /** Epoch ledger account, if the pool records one. */
+    epochLedger: TAccountMetas[4];
+  };
+  data: ReleaseCampaignRewardsInstructionData;
+};
+
+export function parseReleaseCampaignRewardsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedReleaseCampaignRewardsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 5) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewardsPool: getNextAccount(),
+      campaign: getNextAccount(),
+      mint: getNextAccount(),
+      rateHistory: getNextAccount(),
+      epochLedger: getNextAccount(),
+    },
+    data: getReleaseCampaignRewardsInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,288 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { PALADIN_REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const SET_ACCESS_ENTRY_DISCRIMINATOR = 21;
+
+export function getSetAccessEntryDiscriminatorBytes() {
+  return getU8Encoder().encode(SET_ACCESS_ENTRY_DISCRIMINATOR);
+}
+
+export type SetAccessEntryInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountAccessEntry extends string | IAccountMeta<string> = string,
+  TAccountWallet extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewardsPool extends string
+        ? ReadonlyAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountAccessEntry extends string
+        ? WritableAccount<TAccountAccessEntry>
+        : TAccountAccessEntry,
+      TAccountWallet extends string
+        ? ReadonlyAccount<TAccountWallet>
+        : TAccountWallet,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SetAccessEntryInstructionData = {
+  discriminator: number;
+  status: number;
+};
+
+export type SetAccessEntryInstructionDataArgs = { status: number };
+
+export function getSetAccessEntryInstructionDataEncoder(): Encoder<SetAccessEntryInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['status', getU8Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: SET_ACCESS_ENTRY_DISCRIMINATOR })
+  );
+}
+
+export function getSetAccessEntryInstructionDataDecoder(): Decoder<SetAccessEntryInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['status', getU8Decoder()],
+  ]);
+}
+
+export function getSetAccessEntryInstructionDataCodec(): Codec<
+  SetAccessEntryInstructionDataArgs,
+  SetAccessEntryInstructionData
+> {
+  return combineCodec(
+    getSetAccessEntryInstructionDataEncoder(),
+    getSetAccessEntryInstructionDataDecoder()
+  );
+}
+
+export type SetAccessEntryInput<
+  TAccountHolderRewardsPool extends string = string,
+  TAccountAccessEntry extends string = string,
+  TAccountWallet extends string = string,
+  TAccountMint extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Access entry account. */
+  accessEntry: Address<TAccountAccessEntry>;
+  /** Wallet the access entry is for. */
+  wallet: Address<TAccountWallet>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Pool authority. */
+  authority: TransactionSigner<TAccountAuthority>;
+  /** Payer funding the rent for the access entry account. */
+  payer: TransactionSigner<TAccountPayer>;
+  /** System program. */
+  systemProgram?: Address<TAccountSystemProgram>;
+  status: SetAccessEntryInstructionDataArgs['status'];
+};
+
+export function getSetAccessEntryInstruction<
+  TAccountHolderRewardsPool extends string,
+  TAccountAccessEntry extends string,
+  TAccountWallet extends string,
+  TAccountMint extends string,
+  TAccountAuthority extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+  TProgramAddress extends Address = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+>(
+  input: SetAccessEntryInput<
+    TAccountHolderRewardsPool,
+    TAccountAccessEntry,
+    TAccountWallet,
+    TAccountMint,
+    TAccountAuthority,
+    TAccountPayer,
+    TAccountSystemProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): SetAccessEntryInstruction<
+  TProgramAddress,
+  TAccountHolderRewardsPool,
+  TAccountAccessEntry,
+  TAccountWallet,
+  TAccountMint,
+  TAccountAuthority,
+  TAccountPayer,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? PALADIN_REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: false,
+    },
+    accessEntry: { value: input.accessEntry ?? null, isWritable: true },
+    wallet: { value: input.wallet ?? null, isWritable: false },
+    mint: { value: input.mint ?? null, isWritable: false },
+    authority: { value: input.authority ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.accessEntry),
+      getAccountMeta(accounts.wallet),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getSetAccessEntryInstructionDataEncoder().encode(
+      args as SetAccessEntryInstructionDataArgs
+    ),
+  } as SetAccessEntryInstruction<
+    TProgramAddress,
+    TAccountHolderRewardsPool,
+    TAccountAccessEntry,
+    TAccountWallet,
+    TAccountMint,
+    TAccountAuthority,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedSetAccessEntryInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[0];
+    /** Access entry account. */
+    accessEntry: TAccountMetas[1];
+    /** Wallet the access entry is for. */
+    wallet: TAccountMetas[2];
+    /** Token mint. */
+    mint: TAccountMetas[3];
+    /** Pool authority. */
+    authority: TAccountMetas[4];
+    /** Payer funding the rent for the access entry account. */
+    payer: TAccountMetas[5];
+    /** System program. */
+    systemProgram: TAccountMetas[6];
+  };
+  data: SetAccessEntryInstructionData;
+};
+
+export function parseSetAccessEntryInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSetAccessEntryInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 7) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewardsPool: getNextAccount(),
+      accessEntry: getNextAccount(),
+      wallet: getNextAccount(),
+      mint: getNextAccount(),
+      authority: getNextAccount(),
+      payer: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getSetAccessEntryInstructionDataDecoder().decode(instruction.data),
+  };
+}
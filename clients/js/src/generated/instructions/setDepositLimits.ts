@@ -0,0 +1,228 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { PALADIN_REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const SET_DEPOSIT_LIMITS_DISCRIMINATOR = 19;
+
+export function getSetDepositLimitsDiscriminatorBytes() {
+  return getU8Encoder().encode(SET_DEPOSIT_LIMITS_DISCRIMINATOR);
+}
+
+export type SetDepositLimitsInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewardsPool extends string
+        ? WritableAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SetDepositLimitsInstructionData = {
+  discriminator: number;
+  maxTotalDeposit: bigint;
+  maxHolderDeposit: bigint;
+  minDeposit: bigint;
+};
+
+export type SetDepositLimitsInstructionDataArgs = {
+  maxTotalDeposit: number | bigint;
+  maxHolderDeposit: number | bigint;
+  minDeposit: number | bigint;
+};
+
+export function getSetDepositLimitsInstructionDataEncoder(): Encoder<SetDepositLimitsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['maxTotalDeposit', getU64Encoder()],
+      ['maxHolderDeposit', getU64Encoder()],
+      ['minDeposit', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: SET_DEPOSIT_LIMITS_DISCRIMINATOR })
+  );
+}
+
+export function getSetDepositLimitsInstructionDataDecoder(): Decoder<SetDepositLimitsInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['maxTotalDeposit', getU64Decoder()],
+    ['maxHolderDeposit', getU64Decoder()],
+    ['minDeposit', getU64Decoder()],
+  ]);
+}
+
+export function getSetDepositLimitsInstructionDataCodec(): Codec<
+  SetDepositLimitsInstructionDataArgs,
+  SetDepositLimitsInstructionData
+> {
+  return combineCodec(
+    getSetDepositLimitsInstructionDataEncoder(),
+    getSetDepositLimitsInstructionDataDecoder()
+  );
+}
+
+export type SetDepositLimitsInput<
+  TAccountHolderRewardsPool extends string = string,
+  TAccountMint extends string = string,
+  TAccountAuthority extends string = string,
+> = {
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Pool authority. */
+  authority: TransactionSigner<TAccountAuthority>;
+  maxTotalDeposit: SetDepositLimitsInstructionDataArgs['maxTotalDeposit'];
+  maxHolderDeposit: SetDepositLimitsInstructionDataArgs['maxHolderDeposit'];
+  minDeposit: SetDepositLimitsInstructionDataArgs['minDeposit'];
+};
+
+export function getSetDepositLimitsInstruction<
+  TAccountHolderRewardsPool extends string,
+  TAccountMint extends string,
+  TAccountAuthority extends string,
+  TProgramAddress extends Address = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+>(
+  input: SetDepositLimitsInput<
+    TAccountHolderRewardsPool,
+    TAccountMint,
+    TAccountAuthority
+  >,
+  config?: { programAddress?: TProgramAddress }
+): SetDepositLimitsInstruction<
+  TProgramAddress,
+  TAccountHolderRewardsPool,
+  TAccountMint,
+  TAccountAuthority
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? PALADIN_REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: true,
+    },
+    mint: { value: input.mint ?? null, isWritable: false },
+    authority: { value: input.authority ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.authority),
+    ],
+    programAddress,
+    data: getSetDepositLimitsInstructionDataEncoder().encode(
+      args as SetDepositLimitsInstructionDataArgs
+    ),
+  } as SetDepositLimitsInstruction<
+    TProgramAddress,
+    TAccountHolderRewardsPool,
+    TAccountMint,
+    TAccountAuthority
+  >;
+
+  return instruction;
+}
+
+export type ParsedSetDepositLimitsInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[0];
+    /** Token mint. */
+    mint: TAccountMetas[1];
+    /** Pool authority. */
+    authority: TAccountMetas[2];
+  };
+  data: SetDepositLimitsInstructionData;
+};
+
+export function parseSetDepositLimitsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSetDepositLimitsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewardsPool: getNextAccount(),
+      mint: getNextAccount(),
+      authority: getNextAccount(),
+    },
+    data: getSetDepositLimitsInstructionDataDecoder().decode(instruction.data),
+  };
+}
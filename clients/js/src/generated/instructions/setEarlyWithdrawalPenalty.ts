@@ -0,0 +1,230 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU32Decoder,
+  getU32Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { PALADIN_REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const SET_EARLY_WITHDRAWAL_PENALTY_DISCRIMINATOR = 18;
+
+export function getSetEarlyWithdrawalPenaltyDiscriminatorBytes() {
+  return getU8Encoder().encode(SET_EARLY_WITHDRAWAL_PENALTY_DISCRIMINATOR);
+}
+
+export type SetEarlyWithdrawalPenaltyInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewardsPool extends string
+        ? WritableAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SetEarlyWithdrawalPenaltyInstructionData = {
+  discriminator: number;
+  penaltyBps: number;
+  periodSlots: number;
+};
+
+export type SetEarlyWithdrawalPenaltyInstructionDataArgs = {
+  penaltyBps: number;
+  periodSlots: number;
+};
+
+export function getSetEarlyWithdrawalPenaltyInstructionDataEncoder(): Encoder<SetEarlyWithdrawalPenaltyInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['penaltyBps', getU16Encoder()],
+      ['periodSlots', getU32Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: SET_EARLY_WITHDRAWAL_PENALTY_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getSetEarlyWithdrawalPenaltyInstructionDataDecoder(): Decoder<SetEarlyWithdrawalPenaltyInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['penaltyBps', getU16Decoder()],
+    ['periodSlots', getU32Decoder()],
+  ]);
+}
+
+export function getSetEarlyWithdrawalPenaltyInstructionDataCodec(): Codec<
+  SetEarlyWithdrawalPenaltyInstructionDataArgs,
+  SetEarlyWithdrawalPenaltyInstructionData
+> {
+  return combineCodec(
+    getSetEarlyWithdrawalPenaltyInstructionDataEncoder(),
+    getSetEarlyWithdrawalPenaltyInstructionDataDecoder()
+  );
+}
+
+export type SetEarlyWithdrawalPenaltyInput<
+  TAccountHolderRewardsPool extends string = string,
+  TAccountMint extends string = string,
+  TAccountAuthority extends string = string,
+> = {
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Pool authority. */
+  authority: TransactionSigner<TAccountAuthority>;
+  penaltyBps: SetEarlyWithdrawalPenaltyInstructionDataArgs['penaltyBps'];
+  periodSlots: SetEarlyWithdrawalPenaltyInstructionDataArgs['periodSlots'];
+};
+
+export function getSetEarlyWithdrawalPenaltyInstruction<
+  TAccountHolderRewardsPool extends string,
+  TAccountMint extends string,
+  TAccountAuthority extends string,
+  TProgramAddress extends Address = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+>(
+  input: SetEarlyWithdrawalPenaltyInput<
+    TAccountHolderRewardsPool,
+    TAccountMint,
+    TAccountAuthority
+  >,
+  config?: { programAddress?: TProgramAddress }
+): SetEarlyWithdrawalPenaltyInstruction<
+  TProgramAddress,
+  TAccountHolderRewardsPool,
+  TAccountMint,
+  TAccountAuthority
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? PALADIN_REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: true,
+    },
+    mint: { value: input.mint ?? null, isWritable: false },
+    authority: { value: input.authority ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.authority),
+    ],
+    programAddress,
+    data: getSetEarlyWithdrawalPenaltyInstructionDataEncoder().encode(
+      args as SetEarlyWithdrawalPenaltyInstructionDataArgs
+    ),
+  } as SetEarlyWithdrawalPenaltyInstruction<
+    TProgramAddress,
+    TAccountHolderRewardsPool,
+    TAccountMint,
+    TAccountAuthority
+  >;
+
+  return instruction;
+}
+
+export type ParsedSetEarlyWithdrawalPenaltyInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[0];
+    /** Token mint. */
+    mint: TAccountMetas[1];
+    /** Pool authority. */
+    authority: TAccountMetas[2];
+  };
+  data: SetEarlyWithdrawalPenaltyInstructionData;
+};
+
+export function parseSetEarlyWithdrawalPenaltyInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSetEarlyWithdrawalPenaltyInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewardsPool: getNextAccount(),
+      mint: getNextAccount(),
+      authority: getNextAccount(),
+    },
+    data: getSetEarlyWithdrawalPenaltyInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,282 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { PALADIN_REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  getLockupTierDecoder,
+  getLockupTierEncoder,
+  type LockupTier,
+  type LockupTierArgs,
+} from '../types';
+
+export const SET_LOCKUP_TIERS_DISCRIMINATOR = 10;
+
+export function getSetLockupTiersDiscriminatorBytes() {
+  return getU8Encoder().encode(SET_LOCKUP_TIERS_DISCRIMINATOR);
+}
+
+export type SetLockupTiersInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountLockupTiers extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewardsPool extends string
+        ? ReadonlyAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountLockupTiers extends string
+        ? WritableAccount<TAccountLockupTiers>
+        : TAccountLockupTiers,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SetLockupTiersInstructionData = {
+  discriminator: number;
+  tiers: Array<LockupTier>;
+};
+
+export type SetLockupTiersInstructionDataArgs = {
+  tiers: Array<LockupTierArgs>;
+};
+
+export function getSetLockupTiersInstructionDataEncoder(): Encoder<SetLockupTiersInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['tiers', getArrayEncoder(getLockupTierEncoder(), { size: 4 })],
+    ]),
+    (value) => ({ ...value, discriminator: SET_LOCKUP_TIERS_DISCRIMINATOR })
+  );
+}
+
+export function getSetLockupTiersInstructionDataDecoder(): Decoder<SetLockupTiersInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['tiers', getArrayDecoder(getLockupTierDecoder(), { size: 4 })],
+  ]);
+}
+
+export function getSetLockupTiersInstructionDataCodec(): Codec<
+  SetLockupTiersInstructionDataArgs,
+  SetLockupTiersInstructionData
+> {
+  return combineCodec(
+    getSetLockupTiersInstructionDataEncoder(),
+    getSetLockupTiersInstructionDataDecoder()
+  );
+}
+
+export type SetLockupTiersInput<
+  TAccountHolderRewardsPool extends string = string,
+  TAccountLockupTiers extends string = string,
+  TAccountMint extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Lockup tiers account. */
+  lockupTiers: Address<TAccountLockupTiers>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Pool authority. */
+  authority: TransactionSigner<TAccountAuthority>;
+  /** Payer funding the rent for the lockup tiers account. */
+  payer: TransactionSigner<TAccountPayer>;
+  /** System program. */
+  systemProgram?: Address<TAccountSystemProgram>;
+  tiers: SetLockupTiersInstructionDataArgs['tiers'];
+};
+
+export function getSetLockupTiersInstruction<
+  TAccountHolderRewardsPool extends string,
+  TAccountLockupTiers extends string,
+  TAccountMint extends string,
+  TAccountAuthority extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+  TProgramAddress extends Address = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+>(
+  input: SetLockupTiersInput<
+    TAccountHolderRewardsPool,
+    TAccountLockupTiers,
+    TAccountMint,
+    TAccountAuthority,
+    TAccountPayer,
+    TAccountSystemProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): SetLockupTiersInstruction<
+  TProgramAddress,
+  TAccountHolderRewardsPool,
+  TAccountLockupTiers,
+  TAccountMint,
+  TAccountAuthority,
+  TAccountPayer,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? PALADIN_REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: false,
+    },
+    lockupTiers: { value: input.lockupTiers ?? null, isWritable: true },
+    mint: { value: input.mint ?? null, isWritable: false },
+    authority: { value: input.authority ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.lockupTiers),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getSetLockupTiersInstructionDataEncoder().encode(
+      args as SetLockupTiersInstructionDataArgs
+    ),
+  } as SetLockupTiersInstruction<
+    TProgramAddress,
+    TAccountHolderRewardsPool,
+    TAccountLockupTiers,
+    TAccountMint,
+    TAccountAuthority,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedSetLockupTiersInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[0];
+    /** Lockup tiers account. */
+    lockupTiers: TAccountMetas[1];
+    /** Token mint. */
+    mint: TAccountMetas[2];
+    /** Pool authority. */
+    authority: TAccountMetas[3];
+    /** Payer funding the rent for the lockup tiers account. */
+    payer: TAccountMetas[4];
+    /** System program. */
+    systemProgram: TAccountMetas[5];
+  };
+  data: SetLockupTiersInstructionData;
+};
+
+export function parseSetLockupTiersInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSetLockupTiersInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 6) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewardsPool: getNextAccount(),
+      lockupTiers: getNextAccount(),
+      mint: getNextAccount(),
+      authority: getNextAccount(),
+      payer: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getSetLockupTiersInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -0,0 +1,237 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { PALADIN_REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const SET_SLASHING_CONFIG_DISCRIMINATOR = 24;
+
+export function getSetSlashingConfigDiscriminatorBytes() {
+  return getU8Encoder().encode(SET_SLASHING_CONFIG_DISCRIMINATOR);
+}
+
+export type SetSlashingConfigInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountSlashingAuthority extends string | IAccountMeta<string> = string,
+  TAccountSlashDestination extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewardsPool extends string
+        ? WritableAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      TAccountSlashingAuthority extends string
+        ? ReadonlyAccount<TAccountSlashingAuthority>
+        : TAccountSlashingAuthority,
+      TAccountSlashDestination extends string
+        ? ReadonlyAccount<TAccountSlashDestination>
+        : TAccountSlashDestination,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SetSlashingConfigInstructionData = { discriminator: number };
+
+export type SetSlashingConfigInstructionDataArgs = {};
+
+export function getSetSlashingConfigInstructionDataEncoder(): Encoder<SetSlashingConfigInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: SET_SLASHING_CONFIG_DISCRIMINATOR })
+  );
+}
+
+export function getSetSlashingConfigInstructionDataDecoder(): Decoder<SetSlashingConfigInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getSetSlashingConfigInstructionDataCodec(): Codec<
+  SetSlashingConfigInstructionDataArgs,
+  SetSlashingConfigInstructionData
+> {
+  return combineCodec(
+    getSetSlashingConfigInstructionDataEncoder(),
+    getSetSlashingConfigInstructionDataDecoder()
+  );
+}
+
+export type SetSlashingConfigInput<
+  TAccountHolderRewardsPool extends string = string,
+  TAccountMint extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountSlashingAuthority extends string = string,
+  TAccountSlashDestination extends string = string,
+> = {
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Pool authority. */
+  authority: TransactionSigner<TAccountAuthority>;
+  /** Slashing authority. */
+  slashingAuthority: Address<TAccountSlashingAuthority>;
+  /** Slash destination token account. */
+  slashDestination: Address<TAccountSlashDestination>;
+};
+
+export function getSetSlashingConfigInstruction<
+  TAccountHolderRewardsPool extends string,
+  TAccountMint extends string,
+  TAccountAuthority extends string,
+  TAccountSlashingAuthority extends string,
+  TAccountSlashDestination extends string,
+  TProgramAddress extends Address = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+>(
+  input: SetSlashingConfigInput<
+    TAccountHolderRewardsPool,
+    TAccountMint,
+    TAccountAuthority,
+    TAccountSlashingAuthority,
+    TAccountSlashDestination
+  >,
+  config?: { programAddress?: TProgramAddress }
+): SetSlashingConfigInstruction<
+  TProgramAddress,
+  TAccountHolderRewardsPool,
+  TAccountMint,
+  TAccountAuthority,
+  TAccountSlashingAuthority,
+  TAccountSlashDestination
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? PALADIN_REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: true,
+    },
+    mint: { value: input.mint ?? null, isWritable: false },
+    authority: { value: input.authority ?? null, isWritable: false },
+    slashingAuthority: {
+      value: input.slashingAuthority ?? null,
+      isWritable: false,
+    },
+    slashDestination: {
+      value: input.slashDestination ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.slashingAuthority),
+      getAccountMeta(accounts.slashDestination),
+    ],
+    programAddress,
+    data: getSetSlashingConfigInstructionDataEncoder().encode({}),
+  } as SetSlashingConfigInstruction<
+    TProgramAddress,
+    TAccountHolderRewardsPool,
+    TAccountMint,
+    TAccountAuthority,
+    TAccountSlashingAuthority,
+    TAccountSlashDestination
+  >;
+
+  return instruction;
+}
+
+export type ParsedSetSlashingConfigInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[0];
+    /** Token mint. */
+    mint: TAccountMetas[1];
+    /** Pool authority. */
+    authority: TAccountMetas[2];
+    /** Slashing authority. */
+    slashingAuthority: TAccountMetas[3];
+    /** Slash destination token account. */
+    slashDestination: TAccountMetas[4];
+  };
+  data: SetSlashingConfigInstructionData;
+};
+
+export function parseSetSlashingConfigInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSetSlashingConfigInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 5) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewardsPool: getNextAccount(),
+      mint: getNextAccount(),
+      authority: getNextAccount(),
+      slashingAuthority: getNextAccount(),
+      slashDestination: getNextAccount(),
+    },
+    data: getSetSlashingConfigInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -0,0 +1,379 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { PALADIN_REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const SLASH_DISCRIMINATOR = 25;
+
+export function getSlashDiscriminatorBytes() {
+  return getU8Encoder().encode(SLASH_DISCRIMINATOR);
+}
+
+export type SlashInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountHolderRewardsPoolTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountHolderRewards extends string | IAccountMeta<string> = string,
+  TAccountSlashDestination extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountOwner extends string | IAccountMeta<string> = string,
+  TAccountSlashingAuthority extends string | IAccountMeta<string> = string,
+  TAccountTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountRateHistory extends string | IAccountMeta<string> = string,
+  TAccountStakeCheckpoints extends string | IAccountMeta<string> = string,
+  TAccountVoteEscrow extends string | IAccountMeta<string> = string,
+  TAccountEpochLedger extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewardsPool extends string
+        ? WritableAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountHolderRewardsPoolTokenAccount extends string
+        ? WritableAccount<TAccountHolderRewardsPoolTokenAccount>
+        : TAccountHolderRewardsPoolTokenAccount,
+      TAccountHolderRewards extends string
+        ? WritableAccount<TAccountHolderRewards>
+        : TAccountHolderRewards,
+      TAccountSlashDestination extends string
+        ? WritableAccount<TAccountSlashDestination>
+        : TAccountSlashDestination,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountOwner extends string
+        ? ReadonlyAccount<TAccountOwner>
+        : TAccountOwner,
+      TAccountSlashingAuthority extends string
+        ? ReadonlySignerAccount<TAccountSlashingAuthority> &
+            IAccountSignerMeta<TAccountSlashingAuthority>
+        : TAccountSlashingAuthority,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      TAccountRateHistory extends string
+        ? WritableAccount<TAccountRateHistory>
+        : TAccountRateHistory,
+      TAccountStakeCheckpoints extends string
+        ? WritableAccount<TAccountStakeCheckpoints>
+        : TAccountStakeCheckpoints,
+      TAccountVoteEscrow extends string
+        ? WritableAccount<TAccountVoteEscrow>
+        : TAccountVoteEscrow,
+      TAccountEpochLedger extends string
+        ? WritableAccount<TAccountEpochLedger>
+        : TAccountEpochLedger,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SlashInstructionData = { discriminator: number; amount: bigint };
+
+export type SlashInstructionDataArgs = { amount: number | bigint };
+
+export function getSlashInstructionDataEncoder(): Encoder<SlashInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['amount', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: SLASH_DISCRIMINATOR })
+  );
+}
+
+export function getSlashInstructionDataDecoder(): Decoder<SlashInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['amount', getU64Decoder()],
+  ]);
+}
+
+export function getSlashInstructionDataCodec(): Codec<
+  SlashInstructionDataArgs,
+  SlashInstructionData
+> {
+  return combineCodec(
+    getSlashInstructionDataEncoder(),
+    getSlashInstructionDataDecoder()
+  );
+}
+
+export type SlashInput<
+  TAccountHolderRewardsPool extends string = string,
+  TAccountHolderRewardsPoolTokenAccount extends string = string,
+  TAccountHolderRewards extends string = string,
+  TAccountSlashDestination extends string = string,
+  TAccountMint extends string = string,
+  TAccountOwner extends string = string,
+  TAccountSlashingAuthority extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountRateHistory extends string = string,
+  TAccountStakeCheckpoints extends string = string,
+  TAccountVoteEscrow extends string = string,
+  TAccountEpochLedger extends string = string,
+> = {
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Holder rewards pool token account. */
+  holderRewardsPoolTokenAccount: Address<TAccountHolderRewardsPoolTokenAccount>;
+  /** Holder rewards account. */
+  holderRewards: Address<TAccountHolderRewards>;
+  /** Slash destination token account. */
+  slashDestination: Address<TAccountSlashDestination>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Owner of the holder rewards account. */
+  owner: Address<TAccountOwner>;
+  /** Slashing authority. */
+  slashingAuthority: TransactionSigner<TAccountSlashingAuthority>;
+  /** Token program. */
+  tokenProgram?: Address<TAccountTokenProgram>;
+  /** Rate history account, if the pool records one. */
+  rateHistory?: Address<TAccountRateHistory>;
+  /** Stake checkpoints account, required if the holder records one. */
+  stakeCheckpoints?: Address<TAccountStakeCheckpoints>;
+  /** Vote escrow account, required if the slash reaches a lockup counted toward voting power. */
+  voteEscrow?: Address<TAccountVoteEscrow>;
+  /** Epoch ledger account, if the pool records one. */
+  epochLedger?: Address<TAccountEpochLedger>;
+  amount: SlashInstructionDataArgs['amount'];
+};
+
+export function getSlashInstruction<
+  TAccountHolderRewardsPool extends string,
+  TAccountHolderRewardsPoolTokenAccount extends string,
+  TAccountHolderRewards extends string,
+  TAccountSlashDestination extends string,
+  TAccountMint extends string,
+  TAccountOwner extends string,
+  TAccountSlashingAuthority extends string,
+  TAccountTokenProgram extends string,
+  TAccountRateHistory extends string,
+  TAccountStakeCheckpoints extends string,
+  TAccountVoteEscrow extends string,
+  TAccountEpochLedger extends string,
+  TProgramAddress extends Address = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+>(
+  input: SlashInput<
+    TAccountHolderRewardsPool,
+    TAccountHolderRewardsPoolTokenAccount,
+    TAccountHolderRewards,
+    TAccountSlashDestination,
+    TAccountMint,
+    TAccountOwner,
+    TAccountSlashingAuthority,
+    TAccountTokenProgram,
+    TAccountRateHistory,
+    TAccountStakeCheckpoints,
+    TAccountVoteEscrow,
+    TAccountEpochLedger
+  >,
+  config?: { programAddress?: TProgramAddress }
+): SlashInstruction<
+  TProgramAddress,
+  TAccountHolderRewardsPool,
+  TAccountHolderRewardsPoolTokenAccount,
+  TAccountHolderRewards,
+  TAccountSlashDestination,
+  TAccountMint,
+  TAccountOwner,
+  TAccountSlashingAuthority,
+  TAccountTokenProgram,
+  TAccountRateHistory,
+  TAccountStakeCheckpoints,
+  TAccountVoteEscrow,
+  TAccountEpochLedger
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? PALADIN_REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: true,
+    },
+    holderRewardsPoolTokenAccount: {
+      value: input.holderRewardsPoolTokenAccount ?? null,
+      isWritable: true,
+    },
+    holderRewards: { value: input.holderRewards ?? null, isWritable: true },
+    slashDestination: {
+      value: input.slashDestination ?? null,
+      isWritable: true,
+    },
+    mint: { value: input.mint ?? null, isWritable: false },
+    owner: { value: input.owner ?? null, isWritable: false },
+    slashingAuthority: {
+      value: input.slashingAuthority ?? null,
+      isWritable: false,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    rateHistory: { value: input.rateHistory ?? null, isWritable: true },
+    stakeCheckpoints: {
+      value: input.stakeCheckpoints ?? null,
+      isWritable: true,
+    },
+    voteEscrow: { value: input.voteEscrow ?? null, isWritable: true },
+    epochLedger: { value: input.epochLedger ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.holderRewardsPoolTokenAccount),
+      getAccountMeta(accounts.holderRewards),
+      getAccountMeta(accounts.slashDestination),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.owner),
+      getAccountMeta(accounts.slashingAuthority),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.rateHistory),
+      getAccountMeta(accounts.stakeCheckpoints),
+      getAccountMeta(accounts.voteEscrow),
+      getAccountMeta(accounts.epochLedger),
+    ],
+    programAddress,
+    data: getSlashInstructionDataEncoder().encode(
+      args as SlashInstructionDataArgs
+    ),
+  } as SlashInstruction<
+    TProgramAddress,
+    TAccountHolderRewardsPool,
+    TAccountHolderRewardsPoolTokenAccount,
+    TAccountHolderRewards,
+    TAccountSlashDestination,
+    TAccountMint,
+    TAccountOwner,
+    TAccountSlashingAuthority,
+    TAccountTokenProgram,
+    TAccountRateHistory,
+    TAccountStakeCheckpoints,
+    TAccountVoteEscrow,
+    TAccountEpochLedger
+  >;
+
+  return instruction;
+}
+
+export type ParsedSlashInstruction<
+  TProgram extends string = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[0];
+    /** Holder rewards pool token account. */
+    holderRewardsPoolTokenAccount: TAccountMetas[1];
+    /** Holder rewards account. */
+    holderRewards: TAccountMetas[2];
+    /** Slash destination token account. */
+    slashDestination: TAccountMetas[3];
+    /** Token mint. */
+    mint: TAccountMetas[4];
+    /** Owner of the holder rewards account. */
+    owner: TAccountMetas[5];
+    /** Slashing authority. */
+    slashingAuthority: TAccountMetas[6];
+    /** Token program. */
+    tokenProgram: TAccountMetas[7];
+    /** Rate history account, if the pool records one. */
+    rateHistory: TAccountMetas[8];
+    /** Stake checkpoints account, required if the holder records one. */
+    stakeCheckpoints: TAccountMetas[9];
+    /** Vote escrow account, required if the slash reaches a lockup counted toward voting power. */
+    voteEscrow: TAccountMetas[10];
+    /** Epoch ledger account, if the pool records one. */
+    epochLedger: TAccountMetas[11];
+  };
+  data: SlashInstructionData;
+};
+
+export function parseSlashInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSlashInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 12) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewardsPool: getNextAccount(),
+      holderRewardsPoolTokenAccount: getNextAccount(),
+      holderRewards: getNextAccount(),
+      slashDestination: getNextAccount(),
+      mint: getNextAccount(),
+      owner: getNextAccount(),
+      slashingAuthority: getNextAccount(),
+      tokenProgram: getNextAccount(),
+      rateHistory: getNextAccount(),
+      stakeCheckpoints: getNextAccount(),
+      voteEscrow: getNextAccount(),
+      epochLedger: getNextAccount(),
+    },
+    data: getSlashInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -51,6 +51,15 @@ export type WithdrawInstruction<
   TAccountTokenProgram extends
     | string
     | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountRateHistory extends string | IAccountMeta<string> = string,
+  TAccountStakeCheckpoints extends string | IAccountMeta<string> = string,
+  TAccountReceiptMint extends string | IAccountMeta<string> = string,
+  TAccountReceiptTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountReceiptTokenProgram extends string | IAccountMeta<string> = string,
+  TAccountDestinationTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountEpochLedger extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -78,6 +87,27 @@ export type WithdrawInstruction<
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
+      TAccountRateHistory extends string
+        ? WritableAccount<TAccountRateHistory>
+        : TAccountRateHistory,
+      TAccountStakeCheckpoints extends string
+        ? WritableAccount<TAccountStakeCheckpoints>
+        : TAccountStakeCheckpoints,
+      TAccountReceiptMint extends string
+        ? WritableAccount<TAccountReceiptMint>
+        : TAccountReceiptMint,
+      TAccountReceiptTokenAccount extends string
+        ? WritableAccount<TAccountReceiptTokenAccount>
+        : TAccountReceiptTokenAccount,
+      TAccountReceiptTokenProgram extends string
+        ? ReadonlyAccount<TAccountReceiptTokenProgram>
+        : TAccountReceiptTokenProgram,
+      TAccountDestinationTokenAccount extends string
+        ? WritableAccount<TAccountDestinationTokenAccount>
+        : TAccountDestinationTokenAccount,
+      TAccountEpochLedger extends string
+        ? WritableAccount<TAccountEpochLedger>
+        : TAccountEpochLedger,
       ...TRemainingAccounts,
     ]
   >;
@@ -121,6 +151,13 @@ export type WithdrawInput<
   TAccountMint extends string = string,
   TAccountOwner extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountRateHistory extends string = string,
+  TAccountStakeCheckpoints extends string = string,
+  TAccountReceiptMint extends string = string,
+  TAccountReceiptTokenAccount extends string = string,
+  TAccountReceiptTokenProgram extends string = string,
+  TAccountDestinationTokenAccount extends string = string,
+  TAccountEpochLedger extends string = string,
 > = {
   /** Holder rewards pool account. */
   holderRewardsPool: Address<TAccountHolderRewardsPool>;
@@ -128,7 +165,7 @@ export type WithdrawInput<
   holderRewardsPoolTokenAccount: Address<TAccountHolderRewardsPoolTokenAccount>;
   /** Holder rewards account. */
   holderRewards: Address<TAccountHolderRewards>;
-  /** Token account. */
+  /** Owner's token account, unused if a destination token account is provided, in which case any account may be passed, such as the destination. */
   tokenAccount: Address<TAccountTokenAccount>;
   /** Token mint. */
   mint: Address<TAccountMint>;
@@ -136,6 +173,20 @@ export type WithdrawInput<
   owner: TransactionSigner<TAccountOwner>;
   /** token program */
   tokenProgram?: Address<TAccountTokenProgram>;
+  /** Rate history account, if the pool records one. */
+  rateHistory?: Address<TAccountRateHistory>;
+  /** Stake checkpoints account, required if the holder records one. */
+  stakeCheckpoints?: Address<TAccountStakeCheckpoints>;
+  /** Receipt token mint, required if the pool mints receipt tokens. */
+  receiptMint?: Address<TAccountReceiptMint>;
+  /** Owner's receipt token account, required if the pool mints receipt tokens. */
+  receiptTokenAccount?: Address<TAccountReceiptTokenAccount>;
+  /** Token-2022 program, required if the pool mints receipt tokens. */
+  receiptTokenProgram?: Address<TAccountReceiptTokenProgram>;
+  /** Token account receiving the withdrawn tokens instead of the owner's token account. */
+  destinationTokenAccount?: Address<TAccountDestinationTokenAccount>;
+  /** Epoch ledger account, if the pool records one. */
+  epochLedger?: Address<TAccountEpochLedger>;
   amount: WithdrawInstructionDataArgs['amount'];
 };
 
@@ -147,6 +198,13 @@ export function getWithdrawInstruction<
   TAccountMint extends string,
   TAccountOwner extends string,
   TAccountTokenProgram extends string,
+  TAccountRateHistory extends string,
+  TAccountStakeCheckpoints extends string,
+  TAccountReceiptMint extends string,
+  TAccountReceiptTokenAccount extends string,
+  TAccountReceiptTokenProgram extends string,
+  TAccountDestinationTokenAccount extends string,
+  TAccountEpochLedger extends string,
   TProgramAddress extends Address = typeof PALADIN_REWARDS_PROGRAM_ADDRESS,
 >(
   input: WithdrawInput<
@@ -156,7 +214,14 @@ export function getWithdrawInstruction<
     TAccountTokenAccount,
     TAccountMint,
     TAccountOwner,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountRateHistory,
+    TAccountStakeCheckpoints,
+    TAccountReceiptMint,
+    TAccountReceiptTokenAccount,
+    TAccountReceiptTokenProgram,
+    TAccountDestinationTokenAccount,
+    TAccountEpochLedger
   >,
   config?: { programAddress?: TProgramAddress }
 ): WithdrawInstruction<
@@ -167,7 +232,14 @@ export function getWithdrawInstruction<
   TAccountTokenAccount,
   TAccountMint,
   TAccountOwner,
-  TAccountTokenProgram
+  TAccountTokenProgram,
+  TAccountRateHistory,
+  TAccountStakeCheckpoints,
+  TAccountReceiptMint,
+  TAccountReceiptTokenAccount,
+  TAccountReceiptTokenProgram,
+  TAccountDestinationTokenAccount,
+  TAccountEpochLedger
 > {
   // Program address.
   const programAddress =
@@ -188,6 +260,25 @@ export function getWithdrawInstruction<
     mint: { value: input.mint ?? null, isWritable: false },
     owner: { value: input.owner ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    rateHistory: { value: input.rateHistory ?? null, isWritable: true },
+    stakeCheckpoints: {
+      value: input.stakeCheckpoints ?? null,
+      isWritable: true,
+    },
+    receiptMint: { value: input.receiptMint ?? null, isWritable: true },
+    receiptTokenAccount: {
+      value: input.receiptTokenAccount ?? null,
+      isWritable: true,
+    },
+    receiptTokenProgram: {
+      value: input.receiptTokenProgram ?? null,
+      isWritable: false,
+    },
+    destinationTokenAccount: {
+      value: input.destinationTokenAccount ?? null,
+      isWritable: true,
+    },
+    epochLedger: { value: input.epochLedger ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -213,6 +304,13 @@ export function getWithdrawInstruction<
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.rateHistory),
+      getAccountMeta(accounts.stakeCheckpoints),
+      getAccountMeta(accounts.receiptMint),
+      getAccountMeta(accounts.receiptTokenAccount),
+      getAccountMeta(accounts.receiptTokenProgram),
+      getAccountMeta(accounts.destinationTokenAccount),
+      getAccountMeta(accounts.epochLedger),
     ],
     programAddress,
     data: getWithdrawInstructionDataEncoder().encode(
@@ -226,7 +324,14 @@ export function getWithdrawInstruction<
     TAccountTokenAccount,
     TAccountMint,
     TAccountOwner,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountRateHistory,
+    TAccountStakeCheckpoints,
+    TAccountReceiptMint,
+    TAccountReceiptTokenAccount,
+    TAccountReceiptTokenProgram,
+    TAccountDestinationTokenAccount,
+    TAccountEpochLedger
   >;
 
   return instruction;
@@ -244,7 +349,7 @@ export type ParsedWithdrawInstruction<
     holderRewardsPoolTokenAccount: TAccountMetas[1];
     /** Holder rewards account. */
     holderRewards: TAccountMetas[2];
-    /** Token account. */
+    /** Owner's token account, unused if a destination token account is provided, in which case any account may be passed, such as the destination. */
     tokenAccount: TAccountMetas[3];
     /** Token mint. */
     mint: TAccountMetas[4];
@@ -252,6 +357,20 @@ export type ParsedWithdrawInstruction<
     owner: TAccountMetas[5];
     /** token program */
     tokenProgram: TAccountMetas[6];
+    /** Rate history account, if the pool records one. */
+    rateHistory: TAccountMetas[7];
+    /** Stake checkpoints account, required if the holder records one. */
+    stakeCheckpoints: TAccountMetas[8];
+    /** Receipt token mint, required if the pool mints receipt tokens. */
+    receiptMint: TAccountMetas[9];
+    /** Owner's receipt token account, required if the pool mints receipt tokens. */
+    receiptTokenAccount: TAccountMetas[10];
+    /** Token-2022 program, required if the pool mints receipt tokens. */
+    receiptTokenProgram: TAccountMetas[11];
+    /** Token account receiving the withdrawn tokens instead of the owner's token account. */
+    destinationTokenAccount: TAccountMetas[12];
+    /** Epoch ledger account, if the pool records one. */
+    epochLedger: TAccountMetas[13];
   };
   data: WithdrawInstructionData;
 };
@@ -264,7 +383,7 @@ export function parseWithdrawInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedWithdrawInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 14) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -284,6 +403,13 @@ export function parseWithdrawInstruction<
       mint: getNextAccount(),
       owner: getNextAccount(),
       tokenProgram: getNextAccount(),
+      rateHistory: getNextAccount(),
+      stakeCheckpoints: getNextAccount(),
+      receiptMint: getNextAccount(),
+      receiptTokenAccount: getNextAccount(),
+      receiptTokenProgram: getNextAccount(),
+      destinationTokenAccount: getNextAccount(),
+      epochLedger: getNextAccount(),
     },
     data: getWithdrawInstructionDataDecoder().decode(instruction.data),
   };
@@ -0,0 +1,39 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  getAddressEncoder,
+  getProgramDerivedAddress,
+  getUtf8Encoder,
+  type Address,
+  type ProgramDerivedAddress,
+} from '@solana/web3.js';
+
+export type AccessEntrySeeds = {
+  /** Holder rewards pool */
+  holderRewardsPool: Address;
+  /** Wallet */
+  wallet: Address;
+};
+
+export async function findAccessEntryPda(
+  seeds: AccessEntrySeeds,
+  config: { programAddress?: Address | undefined } = {}
+): Promise<ProgramDerivedAddress> {
+  const {
+    programAddress = '7LdHk6jnrY4kJW79mVXshTzduvgn3yz4hZzHpzTbt7Ph' as Address<'7LdHk6jnrY4kJW79mVXshTzduvgn3yz4hZzHpzTbt7Ph'>,
+  } = config;
+  return await getProgramDerivedAddress({
+    programAddress,
+    seeds: [
+      getUtf8Encoder().encode('access_entry'),
+      getAddressEncoder().encode(seeds.holderRewardsPool),
+      getAddressEncoder().encode(seeds.wallet),
+    ],
+  });
+}
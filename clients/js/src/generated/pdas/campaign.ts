@@ -0,0 +1,39 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  getAddressEncoder,
+  getProgramDerivedAddress,
+  getUtf8Encoder,
+  type Address,
+  type ProgramDerivedAddress,
+} from '@solana/web3.js';
+
+export type CampaignSeeds = {
+  /** Holder rewards pool */
+  holderRewardsPool: Address;
+  /** Funder */
+  funder: Address;
+};
+
+export async function findCampaignPda(
+  seeds: CampaignSeeds,
+  config: { programAddress?: Address | undefined } = {}
+): Promise<ProgramDerivedAddress> {
+  const {
+    programAddress = '7LdHk6jnrY4kJW79mVXshTzduvgn3yz4hZzHpzTbt7Ph' as Address<'7LdHk6jnrY4kJW79mVXshTzduvgn3yz4hZzHpzTbt7Ph'>,
+  } = config;
+  return await getProgramDerivedAddress({
+    programAddress,
+    seeds: [
+      getUtf8Encoder().encode('campaign'),
+      getAddressEncoder().encode(seeds.holderRewardsPool),
+      getAddressEncoder().encode(seeds.funder),
+    ],
+  });
+}
@@ -0,0 +1,36 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  getAddressEncoder,
+  getProgramDerivedAddress,
+  getUtf8Encoder,
+  type Address,
+  type ProgramDerivedAddress,
+} from '@solana/web3.js';
+
+export type EpochLedgerSeeds = {
+  /** Holder rewards pool */
+  holderRewardsPool: Address;
+};
+
+export async function findEpochLedgerPda(
+  seeds: EpochLedgerSeeds,
+  config: { programAddress?: Address | undefined } = {}
+): Promise<ProgramDerivedAddress> {
+  const {
+    programAddress = '7LdHk6jnrY4kJW79mVXshTzduvgn3yz4hZzHpzTbt7Ph' as Address<'7LdHk6jnrY4kJW79mVXshTzduvgn3yz4hZzHpzTbt7Ph'>,
+  } = config;
+  return await getProgramDerivedAddress({
+    programAddress,
+    seeds: [
+      getUtf8Encoder().encode('epoch_ledger'),
+      getAddressEncoder().encode(seeds.holderRewardsPool),
+    ],
+  });
+}
@@ -6,5 +6,12 @@
  * @see https://github.com/kinobi-so/kinobi
  */
 
+export * from './accessEntry';
+export * from './campaign';
+export * from './epochLedger';
 export * from './holderRewards';
 export * from './holderRewardsPool';
+export * from './lockupTiers';
+export * from './rateHistory';
+export * from './stakeCheckpoints';
+export * from './voteEscrow';
@@ -13,11 +13,37 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/web3.js';
 import {
+  type ParsedCheckpointVotingPowerInstruction,
   type ParsedCloseHolderRewardsInstruction,
+  type ParsedCreateCampaignInstruction,
   type ParsedDepositInstruction,
+  type ParsedDistributeRewardsInstruction,
+  type ParsedEmergencyWithdrawInstruction,
+  type ParsedGetPendingRewardsInstruction,
+  type ParsedGetStakeAtInstruction,
+  type ParsedGetVotingPowerInstruction,
   type ParsedHarvestRewardsInstruction,
+  type ParsedInitializeEpochLedgerInstruction,
   type ParsedInitializeHolderRewardsInstruction,
   type ParsedInitializeHolderRewardsPoolInstruction,
+  type ParsedInitializeRateHistoryInstruction,
+  type ParsedInitializeReceiptMintInstruction,
+  type ParsedInitializeStakeCheckpointsInstruction,
+  type ParsedInitializeVoteEscrowInstruction,
+  type ParsedLockInstruction,
+  type ParsedMigrateHolderRewardsInstruction,
+  type ParsedMigrateHolderRewardsPoolInstruction,
+  type ParsedReclaimCampaignRewardsInstruction,
+  type ParsedRecoverExcessTokensInstruction,
+  type ParsedReleaseCampaignRewardsInstruction,
+  type ParsedReleaseLockupInstruction,
+  type ParsedSetAccessEntryInstruction,
+  type ParsedSetAccessListInstruction,
+  type ParsedSetDepositLimitsInstruction,
+  type ParsedSetEarlyWithdrawalPenaltyInstruction,
+  type ParsedSetLockupTiersInstruction,
+  type ParsedSetSlashingConfigInstruction,
+  type ParsedSlashInstruction,
   type ParsedWithdrawInstruction,
 } from '../instructions';
 
@@ -27,6 +53,13 @@ export const PALADIN_REWARDS_PROGRAM_ADDRESS =
 export enum PaladinRewardsAccount {
   HolderRewards,
   HolderRewardsPool,
+  RateHistory,
+  LockupTiers,
+  VoteEscrow,
+  StakeCheckpoints,
+  AccessEntry,
+  Campaign,
+  EpochLedger,
 }
 
 export enum PaladinRewardsInstruction {
@@ -36,6 +69,32 @@ export enum PaladinRewardsInstruction {
   CloseHolderRewards,
   Deposit,
   Withdraw,
+  RecoverExcessTokens,
+  GetPendingRewards,
+  MigrateHolderRewardsPool,
+  InitializeRateHistory,
+  SetLockupTiers,
+  Lock,
+  ReleaseLockup,
+  InitializeVoteEscrow,
+  CheckpointVotingPower,
+  GetVotingPower,
+  InitializeStakeCheckpoints,
+  GetStakeAt,
+  SetEarlyWithdrawalPenalty,
+  SetDepositLimits,
+  SetAccessList,
+  SetAccessEntry,
+  InitializeReceiptMint,
+  EmergencyWithdraw,
+  SetSlashingConfig,
+  Slash,
+  CreateCampaign,
+  ReleaseCampaignRewards,
+  ReclaimCampaignRewards,
+  DistributeRewards,
+  InitializeEpochLedger,
+  MigrateHolderRewards,
 }
 
 export function identifyPaladinRewardsInstruction(
@@ -60,6 +119,84 @@ export function identifyPaladinRewardsInstruction(
   if (containsBytes(data, getU8Encoder().encode(5), 0)) {
     return PaladinRewardsInstruction.Withdraw;
   }
+  if (containsBytes(data, getU8Encoder().encode(6), 0)) {
+    return PaladinRewardsInstruction.RecoverExcessTokens;
+  }
+  if (containsBytes(data, getU8Encoder().encode(7), 0)) {
+    return PaladinRewardsInstruction.GetPendingRewards;
+  }
+  if (containsBytes(data, getU8Encoder().encode(8), 0)) {
+    return PaladinRewardsInstruction.MigrateHolderRewardsPool;
+  }
+  if (containsBytes(data, getU8Encoder().encode(9), 0)) {
+    return PaladinRewardsInstruction.InitializeRateHistory;
+  }
+  if (containsBytes(data, getU8Encoder().encode(10), 0)) {
+    return PaladinRewardsInstruction.SetLockupTiers;
+  }
+  if (containsBytes(data, getU8Encoder().encode(11), 0)) {
+    return PaladinRewardsInstruction.Lock;
+  }
+  if (containsBytes(data, getU8Encoder().encode(12), 0)) {
+    return PaladinRewardsInstruction.ReleaseLockup;
+  }
+  if (containsBytes(data, getU8Encoder().encode(13), 0)) {
+    return PaladinRewardsInstruction.InitializeVoteEscrow;
+  }
+  if (containsBytes(data, getU8Encoder().encode(14), 0)) {
+    return PaladinRewardsInstruction.CheckpointVotingPower;
+  }
+  if (containsBytes(data, getU8Encoder().encode(15), 0)) {
+    return PaladinRewardsInstruction.GetVotingPower;
+  }
+  if (containsBytes(data, getU8Encoder().encode(16), 0)) {
+    return PaladinRewardsInstruction.InitializeStakeCheckpoints;
+  }
+  if (containsBytes(data, getU8Encoder().encode(17), 0)) {
+    return PaladinRewardsInstruction.GetStakeAt;
+  }
+  if (containsBytes(data, getU8Encoder().encode(18), 0)) {
+    return PaladinRewardsInstruction.SetEarlyWithdrawalPenalty;
+  }
+  if (containsBytes(data, getU8Encoder().encode(19), 0)) {
+    return PaladinRewardsInstruction.SetDepositLimits;
+  }
+  if (containsBytes(data, getU8Encoder().encode(20), 0)) {
+    return PaladinRewardsInstruction.SetAccessList;
+  }
+  if (containsBytes(data, getU8Encoder().encode(21), 0)) {
+    return PaladinRewardsInstruction.SetAccessEntry;
+  }
+  if (containsBytes(data, getU8Encoder().encode(22), 0)) {
+    return PaladinRewardsInstruction.InitializeReceiptMint;
+  }
+  if (containsBytes(data, getU8Encoder().encode(23), 0)) {
+    return PaladinRewardsInstruction.EmergencyWithdraw;
+  }
+  if (containsBytes(data, getU8Encoder().encode(24), 0)) {
+    return PaladinRewardsInstruction.SetSlashingConfig;
+  }
+  if (containsBytes(data, getU8Encoder().encode(25), 0)) {
+    return PaladinRewardsInstruction.Slash;
+  }
+  if (containsBytes(data, getU8Encoder().encode(26), 0)) {
+    return PaladinRewardsInstruction.CreateCampaign;
+  }
+  if (containsBytes(data, getU8Encoder().encode(27), 0)) {
+    return PaladinRewardsInstruction.ReleaseCampaignRewards;
+  }
+  if (containsBytes(data, getU8Encoder().encode(28), 0)) {
+    return PaladinRewardsInstruction.ReclaimCampaignRewards;
+  }
+  if (containsBytes(data, getU8Encoder().encode(29), 0)) {
+    return PaladinRewardsInstruction.DistributeRewards;
+  }
+  if (containsBytes(data, getU8Encoder().encode(30), 0)) {
+    return PaladinRewardsInstruction.InitializeEpochLedger;
+  }
+  if (containsBytes(data, getU8Encoder().encode(31), 0)) {
+    return PaladinRewardsInstruction.MigrateHolderRewards;
+  }
   throw new Error(
     'The provided instruction could not be identified as a paladinRewards instruction.'
   );
@@ -85,4 +222,82 @@ export type ParsedPaladinRewardsInstruction<
     } & ParsedDepositInstruction<TProgram>)
   | ({
       instructionType: PaladinRewardsInstruction.Withdraw;
-    } & ParsedWithdrawInstruction<TProgram>);
+    } & ParsedWithdrawInstruction<TProgram>)
+  | ({
+      instructionType: PaladinRewardsInstruction.RecoverExcessTokens;
+    } & ParsedRecoverExcessTokensInstruction<TProgram>)
+  | ({
+      instructionType: PaladinRewardsInstruction.GetPendingRewards;
+    } & ParsedGetPendingRewardsInstruction<TProgram>)
+  | ({
+      instructionType: PaladinRewardsInstruction.MigrateHolderRewardsPool;
+    } & ParsedMigrateHolderRewardsPoolInstruction<TProgram>)
+  | ({
+      instructionType: PaladinRewardsInstruction.InitializeRateHistory;
+    } & ParsedInitializeRateHistoryInstruction<TProgram>)
+  | ({
+      instructionType: PaladinRewardsInstruction.SetLockupTiers;
+    } & ParsedSetLockupTiersInstruction<TProgram>)
+  | ({
+      instructionType: PaladinRewardsInstruction.Lock;
+    } & ParsedLockInstruction<TProgram>)
+  | ({
+      instructionType: PaladinRewardsInstruction.ReleaseLockup;
+    } & ParsedReleaseLockupInstruction<TProgram>)
+  | ({
+      instructionType: PaladinRewardsInstruction.InitializeVoteEscrow;
+    } & ParsedInitializeVoteEscrowInstruction<TProgram>)
+  | ({
+      instructionType: PaladinRewardsInstruction.CheckpointVotingPower;
+    } & ParsedCheckpointVotingPowerInstruction<TProgram>)
+  | ({
+      instructionType: PaladinRewardsInstruction.GetVotingPower;
+    } & ParsedGetVotingPowerInstruction<TProgram>)
+  | ({
+      instructionType: PaladinRewardsInstruction.InitializeStakeCheckpoints;
+    } & ParsedInitializeStakeCheckpointsInstruction<TProgram>)
+  | ({
+      instructionType: PaladinRewardsInstruction.GetStakeAt;
+    } & ParsedGetStakeAtInstruction<TProgram>)
+  | ({
+      instructionType: PaladinRewardsInstruction.SetEarlyWithdrawalPenalty;
+    } & ParsedSetEarlyWithdrawalPenaltyInstruction<TProgram>)
+  | ({
+      instructionType: PaladinRewardsInstruction.SetDepositLimits;
+    } & ParsedSetDepositLimitsInstruction<TProgram>)
+  | ({
+      instructionType: PaladinRewardsInstruction.SetAccessList;
+    } & ParsedSetAccessListInstruction<TProgram>)
+  | ({
+      instructionType: PaladinRewardsInstruction.SetAccessEntry;
+    } & ParsedSetAccessEntryInstruction<TProgram>)
+  | ({
+      instructionType: PaladinRewardsInstruction.InitializeReceiptMint;
+    } & ParsedInitializeReceiptMintInstruction<TProgram>)
+  | ({
+      instructionType: PaladinRewardsInstruction.EmergencyWithdraw;
+    } & ParsedEmergencyWithdrawInstruction<TProgram>)
+  | ({
+      instructionType: PaladinRewardsInstruction.SetSlashingConfig;
+    } & ParsedSetSlashingConfigInstruction<TProgram>)
+  | ({
+      instructionType: PaladinRewardsInstruction.Slash;
+    } & ParsedSlashInstruction<TProgram>)
+  | ({
+      instructionType: PaladinRewardsInstruction.CreateCampaign;
+    } & ParsedCreateCampaignInstruction<TProgram>)
+  | ({
+      instructionType: PaladinRewardsInstruction.ReleaseCampaignRewards;
+    } & ParsedReleaseCampaignRewardsInstruction<TProgram>)
+  | ({
+      instructionType: PaladinRewardsInstruction.ReclaimCampaignRewards;
+    } & ParsedReclaimCampaignRewardsInstruction<TProgram>)
+  | ({
+      instructionType: PaladinRewardsInstruction.DistributeRewards;
+    } & ParsedDistributeRewardsInstruction<TProgram>)
+  | ({
+      instructionType: PaladinRewardsInstruction.InitializeEpochLedger;
+    } & ParsedInitializeEpochLedgerInstruction<TProgram>)
+  | ({
+      instructionType: PaladinRewardsInstruction.MigrateHolderRewards;
+    } & ParsedMigrateHolderRewardsInstruction<TProgram>);
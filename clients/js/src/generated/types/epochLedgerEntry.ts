@@ -0,0 +1,58 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU128Decoder,
+  getU128Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
+} from '@solana/web3.js';
+
+export type EpochLedgerEntry = {
+  rateDelta: bigint;
+  epoch: bigint;
+  lamportsReceived: bigint;
+};
+
+export type EpochLedgerEntryArgs = {
+  rateDelta: number | bigint;
+  epoch: number | bigint;
+  lamportsReceived: number | bigint;
+};
+
+export function getEpochLedgerEntryEncoder(): Encoder<EpochLedgerEntryArgs> {
+  return getStructEncoder([
+    ['rateDelta', getU128Encoder()],
+    ['epoch', getU64Encoder()],
+    ['lamportsReceived', getU64Encoder()],
+  ]);
+}
+
+export function getEpochLedgerEntryDecoder(): Decoder<EpochLedgerEntry> {
+  return getStructDecoder([
+    ['rateDelta', getU128Decoder()],
+    ['epoch', getU64Decoder()],
+    ['lamportsReceived', getU64Decoder()],
+  ]);
+}
+
+export function getEpochLedgerEntryCodec(): Codec<
+  EpochLedgerEntryArgs,
+  EpochLedgerEntry
+> {
+  return combineCodec(
+    getEpochLedgerEntryEncoder(),
+    getEpochLedgerEntryDecoder()
+  );
+}
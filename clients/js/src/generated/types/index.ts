@@ -0,0 +1,12 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+export * from './epochLedgerEntry';
+export * from './lockupTier';
+export * from './rateHistoryEntry';
+export * from './stakeCheckpoint';
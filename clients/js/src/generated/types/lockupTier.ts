@@ -0,0 +1,43 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
+} from '@solana/web3.js';
+
+export type LockupTier = { duration: bigint; multiplierBps: bigint };
+
+export type LockupTierArgs = {
+  duration: number | bigint;
+  multiplierBps: number | bigint;
+};
+
+export function getLockupTierEncoder(): Encoder<LockupTierArgs> {
+  return getStructEncoder([
+    ['duration', getU64Encoder()],
+    ['multiplierBps', getU64Encoder()],
+  ]);
+}
+
+export function getLockupTierDecoder(): Decoder<LockupTier> {
+  return getStructDecoder([
+    ['duration', getU64Decoder()],
+    ['multiplierBps', getU64Decoder()],
+  ]);
+}
+
+export function getLockupTierCodec(): Codec<LockupTierArgs, LockupTier> {
+  return combineCodec(getLockupTierEncoder(), getLockupTierDecoder());
+}
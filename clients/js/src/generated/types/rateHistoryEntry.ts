@@ -0,0 +1,71 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
+  getBytesDecoder,
+  getBytesEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU128Decoder,
+  getU128Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type ReadonlyUint8Array,
+} from '@solana/web3.js';
+
+export type RateHistoryEntry = {
+  accumulatedRewardsPerToken: bigint;
+  slot: bigint;
+  epoch: bigint;
+  totalDeposited: bigint;
+  padding: ReadonlyUint8Array;
+};
+
+export type RateHistoryEntryArgs = {
+  accumulatedRewardsPerToken: number | bigint;
+  slot: number | bigint;
+  epoch: number | bigint;
+  totalDeposited: number | bigint;
+  padding: ReadonlyUint8Array;
+};
+
+export function getRateHistoryEntryEncoder(): Encoder<RateHistoryEntryArgs> {
+  return getStructEncoder([
+    ['accumulatedRewardsPerToken', getU128Encoder()],
+    ['slot', getU64Encoder()],
+    ['epoch', getU64Encoder()],
+    ['totalDeposited', getU64Encoder()],
+    ['padding', fixEncoderSize(getBytesEncoder(), 8)],
+  ]);
+}
+
+export function getRateHistoryEntryDecoder(): Decoder<RateHistoryEntry> {
+  return getStructDecoder([
+    ['accumulatedRewardsPerToken', getU128Decoder()],
+    ['slot', getU64Decoder()],
+    ['epoch', getU64Decoder()],
+    ['totalDeposited', getU64Decoder()],
+    ['padding', fixDecoderSize(getBytesDecoder(), 8)],
+  ]);
+}
+
+export function getRateHistoryEntryCodec(): Codec<
+  RateHistoryEntryArgs,
+  RateHistoryEntry
+> {
+  return combineCodec(
+    getRateHistoryEntryEncoder(),
+    getRateHistoryEntryDecoder()
+  );
+}
@@ -0,0 +1,46 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
+} from '@solana/web3.js';
+
+export type StakeCheckpoint = { slot: bigint; deposited: bigint };
+
+export type StakeCheckpointArgs = {
+  slot: number | bigint;
+  deposited: number | bigint;
+};
+
+export function getStakeCheckpointEncoder(): Encoder<StakeCheckpointArgs> {
+  return getStructEncoder([
+    ['slot', getU64Encoder()],
+    ['deposited', getU64Encoder()],
+  ]);
+}
+
+export function getStakeCheckpointDecoder(): Decoder<StakeCheckpoint> {
+  return getStructDecoder([
+    ['slot', getU64Decoder()],
+    ['deposited', getU64Decoder()],
+  ]);
+}
+
+export function getStakeCheckpointCodec(): Codec<
+  StakeCheckpointArgs,
+  StakeCheckpoint
+> {
+  return combineCodec(getStakeCheckpointEncoder(), getStakeCheckpointDecoder());
+}
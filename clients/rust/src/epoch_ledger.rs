@@ -0,0 +1,102 @@
+//! Helpers for reading a holder rewards pool's epoch ledger.
+//!
+//! Each entry records the rewards the pool accrued during an epoch: the
+//! lamports it received, and the increase in its
+//! `accumulated_rewards_per_token` exchange rate. The increase is what a
+//! single deposited token earned in that epoch, which gives the epoch's yield.
+
+use crate::{
+    accounts::EpochLedger, math::REWARDS_PER_TOKEN_SCALING_FACTOR, types::EpochLedgerEntry,
+};
+
+impl EpochLedger {
+    /// Iterates over the recorded entries, from oldest to newest.
+    pub fn iter(&self) -> impl Iterator<Item = &EpochLedgerEntry> {
+        let capacity = self.entries.len();
+        let len = std::cmp::min(self.len as usize, capacity);
+        let start = (self.next_index as usize + capacity - len) % capacity;
+
+        (0..len).map(move |offset| &self.entries[(start + offset) % capacity])
+    }
+
+    /// The entry recorded for `epoch`, if any.
+    ///
+    /// Epochs in which the pool accrued nothing, and epochs old enough to
+    /// have been overwritten, have no entry.
+    pub fn entry(&self, epoch: u64) -> Option<&EpochLedgerEntry> {
+        self.iter().find(|entry| entry.epoch == epoch)
+    }
+}
+
+/// The yield earned by deposits during an entry's epoch, as a fraction
+/// (`0.001` is 0.1%).
+///
+/// `lamports_per_token` is the value of one token, in the mint's smallest
+/// unit, expressed in lamports.
+///
+/// Returns `None` if `lamports_per_token` is not positive.
+pub fn epoch_yield(entry: &EpochLedgerEntry, lamports_per_token: f64) -> Option<f64> {
+    if lamports_per_token <= 0.0 {
+        return None;
+    }
+
+    let rewards_per_token = entry.rate_delta as f64 / REWARDS_PER_TOKEN_SCALING_FACTOR as f64;
+
+    Some(rewards_per_token / lamports_per_token)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(epoch: u64, rate_delta: u128) -> EpochLedgerEntry {
+        EpochLedgerEntry {
+            rate_delta,
+            epoch,
+            lamports_received: 0,
+        }
+    }
+
+    fn ledger(next_index: u64, len: u64, epochs: &[u64]) -> EpochLedger {
+        let mut entries: [EpochLedgerEntry; 64] = std::array::from_fn(|_| entry(0, 0));
+        for (index, epoch) in epochs.iter().enumerate() {
+            entries[index] = entry(*epoch, 0);
+        }
+        EpochLedger {
+            last_accumulated_rewards_per_token: 0,
+            last_total_rewards_received: 0,
+            next_index,
+            len,
+            bump_seed: 0,
+            padding: [0; 7],
+            entries,
+        }
+    }
+
+    #[test]
+    fn iter_after_wrapping() {
+        // Epochs 1..=65 were recorded, so the oldest was overwritten.
+        let mut epochs = vec![65];
+        epochs.extend(2..=64);
+        let ledger = ledger(1, 64, &epochs);
+
+        let iterated: Vec<u64> = ledger.iter().map(|entry| entry.epoch).collect();
+        assert_eq!(iterated, (2..=65).collect::<Vec<u64>>());
+    }
+
+    #[test]
+    fn entry_for_epoch() {
+        let ledger = ledger(3, 3, &[10, 11, 13]);
+        assert_eq!(ledger.entry(11).unwrap().epoch, 11);
+        assert_eq!(ledger.entry(12), None);
+        assert_eq!(ledger.entry(0), None);
+    }
+
+    #[test]
+    fn yield_for_epoch() {
+        // 0.01 lamports per token, for a token worth 2 lamports.
+        let entry = entry(5, REWARDS_PER_TOKEN_SCALING_FACTOR / 100);
+        assert!((epoch_yield(&entry, 2.0).unwrap() - 0.005).abs() < 1e-12);
+        assert_eq!(epoch_yield(&entry, 0.0), None);
+    }
+}
@@ -0,0 +1,221 @@
+//! Decoders for events emitted by the Paladin Rewards program.
+//!
+//! The program logs each event with `sol_log_data` as a single buffer: a
+//! one-byte schema version followed by the Borsh-serialized event.
+
+use {
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::pubkey::Pubkey,
+};
+
+/// The event schema version understood by these decoders.
+pub const EVENT_VERSION: u8 = 1;
+
+/// Events emitted by the Paladin Rewards program.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, Eq, PartialEq)]
+pub enum PaladinRewardsEvent {
+    /// A holder rewards pool was initialized.
+    PoolInitialized {
+        holder_rewards_pool: Pubkey,
+        mint: Pubkey,
+        authority: Pubkey,
+    },
+    /// A holder rewards account was initialized.
+    HolderInitialized {
+        holder_rewards_pool: Pubkey,
+        owner: Pubkey,
+        last_accumulated_rewards_per_token: u128,
+    },
+    /// Tokens were deposited into the pool.
+    Deposited {
+        holder_rewards_pool: Pubkey,
+        owner: Pubkey,
+        amount: u64,
+        deposited: u64,
+    },
+    /// Tokens were withdrawn from the pool.
+    Withdrawn {
+        holder_rewards_pool: Pubkey,
+        owner: Pubkey,
+        amount: u64,
+        deposited: u64,
+    },
+    /// Rewards were paid out to a holder.
+    Harvested {
+        holder_rewards_pool: Pubkey,
+        owner: Pubkey,
+        amount: u64,
+    },
+    /// Lamports received by the pool were accounted for in its rate.
+    RewardsAccrued {
+        holder_rewards_pool: Pubkey,
+        inflow: u64,
+        accumulated_rewards_per_token: u128,
+    },
+    /// A holder rewards account was closed.
+    HolderClosed {
+        holder_rewards_pool: Pubkey,
+        owner: Pubkey,
+    },
+    /// Tokens in excess of the pool's deposits were recovered.
+    ExcessTokensRecovered {
+        holder_rewards_pool: Pubkey,
+        destination: Pubkey,
+        amount: u64,
+    },
+    /// A holder rewards pool was migrated to the current layout.
+    PoolMigrated { holder_rewards_pool: Pubkey },
+    /// A rate history account was initialized for a holder rewards pool.
+    RateHistoryInitialized { holder_rewards_pool: Pubkey },
+    /// A holder rewards pool's lockup tiers were set.
+    LockupTiersSet { holder_rewards_pool: Pubkey },
+    /// Deposited tokens were locked, or an existing lockup was extended.
+    Locked {
+        holder_rewards_pool: Pubkey,
+        owner: Pubkey,
+        locked_amount: u64,
+        unlock_timestamp: i64,
+        multiplier_bps: u64,
+    },
+    /// An expired lockup was released, returning its tokens to `1x` weight.
+    LockupReleased {
+        holder_rewards_pool: Pubkey,
+        owner: Pubkey,
+        amount: u64,
+    },
+    /// A vote escrow account was initialized for a holder rewards pool.
+    VoteEscrowInitialized {
+        holder_rewards_pool: Pubkey,
+        reward_weighting: bool,
+    },
+    /// A holder's voting power was checkpointed, re-weighting their lockup
+    /// if rewards follow voting power.
+    VotingPowerCheckpointed {
+        holder_rewards_pool: Pubkey,
+        owner: Pubkey,
+        voting_power: u64,
+        multiplier_bps: u64,
+    },
+    /// A holder started recording stake checkpoints.
+    StakeCheckpointsInitialized { owner: Pubkey },
+    /// A holder rewards pool's early withdrawal penalty was set.
+    EarlyWithdrawalPenaltySet {
+        holder_rewards_pool: Pubkey,
+        penalty_bps: u16,
+        period_slots: u32,
+    },
+    /// A holder withdrawing early forfeited part of their pending rewards to
+    /// the other holders.
+    EarlyWithdrawalPenalized {
+        holder_rewards_pool: Pubkey,
+        owner: Pubkey,
+        forfeited: u64,
+    },
+    /// A holder rewards pool's deposit limits were set.
+    DepositLimitsSet {
+        holder_rewards_pool: Pubkey,
+        max_total_deposit: u64,
+        max_holder_deposit: u64,
+        min_deposit: u64,
+    },
+    /// A holder rewards pool's access list mode was set.
+    AccessListSet {
+        holder_rewards_pool: Pubkey,
+        access_list: u8,
+    },
+    /// A wallet's status in a holder rewards pool's access list was set.
+    AccessEntrySet {
+        holder_rewards_pool: Pubkey,
+        wallet: Pubkey,
+        status: u8,
+    },
+    /// A holder rewards pool's receipt token mint was initialized.
+    ReceiptMintInitialized {
+        holder_rewards_pool: Pubkey,
+        receipt_mint: Pubkey,
+    },
+    /// Receipt tokens were transferred, moving their deposit between holders.
+    ReceiptsTransferred {
+        holder_rewards_pool: Pubkey,
+        source_owner: Pubkey,
+        destination_owner: Pubkey,
+        amount: u64,
+    },
+    /// Withdrawn tokens were sent to a token account other than the owner's.
+    WithdrawalRedirected {
+        holder_rewards_pool: Pubkey,
+        owner: Pubkey,
+        destination: Pubkey,
+        amount: u64,
+    },
+    /// Tokens were withdrawn from the pool without paying out rewards,
+    /// leaving them owed to the holder.
+    EmergencyWithdrawn {
+        holder_rewards_pool: Pubkey,
+        owner: Pubkey,
+        amount: u64,
+        deposited: u64,
+        unharvested_rewards: u64,
+    },
+    /// A pool's slashing authority and slash destination were set.
+    SlashingConfigSet {
+        holder_rewards_pool: Pubkey,
+        slashing_authority: Pubkey,
+        slash_destination: Pubkey,
+    },
+    /// A holder's deposit was slashed.
+    Slashed {
+        holder_rewards_pool: Pubkey,
+        owner: Pubkey,
+        amount: u64,
+        deposited: u64,
+    },
+    /// A reward campaign was funded.
+    CampaignCreated {
+        holder_rewards_pool: Pubkey,
+        campaign: Pubkey,
+        funder: Pubkey,
+        amount: u64,
+        start_slot: u64,
+        end_slot: u64,
+    },
+    /// A campaign's unlocked rewards were released into the pool, or set
+    /// aside for the funder if nothing was deposited.
+    CampaignRewardsReleased {
+        holder_rewards_pool: Pubkey,
+        campaign: Pubkey,
+        released: u64,
+        unspent: u64,
+    },
+    /// A campaign ended and its unspent rewards were returned to the funder.
+    CampaignReclaimed {
+        holder_rewards_pool: Pubkey,
+        campaign: Pubkey,
+        funder: Pubkey,
+        unspent: u64,
+    },
+    /// Rewards were distributed to the pool by a funder.
+    RewardsDistributed {
+        holder_rewards_pool: Pubkey,
+        funder: Pubkey,
+        amount: u64,
+        memo: [u8; 32],
+    },
+    /// An epoch ledger account was initialized for a holder rewards pool.
+    EpochLedgerInitialized { holder_rewards_pool: Pubkey },
+    /// A holder rewards account was migrated to the current layout.
+    HolderRewardsMigrated { holder_rewards: Pubkey },
+}
+
+impl PaladinRewardsEvent {
+    /// Decodes a single `sol_log_data` buffer emitted by the program.
+    ///
+    /// Returns `None` if the buffer is not a Paladin Rewards event of a
+    /// supported schema version.
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        match data.split_first() {
+            Some((&EVENT_VERSION, rest)) => Self::try_from_slice(rest).ok(),
+            _ => None,
+        }
+    }
+}
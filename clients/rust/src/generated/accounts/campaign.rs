@@ -0,0 +1,119 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+
+use {
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Campaign {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub holder_rewards_pool: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub start_slot: u64,
+    pub end_slot: u64,
+    pub released: u64,
+    pub unspent: u64,
+    pub last_release_slot: u64,
+    pub zero_weight_slots: u64,
+    pub bump_seed: u8,
+    pub padding: [u8; 7],
+}
+
+impl Campaign {
+    pub const LEN: usize = 128;
+
+    /// Prefix values used to generate a PDA for this account.
+    ///
+    /// Values are positional and appear in the following order:
+    ///
+    ///   0. `Campaign::PREFIX`
+    ///   1. holder_rewards_pool (`Pubkey`)
+    ///   2. funder (`Pubkey`)
+    pub const PREFIX: &'static [u8] = "campaign".as_bytes();
+
+    pub fn create_pda(
+        holder_rewards_pool: Pubkey,
+        funder: Pubkey,
+        bump: u8,
+    ) -> Result<solana_program::pubkey::Pubkey, solana_program::pubkey::PubkeyError> {
+        solana_program::pubkey::Pubkey::create_program_address(
+            &[
+                "campaign".as_bytes(),
+                holder_rewards_pool.as_ref(),
+                funder.as_ref(),
+                &[bump],
+            ],
+            &crate::PALADIN_REWARDS_ID,
+        )
+    }
+
+    pub fn find_pda(
+        holder_rewards_pool: &Pubkey,
+        funder: &Pubkey,
+    ) -> (solana_program::pubkey::Pubkey, u8) {
+        solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                "campaign".as_bytes(),
+                holder_rewards_pool.as_ref(),
+                funder.as_ref(),
+            ],
+            &crate::PALADIN_REWARDS_ID,
+        )
+    }
+
+    #[inline(always)]
+    pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
+        let mut data = data;
+        Self::deserialize(&mut data)
+    }
+}
+
+impl<'a> TryFrom<&solana_program::account_info::AccountInfo<'a>> for Campaign {
+    type Error = std::io::Error;
+
+    fn try_from(
+        account_info: &solana_program::account_info::AccountInfo<'a>,
+    ) -> Result<Self, Self::Error> {
+        let mut data: &[u8] = &(*account_info.data).borrow();
+        Self::deserialize(&mut data)
+    }
+}
+
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountDeserialize for Campaign {
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        Ok(Self::deserialize(buf)?)
+    }
+}
+
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountSerialize for Campaign {}
+
+#[cfg(feature = "anchor")]
+impl anchor_lang::Owner for Campaign {
+    fn owner() -> Pubkey {
+        crate::PALADIN_REWARDS_ID
+    }
+}
+
+#[cfg(feature = "anchor-idl-build")]
+impl anchor_lang::IdlBuild for Campaign {}
+
+#[cfg(feature = "anchor-idl-build")]
+impl anchor_lang::Discriminator for Campaign {
+    const DISCRIMINATOR: [u8; 8] = [0; 8];
+}
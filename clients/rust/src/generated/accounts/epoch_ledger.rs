@@ -0,0 +1,99 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+
+use {
+    crate::generated::types::EpochLedgerEntry,
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EpochLedger {
+    pub last_accumulated_rewards_per_token: u128,
+    pub last_total_rewards_received: u64,
+    pub next_index: u64,
+    pub len: u64,
+    pub bump_seed: u8,
+    pub padding: [u8; 7],
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
+    pub entries: [EpochLedgerEntry; 64],
+}
+
+impl EpochLedger {
+    pub const LEN: usize = 2096;
+
+    /// Prefix values used to generate a PDA for this account.
+    ///
+    /// Values are positional and appear in the following order:
+    ///
+    ///   0. `EpochLedger::PREFIX`
+    ///   1. holder_rewards_pool (`Pubkey`)
+    pub const PREFIX: &'static [u8] = "epoch_ledger".as_bytes();
+
+    pub fn create_pda(
+        holder_rewards_pool: Pubkey,
+        bump: u8,
+    ) -> Result<solana_program::pubkey::Pubkey, solana_program::pubkey::PubkeyError> {
+        solana_program::pubkey::Pubkey::create_program_address(
+            &[
+                "epoch_ledger".as_bytes(),
+                holder_rewards_pool.as_ref(),
+                &[bump],
+            ],
+            &crate::PALADIN_REWARDS_ID,
+        )
+    }
+
+    pub fn find_pda(holder_rewards_pool: &Pubkey) -> (solana_program::pubkey::Pubkey, u8) {
+        solana_program::pubkey::Pubkey::find_program_address(
+            &["epoch_ledger".as_bytes(), holder_rewards_pool.as_ref()],
+            &crate::PALADIN_REWARDS_ID,
+        )
+    }
+
+    #[inline(always)]
+    pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
+        let mut data = data;
+        Self::deserialize(&mut data)
+    }
+}
+
+impl<'a> TryFrom<&solana_program::account_info::AccountInfo<'a>> for EpochLedger {
+    type Error = std::io::Error;
+
+    fn try_from(
+        account_info: &solana_program::account_info::AccountInfo<'a>,
+    ) -> Result<Self, Self::Error> {
+        let mut data: &[u8] = &(*account_info.data).borrow();
+        Self::deserialize(&mut data)
+    }
+}
+
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountDeserialize for EpochLedger {
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        Ok(Self::deserialize(buf)?)
+    }
+}
+
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountSerialize for EpochLedger {}
+
+#[cfg(feature = "anchor")]
+impl anchor_lang::Owner for EpochLedger {
+    fn owner() -> Pubkey {
+        crate::PALADIN_REWARDS_ID
+    }
+}
+
+#[cfg(feature = "anchor-idl-build")]
+impl anchor_lang::IdlBuild for EpochLedger {}
+
+#[cfg(feature = "anchor-idl-build")]
+impl anchor_lang::Discriminator for EpochLedger {
+    const DISCRIMINATOR: [u8; 8] = [0; 8];
+}
@@ -14,11 +14,32 @@ use {
 pub struct HolderRewards {
     pub last_accumulated_rewards_per_token: u128,
     pub deposited: u64,
-    pub padding: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub rent_payer: Pubkey,
+    pub bump_seed: u8,
+    pub stake_checkpoints: u8,
+    pub padding: [u8; 6],
+    pub total_rewards_harvested: u64,
+    pub first_deposit_slot: u64,
+    pub last_activity_slot: u64,
+    pub cumulative_deposited: u64,
+    pub cumulative_withdrawn: u64,
+    pub locked_amount: u64,
+    pub unlock_timestamp: i64,
+    pub lockup_multiplier_bps: u64,
+    pub voting_power_locked: u64,
+    pub voting_power_end: i64,
+    pub last_deposit_slot: u64,
+    pub early_withdrawal_penalties: u64,
+    pub unharvested_rewards: u64,
+    pub slashed: u64,
 }
 
 impl HolderRewards {
-    pub const LEN: usize = 32;
+    pub const LEN: usize = 176;
 
     /// Prefix values used to generate a PDA for this account.
     ///
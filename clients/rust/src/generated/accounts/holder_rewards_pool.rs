@@ -15,11 +15,45 @@ pub struct HolderRewardsPool {
     pub accumulated_rewards_per_token: u128,
     pub lamports_last: u64,
     pub duna_document_hash: [u8; 32],
-    pub padding: u64,
+    pub total_deposited: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub authority: Pubkey,
+    pub bump_seed: u8,
+    pub access_list: u8,
+    pub early_withdrawal_penalty_bps: u16,
+    pub early_withdrawal_period_slots: u32,
+    pub last_update_slot: u64,
+    pub total_rewards_received: u64,
+    pub total_rewards_harvested: u64,
+    pub active_holder_count: u64,
+    pub total_lockup_bonus_weight: u64,
+    pub max_total_deposit: u64,
+    pub max_holder_deposit: u64,
+    pub min_deposit: u64,
+    pub receipts: u8,
+    pub receipt_mint_bump_seed: u8,
+    pub rate_history: u8,
+    pub epoch_ledger: u8,
+    pub padding: [u8; 4],
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub slashing_authority: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub slash_destination: Pubkey,
+    pub total_slashed: u64,
+    pub zero_weight_slots: u64,
 }
 
 impl HolderRewardsPool {
-    pub const LEN: usize = 64;
+    pub const LEN: usize = 256;
 
     /// Prefix values used to generate a PDA for this account.
     ///
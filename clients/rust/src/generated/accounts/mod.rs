@@ -4,7 +4,18 @@
 //!
 //! <https://github.com/kinobi-so/kinobi>
 
+pub(crate) mod r#access_entry;
+pub(crate) mod r#campaign;
+pub(crate) mod r#epoch_ledger;
 pub(crate) mod r#holder_rewards;
 pub(crate) mod r#holder_rewards_pool;
+pub(crate) mod r#lockup_tiers;
+pub(crate) mod r#rate_history;
+pub(crate) mod r#stake_checkpoints;
+pub(crate) mod r#vote_escrow;
 
-pub use self::{r#holder_rewards::*, r#holder_rewards_pool::*};
+pub use self::{
+    r#access_entry::*, r#campaign::*, r#epoch_ledger::*, r#holder_rewards::*,
+    r#holder_rewards_pool::*, r#lockup_tiers::*, r#rate_history::*, r#stake_checkpoints::*,
+    r#vote_escrow::*,
+};
@@ -59,6 +59,174 @@ pub enum PaladinRewardsError {
     /// 16 - DUNA PDA invalid seeds
     #[error("DUNA PDA invalid seeds")]
     InvalidDunaPdaSeeds = 0x10,
+    /// 17 - Incorrect holder rewards pool authority
+    #[error("Incorrect holder rewards pool authority")]
+    IncorrectPoolAuthority = 0x11,
+    /// 18 - Pool token account has no excess tokens to recover
+    #[error("Pool token account has no excess tokens to recover")]
+    NoExcessTokens = 0x12,
+    /// 19 - Mint is not owned by the SPL Token program
+    #[error("Mint is not owned by the SPL Token program")]
+    InvalidMintOwner = 0x13,
+    /// 20 - Token account is not owned by the SPL Token program
+    #[error("Token account is not owned by the SPL Token program")]
+    InvalidTokenProgram = 0x14,
+    /// 21 - Account must be writable
+    #[error("Account must be writable")]
+    AccountNotWritable = 0x15,
+    /// 22 - Incorrect token program account
+    #[error("Incorrect token program account")]
+    IncorrectTokenProgram = 0x16,
+    /// 23 - Incorrect system program account
+    #[error("Incorrect system program account")]
+    IncorrectSystemProgram = 0x17,
+    /// 24 - Incorrect rent payer account
+    #[error("Incorrect rent payer account")]
+    IncorrectRentPayer = 0x18,
+    /// 25 - Holder rewards pool has already been migrated
+    #[error("Holder rewards pool has already been migrated")]
+    PoolAlreadyMigrated = 0x19,
+    /// 26 - Incorrect rate history address
+    #[error("Incorrect rate history address")]
+    IncorrectRateHistoryAddress = 0x1A,
+    /// 27 - Incorrect lockup tiers address
+    #[error("Incorrect lockup tiers address")]
+    IncorrectLockupTiersAddress = 0x1B,
+    /// 28 - Invalid lockup tiers
+    #[error("Invalid lockup tiers")]
+    InvalidLockupTiers = 0x1C,
+    /// 29 - Lockup duration is shorter than the shortest lockup tier
+    #[error("Lockup duration is shorter than the shortest lockup tier")]
+    LockupDurationTooShort = 0x1D,
+    /// 30 - Lockup cannot be shortened
+    #[error("Lockup cannot be shortened")]
+    LockupCannotBeShortened = 0x1E,
+    /// 31 - Lock amount exceeds unlocked deposited tokens
+    #[error("Lock amount exceeds unlocked deposited tokens")]
+    LockExceedsUnlocked = 0x1F,
+    /// 32 - Withdraw amount exceeds unlocked deposited tokens
+    #[error("Withdraw amount exceeds unlocked deposited tokens")]
+    WithdrawExceedsUnlocked = 0x20,
+    /// 33 - Lockup has not expired
+    #[error("Lockup has not expired")]
+    LockupNotExpired = 0x21,
+    /// 34 - Lockup duration exceeds the maximum lockup duration
+    #[error("Lockup duration exceeds the maximum lockup duration")]
+    LockupDurationTooLong = 0x22,
+    /// 35 - Incorrect vote escrow address
+    #[error("Incorrect vote escrow address")]
+    IncorrectVoteEscrowAddress = 0x23,
+    /// 36 - The pool's vote escrow account is required
+    #[error("The pool's vote escrow account is required")]
+    VoteEscrowRequired = 0x24,
+    /// 37 - Incorrect stake checkpoints address
+    #[error("Incorrect stake checkpoints address")]
+    IncorrectStakeCheckpointsAddress = 0x25,
+    /// 38 - The holder's stake checkpoints account is required
+    #[error("The holder's stake checkpoints account is required")]
+    StakeCheckpointsRequired = 0x26,
+    /// 39 - No stake checkpoint covers the slot
+    #[error("No stake checkpoint covers the slot")]
+    StakeCheckpointNotFound = 0x27,
+    /// 40 - Snapshot slot must be in the past
+    #[error("Snapshot slot must be in the past")]
+    SnapshotSlotNotPast = 0x28,
+    /// 41 - Early withdrawal penalty exceeds 100%
+    #[error("Early withdrawal penalty exceeds 100%")]
+    InvalidEarlyWithdrawalPenalty = 0x29,
+    /// 42 - Minimum deposit exceeds the maximum holder deposit
+    #[error("Minimum deposit exceeds the maximum holder deposit")]
+    InvalidDepositLimits = 0x2A,
+    /// 43 - Deposit is below the pool's minimum deposit
+    #[error("Deposit is below the pool's minimum deposit")]
+    DepositBelowMinimum = 0x2B,
+    /// 44 - Deposit exceeds the pool's maximum holder deposit
+    #[error("Deposit exceeds the pool's maximum holder deposit")]
+    HolderDepositCapExceeded = 0x2C,
+    /// 45 - Deposit exceeds the pool's maximum total deposit
+    #[error("Deposit exceeds the pool's maximum total deposit")]
+    PoolDepositCapExceeded = 0x2D,
+    /// 46 - Incorrect access entry address
+    #[error("Incorrect access entry address")]
+    IncorrectAccessEntryAddress = 0x2E,
+    /// 47 - The wallet's access entry is required by the pool's access list
+    #[error("The wallet's access entry is required by the pool's access list")]
+    AccessEntryRequired = 0x2F,
+    /// 48 - The pool's access list does not permit the wallet to deposit
+    #[error("The pool's access list does not permit the wallet to deposit")]
+    AccessDenied = 0x30,
+    /// 49 - Invalid access list mode
+    #[error("Invalid access list mode")]
+    InvalidAccessListMode = 0x31,
+    /// 50 - Invalid access status
+    #[error("Invalid access status")]
+    InvalidAccessStatus = 0x32,
+    /// 51 - Incorrect receipt mint address
+    #[error("Incorrect receipt mint address")]
+    IncorrectReceiptMintAddress = 0x33,
+    /// 52 - The receipt accounts are required by the pool's receipt mint
+    #[error("The receipt accounts are required by the pool's receipt mint")]
+    ReceiptAccountsRequired = 0x34,
+    /// 53 - Receipt mint already initialized
+    #[error("Receipt mint already initialized")]
+    ReceiptMintAlreadyInitialized = 0x35,
+    /// 54 - Receipt mint can only be initialized before any deposits
+    #[error("Receipt mint can only be initialized before any deposits")]
+    ReceiptMintRequiresEmptyPool = 0x36,
+    /// 55 - Receipt tokens are not being transferred
+    #[error("Receipt tokens are not being transferred")]
+    ReceiptNotTransferring = 0x37,
+    /// 56 - Receipt transfer exceeds the holder's unlocked deposit
+    #[error("Receipt transfer exceeds the holder's unlocked deposit")]
+    ReceiptTransferExceedsUnlocked = 0x38,
+    /// 57 - Incorrect extra account metas address
+    #[error("Incorrect extra account metas address")]
+    IncorrectExtraAccountMetasAddress = 0x39,
+    /// 58 - Incorrect slashing authority
+    #[error("Incorrect slashing authority")]
+    IncorrectSlashingAuthority = 0x3A,
+    /// 59 - Incorrect slash destination
+    #[error("Incorrect slash destination")]
+    IncorrectSlashDestination = 0x3B,
+    /// 60 - Slash exceeds the holder's deposit
+    #[error("Slash exceeds the holder's deposit")]
+    SlashExceedsDeposited = 0x3C,
+    /// 61 - Campaign must end after it starts and after the current slot
+    #[error("Campaign must end after it starts and after the current slot")]
+    InvalidCampaignSchedule = 0x3D,
+    /// 62 - Incorrect campaign address
+    #[error("Incorrect campaign address")]
+    IncorrectCampaignAddress = 0x3E,
+    /// 63 - Campaign has not ended
+    #[error("Campaign has not ended")]
+    CampaignNotEnded = 0x3F,
+    /// 64 - Incorrect campaign funder
+    #[error("Incorrect campaign funder")]
+    IncorrectCampaignFunder = 0x40,
+    /// 65 - Incorrect epoch ledger address
+    #[error("Incorrect epoch ledger address")]
+    IncorrectEpochLedgerAddress = 0x41,
+    /// 66 - Holder rewards account has already been migrated
+    #[error("Holder rewards account has already been migrated")]
+    HolderRewardsAlreadyMigrated = 0x42,
+    /// 67 - Holder rewards pool has no weight to distribute rewards to
+    #[error("Holder rewards pool has no weight to distribute rewards to")]
+    PoolHasNoWeight = 0x43,
+    /// 68 - Deposits in a pool with a receipt mint cannot be slashed
+    #[error("Deposits in a pool with a receipt mint cannot be slashed")]
+    ReceiptPoolNotSlashable = 0x44,
+    /// 69 - The pool's rate history account is required
+    #[error("The pool's rate history account is required")]
+    RateHistoryRequired = 0x45,
+    /// 70 - Pool token account is not the pool's associated token account
+    #[error("Pool token account is not the pool's associated token account")]
+    IncorrectPoolTokenAccountAddress = 0x46,
+    /// 71 - The pool's epoch ledger account is required
+    #[error("The pool's epoch ledger account is required")]
+    EpochLedgerRequired = 0x47,
+    /// 72 - Receipt recipient has no holder rewards account
+    #[error("Receipt recipient has no holder rewards account")]
+    ReceiptRecipientNotInitialized = 0x48,
 }
 
 impl solana_program::program_error::PrintProgramError for PaladinRewardsError {
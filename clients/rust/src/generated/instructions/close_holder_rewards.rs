@@ -18,6 +18,12 @@ pub struct CloseHolderRewards {
     pub mint: solana_program::pubkey::Pubkey,
     /// Owner of the account.
     pub owner: solana_program::pubkey::Pubkey,
+    /// Payer that funded the holder rewards account.
+    pub rent_payer: solana_program::pubkey::Pubkey,
+    /// Rate history account, if the pool records one.
+    pub rate_history: Option<solana_program::pubkey::Pubkey>,
+    /// Epoch ledger account, if the pool records one.
+    pub epoch_ledger: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl CloseHolderRewards {
@@ -29,7 +35,7 @@ impl CloseHolderRewards {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.holder_rewards_pool,
             false,
@@ -48,6 +54,32 @@ impl CloseHolderRewards {
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.owner, true,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.rent_payer,
+            false,
+        ));
+        if let Some(rate_history) = self.rate_history {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                rate_history,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(epoch_ledger) = self.epoch_ledger {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                epoch_ledger,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let data = CloseHolderRewardsInstructionData::new()
             .try_to_vec()
@@ -87,6 +119,9 @@ impl Default for CloseHolderRewardsInstructionData {
 ///   2. `[writable]` holder_rewards
 ///   3. `[]` mint
 ///   4. `[writable, signer]` owner
+///   5. `[writable]` rent_payer
+///   6. `[writable, optional]` rate_history
+///   7. `[writable, optional]` epoch_ledger
 #[derive(Clone, Debug, Default)]
 pub struct CloseHolderRewardsBuilder {
     holder_rewards_pool: Option<solana_program::pubkey::Pubkey>,
@@ -94,6 +129,9 @@ pub struct CloseHolderRewardsBuilder {
     holder_rewards: Option<solana_program::pubkey::Pubkey>,
     mint: Option<solana_program::pubkey::Pubkey>,
     owner: Option<solana_program::pubkey::Pubkey>,
+    rent_payer: Option<solana_program::pubkey::Pubkey>,
+    rate_history: Option<solana_program::pubkey::Pubkey>,
+    epoch_ledger: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -137,6 +175,32 @@ impl CloseHolderRewardsBuilder {
         self.owner = Some(owner);
         self
     }
+    /// Payer that funded the holder rewards account.
+    #[inline(always)]
+    pub fn rent_payer(&mut self, rent_payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.rent_payer = Some(rent_payer);
+        self
+    }
+    /// `[optional account]`
+    /// Rate history account, if the pool records one.
+    #[inline(always)]
+    pub fn rate_history(
+        &mut self,
+        rate_history: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.rate_history = rate_history;
+        self
+    }
+    /// `[optional account]`
+    /// Epoch ledger account, if the pool records one.
+    #[inline(always)]
+    pub fn epoch_ledger(
+        &mut self,
+        epoch_ledger: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.epoch_ledger = epoch_ledger;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -167,6 +231,9 @@ impl CloseHolderRewardsBuilder {
             holder_rewards: self.holder_rewards.expect("holder_rewards is not set"),
             mint: self.mint.expect("mint is not set"),
             owner: self.owner.expect("owner is not set"),
+            rent_payer: self.rent_payer.expect("rent_payer is not set"),
+            rate_history: self.rate_history,
+            epoch_ledger: self.epoch_ledger,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -185,6 +252,12 @@ pub struct CloseHolderRewardsCpiAccounts<'a, 'b> {
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
     /// Owner of the account.
     pub owner: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Payer that funded the holder rewards account.
+    pub rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Rate history account, if the pool records one.
+    pub rate_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Epoch ledger account, if the pool records one.
+    pub epoch_ledger: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `close_holder_rewards` CPI instruction.
@@ -201,6 +274,12 @@ pub struct CloseHolderRewardsCpi<'a, 'b> {
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
     /// Owner of the account.
     pub owner: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Payer that funded the holder rewards account.
+    pub rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Rate history account, if the pool records one.
+    pub rate_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Epoch ledger account, if the pool records one.
+    pub epoch_ledger: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 impl<'a, 'b> CloseHolderRewardsCpi<'a, 'b> {
@@ -215,6 +294,9 @@ impl<'a, 'b> CloseHolderRewardsCpi<'a, 'b> {
             holder_rewards: accounts.holder_rewards,
             mint: accounts.mint,
             owner: accounts.owner,
+            rent_payer: accounts.rent_payer,
+            rate_history: accounts.rate_history,
+            epoch_ledger: accounts.epoch_ledger,
         }
     }
     #[inline(always)]
@@ -250,7 +332,7 @@ impl<'a, 'b> CloseHolderRewardsCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.holder_rewards_pool.key,
             false,
@@ -271,6 +353,32 @@ impl<'a, 'b> CloseHolderRewardsCpi<'a, 'b> {
             *self.owner.key,
             true,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.rent_payer.key,
+            false,
+        ));
+        if let Some(rate_history) = self.rate_history {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *rate_history.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(epoch_ledger) = self.epoch_ledger {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *epoch_ledger.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -287,13 +395,20 @@ impl<'a, 'b> CloseHolderRewardsCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(8 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.holder_rewards_pool.clone());
         account_infos.push(self.holder_rewards_pool_token_account.clone());
         account_infos.push(self.holder_rewards.clone());
         account_infos.push(self.mint.clone());
         account_infos.push(self.owner.clone());
+        account_infos.push(self.rent_payer.clone());
+        if let Some(rate_history) = self.rate_history {
+            account_infos.push(rate_history.clone());
+        }
+        if let Some(epoch_ledger) = self.epoch_ledger {
+            account_infos.push(epoch_ledger.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -315,6 +430,9 @@ impl<'a, 'b> CloseHolderRewardsCpi<'a, 'b> {
 ///   2. `[writable]` holder_rewards
 ///   3. `[]` mint
 ///   4. `[writable, signer]` owner
+///   5. `[writable]` rent_payer
+///   6. `[writable, optional]` rate_history
+///   7. `[writable, optional]` epoch_ledger
 #[derive(Clone, Debug)]
 pub struct CloseHolderRewardsCpiBuilder<'a, 'b> {
     instruction: Box<CloseHolderRewardsCpiBuilderInstruction<'a, 'b>>,
@@ -329,6 +447,9 @@ impl<'a, 'b> CloseHolderRewardsCpiBuilder<'a, 'b> {
             holder_rewards: None,
             mint: None,
             owner: None,
+            rent_payer: None,
+            rate_history: None,
+            epoch_ledger: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -373,6 +494,35 @@ impl<'a, 'b> CloseHolderRewardsCpiBuilder<'a, 'b> {
         self.instruction.owner = Some(owner);
         self
     }
+    /// Payer that funded the holder rewards account.
+    #[inline(always)]
+    pub fn rent_payer(
+        &mut self,
+        rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.rent_payer = Some(rent_payer);
+        self
+    }
+    /// `[optional account]`
+    /// Rate history account, if the pool records one.
+    #[inline(always)]
+    pub fn rate_history(
+        &mut self,
+        rate_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.rate_history = rate_history;
+        self
+    }
+    /// `[optional account]`
+    /// Epoch ledger account, if the pool records one.
+    #[inline(always)]
+    pub fn epoch_ledger(
+        &mut self,
+        epoch_ledger: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.epoch_ledger = epoch_ledger;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -436,6 +586,12 @@ impl<'a, 'b> CloseHolderRewardsCpiBuilder<'a, 'b> {
             mint: self.instruction.mint.expect("mint is not set"),
 
             owner: self.instruction.owner.expect("owner is not set"),
+
+            rent_payer: self.instruction.rent_payer.expect("rent_payer is not set"),
+
+            rate_history: self.instruction.rate_history,
+
+            epoch_ledger: self.instruction.epoch_ledger,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -452,6 +608,9 @@ struct CloseHolderRewardsCpiBuilderInstruction<'a, 'b> {
     holder_rewards: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     owner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rent_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rate_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    epoch_ledger: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
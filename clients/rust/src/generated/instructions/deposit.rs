@@ -22,6 +22,21 @@ pub struct Deposit {
     pub owner: solana_program::pubkey::Pubkey,
     /// token program
     pub token_program: solana_program::pubkey::Pubkey,
+    /// Rate history account, if the pool records one.
+    pub rate_history: Option<solana_program::pubkey::Pubkey>,
+    /// Stake checkpoints account, required if the holder records one.
+    pub stake_checkpoints: Option<solana_program::pubkey::Pubkey>,
+    /// Owner's access entry account, required if the pool has an access list.
+    pub access_entry: Option<solana_program::pubkey::Pubkey>,
+    /// Receipt token mint, required if the pool mints receipt tokens.
+    pub receipt_mint: Option<solana_program::pubkey::Pubkey>,
+    /// Owner's receipt token account, required if the pool mints receipt
+    /// tokens.
+    pub receipt_token_account: Option<solana_program::pubkey::Pubkey>,
+    /// Token-2022 program, required if the pool mints receipt tokens.
+    pub receipt_token_program: Option<solana_program::pubkey::Pubkey>,
+    /// Epoch ledger account, if the pool records one.
+    pub epoch_ledger: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl Deposit {
@@ -37,7 +52,7 @@ impl Deposit {
         args: DepositInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.holder_rewards_pool,
             false,
@@ -64,6 +79,83 @@ impl Deposit {
             self.token_program,
             false,
         ));
+        if let Some(rate_history) = self.rate_history {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                rate_history,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(stake_checkpoints) = self.stake_checkpoints {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                stake_checkpoints,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(access_entry) = self.access_entry {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                access_entry,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(receipt_mint) = self.receipt_mint {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                receipt_mint,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(receipt_token_account) = self.receipt_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                receipt_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(receipt_token_program) = self.receipt_token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                receipt_token_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(epoch_ledger) = self.epoch_ledger {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                epoch_ledger,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = DepositInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -112,6 +204,13 @@ pub struct DepositInstructionArgs {
 ///   5. `[writable, signer]` owner
 ///   6. `[optional]` token_program (default to
 ///      `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   7. `[writable, optional]` rate_history
+///   8. `[writable, optional]` stake_checkpoints
+///   9. `[optional]` access_entry
+///   10. `[writable, optional]` receipt_mint
+///   11. `[writable, optional]` receipt_token_account
+///   12. `[optional]` receipt_token_program
+///   13. `[writable, optional]` epoch_ledger
 #[derive(Clone, Debug, Default)]
 pub struct DepositBuilder {
     holder_rewards_pool: Option<solana_program::pubkey::Pubkey>,
@@ -121,6 +220,13 @@ pub struct DepositBuilder {
     mint: Option<solana_program::pubkey::Pubkey>,
     owner: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
+    rate_history: Option<solana_program::pubkey::Pubkey>,
+    stake_checkpoints: Option<solana_program::pubkey::Pubkey>,
+    access_entry: Option<solana_program::pubkey::Pubkey>,
+    receipt_mint: Option<solana_program::pubkey::Pubkey>,
+    receipt_token_account: Option<solana_program::pubkey::Pubkey>,
+    receipt_token_program: Option<solana_program::pubkey::Pubkey>,
+    epoch_ledger: Option<solana_program::pubkey::Pubkey>,
     amount: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -178,6 +284,77 @@ impl DepositBuilder {
         self.token_program = Some(token_program);
         self
     }
+    /// `[optional account]`
+    /// Rate history account, if the pool records one.
+    #[inline(always)]
+    pub fn rate_history(
+        &mut self,
+        rate_history: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.rate_history = rate_history;
+        self
+    }
+    /// `[optional account]`
+    /// Stake checkpoints account, required if the holder records one.
+    #[inline(always)]
+    pub fn stake_checkpoints(
+        &mut self,
+        stake_checkpoints: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.stake_checkpoints = stake_checkpoints;
+        self
+    }
+    /// `[optional account]`
+    /// Owner's access entry account, required if the pool has an access list.
+    #[inline(always)]
+    pub fn access_entry(
+        &mut self,
+        access_entry: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.access_entry = access_entry;
+        self
+    }
+    /// `[optional account]`
+    /// Receipt token mint, required if the pool mints receipt tokens.
+    #[inline(always)]
+    pub fn receipt_mint(
+        &mut self,
+        receipt_mint: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.receipt_mint = receipt_mint;
+        self
+    }
+    /// `[optional account]`
+    /// Owner's receipt token account, required if the pool mints receipt
+    /// tokens.
+    #[inline(always)]
+    pub fn receipt_token_account(
+        &mut self,
+        receipt_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.receipt_token_account = receipt_token_account;
+        self
+    }
+    /// `[optional account]`
+    /// Token-2022 program, required if the pool mints receipt tokens.
+    #[inline(always)]
+    pub fn receipt_token_program(
+        &mut self,
+        receipt_token_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.receipt_token_program = receipt_token_program;
+        self
+    }
+    /// `[optional account]`
+    /// Epoch ledger account, if the pool records one.
+    #[inline(always)]
+    pub fn epoch_ledger(
+        &mut self,
+        epoch_ledger: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.epoch_ledger = epoch_ledger;
+        self
+    }
     #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.amount = Some(amount);
@@ -217,6 +394,13 @@ impl DepositBuilder {
             token_program: self.token_program.unwrap_or(solana_program::pubkey!(
                 "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
             )),
+            rate_history: self.rate_history,
+            stake_checkpoints: self.stake_checkpoints,
+            access_entry: self.access_entry,
+            receipt_mint: self.receipt_mint,
+            receipt_token_account: self.receipt_token_account,
+            receipt_token_program: self.receipt_token_program,
+            epoch_ledger: self.epoch_ledger,
         };
         let args = DepositInstructionArgs {
             amount: self.amount.clone().expect("amount is not set"),
@@ -242,6 +426,21 @@ pub struct DepositCpiAccounts<'a, 'b> {
     pub owner: &'b solana_program::account_info::AccountInfo<'a>,
     /// token program
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Rate history account, if the pool records one.
+    pub rate_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Stake checkpoints account, required if the holder records one.
+    pub stake_checkpoints: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Owner's access entry account, required if the pool has an access list.
+    pub access_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Receipt token mint, required if the pool mints receipt tokens.
+    pub receipt_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Owner's receipt token account, required if the pool mints receipt
+    /// tokens.
+    pub receipt_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token-2022 program, required if the pool mints receipt tokens.
+    pub receipt_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Epoch ledger account, if the pool records one.
+    pub epoch_ledger: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `deposit` CPI instruction.
@@ -262,6 +461,21 @@ pub struct DepositCpi<'a, 'b> {
     pub owner: &'b solana_program::account_info::AccountInfo<'a>,
     /// token program
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Rate history account, if the pool records one.
+    pub rate_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Stake checkpoints account, required if the holder records one.
+    pub stake_checkpoints: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Owner's access entry account, required if the pool has an access list.
+    pub access_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Receipt token mint, required if the pool mints receipt tokens.
+    pub receipt_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Owner's receipt token account, required if the pool mints receipt
+    /// tokens.
+    pub receipt_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token-2022 program, required if the pool mints receipt tokens.
+    pub receipt_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Epoch ledger account, if the pool records one.
+    pub epoch_ledger: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: DepositInstructionArgs,
 }
@@ -281,6 +495,13 @@ impl<'a, 'b> DepositCpi<'a, 'b> {
             mint: accounts.mint,
             owner: accounts.owner,
             token_program: accounts.token_program,
+            rate_history: accounts.rate_history,
+            stake_checkpoints: accounts.stake_checkpoints,
+            access_entry: accounts.access_entry,
+            receipt_mint: accounts.receipt_mint,
+            receipt_token_account: accounts.receipt_token_account,
+            receipt_token_program: accounts.receipt_token_program,
+            epoch_ledger: accounts.epoch_ledger,
             __args: args,
         }
     }
@@ -317,7 +538,7 @@ impl<'a, 'b> DepositCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.holder_rewards_pool.key,
             false,
@@ -346,6 +567,83 @@ impl<'a, 'b> DepositCpi<'a, 'b> {
             *self.token_program.key,
             false,
         ));
+        if let Some(rate_history) = self.rate_history {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *rate_history.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(stake_checkpoints) = self.stake_checkpoints {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *stake_checkpoints.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(access_entry) = self.access_entry {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *access_entry.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(receipt_mint) = self.receipt_mint {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *receipt_mint.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(receipt_token_account) = self.receipt_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *receipt_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(receipt_token_program) = self.receipt_token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *receipt_token_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(epoch_ledger) = self.epoch_ledger {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *epoch_ledger.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -362,7 +660,7 @@ impl<'a, 'b> DepositCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(14 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.holder_rewards_pool.clone());
         account_infos.push(self.holder_rewards_pool_token_account.clone());
@@ -371,6 +669,27 @@ impl<'a, 'b> DepositCpi<'a, 'b> {
         account_infos.push(self.mint.clone());
         account_infos.push(self.owner.clone());
         account_infos.push(self.token_program.clone());
+        if let Some(rate_history) = self.rate_history {
+            account_infos.push(rate_history.clone());
+        }
+        if let Some(stake_checkpoints) = self.stake_checkpoints {
+            account_infos.push(stake_checkpoints.clone());
+        }
+        if let Some(access_entry) = self.access_entry {
+            account_infos.push(access_entry.clone());
+        }
+        if let Some(receipt_mint) = self.receipt_mint {
+            account_infos.push(receipt_mint.clone());
+        }
+        if let Some(receipt_token_account) = self.receipt_token_account {
+            account_infos.push(receipt_token_account.clone());
+        }
+        if let Some(receipt_token_program) = self.receipt_token_program {
+            account_infos.push(receipt_token_program.clone());
+        }
+        if let Some(epoch_ledger) = self.epoch_ledger {
+            account_infos.push(epoch_ledger.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -394,6 +713,13 @@ impl<'a, 'b> DepositCpi<'a, 'b> {
 ///   4. `[]` mint
 ///   5. `[writable, signer]` owner
 ///   6. `[]` token_program
+///   7. `[writable, optional]` rate_history
+///   8. `[writable, optional]` stake_checkpoints
+///   9. `[optional]` access_entry
+///   10. `[writable, optional]` receipt_mint
+///   11. `[writable, optional]` receipt_token_account
+///   12. `[optional]` receipt_token_program
+///   13. `[writable, optional]` epoch_ledger
 #[derive(Clone, Debug)]
 pub struct DepositCpiBuilder<'a, 'b> {
     instruction: Box<DepositCpiBuilderInstruction<'a, 'b>>,
@@ -410,6 +736,13 @@ impl<'a, 'b> DepositCpiBuilder<'a, 'b> {
             mint: None,
             owner: None,
             token_program: None,
+            rate_history: None,
+            stake_checkpoints: None,
+            access_entry: None,
+            receipt_mint: None,
+            receipt_token_account: None,
+            receipt_token_program: None,
+            epoch_ledger: None,
             amount: None,
             __remaining_accounts: Vec::new(),
         });
@@ -473,6 +806,77 @@ impl<'a, 'b> DepositCpiBuilder<'a, 'b> {
         self.instruction.token_program = Some(token_program);
         self
     }
+    /// `[optional account]`
+    /// Rate history account, if the pool records one.
+    #[inline(always)]
+    pub fn rate_history(
+        &mut self,
+        rate_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.rate_history = rate_history;
+        self
+    }
+    /// `[optional account]`
+    /// Stake checkpoints account, required if the holder records one.
+    #[inline(always)]
+    pub fn stake_checkpoints(
+        &mut self,
+        stake_checkpoints: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.stake_checkpoints = stake_checkpoints;
+        self
+    }
+    /// `[optional account]`
+    /// Owner's access entry account, required if the pool has an access list.
+    #[inline(always)]
+    pub fn access_entry(
+        &mut self,
+        access_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.access_entry = access_entry;
+        self
+    }
+    /// `[optional account]`
+    /// Receipt token mint, required if the pool mints receipt tokens.
+    #[inline(always)]
+    pub fn receipt_mint(
+        &mut self,
+        receipt_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.receipt_mint = receipt_mint;
+        self
+    }
+    /// `[optional account]`
+    /// Owner's receipt token account, required if the pool mints receipt
+    /// tokens.
+    #[inline(always)]
+    pub fn receipt_token_account(
+        &mut self,
+        receipt_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.receipt_token_account = receipt_token_account;
+        self
+    }
+    /// `[optional account]`
+    /// Token-2022 program, required if the pool mints receipt tokens.
+    #[inline(always)]
+    pub fn receipt_token_program(
+        &mut self,
+        receipt_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.receipt_token_program = receipt_token_program;
+        self
+    }
+    /// `[optional account]`
+    /// Epoch ledger account, if the pool records one.
+    #[inline(always)]
+    pub fn epoch_ledger(
+        &mut self,
+        epoch_ledger: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.epoch_ledger = epoch_ledger;
+        self
+    }
     #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.instruction.amount = Some(amount);
@@ -554,6 +958,20 @@ impl<'a, 'b> DepositCpiBuilder<'a, 'b> {
                 .instruction
                 .token_program
                 .expect("token_program is not set"),
+
+            rate_history: self.instruction.rate_history,
+
+            stake_checkpoints: self.instruction.stake_checkpoints,
+
+            access_entry: self.instruction.access_entry,
+
+            receipt_mint: self.instruction.receipt_mint,
+
+            receipt_token_account: self.instruction.receipt_token_account,
+
+            receipt_token_program: self.instruction.receipt_token_program,
+
+            epoch_ledger: self.instruction.epoch_ledger,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -573,6 +991,13 @@ struct DepositCpiBuilderInstruction<'a, 'b> {
     mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     owner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rate_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    stake_checkpoints: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    access_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    receipt_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    receipt_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    receipt_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    epoch_ledger: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     amount: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
@@ -0,0 +1,952 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct EmergencyWithdraw {
+    /// Holder rewards pool account.
+    pub holder_rewards_pool: solana_program::pubkey::Pubkey,
+    /// Holder rewards pool token account.
+    pub holder_rewards_pool_token_account: solana_program::pubkey::Pubkey,
+    /// Holder rewards account.
+    pub holder_rewards: solana_program::pubkey::Pubkey,
+    /// Token account.
+    pub token_account: solana_program::pubkey::Pubkey,
+    /// Token mint.
+    pub mint: solana_program::pubkey::Pubkey,
+    /// Owner of the account.
+    pub owner: solana_program::pubkey::Pubkey,
+    /// token program
+    pub token_program: solana_program::pubkey::Pubkey,
+    /// Rate history account, if the pool records one.
+    pub rate_history: Option<solana_program::pubkey::Pubkey>,
+    /// Stake checkpoints account, required if the holder records one.
+    pub stake_checkpoints: Option<solana_program::pubkey::Pubkey>,
+    /// Receipt token mint, required if the pool mints receipt tokens.
+    pub receipt_mint: Option<solana_program::pubkey::Pubkey>,
+    /// Owner's receipt token account, required if the pool mints receipt
+    /// tokens.
+    pub receipt_token_account: Option<solana_program::pubkey::Pubkey>,
+    /// Token-2022 program, required if the pool mints receipt tokens.
+    pub receipt_token_program: Option<solana_program::pubkey::Pubkey>,
+    /// Epoch ledger account, if the pool records one.
+    pub epoch_ledger: Option<solana_program::pubkey::Pubkey>,
+}
+
+impl EmergencyWithdraw {
+    pub fn instruction(
+        &self,
+        args: EmergencyWithdrawInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: EmergencyWithdrawInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.holder_rewards_pool,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.holder_rewards_pool_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.holder_rewards,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.mint, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.owner, true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        if let Some(rate_history) = self.rate_history {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                rate_history,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(stake_checkpoints) = self.stake_checkpoints {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                stake_checkpoints,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(receipt_mint) = self.receipt_mint {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                receipt_mint,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(receipt_token_account) = self.receipt_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                receipt_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(receipt_token_program) = self.receipt_token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                receipt_token_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(epoch_ledger) = self.epoch_ledger {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                epoch_ledger,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = EmergencyWithdrawInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::PALADIN_REWARDS_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct EmergencyWithdrawInstructionData {
+    discriminator: u8,
+}
+
+impl EmergencyWithdrawInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 23 }
+    }
+}
+
+impl Default for EmergencyWithdrawInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmergencyWithdrawInstructionArgs {
+    pub amount: u64,
+}
+
+/// Instruction builder for `EmergencyWithdraw`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` holder_rewards_pool
+///   1. `[writable]` holder_rewards_pool_token_account
+///   2. `[writable]` holder_rewards
+///   3. `[writable]` token_account
+///   4. `[]` mint
+///   5. `[writable, signer]` owner
+///   6. `[optional]` token_program (default to
+///      `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   7. `[writable, optional]` rate_history
+///   8. `[writable, optional]` stake_checkpoints
+///   9. `[writable, optional]` receipt_mint
+///   10. `[writable, optional]` receipt_token_account
+///   11. `[optional]` receipt_token_program
+///   12. `[writable, optional]` epoch_ledger
+#[derive(Clone, Debug, Default)]
+pub struct EmergencyWithdrawBuilder {
+    holder_rewards_pool: Option<solana_program::pubkey::Pubkey>,
+    holder_rewards_pool_token_account: Option<solana_program::pubkey::Pubkey>,
+    holder_rewards: Option<solana_program::pubkey::Pubkey>,
+    token_account: Option<solana_program::pubkey::Pubkey>,
+    mint: Option<solana_program::pubkey::Pubkey>,
+    owner: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    rate_history: Option<solana_program::pubkey::Pubkey>,
+    stake_checkpoints: Option<solana_program::pubkey::Pubkey>,
+    receipt_mint: Option<solana_program::pubkey::Pubkey>,
+    receipt_token_account: Option<solana_program::pubkey::Pubkey>,
+    receipt_token_program: Option<solana_program::pubkey::Pubkey>,
+    epoch_ledger: Option<solana_program::pubkey::Pubkey>,
+    amount: Option<u64>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl EmergencyWithdrawBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Holder rewards pool account.
+    #[inline(always)]
+    pub fn holder_rewards_pool(
+        &mut self,
+        holder_rewards_pool: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.holder_rewards_pool = Some(holder_rewards_pool);
+        self
+    }
+    /// Holder rewards pool token account.
+    #[inline(always)]
+    pub fn holder_rewards_pool_token_account(
+        &mut self,
+        holder_rewards_pool_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.holder_rewards_pool_token_account = Some(holder_rewards_pool_token_account);
+        self
+    }
+    /// Holder rewards account.
+    #[inline(always)]
+    pub fn holder_rewards(&mut self, holder_rewards: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.holder_rewards = Some(holder_rewards);
+        self
+    }
+    /// Token account.
+    #[inline(always)]
+    pub fn token_account(&mut self, token_account: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_account = Some(token_account);
+        self
+    }
+    /// Token mint.
+    #[inline(always)]
+    pub fn mint(&mut self, mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    /// Owner of the account.
+    #[inline(always)]
+    pub fn owner(&mut self, owner: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.owner = Some(owner);
+        self
+    }
+    /// `[optional account, default to
+    /// 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']` token program
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// `[optional account]`
+    /// Rate history account, if the pool records one.
+    #[inline(always)]
+    pub fn rate_history(
+        &mut self,
+        rate_history: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.rate_history = rate_history;
+        self
+    }
+    /// `[optional account]`
+    /// Stake checkpoints account, required if the holder records one.
+    #[inline(always)]
+    pub fn stake_checkpoints(
+        &mut self,
+        stake_checkpoints: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.stake_checkpoints = stake_checkpoints;
+        self
+    }
+    /// `[optional account]`
+    /// Receipt token mint, required if the pool mints receipt tokens.
+    #[inline(always)]
+    pub fn receipt_mint(
+        &mut self,
+        receipt_mint: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.receipt_mint = receipt_mint;
+        self
+    }
+    /// `[optional account]`
+    /// Owner's receipt token account, required if the pool mints receipt
+    /// tokens.
+    #[inline(always)]
+    pub fn receipt_token_account(
+        &mut self,
+        receipt_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.receipt_token_account = receipt_token_account;
+        self
+    }
+    /// `[optional account]`
+    /// Token-2022 program, required if the pool mints receipt tokens.
+    #[inline(always)]
+    pub fn receipt_token_program(
+        &mut self,
+        receipt_token_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.receipt_token_program = receipt_token_program;
+        self
+    }
+    /// `[optional account]`
+    /// Epoch ledger account, if the pool records one.
+    #[inline(always)]
+    pub fn epoch_ledger(
+        &mut self,
+        epoch_ledger: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.epoch_ledger = epoch_ledger;
+        self
+    }
+    #[inline(always)]
+    pub fn amount(&mut self, amount: u64) -> &mut Self {
+        self.amount = Some(amount);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = EmergencyWithdraw {
+            holder_rewards_pool: self
+                .holder_rewards_pool
+                .expect("holder_rewards_pool is not set"),
+            holder_rewards_pool_token_account: self
+                .holder_rewards_pool_token_account
+                .expect("holder_rewards_pool_token_account is not set"),
+            holder_rewards: self.holder_rewards.expect("holder_rewards is not set"),
+            token_account: self.token_account.expect("token_account is not set"),
+            mint: self.mint.expect("mint is not set"),
+            owner: self.owner.expect("owner is not set"),
+            token_program: self.token_program.unwrap_or(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            rate_history: self.rate_history,
+            stake_checkpoints: self.stake_checkpoints,
+            receipt_mint: self.receipt_mint,
+            receipt_token_account: self.receipt_token_account,
+            receipt_token_program: self.receipt_token_program,
+            epoch_ledger: self.epoch_ledger,
+        };
+        let args = EmergencyWithdrawInstructionArgs {
+            amount: self.amount.clone().expect("amount is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `emergency_withdraw` CPI accounts.
+pub struct EmergencyWithdrawCpiAccounts<'a, 'b> {
+    /// Holder rewards pool account.
+    pub holder_rewards_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Holder rewards pool token account.
+    pub holder_rewards_pool_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Holder rewards account.
+    pub holder_rewards: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token account.
+    pub token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token mint.
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Owner of the account.
+    pub owner: &'b solana_program::account_info::AccountInfo<'a>,
+    /// token program
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Rate history account, if the pool records one.
+    pub rate_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Stake checkpoints account, required if the holder records one.
+    pub stake_checkpoints: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Receipt token mint, required if the pool mints receipt tokens.
+    pub receipt_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Owner's receipt token account, required if the pool mints receipt
+    /// tokens.
+    pub receipt_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token-2022 program, required if the pool mints receipt tokens.
+    pub receipt_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Epoch ledger account, if the pool records one.
+    pub epoch_ledger: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+}
+
+/// `emergency_withdraw` CPI instruction.
+pub struct EmergencyWithdrawCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Holder rewards pool account.
+    pub holder_rewards_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Holder rewards pool token account.
+    pub holder_rewards_pool_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Holder rewards account.
+    pub holder_rewards: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token account.
+    pub token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token mint.
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Owner of the account.
+    pub owner: &'b solana_program::account_info::AccountInfo<'a>,
+    /// token program
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Rate history account, if the pool records one.
+    pub rate_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Stake checkpoints account, required if the holder records one.
+    pub stake_checkpoints: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Receipt token mint, required if the pool mints receipt tokens.
+    pub receipt_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Owner's receipt token account, required if the pool mints receipt
+    /// tokens.
+    pub receipt_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token-2022 program, required if the pool mints receipt tokens.
+    pub receipt_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Epoch ledger account, if the pool records one.
+    pub epoch_ledger: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The arguments for the instruction.
+    pub __args: EmergencyWithdrawInstructionArgs,
+}
+
+impl<'a, 'b> EmergencyWithdrawCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: EmergencyWithdrawCpiAccounts<'a, 'b>,
+        args: EmergencyWithdrawInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            holder_rewards_pool: accounts.holder_rewards_pool,
+            holder_rewards_pool_token_account: accounts.holder_rewards_pool_token_account,
+            holder_rewards: accounts.holder_rewards,
+            token_account: accounts.token_account,
+            mint: accounts.mint,
+            owner: accounts.owner,
+            token_program: accounts.token_program,
+            rate_history: accounts.rate_history,
+            stake_checkpoints: accounts.stake_checkpoints,
+            receipt_mint: accounts.receipt_mint,
+            receipt_token_account: accounts.receipt_token_account,
+            receipt_token_program: accounts.receipt_token_program,
+            epoch_ledger: accounts.epoch_ledger,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.holder_rewards_pool.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.holder_rewards_pool_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.holder_rewards.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.owner.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        if let Some(rate_history) = self.rate_history {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *rate_history.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(stake_checkpoints) = self.stake_checkpoints {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *stake_checkpoints.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(receipt_mint) = self.receipt_mint {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *receipt_mint.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(receipt_token_account) = self.receipt_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *receipt_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(receipt_token_program) = self.receipt_token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *receipt_token_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(epoch_ledger) = self.epoch_ledger {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *epoch_ledger.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = EmergencyWithdrawInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::PALADIN_REWARDS_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(13 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.holder_rewards_pool.clone());
+        account_infos.push(self.holder_rewards_pool_token_account.clone());
+        account_infos.push(self.holder_rewards.clone());
+        account_infos.push(self.token_account.clone());
+        account_infos.push(self.mint.clone());
+        account_infos.push(self.owner.clone());
+        account_infos.push(self.token_program.clone());
+        if let Some(rate_history) = self.rate_history {
+            account_infos.push(rate_history.clone());
+        }
+        if let Some(stake_checkpoints) = self.stake_checkpoints {
+            account_infos.push(stake_checkpoints.clone());
+        }
+        if let Some(receipt_mint) = self.receipt_mint {
+            account_infos.push(receipt_mint.clone());
+        }
+        if let Some(receipt_token_account) = self.receipt_token_account {
+            account_infos.push(receipt_token_account.clone());
+        }
+        if let Some(receipt_token_program) = self.receipt_token_program {
+            account_infos.push(receipt_token_program.clone());
+        }
+        if let Some(epoch_ledger) = self.epoch_ledger {
+            account_infos.push(epoch_ledger.clone());
+        }
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `EmergencyWithdraw` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` holder_rewards_pool
+///   1. `[writable]` holder_rewards_pool_token_account
+///   2. `[writable]` holder_rewards
+///   3. `[writable]` token_account
+///   4. `[]` mint
+///   5. `[writable, signer]` owner
+///   6. `[]` token_program
+///   7. `[writable, optional]` rate_history
+///   8. `[writable, optional]` stake_checkpoints
+///   9. `[writable, optional]` receipt_mint
+///   10. `[writable, optional]` receipt_token_account
+///   11. `[optional]` receipt_token_program
+///   12. `[writable, optional]` epoch_ledger
+#[derive(Clone, Debug)]
+pub struct EmergencyWithdrawCpiBuilder<'a, 'b> {
+    instruction: Box<EmergencyWithdrawCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> EmergencyWithdrawCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(EmergencyWithdrawCpiBuilderInstruction {
+            __program: program,
+            holder_rewards_pool: None,
+            holder_rewards_pool_token_account: None,
+            holder_rewards: None,
+            token_account: None,
+            mint: None,
+            owner: None,
+            token_program: None,
+            rate_history: None,
+            stake_checkpoints: None,
+            receipt_mint: None,
+            receipt_token_account: None,
+            receipt_token_program: None,
+            epoch_ledger: None,
+            amount: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Holder rewards pool account.
+    #[inline(always)]
+    pub fn holder_rewards_pool(
+        &mut self,
+        holder_rewards_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.holder_rewards_pool = Some(holder_rewards_pool);
+        self
+    }
+    /// Holder rewards pool token account.
+    #[inline(always)]
+    pub fn holder_rewards_pool_token_account(
+        &mut self,
+        holder_rewards_pool_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.holder_rewards_pool_token_account =
+            Some(holder_rewards_pool_token_account);
+        self
+    }
+    /// Holder rewards account.
+    #[inline(always)]
+    pub fn holder_rewards(
+        &mut self,
+        holder_rewards: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.holder_rewards = Some(holder_rewards);
+        self
+    }
+    /// Token account.
+    #[inline(always)]
+    pub fn token_account(
+        &mut self,
+        token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_account = Some(token_account);
+        self
+    }
+    /// Token mint.
+    #[inline(always)]
+    pub fn mint(&mut self, mint: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.mint = Some(mint);
+        self
+    }
+    /// Owner of the account.
+    #[inline(always)]
+    pub fn owner(&mut self, owner: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.owner = Some(owner);
+        self
+    }
+    /// token program
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    /// `[optional account]`
+    /// Rate history account, if the pool records one.
+    #[inline(always)]
+    pub fn rate_history(
+        &mut self,
+        rate_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.rate_history = rate_history;
+        self
+    }
+    /// `[optional account]`
+    /// Stake checkpoints account, required if the holder records one.
+    #[inline(always)]
+    pub fn stake_checkpoints(
+        &mut self,
+        stake_checkpoints: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.stake_checkpoints = stake_checkpoints;
+        self
+    }
+    /// `[optional account]`
+    /// Receipt token mint, required if the pool mints receipt tokens.
+    #[inline(always)]
+    pub fn receipt_mint(
+        &mut self,
+        receipt_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.receipt_mint = receipt_mint;
+        self
+    }
+    /// `[optional account]`
+    /// Owner's receipt token account, required if the pool mints receipt
+    /// tokens.
+    #[inline(always)]
+    pub fn receipt_token_account(
+        &mut self,
+        receipt_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.receipt_token_account = receipt_token_account;
+        self
+    }
+    /// `[optional account]`
+    /// Token-2022 program, required if the pool mints receipt tokens.
+    #[inline(always)]
+    pub fn receipt_token_program(
+        &mut self,
+        receipt_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.receipt_token_program = receipt_token_program;
+        self
+    }
+    /// `[optional account]`
+    /// Epoch ledger account, if the pool records one.
+    #[inline(always)]
+    pub fn epoch_ledger(
+        &mut self,
+        epoch_ledger: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.epoch_ledger = epoch_ledger;
+        self
+    }
+    #[inline(always)]
+    pub fn amount(&mut self, amount: u64) -> &mut Self {
+        self.instruction.amount = Some(amount);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool`
+    /// indicating whether the account is writable or not, and a `bool`
+    /// indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = EmergencyWithdrawInstructionArgs {
+            amount: self.instruction.amount.clone().expect("amount is not set"),
+        };
+        let instruction = EmergencyWithdrawCpi {
+            __program: self.instruction.__program,
+
+            holder_rewards_pool: self
+                .instruction
+                .holder_rewards_pool
+                .expect("holder_rewards_pool is not set"),
+
+            holder_rewards_pool_token_account: self
+                .instruction
+                .holder_rewards_pool_token_account
+                .expect("holder_rewards_pool_token_account is not set"),
+
+            holder_rewards: self
+                .instruction
+                .holder_rewards
+                .expect("holder_rewards is not set"),
+
+            token_account: self
+                .instruction
+                .token_account
+                .expect("token_account is not set"),
+
+            mint: self.instruction.mint.expect("mint is not set"),
+
+            owner: self.instruction.owner.expect("owner is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+
+            rate_history: self.instruction.rate_history,
+
+            stake_checkpoints: self.instruction.stake_checkpoints,
+
+            receipt_mint: self.instruction.receipt_mint,
+
+            receipt_token_account: self.instruction.receipt_token_account,
+
+            receipt_token_program: self.instruction.receipt_token_program,
+
+            epoch_ledger: self.instruction.epoch_ledger,
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct EmergencyWithdrawCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    holder_rewards_pool: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    holder_rewards_pool_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    holder_rewards: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    owner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rate_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    stake_checkpoints: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    receipt_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    receipt_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    receipt_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    epoch_ledger: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    amount: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -18,6 +18,10 @@ pub struct HarvestRewards {
     pub mint: solana_program::pubkey::Pubkey,
     /// owner of token account
     pub owner: solana_program::pubkey::Pubkey,
+    /// Rate history account, if the pool records one.
+    pub rate_history: Option<solana_program::pubkey::Pubkey>,
+    /// Epoch ledger account, if the pool records one.
+    pub epoch_ledger: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl HarvestRewards {
@@ -29,7 +33,7 @@ impl HarvestRewards {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.holder_rewards_pool,
             false,
@@ -48,6 +52,28 @@ impl HarvestRewards {
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.owner, true,
         ));
+        if let Some(rate_history) = self.rate_history {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                rate_history,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(epoch_ledger) = self.epoch_ledger {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                epoch_ledger,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let data = HarvestRewardsInstructionData::new().try_to_vec().unwrap();
 
@@ -85,6 +111,8 @@ impl Default for HarvestRewardsInstructionData {
 ///   2. `[writable]` holder_rewards
 ///   3. `[]` mint
 ///   4. `[writable, signer]` owner
+///   5. `[writable, optional]` rate_history
+///   6. `[writable, optional]` epoch_ledger
 #[derive(Clone, Debug, Default)]
 pub struct HarvestRewardsBuilder {
     holder_rewards_pool: Option<solana_program::pubkey::Pubkey>,
@@ -92,6 +120,8 @@ pub struct HarvestRewardsBuilder {
     holder_rewards: Option<solana_program::pubkey::Pubkey>,
     mint: Option<solana_program::pubkey::Pubkey>,
     owner: Option<solana_program::pubkey::Pubkey>,
+    rate_history: Option<solana_program::pubkey::Pubkey>,
+    epoch_ledger: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -135,6 +165,26 @@ impl HarvestRewardsBuilder {
         self.owner = Some(owner);
         self
     }
+    /// `[optional account]`
+    /// Rate history account, if the pool records one.
+    #[inline(always)]
+    pub fn rate_history(
+        &mut self,
+        rate_history: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.rate_history = rate_history;
+        self
+    }
+    /// `[optional account]`
+    /// Epoch ledger account, if the pool records one.
+    #[inline(always)]
+    pub fn epoch_ledger(
+        &mut self,
+        epoch_ledger: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.epoch_ledger = epoch_ledger;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -165,6 +215,8 @@ impl HarvestRewardsBuilder {
             holder_rewards: self.holder_rewards.expect("holder_rewards is not set"),
             mint: self.mint.expect("mint is not set"),
             owner: self.owner.expect("owner is not set"),
+            rate_history: self.rate_history,
+            epoch_ledger: self.epoch_ledger,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -183,6 +235,10 @@ pub struct HarvestRewardsCpiAccounts<'a, 'b> {
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
     /// owner of token account
     pub owner: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Rate history account, if the pool records one.
+    pub rate_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Epoch ledger account, if the pool records one.
+    pub epoch_ledger: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `harvest_rewards` CPI instruction.
@@ -199,6 +255,10 @@ pub struct HarvestRewardsCpi<'a, 'b> {
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
     /// owner of token account
     pub owner: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Rate history account, if the pool records one.
+    pub rate_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Epoch ledger account, if the pool records one.
+    pub epoch_ledger: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 impl<'a, 'b> HarvestRewardsCpi<'a, 'b> {
@@ -213,6 +273,8 @@ impl<'a, 'b> HarvestRewardsCpi<'a, 'b> {
             holder_rewards: accounts.holder_rewards,
             mint: accounts.mint,
             owner: accounts.owner,
+            rate_history: accounts.rate_history,
+            epoch_ledger: accounts.epoch_ledger,
         }
     }
     #[inline(always)]
@@ -248,7 +310,7 @@ impl<'a, 'b> HarvestRewardsCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.holder_rewards_pool.key,
             false,
@@ -269,6 +331,28 @@ impl<'a, 'b> HarvestRewardsCpi<'a, 'b> {
             *self.owner.key,
             true,
         ));
+        if let Some(rate_history) = self.rate_history {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *rate_history.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(epoch_ledger) = self.epoch_ledger {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *epoch_ledger.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -283,13 +367,19 @@ impl<'a, 'b> HarvestRewardsCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.holder_rewards_pool.clone());
         account_infos.push(self.holder_rewards_pool_token_account.clone());
         account_infos.push(self.holder_rewards.clone());
         account_infos.push(self.mint.clone());
         account_infos.push(self.owner.clone());
+        if let Some(rate_history) = self.rate_history {
+            account_infos.push(rate_history.clone());
+        }
+        if let Some(epoch_ledger) = self.epoch_ledger {
+            account_infos.push(epoch_ledger.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -311,6 +401,8 @@ impl<'a, 'b> HarvestRewardsCpi<'a, 'b> {
 ///   2. `[writable]` holder_rewards
 ///   3. `[]` mint
 ///   4. `[writable, signer]` owner
+///   5. `[writable, optional]` rate_history
+///   6. `[writable, optional]` epoch_ledger
 #[derive(Clone, Debug)]
 pub struct HarvestRewardsCpiBuilder<'a, 'b> {
     instruction: Box<HarvestRewardsCpiBuilderInstruction<'a, 'b>>,
@@ -325,6 +417,8 @@ impl<'a, 'b> HarvestRewardsCpiBuilder<'a, 'b> {
             holder_rewards: None,
             mint: None,
             owner: None,
+            rate_history: None,
+            epoch_ledger: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -369,6 +463,26 @@ impl<'a, 'b> HarvestRewardsCpiBuilder<'a, 'b> {
         self.instruction.owner = Some(owner);
         self
     }
+    /// `[optional account]`
+    /// Rate history account, if the pool records one.
+    #[inline(always)]
+    pub fn rate_history(
+        &mut self,
+        rate_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.rate_history = rate_history;
+        self
+    }
+    /// `[optional account]`
+    /// Epoch ledger account, if the pool records one.
+    #[inline(always)]
+    pub fn epoch_ledger(
+        &mut self,
+        epoch_ledger: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.epoch_ledger = epoch_ledger;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -432,6 +546,10 @@ impl<'a, 'b> HarvestRewardsCpiBuilder<'a, 'b> {
             mint: self.instruction.mint.expect("mint is not set"),
 
             owner: self.instruction.owner.expect("owner is not set"),
+
+            rate_history: self.instruction.rate_history,
+
+            epoch_ledger: self.instruction.epoch_ledger,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -448,6 +566,8 @@ struct HarvestRewardsCpiBuilderInstruction<'a, 'b> {
     holder_rewards: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     owner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rate_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    epoch_ledger: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
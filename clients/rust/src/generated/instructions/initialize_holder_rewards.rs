@@ -20,8 +20,16 @@ pub struct InitializeHolderRewards {
     pub mint: solana_program::pubkey::Pubkey,
     /// DUNA document PDA account
     pub duna_document_pda: solana_program::pubkey::Pubkey,
+    /// Payer funding the rent for the holder rewards account.
+    pub payer: solana_program::pubkey::Pubkey,
     /// System program.
     pub system_program: solana_program::pubkey::Pubkey,
+    /// Rate history account, if the pool records one.
+    pub rate_history: Option<solana_program::pubkey::Pubkey>,
+    /// Owner's access entry account, required if the pool has an access list.
+    pub access_entry: Option<solana_program::pubkey::Pubkey>,
+    /// Epoch ledger account, if the pool records one.
+    pub epoch_ledger: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl InitializeHolderRewards {
@@ -33,7 +41,7 @@ impl InitializeHolderRewards {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.holder_rewards_pool,
             false,
@@ -56,10 +64,46 @@ impl InitializeHolderRewards {
             self.duna_document_pda,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
         ));
+        if let Some(rate_history) = self.rate_history {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                rate_history,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(access_entry) = self.access_entry {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                access_entry,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(epoch_ledger) = self.epoch_ledger {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                epoch_ledger,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let data = InitializeHolderRewardsInstructionData::new()
             .try_to_vec()
@@ -100,8 +144,12 @@ impl Default for InitializeHolderRewardsInstructionData {
 ///   3. `[writable]` holder_rewards
 ///   4. `[]` mint
 ///   5. `[]` duna_document_pda
-///   6. `[optional]` system_program (default to
+///   6. `[writable, signer]` payer
+///   7. `[optional]` system_program (default to
 ///      `11111111111111111111111111111111`)
+///   8. `[writable, optional]` rate_history
+///   9. `[optional]` access_entry
+///   10. `[writable, optional]` epoch_ledger
 #[derive(Clone, Debug, Default)]
 pub struct InitializeHolderRewardsBuilder {
     holder_rewards_pool: Option<solana_program::pubkey::Pubkey>,
@@ -110,7 +158,11 @@ pub struct InitializeHolderRewardsBuilder {
     holder_rewards: Option<solana_program::pubkey::Pubkey>,
     mint: Option<solana_program::pubkey::Pubkey>,
     duna_document_pda: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    rate_history: Option<solana_program::pubkey::Pubkey>,
+    access_entry: Option<solana_program::pubkey::Pubkey>,
+    epoch_ledger: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -163,6 +215,12 @@ impl InitializeHolderRewardsBuilder {
         self.duna_document_pda = Some(duna_document_pda);
         self
     }
+    /// Payer funding the rent for the holder rewards account.
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
     /// `[optional account, default to '11111111111111111111111111111111']`
     /// System program.
     #[inline(always)]
@@ -170,6 +228,36 @@ impl InitializeHolderRewardsBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// Rate history account, if the pool records one.
+    #[inline(always)]
+    pub fn rate_history(
+        &mut self,
+        rate_history: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.rate_history = rate_history;
+        self
+    }
+    /// `[optional account]`
+    /// Owner's access entry account, required if the pool has an access list.
+    #[inline(always)]
+    pub fn access_entry(
+        &mut self,
+        access_entry: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.access_entry = access_entry;
+        self
+    }
+    /// `[optional account]`
+    /// Epoch ledger account, if the pool records one.
+    #[inline(always)]
+    pub fn epoch_ledger(
+        &mut self,
+        epoch_ledger: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.epoch_ledger = epoch_ledger;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -203,9 +291,13 @@ impl InitializeHolderRewardsBuilder {
             duna_document_pda: self
                 .duna_document_pda
                 .expect("duna_document_pda is not set"),
+            payer: self.payer.expect("payer is not set"),
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            rate_history: self.rate_history,
+            access_entry: self.access_entry,
+            epoch_ledger: self.epoch_ledger,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -226,8 +318,16 @@ pub struct InitializeHolderRewardsCpiAccounts<'a, 'b> {
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
     /// DUNA document PDA account
     pub duna_document_pda: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Payer funding the rent for the holder rewards account.
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
     /// System program.
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Rate history account, if the pool records one.
+    pub rate_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Owner's access entry account, required if the pool has an access list.
+    pub access_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Epoch ledger account, if the pool records one.
+    pub epoch_ledger: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `initialize_holder_rewards` CPI instruction.
@@ -246,8 +346,16 @@ pub struct InitializeHolderRewardsCpi<'a, 'b> {
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
     /// DUNA document PDA account
     pub duna_document_pda: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Payer funding the rent for the holder rewards account.
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
     /// System program.
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Rate history account, if the pool records one.
+    pub rate_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Owner's access entry account, required if the pool has an access list.
+    pub access_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Epoch ledger account, if the pool records one.
+    pub epoch_ledger: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 impl<'a, 'b> InitializeHolderRewardsCpi<'a, 'b> {
@@ -263,7 +371,11 @@ impl<'a, 'b> InitializeHolderRewardsCpi<'a, 'b> {
             holder_rewards: accounts.holder_rewards,
             mint: accounts.mint,
             duna_document_pda: accounts.duna_document_pda,
+            payer: accounts.payer,
             system_program: accounts.system_program,
+            rate_history: accounts.rate_history,
+            access_entry: accounts.access_entry,
+            epoch_ledger: accounts.epoch_ledger,
         }
     }
     #[inline(always)]
@@ -299,7 +411,7 @@ impl<'a, 'b> InitializeHolderRewardsCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.holder_rewards_pool.key,
             false,
@@ -324,10 +436,47 @@ impl<'a, 'b> InitializeHolderRewardsCpi<'a, 'b> {
             *self.duna_document_pda.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
         ));
+        if let Some(rate_history) = self.rate_history {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *rate_history.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(access_entry) = self.access_entry {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *access_entry.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(epoch_ledger) = self.epoch_ledger {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *epoch_ledger.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -344,7 +493,7 @@ impl<'a, 'b> InitializeHolderRewardsCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(11 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.holder_rewards_pool.clone());
         account_infos.push(self.holder_rewards_pool_token_account.clone());
@@ -352,7 +501,17 @@ impl<'a, 'b> InitializeHolderRewardsCpi<'a, 'b> {
         account_infos.push(self.holder_rewards.clone());
         account_infos.push(self.mint.clone());
         account_infos.push(self.duna_document_pda.clone());
+        account_infos.push(self.payer.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(rate_history) = self.rate_history {
+            account_infos.push(rate_history.clone());
+        }
+        if let Some(access_entry) = self.access_entry {
+            account_infos.push(access_entry.clone());
+        }
+        if let Some(epoch_ledger) = self.epoch_ledger {
+            account_infos.push(epoch_ledger.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -375,7 +534,11 @@ impl<'a, 'b> InitializeHolderRewardsCpi<'a, 'b> {
 ///   3. `[writable]` holder_rewards
 ///   4. `[]` mint
 ///   5. `[]` duna_document_pda
-///   6. `[]` system_program
+///   6. `[writable, signer]` payer
+///   7. `[]` system_program
+///   8. `[writable, optional]` rate_history
+///   9. `[optional]` access_entry
+///   10. `[writable, optional]` epoch_ledger
 #[derive(Clone, Debug)]
 pub struct InitializeHolderRewardsCpiBuilder<'a, 'b> {
     instruction: Box<InitializeHolderRewardsCpiBuilderInstruction<'a, 'b>>,
@@ -391,7 +554,11 @@ impl<'a, 'b> InitializeHolderRewardsCpiBuilder<'a, 'b> {
             holder_rewards: None,
             mint: None,
             duna_document_pda: None,
+            payer: None,
             system_program: None,
+            rate_history: None,
+            access_entry: None,
+            epoch_ledger: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -445,6 +612,12 @@ impl<'a, 'b> InitializeHolderRewardsCpiBuilder<'a, 'b> {
         self.instruction.duna_document_pda = Some(duna_document_pda);
         self
     }
+    /// Payer funding the rent for the holder rewards account.
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
     /// System program.
     #[inline(always)]
     pub fn system_program(
@@ -454,6 +627,36 @@ impl<'a, 'b> InitializeHolderRewardsCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// Rate history account, if the pool records one.
+    #[inline(always)]
+    pub fn rate_history(
+        &mut self,
+        rate_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.rate_history = rate_history;
+        self
+    }
+    /// `[optional account]`
+    /// Owner's access entry account, required if the pool has an access list.
+    #[inline(always)]
+    pub fn access_entry(
+        &mut self,
+        access_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.access_entry = access_entry;
+        self
+    }
+    /// `[optional account]`
+    /// Epoch ledger account, if the pool records one.
+    #[inline(always)]
+    pub fn epoch_ledger(
+        &mut self,
+        epoch_ledger: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.epoch_ledger = epoch_ledger;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -523,10 +726,18 @@ impl<'a, 'b> InitializeHolderRewardsCpiBuilder<'a, 'b> {
                 .duna_document_pda
                 .expect("duna_document_pda is not set"),
 
+            payer: self.instruction.payer.expect("payer is not set"),
+
             system_program: self
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            rate_history: self.instruction.rate_history,
+
+            access_entry: self.instruction.access_entry,
+
+            epoch_ledger: self.instruction.epoch_ledger,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -544,7 +755,11 @@ struct InitializeHolderRewardsCpiBuilderInstruction<'a, 'b> {
     holder_rewards: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     duna_document_pda: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rate_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    access_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    epoch_ledger: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
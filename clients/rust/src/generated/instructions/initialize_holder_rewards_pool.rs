@@ -18,6 +18,10 @@ pub struct InitializeHolderRewardsPool {
     pub stake_vault_pda: solana_program::pubkey::Pubkey,
     /// Token mint.
     pub vault_holder_rewards: solana_program::pubkey::Pubkey,
+    /// Pool authority, the mint's mint authority.
+    pub authority: solana_program::pubkey::Pubkey,
+    /// Payer funding the rent for the new accounts.
+    pub payer: solana_program::pubkey::Pubkey,
     /// System program.
     pub system_program: solana_program::pubkey::Pubkey,
 }
@@ -35,7 +39,7 @@ impl InitializeHolderRewardsPool {
         args: InitializeHolderRewardsPoolInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.holder_rewards_pool,
             false,
@@ -55,6 +59,13 @@ impl InitializeHolderRewardsPool {
             self.vault_holder_rewards,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.authority,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
@@ -106,7 +117,9 @@ pub struct InitializeHolderRewardsPoolInstructionArgs {
 ///   2. `[]` mint
 ///   3. `[]` stake_vault_pda
 ///   4. `[writable]` vault_holder_rewards
-///   5. `[optional]` system_program (default to
+///   5. `[signer]` authority
+///   6. `[writable, signer]` payer
+///   7. `[optional]` system_program (default to
 ///      `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct InitializeHolderRewardsPoolBuilder {
@@ -115,6 +128,8 @@ pub struct InitializeHolderRewardsPoolBuilder {
     mint: Option<solana_program::pubkey::Pubkey>,
     stake_vault_pda: Option<solana_program::pubkey::Pubkey>,
     vault_holder_rewards: Option<solana_program::pubkey::Pubkey>,
+    authority: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     duna_document_hash: Option<[u8; 32]>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
@@ -166,6 +181,18 @@ impl InitializeHolderRewardsPoolBuilder {
         self.vault_holder_rewards = Some(vault_holder_rewards);
         self
     }
+    /// Pool authority, the mint's mint authority.
+    #[inline(always)]
+    pub fn authority(&mut self, authority: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// Payer funding the rent for the new accounts.
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
     /// `[optional account, default to '11111111111111111111111111111111']`
     /// System program.
     #[inline(always)]
@@ -210,6 +237,8 @@ impl InitializeHolderRewardsPoolBuilder {
             vault_holder_rewards: self
                 .vault_holder_rewards
                 .expect("vault_holder_rewards is not set"),
+            authority: self.authority.expect("authority is not set"),
+            payer: self.payer.expect("payer is not set"),
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
@@ -237,6 +266,10 @@ pub struct InitializeHolderRewardsPoolCpiAccounts<'a, 'b> {
     pub stake_vault_pda: &'b solana_program::account_info::AccountInfo<'a>,
     /// Token mint.
     pub vault_holder_rewards: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Pool authority, the mint's mint authority.
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Payer funding the rent for the new accounts.
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
     /// System program.
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 }
@@ -255,6 +288,10 @@ pub struct InitializeHolderRewardsPoolCpi<'a, 'b> {
     pub stake_vault_pda: &'b solana_program::account_info::AccountInfo<'a>,
     /// Token mint.
     pub vault_holder_rewards: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Pool authority, the mint's mint authority.
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Payer funding the rent for the new accounts.
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
     /// System program.
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
@@ -274,6 +311,8 @@ impl<'a, 'b> InitializeHolderRewardsPoolCpi<'a, 'b> {
             mint: accounts.mint,
             stake_vault_pda: accounts.stake_vault_pda,
             vault_holder_rewards: accounts.vault_holder_rewards,
+            authority: accounts.authority,
+            payer: accounts.payer,
             system_program: accounts.system_program,
             __args: args,
         }
@@ -311,7 +350,7 @@ impl<'a, 'b> InitializeHolderRewardsPoolCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.holder_rewards_pool.key,
             false,
@@ -332,6 +371,14 @@ impl<'a, 'b> InitializeHolderRewardsPoolCpi<'a, 'b> {
             *self.vault_holder_rewards.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.authority.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
@@ -354,13 +401,15 @@ impl<'a, 'b> InitializeHolderRewardsPoolCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(8 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.holder_rewards_pool.clone());
         account_infos.push(self.holder_rewards_pool_token_account.clone());
         account_infos.push(self.mint.clone());
         account_infos.push(self.stake_vault_pda.clone());
         account_infos.push(self.vault_holder_rewards.clone());
+        account_infos.push(self.authority.clone());
+        account_infos.push(self.payer.clone());
         account_infos.push(self.system_program.clone());
         remaining_accounts
             .iter()
@@ -383,7 +432,9 @@ impl<'a, 'b> InitializeHolderRewardsPoolCpi<'a, 'b> {
 ///   2. `[]` mint
 ///   3. `[]` stake_vault_pda
 ///   4. `[writable]` vault_holder_rewards
-///   5. `[]` system_program
+///   5. `[signer]` authority
+///   6. `[writable, signer]` payer
+///   7. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct InitializeHolderRewardsPoolCpiBuilder<'a, 'b> {
     instruction: Box<InitializeHolderRewardsPoolCpiBuilderInstruction<'a, 'b>>,
@@ -398,6 +449,8 @@ impl<'a, 'b> InitializeHolderRewardsPoolCpiBuilder<'a, 'b> {
             mint: None,
             stake_vault_pda: None,
             vault_holder_rewards: None,
+            authority: None,
+            payer: None,
             system_program: None,
             duna_document_hash: None,
             __remaining_accounts: Vec::new(),
@@ -447,6 +500,21 @@ impl<'a, 'b> InitializeHolderRewardsPoolCpiBuilder<'a, 'b> {
         self.instruction.vault_holder_rewards = Some(vault_holder_rewards);
         self
     }
+    /// Pool authority, the mint's mint authority.
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority = Some(authority);
+        self
+    }
+    /// Payer funding the rent for the new accounts.
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
     /// System program.
     #[inline(always)]
     pub fn system_program(
@@ -535,6 +603,10 @@ impl<'a, 'b> InitializeHolderRewardsPoolCpiBuilder<'a, 'b> {
                 .vault_holder_rewards
                 .expect("vault_holder_rewards is not set"),
 
+            authority: self.instruction.authority.expect("authority is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
             system_program: self
                 .instruction
                 .system_program
@@ -556,6 +628,8 @@ struct InitializeHolderRewardsPoolCpiBuilderInstruction<'a, 'b> {
     mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     stake_vault_pda: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     vault_holder_rewards: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     duna_document_hash: Option<[u8; 32]>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -4,14 +4,48 @@
 //!
 //! <https://github.com/kinobi-so/kinobi>
 
+pub(crate) mod r#checkpoint_voting_power;
 pub(crate) mod r#close_holder_rewards;
+pub(crate) mod r#create_campaign;
 pub(crate) mod r#deposit;
+pub(crate) mod r#distribute_rewards;
+pub(crate) mod r#emergency_withdraw;
+pub(crate) mod r#get_pending_rewards;
+pub(crate) mod r#get_stake_at;
+pub(crate) mod r#get_voting_power;
 pub(crate) mod r#harvest_rewards;
+pub(crate) mod r#initialize_epoch_ledger;
 pub(crate) mod r#initialize_holder_rewards;
 pub(crate) mod r#initialize_holder_rewards_pool;
+pub(crate) mod r#initialize_rate_history;
+pub(crate) mod r#initialize_receipt_mint;
+pub(crate) mod r#initialize_stake_checkpoints;
+pub(crate) mod r#initialize_vote_escrow;
+pub(crate) mod r#lock;
+pub(crate) mod r#migrate_holder_rewards;
+pub(crate) mod r#migrate_holder_rewards_pool;
+pub(crate) mod r#reclaim_campaign_rewards;
+pub(crate) mod r#recover_excess_tokens;
+pub(crate) mod r#release_campaign_rewards;
+pub(crate) mod r#release_lockup;
+pub(crate) mod r#set_access_entry;
+pub(crate) mod r#set_access_list;
+pub(crate) mod r#set_deposit_limits;
+pub(crate) mod r#set_early_withdrawal_penalty;
+pub(crate) mod r#set_lockup_tiers;
+pub(crate) mod r#set_slashing_config;
+pub(crate) mod r#slash;
 pub(crate) mod r#withdraw;
 
 pub use self::{
-    r#close_holder_rewards::*, r#deposit::*, r#harvest_rewards::*, r#initialize_holder_rewards::*,
-    r#initialize_holder_rewards_pool::*, r#withdraw::*,
+    r#checkpoint_voting_power::*, r#close_holder_rewards::*, r#create_campaign::*, r#deposit::*,
+    r#distribute_rewards::*, r#emergency_withdraw::*, r#get_pending_rewards::*, r#get_stake_at::*,
+    r#get_voting_power::*, r#harvest_rewards::*, r#initialize_epoch_ledger::*,
+    r#initialize_holder_rewards::*, r#initialize_holder_rewards_pool::*,
+    r#initialize_rate_history::*, r#initialize_receipt_mint::*, r#initialize_stake_checkpoints::*,
+    r#initialize_vote_escrow::*, r#lock::*, r#migrate_holder_rewards::*,
+    r#migrate_holder_rewards_pool::*, r#reclaim_campaign_rewards::*, r#recover_excess_tokens::*,
+    r#release_campaign_rewards::*, r#release_lockup::*, r#set_access_entry::*,
+    r#set_access_list::*, r#set_deposit_limits::*, r#set_early_withdrawal_penalty::*,
+    r#set_lockup_tiers::*, r#set_slashing_config::*, r#slash::*, r#withdraw::*,
 };
@@ -14,7 +14,9 @@ pub struct Withdraw {
     pub holder_rewards_pool_token_account: solana_program::pubkey::Pubkey,
     /// Holder rewards account.
     pub holder_rewards: solana_program::pubkey::Pubkey,
-    /// Token account.
+    /// Owner's token account, unused if a destination token account is
+    /// provided, in which case any account may be passed, such as the
+    /// destination.
     pub token_account: solana_program::pubkey::Pubkey,
     /// Token mint.
     pub mint: solana_program::pubkey::Pubkey,
@@ -22,6 +24,22 @@ pub struct Withdraw {
     pub owner: solana_program::pubkey::Pubkey,
     /// token program
     pub token_program: solana_program::pubkey::Pubkey,
+    /// Rate history account, if the pool records one.
+    pub rate_history: Option<solana_program::pubkey::Pubkey>,
+    /// Stake checkpoints account, required if the holder records one.
+    pub stake_checkpoints: Option<solana_program::pubkey::Pubkey>,
+    /// Receipt token mint, required if the pool mints receipt tokens.
+    pub receipt_mint: Option<solana_program::pubkey::Pubkey>,
+    /// Owner's receipt token account, required if the pool mints receipt
+    /// tokens.
+    pub receipt_token_account: Option<solana_program::pubkey::Pubkey>,
+    /// Token-2022 program, required if the pool mints receipt tokens.
+    pub receipt_token_program: Option<solana_program::pubkey::Pubkey>,
+    /// Token account receiving the withdrawn tokens instead of the owner's
+    /// token account.
+    pub destination_token_account: Option<solana_program::pubkey::Pubkey>,
+    /// Epoch ledger account, if the pool records one.
+    pub epoch_ledger: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl Withdraw {
@@ -37,7 +55,7 @@ impl Withdraw {
         args: WithdrawInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.holder_rewards_pool,
             false,
@@ -64,6 +82,83 @@ impl Withdraw {
             self.token_program,
             false,
         ));
+        if let Some(rate_history) = self.rate_history {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                rate_history,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(stake_checkpoints) = self.stake_checkpoints {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                stake_checkpoints,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(receipt_mint) = self.receipt_mint {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                receipt_mint,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(receipt_token_account) = self.receipt_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                receipt_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(receipt_token_program) = self.receipt_token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                receipt_token_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(destination_token_account) = self.destination_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                destination_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(epoch_ledger) = self.epoch_ledger {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                epoch_ledger,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = WithdrawInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -112,6 +207,13 @@ pub struct WithdrawInstructionArgs {
 ///   5. `[writable, signer]` owner
 ///   6. `[optional]` token_program (default to
 ///      `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   7. `[writable, optional]` rate_history
+///   8. `[writable, optional]` stake_checkpoints
+///   9. `[writable, optional]` receipt_mint
+///   10. `[writable, optional]` receipt_token_account
+///   11. `[optional]` receipt_token_program
+///   12. `[writable, optional]` destination_token_account
+///   13. `[writable, optional]` epoch_ledger
 #[derive(Clone, Debug, Default)]
 pub struct WithdrawBuilder {
     holder_rewards_pool: Option<solana_program::pubkey::Pubkey>,
@@ -121,6 +223,13 @@ pub struct WithdrawBuilder {
     mint: Option<solana_program::pubkey::Pubkey>,
     owner: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
+    rate_history: Option<solana_program::pubkey::Pubkey>,
+    stake_checkpoints: Option<solana_program::pubkey::Pubkey>,
+    receipt_mint: Option<solana_program::pubkey::Pubkey>,
+    receipt_token_account: Option<solana_program::pubkey::Pubkey>,
+    receipt_token_program: Option<solana_program::pubkey::Pubkey>,
+    destination_token_account: Option<solana_program::pubkey::Pubkey>,
+    epoch_ledger: Option<solana_program::pubkey::Pubkey>,
     amount: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -153,7 +262,9 @@ impl WithdrawBuilder {
         self.holder_rewards = Some(holder_rewards);
         self
     }
-    /// Token account.
+    /// Owner's token account, unused if a destination token account is
+    /// provided, in which case any account may be passed, such as the
+    /// destination.
     #[inline(always)]
     pub fn token_account(&mut self, token_account: solana_program::pubkey::Pubkey) -> &mut Self {
         self.token_account = Some(token_account);
@@ -178,6 +289,78 @@ impl WithdrawBuilder {
         self.token_program = Some(token_program);
         self
     }
+    /// `[optional account]`
+    /// Rate history account, if the pool records one.
+    #[inline(always)]
+    pub fn rate_history(
+        &mut self,
+        rate_history: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.rate_history = rate_history;
+        self
+    }
+    /// `[optional account]`
+    /// Stake checkpoints account, required if the holder records one.
+    #[inline(always)]
+    pub fn stake_checkpoints(
+        &mut self,
+        stake_checkpoints: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.stake_checkpoints = stake_checkpoints;
+        self
+    }
+    /// `[optional account]`
+    /// Receipt token mint, required if the pool mints receipt tokens.
+    #[inline(always)]
+    pub fn receipt_mint(
+        &mut self,
+        receipt_mint: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.receipt_mint = receipt_mint;
+        self
+    }
+    /// `[optional account]`
+    /// Owner's receipt token account, required if the pool mints receipt
+    /// tokens.
+    #[inline(always)]
+    pub fn receipt_token_account(
+        &mut self,
+        receipt_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.receipt_token_account = receipt_token_account;
+        self
+    }
+    /// `[optional account]`
+    /// Token-2022 program, required if the pool mints receipt tokens.
+    #[inline(always)]
+    pub fn receipt_token_program(
+        &mut self,
+        receipt_token_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.receipt_token_program = receipt_token_program;
+        self
+    }
+    /// `[optional account]`
+    /// Token account receiving the withdrawn tokens instead of the owner's
+    /// token account.
+    #[inline(always)]
+    pub fn destination_token_account(
+        &mut self,
+        destination_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.destination_token_account = destination_token_account;
+        self
+    }
+    /// `[optional account]`
+    /// Epoch ledger account, if the pool records one.
+    #[inline(always)]
+    pub fn epoch_ledger(
+        &mut self,
+        epoch_ledger: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.epoch_ledger = epoch_ledger;
+        self
+    }
     #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.amount = Some(amount);
@@ -217,6 +400,13 @@ impl WithdrawBuilder {
             token_program: self.token_program.unwrap_or(solana_program::pubkey!(
                 "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
             )),
+            rate_history: self.rate_history,
+            stake_checkpoints: self.stake_checkpoints,
+            receipt_mint: self.receipt_mint,
+            receipt_token_account: self.receipt_token_account,
+            receipt_token_program: self.receipt_token_program,
+            destination_token_account: self.destination_token_account,
+            epoch_ledger: self.epoch_ledger,
         };
         let args = WithdrawInstructionArgs {
             amount: self.amount.clone().expect("amount is not set"),
@@ -234,7 +424,9 @@ pub struct WithdrawCpiAccounts<'a, 'b> {
     pub holder_rewards_pool_token_account: &'b solana_program::account_info::AccountInfo<'a>,
     /// Holder rewards account.
     pub holder_rewards: &'b solana_program::account_info::AccountInfo<'a>,
-    /// Token account.
+    /// Owner's token account, unused if a destination token account is
+    /// provided, in which case any account may be passed, such as the
+    /// destination.
     pub token_account: &'b solana_program::account_info::AccountInfo<'a>,
     /// Token mint.
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
@@ -242,6 +434,22 @@ pub struct WithdrawCpiAccounts<'a, 'b> {
     pub owner: &'b solana_program::account_info::AccountInfo<'a>,
     /// token program
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Rate history account, if the pool records one.
+    pub rate_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Stake checkpoints account, required if the holder records one.
+    pub stake_checkpoints: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Receipt token mint, required if the pool mints receipt tokens.
+    pub receipt_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Owner's receipt token account, required if the pool mints receipt
+    /// tokens.
+    pub receipt_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token-2022 program, required if the pool mints receipt tokens.
+    pub receipt_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token account receiving the withdrawn tokens instead of the owner's
+    /// token account.
+    pub destination_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Epoch ledger account, if the pool records one.
+    pub epoch_ledger: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `withdraw` CPI instruction.
@@ -254,7 +462,9 @@ pub struct WithdrawCpi<'a, 'b> {
     pub holder_rewards_pool_token_account: &'b solana_program::account_info::AccountInfo<'a>,
     /// Holder rewards account.
     pub holder_rewards: &'b solana_program::account_info::AccountInfo<'a>,
-    /// Token account.
+    /// Owner's token account, unused if a destination token account is
+    /// provided, in which case any account may be passed, such as the
+    /// destination.
     pub token_account: &'b solana_program::account_info::AccountInfo<'a>,
     /// Token mint.
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
@@ -262,6 +472,22 @@ pub struct WithdrawCpi<'a, 'b> {
     pub owner: &'b solana_program::account_info::AccountInfo<'a>,
     /// token program
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Rate history account, if the pool records one.
+    pub rate_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Stake checkpoints account, required if the holder records one.
+    pub stake_checkpoints: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Receipt token mint, required if the pool mints receipt tokens.
+    pub receipt_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Owner's receipt token account, required if the pool mints receipt
+    /// tokens.
+    pub receipt_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token-2022 program, required if the pool mints receipt tokens.
+    pub receipt_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token account receiving the withdrawn tokens instead of the owner's
+    /// token account.
+    pub destination_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Epoch ledger account, if the pool records one.
+    pub epoch_ledger: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: WithdrawInstructionArgs,
 }
@@ -281,6 +507,13 @@ impl<'a, 'b> WithdrawCpi<'a, 'b> {
             mint: accounts.mint,
             owner: accounts.owner,
             token_program: accounts.token_program,
+            rate_history: accounts.rate_history,
+            stake_checkpoints: accounts.stake_checkpoints,
+            receipt_mint: accounts.receipt_mint,
+            receipt_token_account: accounts.receipt_token_account,
+            receipt_token_program: accounts.receipt_token_program,
+            destination_token_account: accounts.destination_token_account,
+            epoch_ledger: accounts.epoch_ledger,
             __args: args,
         }
     }
@@ -317,7 +550,7 @@ impl<'a, 'b> WithdrawCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.holder_rewards_pool.key,
             false,
@@ -346,6 +579,83 @@ impl<'a, 'b> WithdrawCpi<'a, 'b> {
             *self.token_program.key,
             false,
         ));
+        if let Some(rate_history) = self.rate_history {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *rate_history.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(stake_checkpoints) = self.stake_checkpoints {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *stake_checkpoints.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(receipt_mint) = self.receipt_mint {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *receipt_mint.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(receipt_token_account) = self.receipt_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *receipt_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(receipt_token_program) = self.receipt_token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *receipt_token_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(destination_token_account) = self.destination_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *destination_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
+        if let Some(epoch_ledger) = self.epoch_ledger {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *epoch_ledger.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PALADIN_REWARDS_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -362,7 +672,7 @@ impl<'a, 'b> WithdrawCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(14 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.holder_rewards_pool.clone());
         account_infos.push(self.holder_rewards_pool_token_account.clone());
@@ -371,6 +681,27 @@ impl<'a, 'b> WithdrawCpi<'a, 'b> {
         account_infos.push(self.mint.clone());
         account_infos.push(self.owner.clone());
         account_infos.push(self.token_program.clone());
+        if let Some(rate_history) = self.rate_history {
+            account_infos.push(rate_history.clone());
+        }
+        if let Some(stake_checkpoints) = self.stake_checkpoints {
+            account_infos.push(stake_checkpoints.clone());
+        }
+        if let Some(receipt_mint) = self.receipt_mint {
+            account_infos.push(receipt_mint.clone());
+        }
+        if let Some(receipt_token_account) = self.receipt_token_account {
+            account_infos.push(receipt_token_account.clone());
+        }
+        if let Some(receipt_token_program) = self.receipt_token_program {
+            account_infos.push(receipt_token_program.clone());
+        }
+        if let Some(destination_token_account) = self.destination_token_account {
+            account_infos.push(destination_token_account.clone());
+        }
+        if let Some(epoch_ledger) = self.epoch_ledger {
+            account_infos.push(epoch_ledger.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -394,6 +725,13 @@ impl<'a, 'b> WithdrawCpi<'a, 'b> {
 ///   4. `[]` mint
 ///   5. `[writable, signer]` owner
 ///   6. `[]` token_program
+///   7. `[writable, optional]` rate_history
+///   8. `[writable, optional]` stake_checkpoints
+///   9. `[writable, optional]` receipt_mint
+///   10. `[writable, optional]` receipt_token_account
+///   11. `[optional]` receipt_token_program
+///   12. `[writable, optional]` destination_token_account
+///   13. `[writable, optional]` epoch_ledger
 #[derive(Clone, Debug)]
 pub struct WithdrawCpiBuilder<'a, 'b> {
     instruction: Box<WithdrawCpiBuilderInstruction<'a, 'b>>,
@@ -410,6 +748,13 @@ impl<'a, 'b> WithdrawCpiBuilder<'a, 'b> {
             mint: None,
             owner: None,
             token_program: None,
+            rate_history: None,
+            stake_checkpoints: None,
+            receipt_mint: None,
+            receipt_token_account: None,
+            receipt_token_program: None,
+            destination_token_account: None,
+            epoch_ledger: None,
             amount: None,
             __remaining_accounts: Vec::new(),
         });
@@ -443,7 +788,9 @@ impl<'a, 'b> WithdrawCpiBuilder<'a, 'b> {
         self.instruction.holder_rewards = Some(holder_rewards);
         self
     }
-    /// Token account.
+    /// Owner's token account, unused if a destination token account is
+    /// provided, in which case any account may be passed, such as the
+    /// destination.
     #[inline(always)]
     pub fn token_account(
         &mut self,
@@ -473,6 +820,78 @@ impl<'a, 'b> WithdrawCpiBuilder<'a, 'b> {
         self.instruction.token_program = Some(token_program);
         self
     }
+    /// `[optional account]`
+    /// Rate history account, if the pool records one.
+    #[inline(always)]
+    pub fn rate_history(
+        &mut self,
+        rate_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.rate_history = rate_history;
+        self
+    }
+    /// `[optional account]`
+    /// Stake checkpoints account, required if the holder records one.
+    #[inline(always)]
+    pub fn stake_checkpoints(
+        &mut self,
+        stake_checkpoints: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.stake_checkpoints = stake_checkpoints;
+        self
+    }
+    /// `[optional account]`
+    /// Receipt token mint, required if the pool mints receipt tokens.
+    #[inline(always)]
+    pub fn receipt_mint(
+        &mut self,
+        receipt_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.receipt_mint = receipt_mint;
+        self
+    }
+    /// `[optional account]`
+    /// Owner's receipt token account, required if the pool mints receipt
+    /// tokens.
+    #[inline(always)]
+    pub fn receipt_token_account(
+        &mut self,
+        receipt_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.receipt_token_account = receipt_token_account;
+        self
+    }
+    /// `[optional account]`
+    /// Token-2022 program, required if the pool mints receipt tokens.
+    #[inline(always)]
+    pub fn receipt_token_program(
+        &mut self,
+        receipt_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.receipt_token_program = receipt_token_program;
+        self
+    }
+    /// `[optional account]`
+    /// Token account receiving the withdrawn tokens instead of the owner's
+    /// token account.
+    #[inline(always)]
+    pub fn destination_token_account(
+        &mut self,
+        destination_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.destination_token_account = destination_token_account;
+        self
+    }
+    /// `[optional account]`
+    /// Epoch ledger account, if the pool records one.
+    #[inline(always)]
+    pub fn epoch_ledger(
+        &mut self,
+        epoch_ledger: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.epoch_ledger = epoch_ledger;
+        self
+    }
     #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.instruction.amount = Some(amount);
@@ -554,6 +973,20 @@ impl<'a, 'b> WithdrawCpiBuilder<'a, 'b> {
                 .instruction
                 .token_program
                 .expect("token_program is not set"),
+
+            rate_history: self.instruction.rate_history,
+
+            stake_checkpoints: self.instruction.stake_checkpoints,
+
+            receipt_mint: self.instruction.receipt_mint,
+
+            receipt_token_account: self.instruction.receipt_token_account,
+
+            receipt_token_program: self.instruction.receipt_token_program,
+
+            destination_token_account: self.instruction.destination_token_account,
+
+            epoch_ledger: self.instruction.epoch_ledger,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -573,6 +1006,13 @@ struct WithdrawCpiBuilderInstruction<'a, 'b> {
     mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     owner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rate_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    stake_checkpoints: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    receipt_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    receipt_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    receipt_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    destination_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    epoch_ledger: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     amount: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
@@ -0,0 +1,14 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+
+pub(crate) mod r#epoch_ledger_entry;
+pub(crate) mod r#lockup_tier;
+pub(crate) mod r#rate_history_entry;
+pub(crate) mod r#stake_checkpoint;
+
+pub use self::{
+    r#epoch_ledger_entry::*, r#lockup_tier::*, r#rate_history_entry::*, r#stake_checkpoint::*,
+};
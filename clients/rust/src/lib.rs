@@ -1,5 +1,12 @@
 #![allow(non_local_definitions)]
 
+pub mod epoch_ledger;
+pub mod events;
 mod generated;
+pub mod onboarding;
+pub mod rate_history;
 
-pub use generated::{programs::PALADIN_REWARDS_ID as ID, *};
+pub use {
+    generated::{programs::PALADIN_REWARDS_ID as ID, *},
+    paladin_rewards_math as math,
+};
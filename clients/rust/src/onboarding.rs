@@ -0,0 +1,455 @@
+//! Helpers for onboarding a holder to a holder rewards pool.
+//!
+//! Onboarding takes several steps: signing the pool's DUNA document,
+//! initializing the holder rewards account, creating the receipt token
+//! account if the pool mints receipt tokens, and the first deposit.
+//! [Onboarding] assembles the steps that are still outstanding into a single
+//! transaction, skipping accounts that already exist, so a failed or partial
+//! onboarding can simply be retried.
+//!
+//! The DUNA document is signed with an instruction of the external DUNA
+//! program, which the caller provides.
+
+use {
+    crate::{
+        accounts::{AccessEntry, EpochLedger, HolderRewards, HolderRewardsPool, RateHistory},
+        instructions::{DepositBuilder, InitializeHolderRewardsBuilder},
+    },
+    solana_program::{instruction::Instruction, pubkey, pubkey::Pubkey},
+    spl_associated_token_account::{
+        get_associated_token_address_with_program_id,
+        instruction::create_associated_token_account_idempotent,
+    },
+    thiserror::Error,
+};
+
+/// The program which records signed DUNA documents.
+pub const DUNA_PROGRAM_ID: Pubkey = pubkey!("8TwDM3rkxQuFCiS2iPB1HB3Q3qnN7b6J4SCTDCpw9SS1");
+
+/// Derive the address of the account recording that `signer` signed the
+/// DUNA document with hash `doc_hash`.
+pub fn find_duna_document_pda(signer: &Pubkey, doc_hash: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"consitution", signer.as_ref(), doc_hash],
+        &DUNA_PROGRAM_ID,
+    )
+}
+
+/// Errors assembling an onboarding.
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum OnboardingError {
+    /// The holder has not signed the pool's DUNA document, and no instruction
+    /// signing it was provided.
+    #[error("DUNA document is not signed and no signing instruction was provided")]
+    DunaDocumentNotSigned,
+    /// The pool's receipt mint address could not be derived from its stored
+    /// bump seed.
+    #[error("Invalid receipt mint bump seed")]
+    InvalidReceiptMintBumpSeed,
+}
+
+/// The onboarding steps a holder has already completed.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct OnboardingStatus {
+    /// Whether the holder has signed the pool's DUNA document.
+    pub duna_document_signed: bool,
+    /// Whether the holder's holder rewards account is initialized.
+    pub holder_rewards_initialized: bool,
+}
+
+impl OnboardingStatus {
+    /// Determines the status from the data of the holder's DUNA document and
+    /// holder rewards accounts, or `None` for accounts that do not exist.
+    pub fn from_accounts(
+        duna_document_data: Option<&[u8]>,
+        holder_rewards_data: Option<&[u8]>,
+    ) -> Self {
+        Self {
+            duna_document_signed: duna_document_data.is_some_and(|data| data.first() == Some(&1)),
+            holder_rewards_initialized: holder_rewards_data.is_some_and(|data| !data.is_empty()),
+        }
+    }
+}
+
+/// Onboards a holder to the holder rewards pool of a mint.
+#[derive(Clone, Debug)]
+pub struct Onboarding {
+    /// Token mint.
+    pub mint: Pubkey,
+    /// Holder.
+    pub owner: Pubkey,
+    /// Payer funding the rent for the accounts created.
+    pub payer: Pubkey,
+    /// The amount of tokens to deposit from the owner's associated token
+    /// account. Nothing is deposited if zero.
+    pub amount: u64,
+    /// Rate history account, derived from the pool if not set and the pool
+    /// records one.
+    pub rate_history: Option<Pubkey>,
+    /// Epoch ledger account, derived from the pool if not set and the pool
+    /// records one.
+    pub epoch_ledger: Option<Pubkey>,
+}
+
+impl Onboarding {
+    pub fn new(mint: Pubkey, owner: Pubkey, payer: Pubkey, amount: u64) -> Self {
+        Self {
+            mint,
+            owner,
+            payer,
+            amount,
+            rate_history: None,
+            epoch_ledger: None,
+        }
+    }
+
+    /// The holder rewards pool account.
+    pub fn holder_rewards_pool(&self) -> Pubkey {
+        HolderRewardsPool::find_pda(&self.mint).0
+    }
+
+    /// The holder's holder rewards account.
+    pub fn holder_rewards(&self) -> Pubkey {
+        HolderRewards::find_pda(&self.owner).0
+    }
+
+    /// The holder's DUNA document account for `pool`.
+    pub fn duna_document(&self, pool: &HolderRewardsPool) -> Pubkey {
+        find_duna_document_pda(&self.owner, &pool.duna_document_hash).0
+    }
+
+    /// The owner's token account deposited from.
+    pub fn token_account(&self) -> Pubkey {
+        get_associated_token_address_with_program_id(&self.owner, &self.mint, &spl_token::ID)
+    }
+
+    /// The instructions completing the steps of the onboarding not yet in
+    /// `status`, in order, for a single transaction signed by the owner and
+    /// the payer.
+    ///
+    /// `sign_duna_document` is the DUNA program instruction signing `pool`'s
+    /// document, and is only required if it is not signed yet.
+    pub fn instructions(
+        &self,
+        pool: &HolderRewardsPool,
+        status: &OnboardingStatus,
+        sign_duna_document: Option<Instruction>,
+    ) -> Result<Vec<Instruction>, OnboardingError> {
+        let holder_rewards_pool = self.holder_rewards_pool();
+        let holder_rewards_pool_token_account = get_associated_token_address_with_program_id(
+            &holder_rewards_pool,
+            &self.mint,
+            &spl_token::ID,
+        );
+        let access_entry = (pool.access_list != 0)
+            .then(|| AccessEntry::find_pda(&holder_rewards_pool, &self.owner).0);
+        let rate_history = self.rate_history.or_else(|| {
+            (pool.rate_history != 0).then(|| RateHistory::find_pda(&holder_rewards_pool).0)
+        });
+        let epoch_ledger = self.epoch_ledger.or_else(|| {
+            (pool.epoch_ledger != 0).then(|| EpochLedger::find_pda(&holder_rewards_pool).0)
+        });
+
+        let mut instructions = Vec::new();
+
+        if !status.duna_document_signed {
+            instructions.push(sign_duna_document.ok_or(OnboardingError::DunaDocumentNotSigned)?);
+        }
+
+        if !status.holder_rewards_initialized {
+            instructions.push(
+                InitializeHolderRewardsBuilder::new()
+                    .holder_rewards_pool(holder_rewards_pool)
+                    .holder_rewards_pool_token_account(holder_rewards_pool_token_account)
+                    .owner(self.owner)
+                    .holder_rewards(self.holder_rewards())
+                    .mint(self.mint)
+                    .duna_document_pda(self.duna_document(pool))
+                    .payer(self.payer)
+                    .rate_history(rate_history)
+                    .access_entry(access_entry)
+                    .epoch_ledger(epoch_ledger)
+                    .instruction(),
+            );
+        }
+
+        if self.amount > 0 {
+            let mut deposit = DepositBuilder::new();
+            deposit
+                .holder_rewards_pool(holder_rewards_pool)
+                .holder_rewards_pool_token_account(holder_rewards_pool_token_account)
+                .holder_rewards(self.holder_rewards())
+                .token_account(self.token_account())
+                .mint(self.mint)
+                .owner(self.owner)
+                .rate_history(rate_history)
+                .access_entry(access_entry)
+                .epoch_ledger(epoch_ledger)
+                .amount(self.amount);
+
+            // Receipt tokens are minted to the owner's Token-2022 associated
+            // token account, which must exist before the deposit.
+            if pool.receipts != 0 {
+                let receipt_mint = Pubkey::create_program_address(
+                    &[
+                        b"receipt_mint",
+                        holder_rewards_pool.as_ref(),
+                        &[pool.receipt_mint_bump_seed],
+                    ],
+                    &crate::ID,
+                )
+                .map_err(|_| OnboardingError::InvalidReceiptMintBumpSeed)?;
+                let receipt_token_account = get_associated_token_address_with_program_id(
+                    &self.owner,
+                    &receipt_mint,
+                    &spl_token_2022::ID,
+                );
+
+                instructions.push(create_associated_token_account_idempotent(
+                    &self.payer,
+                    &self.owner,
+                    &receipt_mint,
+                    &spl_token_2022::ID,
+                ));
+                deposit
+                    .receipt_mint(Some(receipt_mint))
+                    .receipt_token_account(Some(receipt_token_account))
+                    .receipt_token_program(Some(spl_token_2022::ID));
+            }
+
+            instructions.push(deposit.instruction());
+        }
+
+        Ok(instructions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, solana_program::instruction::AccountMeta};
+
+    fn pool(access_list: u8, receipts: u8) -> HolderRewardsPool {
+        HolderRewardsPool {
+            accumulated_rewards_per_token: 0,
+            lamports_last: 0,
+            duna_document_hash: [1; 32],
+            total_deposited: 0,
+            authority: Pubkey::default(),
+            bump_seed: 0,
+            access_list,
+            early_withdrawal_penalty_bps: 0,
+            early_withdrawal_period_slots: 0,
+            last_update_slot: 0,
+            total_rewards_received: 0,
+            total_rewards_harvested: 0,
+            active_holder_count: 0,
+            total_lockup_bonus_weight: 0,
+            max_total_deposit: 0,
+            max_holder_deposit: 0,
+            min_deposit: 0,
+            receipts,
+            receipt_mint_bump_seed: 0,
+            rate_history: 0,
+            epoch_ledger: 0,
+            padding: [0; 4],
+            slashing_authority: Pubkey::default(),
+            slash_destination: Pubkey::default(),
+            total_slashed: 0,
+            zero_weight_slots: 0,
+        }
+    }
+
+    fn sign_duna_document() -> Instruction {
+        Instruction::new_with_bytes(DUNA_PROGRAM_ID, &[], vec![])
+    }
+
+    #[test]
+    fn status_from_accounts() {
+        assert_eq!(
+            OnboardingStatus::from_accounts(None, None),
+            OnboardingStatus::default()
+        );
+        assert_eq!(
+            OnboardingStatus::from_accounts(Some(&[0]), Some(&[])),
+            OnboardingStatus::default()
+        );
+        assert_eq!(
+            OnboardingStatus::from_accounts(Some(&[1]), Some(&[0; HolderRewards::LEN])),
+            OnboardingStatus {
+                duna_document_signed: true,
+                holder_rewards_initialized: true,
+            }
+        );
+    }
+
+    #[test]
+    fn fail_duna_document_not_signed() {
+        let onboarding = Onboarding::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            100,
+        );
+        assert_eq!(
+            onboarding.instructions(&pool(0, 0), &OnboardingStatus::default(), None),
+            Err(OnboardingError::DunaDocumentNotSigned)
+        );
+    }
+
+    #[test]
+    fn all_steps() {
+        let onboarding = Onboarding::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            100,
+        );
+        let instructions = onboarding
+            .instructions(
+                &pool(0, 0),
+                &OnboardingStatus::default(),
+                Some(sign_duna_document()),
+            )
+            .unwrap();
+
+        let program_ids: Vec<Pubkey> = instructions.iter().map(|ix| ix.program_id).collect();
+        assert_eq!(program_ids, vec![DUNA_PROGRAM_ID, crate::ID, crate::ID]);
+        assert_eq!(instructions[1].data, vec![1]);
+        assert_eq!(instructions[2].data[0], 4);
+    }
+
+    #[test]
+    fn skips_completed_steps() {
+        let onboarding = Onboarding::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            100,
+        );
+        let status = OnboardingStatus {
+            duna_document_signed: true,
+            holder_rewards_initialized: true,
+        };
+
+        let instructions = onboarding
+            .instructions(&pool(0, 0), &status, Some(sign_duna_document()))
+            .unwrap();
+        assert_eq!(instructions.len(), 1);
+        assert_eq!(instructions[0].data[0], 4);
+
+        let onboarding = Onboarding {
+            amount: 0,
+            ..onboarding
+        };
+        assert_eq!(
+            onboarding.instructions(&pool(0, 0), &status, None),
+            Ok(vec![])
+        );
+    }
+
+    #[test]
+    fn includes_access_entry_and_receipt_accounts() {
+        let onboarding = Onboarding::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            100,
+        );
+        let holder_rewards_pool = onboarding.holder_rewards_pool();
+        let (receipt_mint, receipt_mint_bump_seed) = Pubkey::find_program_address(
+            &[b"receipt_mint", holder_rewards_pool.as_ref()],
+            &crate::ID,
+        );
+        let pool = HolderRewardsPool {
+            receipt_mint_bump_seed,
+            ..pool(1, 1)
+        };
+        let status = OnboardingStatus {
+            duna_document_signed: true,
+            holder_rewards_initialized: false,
+        };
+
+        let instructions = onboarding.instructions(&pool, &status, None).unwrap();
+        assert_eq!(instructions.len(), 3);
+
+        let access_entry = AccessEntry::find_pda(&holder_rewards_pool, &onboarding.owner).0;
+        assert_eq!(
+            instructions[0].accounts[9],
+            AccountMeta::new_readonly(access_entry, false)
+        );
+
+        assert_eq!(instructions[1].program_id, spl_associated_token_account::ID);
+        let receipt_token_account = get_associated_token_address_with_program_id(
+            &onboarding.owner,
+            &receipt_mint,
+            &spl_token_2022::ID,
+        );
+        let deposit = &instructions[2];
+        assert_eq!(deposit.accounts[9].pubkey, access_entry);
+        assert_eq!(deposit.accounts[10].pubkey, receipt_mint);
+        assert_eq!(deposit.accounts[11].pubkey, receipt_token_account);
+        assert_eq!(deposit.accounts[12].pubkey, spl_token_2022::ID);
+    }
+
+    #[test]
+    fn includes_rate_history() {
+        let onboarding = Onboarding::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            100,
+        );
+        let pool = HolderRewardsPool {
+            rate_history: 1,
+            ..pool(0, 0)
+        };
+        let status = OnboardingStatus {
+            duna_document_signed: true,
+            holder_rewards_initialized: false,
+        };
+
+        let instructions = onboarding.instructions(&pool, &status, None).unwrap();
+        assert_eq!(instructions.len(), 2);
+
+        let rate_history = RateHistory::find_pda(&onboarding.holder_rewards_pool()).0;
+        assert_eq!(
+            instructions[0].accounts[8],
+            AccountMeta::new(rate_history, false)
+        );
+        assert_eq!(
+            instructions[1].accounts[7],
+            AccountMeta::new(rate_history, false)
+        );
+    }
+
+    #[test]
+    fn includes_epoch_ledger() {
+        let onboarding = Onboarding::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            100,
+        );
+        let pool = HolderRewardsPool {
+            epoch_ledger: 1,
+            ..pool(0, 0)
+        };
+        let status = OnboardingStatus {
+            duna_document_signed: true,
+            holder_rewards_initialized: false,
+        };
+
+        let instructions = onboarding.instructions(&pool, &status, None).unwrap();
+        assert_eq!(instructions.len(), 2);
+
+        let epoch_ledger = EpochLedger::find_pda(&onboarding.holder_rewards_pool()).0;
+        assert_eq!(
+            instructions[0].accounts[10],
+            AccountMeta::new(epoch_ledger, false)
+        );
+        assert_eq!(
+            instructions[1].accounts[13],
+            AccountMeta::new(epoch_ledger, false)
+        );
+    }
+}
@@ -0,0 +1,155 @@
+//! Helpers for reading a holder rewards pool's rate history.
+//!
+//! Each entry records the pool's `accumulated_rewards_per_token` exchange
+//! rate at a slot. The rewards earned by a single token between two entries
+//! is the difference between their rates, which can then be annualized.
+
+pub use crate::math::REWARDS_PER_TOKEN_SCALING_FACTOR;
+use crate::{accounts::RateHistory, types::RateHistoryEntry};
+
+/// The approximate number of slots in a year, assuming 400ms slots.
+pub const SLOTS_PER_YEAR: u64 = 78_894_000;
+
+impl RateHistory {
+    /// Iterates over the recorded entries, from oldest to newest.
+    pub fn iter(&self) -> impl Iterator<Item = &RateHistoryEntry> {
+        let capacity = self.entries.len();
+        let len = std::cmp::min(self.len as usize, capacity);
+        let start = (self.next_index as usize + capacity - len) % capacity;
+
+        (0..len).map(move |offset| &self.entries[(start + offset) % capacity])
+    }
+
+    /// The most recent entry recorded at or before `slot`, if any.
+    pub fn entry_at(&self, slot: u64) -> Option<&RateHistoryEntry> {
+        self.iter().take_while(|entry| entry.slot <= slot).last()
+    }
+}
+
+/// The lamports earned per token between two entries, scaled by
+/// [REWARDS_PER_TOKEN_SCALING_FACTOR].
+///
+/// The on-chain rate wraps on overflow, so the difference is computed with
+/// wrapping arithmetic.
+pub fn rewards_per_token_between(start: &RateHistoryEntry, end: &RateHistoryEntry) -> u128 {
+    end.accumulated_rewards_per_token
+        .wrapping_sub(start.accumulated_rewards_per_token)
+}
+
+/// The annual percentage rate earned by deposits between two entries, as a
+/// fraction (`0.05` is 5%).
+///
+/// `lamports_per_token` is the value of one token, in the mint's smallest
+/// unit, expressed in lamports.
+///
+/// Returns `None` if `end` was not recorded after `start`, or if
+/// `lamports_per_token` is not positive.
+pub fn apr(
+    start: &RateHistoryEntry,
+    end: &RateHistoryEntry,
+    lamports_per_token: f64,
+) -> Option<f64> {
+    let slots = end
+        .slot
+        .checked_sub(start.slot)
+        .filter(|slots| *slots > 0)?;
+    if lamports_per_token <= 0.0 {
+        return None;
+    }
+
+    let rewards_per_token =
+        rewards_per_token_between(start, end) as f64 / REWARDS_PER_TOKEN_SCALING_FACTOR as f64;
+    let years = slots as f64 / SLOTS_PER_YEAR as f64;
+
+    Some(rewards_per_token / lamports_per_token / years)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(slot: u64, accumulated_rewards_per_token: u128) -> RateHistoryEntry {
+        RateHistoryEntry {
+            accumulated_rewards_per_token,
+            slot,
+            epoch: 0,
+            total_deposited: 0,
+            padding: [0; 8],
+        }
+    }
+
+    fn history(next_index: u64, len: u64, slots: &[u64]) -> RateHistory {
+        let mut entries: [RateHistoryEntry; 128] = std::array::from_fn(|_| entry(0, 0));
+        for (index, slot) in slots.iter().enumerate() {
+            entries[index] = entry(*slot, 0);
+        }
+        RateHistory {
+            next_index,
+            len,
+            bump_seed: 0,
+            padding: [0; 15],
+            entries,
+        }
+    }
+
+    #[test]
+    fn iter_before_wrapping() {
+        let history = history(3, 3, &[10, 20, 30]);
+        let slots: Vec<u64> = history.iter().map(|entry| entry.slot).collect();
+        assert_eq!(slots, vec![10, 20, 30]);
+    }
+
+    #[test]
+    fn iter_after_wrapping() {
+        // Slots 1..=130 were recorded, so the two oldest were overwritten.
+        let mut slots: Vec<u64> = (129..=130).collect();
+        slots.extend(3..=128);
+        let history = history(2, 128, &slots);
+
+        let iterated: Vec<u64> = history.iter().map(|entry| entry.slot).collect();
+        assert_eq!(iterated, (3..=130).collect::<Vec<u64>>());
+    }
+
+    #[test]
+    fn entry_at_slot() {
+        let history = history(3, 3, &[10, 20, 30]);
+        assert_eq!(history.entry_at(5), None);
+        assert_eq!(history.entry_at(20).unwrap().slot, 20);
+        assert_eq!(history.entry_at(25).unwrap().slot, 20);
+        assert_eq!(history.entry_at(u64::MAX).unwrap().slot, 30);
+    }
+
+    #[test]
+    fn rewards_per_token_wrapping() {
+        let start = entry(0, u128::MAX);
+        let end = entry(1, 9);
+        assert_eq!(rewards_per_token_between(&start, &end), 10);
+    }
+
+    #[test]
+    fn apr_over_one_year() {
+        // 0.1 lamports per token, for a token worth 2 lamports.
+        let start = entry(0, 0);
+        let end = entry(SLOTS_PER_YEAR, REWARDS_PER_TOKEN_SCALING_FACTOR / 10);
+        assert!((apr(&start, &end, 2.0).unwrap() - 0.05).abs() < 1e-12);
+    }
+
+    #[test]
+    fn apr_annualizes_shorter_periods() {
+        let start = entry(100, 0);
+        let end = entry(
+            100 + SLOTS_PER_YEAR / 2,
+            REWARDS_PER_TOKEN_SCALING_FACTOR / 10,
+        );
+        assert!((apr(&start, &end, 1.0).unwrap() - 0.2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn apr_invalid_inputs() {
+        let start = entry(100, 0);
+        let end = entry(200, REWARDS_PER_TOKEN_SCALING_FACTOR);
+        assert_eq!(apr(&end, &start, 1.0), None);
+        assert_eq!(apr(&start, &start, 1.0), None);
+        assert_eq!(apr(&start, &end, 0.0), None);
+    }
+}
@@ -0,0 +1,245 @@
+#![cfg(feature = "test-sbf")]
+
+use {
+    borsh::BorshSerialize,
+    paladin_rewards_program_client::{
+        accounts::{HolderRewards, HolderRewardsPool},
+        onboarding::{Onboarding, OnboardingStatus, DUNA_PROGRAM_ID},
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        account::{Account, AccountSharedData},
+        program_pack::Pack,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::Transaction,
+    },
+    spl_associated_token_account::get_associated_token_address,
+    spl_token::state::{Account as TokenAccount, AccountState, Mint},
+};
+
+const DEPOSIT_AMOUNT: u64 = 100_000;
+const DUNA_DOCUMENT_HASH: [u8; 32] = [1; 32];
+
+fn setup() -> ProgramTest {
+    ProgramTest::new(
+        "paladin_rewards_program",
+        paladin_rewards_program_client::ID,
+        None,
+    )
+}
+
+async fn set_account(
+    context: &mut ProgramTestContext,
+    address: &Pubkey,
+    data: Vec<u8>,
+    owner: &Pubkey,
+) {
+    let rent = context.banks_client.get_rent().await.unwrap();
+    context.set_account(
+        address,
+        &AccountSharedData::from(Account {
+            lamports: rent.minimum_balance(data.len()),
+            data,
+            owner: *owner,
+            ..Account::default()
+        }),
+    );
+}
+
+async fn setup_token_account(
+    context: &mut ProgramTestContext,
+    owner: &Pubkey,
+    mint: &Pubkey,
+    amount: u64,
+) {
+    let mut data = vec![0; TokenAccount::LEN];
+    TokenAccount::pack(
+        TokenAccount {
+            amount,
+            mint: *mint,
+            owner: *owner,
+            state: AccountState::Initialized,
+            ..TokenAccount::default()
+        },
+        &mut data,
+    )
+    .unwrap();
+    set_account(
+        context,
+        &get_associated_token_address(owner, mint),
+        data,
+        &spl_token::ID,
+    )
+    .await;
+}
+
+// Sets up the mint, its pool and the owner's tokens, with the DUNA document
+// signed by a stub account of the DUNA program.
+async fn setup_pool(context: &mut ProgramTestContext, mint: &Pubkey, owner: &Pubkey) {
+    let mut data = vec![0; Mint::LEN];
+    Mint::pack(
+        Mint {
+            supply: DEPOSIT_AMOUNT * 2,
+            decimals: 6,
+            is_initialized: true,
+            ..Mint::default()
+        },
+        &mut data,
+    )
+    .unwrap();
+    set_account(context, mint, data, &spl_token::ID).await;
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let (holder_rewards_pool, bump_seed) = HolderRewardsPool::find_pda(mint);
+    let pool = HolderRewardsPool {
+        accumulated_rewards_per_token: 0,
+        lamports_last: rent.minimum_balance(HolderRewardsPool::LEN),
+        duna_document_hash: DUNA_DOCUMENT_HASH,
+        total_deposited: 0,
+        authority: Pubkey::new_unique(),
+        bump_seed,
+        access_list: 0,
+        early_withdrawal_penalty_bps: 0,
+        early_withdrawal_period_slots: 0,
+        last_update_slot: 0,
+        total_rewards_received: 0,
+        total_rewards_harvested: 0,
+        active_holder_count: 0,
+        total_lockup_bonus_weight: 0,
+        max_total_deposit: 0,
+        max_holder_deposit: 0,
+        min_deposit: 0,
+        receipts: 0,
+        receipt_mint_bump_seed: 0,
+        rate_history: 0,
+        epoch_ledger: 0,
+        padding: [0; 4],
+        slashing_authority: Pubkey::default(),
+        slash_destination: Pubkey::default(),
+        total_slashed: 0,
+        zero_weight_slots: 0,
+    };
+    let data = pool.try_to_vec().unwrap();
+    assert_eq!(data.len(), HolderRewardsPool::LEN);
+    set_account(
+        context,
+        &holder_rewards_pool,
+        data,
+        &paladin_rewards_program_client::ID,
+    )
+    .await;
+
+    setup_token_account(context, &holder_rewards_pool, mint, 0).await;
+    setup_token_account(context, owner, mint, DEPOSIT_AMOUNT * 2).await;
+
+    let onboarding = Onboarding::new(*mint, *owner, Pubkey::default(), 0);
+    set_account(
+        context,
+        &onboarding.duna_document(&pool),
+        vec![1],
+        &DUNA_PROGRAM_ID,
+    )
+    .await;
+}
+
+async fn onboard(context: &mut ProgramTestContext, onboarding: &Onboarding, owner: &Keypair) {
+    let pool_account = context
+        .banks_client
+        .get_account(onboarding.holder_rewards_pool())
+        .await
+        .unwrap()
+        .unwrap();
+    let pool = HolderRewardsPool::from_bytes(&pool_account.data).unwrap();
+    let duna_document = context
+        .banks_client
+        .get_account(onboarding.duna_document(&pool))
+        .await
+        .unwrap();
+    let holder_rewards = context
+        .banks_client
+        .get_account(onboarding.holder_rewards())
+        .await
+        .unwrap();
+    let status = OnboardingStatus::from_accounts(
+        duna_document
+            .as_ref()
+            .map(|account| account.data.as_slice()),
+        holder_rewards
+            .as_ref()
+            .map(|account| account.data.as_slice()),
+    );
+
+    let instructions = onboarding.instructions(&pool, &status, None).unwrap();
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, owner],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+}
+
+async fn deposited(context: &mut ProgramTestContext, onboarding: &Onboarding) -> u64 {
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(onboarding.holder_rewards())
+        .await
+        .unwrap()
+        .unwrap();
+    HolderRewards::from_bytes(&holder_rewards_account.data)
+        .unwrap()
+        .deposited
+}
+
+#[tokio::test]
+async fn success() {
+    let mint = Pubkey::new_unique();
+    let owner = Keypair::new();
+
+    let mut context = setup().start_with_context().await;
+    setup_pool(&mut context, &mint, &owner.pubkey()).await;
+
+    let onboarding = Onboarding::new(mint, owner.pubkey(), context.payer.pubkey(), DEPOSIT_AMOUNT);
+    onboard(&mut context, &onboarding, &owner).await;
+
+    // Assert the holder was initialized and deposited in one transaction.
+    assert_eq!(deposited(&mut context, &onboarding).await, DEPOSIT_AMOUNT);
+
+    let token_account = context
+        .banks_client
+        .get_account(onboarding.token_account())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        TokenAccount::unpack(&token_account.data).unwrap().amount,
+        DEPOSIT_AMOUNT
+    );
+}
+
+#[tokio::test]
+async fn success_skips_existing_accounts() {
+    let mint = Pubkey::new_unique();
+    let owner = Keypair::new();
+
+    let mut context = setup().start_with_context().await;
+    setup_pool(&mut context, &mint, &owner.pubkey()).await;
+
+    let onboarding = Onboarding::new(mint, owner.pubkey(), context.payer.pubkey(), DEPOSIT_AMOUNT);
+    onboard(&mut context, &onboarding, &owner).await;
+
+    // Onboarding again only deposits, rather than failing to initialize the
+    // holder rewards account again.
+    onboard(&mut context, &onboarding, &owner).await;
+
+    assert_eq!(
+        deposited(&mut context, &onboarding).await,
+        DEPOSIT_AMOUNT * 2
+    );
+}
@@ -0,0 +1,509 @@
+//! Rewards math shared by the Paladin Rewards program and its clients.
+//!
+//! Rewards are tracked as an accumulated rewards per token exchange rate,
+//! scaled by [REWARDS_PER_TOKEN_SCALING_FACTOR]. The rate wraps on overflow,
+//! so differences between rates are always computed with wrapping
+//! arithmetic.
+
+#![cfg_attr(not(test), no_std)]
+
+/// The scaling factor applied to rewards per token exchange rates, giving
+/// them 18 decimal places of precision.
+pub const REWARDS_PER_TOKEN_SCALING_FACTOR: u128 = 1_000_000_000_000_000_000; // 1e18
+
+/// Calculate the rewards per token.
+///
+/// Calculation: rewards / total_weight
+/// Scaled by 1e18 to store 18 decimal places of precision.
+///
+/// Rewards are distributed per unit of weight, which is one per deposited
+/// token plus the bonus weight of any lockups.
+///
+/// This calculation is valid for all possible values of rewards and total
+/// weight, since the scaling to `u128` prevents multiplication from breaking
+/// the `u64::MAX` ceiling, and the `total_weight == 0` check prevents
+/// `checked_div` returning `None` from a zero denominator.
+pub fn calculate_rewards_per_token(rewards: u64, total_weight: u64) -> Option<u128> {
+    if total_weight == 0 {
+        return Some(0);
+    }
+    (rewards as u128)
+        .checked_mul(REWARDS_PER_TOKEN_SCALING_FACTOR)
+        .and_then(|product| product.checked_div(total_weight as u128))
+}
+
+/// Calculate the eligible rewards for a holder.
+///
+/// Calculation: (current - last) * weight
+/// The result is descaled by a factor of 1e18 since both rewards per token
+/// values are scaled by 1e18 for precision.
+///
+/// This calculation is valid as long as the total rewards accumulated by the
+/// system does not exceed `u64::MAX`.
+///
+/// This condition is a reasonable upper bound, considering `u64::MAX` is
+/// approximately 386_266 % of the current circulating supply of SOL.
+///
+/// For more information, see this function's prop tests.
+pub fn calculate_eligible_rewards(
+    current_accumulated_rewards_per_token: u128,
+    last_accumulated_rewards_per_token: u128,
+    weight: u64,
+) -> Option<u64> {
+    let marginal_rate =
+        current_accumulated_rewards_per_token.wrapping_sub(last_accumulated_rewards_per_token);
+
+    if marginal_rate == 0 {
+        return Some(0);
+    }
+    marginal_rate
+        .checked_mul(weight as u128)
+        .and_then(|product| product.checked_div(REWARDS_PER_TOKEN_SCALING_FACTOR))
+        .and_then(|product| product.try_into().ok())
+}
+
+/// The parts of a holder rewards pool's state its rewards depend on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PoolRewardsState {
+    /// The pool's accumulated rewards per token exchange rate.
+    pub accumulated_rewards_per_token: u128,
+    /// The pool's lamports when the rate was last updated.
+    pub lamports_last: u64,
+    /// The tokens deposited by every holder.
+    pub total_deposited: u64,
+    /// The bonus weight of every holder's lockup.
+    pub total_lockup_bonus_weight: u64,
+}
+
+/// The parts of a holder's state their rewards depend on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HolderRewardsState {
+    /// The pool's exchange rate when the holder's rewards were last settled.
+    pub last_accumulated_rewards_per_token: u128,
+    /// The holder's weight: their deposit, plus the bonus weight of any
+    /// locked tokens.
+    pub weight: u64,
+    /// Rewards settled to the holder but not yet harvested.
+    pub unharvested_rewards: u64,
+}
+
+/// The rewards a holder would harvest now, including rewards from lamports
+/// the pool received since its rate was last updated.
+///
+/// `pool_lamports` is the pool account's current balance, and `rent` its
+/// rent-exempt minimum balance.
+///
+/// Returns `None` if a harvest would fail: if the calculation overflows, or
+/// if the pool cannot cover the rewards above its rent-exempt minimum.
+pub fn project_pending_rewards(
+    pool: &PoolRewardsState,
+    holder: &HolderRewardsState,
+    pool_lamports: u64,
+    rent: u64,
+) -> Option<u64> {
+    let total_weight = pool
+        .total_deposited
+        .checked_add(pool.total_lockup_bonus_weight)?;
+    let additional_lamports = pool_lamports.checked_sub(pool.lamports_last)?;
+    let accumulated_rewards_per_token =
+        pool.accumulated_rewards_per_token
+            .wrapping_add(calculate_rewards_per_token(
+                additional_lamports,
+                total_weight,
+            )?);
+
+    let pending_rewards = calculate_eligible_rewards(
+        accumulated_rewards_per_token,
+        holder.last_accumulated_rewards_per_token,
+        holder.weight,
+    )?
+    .checked_add(holder.unharvested_rewards)?;
+
+    (pending_rewards <= pool_lamports.saturating_sub(rent)).then_some(pending_rewards)
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, proptest::prelude::*};
+
+    const BENCH_TOKEN_SUPPLY: u64 = 1_000_000_000 * 1_000_000_000; // 1 billion with 9 decimals
+
+    #[test]
+    fn minimum_rewards_per_token() {
+        // 1 lamport (arithmetic minimum)
+        let minimum_reward = 1;
+        let result = calculate_rewards_per_token(minimum_reward, BENCH_TOKEN_SUPPLY).unwrap();
+        assert_ne!(result, 0);
+
+        // Anything below the minimum should return zero.
+        let result = calculate_rewards_per_token(minimum_reward - 1, BENCH_TOKEN_SUPPLY).unwrap();
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn maximum_rewards_per_token() {
+        // u64::MAX (not really practical, but shows that we're ok)
+        let maximum_reward = u64::MAX;
+        let _ = calculate_rewards_per_token(maximum_reward, BENCH_TOKEN_SUPPLY).unwrap();
+    }
+
+    #[test]
+    fn minimum_eligible_rewards() {
+        // 1 / 1e18 lamports per token
+        let minimum_marginal_rewards_per_token = 1;
+        let result = calculate_eligible_rewards(
+            minimum_marginal_rewards_per_token,
+            0,
+            BENCH_TOKEN_SUPPLY, // 100% of the supply.
+        )
+        .unwrap();
+        assert_ne!(result, 0);
+
+        // Anything below the minimum should return zero.
+        let result = calculate_eligible_rewards(
+            minimum_marginal_rewards_per_token - 1,
+            0,
+            BENCH_TOKEN_SUPPLY, // 100% of the supply.
+        )
+        .unwrap();
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn minimum_eligible_rewards_with_one_token() {
+        // 1 / 1e9 lamports per token
+        let minimum_marginal_rewards_per_token = 1_000_000_000;
+        let result = calculate_eligible_rewards(
+            minimum_marginal_rewards_per_token,
+            0,
+            BENCH_TOKEN_SUPPLY / 1_000_000_000, // 1 with 9 decimals.
+        )
+        .unwrap();
+        assert_ne!(result, 0);
+
+        // Anything below the minimum should return zero.
+        let result = calculate_eligible_rewards(
+            minimum_marginal_rewards_per_token - 1,
+            0,
+            BENCH_TOKEN_SUPPLY / 1_000_000_000, // 1 with 9 decimals.
+        )
+        .unwrap();
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn minimum_eligible_rewards_with_smallest_fractional_token() {
+        // 1 lamport per token
+        let minimum_marginal_rewards_per_token = 1_000_000_000_000_000_000;
+        let result = calculate_eligible_rewards(
+            minimum_marginal_rewards_per_token,
+            0,
+            BENCH_TOKEN_SUPPLY / 1_000_000_000_000_000_000, // .000_000_001 with 9 decimals.
+        )
+        .unwrap();
+        assert_ne!(result, 0);
+
+        // Anything below the minimum should return zero.
+        let result = calculate_eligible_rewards(
+            minimum_marginal_rewards_per_token - 1,
+            0,
+            BENCH_TOKEN_SUPPLY / 1_000_000_000_000_000_000, // .000_000_001 with 9 decimals.
+        )
+        .unwrap();
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn maximum_eligible_rewards() {
+        // 1 lamport per token (not really practical, but shows that we're ok)
+        let maximum_marginal_rewards_per_token = REWARDS_PER_TOKEN_SCALING_FACTOR;
+        let _ = calculate_eligible_rewards(
+            maximum_marginal_rewards_per_token,
+            0,
+            BENCH_TOKEN_SUPPLY, // 100% of the supply.
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn wrapping_eligible_rewards() {
+        // Set up current to be less than rate, simulating a scenario where the
+        // current reward has wrapped around `u128::MAX`.
+        let current_accumulated_rewards_per_token = 0;
+        let last_accumulated_rewards_per_token = u128::MAX - 1_000_000_000_000_000_000;
+        let result = calculate_eligible_rewards(
+            current_accumulated_rewards_per_token,
+            last_accumulated_rewards_per_token,
+            BENCH_TOKEN_SUPPLY,
+        )
+        .unwrap();
+        assert_eq!(result, 1_000_000_000_000_000_001);
+
+        // Try it again at the very edge. Result should be one.
+        let current_accumulated_rewards_per_token = 0;
+        let last_accumulated_rewards_per_token = u128::MAX;
+        let result = calculate_eligible_rewards(
+            current_accumulated_rewards_per_token,
+            last_accumulated_rewards_per_token,
+            BENCH_TOKEN_SUPPLY,
+        )
+        .unwrap();
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn project_pending_rewards_accrues_received_lamports() {
+        let pool = PoolRewardsState {
+            accumulated_rewards_per_token: REWARDS_PER_TOKEN_SCALING_FACTOR,
+            lamports_last: 1_000,
+            total_deposited: 100,
+            total_lockup_bonus_weight: 50,
+        };
+        let holder = HolderRewardsState {
+            last_accumulated_rewards_per_token: 0,
+            weight: 100,
+            unharvested_rewards: 7,
+        };
+
+        // 1 lamport per token already accrued, 300 lamports received since
+        // across a total weight of 150, and 7 lamports unharvested.
+        assert_eq!(
+            project_pending_rewards(&pool, &holder, 1_300, 500),
+            Some(100 + 200 + 7)
+        );
+    }
+
+    #[test]
+    fn project_pending_rewards_wrapping() {
+        let pool = PoolRewardsState {
+            accumulated_rewards_per_token: 0,
+            lamports_last: 1_000,
+            total_deposited: 10,
+            total_lockup_bonus_weight: 0,
+        };
+        let holder = HolderRewardsState {
+            last_accumulated_rewards_per_token: u128::MAX - REWARDS_PER_TOKEN_SCALING_FACTOR + 1,
+            weight: 10,
+            unharvested_rewards: 0,
+        };
+        assert_eq!(project_pending_rewards(&pool, &holder, 1_000, 0), Some(10));
+    }
+
+    #[test]
+    fn project_pending_rewards_fails_when_harvest_would() {
+        let pool = PoolRewardsState {
+            accumulated_rewards_per_token: REWARDS_PER_TOKEN_SCALING_FACTOR,
+            lamports_last: 1_000,
+            total_deposited: 600,
+            total_lockup_bonus_weight: 0,
+        };
+        let holder = HolderRewardsState {
+            last_accumulated_rewards_per_token: 0,
+            weight: 600,
+            unharvested_rewards: 0,
+        };
+
+        // The pool can only cover 500 lamports above its rent-exempt minimum.
+        assert_eq!(project_pending_rewards(&pool, &holder, 1_000, 500), None);
+        assert_eq!(
+            project_pending_rewards(&pool, &holder, 1_000, 400),
+            Some(600)
+        );
+
+        // The pool's lamports never drop below those last accounted for.
+        assert_eq!(project_pending_rewards(&pool, &holder, 999, 0), None);
+    }
+
+    proptest! {
+        #[test]
+        fn test_calculate_rewards_per_token(
+            rewards in 0u64..,
+            token_supply in 0u64..,
+        ) {
+            // Calculate.
+            //
+            // For all possible values of rewards and token_supply, the
+            // calculation should never return an error, hence the
+            // `unwrap` here.
+            //
+            // The scaling to `u128` prevents multiplication from breaking
+            // the `u64::MAX` ceiling, and the `token_supply == 0` check
+            // prevents `checked_div` returning `None` from a zero
+            // denominator.
+            let result = calculate_rewards_per_token(rewards, token_supply).unwrap();
+            // Evaluate.
+            if token_supply == 0 {
+                prop_assert_eq!(result, 0);
+            } else {
+                let expected = (rewards as u128)
+                    .checked_mul(REWARDS_PER_TOKEN_SCALING_FACTOR)
+                    .and_then(|product| product.checked_div(token_supply as u128))
+                    .unwrap();
+                prop_assert_eq!(result, expected);
+            }
+        }
+    }
+
+    // The marginal reward per token (current - last) within the
+    // `calculate_eligible_rewards` function (tested below) is expressed in
+    // terms of rewards _per token_, which is stored as a `u128` and calculated
+    // by the `calculate_rewards_per_token` function (tested above).
+    //
+    // The return type of `calculate_eligible_rewards` is limited to
+    // `u64::MAX`, but in order to determine the function's upper bounds for
+    // each input parameter, we must consider the maximum marginal reward per token
+    // (`current_accumulated_rewards_per_token`
+    //             - `last_accumulated_rewards_per_token`)
+    // and token account balance that this function can support.
+    //
+    // Since the marginal reward per token is at its maximum anytime a holder
+    // has a "last seen rate" (`last_accumulated_rewards_per_token`) of zero,
+    // we can evaluate in terms of `current_accumulated_rewards_per_token`,
+    // assuming the "last seen rate" to be zero. We will stick to this
+    // assumption in all references to `marginal_rewards_per_token` below.
+    //
+    // On its face, the maximum marginal reward per token is bound by
+    // `u128::MAX` - since both `current_accumulated_rewards_per_token` and
+    // `last_accumulated_rewards_per_token` are represented as `u128` integers.
+    // However, since the return value is capped at `u64::MAX`, we can perform
+    // the following arithmetic.
+    //
+    // Consider the original function:
+    //
+    //     eligible_rewards = (marginal_rewards_per_token * balance) / 1e18
+    //
+    // We can plug in `u64::MAX` for both `eligible_rewards` and `balance`
+    // to calculate the input `marginal_rewards_per_token` upper bound.
+    //
+    //     u64::MAX = (marginal_rewards_per_token * u64::MAX) / 1e18
+    //
+    // And evaluate to:
+    //
+    //     marginal_rewards_per_token = 1e18
+    //
+    // This means `calculate_eligible_rewards` can handle a maximum marginal
+    // reward per token of 1e18, or 1 lamport per token.
+    //
+    // But what does this mean as a constraint on the system as a whole? In
+    // other words, if a holder had 100% of the token supply and their last
+    // seen rate was zero, what's the maximum number of rewards the entire
+    // system can accumulate (in lamports) before this function would break?
+    //
+    // We can compute this value from the formula for
+    // `calculate_rewards_per_token`, which is represented below.
+    //
+    //     rewards_per_token = (reward * 1e18) / mint_supply
+    //
+    // Plugging in the values for maximum marginal reward per token and
+    // `u64::MAX` for token supply...
+    //
+    //     1e18 = (reward * 1e18) / u64::MAX
+    //
+    // ... we get:
+    //
+    //     reward = u64::MAX
+    //
+    // This means that the maximum lamports that can be paid into the system
+    // when a holder has 100% of the token supply and has never claimed is
+    // u64::MAX.
+    //
+    // This also means that the two functions - `calculate_rewards_per_token`
+    // and `calculate_eligible_rewards` share the same upper bound, since
+    // `calculate_rewards_per_token` expects a `u64` for rewards.
+    //
+    // However, since rewards can be paid into the system incrementally, and
+    // are stored as _rewards per token_ in a `u128`, it's mathematically
+    // possible for the system to receive more than `u64::MAX` over time.
+    //
+    // It's worth noting that `u64::MAX` exceeds the current circulating supply
+    // of SOL (`4.66e15`) by 386_266 %.
+    //
+    // That being said, we can pipe `u64::MAX` into `calculate_rewards_per_token`
+    // as the function's upper bound for proptesting. This will also max-out at
+    // 1e18.
+    prop_compose! {
+        fn current_last_and_balance(max_accumulated_rewards: u64)
+        (mint_supply in 0..u64::MAX)
+        (
+            current in 0..=calculate_rewards_per_token(
+                max_accumulated_rewards,
+                mint_supply,
+            ).unwrap(),
+            balance in 0..=mint_supply,
+        ) -> (u128, u128, u64) {
+            (
+                current, // Current accumulated rewards per token.
+                0,       // Last accumulated rewards per token (always 0 here for maximum margin).
+                balance, // Token account balance (up to 100% of mint supply).
+            )
+        }
+    }
+    proptest! {
+        #[test]
+        fn test_calculate_eligible_rewards(
+            (
+                current_accumulated_rewards_per_token,
+                last_accumulated_rewards_per_token,
+                token_account_balance,
+            ) in current_last_and_balance(u64::MAX),
+        ) {
+            // Calculate.
+            let result = calculate_eligible_rewards(
+                current_accumulated_rewards_per_token,
+                last_accumulated_rewards_per_token,
+                token_account_balance,
+            )
+            .unwrap();
+            // Evaluate.
+            //
+            // Since we've configured the inputs so that last never exceeds
+            // current, this subtraction never overflows, so it's safe to
+            // unwrap here.
+            let marginal_rate = current_accumulated_rewards_per_token
+                .checked_sub(last_accumulated_rewards_per_token)
+                .unwrap();
+            if marginal_rate == 0 {
+                // If the marginal rate resolves to zero, the
+                // calculation should short-circuit and return zero.
+                prop_assert_eq!(result, 0);
+            } else {
+                // The rest of the calculation consists of three steps,
+                // so evaluate each step one at a time.
+                //
+                // 1. marginal rate x token account balance
+                // 2. product / REWARDS_PER_TOKEN_SCALING_FACTOR
+                // 3. product.try_into (u64)
+
+                // Step 1.
+                //
+                // Since we've restricted the inputs within the bounds
+                // of the system, the multiplication should never exceed
+                // `u128::MAX`.
+                let marginal_rewards = marginal_rate
+                    .checked_mul(token_account_balance as u128)
+                    .unwrap();
+
+                // Step 2.
+                //
+                // Since we're always dividing by a non-zero constant,
+                // the division should never return `None`, so we can
+                // unwrap here.
+                let descaled_marginal_rewards = marginal_rewards
+                    .checked_div(REWARDS_PER_TOKEN_SCALING_FACTOR)
+                    .unwrap();
+
+                // Step 3.
+                //
+                // Since we've restricted the inputs within the bounds
+                // of the system, the conversion to `u64` should always
+                // succeed.
+                let expected_result: u64 = descaled_marginal_rewards
+                    .try_into()
+                    .unwrap();
+
+                // The calculation should return the expected value.
+                prop_assert_eq!(result, expected_result);
+            }
+        }
+    }
+}
@@ -66,6 +66,174 @@ pub enum PaladinRewardsError {
     /// 16 - DUNA PDA invalid seeds
     #[error("DUNA PDA invalid seeds")]
     InvalidDunaPdaSeeds,
+    /// 17 - Incorrect holder rewards pool authority
+    #[error("Incorrect holder rewards pool authority")]
+    IncorrectPoolAuthority,
+    /// 18 - Pool token account has no excess tokens to recover
+    #[error("Pool token account has no excess tokens to recover")]
+    NoExcessTokens,
+    /// 19 - Mint is not owned by the SPL Token program
+    #[error("Mint is not owned by the SPL Token program")]
+    InvalidMintOwner,
+    /// 20 - Token account is not owned by the SPL Token program
+    #[error("Token account is not owned by the SPL Token program")]
+    InvalidTokenProgram,
+    /// 21 - Account must be writable
+    #[error("Account must be writable")]
+    AccountNotWritable,
+    /// 22 - Incorrect token program account
+    #[error("Incorrect token program account")]
+    IncorrectTokenProgram,
+    /// 23 - Incorrect system program account
+    #[error("Incorrect system program account")]
+    IncorrectSystemProgram,
+    /// 24 - Incorrect rent payer account
+    #[error("Incorrect rent payer account")]
+    IncorrectRentPayer,
+    /// 25 - Holder rewards pool has already been migrated
+    #[error("Holder rewards pool has already been migrated")]
+    PoolAlreadyMigrated,
+    /// 26 - Incorrect rate history address
+    #[error("Incorrect rate history address")]
+    IncorrectRateHistoryAddress,
+    /// 27 - Incorrect lockup tiers address
+    #[error("Incorrect lockup tiers address")]
+    IncorrectLockupTiersAddress,
+    /// 28 - Invalid lockup tiers
+    #[error("Invalid lockup tiers")]
+    InvalidLockupTiers,
+    /// 29 - Lockup duration is shorter than the shortest lockup tier
+    #[error("Lockup duration is shorter than the shortest lockup tier")]
+    LockupDurationTooShort,
+    /// 30 - Lockup cannot be shortened
+    #[error("Lockup cannot be shortened")]
+    LockupCannotBeShortened,
+    /// 31 - Lock amount exceeds unlocked deposited tokens
+    #[error("Lock amount exceeds unlocked deposited tokens")]
+    LockExceedsUnlocked,
+    /// 32 - Withdraw amount exceeds unlocked deposited tokens
+    #[error("Withdraw amount exceeds unlocked deposited tokens")]
+    WithdrawExceedsUnlocked,
+    /// 33 - Lockup has not expired
+    #[error("Lockup has not expired")]
+    LockupNotExpired,
+    /// 34 - Lockup duration exceeds the maximum lockup duration
+    #[error("Lockup duration exceeds the maximum lockup duration")]
+    LockupDurationTooLong,
+    /// 35 - Incorrect vote escrow address
+    #[error("Incorrect vote escrow address")]
+    IncorrectVoteEscrowAddress,
+    /// 36 - The pool's vote escrow account is required
+    #[error("The pool's vote escrow account is required")]
+    VoteEscrowRequired,
+    /// 37 - Incorrect stake checkpoints address
+    #[error("Incorrect stake checkpoints address")]
+    IncorrectStakeCheckpointsAddress,
+    /// 38 - The holder's stake checkpoints account is required
+    #[error("The holder's stake checkpoints account is required")]
+    StakeCheckpointsRequired,
+    /// 39 - No stake checkpoint covers the slot
+    #[error("No stake checkpoint covers the slot")]
+    StakeCheckpointNotFound,
+    /// 40 - Snapshot slot must be in the past
+    #[error("Snapshot slot must be in the past")]
+    SnapshotSlotNotPast,
+    /// 41 - Early withdrawal penalty exceeds 100%
+    #[error("Early withdrawal penalty exceeds 100%")]
+    InvalidEarlyWithdrawalPenalty,
+    /// 42 - Minimum deposit exceeds the maximum holder deposit
+    #[error("Minimum deposit exceeds the maximum holder deposit")]
+    InvalidDepositLimits,
+    /// 43 - Deposit is below the pool's minimum deposit
+    #[error("Deposit is below the pool's minimum deposit")]
+    DepositBelowMinimum,
+    /// 44 - Deposit exceeds the pool's maximum holder deposit
+    #[error("Deposit exceeds the pool's maximum holder deposit")]
+    HolderDepositCapExceeded,
+    /// 45 - Deposit exceeds the pool's maximum total deposit
+    #[error("Deposit exceeds the pool's maximum total deposit")]
+    PoolDepositCapExceeded,
+    /// 46 - Incorrect access entry address
+    #[error("Incorrect access entry address")]
+    IncorrectAccessEntryAddress,
+    /// 47 - The wallet's access entry is required by the pool's access list
+    #[error("The wallet's access entry is required by the pool's access list")]
+    AccessEntryRequired,
+    /// 48 - The pool's access list does not permit the wallet to deposit
+    #[error("The pool's access list does not permit the wallet to deposit")]
+    AccessDenied,
+    /// 49 - Invalid access list mode
+    #[error("Invalid access list mode")]
+    InvalidAccessListMode,
+    /// 50 - Invalid access status
+    #[error("Invalid access status")]
+    InvalidAccessStatus,
+    /// 51 - Incorrect receipt mint address
+    #[error("Incorrect receipt mint address")]
+    IncorrectReceiptMintAddress,
+    /// 52 - The receipt accounts are required by the pool's receipt mint
+    #[error("The receipt accounts are required by the pool's receipt mint")]
+    ReceiptAccountsRequired,
+    /// 53 - Receipt mint already initialized
+    #[error("Receipt mint already initialized")]
+    ReceiptMintAlreadyInitialized,
+    /// 54 - Receipt mint can only be initialized before any deposits
+    #[error("Receipt mint can only be initialized before any deposits")]
+    ReceiptMintRequiresEmptyPool,
+    /// 55 - Receipt tokens are not being transferred
+    #[error("Receipt tokens are not being transferred")]
+    ReceiptNotTransferring,
+    /// 56 - Receipt transfer exceeds the holder's unlocked deposit
+    #[error("Receipt transfer exceeds the holder's unlocked deposit")]
+    ReceiptTransferExceedsUnlocked,
+    /// 57 - Incorrect extra account metas address
+    #[error("Incorrect extra account metas address")]
+    IncorrectExtraAccountMetasAddress,
+    /// 58 - Incorrect slashing authority
+    #[error("Incorrect slashing authority")]
+    IncorrectSlashingAuthority,
+    /// 59 - Incorrect slash destination
+    #[error("Incorrect slash destination")]
+    IncorrectSlashDestination,
+    /// 60 - Slash exceeds the holder's deposit
+    #[error("Slash exceeds the holder's deposit")]
+    SlashExceedsDeposited,
+    /// 61 - Campaign must end after it starts and after the current slot
+    #[error("Campaign must end after it starts and after the current slot")]
+    InvalidCampaignSchedule,
+    /// 62 - Incorrect campaign address
+    #[error("Incorrect campaign address")]
+    IncorrectCampaignAddress,
+    /// 63 - Campaign has not ended
+    #[error("Campaign has not ended")]
+    CampaignNotEnded,
+    /// 64 - Incorrect campaign funder
+    #[error("Incorrect campaign funder")]
+    IncorrectCampaignFunder,
+    /// 65 - Incorrect epoch ledger address
+    #[error("Incorrect epoch ledger address")]
+    IncorrectEpochLedgerAddress,
+    /// 66 - Holder rewards account has already been migrated
+    #[error("Holder rewards account has already been migrated")]
+    HolderRewardsAlreadyMigrated,
+    /// 67 - Holder rewards pool has no weight to distribute rewards to
+    #[error("Holder rewards pool has no weight to distribute rewards to")]
+    PoolHasNoWeight,
+    /// 68 - Deposits in a pool with a receipt mint cannot be slashed
+    #[error("Deposits in a pool with a receipt mint cannot be slashed")]
+    ReceiptPoolNotSlashable,
+    /// 69 - The pool's rate history account is required
+    #[error("The pool's rate history account is required")]
+    RateHistoryRequired,
+    /// 70 - Pool token account is not the pool's associated token account
+    #[error("Pool token account is not the pool's associated token account")]
+    IncorrectPoolTokenAccountAddress,
+    /// 71 - The pool's epoch ledger account is required
+    #[error("The pool's epoch ledger account is required")]
+    EpochLedgerRequired,
+    /// 72 - Receipt recipient has no holder rewards account
+    #[error("Receipt recipient has no holder rewards account")]
+    ReceiptRecipientNotInitialized,
 }
 
 impl PrintProgramError for PaladinRewardsError {
@@ -0,0 +1,658 @@
+//! Program event types.
+//!
+//! Events are emitted with `sol_log_data` as a single buffer: a one-byte
+//! schema version followed by the Borsh-serialized
+//! [PaladinRewardsEvent](enum.PaladinRewardsEvent.html).
+
+use {
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::{log::sol_log_data, pubkey::Pubkey},
+};
+
+/// The current version of the event schema.
+///
+/// Bumped whenever an existing event's layout changes. New variants are only
+/// ever appended, so they do not require a version bump.
+pub const EVENT_VERSION: u8 = 1;
+
+/// Events emitted by the Paladin Rewards program.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub enum PaladinRewardsEvent {
+    /// A holder rewards pool was initialized.
+    PoolInitialized {
+        holder_rewards_pool: Pubkey,
+        mint: Pubkey,
+        authority: Pubkey,
+    },
+    /// A holder rewards account was initialized.
+    HolderInitialized {
+        holder_rewards_pool: Pubkey,
+        owner: Pubkey,
+        last_accumulated_rewards_per_token: u128,
+    },
+    /// Tokens were deposited into the pool.
+    Deposited {
+        holder_rewards_pool: Pubkey,
+        owner: Pubkey,
+        amount: u64,
+        deposited: u64,
+    },
+    /// Tokens were withdrawn from the pool.
+    Withdrawn {
+        holder_rewards_pool: Pubkey,
+        owner: Pubkey,
+        amount: u64,
+        deposited: u64,
+    },
+    /// Rewards were paid out to a holder.
+    Harvested {
+        holder_rewards_pool: Pubkey,
+        owner: Pubkey,
+        amount: u64,
+    },
+    /// Lamports received by the pool were accounted for in its rate.
+    RewardsAccrued {
+        holder_rewards_pool: Pubkey,
+        inflow: u64,
+        accumulated_rewards_per_token: u128,
+    },
+    /// A holder rewards account was closed.
+    HolderClosed {
+        holder_rewards_pool: Pubkey,
+        owner: Pubkey,
+    },
+    /// Tokens in excess of the pool's deposits were recovered.
+    ExcessTokensRecovered {
+        holder_rewards_pool: Pubkey,
+        destination: Pubkey,
+        amount: u64,
+    },
+    /// A holder rewards pool was migrated to the current layout.
+    PoolMigrated { holder_rewards_pool: Pubkey },
+    /// A rate history account was initialized for a holder rewards pool.
+    RateHistoryInitialized { holder_rewards_pool: Pubkey },
+    /// A holder rewards pool's lockup tiers were set.
+    LockupTiersSet { holder_rewards_pool: Pubkey },
+    /// Deposited tokens were locked, or an existing lockup was extended.
+    Locked {
+        holder_rewards_pool: Pubkey,
+        owner: Pubkey,
+        locked_amount: u64,
+        unlock_timestamp: i64,
+        multiplier_bps: u64,
+    },
+    /// An expired lockup was released, returning its tokens to `1x` weight.
+    LockupReleased {
+        holder_rewards_pool: Pubkey,
+        owner: Pubkey,
+        amount: u64,
+    },
+    /// A vote escrow account was initialized for a holder rewards pool.
+    VoteEscrowInitialized {
+        holder_rewards_pool: Pubkey,
+        reward_weighting: bool,
+    },
+    /// A holder's voting power was checkpointed, re-weighting their lockup
+    /// if rewards follow voting power.
+    VotingPowerCheckpointed {
+        holder_rewards_pool: Pubkey,
+        owner: Pubkey,
+        voting_power: u64,
+        multiplier_bps: u64,
+    },
+    /// A holder started recording stake checkpoints.
+    StakeCheckpointsInitialized { owner: Pubkey },
+    /// A holder rewards pool's early withdrawal penalty was set.
+    EarlyWithdrawalPenaltySet {
+        holder_rewards_pool: Pubkey,
+        penalty_bps: u16,
+        period_slots: u32,
+    },
+    /// A holder withdrawing early forfeited part of their pending rewards to
+    /// the other holders.
+    EarlyWithdrawalPenalized {
+        holder_rewards_pool: Pubkey,
+        owner: Pubkey,
+        forfeited: u64,
+    },
+    /// A holder rewards pool's deposit limits were set.
+    DepositLimitsSet {
+        holder_rewards_pool: Pubkey,
+        max_total_deposit: u64,
+        max_holder_deposit: u64,
+        min_deposit: u64,
+    },
+    /// A holder rewards pool's access list mode was set.
+    AccessListSet {
+        holder_rewards_pool: Pubkey,
+        access_list: u8,
+    },
+    /// A wallet's status in a holder rewards pool's access list was set.
+    AccessEntrySet {
+        holder_rewards_pool: Pubkey,
+        wallet: Pubkey,
+        status: u8,
+    },
+    /// A holder rewards pool's receipt token mint was initialized.
+    ReceiptMintInitialized {
+        holder_rewards_pool: Pubkey,
+        receipt_mint: Pubkey,
+    },
+    /// Receipt tokens were transferred, moving their deposit between holders.
+    ReceiptsTransferred {
+        holder_rewards_pool: Pubkey,
+        source_owner: Pubkey,
+        destination_owner: Pubkey,
+        amount: u64,
+    },
+    /// Withdrawn tokens were sent to a token account other than the owner's.
+    WithdrawalRedirected {
+        holder_rewards_pool: Pubkey,
+        owner: Pubkey,
+        destination: Pubkey,
+        amount: u64,
+    },
+    /// Tokens were withdrawn from the pool without paying out rewards,
+    /// leaving them owed to the holder.
+    EmergencyWithdrawn {
+        holder_rewards_pool: Pubkey,
+        owner: Pubkey,
+        amount: u64,
+        deposited: u64,
+        unharvested_rewards: u64,
+    },
+    /// A pool's slashing authority and slash destination were set.
+    SlashingConfigSet {
+        holder_rewards_pool: Pubkey,
+        slashing_authority: Pubkey,
+        slash_destination: Pubkey,
+    },
+    /// A holder's deposit was slashed.
+    Slashed {
+        holder_rewards_pool: Pubkey,
+        owner: Pubkey,
+        amount: u64,
+        deposited: u64,
+    },
+    /// A reward campaign was funded.
+    CampaignCreated {
+        holder_rewards_pool: Pubkey,
+        campaign: Pubkey,
+        funder: Pubkey,
+        amount: u64,
+        start_slot: u64,
+        end_slot: u64,
+    },
+    /// A campaign's unlocked rewards were released into the pool, or set
+    /// aside for the funder if nothing was deposited.
+    CampaignRewardsReleased {
+        holder_rewards_pool: Pubkey,
+        campaign: Pubkey,
+        released: u64,
+        unspent: u64,
+    },
+    /// A campaign ended and its unspent rewards were returned to the funder.
+    CampaignReclaimed {
+        holder_rewards_pool: Pubkey,
+        campaign: Pubkey,
+        funder: Pubkey,
+        unspent: u64,
+    },
+    /// Rewards were distributed to the pool by a funder.
+    RewardsDistributed {
+        holder_rewards_pool: Pubkey,
+        funder: Pubkey,
+        amount: u64,
+        memo: [u8; 32],
+    },
+    /// An epoch ledger account was initialized for a holder rewards pool.
+    EpochLedgerInitialized { holder_rewards_pool: Pubkey },
+    /// A holder rewards account was migrated to the current layout.
+    HolderRewardsMigrated { holder_rewards: Pubkey },
+}
+
+impl PaladinRewardsEvent {
+    /// Serializes the event, prefixed with the schema version.
+    pub fn pack(&self) -> Vec<u8> {
+        let mut data = vec![EVENT_VERSION];
+        // Writing into a `Vec` cannot fail.
+        self.serialize(&mut data).unwrap();
+        data
+    }
+
+    /// Logs the event as program data.
+    pub fn emit(&self) {
+        sol_log_data(&[&self.pack()]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, paladin_rewards_program_client::events as client};
+
+    fn assert_client_decodes(event: PaladinRewardsEvent, expected: client::PaladinRewardsEvent) {
+        let decoded = client::PaladinRewardsEvent::decode(&event.pack()).unwrap();
+        assert_eq!(decoded, expected);
+    }
+
+    #[test]
+    fn test_pack_starts_with_version() {
+        let event = PaladinRewardsEvent::HolderClosed {
+            holder_rewards_pool: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+        };
+        assert_eq!(event.pack()[0], EVENT_VERSION);
+    }
+
+    #[test]
+    fn test_client_decodes_events() {
+        let holder_rewards_pool = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+        let holder_rewards = Pubkey::new_unique();
+
+        assert_client_decodes(
+            PaladinRewardsEvent::PoolInitialized {
+                holder_rewards_pool,
+                mint,
+                authority,
+            },
+            client::PaladinRewardsEvent::PoolInitialized {
+                holder_rewards_pool,
+                mint,
+                authority,
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::HolderInitialized {
+                holder_rewards_pool,
+                owner,
+                last_accumulated_rewards_per_token: u128::MAX,
+            },
+            client::PaladinRewardsEvent::HolderInitialized {
+                holder_rewards_pool,
+                owner,
+                last_accumulated_rewards_per_token: u128::MAX,
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::Deposited {
+                holder_rewards_pool,
+                owner,
+                amount: 1,
+                deposited: 2,
+            },
+            client::PaladinRewardsEvent::Deposited {
+                holder_rewards_pool,
+                owner,
+                amount: 1,
+                deposited: 2,
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::Withdrawn {
+                holder_rewards_pool,
+                owner,
+                amount: 3,
+                deposited: 4,
+            },
+            client::PaladinRewardsEvent::Withdrawn {
+                holder_rewards_pool,
+                owner,
+                amount: 3,
+                deposited: 4,
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::Harvested {
+                holder_rewards_pool,
+                owner,
+                amount: 5,
+            },
+            client::PaladinRewardsEvent::Harvested {
+                holder_rewards_pool,
+                owner,
+                amount: 5,
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::RewardsAccrued {
+                holder_rewards_pool,
+                inflow: 6,
+                accumulated_rewards_per_token: 7,
+            },
+            client::PaladinRewardsEvent::RewardsAccrued {
+                holder_rewards_pool,
+                inflow: 6,
+                accumulated_rewards_per_token: 7,
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::HolderClosed {
+                holder_rewards_pool,
+                owner,
+            },
+            client::PaladinRewardsEvent::HolderClosed {
+                holder_rewards_pool,
+                owner,
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::ExcessTokensRecovered {
+                holder_rewards_pool,
+                destination,
+                amount: 8,
+            },
+            client::PaladinRewardsEvent::ExcessTokensRecovered {
+                holder_rewards_pool,
+                destination,
+                amount: 8,
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::PoolMigrated {
+                holder_rewards_pool,
+            },
+            client::PaladinRewardsEvent::PoolMigrated {
+                holder_rewards_pool,
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::RateHistoryInitialized {
+                holder_rewards_pool,
+            },
+            client::PaladinRewardsEvent::RateHistoryInitialized {
+                holder_rewards_pool,
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::LockupTiersSet {
+                holder_rewards_pool,
+            },
+            client::PaladinRewardsEvent::LockupTiersSet {
+                holder_rewards_pool,
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::Locked {
+                holder_rewards_pool,
+                owner,
+                locked_amount: 9,
+                unlock_timestamp: -10,
+                multiplier_bps: 11,
+            },
+            client::PaladinRewardsEvent::Locked {
+                holder_rewards_pool,
+                owner,
+                locked_amount: 9,
+                unlock_timestamp: -10,
+                multiplier_bps: 11,
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::LockupReleased {
+                holder_rewards_pool,
+                owner,
+                amount: 12,
+            },
+            client::PaladinRewardsEvent::LockupReleased {
+                holder_rewards_pool,
+                owner,
+                amount: 12,
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::VoteEscrowInitialized {
+                holder_rewards_pool,
+                reward_weighting: true,
+            },
+            client::PaladinRewardsEvent::VoteEscrowInitialized {
+                holder_rewards_pool,
+                reward_weighting: true,
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::VotingPowerCheckpointed {
+                holder_rewards_pool,
+                owner,
+                voting_power: 13,
+                multiplier_bps: 14,
+            },
+            client::PaladinRewardsEvent::VotingPowerCheckpointed {
+                holder_rewards_pool,
+                owner,
+                voting_power: 13,
+                multiplier_bps: 14,
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::StakeCheckpointsInitialized { owner },
+            client::PaladinRewardsEvent::StakeCheckpointsInitialized { owner },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::EarlyWithdrawalPenaltySet {
+                holder_rewards_pool,
+                penalty_bps: 15,
+                period_slots: 16,
+            },
+            client::PaladinRewardsEvent::EarlyWithdrawalPenaltySet {
+                holder_rewards_pool,
+                penalty_bps: 15,
+                period_slots: 16,
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::EarlyWithdrawalPenalized {
+                holder_rewards_pool,
+                owner,
+                forfeited: 17,
+            },
+            client::PaladinRewardsEvent::EarlyWithdrawalPenalized {
+                holder_rewards_pool,
+                owner,
+                forfeited: 17,
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::DepositLimitsSet {
+                holder_rewards_pool,
+                max_total_deposit: 18,
+                max_holder_deposit: 19,
+                min_deposit: 20,
+            },
+            client::PaladinRewardsEvent::DepositLimitsSet {
+                holder_rewards_pool,
+                max_total_deposit: 18,
+                max_holder_deposit: 19,
+                min_deposit: 20,
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::AccessListSet {
+                holder_rewards_pool,
+                access_list: 1,
+            },
+            client::PaladinRewardsEvent::AccessListSet {
+                holder_rewards_pool,
+                access_list: 1,
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::AccessEntrySet {
+                holder_rewards_pool,
+                wallet: owner,
+                status: 2,
+            },
+            client::PaladinRewardsEvent::AccessEntrySet {
+                holder_rewards_pool,
+                wallet: owner,
+                status: 2,
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::ReceiptMintInitialized {
+                holder_rewards_pool,
+                receipt_mint: mint,
+            },
+            client::PaladinRewardsEvent::ReceiptMintInitialized {
+                holder_rewards_pool,
+                receipt_mint: mint,
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::ReceiptsTransferred {
+                holder_rewards_pool,
+                source_owner: owner,
+                destination_owner: destination,
+                amount: 21,
+            },
+            client::PaladinRewardsEvent::ReceiptsTransferred {
+                holder_rewards_pool,
+                source_owner: owner,
+                destination_owner: destination,
+                amount: 21,
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::WithdrawalRedirected {
+                holder_rewards_pool,
+                owner,
+                destination,
+                amount: 22,
+            },
+            client::PaladinRewardsEvent::WithdrawalRedirected {
+                holder_rewards_pool,
+                owner,
+                destination,
+                amount: 22,
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::EmergencyWithdrawn {
+                holder_rewards_pool,
+                owner,
+                amount: 23,
+                deposited: 24,
+                unharvested_rewards: 25,
+            },
+            client::PaladinRewardsEvent::EmergencyWithdrawn {
+                holder_rewards_pool,
+                owner,
+                amount: 23,
+                deposited: 24,
+                unharvested_rewards: 25,
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::SlashingConfigSet {
+                holder_rewards_pool,
+                slashing_authority: owner,
+                slash_destination: destination,
+            },
+            client::PaladinRewardsEvent::SlashingConfigSet {
+                holder_rewards_pool,
+                slashing_authority: owner,
+                slash_destination: destination,
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::Slashed {
+                holder_rewards_pool,
+                owner,
+                amount: 26,
+                deposited: 27,
+            },
+            client::PaladinRewardsEvent::Slashed {
+                holder_rewards_pool,
+                owner,
+                amount: 26,
+                deposited: 27,
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::CampaignCreated {
+                holder_rewards_pool,
+                campaign: destination,
+                funder: owner,
+                amount: 28,
+                start_slot: 29,
+                end_slot: 30,
+            },
+            client::PaladinRewardsEvent::CampaignCreated {
+                holder_rewards_pool,
+                campaign: destination,
+                funder: owner,
+                amount: 28,
+                start_slot: 29,
+                end_slot: 30,
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::CampaignRewardsReleased {
+                holder_rewards_pool,
+                campaign: destination,
+                released: 31,
+                unspent: 32,
+            },
+            client::PaladinRewardsEvent::CampaignRewardsReleased {
+                holder_rewards_pool,
+                campaign: destination,
+                released: 31,
+                unspent: 32,
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::CampaignReclaimed {
+                holder_rewards_pool,
+                campaign: destination,
+                funder: owner,
+                unspent: 33,
+            },
+            client::PaladinRewardsEvent::CampaignReclaimed {
+                holder_rewards_pool,
+                campaign: destination,
+                funder: owner,
+                unspent: 33,
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::RewardsDistributed {
+                holder_rewards_pool,
+                funder: owner,
+                amount: 34,
+                memo: [35; 32],
+            },
+            client::PaladinRewardsEvent::RewardsDistributed {
+                holder_rewards_pool,
+                funder: owner,
+                amount: 34,
+                memo: [35; 32],
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::EpochLedgerInitialized {
+                holder_rewards_pool,
+            },
+            client::PaladinRewardsEvent::EpochLedgerInitialized {
+                holder_rewards_pool,
+            },
+        );
+        assert_client_decodes(
+            PaladinRewardsEvent::HolderRewardsMigrated { holder_rewards },
+            client::PaladinRewardsEvent::HolderRewardsMigrated { holder_rewards },
+        );
+    }
+
+    #[test]
+    fn test_client_rejects_unknown_version() {
+        let mut data = PaladinRewardsEvent::HolderClosed {
+            holder_rewards_pool: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+        }
+        .pack();
+        data[0] = EVENT_VERSION + 1;
+        assert_eq!(client::PaladinRewardsEvent::decode(&data), None);
+    }
+}
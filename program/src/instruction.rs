@@ -1,6 +1,11 @@
 //! Program instruction types.
 
-use {arrayref::array_ref, shank::ShankInstruction, solana_program::program_error::ProgramError};
+use {
+    crate::state::{LockupTier, LOCKUP_TIERS_CAPACITY},
+    arrayref::array_ref,
+    shank::ShankInstruction,
+    solana_program::program_error::ProgramError,
+};
 
 /// Instructions supported by the Paladin Rewards program.
 #[rustfmt::skip]
@@ -14,13 +19,18 @@ pub enum PaladinRewardsInstruction {
     /// - Initialize a holder rewards pool account.
     /// - Initialize the required accounts for the transfer hook.
     ///
+    /// The pool authority must be the mint's mint authority, and must sign.
+    ///
     /// Accounts expected by this instruction:
     ///
     /// 0. `[w]` Holder rewards pool account.
-    /// 1. `[w]` Transfer hook extra account metas account.
+    /// 1. `[ ]` Holder rewards pool token account.
     /// 2. `[ ]` Token mint.
-    /// 3. `[s]` Mint authority.
-    /// 4. `[ ]` System program.
+    /// 3. `[ ]` Stake vault PDA.
+    /// 4. `[w]` Stake vault holder rewards account.
+    /// 5. `[s]` Pool authority.
+    /// 6. `[w, s]` Payer.
+    /// 7. `[ ]` System program.
     #[account(
         0,
         writable,
@@ -50,6 +60,19 @@ pub enum PaladinRewardsInstruction {
     )]
     #[account(
         5,
+        signer,
+        name = "authority",
+        desc = "Pool authority, the mint's mint authority.",
+    )]
+    #[account(
+        6,
+        signer,
+        writable,
+        name = "payer",
+        desc = "Payer funding the rent for the new accounts.",
+    )]
+    #[account(
+        7,
         name = "system_program",
         desc = "System program.",
     )]
@@ -68,7 +91,8 @@ pub enum PaladinRewardsInstruction {
     /// 1. `[w]` Holder rewards account.
     /// 2. `[ ]` Token account.
     /// 3. `[ ]` Token mint.
-    /// 4. `[ ]` System program.
+    /// 4. `[w, s]` Payer.
+    /// 5. `[ ]` System program.
     #[account(
         0,
         writable,
@@ -105,21 +129,53 @@ pub enum PaladinRewardsInstruction {
     )]
     #[account(
         6,
+        signer,
+        writable,
+        name = "payer",
+        desc = "Payer funding the rent for the holder rewards account.",
+    )]
+    #[account(
+        7,
         name = "system_program",
         desc = "System program.",
     )]
+    #[account(
+        8,
+        optional,
+        writable,
+        name = "rate_history",
+        desc = "Rate history account, if the pool records one.",
+    )]
+    #[account(
+        9,
+        optional,
+        name = "access_entry",
+        desc = "Owner's access entry account, required if the pool has an access list.",
+    )]
+    #[account(
+        10,
+        optional,
+        writable,
+        name = "epoch_ledger",
+        desc = "Epoch ledger account, if the pool records one.",
+    )]
     InitializeHolderRewards,
     /// Moves accrued SOL rewards into the provided token account based on the
     /// share of the total rewards pool represented in the holder rewards
     /// account.
     ///
+    /// Rewards are withheld while the holder is within the pool's early
+    /// withdrawal period.
+    ///
     /// Accounts expected by this instruction:
     ///
     /// 0. `[w]` Holder rewards pool account.
     /// 1. `[w]` Holder rewards account.
     /// 2. `[w]` Token account.
     /// 3. `[ ]` Token mint.
-    /// 4. `[w]?` Sponsor account if rent_debt is non zero.
+    /// 4. `[w, s]` Owner of the holder rewards account.
+    /// 5. `[w]` (Optional) Rate history account.
+    /// 6. `[w]` (Optional) Epoch ledger account.
     #[account(
         0,
         writable,
@@ -149,8 +205,23 @@ pub enum PaladinRewardsInstruction {
         name = "owner",
         desc = "owner of token account",
     )]
+    #[account(
+        5,
+        optional,
+        writable,
+        name = "rate_history",
+        desc = "Rate history account, if the pool records one.",
+    )]
+    #[account(
+        6,
+        optional,
+        writable,
+        name = "epoch_ledger",
+        desc = "Epoch ledger account, if the pool records one.",
+    )]
     HarvestRewards,
-    /// Closes the provided holder rewards account.
+    /// Closes the provided holder rewards account, returning its rent to the
+    /// payer that funded it.
     #[account(
         0,
         writable,
@@ -181,6 +252,26 @@ pub enum PaladinRewardsInstruction {
         name = "owner",
         desc = "Owner of the account.",
     )]
+    #[account(
+        5,
+        writable,
+        name = "rent_payer",
+        desc = "Payer that funded the holder rewards account.",
+    )]
+    #[account(
+        6,
+        optional,
+        writable,
+        name = "rate_history",
+        desc = "Rate history account, if the pool records one.",
+    )]
+    #[account(
+        7,
+        optional,
+        writable,
+        name = "epoch_ledger",
+        desc = "Epoch ledger account, if the pool records one.",
+    )]
     CloseHolderRewards,
     #[account(
         0,
@@ -223,6 +314,53 @@ pub enum PaladinRewardsInstruction {
         name = "token program",
         desc = "token program",
     )]
+    #[account(
+        7,
+        optional,
+        writable,
+        name = "rate_history",
+        desc = "Rate history account, if the pool records one.",
+    )]
+    #[account(
+        8,
+        optional,
+        writable,
+        name = "stake_checkpoints",
+        desc = "Stake checkpoints account, required if the holder records one.",
+    )]
+    #[account(
+        9,
+        optional,
+        name = "access_entry",
+        desc = "Owner's access entry account, required if the pool has an access list.",
+    )]
+    #[account(
+        10,
+        optional,
+        writable,
+        name = "receipt_mint",
+        desc = "Receipt token mint, required if the pool mints receipt tokens.",
+    )]
+    #[account(
+        11,
+        optional,
+        writable,
+        name = "receipt_token_account",
+        desc = "Owner's receipt token account, required if the pool mints receipt tokens.",
+    )]
+    #[account(
+        12,
+        optional,
+        name = "receipt_token_program",
+        desc = "Token-2022 program, required if the pool mints receipt tokens.",
+    )]
+    #[account(
+        13,
+        optional,
+        writable,
+        name = "epoch_ledger",
+        desc = "Epoch ledger account, if the pool records one.",
+    )]
     Deposit { amount: u64},
      #[account(
         0,
@@ -246,7 +384,7 @@ pub enum PaladinRewardsInstruction {
         3,
         writable,
         name = "token_account",
-        desc = "Token account.",
+        desc = "Owner's token account, unused if a destination token account is provided, in which case any account may be passed, such as the destination.",
     )]
     #[account(
         4,
@@ -265,115 +403,1926 @@ pub enum PaladinRewardsInstruction {
         name = "token program",
         desc = "token program",
     )]
+    #[account(
+        7,
+        optional,
+        writable,
+        name = "rate_history",
+        desc = "Rate history account, if the pool records one.",
+    )]
+    #[account(
+        8,
+        optional,
+        writable,
+        name = "stake_checkpoints",
+        desc = "Stake checkpoints account, required if the holder records one.",
+    )]
+    #[account(
+        9,
+        optional,
+        writable,
+        name = "receipt_mint",
+        desc = "Receipt token mint, required if the pool mints receipt tokens.",
+    )]
+    #[account(
+        10,
+        optional,
+        writable,
+        name = "receipt_token_account",
+        desc = "Owner's receipt token account, required if the pool mints receipt tokens.",
+    )]
+    #[account(
+        11,
+        optional,
+        name = "receipt_token_program",
+        desc = "Token-2022 program, required if the pool mints receipt tokens.",
+    )]
+    #[account(
+        12,
+        optional,
+        writable,
+        name = "destination_token_account",
+        desc = "Token account receiving the withdrawn tokens instead of the owner's token account.",
+    )]
+    #[account(
+        13,
+        optional,
+        writable,
+        name = "epoch_ledger",
+        desc = "Epoch ledger account, if the pool records one.",
+    )]
     Withdraw { amount: u64},
-}
-
-impl PaladinRewardsInstruction {
-    /// Packs a
-    /// [PaladinRewardsInstruction](enum.PaladinRewardsInstruction.html)
-    /// into a byte buffer.
-    pub fn pack(&self) -> Vec<u8> {
-        match self {
-            PaladinRewardsInstruction::InitializeHolderRewardsPool { duna_document_hash } => {
-                let mut data = Vec::with_capacity(33);
-                data.push(0);
-                data.extend_from_slice(duna_document_hash);
-                data
-            }
-            PaladinRewardsInstruction::InitializeHolderRewards => vec![1],
-            PaladinRewardsInstruction::HarvestRewards => vec![2],
-            PaladinRewardsInstruction::CloseHolderRewards => vec![3],
-            PaladinRewardsInstruction::Deposit { amount } => {
-                let mut data = Vec::with_capacity(9);
-                data.push(4);
-                data.extend_from_slice(&amount.to_le_bytes());
-                data
-            }
-            PaladinRewardsInstruction::Withdraw { amount } => {
-                let mut data = Vec::with_capacity(9);
-                data.push(5);
-                data.extend_from_slice(&amount.to_le_bytes());
-                data
-            }
-        }
-    }
-
-    /// Unpacks a byte buffer into a
-    /// [PaladinRewardsInstruction](enum.PaladinRewardsInstruction.html).
-    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
-        match input.split_first() {
-            Some((&0, rest)) => {
-                let duna_document_hash = *array_ref![rest, 0, 32];
-
-                Ok(PaladinRewardsInstruction::InitializeHolderRewardsPool { duna_document_hash })
-            }
-            Some((&1, _)) => Ok(PaladinRewardsInstruction::InitializeHolderRewards),
-            Some((&2, _)) => Ok(PaladinRewardsInstruction::HarvestRewards),
-            Some((&3, _)) => Ok(PaladinRewardsInstruction::CloseHolderRewards),
-            Some((&4, rest)) if rest.len() == 8 => {
-                let amount = u64::from_le_bytes(*array_ref![rest, 0, 8]);
-
-                Ok(PaladinRewardsInstruction::Deposit { amount })
-            }
-            Some((&5, rest)) if rest.len() == 8 => {
-                let amount = u64::from_le_bytes(*array_ref![rest, 0, 8]);
-
-                Ok(PaladinRewardsInstruction::Withdraw { amount })
-            }
-            _ => Err(ProgramError::InvalidInstructionData),
-        }
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_pack_unpack_initialize_holder_rewards_pool() {
-        let original = PaladinRewardsInstruction::InitializeHolderRewardsPool {
-            duna_document_hash: [0; 32],
-        };
-        let packed = original.pack();
-        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
-        assert_eq!(original, unpacked);
-    }
-
-    #[test]
-    fn test_pack_unpack_initialize_holder_rewards() {
-        let original = PaladinRewardsInstruction::InitializeHolderRewards;
-        let packed = original.pack();
-        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
-        assert_eq!(original, unpacked);
-    }
-
-    #[test]
-    fn test_pack_unpack_harvest_rewards() {
-        let original = PaladinRewardsInstruction::HarvestRewards;
-        let packed = original.pack();
-        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
-        assert_eq!(original, unpacked);
-    }
-
-    #[test]
-    fn test_pack_unpack_close_holder_rewards() {
-        let original = PaladinRewardsInstruction::CloseHolderRewards;
-        let packed = original.pack();
-        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
-        assert_eq!(original, unpacked);
-    }
-
-    #[test]
-    fn test_pack_unpack_deposit() {
-        let original = PaladinRewardsInstruction::Deposit { amount: 1000 };
-        let packed = original.pack();
-        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
-        assert_eq!(original, unpacked);
-    }
-
-    #[test]
-    fn test_pack_unpack_withdraw() {
-        let original = PaladinRewardsInstruction::Withdraw { amount: 1000 };
+    /// Transfers tokens held by the pool token account in excess of the total
+    /// deposited by holders to the provided token account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[ ]` Holder rewards pool account.
+    /// 1. `[w]` Holder rewards pool token account.
+    /// 2. `[w]` Destination token account.
+    /// 3. `[ ]` Token mint.
+    /// 4. `[s]` Pool authority.
+    /// 5. `[ ]` Token program.
+    #[account(
+        0,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "holder_rewards_pool_token_account",
+        desc = "Holder rewards pool token account."
+    )]
+    #[account(
+        2,
+        writable,
+        name = "destination_token_account",
+        desc = "Destination token account.",
+    )]
+    #[account(
+        3,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        4,
+        signer,
+        name = "authority",
+        desc = "Pool authority.",
+    )]
+    #[account(
+        5,
+        name = "token program",
+        desc = "token program",
+    )]
+    RecoverExcessTokens,
+    /// Computes the lamports the holder could currently harvest, including
+    /// any rewards received by the pool since its rate was last updated.
+    ///
+    /// No accounts are modified. The result is written as a little-endian
+    /// `u64` to the transaction's return data.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[ ]` Holder rewards pool account.
+    /// 1. `[ ]` Holder rewards account.
+    /// 2. `[ ]` Token mint.
+    /// 3. `[ ]` Owner of the holder rewards account.
+    #[account(
+        0,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        name = "holder_rewards",
+        desc = "Holder rewards account.",
+    )]
+    #[account(
+        2,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        3,
+        name = "owner",
+        desc = "Owner of the holder rewards account.",
+    )]
+    GetPendingRewards,
+    /// Migrates a holder rewards pool account created by the first release
+    /// of the program to the current layout.
+    ///
+    /// The first release does not store the bump seed, track the total
+    /// deposited or have an authority. The total deposited is taken from the
+    /// balance of the pool's associated token account, and the provided pool
+    /// authority, which must be the mint's mint authority and sign, is set.
+    ///
+    /// Statistics are tracked from the migration onwards, so the pool's
+    /// totals and active holder count only reflect activity since then.
+    /// Migrated pools start without deposit limits.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[ ]` Holder rewards pool token account.
+    /// 2. `[ ]` Token mint.
+    /// 3. `[s]` Pool authority.
+    /// 4. `[w, s]` Payer.
+    /// 5. `[ ]` System program.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        name = "holder_rewards_pool_token_account",
+        desc = "Holder rewards pool token account."
+    )]
+    #[account(
+        2,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        3,
+        signer,
+        name = "authority",
+        desc = "Pool authority, the mint's mint authority.",
+    )]
+    #[account(
+        4,
+        signer,
+        writable,
+        name = "payer",
+        desc = "Payer funding the rent for the larger account.",
+    )]
+    #[account(
+        5,
+        name = "system_program",
+        desc = "System program.",
+    )]
+    MigrateHolderRewardsPool,
+    /// Initializes a rate history account for a holder rewards pool, seeded
+    /// with the pool's current rate.
+    ///
+    /// Once initialized, every instruction that updates the pool's rate must
+    /// provide the rate history, and appends to it.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[w]` Rate history account.
+    /// 2. `[ ]` Token mint.
+    /// 3. `[w, s]` Payer.
+    /// 4. `[ ]` System program.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "rate_history",
+        desc = "Rate history account.",
+    )]
+    #[account(
+        2,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        3,
+        signer,
+        writable,
+        name = "payer",
+        desc = "Payer funding the rent for the rate history account.",
+    )]
+    #[account(
+        4,
+        name = "system_program",
+        desc = "System program.",
+    )]
+    InitializeRateHistory,
+    /// Sets a holder rewards pool's lockup tiers, creating the lockup tiers
+    /// account if it does not exist yet.
+    ///
+    /// Only affects lockups made after the tiers are set.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[ ]` Holder rewards pool account.
+    /// 1. `[w]` Lockup tiers account.
+    /// 2. `[ ]` Token mint.
+    /// 3. `[s]` Pool authority.
+    /// 4. `[w, s]` Payer.
+    /// 5. `[ ]` System program.
+    #[account(
+        0,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "lockup_tiers",
+        desc = "Lockup tiers account.",
+    )]
+    #[account(
+        2,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        3,
+        signer,
+        name = "authority",
+        desc = "Pool authority.",
+    )]
+    #[account(
+        4,
+        signer,
+        writable,
+        name = "payer",
+        desc = "Payer funding the rent for the lockup tiers account.",
+    )]
+    #[account(
+        5,
+        name = "system_program",
+        desc = "System program.",
+    )]
+    SetLockupTiers { tiers: [LockupTier; 4] },
+    /// Locks deposited tokens until `unlock_timestamp`, applying the
+    /// multiplier of the longest lockup tier the lockup qualifies for.
+    ///
+    /// Locking into an existing lockup extends it: all locked tokens share
+    /// the new unlock time and multiplier, which cannot be earlier than the
+    /// current unlock time. Pending rewards are harvested first.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[w]` Holder rewards account.
+    /// 2. `[ ]` Lockup tiers account.
+    /// 3. `[ ]` Token mint.
+    /// 4. `[w, s]` Owner of the holder rewards account.
+    /// 5. `[w]` Rate history account (optional).
+    /// 6. `[w]` Vote escrow account (required if the pool has one).
+    /// 7. `[w]` Epoch ledger account (optional).
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "holder_rewards",
+        desc = "Holder rewards account.",
+    )]
+    #[account(
+        2,
+        name = "lockup_tiers",
+        desc = "Lockup tiers account.",
+    )]
+    #[account(
+        3,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        4,
+        writable,
+        signer,
+        name = "owner",
+        desc = "Owner of the account.",
+    )]
+    #[account(
+        5,
+        optional,
+        writable,
+        name = "rate_history",
+        desc = "Rate history account, if the pool records one.",
+    )]
+    #[account(
+        6,
+        optional,
+        writable,
+        name = "vote_escrow",
+        desc = "Vote escrow account, required if the pool has one.",
+    )]
+    #[account(
+        7,
+        optional,
+        writable,
+        name = "epoch_ledger",
+        desc = "Epoch ledger account, if the pool records one.",
+    )]
+    Lock { amount: u64, unlock_timestamp: i64 },
+    /// Releases a holder's expired lockup, returning the locked tokens to
+    /// `1x` weight. Pending rewards are harvested to the owner first.
+    ///
+    /// Expired lockups are also released by the holder's next deposit,
+    /// withdrawal or harvest; this instruction lets anyone release them.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[w]` Holder rewards account.
+    /// 2. `[ ]` Token mint.
+    /// 3. `[w]` Owner of the holder rewards account.
+    /// 4. `[w]` Rate history account (optional).
+    /// 5. `[w]` Epoch ledger account (optional).
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "holder_rewards",
+        desc = "Holder rewards account.",
+    )]
+    #[account(
+        2,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        3,
+        writable,
+        name = "owner",
+        desc = "Owner of the holder rewards account.",
+    )]
+    #[account(
+        4,
+        optional,
+        writable,
+        name = "rate_history",
+        desc = "Rate history account, if the pool records one.",
+    )]
+    #[account(
+        5,
+        optional,
+        writable,
+        name = "epoch_ledger",
+        desc = "Epoch ledger account, if the pool records one.",
+    )]
+    ReleaseLockup,
+    /// Initializes a vote escrow account for a holder rewards pool, which
+    /// tracks the voting power of lockups made from then on.
+    ///
+    /// Voting power decays linearly to zero at the end of the lockup, with
+    /// tokens locked for the maximum duration carrying one vote each.
+    /// Lockups made before the vote escrow is initialized carry no voting
+    /// power until they are extended.
+    ///
+    /// If `reward_weighting` is set, lockup reward multipliers follow voting
+    /// power instead of the lockup tiers: `1x`, plus one voting power per
+    /// locked token.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[ ]` Holder rewards pool account.
+    /// 1. `[w]` Lockup tiers account.
+    /// 2. `[w]` Vote escrow account.
+    /// 3. `[ ]` Token mint.
+    /// 4. `[s]` Pool authority.
+    /// 5. `[w, s]` Payer.
+    /// 6. `[ ]` System program.
+    #[account(
+        0,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "lockup_tiers",
+        desc = "Lockup tiers account.",
+    )]
+    #[account(
+        2,
+        writable,
+        name = "vote_escrow",
+        desc = "Vote escrow account.",
+    )]
+    #[account(
+        3,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        4,
+        signer,
+        name = "authority",
+        desc = "Pool authority.",
+    )]
+    #[account(
+        5,
+        signer,
+        writable,
+        name = "payer",
+        desc = "Payer funding the rent for the vote escrow account.",
+    )]
+    #[account(
+        6,
+        name = "system_program",
+        desc = "System program.",
+    )]
+    InitializeVoteEscrow { reward_weighting: bool },
+    /// Brings a pool's vote escrow up to date and, if rewards follow voting
+    /// power, re-weights a holder's lockup to their current voting power.
+    /// Pending rewards are harvested to the owner first.
+    ///
+    /// Voting power decays continuously, but a lockup's reward multiplier is
+    /// only updated when it is checkpointed, so anyone may checkpoint any
+    /// holder.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[w]` Holder rewards account.
+    /// 2. `[w]` Vote escrow account.
+    /// 3. `[ ]` Token mint.
+    /// 4. `[w]` Owner of the holder rewards account.
+    /// 5. `[w]` Rate history account (optional).
+    /// 6. `[w]` Epoch ledger account (optional).
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "holder_rewards",
+        desc = "Holder rewards account.",
+    )]
+    #[account(
+        2,
+        writable,
+        name = "vote_escrow",
+        desc = "Vote escrow account.",
+    )]
+    #[account(
+        3,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        4,
+        writable,
+        name = "owner",
+        desc = "Owner of the holder rewards account.",
+    )]
+    #[account(
+        5,
+        optional,
+        writable,
+        name = "rate_history",
+        desc = "Rate history account, if the pool records one.",
+    )]
+    #[account(
+        6,
+        optional,
+        writable,
+        name = "epoch_ledger",
+        desc = "Epoch ledger account, if the pool records one.",
+    )]
+    CheckpointVotingPower,
+    /// Computes a holder's and the pool's current voting power.
+    ///
+    /// No accounts are modified. The result is written to the transaction's
+    /// return data as two little-endian `u64`s: the holder's voting power,
+    /// then the pool's total voting power.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[ ]` Holder rewards pool account.
+    /// 1. `[ ]` Vote escrow account.
+    /// 2. `[ ]` Holder rewards account.
+    /// 3. `[ ]` Token mint.
+    /// 4. `[ ]` Owner of the holder rewards account.
+    #[account(
+        0,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        name = "vote_escrow",
+        desc = "Vote escrow account.",
+    )]
+    #[account(
+        2,
+        name = "holder_rewards",
+        desc = "Holder rewards account.",
+    )]
+    #[account(
+        3,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        4,
+        name = "owner",
+        desc = "Owner of the holder rewards account.",
+    )]
+    GetVotingPower,
+    /// Starts recording a holder's deposited balance in a stake checkpoints
+    /// account, so it can later be read as of a past slot with
+    /// `GetStakeAt`.
+    ///
+    /// Once recorded, every deposit and withdrawal must provide the stake
+    /// checkpoints account. The holder's current balance is recorded
+    /// immediately; balances before then are not known.
+    ///
+    /// The account is kept when the holder rewards account is closed, so
+    /// past balances stay readable. A holder who reopens their holder
+    /// rewards account resumes recording by calling this instruction again.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards account.
+    /// 1. `[w]` Stake checkpoints account.
+    /// 2. `[s]` Owner of the holder rewards account.
+    /// 3. `[w, s]` Payer.
+    /// 4. `[ ]` System program.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards",
+        desc = "Holder rewards account.",
+    )]
+    #[account(
+        1,
+        writable,
+        name = "stake_checkpoints",
+        desc = "Stake checkpoints account.",
+    )]
+    #[account(
+        2,
+        signer,
+        name = "owner",
+        desc = "Owner of the holder rewards account.",
+    )]
+    #[account(
+        3,
+        signer,
+        writable,
+        name = "payer",
+        desc = "Payer funding the rent for the stake checkpoints account.",
+    )]
+    #[account(
+        4,
+        name = "system_program",
+        desc = "System program.",
+    )]
+    InitializeStakeCheckpoints,
+    /// Reads a holder's deposited balance at the end of a past slot from
+    /// their stake checkpoints.
+    ///
+    /// No accounts are modified. The balance is written to the transaction's
+    /// return data as a little-endian `u64`. Only past slots can be read, so
+    /// the result cannot change once returned.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[ ]` Stake checkpoints account.
+    /// 1. `[ ]` Owner of the stake checkpoints.
+    #[account(
+        0,
+        name = "stake_checkpoints",
+        desc = "Stake checkpoints account.",
+    )]
+    #[account(
+        1,
+        name = "owner",
+        desc = "Owner of the stake checkpoints.",
+    )]
+    GetStakeAt { slot: u64 },
+    /// Sets a holder rewards pool's early withdrawal penalty.
+    ///
+    /// A holder withdrawing within `period_slots` of their last deposit
+    /// forfeits `penalty_bps` of the rewards harvested by the withdrawal,
+    /// rounded up to the lamport.
+    /// The forfeited lamports stay in the pool and are distributed to the
+    /// other holders. Rewards harvested within the period by any other
+    /// instruction are withheld until it elapses, so they cannot be drawn
+    /// ahead of a withdrawal. A `period_slots` of `0` disables the penalty.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[ ]` Token mint.
+    /// 2. `[s]` Pool authority.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        2,
+        signer,
+        name = "authority",
+        desc = "Pool authority.",
+    )]
+    SetEarlyWithdrawalPenalty { penalty_bps: u16, period_slots: u32 },
+    /// Sets a holder rewards pool's deposit limits.
+    ///
+    /// Deposits must add at least `min_deposit` tokens, and cannot take a
+    /// holder's deposit above `max_holder_deposit` or the pool's total
+    /// deposit above `max_total_deposit`. A maximum of `0` is unlimited.
+    /// Only deposits made after the limits are set are checked.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[ ]` Token mint.
+    /// 2. `[s]` Pool authority.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        2,
+        signer,
+        name = "authority",
+        desc = "Pool authority.",
+    )]
+    SetDepositLimits { max_total_deposit: u64, max_holder_deposit: u64, min_deposit: u64 },
+    /// Sets which wallets may open holder rewards accounts in a holder
+    /// rewards pool and deposit into it.
+    ///
+    /// `access_list` is one of the `ACCESS_LIST_*` modes: no access list,
+    /// an allowlist admitting only allowed wallets, or a denylist admitting
+    /// all but denied wallets. Wallets are listed with `SetAccessEntry`.
+    /// Withdrawing and harvesting are never gated.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[ ]` Token mint.
+    /// 2. `[s]` Pool authority.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        2,
+        signer,
+        name = "authority",
+        desc = "Pool authority.",
+    )]
+    SetAccessList { access_list: u8 },
+    /// Sets a wallet's status in a holder rewards pool's access list,
+    /// creating its access entry account if it does not exist yet.
+    ///
+    /// `status` is one of the `ACCESS_STATUS_*` values. Setting a wallet
+    /// back to unlisted keeps the account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[ ]` Holder rewards pool account.
+    /// 1. `[w]` Access entry account.
+    /// 2. `[ ]` Wallet.
+    /// 3. `[ ]` Token mint.
+    /// 4. `[s]` Pool authority.
+    /// 5. `[w, s]` Payer.
+    /// 6. `[ ]` System program.
+    #[account(
+        0,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "access_entry",
+        desc = "Access entry account.",
+    )]
+    #[account(
+        2,
+        name = "wallet",
+        desc = "Wallet the access entry is for.",
+    )]
+    #[account(
+        3,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        4,
+        signer,
+        name = "authority",
+        desc = "Pool authority.",
+    )]
+    #[account(
+        5,
+        signer,
+        writable,
+        name = "payer",
+        desc = "Payer funding the rent for the access entry account.",
+    )]
+    #[account(
+        6,
+        name = "system_program",
+        desc = "System program.",
+    )]
+    SetAccessEntry { status: u8 },
+    /// Initializes a holder rewards pool's receipt token mint, after which
+    /// deposits mint receipt tokens 1:1 and withdrawals burn them.
+    ///
+    /// The receipt mint is a Token-2022 mint whose transfer hook is this
+    /// program, so transferring receipt tokens moves the deposit they
+    /// represent to the recipient, settling both holders' rewards first.
+    /// Recipients must have a holder rewards account, or the transfer fails
+    /// with `ReceiptRecipientNotInitialized`.
+    ///
+    /// Receipts can only be enabled before anything is deposited into the
+    /// pool, so every deposit is backed by receipt tokens.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[w]` Receipt token mint.
+    /// 2. `[w]` Transfer hook extra account metas account.
+    /// 3. `[ ]` Token mint.
+    /// 4. `[s]` Pool authority.
+    /// 5. `[w, s]` Payer.
+    /// 6. `[ ]` System program.
+    /// 7. `[ ]` Token-2022 program.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "receipt_mint",
+        desc = "Receipt token mint.",
+    )]
+    #[account(
+        2,
+        writable,
+        name = "extra_account_metas",
+        desc = "Transfer hook extra account metas account.",
+    )]
+    #[account(
+        3,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        4,
+        signer,
+        name = "authority",
+        desc = "Pool authority.",
+    )]
+    #[account(
+        5,
+        signer,
+        writable,
+        name = "payer",
+        desc = "Payer funding the rent for the receipt mint and extra account metas accounts.",
+    )]
+    #[account(
+        6,
+        name = "system_program",
+        desc = "System program.",
+    )]
+    #[account(
+        7,
+        name = "receipt_token_program",
+        desc = "Token-2022 program.",
+    )]
+    InitializeReceiptMint,
+    /// Withdraws deposited tokens without paying out rewards, for when the
+    /// pool doesn't hold enough lamports to cover the holder's rewards.
+    ///
+    /// The holder's unpaid rewards are recorded on their holder rewards
+    /// account, net of any early withdrawal penalty, and paid out by a later
+    /// harvest once the pool holds enough lamports.
+    ///
+    /// Passing `u64::MAX` as the amount withdraws the entire deposit.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[w]` Holder rewards pool token account.
+    /// 2. `[w]` Holder rewards account.
+    /// 3. `[w]` Token account.
+    /// 4. `[ ]` Token mint.
+    /// 5. `[w, s]` Owner.
+    /// 6. `[ ]` Token program.
+    /// 7. `[w]` (Optional) Rate history account.
+    /// 8. `[w]` (Optional) Stake checkpoints account.
+    /// 9. `[w]` (Optional) Receipt token mint.
+    /// 10. `[w]` (Optional) Receipt token account.
+    /// 11. `[ ]` (Optional) Token-2022 program.
+    /// 12. `[w]` (Optional) Epoch ledger account.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "holder_rewards_pool_token_account",
+        desc = "Holder rewards pool token account."
+    )]
+    #[account(
+        2,
+        writable,
+        name = "holder_rewards",
+        desc = "Holder rewards account.",
+    )]
+    #[account(
+        3,
+        writable,
+        name = "token_account",
+        desc = "Token account.",
+    )]
+    #[account(
+        4,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        5,
+        writable,
+        signer,
+        name = "owner",
+        desc = "Owner of the account.",
+    )]
+    #[account(
+        6,
+        name = "token program",
+        desc = "token program",
+    )]
+    #[account(
+        7,
+        optional,
+        writable,
+        name = "rate_history",
+        desc = "Rate history account, if the pool records one.",
+    )]
+    #[account(
+        8,
+        optional,
+        writable,
+        name = "stake_checkpoints",
+        desc = "Stake checkpoints account, required if the holder records one.",
+    )]
+    #[account(
+        9,
+        optional,
+        writable,
+        name = "receipt_mint",
+        desc = "Receipt token mint, required if the pool mints receipt tokens.",
+    )]
+    #[account(
+        10,
+        optional,
+        writable,
+        name = "receipt_token_account",
+        desc = "Owner's receipt token account, required if the pool mints receipt tokens.",
+    )]
+    #[account(
+        11,
+        optional,
+        name = "receipt_token_program",
+        desc = "Token-2022 program, required if the pool mints receipt tokens.",
+    )]
+    #[account(
+        12,
+        optional,
+        writable,
+        name = "epoch_ledger",
+        desc = "Epoch ledger account, if the pool records one.",
+    )]
+    EmergencyWithdraw { amount: u64 },
+    /// Sets the authority permitted to slash holders' deposits in a holder
+    /// rewards pool, and the token account receiving slashed tokens.
+    ///
+    /// The slashing authority is expected to be a PDA of the stake program,
+    /// signing with `invoke_signed`. Passing the default pubkey as the
+    /// slashing authority disables slashing.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[ ]` Token mint.
+    /// 2. `[s]` Pool authority.
+    /// 3. `[ ]` Slashing authority.
+    /// 4. `[ ]` Slash destination token account.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        2,
+        signer,
+        name = "authority",
+        desc = "Pool authority.",
+    )]
+    #[account(
+        3,
+        name = "slashing_authority",
+        desc = "Slashing authority.",
+    )]
+    #[account(
+        4,
+        name = "slash_destination",
+        desc = "Slash destination token account.",
+    )]
+    SetSlashingConfig,
+    /// Slashes a holder's deposit, moving the slashed tokens from the pool
+    /// token account to the pool's slash destination.
+    ///
+    /// The holder's rewards are settled first, so rewards earned before the
+    /// slash are still owed to them. Locked tokens are slashed last, with the
+    /// lockup, and any voting power it counts toward, shrinking to fit the
+    /// remaining deposit.
+    ///
+    /// Deposits in a pool with a receipt mint cannot be slashed, since the
+    /// receipt tokens backing them would be left unredeemable.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[w]` Holder rewards pool token account.
+    /// 2. `[w]` Holder rewards account.
+    /// 3. `[w]` Slash destination token account.
+    /// 4. `[ ]` Token mint.
+    /// 5. `[ ]` Owner of the holder rewards account.
+    /// 6. `[s]` Slashing authority.
+    /// 7. `[ ]` Token program.
+    /// 8. `[w]` (Optional) Rate history account.
+    /// 9. `[w]` (Optional) Stake checkpoints account.
+    /// 10. `[w]` (Optional) Vote escrow account.
+    /// 11. `[w]` (Optional) Epoch ledger account.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "holder_rewards_pool_token_account",
+        desc = "Holder rewards pool token account."
+    )]
+    #[account(
+        2,
+        writable,
+        name = "holder_rewards",
+        desc = "Holder rewards account.",
+    )]
+    #[account(
+        3,
+        writable,
+        name = "slash_destination",
+        desc = "Slash destination token account.",
+    )]
+    #[account(
+        4,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        5,
+        name = "owner",
+        desc = "Owner of the holder rewards account.",
+    )]
+    #[account(
+        6,
+        signer,
+        name = "slashing_authority",
+        desc = "Slashing authority.",
+    )]
+    #[account(
+        7,
+        name = "token_program",
+        desc = "Token program.",
+    )]
+    #[account(
+        8,
+        optional,
+        writable,
+        name = "rate_history",
+        desc = "Rate history account, if the pool records one.",
+    )]
+    #[account(
+        9,
+        optional,
+        writable,
+        name = "stake_checkpoints",
+        desc = "Stake checkpoints account, required if the holder records one.",
+    )]
+    #[account(
+        10,
+        optional,
+        writable,
+        name = "vote_escrow",
+        desc = "Vote escrow account, required if the slash reaches a lockup counted toward voting power.",
+    )]
+    #[account(
+        11,
+        optional,
+        writable,
+        name = "epoch_ledger",
+        desc = "Epoch ledger account, if the pool records one.",
+    )]
+    Slash { amount: u64 },
+    /// Creates a funder's reward campaign for a holder rewards pool,
+    /// escrowing `amount` lamports that unlock linearly into the pool
+    /// between `start_slot` and `end_slot`.
+    ///
+    /// The campaign account is derived from the pool and the funder, so a
+    /// funder runs one campaign per pool at a time.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[ ]` Holder rewards pool account.
+    /// 1. `[w]` Campaign account.
+    /// 2. `[ ]` Token mint.
+    /// 3. `[w, s]` Funder.
+    /// 4. `[ ]` System program.
+    #[account(
+        0,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "campaign",
+        desc = "Campaign account.",
+    )]
+    #[account(
+        2,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        3,
+        signer,
+        writable,
+        name = "funder",
+        desc = "Funder escrowing the rewards and the rent for the campaign account.",
+    )]
+    #[account(
+        4,
+        name = "system_program",
+        desc = "System program.",
+    )]
+    CreateCampaign { amount: u64, start_slot: u64, end_slot: u64 },
+    /// Releases a campaign's rewards unlocked since it was last released
+    /// into its holder rewards pool, which accrues them like any other
+    /// rewards. Anyone may release a campaign's rewards.
+    ///
+    /// Campaign rewards only reach the pool through this instruction and
+    /// `ReclaimCampaignRewards`, and accrue at the pool's weight when they
+    /// run, so campaigns must be released regularly by a crank. The share
+    /// of the rewards matching the slots since the last release that the
+    /// pool spent with nothing deposited is set aside for the funder
+    /// instead, as is everything if nothing is deposited now.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[w]` Campaign account.
+    /// 2. `[ ]` Token mint.
+    /// 3. `[w]` (Optional) Rate history account.
+    /// 4. `[w]` (Optional) Epoch ledger account.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "campaign",
+        desc = "Campaign account.",
+    )]
+    #[account(
+        2,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        3,
+        optional,
+        writable,
+        name = "rate_history",
+        desc = "Rate history account, if the pool records one.",
+    )]
+    #[account(
+        4,
+        optional,
+        writable,
+        name = "epoch_ledger",
+        desc = "Epoch ledger account, if the pool records one.",
+    )]
+    ReleaseCampaignRewards,
+    /// Closes a campaign after its end slot, releasing its remaining
+    /// rewards and returning the rewards set aside, along with the
+    /// account's rent, to the funder.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[w]` Campaign account.
+    /// 2. `[ ]` Token mint.
+    /// 3. `[w, s]` Funder.
+    /// 4. `[w]` (Optional) Rate history account.
+    /// 5. `[w]` (Optional) Epoch ledger account.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "campaign",
+        desc = "Campaign account.",
+    )]
+    #[account(
+        2,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        3,
+        signer,
+        writable,
+        name = "funder",
+        desc = "Funder of the campaign.",
+    )]
+    #[account(
+        4,
+        optional,
+        writable,
+        name = "rate_history",
+        desc = "Rate history account, if the pool records one.",
+    )]
+    #[account(
+        5,
+        optional,
+        writable,
+        name = "epoch_ledger",
+        desc = "Epoch ledger account, if the pool records one.",
+    )]
+    ReclaimCampaignRewards,
+    /// Distributes rewards to a holder rewards pool's holders, transferring
+    /// `amount` lamports from the funder into the pool and accruing them
+    /// immediately.
+    ///
+    /// Unlike lamports transferred to the pool directly, which are accrued
+    /// by the next instruction to update the pool, the distribution is
+    /// attributed to the funder in the emitted event, tagged with `memo`,
+    /// for example an epoch number. An all-zero memo means none.
+    ///
+    /// Fails if the pool has no weight, since no holder could claim the
+    /// distribution.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[ ]` Token mint.
+    /// 2. `[w, s]` Funder.
+    /// 3. `[ ]` System program.
+    /// 4. `[w]` (Optional) Rate history account.
+    /// 5. `[w]` (Optional) Epoch ledger account.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        2,
+        signer,
+        writable,
+        name = "funder",
+        desc = "Funder of the rewards.",
+    )]
+    #[account(
+        3,
+        name = "system_program",
+        desc = "System program.",
+    )]
+    #[account(
+        4,
+        optional,
+        writable,
+        name = "rate_history",
+        desc = "Rate history account, if the pool records one.",
+    )]
+    #[account(
+        5,
+        optional,
+        writable,
+        name = "epoch_ledger",
+        desc = "Epoch ledger account, if the pool records one.",
+    )]
+    DistributeRewards { amount: u64, memo: [u8; 32] },
+    /// Initializes an epoch ledger account for a holder rewards pool, seeded
+    /// with the pool's current totals.
+    ///
+    /// Once initialized, every instruction that updates the pool's rate must
+    /// provide the epoch ledger, and records the rewards accrued in each
+    /// epoch to it.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[w]` Epoch ledger account.
+    /// 2. `[ ]` Token mint.
+    /// 3. `[w, s]` Payer.
+    /// 4. `[ ]` System program.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "epoch_ledger",
+        desc = "Epoch ledger account.",
+    )]
+    #[account(
+        2,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        3,
+        signer,
+        writable,
+        name = "payer",
+        desc = "Payer funding the rent for the epoch ledger account.",
+    )]
+    #[account(
+        4,
+        name = "system_program",
+        desc = "System program.",
+    )]
+    InitializeEpochLedger,
+    /// Migrates a holder rewards account created by the first release of the
+    /// program to the current layout.
+    ///
+    /// The owner is recorded as the rent payer, refunded when the account
+    /// is closed. Statistics are tracked from the migration onwards.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards account.
+    /// 1. `[ ]` Owner of the holder rewards account.
+    /// 2. `[w, s]` Payer.
+    /// 3. `[ ]` System program.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards",
+        desc = "Holder rewards account.",
+    )]
+    #[account(
+        1,
+        name = "owner",
+        desc = "Owner of the holder rewards account.",
+    )]
+    #[account(
+        2,
+        signer,
+        writable,
+        name = "payer",
+        desc = "Payer funding the rent for the larger account.",
+    )]
+    #[account(
+        3,
+        name = "system_program",
+        desc = "System program.",
+    )]
+    MigrateHolderRewards,
+}
+
+impl PaladinRewardsInstruction {
+    /// Packs a
+    /// [PaladinRewardsInstruction](enum.PaladinRewardsInstruction.html)
+    /// into a byte buffer.
+    pub fn pack(&self) -> Vec<u8> {
+        match self {
+            PaladinRewardsInstruction::InitializeHolderRewardsPool { duna_document_hash } => {
+                let mut data = Vec::with_capacity(33);
+                data.push(0);
+                data.extend_from_slice(duna_document_hash);
+                data
+            }
+            PaladinRewardsInstruction::InitializeHolderRewards => vec![1],
+            PaladinRewardsInstruction::HarvestRewards => vec![2],
+            PaladinRewardsInstruction::CloseHolderRewards => vec![3],
+            PaladinRewardsInstruction::Deposit { amount } => {
+                let mut data = Vec::with_capacity(9);
+                data.push(4);
+                data.extend_from_slice(&amount.to_le_bytes());
+                data
+            }
+            PaladinRewardsInstruction::Withdraw { amount } => {
+                let mut data = Vec::with_capacity(9);
+                data.push(5);
+                data.extend_from_slice(&amount.to_le_bytes());
+                data
+            }
+            PaladinRewardsInstruction::RecoverExcessTokens => vec![6],
+            PaladinRewardsInstruction::GetPendingRewards => vec![7],
+            PaladinRewardsInstruction::MigrateHolderRewardsPool => vec![8],
+            PaladinRewardsInstruction::InitializeRateHistory => vec![9],
+            PaladinRewardsInstruction::SetLockupTiers { tiers } => {
+                let mut data = Vec::with_capacity(1 + LOCKUP_TIERS_CAPACITY * 16);
+                data.push(10);
+                for tier in tiers {
+                    data.extend_from_slice(&tier.duration.to_le_bytes());
+                    data.extend_from_slice(&tier.multiplier_bps.to_le_bytes());
+                }
+                data
+            }
+            PaladinRewardsInstruction::Lock {
+                amount,
+                unlock_timestamp,
+            } => {
+                let mut data = Vec::with_capacity(17);
+                data.push(11);
+                data.extend_from_slice(&amount.to_le_bytes());
+                data.extend_from_slice(&unlock_timestamp.to_le_bytes());
+                data
+            }
+            PaladinRewardsInstruction::ReleaseLockup => vec![12],
+            PaladinRewardsInstruction::InitializeVoteEscrow { reward_weighting } => {
+                vec![13, *reward_weighting as u8]
+            }
+            PaladinRewardsInstruction::CheckpointVotingPower => vec![14],
+            PaladinRewardsInstruction::GetVotingPower => vec![15],
+            PaladinRewardsInstruction::InitializeStakeCheckpoints => vec![16],
+            PaladinRewardsInstruction::GetStakeAt { slot } => {
+                let mut data = Vec::with_capacity(9);
+                data.push(17);
+                data.extend_from_slice(&slot.to_le_bytes());
+                data
+            }
+            PaladinRewardsInstruction::SetEarlyWithdrawalPenalty {
+                penalty_bps,
+                period_slots,
+            } => {
+                let mut data = Vec::with_capacity(7);
+                data.push(18);
+                data.extend_from_slice(&penalty_bps.to_le_bytes());
+                data.extend_from_slice(&period_slots.to_le_bytes());
+                data
+            }
+            PaladinRewardsInstruction::SetDepositLimits {
+                max_total_deposit,
+                max_holder_deposit,
+                min_deposit,
+            } => {
+                let mut data = Vec::with_capacity(25);
+                data.push(19);
+                data.extend_from_slice(&max_total_deposit.to_le_bytes());
+                data.extend_from_slice(&max_holder_deposit.to_le_bytes());
+                data.extend_from_slice(&min_deposit.to_le_bytes());
+                data
+            }
+            PaladinRewardsInstruction::SetAccessList { access_list } => vec![20, *access_list],
+            PaladinRewardsInstruction::SetAccessEntry { status } => vec![21, *status],
+            PaladinRewardsInstruction::InitializeReceiptMint => vec![22],
+            PaladinRewardsInstruction::EmergencyWithdraw { amount } => {
+                let mut data = Vec::with_capacity(9);
+                data.push(23);
+                data.extend_from_slice(&amount.to_le_bytes());
+                data
+            }
+            PaladinRewardsInstruction::SetSlashingConfig => vec![24],
+            PaladinRewardsInstruction::Slash { amount } => {
+                let mut data = Vec::with_capacity(9);
+                data.push(25);
+                data.extend_from_slice(&amount.to_le_bytes());
+                data
+            }
+            PaladinRewardsInstruction::CreateCampaign {
+                amount,
+                start_slot,
+                end_slot,
+            } => {
+                let mut data = Vec::with_capacity(25);
+                data.push(26);
+                data.extend_from_slice(&amount.to_le_bytes());
+                data.extend_from_slice(&start_slot.to_le_bytes());
+                data.extend_from_slice(&end_slot.to_le_bytes());
+                data
+            }
+            PaladinRewardsInstruction::ReleaseCampaignRewards => vec![27],
+            PaladinRewardsInstruction::ReclaimCampaignRewards => vec![28],
+            PaladinRewardsInstruction::DistributeRewards { amount, memo } => {
+                let mut data = Vec::with_capacity(41);
+                data.push(29);
+                data.extend_from_slice(&amount.to_le_bytes());
+                data.extend_from_slice(memo);
+                data
+            }
+            PaladinRewardsInstruction::InitializeEpochLedger => vec![30],
+            PaladinRewardsInstruction::MigrateHolderRewards => vec![31],
+        }
+    }
+
+    /// Unpacks a byte buffer into a
+    /// [PaladinRewardsInstruction](enum.PaladinRewardsInstruction.html).
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        match input.split_first() {
+            Some((&0, rest)) => {
+                let duna_document_hash = *array_ref![rest, 0, 32];
+
+                Ok(PaladinRewardsInstruction::InitializeHolderRewardsPool { duna_document_hash })
+            }
+            Some((&1, _)) => Ok(PaladinRewardsInstruction::InitializeHolderRewards),
+            Some((&2, _)) => Ok(PaladinRewardsInstruction::HarvestRewards),
+            Some((&3, _)) => Ok(PaladinRewardsInstruction::CloseHolderRewards),
+            Some((&4, rest)) if rest.len() == 8 => {
+                let amount = u64::from_le_bytes(*array_ref![rest, 0, 8]);
+
+                Ok(PaladinRewardsInstruction::Deposit { amount })
+            }
+            Some((&5, rest)) if rest.len() == 8 => {
+                let amount = u64::from_le_bytes(*array_ref![rest, 0, 8]);
+
+                Ok(PaladinRewardsInstruction::Withdraw { amount })
+            }
+            Some((&6, _)) => Ok(PaladinRewardsInstruction::RecoverExcessTokens),
+            Some((&7, _)) => Ok(PaladinRewardsInstruction::GetPendingRewards),
+            Some((&8, _)) => Ok(PaladinRewardsInstruction::MigrateHolderRewardsPool),
+            Some((&9, _)) => Ok(PaladinRewardsInstruction::InitializeRateHistory),
+            Some((&10, rest)) if rest.len() == LOCKUP_TIERS_CAPACITY * 16 => {
+                let mut tiers = [LockupTier::default(); LOCKUP_TIERS_CAPACITY];
+                for (tier, chunk) in tiers.iter_mut().zip(rest.chunks_exact(16)) {
+                    tier.duration = u64::from_le_bytes(*array_ref![chunk, 0, 8]);
+                    tier.multiplier_bps = u64::from_le_bytes(*array_ref![chunk, 8, 8]);
+                }
+
+                Ok(PaladinRewardsInstruction::SetLockupTiers { tiers })
+            }
+            Some((&11, rest)) if rest.len() == 16 => {
+                let amount = u64::from_le_bytes(*array_ref![rest, 0, 8]);
+                let unlock_timestamp = i64::from_le_bytes(*array_ref![rest, 8, 8]);
+
+                Ok(PaladinRewardsInstruction::Lock {
+                    amount,
+                    unlock_timestamp,
+                })
+            }
+            Some((&12, _)) => Ok(PaladinRewardsInstruction::ReleaseLockup),
+            Some((&13, [reward_weighting])) if *reward_weighting <= 1 => {
+                Ok(PaladinRewardsInstruction::InitializeVoteEscrow {
+                    reward_weighting: *reward_weighting == 1,
+                })
+            }
+            Some((&14, _)) => Ok(PaladinRewardsInstruction::CheckpointVotingPower),
+            Some((&15, _)) => Ok(PaladinRewardsInstruction::GetVotingPower),
+            Some((&16, _)) => Ok(PaladinRewardsInstruction::InitializeStakeCheckpoints),
+            Some((&17, rest)) if rest.len() == 8 => {
+                let slot = u64::from_le_bytes(*array_ref![rest, 0, 8]);
+
+                Ok(PaladinRewardsInstruction::GetStakeAt { slot })
+            }
+            Some((&18, rest)) if rest.len() == 6 => {
+                let penalty_bps = u16::from_le_bytes(*array_ref![rest, 0, 2]);
+                let period_slots = u32::from_le_bytes(*array_ref![rest, 2, 4]);
+
+                Ok(PaladinRewardsInstruction::SetEarlyWithdrawalPenalty {
+                    penalty_bps,
+                    period_slots,
+                })
+            }
+            Some((&19, rest)) if rest.len() == 24 => {
+                let max_total_deposit = u64::from_le_bytes(*array_ref![rest, 0, 8]);
+                let max_holder_deposit = u64::from_le_bytes(*array_ref![rest, 8, 8]);
+                let min_deposit = u64::from_le_bytes(*array_ref![rest, 16, 8]);
+
+                Ok(PaladinRewardsInstruction::SetDepositLimits {
+                    max_total_deposit,
+                    max_holder_deposit,
+                    min_deposit,
+                })
+            }
+            Some((&20, [access_list])) => Ok(PaladinRewardsInstruction::SetAccessList {
+                access_list: *access_list,
+            }),
+            Some((&21, [status])) => {
+                Ok(PaladinRewardsInstruction::SetAccessEntry { status: *status })
+            }
+            Some((&22, _)) => Ok(PaladinRewardsInstruction::InitializeReceiptMint),
+            Some((&23, rest)) if rest.len() == 8 => {
+                let amount = u64::from_le_bytes(*array_ref![rest, 0, 8]);
+
+                Ok(PaladinRewardsInstruction::EmergencyWithdraw { amount })
+            }
+            Some((&24, _)) => Ok(PaladinRewardsInstruction::SetSlashingConfig),
+            Some((&25, rest)) if rest.len() == 8 => {
+                let amount = u64::from_le_bytes(*array_ref![rest, 0, 8]);
+
+                Ok(PaladinRewardsInstruction::Slash { amount })
+            }
+            Some((&26, rest)) if rest.len() == 24 => {
+                let amount = u64::from_le_bytes(*array_ref![rest, 0, 8]);
+                let start_slot = u64::from_le_bytes(*array_ref![rest, 8, 8]);
+                let end_slot = u64::from_le_bytes(*array_ref![rest, 16, 8]);
+
+                Ok(PaladinRewardsInstruction::CreateCampaign {
+                    amount,
+                    start_slot,
+                    end_slot,
+                })
+            }
+            Some((&27, _)) => Ok(PaladinRewardsInstruction::ReleaseCampaignRewards),
+            Some((&28, _)) => Ok(PaladinRewardsInstruction::ReclaimCampaignRewards),
+            Some((&29, rest)) if rest.len() == 40 => {
+                let amount = u64::from_le_bytes(*array_ref![rest, 0, 8]);
+                let memo = *array_ref![rest, 8, 32];
+
+                Ok(PaladinRewardsInstruction::DistributeRewards { amount, memo })
+            }
+            Some((&30, _)) => Ok(PaladinRewardsInstruction::InitializeEpochLedger),
+            Some((&31, _)) => Ok(PaladinRewardsInstruction::MigrateHolderRewards),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pack_unpack_initialize_holder_rewards_pool() {
+        let original = PaladinRewardsInstruction::InitializeHolderRewardsPool {
+            duna_document_hash: [0; 32],
+        };
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_initialize_holder_rewards() {
+        let original = PaladinRewardsInstruction::InitializeHolderRewards;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_harvest_rewards() {
+        let original = PaladinRewardsInstruction::HarvestRewards;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_close_holder_rewards() {
+        let original = PaladinRewardsInstruction::CloseHolderRewards;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_deposit() {
+        let original = PaladinRewardsInstruction::Deposit { amount: 1000 };
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_withdraw() {
+        let original = PaladinRewardsInstruction::Withdraw { amount: 1000 };
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_recover_excess_tokens() {
+        let original = PaladinRewardsInstruction::RecoverExcessTokens;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_get_pending_rewards() {
+        let original = PaladinRewardsInstruction::GetPendingRewards;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_migrate_holder_rewards_pool() {
+        let original = PaladinRewardsInstruction::MigrateHolderRewardsPool;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_initialize_rate_history() {
+        let original = PaladinRewardsInstruction::InitializeRateHistory;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_set_lockup_tiers() {
+        let original = PaladinRewardsInstruction::SetLockupTiers {
+            tiers: [
+                LockupTier {
+                    duration: 1,
+                    multiplier_bps: 10_000,
+                },
+                LockupTier {
+                    duration: 2,
+                    multiplier_bps: 20_000,
+                },
+                LockupTier::default(),
+                LockupTier::default(),
+            ],
+        };
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_lock() {
+        let original = PaladinRewardsInstruction::Lock {
+            amount: 1000,
+            unlock_timestamp: 1_700_000_000,
+        };
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_release_lockup() {
+        let original = PaladinRewardsInstruction::ReleaseLockup;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_initialize_vote_escrow() {
+        for reward_weighting in [false, true] {
+            let original = PaladinRewardsInstruction::InitializeVoteEscrow { reward_weighting };
+            let packed = original.pack();
+            let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+            assert_eq!(original, unpacked);
+        }
+
+        assert_eq!(
+            PaladinRewardsInstruction::unpack(&[13, 2]),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+
+    #[test]
+    fn test_pack_unpack_checkpoint_voting_power() {
+        let original = PaladinRewardsInstruction::CheckpointVotingPower;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_get_voting_power() {
+        let original = PaladinRewardsInstruction::GetVotingPower;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_initialize_stake_checkpoints() {
+        let original = PaladinRewardsInstruction::InitializeStakeCheckpoints;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_get_stake_at() {
+        let original = PaladinRewardsInstruction::GetStakeAt { slot: 1000 };
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_set_early_withdrawal_penalty() {
+        let original = PaladinRewardsInstruction::SetEarlyWithdrawalPenalty {
+            penalty_bps: 5_000,
+            period_slots: 1000,
+        };
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_set_deposit_limits() {
+        let original = PaladinRewardsInstruction::SetDepositLimits {
+            max_total_deposit: 1000,
+            max_holder_deposit: 100,
+            min_deposit: 10,
+        };
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_set_access_list() {
+        let original = PaladinRewardsInstruction::SetAccessList { access_list: 1 };
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_set_access_entry() {
+        let original = PaladinRewardsInstruction::SetAccessEntry { status: 2 };
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_initialize_receipt_mint() {
+        let original = PaladinRewardsInstruction::InitializeReceiptMint;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_emergency_withdraw() {
+        let original = PaladinRewardsInstruction::EmergencyWithdraw { amount: 1000 };
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_set_slashing_config() {
+        let original = PaladinRewardsInstruction::SetSlashingConfig;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_slash() {
+        let original = PaladinRewardsInstruction::Slash { amount: 1000 };
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_create_campaign() {
+        let original = PaladinRewardsInstruction::CreateCampaign {
+            amount: 1000,
+            start_slot: 10,
+            end_slot: 20,
+        };
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_release_campaign_rewards() {
+        let original = PaladinRewardsInstruction::ReleaseCampaignRewards;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_reclaim_campaign_rewards() {
+        let original = PaladinRewardsInstruction::ReclaimCampaignRewards;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_distribute_rewards() {
+        let original = PaladinRewardsInstruction::DistributeRewards {
+            amount: 1000,
+            memo: [7; 32],
+        };
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_initialize_epoch_ledger() {
+        let original = PaladinRewardsInstruction::InitializeEpochLedger;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_migrate_holder_rewards() {
+        let original = PaladinRewardsInstruction::MigrateHolderRewards;
         let packed = original.pack();
         let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
         assert_eq!(original, unpacked);
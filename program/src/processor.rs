@@ -3,39 +3,78 @@
 use {
     crate::{
         error::PaladinRewardsError,
+        event::PaladinRewardsEvent,
         instruction::PaladinRewardsInstruction,
         state::{
-            collect_holder_rewards_pool_signer_seeds, collect_holder_rewards_signer_seeds,
-            find_duna_document_pda, get_holder_rewards_address,
-            get_holder_rewards_address_and_bump_seed, get_holder_rewards_pool_address,
-            get_holder_rewards_pool_address_and_bump_seed, HolderRewards, HolderRewardsPool,
+            access_permitted, collect_access_entry_signer_seeds, collect_campaign_signer_seeds,
+            collect_epoch_ledger_signer_seeds, collect_holder_rewards_pool_signer_seeds,
+            collect_holder_rewards_signer_seeds, collect_lockup_tiers_signer_seeds,
+            collect_rate_history_signer_seeds, collect_receipt_mint_signer_seeds,
+            collect_stake_checkpoints_signer_seeds, collect_vote_escrow_signer_seeds,
+            create_access_entry_address, create_campaign_address, create_epoch_ledger_address,
+            create_holder_rewards_address, create_holder_rewards_pool_address,
+            create_lockup_tiers_address, create_rate_history_address, create_receipt_mint_address,
+            create_stake_checkpoints_address, create_vote_escrow_address, find_duna_document_pda,
+            get_access_entry_address, get_access_entry_address_and_bump_seed,
+            get_campaign_address_and_bump_seed, get_epoch_ledger_address_and_bump_seed,
+            get_holder_rewards_address_and_bump_seed,
+            get_holder_rewards_pool_address_and_bump_seed, get_lockup_tiers_address_and_bump_seed,
+            get_rate_history_address_and_bump_seed, get_receipt_mint_address_and_bump_seed,
+            get_stake_checkpoints_address_and_bump_seed, get_vote_escrow_address_and_bump_seed,
+            voting_power_end, AccessEntry, Campaign, EpochLedger, HolderRewards, HolderRewardsPool,
+            LockupTier, LockupTiers, RateHistory, RateHistoryEntry, StakeCheckpoints, VoteEscrow,
+            ACCESS_LIST_DENYLIST, ACCESS_LIST_NONE, ACCESS_STATUS_DENIED, ACCESS_STATUS_UNLISTED,
+            BASIS_POINTS, LOCKUP_TIERS_CAPACITY, MAX_LOCKUP_DURATION, SEED_PREFIX_ACCESS_ENTRY,
+            SEED_PREFIX_EPOCH_LEDGER, SEED_PREFIX_HOLDER_REWARDS, SEED_PREFIX_RATE_HISTORY,
+            SEED_PREFIX_STAKE_CHECKPOINTS,
         },
     },
+    paladin_rewards_math::{calculate_eligible_rewards, calculate_rewards_per_token},
     solana_program::{
         account_info::{next_account_info, AccountInfo},
+        clock::Clock,
         entrypoint::ProgramResult,
         msg,
-        program::{invoke, invoke_signed},
+        program::{invoke, invoke_signed, set_return_data},
         program_error::ProgramError,
+        program_option::COption,
         program_pack::Pack,
         pubkey::Pubkey,
         rent::Rent,
         system_instruction, system_program,
         sysvar::Sysvar,
     },
+    spl_associated_token_account::get_associated_token_address,
+    spl_tlv_account_resolution::{
+        account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList,
+    },
     spl_token::{
         instruction::transfer,
         state::{Account as TokenAccount, AccountState, Mint},
     },
+    spl_token_2022::{
+        extension::{
+            transfer_hook::{self, TransferHookAccount},
+            BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+        },
+        instruction::{burn, initialize_mint2, mint_to},
+        state::{Account as ReceiptTokenAccount, Mint as ReceiptMint},
+    },
+    spl_transfer_hook_interface::{
+        collect_extra_account_metas_signer_seeds, get_extra_account_metas_address_and_bump_seed,
+        instruction::{ExecuteInstruction, TransferHookInstruction},
+    },
 };
 
-pub const REWARDS_PER_TOKEN_SCALING_FACTOR: u128 = 1_000_000_000_000_000_000; // 1e18
+pub use paladin_rewards_math::REWARDS_PER_TOKEN_SCALING_FACTOR;
 
 fn get_token_account_balance_checked(
     mint: &Pubkey,
     token_account_info: &AccountInfo,
 ) -> Result<u64, ProgramError> {
-    assert_eq!(token_account_info.owner, &spl_token::ID);
+    if token_account_info.owner != &spl_token::ID {
+        return Err(PaladinRewardsError::InvalidTokenProgram.into());
+    }
     let token_account_data = token_account_info.try_borrow_data()?;
     let token_account = TokenAccount::unpack(&token_account_data)?;
 
@@ -58,9 +97,15 @@ fn check_pool(
         return Err(ProgramError::InvalidAccountOwner);
     }
 
+    let data = holder_rewards_pool_info.try_borrow_data()?;
+    let state = bytemuck::try_from_bytes::<HolderRewardsPool>(&data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
     // Ensure the provided holder rewards pool address is the correct
-    // address derived from the mint.
-    if holder_rewards_pool_info.key != &get_holder_rewards_pool_address(mint, program_id) {
+    // address derived from the mint and the stored bump seed.
+    if create_holder_rewards_pool_address(mint, state.bump_seed, program_id).as_ref()
+        != Ok(holder_rewards_pool_info.key)
+    {
         return Err(PaladinRewardsError::IncorrectHolderRewardsPoolAddress.into());
     }
 
@@ -78,101 +123,528 @@ fn check_holder_rewards(
         return Err(ProgramError::InvalidAccountOwner);
     }
 
+    let data = holder_rewards_info.try_borrow_data()?;
+    let state = bytemuck::try_from_bytes::<HolderRewards>(&data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
     // Ensure the provided holder rewards address is the correct address
-    // derived from the token account.
-    if holder_rewards_info.key != &get_holder_rewards_address(owner_address, program_id) {
+    // derived from the owner and the stored bump seed.
+    if create_holder_rewards_address(owner_address, state.bump_seed, program_id).as_ref()
+        != Ok(holder_rewards_info.key)
+    {
         return Err(PaladinRewardsError::IncorrectHolderRewardsAddress.into());
     }
 
     Ok(())
 }
 
-// Calculate the rewards per token.
-//
-// Calculation: rewards / token_supply
-// Scaled by 1e18 to store 18 decimal places of precision.
-//
-// This calculation is valid for all possible values of rewards and token
-// supply, since the scaling to `u128` prevents multiplication from breaking
-// the `u64::MAX` ceiling, and the `token_supply == 0` check prevents
-// `checked_div` returning `None` from a zero denominator.
-fn calculate_rewards_per_token(rewards: u64, total_deposited: u64) -> Result<u128, ProgramError> {
-    if total_deposited == 0 {
-        return Ok(0);
+fn check_rate_history(
+    program_id: &Pubkey,
+    holder_rewards_pool_address: &Pubkey,
+    rate_history_info: &AccountInfo,
+) -> ProgramResult {
+    // Ensure the rate history account is owned by the Paladin Rewards
+    // program.
+    if rate_history_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    if !rate_history_info.is_writable {
+        return Err(PaladinRewardsError::AccountNotWritable.into());
+    }
+
+    let data = rate_history_info.try_borrow_data()?;
+    let state = bytemuck::try_from_bytes::<RateHistory>(&data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Ensure the provided rate history address is the correct address
+    // derived from the pool and the stored bump seed.
+    if create_rate_history_address(holder_rewards_pool_address, state.bump_seed, program_id)
+        .as_ref()
+        != Ok(rate_history_info.key)
+    {
+        return Err(PaladinRewardsError::IncorrectRateHistoryAddress.into());
     }
-    (rewards as u128)
-        .checked_mul(REWARDS_PER_TOKEN_SCALING_FACTOR)
-        .and_then(|product| product.checked_div(total_deposited as u128))
-        .ok_or(ProgramError::ArithmeticOverflow)
+
+    Ok(())
 }
 
-// Calculate the eligible rewards for a token account.
-//
-// Calculation: (current - last) * balance
-// The result is descaled by a factor of 1e18 since both rewards per token
-// values are scaled by 1e18 for precision.
-//
-// This calculation is valid as long as the total rewards accumulated by the
-// system does not exceed `u64::MAX`.
-//
-// This condition is a reasonable upper bound, considering `u64::MAX` is
-// approximately 386_266 % of the current circulating supply of SOL.
-//
-// For more information, see this function's prop tests.
-fn calculate_eligible_rewards(
-    current_accumulated_rewards_per_token: u128,
-    last_accumulated_rewards_per_token: u128,
-    deposited_amount: u64,
-) -> Result<u64, ProgramError> {
-    let marginal_rate =
-        current_accumulated_rewards_per_token.wrapping_sub(last_accumulated_rewards_per_token);
+fn check_epoch_ledger(
+    program_id: &Pubkey,
+    holder_rewards_pool_address: &Pubkey,
+    epoch_ledger_info: &AccountInfo,
+) -> ProgramResult {
+    // Ensure the epoch ledger account is owned by the Paladin Rewards
+    // program.
+    if epoch_ledger_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    if !epoch_ledger_info.is_writable {
+        return Err(PaladinRewardsError::AccountNotWritable.into());
+    }
+
+    let data = epoch_ledger_info.try_borrow_data()?;
+    let state = bytemuck::try_from_bytes::<EpochLedger>(&data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
 
-    if marginal_rate == 0 {
-        return Ok(0);
+    // Ensure the provided epoch ledger address is the correct address
+    // derived from the pool and the stored bump seed.
+    if create_epoch_ledger_address(holder_rewards_pool_address, state.bump_seed, program_id)
+        .as_ref()
+        != Ok(epoch_ledger_info.key)
+    {
+        return Err(PaladinRewardsError::IncorrectEpochLedgerAddress.into());
     }
-    marginal_rate
-        .checked_mul(deposited_amount as u128)
-        .and_then(|product| product.checked_div(REWARDS_PER_TOKEN_SCALING_FACTOR))
-        .and_then(|product| product.try_into().ok())
-        .ok_or(ProgramError::ArithmeticOverflow)
+
+    Ok(())
 }
 
-fn update_accumulated_rewards_per_token(
-    mint_info: &AccountInfo,
-    holder_rewards_pool_info: &AccountInfo,
-    pool_token_account: &AccountInfo,
-    pool_state: &mut HolderRewardsPool,
+fn check_lockup_tiers(
+    program_id: &Pubkey,
+    holder_rewards_pool_address: &Pubkey,
+    lockup_tiers_info: &AccountInfo,
 ) -> ProgramResult {
-    let total_deposited = get_token_account_balance_checked(mint_info.key, pool_token_account)?;
-    let latest_lamports = holder_rewards_pool_info.lamports();
+    // Ensure the lockup tiers account is owned by the Paladin Rewards
+    // program.
+    if lockup_tiers_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let data = lockup_tiers_info.try_borrow_data()?;
+    let state = bytemuck::try_from_bytes::<LockupTiers>(&data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Ensure the provided lockup tiers address is the correct address
+    // derived from the pool and the stored bump seed.
+    if create_lockup_tiers_address(holder_rewards_pool_address, state.bump_seed, program_id)
+        .as_ref()
+        != Ok(lockup_tiers_info.key)
+    {
+        return Err(PaladinRewardsError::IncorrectLockupTiersAddress.into());
+    }
+
+    Ok(())
+}
+
+fn check_vote_escrow(
+    program_id: &Pubkey,
+    holder_rewards_pool_address: &Pubkey,
+    vote_escrow_info: &AccountInfo,
+) -> ProgramResult {
+    // Ensure the vote escrow account is owned by the Paladin Rewards
+    // program.
+    if vote_escrow_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let data = vote_escrow_info.try_borrow_data()?;
+    let state = bytemuck::try_from_bytes::<VoteEscrow>(&data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Ensure the provided vote escrow address is the correct address
+    // derived from the pool and the stored bump seed.
+    if create_vote_escrow_address(holder_rewards_pool_address, state.bump_seed, program_id).as_ref()
+        != Ok(vote_escrow_info.key)
+    {
+        return Err(PaladinRewardsError::IncorrectVoteEscrowAddress.into());
+    }
+
+    Ok(())
+}
+
+fn check_access_entry(
+    program_id: &Pubkey,
+    holder_rewards_pool_address: &Pubkey,
+    wallet_address: &Pubkey,
+    access_entry_info: &AccountInfo,
+) -> ProgramResult {
+    // Ensure the access entry account is owned by the Paladin Rewards
+    // program.
+    if access_entry_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let data = access_entry_info.try_borrow_data()?;
+    let state = bytemuck::try_from_bytes::<AccessEntry>(&data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Ensure the provided access entry address is the correct address
+    // derived from the pool, the wallet and the stored bump seed.
+    if create_access_entry_address(
+        holder_rewards_pool_address,
+        wallet_address,
+        state.bump_seed,
+        program_id,
+    )
+    .as_ref()
+        != Ok(access_entry_info.key)
+    {
+        return Err(PaladinRewardsError::IncorrectAccessEntryAddress.into());
+    }
+
+    Ok(())
+}
+
+fn check_stake_checkpoints(
+    program_id: &Pubkey,
+    owner_address: &Pubkey,
+    stake_checkpoints_info: &AccountInfo,
+) -> ProgramResult {
+    // Ensure the stake checkpoints account is owned by the Paladin Rewards
+    // program.
+    if stake_checkpoints_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let data = stake_checkpoints_info.try_borrow_data()?;
+    let state = bytemuck::try_from_bytes::<StakeCheckpoints>(&data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
 
+    // Ensure the provided stake checkpoints address is the correct address
+    // derived from the owner and the stored bump seed.
+    if create_stake_checkpoints_address(owner_address, state.bump_seed, program_id).as_ref()
+        != Ok(stake_checkpoints_info.key)
+    {
+        return Err(PaladinRewardsError::IncorrectStakeCheckpointsAddress.into());
+    }
+
+    Ok(())
+}
+
+fn check_campaign(
+    program_id: &Pubkey,
+    holder_rewards_pool_address: &Pubkey,
+    campaign_info: &AccountInfo,
+) -> ProgramResult {
+    // Ensure the campaign account is owned by the Paladin Rewards program.
+    if campaign_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let data = campaign_info.try_borrow_data()?;
+    let state = bytemuck::try_from_bytes::<Campaign>(&data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Ensure the provided campaign address is the correct address derived
+    // from the pool, the funder and the stored bump seed.
+    if create_campaign_address(
+        holder_rewards_pool_address,
+        &state.funder,
+        state.bump_seed,
+        program_id,
+    )
+    .as_ref()
+        != Ok(campaign_info.key)
+    {
+        return Err(PaladinRewardsError::IncorrectCampaignAddress.into());
+    }
+
+    Ok(())
+}
+
+// Optional accounts that are not provided are either omitted or passed as the
+// program ID.
+fn next_optional_account_info<'a, 'b, I: Iterator<Item = &'a AccountInfo<'b>>>(
+    program_id: &Pubkey,
+    iter: &mut I,
+) -> Option<I::Item> {
+    next_account_info(iter)
+        .ok()
+        .filter(|account_info| account_info.key != program_id)
+}
+
+// Calculate the pool's accumulated rewards per token, including any lamports
+// received since the rate was last updated.
+fn calculate_latest_accumulated_rewards_per_token(
+    pool_state: &HolderRewardsPool,
+    latest_lamports: u64,
+) -> Result<u128, ProgramError> {
     let additional_lamports = latest_lamports
         .checked_sub(pool_state.lamports_last)
         .ok_or(ProgramError::ArithmeticOverflow)?;
 
-    let marginal_rate = calculate_rewards_per_token(additional_lamports, total_deposited)?;
+    let total_weight = pool_state
+        .total_weight()
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let marginal_rate = calculate_rewards_per_token(additional_lamports, total_weight)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
 
-    pool_state.accumulated_rewards_per_token = pool_state
+    Ok(pool_state
         .accumulated_rewards_per_token
-        .wrapping_add(marginal_rate);
+        .wrapping_add(marginal_rate))
+}
+
+// Records a change to the pool's rewards per token exchange rate, before it
+// is applied to the pool. The rate history and epoch ledger accounts must be
+// provided if the pool records them.
+fn record_accumulated_rewards_per_token(
+    program_id: &Pubkey,
+    holder_rewards_pool_info: &AccountInfo,
+    pool_state: &HolderRewardsPool,
+    accumulated_rewards_per_token: u128,
+    rate_history_info: Option<&AccountInfo>,
+    epoch_ledger_info: Option<&AccountInfo>,
+    clock: &Clock,
+) -> ProgramResult {
+    match rate_history_info {
+        Some(rate_history_info) => {
+            check_rate_history(program_id, holder_rewards_pool_info.key, rate_history_info)?;
+
+            if accumulated_rewards_per_token != pool_state.accumulated_rewards_per_token {
+                let mut rate_history_data = rate_history_info.try_borrow_mut_data()?;
+                bytemuck::try_from_bytes_mut::<RateHistory>(&mut rate_history_data)
+                    .map_err(|_| ProgramError::InvalidAccountData)?
+                    .push(RateHistoryEntry {
+                        accumulated_rewards_per_token,
+                        slot: clock.slot,
+                        epoch: clock.epoch,
+                        total_deposited: pool_state.total_deposited,
+                        _padding: [0; 8],
+                    });
+            }
+        }
+        None if pool_state.rate_history != 0 => {
+            return Err(PaladinRewardsError::RateHistoryRequired.into());
+        }
+        None => {}
+    }
+
+    match epoch_ledger_info {
+        Some(epoch_ledger_info) => {
+            check_epoch_ledger(program_id, holder_rewards_pool_info.key, epoch_ledger_info)?;
+
+            let mut epoch_ledger_data = epoch_ledger_info.try_borrow_mut_data()?;
+            bytemuck::try_from_bytes_mut::<EpochLedger>(&mut epoch_ledger_data)
+                .map_err(|_| ProgramError::InvalidAccountData)?
+                .record(
+                    clock.epoch,
+                    accumulated_rewards_per_token,
+                    pool_state.total_rewards_received,
+                );
+        }
+        None if pool_state.epoch_ledger != 0 => {
+            return Err(PaladinRewardsError::EpochLedgerRequired.into());
+        }
+        None => {}
+    }
+
+    Ok(())
+}
+
+// Accrues any lamports the pool received since it was last updated. The rate
+// history and epoch ledger accounts must be provided if the pool records
+// them.
+fn update_accumulated_rewards_per_token(
+    program_id: &Pubkey,
+    holder_rewards_pool_info: &AccountInfo,
+    pool_state: &mut HolderRewardsPool,
+    rate_history_info: Option<&AccountInfo>,
+    epoch_ledger_info: Option<&AccountInfo>,
+) -> ProgramResult {
+    let latest_lamports = holder_rewards_pool_info.lamports();
+
+    let accumulated_rewards_per_token =
+        calculate_latest_accumulated_rewards_per_token(pool_state, latest_lamports)?;
+
+    if latest_lamports > pool_state.lamports_last {
+        let inflow = latest_lamports.saturating_sub(pool_state.lamports_last);
+        pool_state.total_rewards_received = pool_state
+            .total_rewards_received
+            .checked_add(inflow)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        PaladinRewardsEvent::RewardsAccrued {
+            holder_rewards_pool: *holder_rewards_pool_info.key,
+            inflow,
+            accumulated_rewards_per_token,
+        }
+        .emit();
+    }
+
+    let clock = Clock::get()?;
+    record_accumulated_rewards_per_token(
+        program_id,
+        holder_rewards_pool_info,
+        pool_state,
+        accumulated_rewards_per_token,
+        rate_history_info,
+        epoch_ledger_info,
+        &clock,
+    )?;
+
+    // The pool's weight is unchanged since it was last updated.
+    pool_state.zero_weight_slots = pool_state
+        .zero_weight_slots_at(clock.slot)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    pool_state.accumulated_rewards_per_token = accumulated_rewards_per_token;
     pool_state.lamports_last = latest_lamports;
+    // Every path that modifies the pool processes received lamports first.
+    pool_state.last_update_slot = clock.slot;
+
+    Ok(())
+}
+
+// Releases a campaign's rewards unlocked since it was last released into the
+// pool, accruing them at the pool's current weight. Rewards unlocked while
+// nothing was deposited could not be earned by any holder, so the share of
+// the slots since the last release the pool spent without weight is set
+// aside for the funder instead.
+fn release_campaign_rewards(
+    program_id: &Pubkey,
+    holder_rewards_pool_info: &AccountInfo,
+    pool_state: &mut HolderRewardsPool,
+    campaign_info: &AccountInfo,
+    campaign_state: &mut Campaign,
+    rate_history_info: Option<&AccountInfo>,
+    epoch_ledger_info: Option<&AccountInfo>,
+) -> ProgramResult {
+    // Accrue any lamports received before the release first.
+    update_accumulated_rewards_per_token(
+        program_id,
+        holder_rewards_pool_info,
+        pool_state,
+        rate_history_info,
+        epoch_ledger_info,
+    )?;
+
+    let slot = Clock::get()?.slot;
+    let releasable = campaign_state.releasable(slot);
+    let total_weight = pool_state
+        .total_weight()
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let unspent = campaign_state.unspendable(
+        releasable,
+        slot,
+        pool_state.zero_weight_slots,
+        total_weight != 0,
+    );
+    let released = releasable.saturating_sub(unspent);
+
+    campaign_state.last_release_slot = slot;
+    campaign_state.zero_weight_slots = pool_state.zero_weight_slots;
+
+    if releasable == 0 {
+        return Ok(());
+    }
+
+    if released > 0 {
+        // Move the released lamports from the campaign to the pool, where
+        // they accrue like any other rewards.
+        let new_campaign_lamports = campaign_info
+            .lamports()
+            .checked_sub(released)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        let new_holder_rewards_pool_lamports = holder_rewards_pool_info
+            .lamports()
+            .checked_add(released)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        **campaign_info.try_borrow_mut_lamports()? = new_campaign_lamports;
+        **holder_rewards_pool_info.try_borrow_mut_lamports()? = new_holder_rewards_pool_lamports;
+
+        update_accumulated_rewards_per_token(
+            program_id,
+            holder_rewards_pool_info,
+            pool_state,
+            rate_history_info,
+            epoch_ledger_info,
+        )?;
+    }
+
+    campaign_state.released = campaign_state
+        .released
+        .checked_add(released)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    campaign_state.unspent = campaign_state
+        .unspent
+        .checked_add(unspent)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    PaladinRewardsEvent::CampaignRewardsReleased {
+        holder_rewards_pool: *holder_rewards_pool_info.key,
+        campaign: *campaign_info.key,
+        released,
+        unspent,
+    }
+    .emit();
+
+    Ok(())
+}
+
+// Tops the account up from the payer to the rent-exempt minimum for `space`
+// bytes.
+fn top_up_rent_exemption<'a>(
+    payer_info: &AccountInfo<'a>,
+    account_info: &AccountInfo<'a>,
+    space: usize,
+) -> ProgramResult {
+    let rent = Rent::get()?;
+    let required_lamports = rent
+        .minimum_balance(space)
+        .saturating_sub(account_info.lamports());
+
+    if required_lamports > 0 {
+        invoke(
+            &system_instruction::transfer(payer_info.key, account_info.key, required_lamports),
+            &[payer_info.clone(), account_info.clone()],
+        )?;
+    }
 
     Ok(())
 }
 
-fn assert_rent_exempt(account: &AccountInfo) {
-    assert!(account.lamports() >= Rent::get().unwrap().minimum_balance(account.data_len()));
+// Tops the account up to rent exemption from the payer, covering accounts
+// that are unfunded or only partially pre-funded, then allocates and assigns
+// it to the program.
+fn create_pda_account<'a>(
+    program_id: &Pubkey,
+    payer_info: &AccountInfo<'a>,
+    account_info: &AccountInfo<'a>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> ProgramResult {
+    top_up_rent_exemption(payer_info, account_info, space)?;
+
+    invoke_signed(
+        &system_instruction::allocate(account_info.key, space as u64),
+        &[account_info.clone()],
+        &[signer_seeds],
+    )?;
+    invoke_signed(
+        &system_instruction::assign(account_info.key, program_id),
+        &[account_info.clone()],
+        &[signer_seeds],
+    )
+}
+
+// Ensures the pool authority is the mint's mint authority, so only the
+// mint's issuer can take the pool's admin rights. The authority's signature
+// is checked with the instruction's accounts.
+fn check_mint_authority(mint_info: &AccountInfo, authority_info: &AccountInfo) -> ProgramResult {
+    if mint_info.owner != &spl_token::ID {
+        return Err(PaladinRewardsError::InvalidMintOwner.into());
+    }
+    let mint = Mint::unpack(&mint_info.try_borrow_data()?)?;
+
+    if mint.mint_authority != COption::Some(*authority_info.key) {
+        return Err(PaladinRewardsError::IncorrectPoolAuthority.into());
+    }
+
+    Ok(())
 }
 
+// Validates a token account for the mint, owned by the expected owner if one
+// is given.
 fn validate_token_account(
     token_account_info: &AccountInfo,
-    expected_owner: &Pubkey,
+    expected_owner: Option<&Pubkey>,
     expected_mint: &Pubkey,
 ) -> ProgramResult {
     // Check if account is owned by SPL Token program
     if token_account_info.owner != &spl_token::id() {
         msg!("Token account not owned by SPL Token program");
-        return Err(ProgramError::IncorrectProgramId);
+        return Err(PaladinRewardsError::InvalidTokenProgram.into());
     }
 
     // Check rent exemption
@@ -192,7 +664,7 @@ fn validate_token_account(
     }
 
     // Verify owner
-    if token_account.owner != *expected_owner {
+    if expected_owner.is_some_and(|expected_owner| &token_account.owner != expected_owner) {
         msg!("Invalid token account owner");
         return Err(PaladinRewardsError::TokenAccountOwnerMissmatch.into());
     }
@@ -224,12 +696,18 @@ fn calculate_rewards_to_harvest(
     pool_state: &HolderRewardsPool,
     pool_lamports: u64,
 ) -> Result<u64, ProgramError> {
-    // Calculate the eligible rewards from the marginal rate.
+    // Calculate the eligible rewards from the marginal rate, plus any rewards
+    // already settled.
     let eligible_rewards = calculate_eligible_rewards(
         pool_state.accumulated_rewards_per_token,
         holder_rewards_state.last_accumulated_rewards_per_token,
-        holder_rewards_state.deposited,
-    )?;
+        holder_rewards_state
+            .weight()
+            .ok_or(ProgramError::ArithmeticOverflow)?,
+    )
+    .ok_or(ProgramError::ArithmeticOverflow)?
+    .checked_add(holder_rewards_state.unharvested_rewards)
+    .ok_or(ProgramError::ArithmeticOverflow)?;
 
     // Error if the pool doesn't have enough lamports to cover the rewards,
     // This should never happen, but the check is a failsafe.
@@ -246,63 +724,833 @@ fn calculate_rewards_to_harvest(
     // Update the holder rewards state with last rewards per token
     holder_rewards_state.last_accumulated_rewards_per_token =
         pool_state.accumulated_rewards_per_token;
+    holder_rewards_state.unharvested_rewards = 0;
 
     Ok(eligible_rewards)
 }
 
-// Send the rewards to the holder's token account.
-fn send_rewards(
-    holder_rewards_pool_info: AccountInfo,
-    owner: AccountInfo,
-    pool_state: &mut HolderRewardsPool,
-    rewards_to_harvest: u64,
+// Credits the holder with the rewards earned since their last harvest, to be
+// paid out with their next one.
+//
+// Must be called before the holder's weight changes, since the rewards are
+// calculated from it.
+fn settle_rewards(
+    pool_state: &HolderRewardsPool,
+    holder_rewards_state: &mut HolderRewards,
 ) -> ProgramResult {
-    // Move the amount from the holder rewards pool to the token account.
-    let new_holder_rewards_pool_lamports = holder_rewards_pool_info
-        .lamports()
-        .checked_sub(rewards_to_harvest)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-    let new_token_account_lamports = owner
-        .lamports()
-        .checked_add(rewards_to_harvest)
+    let eligible_rewards = calculate_eligible_rewards(
+        pool_state.accumulated_rewards_per_token,
+        holder_rewards_state.last_accumulated_rewards_per_token,
+        holder_rewards_state
+            .weight()
+            .ok_or(ProgramError::ArithmeticOverflow)?,
+    )
+    .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    holder_rewards_state.unharvested_rewards = holder_rewards_state
+        .unharvested_rewards
+        .checked_add(eligible_rewards)
         .ok_or(ProgramError::ArithmeticOverflow)?;
-
-    **holder_rewards_pool_info.try_borrow_mut_lamports()? = new_holder_rewards_pool_lamports;
-    **owner.try_borrow_mut_lamports()? = new_token_account_lamports;
-    pool_state.lamports_last = new_holder_rewards_pool_lamports;
+    holder_rewards_state.last_accumulated_rewards_per_token =
+        pool_state.accumulated_rewards_per_token;
 
     Ok(())
 }
 
-// Check that duna document is signed
-pub(crate) fn check_duna_document_signed(
-    signer: &Pubkey,
-    doc_pda: &AccountInfo,
-    doc_hash: &[u8; 32],
+// Checks a deposit against the pool's deposit limits.
+fn check_deposit_limits(
+    pool_state: &HolderRewardsPool,
+    holder_rewards_state: &HolderRewards,
+    amount: u64,
 ) -> ProgramResult {
-    let (duna_document_pda, _) = find_duna_document_pda(signer, doc_hash);
-
-    // Check the duna document PDA is correct.
-    if doc_pda.key != &duna_document_pda {
-        return Err(PaladinRewardsError::InvalidDunaPdaSeeds.into());
+    if amount < pool_state.min_deposit {
+        return Err(PaladinRewardsError::DepositBelowMinimum.into());
     }
 
-    // Ensure the duna document PDA is initialized.
-    let duna_document_data = doc_pda.try_borrow_data()?;
+    let holder_deposited = holder_rewards_state
+        .deposited
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    if pool_state.max_holder_deposit != 0 && holder_deposited > pool_state.max_holder_deposit {
+        return Err(PaladinRewardsError::HolderDepositCapExceeded.into());
+    }
 
-    if duna_document_data.first() != Some(&1) {
-        return Err(PaladinRewardsError::DunaDocumentNotInitialized.into());
+    let total_deposited = pool_state
+        .total_deposited
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    if pool_state.max_total_deposit != 0 && total_deposited > pool_state.max_total_deposit {
+        return Err(PaladinRewardsError::PoolDepositCapExceeded.into());
     }
 
     Ok(())
 }
 
-/// Processes an
-/// [InitializeHolderRewardsPool](enum.PaladinRewardsInstruction.html)
-/// instruction.
-fn process_initialize_holder_rewards_pool(
+// Withholds the pool's early withdrawal penalty from the rewards harvested by
+// a withdrawal, returning the rewards left to send.
+//
+// The forfeited lamports stay in the pool and are added to the accumulator
+// for the other holders, so the withdrawing holder's deposit must be updated
+// first. The increase is recorded in the pool's rate history and epoch ledger
+// like any other accrual. If no other holder has weight in the pool, nothing
+// is forfeited.
+#[allow(clippy::too_many_arguments)]
+fn apply_early_withdrawal_penalty(
     program_id: &Pubkey,
-    accounts: &[AccountInfo],
+    holder_rewards_pool_info: &AccountInfo,
+    owner: &Pubkey,
+    pool_state: &mut HolderRewardsPool,
+    holder_rewards_state: &mut HolderRewards,
+    rewards: u64,
+    rate_history_info: Option<&AccountInfo>,
+    epoch_ledger_info: Option<&AccountInfo>,
+    clock: &Clock,
+) -> Result<u64, ProgramError> {
+    let penalty = pool_state.early_withdrawal_penalty(
+        rewards,
+        holder_rewards_state.last_deposit_slot,
+        clock.slot,
+    );
+    if penalty == 0 {
+        return Ok(rewards);
+    }
+
+    let other_weight = pool_state
+        .total_weight()
+        .and_then(|total| total.checked_sub(holder_rewards_state.weight()?))
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    if other_weight == 0 {
+        return Ok(rewards);
+    }
+
+    // Advance the holder along with the pool, so they earn nothing from
+    // their own penalty.
+    let marginal_rate = calculate_rewards_per_token(penalty, other_weight)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let accumulated_rewards_per_token = pool_state
+        .accumulated_rewards_per_token
+        .wrapping_add(marginal_rate);
+    record_accumulated_rewards_per_token(
+        program_id,
+        holder_rewards_pool_info,
+        pool_state,
+        accumulated_rewards_per_token,
+        rate_history_info,
+        epoch_ledger_info,
+        clock,
+    )?;
+    pool_state.accumulated_rewards_per_token = accumulated_rewards_per_token;
+    holder_rewards_state.last_accumulated_rewards_per_token =
+        pool_state.accumulated_rewards_per_token;
+
+    holder_rewards_state.early_withdrawal_penalties = holder_rewards_state
+        .early_withdrawal_penalties
+        .checked_add(penalty)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    PaladinRewardsEvent::EarlyWithdrawalPenalized {
+        holder_rewards_pool: *holder_rewards_pool_info.key,
+        owner: *owner,
+        forfeited: penalty,
+    }
+    .emit();
+
+    Ok(rewards - penalty)
+}
+
+// Holds back rewards harvested within the pool's early withdrawal period,
+// returning the rewards left to send.
+//
+// The withheld rewards are settled to the holder and paid out by a harvest
+// once the period has elapsed, or penalized if the holder withdraws first.
+fn withhold_early_rewards(
+    pool_state: &HolderRewardsPool,
+    holder_rewards_state: &mut HolderRewards,
+    rewards: u64,
+    slot: u64,
+) -> Result<u64, ProgramError> {
+    if pool_state.early_withdrawal_penalty(rewards, holder_rewards_state.last_deposit_slot, slot)
+        == 0
+    {
+        return Ok(rewards);
+    }
+
+    holder_rewards_state.unharvested_rewards = holder_rewards_state
+        .unharvested_rewards
+        .checked_add(rewards)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    Ok(0)
+}
+
+// Replaces the holder's lockup, moving its bonus weight in the pool's total.
+//
+// The holder's pending rewards must be calculated first, since changing the
+// weight changes the rewards earned since the holder's last harvest.
+fn set_lockup(
+    pool_state: &mut HolderRewardsPool,
+    holder_rewards_state: &mut HolderRewards,
+    locked_amount: u64,
+    unlock_timestamp: i64,
+    multiplier_bps: u64,
+) -> ProgramResult {
+    let previous_bonus_weight = holder_rewards_state
+        .lockup_bonus_weight()
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    holder_rewards_state.locked_amount = locked_amount;
+    holder_rewards_state.unlock_timestamp = unlock_timestamp;
+    holder_rewards_state.lockup_multiplier_bps = multiplier_bps;
+
+    let bonus_weight = holder_rewards_state
+        .lockup_bonus_weight()
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    pool_state.total_lockup_bonus_weight = pool_state
+        .total_lockup_bonus_weight
+        .checked_sub(previous_bonus_weight)
+        .and_then(|total| total.checked_add(bonus_weight))
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    Ok(())
+}
+
+// Releases the holder's lockup if it has expired.
+fn release_expired_lockup(
+    holder_rewards_pool: &Pubkey,
+    owner: &Pubkey,
+    pool_state: &mut HolderRewardsPool,
+    holder_rewards_state: &mut HolderRewards,
+    unix_timestamp: i64,
+) -> ProgramResult {
+    let amount = holder_rewards_state.locked_amount;
+    if amount == 0 || unix_timestamp < holder_rewards_state.unlock_timestamp {
+        return Ok(());
+    }
+
+    set_lockup(pool_state, holder_rewards_state, 0, 0, 0)?;
+    // Voting power has already ended, no later than the unlock time.
+    holder_rewards_state.voting_power_locked = 0;
+    holder_rewards_state.voting_power_end = 0;
+
+    PaladinRewardsEvent::LockupReleased {
+        holder_rewards_pool: *holder_rewards_pool,
+        owner: *owner,
+        amount,
+    }
+    .emit();
+
+    Ok(())
+}
+
+// Records the holder's deposited balance in their stake checkpoints, which
+// must be provided if the holder records them.
+fn record_stake_checkpoint(
+    program_id: &Pubkey,
+    owner: &Pubkey,
+    holder_rewards_state: &HolderRewards,
+    stake_checkpoints_info: Option<&AccountInfo>,
+    slot: u64,
+) -> ProgramResult {
+    let stake_checkpoints_info = match stake_checkpoints_info {
+        Some(stake_checkpoints_info) => stake_checkpoints_info,
+        None if holder_rewards_state.stake_checkpoints != 0 => {
+            return Err(PaladinRewardsError::StakeCheckpointsRequired.into());
+        }
+        None => return Ok(()),
+    };
+
+    check_stake_checkpoints(program_id, owner, stake_checkpoints_info)?;
+    if !stake_checkpoints_info.is_writable {
+        return Err(PaladinRewardsError::AccountNotWritable.into());
+    }
+
+    let mut data = stake_checkpoints_info.try_borrow_mut_data()?;
+    let state = bytemuck::try_from_bytes_mut::<StakeCheckpoints>(&mut data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    state.push(slot, holder_rewards_state.deposited);
+
+    Ok(())
+}
+
+// Checks the pool's access list permits the wallet to deposit.
+//
+// A wallet without an access entry account is unlisted, so its derived
+// address must be provided instead.
+fn check_access_list(
+    program_id: &Pubkey,
+    holder_rewards_pool_address: &Pubkey,
+    pool_state: &HolderRewardsPool,
+    wallet_address: &Pubkey,
+    access_entry_info: Option<&AccountInfo>,
+) -> ProgramResult {
+    let access_entry_info = match access_entry_info {
+        Some(access_entry_info) => access_entry_info,
+        None if pool_state.access_list != ACCESS_LIST_NONE => {
+            return Err(PaladinRewardsError::AccessEntryRequired.into());
+        }
+        None => return Ok(()),
+    };
+
+    let status = if access_entry_info.data_is_empty() {
+        if access_entry_info.key
+            != &get_access_entry_address(holder_rewards_pool_address, wallet_address, program_id)
+        {
+            return Err(PaladinRewardsError::IncorrectAccessEntryAddress.into());
+        }
+        ACCESS_STATUS_UNLISTED
+    } else {
+        check_access_entry(
+            program_id,
+            holder_rewards_pool_address,
+            wallet_address,
+            access_entry_info,
+        )?;
+        let data = access_entry_info.try_borrow_data()?;
+        bytemuck::try_from_bytes::<AccessEntry>(&data)
+            .map_err(|_| ProgramError::InvalidAccountData)?
+            .status
+    };
+
+    if !access_permitted(pool_state.access_list, status) {
+        return Err(PaladinRewardsError::AccessDenied.into());
+    }
+
+    Ok(())
+}
+
+// Checks the receipt accounts a deposit or withdrawal must provide if the
+// pool mints receipt tokens, returning them if it does.
+fn check_receipt_accounts<'a, 'b>(
+    program_id: &Pubkey,
+    holder_rewards_pool_address: &Pubkey,
+    pool_state: &HolderRewardsPool,
+    receipt_mint_info: Option<&'a AccountInfo<'b>>,
+    receipt_token_account_info: Option<&'a AccountInfo<'b>>,
+    receipt_token_program_info: Option<&'a AccountInfo<'b>>,
+) -> Result<Option<[&'a AccountInfo<'b>; 3]>, ProgramError> {
+    if pool_state.receipts == 0 {
+        return Ok(None);
+    }
+
+    let (
+        Some(receipt_mint_info),
+        Some(receipt_token_account_info),
+        Some(receipt_token_program_info),
+    ) = (
+        receipt_mint_info,
+        receipt_token_account_info,
+        receipt_token_program_info,
+    )
+    else {
+        return Err(PaladinRewardsError::ReceiptAccountsRequired.into());
+    };
+
+    // Ensure the provided receipt mint address is the correct address
+    // derived from the pool and the stored bump seed.
+    if create_receipt_mint_address(
+        holder_rewards_pool_address,
+        pool_state.receipt_mint_bump_seed,
+        program_id,
+    )
+    .as_ref()
+        != Ok(receipt_mint_info.key)
+    {
+        return Err(PaladinRewardsError::IncorrectReceiptMintAddress.into());
+    }
+
+    if receipt_token_program_info.key != &spl_token_2022::ID {
+        return Err(PaladinRewardsError::IncorrectTokenProgram.into());
+    }
+
+    Ok(Some([
+        receipt_mint_info,
+        receipt_token_account_info,
+        receipt_token_program_info,
+    ]))
+}
+
+// The accounts the Token-2022 program resolves for the receipt mint's
+// transfer hook, following the five accounts of every `Execute` instruction:
+//
+// 5. `[w]` Holder rewards pool account.
+// 6. `[w]` Source owner's holder rewards account.
+// 7. `[w]` Destination owner's holder rewards account.
+// 8. `[w]` Source owner's stake checkpoints account.
+// 9. `[w]` Destination owner's stake checkpoints account.
+// 10. `[ ]` Destination owner's access entry account.
+// 11. `[w]` Rate history account.
+// 12. `[w]` Epoch ledger account.
+//
+// The owner of a token account is read from bytes `32..64` of its data.
+fn receipt_extra_account_metas(
+    holder_rewards_pool_address: &Pubkey,
+) -> Result<[ExtraAccountMeta; 8], ProgramError> {
+    let literal = |bytes: &[u8]| Seed::Literal {
+        bytes: bytes.to_vec(),
+    };
+    let owner = |account_index| Seed::AccountData {
+        account_index,
+        data_index: 32,
+        length: 32,
+    };
+
+    Ok([
+        ExtraAccountMeta::new_with_pubkey(holder_rewards_pool_address, false, true)?,
+        ExtraAccountMeta::new_with_seeds(
+            &[literal(SEED_PREFIX_HOLDER_REWARDS), owner(0)],
+            false,
+            true,
+        )?,
+        ExtraAccountMeta::new_with_seeds(
+            &[literal(SEED_PREFIX_HOLDER_REWARDS), owner(2)],
+            false,
+            true,
+        )?,
+        ExtraAccountMeta::new_with_seeds(
+            &[literal(SEED_PREFIX_STAKE_CHECKPOINTS), owner(0)],
+            false,
+            true,
+        )?,
+        ExtraAccountMeta::new_with_seeds(
+            &[literal(SEED_PREFIX_STAKE_CHECKPOINTS), owner(2)],
+            false,
+            true,
+        )?,
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                literal(SEED_PREFIX_ACCESS_ENTRY),
+                Seed::AccountKey { index: 5 },
+                owner(2),
+            ],
+            false,
+            false,
+        )?,
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                literal(SEED_PREFIX_RATE_HISTORY),
+                Seed::AccountKey { index: 5 },
+            ],
+            false,
+            true,
+        )?,
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                literal(SEED_PREFIX_EPOCH_LEDGER),
+                Seed::AccountKey { index: 5 },
+            ],
+            false,
+            true,
+        )?,
+    ])
+}
+
+// Returns the owner of a receipt token account.
+fn get_receipt_token_account_owner(
+    receipt_mint: &Pubkey,
+    token_account_info: &AccountInfo,
+) -> Result<Pubkey, ProgramError> {
+    if token_account_info.owner != &spl_token_2022::ID {
+        return Err(PaladinRewardsError::InvalidTokenProgram.into());
+    }
+    let data = token_account_info.try_borrow_data()?;
+    let token_account = StateWithExtensions::<ReceiptTokenAccount>::unpack(&data)?;
+
+    // Ensure the provided token account is for the receipt mint.
+    if &token_account.base.mint != receipt_mint {
+        return Err(PaladinRewardsError::TokenAccountMintMismatch.into());
+    }
+
+    Ok(token_account.base.owner)
+}
+
+// Checks the source account of a receipt token transfer is flagged as
+// transferring, which only the Token-2022 program can do, so deposits only
+// move along with receipt tokens.
+fn check_receipt_transferring(source_info: &AccountInfo) -> ProgramResult {
+    let data = source_info.try_borrow_data()?;
+    let token_account = StateWithExtensions::<ReceiptTokenAccount>::unpack(&data)?;
+    let extension = token_account.get_extension::<TransferHookAccount>()?;
+
+    if !bool::from(&extension.transferring) {
+        return Err(PaladinRewardsError::ReceiptNotTransferring.into());
+    }
+
+    Ok(())
+}
+
+// Send the rewards to the holder's token account.
+fn send_rewards(
+    holder_rewards_pool_info: AccountInfo,
+    owner: AccountInfo,
+    pool_state: &mut HolderRewardsPool,
+    holder_rewards_state: &mut HolderRewards,
+    rewards_to_harvest: u64,
+) -> ProgramResult {
+    pool_state.total_rewards_harvested = pool_state
+        .total_rewards_harvested
+        .checked_add(rewards_to_harvest)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    holder_rewards_state.total_rewards_harvested = holder_rewards_state
+        .total_rewards_harvested
+        .checked_add(rewards_to_harvest)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // Move the amount from the holder rewards pool to the token account.
+    let new_holder_rewards_pool_lamports = holder_rewards_pool_info
+        .lamports()
+        .checked_sub(rewards_to_harvest)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let new_token_account_lamports = owner
+        .lamports()
+        .checked_add(rewards_to_harvest)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    **holder_rewards_pool_info.try_borrow_mut_lamports()? = new_holder_rewards_pool_lamports;
+    **owner.try_borrow_mut_lamports()? = new_token_account_lamports;
+    pool_state.lamports_last = new_holder_rewards_pool_lamports;
+
+    PaladinRewardsEvent::Harvested {
+        holder_rewards_pool: *holder_rewards_pool_info.key,
+        owner: *owner.key,
+        amount: rewards_to_harvest,
+    }
+    .emit();
+
+    Ok(())
+}
+
+// Check that duna document is signed
+pub(crate) fn check_duna_document_signed(
+    signer: &Pubkey,
+    doc_pda: &AccountInfo,
+    doc_hash: &[u8; 32],
+) -> ProgramResult {
+    let (duna_document_pda, _) = find_duna_document_pda(signer, doc_hash);
+
+    // Check the duna document PDA is correct.
+    if doc_pda.key != &duna_document_pda {
+        return Err(PaladinRewardsError::InvalidDunaPdaSeeds.into());
+    }
+
+    // Ensure the duna document PDA is initialized.
+    let duna_document_data = doc_pda.try_borrow_data()?;
+
+    if duna_document_data.first() != Some(&1) {
+        return Err(PaladinRewardsError::DunaDocumentNotInitialized.into());
+    }
+
+    Ok(())
+}
+
+/// How an instruction expects one of its accounts to be provided, mirroring
+/// the `#[account(...)]` declarations on
+/// [PaladinRewardsInstruction](enum.PaladinRewardsInstruction.html).
+#[derive(Clone, Copy, Debug)]
+enum ExpectedAccount {
+    Readonly,
+    Writable,
+    Signer,
+    WritableSigner,
+    /// A signer that owns a holder rewards account, reported with
+    /// [PaladinRewardsError::OwnerNotSigner].
+    OwnerSigner,
+    WritableOwnerSigner,
+    TokenProgram,
+    Token2022Program,
+    SystemProgram,
+}
+
+impl ExpectedAccount {
+    fn is_writable(self) -> bool {
+        matches!(
+            self,
+            Self::Writable | Self::WritableSigner | Self::WritableOwnerSigner
+        )
+    }
+
+    fn is_signer(self) -> bool {
+        matches!(
+            self,
+            Self::Signer | Self::WritableSigner | Self::OwnerSigner | Self::WritableOwnerSigner
+        )
+    }
+
+    fn is_owner(self) -> bool {
+        matches!(self, Self::OwnerSigner | Self::WritableOwnerSigner)
+    }
+}
+
+fn expected_accounts(instruction: &PaladinRewardsInstruction) -> &'static [ExpectedAccount] {
+    use ExpectedAccount::*;
+
+    match instruction {
+        PaladinRewardsInstruction::InitializeHolderRewardsPool { .. } => &[
+            Writable,       // Holder rewards pool.
+            Readonly,       // Holder rewards pool token account.
+            Readonly,       // Mint.
+            Readonly,       // Stake vault PDA.
+            Writable,       // Vault holder rewards.
+            Signer,         // Pool authority.
+            WritableSigner, // Payer.
+            SystemProgram,  // System program.
+        ],
+        PaladinRewardsInstruction::InitializeHolderRewards => &[
+            Writable,            // Holder rewards pool.
+            Readonly,            // Holder rewards pool token account.
+            WritableOwnerSigner, // Owner.
+            Writable,            // Holder rewards.
+            Readonly,            // Mint.
+            Readonly,            // DUNA document PDA.
+            WritableSigner,      // Payer.
+            SystemProgram,       // System program.
+        ],
+        PaladinRewardsInstruction::HarvestRewards => &[
+            Writable,            // Holder rewards pool.
+            Readonly,            // Holder rewards pool token account.
+            Writable,            // Holder rewards.
+            Readonly,            // Mint.
+            WritableOwnerSigner, // Owner.
+        ],
+        PaladinRewardsInstruction::CloseHolderRewards => &[
+            Writable,            // Holder rewards pool.
+            Writable,            // Holder rewards pool token account.
+            Writable,            // Holder rewards.
+            Readonly,            // Mint.
+            WritableOwnerSigner, // Owner.
+            Writable,            // Rent payer.
+        ],
+        PaladinRewardsInstruction::Deposit { .. }
+        | PaladinRewardsInstruction::Withdraw { .. }
+        | PaladinRewardsInstruction::EmergencyWithdraw { .. } => {
+            &[
+                Writable,            // Holder rewards pool.
+                Writable,            // Holder rewards pool token account.
+                Writable,            // Holder rewards.
+                Writable,            // Token account.
+                Readonly,            // Mint.
+                WritableOwnerSigner, // Owner.
+                TokenProgram,        // Token program.
+            ]
+        }
+        PaladinRewardsInstruction::RecoverExcessTokens => &[
+            Readonly,     // Holder rewards pool.
+            Writable,     // Holder rewards pool token account.
+            Writable,     // Destination token account.
+            Readonly,     // Mint.
+            Signer,       // Pool authority.
+            TokenProgram, // Token program.
+        ],
+        PaladinRewardsInstruction::GetPendingRewards => &[
+            Readonly, // Holder rewards pool.
+            Readonly, // Holder rewards.
+            Readonly, // Mint.
+            Readonly, // Owner.
+        ],
+        PaladinRewardsInstruction::MigrateHolderRewardsPool => &[
+            Writable,       // Holder rewards pool.
+            Readonly,       // Holder rewards pool token account.
+            Readonly,       // Mint.
+            Signer,         // Pool authority.
+            WritableSigner, // Payer.
+            SystemProgram,  // System program.
+        ],
+        PaladinRewardsInstruction::InitializeRateHistory => &[
+            Writable,       // Holder rewards pool.
+            Writable,       // Rate history.
+            Readonly,       // Mint.
+            WritableSigner, // Payer.
+            SystemProgram,  // System program.
+        ],
+        PaladinRewardsInstruction::SetLockupTiers { .. } => &[
+            Readonly,       // Holder rewards pool.
+            Writable,       // Lockup tiers.
+            Readonly,       // Mint.
+            Signer,         // Pool authority.
+            WritableSigner, // Payer.
+            SystemProgram,  // System program.
+        ],
+        PaladinRewardsInstruction::Lock { .. } => &[
+            Writable,            // Holder rewards pool.
+            Writable,            // Holder rewards.
+            Readonly,            // Lockup tiers.
+            Readonly,            // Mint.
+            WritableOwnerSigner, // Owner.
+        ],
+        PaladinRewardsInstruction::ReleaseLockup => &[
+            Writable, // Holder rewards pool.
+            Writable, // Holder rewards.
+            Readonly, // Mint.
+            Writable, // Owner.
+        ],
+        PaladinRewardsInstruction::InitializeVoteEscrow { .. } => &[
+            Readonly,       // Holder rewards pool.
+            Writable,       // Lockup tiers.
+            Writable,       // Vote escrow.
+            Readonly,       // Mint.
+            Signer,         // Pool authority.
+            WritableSigner, // Payer.
+            SystemProgram,  // System program.
+        ],
+        PaladinRewardsInstruction::CheckpointVotingPower => &[
+            Writable, // Holder rewards pool.
+            Writable, // Holder rewards.
+            Writable, // Vote escrow.
+            Readonly, // Mint.
+            Writable, // Owner.
+        ],
+        PaladinRewardsInstruction::GetVotingPower => &[
+            Readonly, // Holder rewards pool.
+            Readonly, // Vote escrow.
+            Readonly, // Holder rewards.
+            Readonly, // Mint.
+            Readonly, // Owner.
+        ],
+        PaladinRewardsInstruction::InitializeStakeCheckpoints => &[
+            Writable,       // Holder rewards.
+            Writable,       // Stake checkpoints.
+            OwnerSigner,    // Owner.
+            WritableSigner, // Payer.
+            SystemProgram,  // System program.
+        ],
+        PaladinRewardsInstruction::GetStakeAt { .. } => &[
+            Readonly, // Stake checkpoints.
+            Readonly, // Owner.
+        ],
+        PaladinRewardsInstruction::SetEarlyWithdrawalPenalty { .. }
+        | PaladinRewardsInstruction::SetDepositLimits { .. }
+        | PaladinRewardsInstruction::SetAccessList { .. } => &[
+            Writable, // Holder rewards pool.
+            Readonly, // Mint.
+            Signer,   // Pool authority.
+        ],
+        PaladinRewardsInstruction::SetAccessEntry { .. } => &[
+            Readonly,       // Holder rewards pool.
+            Writable,       // Access entry.
+            Readonly,       // Wallet.
+            Readonly,       // Mint.
+            Signer,         // Pool authority.
+            WritableSigner, // Payer.
+            SystemProgram,  // System program.
+        ],
+        PaladinRewardsInstruction::InitializeReceiptMint => &[
+            Writable,         // Holder rewards pool.
+            Writable,         // Receipt mint.
+            Writable,         // Extra account metas.
+            Readonly,         // Mint.
+            Signer,           // Pool authority.
+            WritableSigner,   // Payer.
+            SystemProgram,    // System program.
+            Token2022Program, // Token-2022 program.
+        ],
+        PaladinRewardsInstruction::SetSlashingConfig => &[
+            Writable, // Holder rewards pool.
+            Readonly, // Mint.
+            Signer,   // Pool authority.
+            Readonly, // Slashing authority.
+            Readonly, // Slash destination.
+        ],
+        PaladinRewardsInstruction::Slash { .. } => &[
+            Writable,     // Holder rewards pool.
+            Writable,     // Holder rewards pool token account.
+            Writable,     // Holder rewards.
+            Writable,     // Slash destination.
+            Readonly,     // Mint.
+            Readonly,     // Owner.
+            Signer,       // Slashing authority.
+            TokenProgram, // Token program.
+        ],
+        PaladinRewardsInstruction::CreateCampaign { .. } => &[
+            Readonly,       // Holder rewards pool.
+            Writable,       // Campaign.
+            Readonly,       // Mint.
+            WritableSigner, // Funder.
+            SystemProgram,  // System program.
+        ],
+        PaladinRewardsInstruction::ReleaseCampaignRewards => &[
+            Writable, // Holder rewards pool.
+            Writable, // Campaign.
+            Readonly, // Mint.
+        ],
+        PaladinRewardsInstruction::ReclaimCampaignRewards => &[
+            Writable,       // Holder rewards pool.
+            Writable,       // Campaign.
+            Readonly,       // Mint.
+            WritableSigner, // Funder.
+        ],
+        PaladinRewardsInstruction::DistributeRewards { .. } => &[
+            Writable,       // Holder rewards pool.
+            Readonly,       // Mint.
+            WritableSigner, // Funder.
+            SystemProgram,  // System program.
+        ],
+        PaladinRewardsInstruction::InitializeEpochLedger => &[
+            Writable,       // Holder rewards pool.
+            Writable,       // Epoch ledger.
+            Readonly,       // Mint.
+            WritableSigner, // Payer.
+            SystemProgram,  // System program.
+        ],
+        PaladinRewardsInstruction::MigrateHolderRewards => &[
+            Writable,       // Holder rewards.
+            Readonly,       // Owner.
+            WritableSigner, // Payer.
+            SystemProgram,  // System program.
+        ],
+    }
+}
+
+// Check the provided accounts against the instruction's account
+// declarations: writability, signers and program IDs.
+fn validate_accounts(
+    instruction: &PaladinRewardsInstruction,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let expected_accounts = expected_accounts(instruction);
+
+    if accounts.len() < expected_accounts.len() {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    for (index, (expected, account_info)) in expected_accounts.iter().zip(accounts).enumerate() {
+        if expected.is_writable() && !account_info.is_writable {
+            msg!("Account {} must be writable", index);
+            return Err(PaladinRewardsError::AccountNotWritable.into());
+        }
+
+        if expected.is_signer() && !account_info.is_signer {
+            msg!("Account {} must be a signer", index);
+            if expected.is_owner() {
+                return Err(PaladinRewardsError::OwnerNotSigner.into());
+            }
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        match expected {
+            ExpectedAccount::TokenProgram if account_info.key != &spl_token::ID => {
+                msg!("Account {} must be the SPL Token program", index);
+                return Err(PaladinRewardsError::IncorrectTokenProgram.into());
+            }
+            ExpectedAccount::Token2022Program if account_info.key != &spl_token_2022::ID => {
+                msg!("Account {} must be the SPL Token-2022 program", index);
+                return Err(PaladinRewardsError::IncorrectTokenProgram.into());
+            }
+            ExpectedAccount::SystemProgram if account_info.key != &system_program::ID => {
+                msg!("Account {} must be the System program", index);
+                return Err(PaladinRewardsError::IncorrectSystemProgram.into());
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Processes an
+/// [InitializeHolderRewardsPool](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_initialize_holder_rewards_pool(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
     duna_document_hash: [u8; 32],
 ) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
@@ -312,17 +1560,17 @@ fn process_initialize_holder_rewards_pool(
     let mint_info = next_account_info(accounts_iter)?;
     let vault_pda_info = next_account_info(accounts_iter)?;
     let vault_holder_rewards_info = next_account_info(accounts_iter)?;
+    let authority_info = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter)?;
     let _system_program_info = next_account_info(accounts_iter)?;
 
     // Run checks on the mint.
-    assert_eq!(mint_info.owner, &spl_token::ID);
-    let mint_data = mint_info.try_borrow_data()?;
-    Mint::unpack(&mint_data)?;
+    check_mint_authority(mint_info, authority_info)?;
 
     // Validate pool token account
     validate_token_account(
         holder_rewards_pool_token_account_info,
-        holder_rewards_pool_info.key,
+        Some(holder_rewards_pool_info.key),
         mint_info.key,
     )?;
 
@@ -346,21 +1594,14 @@ fn process_initialize_holder_rewards_pool(
             return Err(ProgramError::AccountAlreadyInitialized);
         }
 
-        // Allocate & assign.
-        invoke_signed(
-            &system_instruction::allocate(
-                &holder_rewards_pool_address,
-                HolderRewardsPool::LEN as u64,
-            ),
-            &[holder_rewards_pool_info.clone()],
-            &[&holder_rewards_pool_signer_seeds],
+        // Fund, allocate & assign.
+        create_pda_account(
+            program_id,
+            payer_info,
+            holder_rewards_pool_info,
+            HolderRewardsPool::LEN,
+            &holder_rewards_pool_signer_seeds,
         )?;
-        invoke_signed(
-            &system_instruction::assign(&holder_rewards_pool_address, program_id),
-            &[holder_rewards_pool_info.clone()],
-            &[&holder_rewards_pool_signer_seeds],
-        )?;
-        assert_rent_exempt(holder_rewards_pool_info);
 
         // Write the data.
         let mut data = holder_rewards_pool_info.try_borrow_mut_data()?;
@@ -369,9 +1610,39 @@ fn process_initialize_holder_rewards_pool(
                 accumulated_rewards_per_token: 0,
                 lamports_last: holder_rewards_pool_info.lamports(),
                 duna_document_hash,
-                _padding: 0,
+                total_deposited: 0,
+                authority: *authority_info.key,
+                bump_seed: bump_seed[0],
+                access_list: ACCESS_LIST_NONE,
+                early_withdrawal_penalty_bps: 0,
+                early_withdrawal_period_slots: 0,
+                last_update_slot: Clock::get()?.slot,
+                total_rewards_received: 0,
+                total_rewards_harvested: 0,
+                // The stake program vault holder is initialized below.
+                active_holder_count: 1,
+                total_lockup_bonus_weight: 0,
+                max_total_deposit: 0,
+                max_holder_deposit: 0,
+                min_deposit: 0,
+                receipts: 0,
+                receipt_mint_bump_seed: 0,
+                rate_history: 0,
+                epoch_ledger: 0,
+                _padding: [0; 4],
+                slashing_authority: Pubkey::default(),
+                slash_destination: Pubkey::default(),
+                total_slashed: 0,
+                zero_weight_slots: 0,
             };
-    }
+
+        PaladinRewardsEvent::PoolInitialized {
+            holder_rewards_pool: *holder_rewards_pool_info.key,
+            mint: *mint_info.key,
+            authority: *authority_info.key,
+        }
+        .emit();
+    }
 
     // Initialize stake program vault holder rewards account
     {
@@ -392,18 +1663,14 @@ fn process_initialize_holder_rewards_pool(
             return Err(ProgramError::AccountAlreadyInitialized);
         }
 
-        // Allocate & assign.
-        invoke_signed(
-            &system_instruction::allocate(&holder_rewards_address, HolderRewards::LEN as u64),
-            &[vault_holder_rewards_info.clone()],
-            &[&holder_rewards_signer_seeds],
+        // Fund, allocate & assign.
+        create_pda_account(
+            program_id,
+            payer_info,
+            vault_holder_rewards_info,
+            HolderRewards::LEN,
+            &holder_rewards_signer_seeds,
         )?;
-        invoke_signed(
-            &system_instruction::assign(&holder_rewards_address, program_id),
-            &[vault_holder_rewards_info.clone()],
-            &[&holder_rewards_signer_seeds],
-        )?;
-        assert_rent_exempt(vault_holder_rewards_info);
 
         // Write the data.
         let mut data = vault_holder_rewards_info.try_borrow_mut_data()?;
@@ -411,467 +1678,3003 @@ fn process_initialize_holder_rewards_pool(
             HolderRewards {
                 last_accumulated_rewards_per_token: 0,
                 deposited: 0,
-                _padding: 0,
+                rent_payer: *payer_info.key,
+                bump_seed: bump_seed[0],
+                stake_checkpoints: 0,
+                _padding: [0; 6],
+                total_rewards_harvested: 0,
+                first_deposit_slot: 0,
+                last_activity_slot: 0,
+                cumulative_deposited: 0,
+                cumulative_withdrawn: 0,
+                locked_amount: 0,
+                unlock_timestamp: 0,
+                lockup_multiplier_bps: 0,
+                voting_power_locked: 0,
+                voting_power_end: 0,
+                last_deposit_slot: 0,
+                early_withdrawal_penalties: 0,
+                unharvested_rewards: 0,
+                slashed: 0,
+            };
+
+        PaladinRewardsEvent::HolderInitialized {
+            holder_rewards_pool: *holder_rewards_pool_info.key,
+            owner: *vault_pda_info.key,
+            last_accumulated_rewards_per_token: 0,
+        }
+        .emit();
+    }
+    Ok(())
+}
+
+/// Processes an
+/// [InitializeHolderRewards](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_initialize_holder_rewards(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let holder_rewards_pool_token_account_info = next_account_info(accounts_iter)?;
+    let owner = next_account_info(accounts_iter)?;
+    let holder_rewards_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let duna_document_info = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter)?;
+    let _system_program = next_account_info(accounts_iter)?;
+    let rate_history_info = next_optional_account_info(program_id, accounts_iter);
+    let access_entry_info = next_optional_account_info(program_id, accounts_iter);
+    let epoch_ledger_info = next_optional_account_info(program_id, accounts_iter);
+
+    validate_token_account(
+        holder_rewards_pool_token_account_info,
+        Some(holder_rewards_pool_info.key),
+        mint_info.key,
+    )?;
+
+    // Run checks on the pool account.
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_state = bytemuck::try_from_bytes_mut::<HolderRewardsPool>(&mut pool_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Check duna is signed
+    check_duna_document_signed(
+        owner.key,
+        duna_document_info,
+        &pool_state.duna_document_hash,
+    )?;
+
+    check_access_list(
+        program_id,
+        holder_rewards_pool_info.key,
+        pool_state,
+        owner.key,
+        access_entry_info,
+    )?;
+
+    // Process any received lamports.
+    update_accumulated_rewards_per_token(
+        program_id,
+        holder_rewards_pool_info,
+        pool_state,
+        rate_history_info,
+        epoch_ledger_info,
+    )?;
+
+    // Initialize the holder rewards account.
+    {
+        let (holder_rewards_address, bump_seed) =
+            get_holder_rewards_address_and_bump_seed(owner.key, program_id);
+        let bump_seed = [bump_seed];
+        let holder_rewards_signer_seeds =
+            collect_holder_rewards_signer_seeds(owner.key, &bump_seed);
+
+        // Ensure the provided holder rewards address is the correct address
+        // derived from the token account.
+        if holder_rewards_info.key != &holder_rewards_address {
+            return Err(PaladinRewardsError::IncorrectHolderRewardsAddress.into());
+        }
+
+        // Ensure the holder rewards account has not already been initialized.
+        if holder_rewards_info.data.borrow().len() != 0 {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        // Fund, allocate & assign.
+        create_pda_account(
+            program_id,
+            payer_info,
+            holder_rewards_info,
+            HolderRewards::LEN,
+            &holder_rewards_signer_seeds,
+        )?;
+
+        // Write the data.
+        let mut data = holder_rewards_info.try_borrow_mut_data()?;
+        *bytemuck::try_from_bytes_mut(&mut data).map_err(|_| ProgramError::InvalidAccountData)? =
+            HolderRewards {
+                last_accumulated_rewards_per_token: pool_state.accumulated_rewards_per_token,
+                deposited: 0,
+                rent_payer: *payer_info.key,
+                bump_seed: bump_seed[0],
+                stake_checkpoints: 0,
+                _padding: [0; 6],
+                total_rewards_harvested: 0,
+                first_deposit_slot: 0,
+                last_activity_slot: 0,
+                cumulative_deposited: 0,
+                cumulative_withdrawn: 0,
+                locked_amount: 0,
+                unlock_timestamp: 0,
+                lockup_multiplier_bps: 0,
+                voting_power_locked: 0,
+                voting_power_end: 0,
+                last_deposit_slot: 0,
+                early_withdrawal_penalties: 0,
+                unharvested_rewards: 0,
+                slashed: 0,
             };
+
+        PaladinRewardsEvent::HolderInitialized {
+            holder_rewards_pool: *holder_rewards_pool_info.key,
+            owner: *owner.key,
+            last_accumulated_rewards_per_token: pool_state.accumulated_rewards_per_token,
+        }
+        .emit();
+    }
+
+    pool_state.active_holder_count = pool_state
+        .active_holder_count
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    Ok(())
+}
+
+/// Processes a [HarvestRewards](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_harvest_rewards(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let holder_rewards_pool_token_account_info = next_account_info(accounts_iter)?;
+    let holder_rewards_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let owner = next_account_info(accounts_iter)?;
+    let rate_history_info = next_optional_account_info(program_id, accounts_iter);
+    let epoch_ledger_info = next_optional_account_info(program_id, accounts_iter);
+
+    validate_token_account(
+        holder_rewards_pool_token_account_info,
+        Some(holder_rewards_pool_info.key),
+        mint_info.key,
+    )?;
+
+    // Check & load the pool
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_state = bytemuck::try_from_bytes_mut::<HolderRewardsPool>(&mut pool_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Check & load the holder rewards.
+    check_holder_rewards(program_id, owner.key, holder_rewards_info)?;
+    let mut holder_rewards_data = holder_rewards_info.try_borrow_mut_data()?;
+    let holder_rewards_state =
+        bytemuck::try_from_bytes_mut::<HolderRewards>(&mut holder_rewards_data)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Handle any lamports received since last harvest.
+    update_accumulated_rewards_per_token(
+        program_id,
+        holder_rewards_pool_info,
+        pool_state,
+        rate_history_info,
+        epoch_ledger_info,
+    )?;
+
+    // Determine the amount the holder can harvest.
+    let mut rewards_to_harvest = calculate_rewards_to_harvest(
+        holder_rewards_state,
+        pool_state,
+        holder_rewards_pool_info.lamports(),
+    )?;
+
+    let clock = Clock::get()?;
+    release_expired_lockup(
+        holder_rewards_pool_info.key,
+        owner.key,
+        pool_state,
+        holder_rewards_state,
+        clock.unix_timestamp,
+    )?;
+    holder_rewards_state.last_activity_slot = clock.slot;
+
+    // Rewards harvested within the early withdrawal period are withheld.
+    rewards_to_harvest = withhold_early_rewards(
+        pool_state,
+        holder_rewards_state,
+        rewards_to_harvest,
+        clock.slot,
+    )?;
+
+    if rewards_to_harvest > 0 {
+        send_rewards(
+            holder_rewards_pool_info.clone(),
+            owner.clone(),
+            pool_state,
+            holder_rewards_state,
+            rewards_to_harvest,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Processes a
+/// [CloseHolderRewards](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_close_holder_rewards(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let holder_rewards_pool_token_account_info = next_account_info(accounts_iter)?;
+    let holder_rewards_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let owner = next_account_info(accounts_iter)?;
+    let rent_payer_info = next_account_info(accounts_iter)?;
+    let rate_history_info = next_optional_account_info(program_id, accounts_iter);
+    let epoch_ledger_info = next_optional_account_info(program_id, accounts_iter);
+
+    validate_token_account(
+        holder_rewards_pool_token_account_info,
+        Some(holder_rewards_pool_info.key),
+        mint_info.key,
+    )?;
+
+    // Load pool & holder rewards.
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_state = bytemuck::try_from_bytes_mut::<HolderRewardsPool>(&mut pool_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    check_holder_rewards(program_id, owner.key, holder_rewards_info)?;
+    let holder_rewards_data = holder_rewards_info.try_borrow_data()?;
+    let holder_rewards_state = bytemuck::try_from_bytes::<HolderRewards>(&holder_rewards_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Handle any lamports received since last harvest.
+    update_accumulated_rewards_per_token(
+        program_id,
+        holder_rewards_pool_info,
+        pool_state,
+        rate_history_info,
+        epoch_ledger_info,
+    )?;
+
+    // Ensure holder has no unclaimed rewards.
+    if holder_rewards_state.last_accumulated_rewards_per_token
+        < pool_state.accumulated_rewards_per_token
+        || holder_rewards_state.unharvested_rewards > 0
+    {
+        return Err(PaladinRewardsError::CloseWithUnclaimedRewards.into());
+    }
+
+    // Ensure holder withdrew all tokens
+    if holder_rewards_state.deposited > 0 {
+        return Err(PaladinRewardsError::CloseWithDepositedTokens.into());
+    }
+
+    // Ensure the rent is returned to the account that paid it.
+    if rent_payer_info.key != &holder_rewards_state.rent_payer {
+        return Err(PaladinRewardsError::IncorrectRentPayer.into());
+    }
+
+    drop(holder_rewards_data);
+
+    // NB: If this overflows then the runtime will catch it.
+    #[allow(clippy::arithmetic_side_effects)]
+    {
+        **rent_payer_info.lamports.borrow_mut() += holder_rewards_info.lamports();
+    }
+
+    // Close the account.
+    **holder_rewards_info.lamports.borrow_mut() = 0;
+    holder_rewards_info.realloc(0, true)?;
+    holder_rewards_info.assign(&system_program::ID);
+
+    // Holders opened before the pool was migrated were never counted.
+    pool_state.active_holder_count = pool_state.active_holder_count.saturating_sub(1);
+
+    PaladinRewardsEvent::HolderClosed {
+        holder_rewards_pool: *holder_rewards_pool_info.key,
+        owner: *owner.key,
+    }
+    .emit();
+
+    Ok(())
+}
+
+/// Processes a [Deposit](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_deposit(program_id: &Pubkey, accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let holder_rewards_pool_token_account_info = next_account_info(accounts_iter)?;
+    let holder_rewards_info = next_account_info(accounts_iter)?;
+    let token_account_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let owner = next_account_info(accounts_iter)?;
+    let token_program = next_account_info(accounts_iter)?;
+    let rate_history_info = next_optional_account_info(program_id, accounts_iter);
+    let stake_checkpoints_info = next_optional_account_info(program_id, accounts_iter);
+    let access_entry_info = next_optional_account_info(program_id, accounts_iter);
+    let receipt_mint_info = next_optional_account_info(program_id, accounts_iter);
+    let receipt_token_account_info = next_optional_account_info(program_id, accounts_iter);
+    let receipt_token_program_info = next_optional_account_info(program_id, accounts_iter);
+    let epoch_ledger_info = next_optional_account_info(program_id, accounts_iter);
+
+    // Validate pool token account.
+    validate_token_account(
+        holder_rewards_pool_token_account_info,
+        Some(holder_rewards_pool_info.key),
+        mint_info.key,
+    )?;
+
+    // Validate user has enough tokens to deposit. Reading the balance
+    // checks the token account's program and mint.
+    let owner_balance = get_token_account_balance_checked(mint_info.key, token_account_info)?;
+    if owner_balance < amount {
+        return Err(PaladinRewardsError::NotEnoughTokenToDeposit.into());
+    }
+
+    // Validate the owner token account.
+    validate_token_account(token_account_info, Some(owner.key), mint_info.key)?;
+
+    // Load pool & holder rewards.
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_state = bytemuck::try_from_bytes_mut::<HolderRewardsPool>(&mut pool_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    check_holder_rewards(program_id, owner.key, holder_rewards_info)?;
+    let mut holder_rewards_data = holder_rewards_info.try_borrow_mut_data()?;
+    let holder_rewards_state =
+        bytemuck::try_from_bytes_mut::<HolderRewards>(&mut holder_rewards_data)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    check_access_list(
+        program_id,
+        holder_rewards_pool_info.key,
+        pool_state,
+        owner.key,
+        access_entry_info,
+    )?;
+    check_deposit_limits(pool_state, holder_rewards_state, amount)?;
+    let receipt_accounts = check_receipt_accounts(
+        program_id,
+        holder_rewards_pool_info.key,
+        pool_state,
+        receipt_mint_info,
+        receipt_token_account_info,
+        receipt_token_program_info,
+    )?;
+
+    // Handle any lamports received since last harvest.
+    update_accumulated_rewards_per_token(
+        program_id,
+        holder_rewards_pool_info,
+        pool_state,
+        rate_history_info,
+        epoch_ledger_info,
+    )?;
+
+    // Calculate rewards to harvest before new deposit
+    let mut rewards_to_harvest = calculate_rewards_to_harvest(
+        holder_rewards_state,
+        pool_state,
+        holder_rewards_pool_info.lamports(),
+    )?;
+
+    let clock = Clock::get()?;
+    release_expired_lockup(
+        holder_rewards_pool_info.key,
+        owner.key,
+        pool_state,
+        holder_rewards_state,
+        clock.unix_timestamp,
+    )?;
+
+    // Update total deposited tokens
+    holder_rewards_state.deposited = holder_rewards_state
+        .deposited
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    holder_rewards_state.cumulative_deposited = holder_rewards_state
+        .cumulative_deposited
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    pool_state.total_deposited = pool_state
+        .total_deposited
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // Record the holder's activity.
+    if amount > 0 {
+        if holder_rewards_state.first_deposit_slot == 0 {
+            holder_rewards_state.first_deposit_slot = clock.slot;
+        }
+        holder_rewards_state.last_deposit_slot = clock.slot;
+    }
+    holder_rewards_state.last_activity_slot = clock.slot;
+    record_stake_checkpoint(
+        program_id,
+        owner.key,
+        holder_rewards_state,
+        stake_checkpoints_info,
+        clock.slot,
+    )?;
+
+    // Transfer tokens from the owner to the holder rewards pool.
+    let transfer_ix = transfer(
+        &spl_token::ID,
+        token_account_info.key,
+        holder_rewards_pool_token_account_info.key,
+        owner.key,
+        &[owner.key],
+        amount,
+    )?;
+
+    invoke(
+        &transfer_ix,
+        &[
+            token_account_info.clone(),
+            holder_rewards_pool_token_account_info.clone(),
+            owner.clone(),
+            token_program.clone(),
+        ],
+    )?;
+
+    PaladinRewardsEvent::Deposited {
+        holder_rewards_pool: *holder_rewards_pool_info.key,
+        owner: *owner.key,
+        amount,
+        deposited: holder_rewards_state.deposited,
+    }
+    .emit();
+
+    // Rewards harvested within the early withdrawal period are withheld.
+    rewards_to_harvest = withhold_early_rewards(
+        pool_state,
+        holder_rewards_state,
+        rewards_to_harvest,
+        clock.slot,
+    )?;
+
+    // Send rewards to the owner
+    if rewards_to_harvest > 0 {
+        send_rewards(
+            holder_rewards_pool_info.clone(),
+            owner.clone(),
+            pool_state,
+            holder_rewards_state,
+            rewards_to_harvest,
+        )?;
+    }
+
+    // Mint receipt tokens for the deposit, signed by the pool as the receipt
+    // mint authority.
+    if let Some([receipt_mint_info, receipt_token_account_info, receipt_token_program_info]) =
+        receipt_accounts
+    {
+        let bump_seed = [pool_state.bump_seed];
+        let holder_rewards_pool_signer_seeds =
+            collect_holder_rewards_pool_signer_seeds(mint_info.key, &bump_seed);
+
+        let mint_to_ix = mint_to(
+            &spl_token_2022::ID,
+            receipt_mint_info.key,
+            receipt_token_account_info.key,
+            holder_rewards_pool_info.key,
+            &[],
+            amount,
+        )?;
+
+        drop(pool_data);
+        invoke_signed(
+            &mint_to_ix,
+            &[
+                receipt_mint_info.clone(),
+                receipt_token_account_info.clone(),
+                holder_rewards_pool_info.clone(),
+                receipt_token_program_info.clone(),
+            ],
+            &[&holder_rewards_pool_signer_seeds],
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Processes a [Withdraw](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_withdraw(program_id: &Pubkey, accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let holder_rewards_pool_token_account_info = next_account_info(accounts_iter)?;
+    let holder_rewards_info = next_account_info(accounts_iter)?;
+    let token_account_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let owner = next_account_info(accounts_iter)?;
+    let token_program = next_account_info(accounts_iter)?;
+    let rate_history_info = next_optional_account_info(program_id, accounts_iter);
+    let stake_checkpoints_info = next_optional_account_info(program_id, accounts_iter);
+    let receipt_mint_info = next_optional_account_info(program_id, accounts_iter);
+    let receipt_token_account_info = next_optional_account_info(program_id, accounts_iter);
+    let receipt_token_program_info = next_optional_account_info(program_id, accounts_iter);
+    let destination_token_account_info = next_optional_account_info(program_id, accounts_iter);
+    let epoch_ledger_info = next_optional_account_info(program_id, accounts_iter);
+
+    // Validate pool token account
+    validate_token_account(
+        holder_rewards_pool_token_account_info,
+        Some(holder_rewards_pool_info.key),
+        mint_info.key,
+    )?;
+
+    // The withdrawn tokens may go to any token account for the mint instead
+    // of the owner's, which is then left unused and need not exist.
+    let redirected = destination_token_account_info.is_some();
+    let destination_token_account_info = match destination_token_account_info {
+        Some(destination_token_account_info) => {
+            validate_token_account(destination_token_account_info, None, mint_info.key)?;
+            destination_token_account_info
+        }
+        None => {
+            validate_token_account(token_account_info, Some(owner.key), mint_info.key)?;
+            token_account_info
+        }
+    };
+
+    // Load pool & holder rewards.
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_state = bytemuck::try_from_bytes_mut::<HolderRewardsPool>(&mut pool_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    check_holder_rewards(program_id, owner.key, holder_rewards_info)?;
+    let mut holder_rewards_data = holder_rewards_info.try_borrow_mut_data()?;
+    let holder_rewards_state =
+        bytemuck::try_from_bytes_mut::<HolderRewards>(&mut holder_rewards_data)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    let receipt_accounts = check_receipt_accounts(
+        program_id,
+        holder_rewards_pool_info.key,
+        pool_state,
+        receipt_mint_info,
+        receipt_token_account_info,
+        receipt_token_program_info,
+    )?;
+
+    // Validate that we have enough deposited tokens to withdraw
+    let to_withdraw = if amount == u64::MAX {
+        holder_rewards_state.deposited
+    } else {
+        amount
+    };
+
+    if holder_rewards_state.deposited == 0 {
+        return Err(PaladinRewardsError::NoDepositedTokensToWithdraw.into());
+    } else if holder_rewards_state.deposited > pool_state.total_deposited {
+        return Err(PaladinRewardsError::WithdrawExceedsPoolBalance.into());
+    } else if to_withdraw > holder_rewards_state.deposited {
+        return Err(PaladinRewardsError::WithdrawExceedsDeposited.into());
+    }
+
+    // Handle any lamports received since last harvest.
+    update_accumulated_rewards_per_token(
+        program_id,
+        holder_rewards_pool_info,
+        pool_state,
+        rate_history_info,
+        epoch_ledger_info,
+    )?;
+
+    // Calculate rewards to harvest before withdrawal
+    let mut rewards_to_harvest = match calculate_rewards_to_harvest(
+        holder_rewards_state,
+        pool_state,
+        holder_rewards_pool_info.lamports(),
+    ) {
+        Ok(rewards) => Ok(rewards),
+        Err(ProgramError::Custom(err)) => {
+            // If the pool does not have enough lamports to cover the rewards,
+            // they are settled for a later harvest so the withdrawal can
+            // proceed.
+            if err == PaladinRewardsError::RewardsExcessPoolBalance as u32 {
+                settle_rewards(pool_state, holder_rewards_state)?;
+                Ok(0)
+            } else {
+                return Err(ProgramError::Custom(err));
+            }
+        }
+        Err(err) => Err(err),
+    }?;
+
+    let clock = Clock::get()?;
+    release_expired_lockup(
+        holder_rewards_pool_info.key,
+        owner.key,
+        pool_state,
+        holder_rewards_state,
+        clock.unix_timestamp,
+    )?;
+
+    // Locked tokens cannot be withdrawn until the lockup expires.
+    let unlocked = holder_rewards_state
+        .deposited
+        .saturating_sub(holder_rewards_state.locked_amount);
+    if to_withdraw > unlocked {
+        return Err(PaladinRewardsError::WithdrawExceedsUnlocked.into());
+    }
+
+    // Update total deposited tokens
+    holder_rewards_state.deposited = holder_rewards_state
+        .deposited
+        .checked_sub(to_withdraw)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    holder_rewards_state.cumulative_withdrawn = holder_rewards_state
+        .cumulative_withdrawn
+        .checked_add(to_withdraw)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    pool_state.total_deposited = pool_state
+        .total_deposited
+        .checked_sub(to_withdraw)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    holder_rewards_state.last_activity_slot = clock.slot;
+    record_stake_checkpoint(
+        program_id,
+        owner.key,
+        holder_rewards_state,
+        stake_checkpoints_info,
+        clock.slot,
+    )?;
+
+    // Forfeit part of the rewards if withdrawing early.
+    rewards_to_harvest = apply_early_withdrawal_penalty(
+        program_id,
+        holder_rewards_pool_info,
+        owner.key,
+        pool_state,
+        holder_rewards_state,
+        rewards_to_harvest,
+        rate_history_info,
+        epoch_ledger_info,
+        &clock,
+    )?;
+
+    // Get pool token account signer seeds.
+    let bump_seed = [pool_state.bump_seed];
+    let holder_rewards_pool_signer_seeds =
+        collect_holder_rewards_pool_signer_seeds(mint_info.key, &bump_seed);
+
+    // Transfer tokens from the pool to the destination.
+    let transfer_ix = transfer(
+        &spl_token::ID,
+        holder_rewards_pool_token_account_info.key,
+        destination_token_account_info.key,
+        holder_rewards_pool_info.key,
+        &[holder_rewards_pool_info.key],
+        to_withdraw,
+    )?;
+
+    drop(pool_data);
+    invoke_signed(
+        &transfer_ix,
+        &[
+            holder_rewards_pool_token_account_info.clone(),
+            destination_token_account_info.clone(),
+            holder_rewards_pool_info.clone(),
+            token_program.clone(),
+        ],
+        &[&holder_rewards_pool_signer_seeds],
+    )?;
+
+    // Burn the receipt tokens for the withdrawal.
+    if let Some([receipt_mint_info, receipt_token_account_info, receipt_token_program_info]) =
+        receipt_accounts
+    {
+        let burn_ix = burn(
+            &spl_token_2022::ID,
+            receipt_token_account_info.key,
+            receipt_mint_info.key,
+            owner.key,
+            &[],
+            to_withdraw,
+        )?;
+
+        invoke(
+            &burn_ix,
+            &[
+                receipt_token_account_info.clone(),
+                receipt_mint_info.clone(),
+                owner.clone(),
+                receipt_token_program_info.clone(),
+            ],
+        )?;
+    }
+
+    PaladinRewardsEvent::Withdrawn {
+        holder_rewards_pool: *holder_rewards_pool_info.key,
+        owner: *owner.key,
+        amount: to_withdraw,
+        deposited: holder_rewards_state.deposited,
+    }
+    .emit();
+    if redirected {
+        PaladinRewardsEvent::WithdrawalRedirected {
+            holder_rewards_pool: *holder_rewards_pool_info.key,
+            owner: *owner.key,
+            destination: *destination_token_account_info.key,
+            amount: to_withdraw,
+        }
+        .emit();
+    }
+
+    // re-borrow the pool data to use in `send_rewards`
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_state = bytemuck::try_from_bytes_mut::<HolderRewardsPool>(&mut pool_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Send rewards to the owner
+    if rewards_to_harvest > 0 {
+        send_rewards(
+            holder_rewards_pool_info.clone(),
+            owner.clone(),
+            pool_state,
+            holder_rewards_state,
+            rewards_to_harvest,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Processes an
+/// [EmergencyWithdraw](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_emergency_withdraw(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let holder_rewards_pool_token_account_info = next_account_info(accounts_iter)?;
+    let holder_rewards_info = next_account_info(accounts_iter)?;
+    let token_account_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let owner = next_account_info(accounts_iter)?;
+    let token_program = next_account_info(accounts_iter)?;
+    let rate_history_info = next_optional_account_info(program_id, accounts_iter);
+    let stake_checkpoints_info = next_optional_account_info(program_id, accounts_iter);
+    let receipt_mint_info = next_optional_account_info(program_id, accounts_iter);
+    let receipt_token_account_info = next_optional_account_info(program_id, accounts_iter);
+    let receipt_token_program_info = next_optional_account_info(program_id, accounts_iter);
+    let epoch_ledger_info = next_optional_account_info(program_id, accounts_iter);
+
+    // Validate pool token account
+    validate_token_account(
+        holder_rewards_pool_token_account_info,
+        Some(holder_rewards_pool_info.key),
+        mint_info.key,
+    )?;
+
+    // Validate the owner token account
+    validate_token_account(token_account_info, Some(owner.key), mint_info.key)?;
+
+    // Load pool & holder rewards.
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_state = bytemuck::try_from_bytes_mut::<HolderRewardsPool>(&mut pool_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    check_holder_rewards(program_id, owner.key, holder_rewards_info)?;
+    let mut holder_rewards_data = holder_rewards_info.try_borrow_mut_data()?;
+    let holder_rewards_state =
+        bytemuck::try_from_bytes_mut::<HolderRewards>(&mut holder_rewards_data)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    let receipt_accounts = check_receipt_accounts(
+        program_id,
+        holder_rewards_pool_info.key,
+        pool_state,
+        receipt_mint_info,
+        receipt_token_account_info,
+        receipt_token_program_info,
+    )?;
+
+    // Validate that we have enough deposited tokens to withdraw
+    let to_withdraw = if amount == u64::MAX {
+        holder_rewards_state.deposited
+    } else {
+        amount
+    };
+
+    if holder_rewards_state.deposited == 0 {
+        return Err(PaladinRewardsError::NoDepositedTokensToWithdraw.into());
+    } else if holder_rewards_state.deposited > pool_state.total_deposited {
+        return Err(PaladinRewardsError::WithdrawExceedsPoolBalance.into());
+    } else if to_withdraw > holder_rewards_state.deposited {
+        return Err(PaladinRewardsError::WithdrawExceedsDeposited.into());
+    }
+
+    // Handle any lamports received since last harvest.
+    update_accumulated_rewards_per_token(
+        program_id,
+        holder_rewards_pool_info,
+        pool_state,
+        rate_history_info,
+        epoch_ledger_info,
+    )?;
+
+    // Record the rewards earned so far as owed, rather than paying them out.
+    settle_rewards(pool_state, holder_rewards_state)?;
+
+    let clock = Clock::get()?;
+    release_expired_lockup(
+        holder_rewards_pool_info.key,
+        owner.key,
+        pool_state,
+        holder_rewards_state,
+        clock.unix_timestamp,
+    )?;
+
+    // Locked tokens cannot be withdrawn until the lockup expires.
+    let unlocked = holder_rewards_state
+        .deposited
+        .saturating_sub(holder_rewards_state.locked_amount);
+    if to_withdraw > unlocked {
+        return Err(PaladinRewardsError::WithdrawExceedsUnlocked.into());
+    }
+
+    // Update total deposited tokens
+    holder_rewards_state.deposited = holder_rewards_state
+        .deposited
+        .checked_sub(to_withdraw)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    holder_rewards_state.cumulative_withdrawn = holder_rewards_state
+        .cumulative_withdrawn
+        .checked_add(to_withdraw)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    pool_state.total_deposited = pool_state
+        .total_deposited
+        .checked_sub(to_withdraw)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    holder_rewards_state.last_activity_slot = clock.slot;
+    record_stake_checkpoint(
+        program_id,
+        owner.key,
+        holder_rewards_state,
+        stake_checkpoints_info,
+        clock.slot,
+    )?;
+
+    // The owed rewards are forfeited as if they were harvested, so an
+    // emergency withdrawal can't avoid the early withdrawal penalty.
+    let owed_rewards = holder_rewards_state.unharvested_rewards;
+    holder_rewards_state.unharvested_rewards = apply_early_withdrawal_penalty(
+        program_id,
+        holder_rewards_pool_info,
+        owner.key,
+        pool_state,
+        holder_rewards_state,
+        owed_rewards,
+        rate_history_info,
+        epoch_ledger_info,
+        &clock,
+    )?;
+
+    // Get pool token account signer seeds.
+    let bump_seed = [pool_state.bump_seed];
+    let holder_rewards_pool_signer_seeds =
+        collect_holder_rewards_pool_signer_seeds(mint_info.key, &bump_seed);
+
+    // Transfer tokens from the pool to the owner.
+    let transfer_ix = transfer(
+        &spl_token::ID,
+        holder_rewards_pool_token_account_info.key,
+        token_account_info.key,
+        holder_rewards_pool_info.key,
+        &[holder_rewards_pool_info.key],
+        to_withdraw,
+    )?;
+
+    drop(pool_data);
+    invoke_signed(
+        &transfer_ix,
+        &[
+            holder_rewards_pool_token_account_info.clone(),
+            token_account_info.clone(),
+            holder_rewards_pool_info.clone(),
+            token_program.clone(),
+        ],
+        &[&holder_rewards_pool_signer_seeds],
+    )?;
+
+    // Burn the receipt tokens for the withdrawal.
+    if let Some([receipt_mint_info, receipt_token_account_info, receipt_token_program_info]) =
+        receipt_accounts
+    {
+        let burn_ix = burn(
+            &spl_token_2022::ID,
+            receipt_token_account_info.key,
+            receipt_mint_info.key,
+            owner.key,
+            &[],
+            to_withdraw,
+        )?;
+
+        invoke(
+            &burn_ix,
+            &[
+                receipt_token_account_info.clone(),
+                receipt_mint_info.clone(),
+                owner.clone(),
+                receipt_token_program_info.clone(),
+            ],
+        )?;
+    }
+
+    PaladinRewardsEvent::EmergencyWithdrawn {
+        holder_rewards_pool: *holder_rewards_pool_info.key,
+        owner: *owner.key,
+        amount: to_withdraw,
+        deposited: holder_rewards_state.deposited,
+        unharvested_rewards: holder_rewards_state.unharvested_rewards,
+    }
+    .emit();
+
+    Ok(())
+}
+
+/// Processes a
+/// [RecoverExcessTokens](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_recover_excess_tokens(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let holder_rewards_pool_token_account_info = next_account_info(accounts_iter)?;
+    let destination_token_account_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let authority_info = next_account_info(accounts_iter)?;
+    let token_program = next_account_info(accounts_iter)?;
+
+    // Validate pool token account.
+    validate_token_account(
+        holder_rewards_pool_token_account_info,
+        Some(holder_rewards_pool_info.key),
+        mint_info.key,
+    )?;
+
+    // Load the pool.
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let pool_data = holder_rewards_pool_info.try_borrow_data()?;
+    let pool_state = bytemuck::try_from_bytes::<HolderRewardsPool>(&pool_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Ensure the pool authority is the signer.
+    if authority_info.key != &pool_state.authority {
+        return Err(PaladinRewardsError::IncorrectPoolAuthority.into());
+    }
+
+    // Determine the tokens held beyond what holders have deposited.
+    let pool_balance =
+        get_token_account_balance_checked(mint_info.key, holder_rewards_pool_token_account_info)?;
+    let excess = pool_balance.saturating_sub(pool_state.total_deposited);
+    if excess == 0 {
+        return Err(PaladinRewardsError::NoExcessTokens.into());
+    }
+
+    // Get pool token account signer seeds.
+    let bump_seed = [pool_state.bump_seed];
+    let holder_rewards_pool_signer_seeds =
+        collect_holder_rewards_pool_signer_seeds(mint_info.key, &bump_seed);
+
+    // Transfer the excess tokens from the pool to the destination.
+    let transfer_ix = transfer(
+        &spl_token::ID,
+        holder_rewards_pool_token_account_info.key,
+        destination_token_account_info.key,
+        holder_rewards_pool_info.key,
+        &[holder_rewards_pool_info.key],
+        excess,
+    )?;
+
+    drop(pool_data);
+    invoke_signed(
+        &transfer_ix,
+        &[
+            holder_rewards_pool_token_account_info.clone(),
+            destination_token_account_info.clone(),
+            holder_rewards_pool_info.clone(),
+            token_program.clone(),
+        ],
+        &[&holder_rewards_pool_signer_seeds],
+    )?;
+
+    PaladinRewardsEvent::ExcessTokensRecovered {
+        holder_rewards_pool: *holder_rewards_pool_info.key,
+        destination: *destination_token_account_info.key,
+        amount: excess,
+    }
+    .emit();
+
+    Ok(())
+}
+
+/// Processes a
+/// [GetPendingRewards](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_get_pending_rewards(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let holder_rewards_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let owner = next_account_info(accounts_iter)?;
+
+    // Load pool & holder rewards.
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let pool_data = holder_rewards_pool_info.try_borrow_data()?;
+    let pool_state = bytemuck::try_from_bytes::<HolderRewardsPool>(&pool_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    check_holder_rewards(program_id, owner.key, holder_rewards_info)?;
+    let holder_rewards_data = holder_rewards_info.try_borrow_data()?;
+    let holder_rewards_state = bytemuck::try_from_bytes::<HolderRewards>(&holder_rewards_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Account for any lamports received since the rate was last updated,
+    // without writing the new rate back to the pool.
+    let accumulated_rewards_per_token = calculate_latest_accumulated_rewards_per_token(
+        pool_state,
+        holder_rewards_pool_info.lamports(),
+    )?;
+
+    let pending_rewards = calculate_eligible_rewards(
+        accumulated_rewards_per_token,
+        holder_rewards_state.last_accumulated_rewards_per_token,
+        holder_rewards_state
+            .weight()
+            .ok_or(ProgramError::ArithmeticOverflow)?,
+    )
+    .ok_or(ProgramError::ArithmeticOverflow)?
+    .checked_add(holder_rewards_state.unharvested_rewards)
+    .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    set_return_data(&pending_rewards.to_le_bytes());
+
+    Ok(())
+}
+
+/// Processes a
+/// [MigrateHolderRewardsPool](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_migrate_holder_rewards_pool(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let holder_rewards_pool_token_account_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let authority_info = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+
+    // Ensure the holder rewards pool is owned by the Paladin Rewards
+    // program.
+    if holder_rewards_pool_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Ensure the pool still has the baseline layout.
+    let legacy_len = holder_rewards_pool_info.data_len();
+    if legacy_len == HolderRewardsPool::LEN {
+        return Err(PaladinRewardsError::PoolAlreadyMigrated.into());
+    }
+    if legacy_len != HolderRewardsPool::BASELINE_LEN {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // The baseline layout is a prefix of the current layout.
+    let mut pool_state: HolderRewardsPool = bytemuck::Zeroable::zeroed();
+    bytemuck::bytes_of_mut(&mut pool_state)[..legacy_len]
+        .copy_from_slice(&holder_rewards_pool_info.try_borrow_data()?);
+
+    // The baseline layout does not store the bump seed, so derive it.
+    pool_state.bump_seed =
+        get_holder_rewards_pool_address_and_bump_seed(mint_info.key, program_id).1;
+
+    // Ensure the provided holder rewards pool address is the correct
+    // address derived from the mint and the stored bump seed.
+    if create_holder_rewards_pool_address(mint_info.key, pool_state.bump_seed, program_id).as_ref()
+        != Ok(holder_rewards_pool_info.key)
+    {
+        return Err(PaladinRewardsError::IncorrectHolderRewardsPoolAddress.into());
+    }
+
+    // The baseline layout distributed rewards by the pool token account's
+    // balance and had no authority, so backfill both.
+    if holder_rewards_pool_token_account_info.key
+        != &get_associated_token_address(holder_rewards_pool_info.key, mint_info.key)
+    {
+        return Err(PaladinRewardsError::IncorrectPoolTokenAccountAddress.into());
+    }
+    check_mint_authority(mint_info, authority_info)?;
+    validate_token_account(
+        holder_rewards_pool_token_account_info,
+        Some(holder_rewards_pool_info.key),
+        mint_info.key,
+    )?;
+    pool_state.total_deposited =
+        get_token_account_balance_checked(mint_info.key, holder_rewards_pool_token_account_info)?;
+    pool_state.authority = *authority_info.key;
+
+    // Fund the larger account, then resize it.
+    let lamports_before = holder_rewards_pool_info.lamports();
+    top_up_rent_exemption(payer_info, holder_rewards_pool_info, HolderRewardsPool::LEN)?;
+    holder_rewards_pool_info.realloc(HolderRewardsPool::LEN, true)?;
+
+    // Lamports added for rent are not rewards.
+    pool_state.lamports_last = pool_state
+        .lamports_last
+        .checked_add(
+            holder_rewards_pool_info
+                .lamports()
+                .saturating_sub(lamports_before),
+        )
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    pool_state.last_update_slot = Clock::get()?.slot;
+
+    let mut data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    *bytemuck::try_from_bytes_mut(&mut data).map_err(|_| ProgramError::InvalidAccountData)? =
+        pool_state;
+
+    PaladinRewardsEvent::PoolMigrated {
+        holder_rewards_pool: *holder_rewards_pool_info.key,
+    }
+    .emit();
+
+    Ok(())
+}
+
+/// Processes an
+/// [InitializeRateHistory](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_initialize_rate_history(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let rate_history_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_state = bytemuck::try_from_bytes_mut::<HolderRewardsPool>(&mut pool_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    let (rate_history_address, bump_seed) =
+        get_rate_history_address_and_bump_seed(holder_rewards_pool_info.key, program_id);
+    let bump_seed = [bump_seed];
+    let rate_history_signer_seeds =
+        collect_rate_history_signer_seeds(holder_rewards_pool_info.key, &bump_seed);
+
+    // Ensure the provided rate history address is the correct address
+    // derived from the pool.
+    if rate_history_info.key != &rate_history_address {
+        return Err(PaladinRewardsError::IncorrectRateHistoryAddress.into());
+    }
+
+    // Ensure the rate history account has not already been initialized.
+    if rate_history_info.data.borrow().len() != 0 {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    // Fund, allocate & assign.
+    create_pda_account(
+        program_id,
+        payer_info,
+        rate_history_info,
+        RateHistory::LEN,
+        &rate_history_signer_seeds,
+    )?;
+
+    // Write the data, seeded with the pool's current rate.
+    let clock = Clock::get()?;
+    let mut data = rate_history_info.try_borrow_mut_data()?;
+    let state = bytemuck::try_from_bytes_mut::<RateHistory>(&mut data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    state.bump_seed = bump_seed[0];
+    state.push(RateHistoryEntry {
+        accumulated_rewards_per_token: pool_state.accumulated_rewards_per_token,
+        slot: clock.slot,
+        epoch: clock.epoch,
+        total_deposited: pool_state.total_deposited,
+        _padding: [0; 8],
+    });
+    pool_state.rate_history = 1;
+
+    PaladinRewardsEvent::RateHistoryInitialized {
+        holder_rewards_pool: *holder_rewards_pool_info.key,
+    }
+    .emit();
+
+    Ok(())
+}
+
+/// Processes a [SetLockupTiers](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_set_lockup_tiers(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    tiers: [LockupTier; LOCKUP_TIERS_CAPACITY],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let lockup_tiers_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let authority_info = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let pool_data = holder_rewards_pool_info.try_borrow_data()?;
+    let pool_state = bytemuck::try_from_bytes::<HolderRewardsPool>(&pool_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Ensure the pool authority is the signer.
+    if authority_info.key != &pool_state.authority {
+        return Err(PaladinRewardsError::IncorrectPoolAuthority.into());
+    }
+
+    if !LockupTiers::is_valid(&tiers) {
+        return Err(PaladinRewardsError::InvalidLockupTiers.into());
+    }
+
+    let (lockup_tiers_address, bump_seed) =
+        get_lockup_tiers_address_and_bump_seed(holder_rewards_pool_info.key, program_id);
+
+    // Ensure the provided lockup tiers address is the correct address
+    // derived from the pool.
+    if lockup_tiers_info.key != &lockup_tiers_address {
+        return Err(PaladinRewardsError::IncorrectLockupTiersAddress.into());
+    }
+
+    // Create the account the first time the tiers are set.
+    if lockup_tiers_info.data.borrow().len() == 0 {
+        let bump_seed = [bump_seed];
+        let lockup_tiers_signer_seeds =
+            collect_lockup_tiers_signer_seeds(holder_rewards_pool_info.key, &bump_seed);
+
+        create_pda_account(
+            program_id,
+            payer_info,
+            lockup_tiers_info,
+            LockupTiers::LEN,
+            &lockup_tiers_signer_seeds,
+        )?;
+    } else if lockup_tiers_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Write the data.
+    let mut data = lockup_tiers_info.try_borrow_mut_data()?;
+    let state = bytemuck::try_from_bytes_mut::<LockupTiers>(&mut data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    state.tiers = tiers;
+    state.bump_seed = bump_seed;
+
+    PaladinRewardsEvent::LockupTiersSet {
+        holder_rewards_pool: *holder_rewards_pool_info.key,
+    }
+    .emit();
+
+    Ok(())
+}
+
+/// Processes a [Lock](enum.PaladinRewardsInstruction.html) instruction.
+fn process_lock(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+    unlock_timestamp: i64,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let holder_rewards_info = next_account_info(accounts_iter)?;
+    let lockup_tiers_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let owner = next_account_info(accounts_iter)?;
+    let rate_history_info = next_optional_account_info(program_id, accounts_iter);
+    let vote_escrow_info = next_optional_account_info(program_id, accounts_iter);
+    let epoch_ledger_info = next_optional_account_info(program_id, accounts_iter);
+
+    // Load pool, holder rewards & lockup tiers.
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_state = bytemuck::try_from_bytes_mut::<HolderRewardsPool>(&mut pool_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    check_holder_rewards(program_id, owner.key, holder_rewards_info)?;
+    let mut holder_rewards_data = holder_rewards_info.try_borrow_mut_data()?;
+    let holder_rewards_state =
+        bytemuck::try_from_bytes_mut::<HolderRewards>(&mut holder_rewards_data)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+    check_lockup_tiers(program_id, holder_rewards_pool_info.key, lockup_tiers_info)?;
+    let lockup_tiers_data = lockup_tiers_info.try_borrow_data()?;
+    let lockup_tiers_state = bytemuck::try_from_bytes::<LockupTiers>(&lockup_tiers_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Load the vote escrow, which must be provided if the pool has one.
+    let mut vote_escrow_data = match vote_escrow_info {
+        Some(vote_escrow_info) => {
+            check_vote_escrow(program_id, holder_rewards_pool_info.key, vote_escrow_info)?;
+            if !vote_escrow_info.is_writable {
+                return Err(PaladinRewardsError::AccountNotWritable.into());
+            }
+            Some(vote_escrow_info.try_borrow_mut_data()?)
+        }
+        None if lockup_tiers_state.vote_escrow != 0 => {
+            return Err(PaladinRewardsError::VoteEscrowRequired.into());
+        }
+        None => None,
+    };
+    let vote_escrow_state = vote_escrow_data
+        .as_deref_mut()
+        .map(|data| {
+            bytemuck::try_from_bytes_mut::<VoteEscrow>(data)
+                .map_err(|_| ProgramError::InvalidAccountData)
+        })
+        .transpose()?;
+
+    // Handle any lamports received since last harvest.
+    update_accumulated_rewards_per_token(
+        program_id,
+        holder_rewards_pool_info,
+        pool_state,
+        rate_history_info,
+        epoch_ledger_info,
+    )?;
+
+    // Calculate rewards to harvest at the holder's current weight.
+    let mut rewards_to_harvest = calculate_rewards_to_harvest(
+        holder_rewards_state,
+        pool_state,
+        holder_rewards_pool_info.lamports(),
+    )?;
+
+    let clock = Clock::get()?;
+    release_expired_lockup(
+        holder_rewards_pool_info.key,
+        owner.key,
+        pool_state,
+        holder_rewards_state,
+        clock.unix_timestamp,
+    )?;
+
+    // An existing lockup can only be extended.
+    if holder_rewards_state.locked_amount > 0
+        && unlock_timestamp < holder_rewards_state.unlock_timestamp
+    {
+        return Err(PaladinRewardsError::LockupCannotBeShortened.into());
+    }
+
+    // Determine the multiplier from the lockup's duration. If rewards
+    // follow voting power, the tiers only set the minimum duration.
+    let duration = u64::try_from(unlock_timestamp.saturating_sub(clock.unix_timestamp))
+        .map_err(|_| PaladinRewardsError::LockupDurationTooShort)?;
+    if duration > MAX_LOCKUP_DURATION {
+        return Err(PaladinRewardsError::LockupDurationTooLong.into());
+    }
+    let tier_multiplier_bps = lockup_tiers_state
+        .multiplier_bps(duration)
+        .ok_or(PaladinRewardsError::LockupDurationTooShort)?;
+    let end = voting_power_end(unlock_timestamp);
+    let multiplier_bps = match &vote_escrow_state {
+        Some(vote_escrow_state) if vote_escrow_state.reward_weighting != 0 => {
+            VoteEscrow::reward_multiplier_bps(end, clock.unix_timestamp)
+        }
+        _ => tier_multiplier_bps,
+    };
+
+    // Ensure the holder has enough unlocked tokens.
+    let locked_amount = holder_rewards_state
+        .locked_amount
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    if locked_amount > holder_rewards_state.deposited {
+        return Err(PaladinRewardsError::LockExceedsUnlocked.into());
+    }
+    if locked_amount == 0 {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    set_lockup(
+        pool_state,
+        holder_rewards_state,
+        locked_amount,
+        unlock_timestamp,
+        multiplier_bps,
+    )?;
+    holder_rewards_state.last_activity_slot = clock.slot;
+
+    // Count the lockup's voting power in place of any it had before.
+    if let Some(vote_escrow_state) = vote_escrow_state {
+        vote_escrow_state.checkpoint(clock.unix_timestamp);
+        vote_escrow_state
+            .remove(
+                holder_rewards_state.voting_power_locked,
+                holder_rewards_state.voting_power_end,
+            )
+            .and_then(|()| vote_escrow_state.add(locked_amount, end))
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        holder_rewards_state.voting_power_locked = locked_amount;
+        holder_rewards_state.voting_power_end = end;
+    }
+
+    PaladinRewardsEvent::Locked {
+        holder_rewards_pool: *holder_rewards_pool_info.key,
+        owner: *owner.key,
+        locked_amount,
+        unlock_timestamp,
+        multiplier_bps,
+    }
+    .emit();
+
+    // Rewards harvested within the early withdrawal period are withheld.
+    rewards_to_harvest = withhold_early_rewards(
+        pool_state,
+        holder_rewards_state,
+        rewards_to_harvest,
+        clock.slot,
+    )?;
+
+    // Send rewards to the owner
+    if rewards_to_harvest > 0 {
+        send_rewards(
+            holder_rewards_pool_info.clone(),
+            owner.clone(),
+            pool_state,
+            holder_rewards_state,
+            rewards_to_harvest,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Processes a [ReleaseLockup](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_release_lockup(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let holder_rewards_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let owner = next_account_info(accounts_iter)?;
+    let rate_history_info = next_optional_account_info(program_id, accounts_iter);
+    let epoch_ledger_info = next_optional_account_info(program_id, accounts_iter);
+
+    // Load pool & holder rewards.
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_state = bytemuck::try_from_bytes_mut::<HolderRewardsPool>(&mut pool_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    check_holder_rewards(program_id, owner.key, holder_rewards_info)?;
+    let mut holder_rewards_data = holder_rewards_info.try_borrow_mut_data()?;
+    let holder_rewards_state =
+        bytemuck::try_from_bytes_mut::<HolderRewards>(&mut holder_rewards_data)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    let clock = Clock::get()?;
+    if holder_rewards_state.locked_amount == 0
+        || clock.unix_timestamp < holder_rewards_state.unlock_timestamp
+    {
+        return Err(PaladinRewardsError::LockupNotExpired.into());
+    }
+
+    // Handle any lamports received since last harvest.
+    update_accumulated_rewards_per_token(
+        program_id,
+        holder_rewards_pool_info,
+        pool_state,
+        rate_history_info,
+        epoch_ledger_info,
+    )?;
+
+    // Calculate rewards to harvest at the holder's locked weight.
+    let mut rewards_to_harvest = calculate_rewards_to_harvest(
+        holder_rewards_state,
+        pool_state,
+        holder_rewards_pool_info.lamports(),
+    )?;
+
+    release_expired_lockup(
+        holder_rewards_pool_info.key,
+        owner.key,
+        pool_state,
+        holder_rewards_state,
+        clock.unix_timestamp,
+    )?;
+
+    // Rewards harvested within the early withdrawal period are withheld.
+    rewards_to_harvest = withhold_early_rewards(
+        pool_state,
+        holder_rewards_state,
+        rewards_to_harvest,
+        clock.slot,
+    )?;
+
+    // Send rewards to the owner
+    if rewards_to_harvest > 0 {
+        send_rewards(
+            holder_rewards_pool_info.clone(),
+            owner.clone(),
+            pool_state,
+            holder_rewards_state,
+            rewards_to_harvest,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Processes an [InitializeVoteEscrow](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_initialize_vote_escrow(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    reward_weighting: bool,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let lockup_tiers_info = next_account_info(accounts_iter)?;
+    let vote_escrow_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let authority_info = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let pool_data = holder_rewards_pool_info.try_borrow_data()?;
+    let pool_state = bytemuck::try_from_bytes::<HolderRewardsPool>(&pool_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Ensure the pool authority is the signer.
+    if authority_info.key != &pool_state.authority {
+        return Err(PaladinRewardsError::IncorrectPoolAuthority.into());
+    }
+
+    check_lockup_tiers(program_id, holder_rewards_pool_info.key, lockup_tiers_info)?;
+
+    let (vote_escrow_address, bump_seed) =
+        get_vote_escrow_address_and_bump_seed(holder_rewards_pool_info.key, program_id);
+    let bump_seed = [bump_seed];
+    let vote_escrow_signer_seeds =
+        collect_vote_escrow_signer_seeds(holder_rewards_pool_info.key, &bump_seed);
+
+    // Ensure the provided vote escrow address is the correct address
+    // derived from the pool.
+    if vote_escrow_info.key != &vote_escrow_address {
+        return Err(PaladinRewardsError::IncorrectVoteEscrowAddress.into());
+    }
+
+    // Ensure the vote escrow account has not already been initialized.
+    if vote_escrow_info.data.borrow().len() != 0 {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    // Fund, allocate & assign.
+    create_pda_account(
+        program_id,
+        payer_info,
+        vote_escrow_info,
+        VoteEscrow::LEN,
+        &vote_escrow_signer_seeds,
+    )?;
+
+    // Write the data.
+    let clock = Clock::get()?;
+    let mut data = vote_escrow_info.try_borrow_mut_data()?;
+    let state = bytemuck::try_from_bytes_mut::<VoteEscrow>(&mut data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    state.last_checkpoint = clock.unix_timestamp;
+    state.bump_seed = bump_seed[0];
+    state.reward_weighting = reward_weighting.into();
+
+    // Require lockups to be recorded in the vote escrow from now on.
+    let mut lockup_tiers_data = lockup_tiers_info.try_borrow_mut_data()?;
+    let lockup_tiers_state = bytemuck::try_from_bytes_mut::<LockupTiers>(&mut lockup_tiers_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    lockup_tiers_state.vote_escrow = 1;
+
+    PaladinRewardsEvent::VoteEscrowInitialized {
+        holder_rewards_pool: *holder_rewards_pool_info.key,
+        reward_weighting,
+    }
+    .emit();
+
+    Ok(())
+}
+
+/// Processes a [CheckpointVotingPower](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_checkpoint_voting_power(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let holder_rewards_info = next_account_info(accounts_iter)?;
+    let vote_escrow_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let owner = next_account_info(accounts_iter)?;
+    let rate_history_info = next_optional_account_info(program_id, accounts_iter);
+    let epoch_ledger_info = next_optional_account_info(program_id, accounts_iter);
+
+    // Load pool, holder rewards & vote escrow.
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_state = bytemuck::try_from_bytes_mut::<HolderRewardsPool>(&mut pool_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    check_holder_rewards(program_id, owner.key, holder_rewards_info)?;
+    let mut holder_rewards_data = holder_rewards_info.try_borrow_mut_data()?;
+    let holder_rewards_state =
+        bytemuck::try_from_bytes_mut::<HolderRewards>(&mut holder_rewards_data)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+    check_vote_escrow(program_id, holder_rewards_pool_info.key, vote_escrow_info)?;
+    let mut vote_escrow_data = vote_escrow_info.try_borrow_mut_data()?;
+    let vote_escrow_state = bytemuck::try_from_bytes_mut::<VoteEscrow>(&mut vote_escrow_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Handle any lamports received since last harvest.
+    update_accumulated_rewards_per_token(
+        program_id,
+        holder_rewards_pool_info,
+        pool_state,
+        rate_history_info,
+        epoch_ledger_info,
+    )?;
+
+    // Calculate rewards to harvest at the holder's current weight.
+    let mut rewards_to_harvest = calculate_rewards_to_harvest(
+        holder_rewards_state,
+        pool_state,
+        holder_rewards_pool_info.lamports(),
+    )?;
+
+    let clock = Clock::get()?;
+    release_expired_lockup(
+        holder_rewards_pool_info.key,
+        owner.key,
+        pool_state,
+        holder_rewards_state,
+        clock.unix_timestamp,
+    )?;
+    vote_escrow_state.checkpoint(clock.unix_timestamp);
+
+    // Re-weight the lockup to its decayed voting power.
+    if vote_escrow_state.reward_weighting != 0
+        && holder_rewards_state.locked_amount > 0
+        && holder_rewards_state.voting_power_locked > 0
+    {
+        set_lockup(
+            pool_state,
+            holder_rewards_state,
+            holder_rewards_state.locked_amount,
+            holder_rewards_state.unlock_timestamp,
+            VoteEscrow::reward_multiplier_bps(
+                holder_rewards_state.voting_power_end,
+                clock.unix_timestamp,
+            ),
+        )?;
+    }
+
+    PaladinRewardsEvent::VotingPowerCheckpointed {
+        holder_rewards_pool: *holder_rewards_pool_info.key,
+        owner: *owner.key,
+        voting_power: holder_rewards_state.voting_power(clock.unix_timestamp),
+        multiplier_bps: holder_rewards_state.lockup_multiplier_bps,
+    }
+    .emit();
+
+    // Rewards harvested within the early withdrawal period are withheld.
+    rewards_to_harvest = withhold_early_rewards(
+        pool_state,
+        holder_rewards_state,
+        rewards_to_harvest,
+        clock.slot,
+    )?;
+
+    // Send rewards to the owner
+    if rewards_to_harvest > 0 {
+        send_rewards(
+            holder_rewards_pool_info.clone(),
+            owner.clone(),
+            pool_state,
+            holder_rewards_state,
+            rewards_to_harvest,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Processes a [GetVotingPower](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_get_voting_power(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let vote_escrow_info = next_account_info(accounts_iter)?;
+    let holder_rewards_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let owner = next_account_info(accounts_iter)?;
+
+    // Load vote escrow & holder rewards.
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    check_vote_escrow(program_id, holder_rewards_pool_info.key, vote_escrow_info)?;
+    let vote_escrow_data = vote_escrow_info.try_borrow_data()?;
+    let vote_escrow_state = bytemuck::try_from_bytes::<VoteEscrow>(&vote_escrow_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    check_holder_rewards(program_id, owner.key, holder_rewards_info)?;
+    let holder_rewards_data = holder_rewards_info.try_borrow_data()?;
+    let holder_rewards_state = bytemuck::try_from_bytes::<HolderRewards>(&holder_rewards_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    let unix_timestamp = Clock::get()?.unix_timestamp;
+    let mut return_data = [0; 16];
+    return_data[..8].copy_from_slice(
+        &holder_rewards_state
+            .voting_power(unix_timestamp)
+            .to_le_bytes(),
+    );
+    return_data[8..].copy_from_slice(
+        &vote_escrow_state
+            .total_voting_power(unix_timestamp)
+            .to_le_bytes(),
+    );
+    set_return_data(&return_data);
+
+    Ok(())
+}
+
+/// Processes an
+/// [InitializeStakeCheckpoints](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_initialize_stake_checkpoints(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_info = next_account_info(accounts_iter)?;
+    let stake_checkpoints_info = next_account_info(accounts_iter)?;
+    let owner = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+
+    check_holder_rewards(program_id, owner.key, holder_rewards_info)?;
+    let mut holder_rewards_data = holder_rewards_info.try_borrow_mut_data()?;
+    let holder_rewards_state =
+        bytemuck::try_from_bytes_mut::<HolderRewards>(&mut holder_rewards_data)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Ensure the holder is not already recording stake checkpoints.
+    if holder_rewards_state.stake_checkpoints != 0 {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    let (stake_checkpoints_address, bump_seed) =
+        get_stake_checkpoints_address_and_bump_seed(owner.key, program_id);
+
+    // Ensure the provided stake checkpoints address is the correct address
+    // derived from the owner.
+    if stake_checkpoints_info.key != &stake_checkpoints_address {
+        return Err(PaladinRewardsError::IncorrectStakeCheckpointsAddress.into());
+    }
+
+    // Create the account the first time, otherwise resume recording in the
+    // checkpoints kept from a closed holder rewards account.
+    if stake_checkpoints_info.data.borrow().len() == 0 {
+        let bump_seed = [bump_seed];
+        let stake_checkpoints_signer_seeds =
+            collect_stake_checkpoints_signer_seeds(owner.key, &bump_seed);
+
+        create_pda_account(
+            program_id,
+            payer_info,
+            stake_checkpoints_info,
+            StakeCheckpoints::LEN,
+            &stake_checkpoints_signer_seeds,
+        )?;
+    } else if stake_checkpoints_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Write the data, seeded with the holder's current balance.
+    let clock = Clock::get()?;
+    let mut data = stake_checkpoints_info.try_borrow_mut_data()?;
+    let state = bytemuck::try_from_bytes_mut::<StakeCheckpoints>(&mut data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    state.bump_seed = bump_seed;
+    state.push(clock.slot, holder_rewards_state.deposited);
+    holder_rewards_state.stake_checkpoints = 1;
+
+    PaladinRewardsEvent::StakeCheckpointsInitialized { owner: *owner.key }.emit();
+
+    Ok(())
+}
+
+/// Processes a [GetStakeAt](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_get_stake_at(program_id: &Pubkey, accounts: &[AccountInfo], slot: u64) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let stake_checkpoints_info = next_account_info(accounts_iter)?;
+    let owner = next_account_info(accounts_iter)?;
+
+    check_stake_checkpoints(program_id, owner.key, stake_checkpoints_info)?;
+    let data = stake_checkpoints_info.try_borrow_data()?;
+    let state = bytemuck::try_from_bytes::<StakeCheckpoints>(&data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Balances can still change within the current slot.
+    if slot >= Clock::get()?.slot {
+        return Err(PaladinRewardsError::SnapshotSlotNotPast.into());
+    }
+
+    let deposited = state
+        .deposited_at(slot)
+        .ok_or(PaladinRewardsError::StakeCheckpointNotFound)?;
+
+    set_return_data(&deposited.to_le_bytes());
+
+    Ok(())
+}
+
+/// Processes a
+/// [SetEarlyWithdrawalPenalty](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_set_early_withdrawal_penalty(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    penalty_bps: u16,
+    period_slots: u32,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let authority_info = next_account_info(accounts_iter)?;
+
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_state = bytemuck::try_from_bytes_mut::<HolderRewardsPool>(&mut pool_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Ensure the pool authority is the signer.
+    if authority_info.key != &pool_state.authority {
+        return Err(PaladinRewardsError::IncorrectPoolAuthority.into());
+    }
+
+    if penalty_bps as u64 > BASIS_POINTS {
+        return Err(PaladinRewardsError::InvalidEarlyWithdrawalPenalty.into());
+    }
+
+    pool_state.early_withdrawal_penalty_bps = penalty_bps;
+    pool_state.early_withdrawal_period_slots = period_slots;
+
+    PaladinRewardsEvent::EarlyWithdrawalPenaltySet {
+        holder_rewards_pool: *holder_rewards_pool_info.key,
+        penalty_bps,
+        period_slots,
+    }
+    .emit();
+
+    Ok(())
+}
+
+/// Processes a
+/// [SetDepositLimits](enum.PaladinRewardsInstruction.html) instruction.
+fn process_set_deposit_limits(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    max_total_deposit: u64,
+    max_holder_deposit: u64,
+    min_deposit: u64,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let authority_info = next_account_info(accounts_iter)?;
+
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_state = bytemuck::try_from_bytes_mut::<HolderRewardsPool>(&mut pool_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Ensure the pool authority is the signer.
+    if authority_info.key != &pool_state.authority {
+        return Err(PaladinRewardsError::IncorrectPoolAuthority.into());
+    }
+
+    // No deposit could satisfy both limits.
+    if max_holder_deposit != 0 && min_deposit > max_holder_deposit {
+        return Err(PaladinRewardsError::InvalidDepositLimits.into());
+    }
+
+    pool_state.max_total_deposit = max_total_deposit;
+    pool_state.max_holder_deposit = max_holder_deposit;
+    pool_state.min_deposit = min_deposit;
+
+    PaladinRewardsEvent::DepositLimitsSet {
+        holder_rewards_pool: *holder_rewards_pool_info.key,
+        max_total_deposit,
+        max_holder_deposit,
+        min_deposit,
+    }
+    .emit();
+
+    Ok(())
+}
+
+/// Processes a [SetAccessList](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_set_access_list(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    access_list: u8,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let authority_info = next_account_info(accounts_iter)?;
+
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_state = bytemuck::try_from_bytes_mut::<HolderRewardsPool>(&mut pool_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Ensure the pool authority is the signer.
+    if authority_info.key != &pool_state.authority {
+        return Err(PaladinRewardsError::IncorrectPoolAuthority.into());
+    }
+
+    if access_list > ACCESS_LIST_DENYLIST {
+        return Err(PaladinRewardsError::InvalidAccessListMode.into());
+    }
+
+    pool_state.access_list = access_list;
+
+    PaladinRewardsEvent::AccessListSet {
+        holder_rewards_pool: *holder_rewards_pool_info.key,
+        access_list,
+    }
+    .emit();
+
+    Ok(())
+}
+
+/// Processes a [SetAccessEntry](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_set_access_entry(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    status: u8,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let access_entry_info = next_account_info(accounts_iter)?;
+    let wallet_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let authority_info = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let pool_data = holder_rewards_pool_info.try_borrow_data()?;
+    let pool_state = bytemuck::try_from_bytes::<HolderRewardsPool>(&pool_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Ensure the pool authority is the signer.
+    if authority_info.key != &pool_state.authority {
+        return Err(PaladinRewardsError::IncorrectPoolAuthority.into());
+    }
+
+    if status > ACCESS_STATUS_DENIED {
+        return Err(PaladinRewardsError::InvalidAccessStatus.into());
+    }
+
+    let (access_entry_address, bump_seed) = get_access_entry_address_and_bump_seed(
+        holder_rewards_pool_info.key,
+        wallet_info.key,
+        program_id,
+    );
+
+    // Ensure the provided access entry address is the correct address
+    // derived from the pool and the wallet.
+    if access_entry_info.key != &access_entry_address {
+        return Err(PaladinRewardsError::IncorrectAccessEntryAddress.into());
+    }
+
+    // Create the account the first time the wallet is listed.
+    if access_entry_info.data_is_empty() {
+        let bump_seed = [bump_seed];
+        let access_entry_signer_seeds = collect_access_entry_signer_seeds(
+            holder_rewards_pool_info.key,
+            wallet_info.key,
+            &bump_seed,
+        );
+
+        create_pda_account(
+            program_id,
+            payer_info,
+            access_entry_info,
+            AccessEntry::LEN,
+            &access_entry_signer_seeds,
+        )?;
+    } else if access_entry_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Write the data.
+    let mut data = access_entry_info.try_borrow_mut_data()?;
+    let state = bytemuck::try_from_bytes_mut::<AccessEntry>(&mut data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    state.status = status;
+    state.bump_seed = bump_seed;
+
+    PaladinRewardsEvent::AccessEntrySet {
+        holder_rewards_pool: *holder_rewards_pool_info.key,
+        wallet: *wallet_info.key,
+        status,
+    }
+    .emit();
+
+    Ok(())
+}
+
+/// Processes an
+/// [InitializeReceiptMint](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_initialize_receipt_mint(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let receipt_mint_info = next_account_info(accounts_iter)?;
+    let extra_account_metas_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let authority_info = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+    let receipt_token_program_info = next_account_info(accounts_iter)?;
+
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_state = bytemuck::try_from_bytes_mut::<HolderRewardsPool>(&mut pool_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Ensure the pool authority is the signer.
+    if authority_info.key != &pool_state.authority {
+        return Err(PaladinRewardsError::IncorrectPoolAuthority.into());
+    }
+
+    if pool_state.receipts != 0 {
+        return Err(PaladinRewardsError::ReceiptMintAlreadyInitialized.into());
+    }
+
+    // Every deposit must be backed by receipt tokens.
+    if pool_state.total_deposited != 0 {
+        return Err(PaladinRewardsError::ReceiptMintRequiresEmptyPool.into());
+    }
+
+    let (receipt_mint_address, receipt_mint_bump_seed) =
+        get_receipt_mint_address_and_bump_seed(holder_rewards_pool_info.key, program_id);
+
+    // Ensure the provided receipt mint address is the correct address
+    // derived from the pool.
+    if receipt_mint_info.key != &receipt_mint_address {
+        return Err(PaladinRewardsError::IncorrectReceiptMintAddress.into());
+    }
+
+    let (extra_account_metas_address, extra_account_metas_bump_seed) =
+        get_extra_account_metas_address_and_bump_seed(&receipt_mint_address, program_id);
+
+    // Ensure the provided extra account metas address is the correct address
+    // derived from the receipt mint.
+    if extra_account_metas_info.key != &extra_account_metas_address {
+        return Err(PaladinRewardsError::IncorrectExtraAccountMetasAddress.into());
+    }
+
+    // Receipt tokens share the decimals of the deposited token.
+    let decimals = {
+        let mint_data = mint_info.try_borrow_data()?;
+        Mint::unpack(&mint_data)?.decimals
+    };
+
+    // Create the receipt mint, with this program as its transfer hook and
+    // the pool as its mint authority.
+    let bump_seed = [receipt_mint_bump_seed];
+    let receipt_mint_signer_seeds =
+        collect_receipt_mint_signer_seeds(holder_rewards_pool_info.key, &bump_seed);
+
+    create_pda_account(
+        &spl_token_2022::ID,
+        payer_info,
+        receipt_mint_info,
+        ExtensionType::try_calculate_account_len::<ReceiptMint>(&[ExtensionType::TransferHook])?,
+        &receipt_mint_signer_seeds,
+    )?;
+    invoke(
+        &transfer_hook::instruction::initialize(
+            &spl_token_2022::ID,
+            receipt_mint_info.key,
+            None,
+            Some(*program_id),
+        )?,
+        &[
+            receipt_mint_info.clone(),
+            receipt_token_program_info.clone(),
+        ],
+    )?;
+    invoke(
+        &initialize_mint2(
+            &spl_token_2022::ID,
+            receipt_mint_info.key,
+            holder_rewards_pool_info.key,
+            None,
+            decimals,
+        )?,
+        &[
+            receipt_mint_info.clone(),
+            receipt_token_program_info.clone(),
+        ],
+    )?;
+
+    // Create the accounts the transfer hook needs resolved.
+    let extra_account_metas = receipt_extra_account_metas(holder_rewards_pool_info.key)?;
+    let bump_seed = [extra_account_metas_bump_seed];
+    let extra_account_metas_signer_seeds =
+        collect_extra_account_metas_signer_seeds(receipt_mint_info.key, &bump_seed);
+
+    create_pda_account(
+        program_id,
+        payer_info,
+        extra_account_metas_info,
+        ExtraAccountMetaList::size_of(extra_account_metas.len())?,
+        &extra_account_metas_signer_seeds,
+    )?;
+    ExtraAccountMetaList::init::<ExecuteInstruction>(
+        &mut extra_account_metas_info.try_borrow_mut_data()?,
+        &extra_account_metas,
+    )?;
+
+    pool_state.receipts = 1;
+    pool_state.receipt_mint_bump_seed = receipt_mint_bump_seed;
+
+    PaladinRewardsEvent::ReceiptMintInitialized {
+        holder_rewards_pool: *holder_rewards_pool_info.key,
+        receipt_mint: *receipt_mint_info.key,
+    }
+    .emit();
+
+    Ok(())
+}
+
+/// Processes a transfer hook `Execute` instruction, invoked by the Token-2022
+/// program whenever a pool's receipt tokens are transferred.
+///
+/// Moves the deposit represented by the receipt tokens from the source
+/// owner's holder rewards account to the destination owner's, settling both
+/// holders' rewards first.
+fn process_execute(program_id: &Pubkey, accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let source_info = next_account_info(accounts_iter)?;
+    let receipt_mint_info = next_account_info(accounts_iter)?;
+    let destination_info = next_account_info(accounts_iter)?;
+    let _authority_info = next_account_info(accounts_iter)?;
+    let _extra_account_metas_info = next_account_info(accounts_iter)?;
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let source_holder_rewards_info = next_account_info(accounts_iter)?;
+    let destination_holder_rewards_info = next_account_info(accounts_iter)?;
+    let source_stake_checkpoints_info = next_account_info(accounts_iter)?;
+    let destination_stake_checkpoints_info = next_account_info(accounts_iter)?;
+    let destination_access_entry_info = next_account_info(accounts_iter)?;
+    let rate_history_info = next_account_info(accounts_iter)?;
+    let epoch_ledger_info = next_account_info(accounts_iter)?;
+
+    let source_owner = get_receipt_token_account_owner(receipt_mint_info.key, source_info)?;
+    let destination_owner =
+        get_receipt_token_account_owner(receipt_mint_info.key, destination_info)?;
+    check_receipt_transferring(source_info)?;
+
+    // Load the pool, which must have minted the receipt tokens.
+    if holder_rewards_pool_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_state = bytemuck::try_from_bytes_mut::<HolderRewardsPool>(&mut pool_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    if pool_state.receipts == 0
+        || create_receipt_mint_address(
+            holder_rewards_pool_info.key,
+            pool_state.receipt_mint_bump_seed,
+            program_id,
+        )
+        .as_ref()
+            != Ok(receipt_mint_info.key)
+    {
+        return Err(PaladinRewardsError::IncorrectReceiptMintAddress.into());
+    }
+
+    // Moving receipt tokens between an owner's own accounts moves nothing.
+    if source_owner == destination_owner {
+        return Ok(());
+    }
+
+    // Load both holders' rewards.
+    check_holder_rewards(program_id, &source_owner, source_holder_rewards_info)?;
+    let mut source_data = source_holder_rewards_info.try_borrow_mut_data()?;
+    let source_state = bytemuck::try_from_bytes_mut::<HolderRewards>(&mut source_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    // The recipient must initialize a holder rewards account before they can
+    // receive receipts.
+    if destination_holder_rewards_info.data_is_empty() {
+        return Err(PaladinRewardsError::ReceiptRecipientNotInitialized.into());
+    }
+    check_holder_rewards(
+        program_id,
+        &destination_owner,
+        destination_holder_rewards_info,
+    )?;
+    let mut destination_data = destination_holder_rewards_info.try_borrow_mut_data()?;
+    let destination_state = bytemuck::try_from_bytes_mut::<HolderRewards>(&mut destination_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    check_access_list(
+        program_id,
+        holder_rewards_pool_info.key,
+        pool_state,
+        &destination_owner,
+        Some(destination_access_entry_info),
+    )?;
+
+    // Handle any lamports received since last harvest. The rate history and
+    // epoch ledger accounts are always resolved, but only exist for pools
+    // recording them.
+    update_accumulated_rewards_per_token(
+        program_id,
+        holder_rewards_pool_info,
+        pool_state,
+        (pool_state.rate_history != 0).then_some(rate_history_info),
+        (pool_state.epoch_ledger != 0).then_some(epoch_ledger_info),
+    )?;
+
+    let clock = Clock::get()?;
+
+    // Move the deposit out of the source holder's account. Locked tokens
+    // cannot be transferred until the lockup expires.
+    settle_rewards(pool_state, source_state)?;
+    release_expired_lockup(
+        holder_rewards_pool_info.key,
+        &source_owner,
+        pool_state,
+        source_state,
+        clock.unix_timestamp,
+    )?;
+
+    let unlocked = source_state
+        .deposited
+        .saturating_sub(source_state.locked_amount);
+    if amount > unlocked {
+        return Err(PaladinRewardsError::ReceiptTransferExceedsUnlocked.into());
+    }
+
+    source_state.deposited = source_state
+        .deposited
+        .checked_sub(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    source_state.last_activity_slot = clock.slot;
+    // The stake checkpoints accounts are always resolved, but only exist for
+    // holders recording them.
+    record_stake_checkpoint(
+        program_id,
+        &source_owner,
+        source_state,
+        (source_state.stake_checkpoints != 0).then_some(source_stake_checkpoints_info),
+        clock.slot,
+    )?;
+
+    // Move the deposit into the destination holder's account, as if they
+    // had deposited it.
+    settle_rewards(pool_state, destination_state)?;
+    release_expired_lockup(
+        holder_rewards_pool_info.key,
+        &destination_owner,
+        pool_state,
+        destination_state,
+        clock.unix_timestamp,
+    )?;
+
+    let destination_deposited = destination_state
+        .deposited
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    if pool_state.max_holder_deposit != 0 && destination_deposited > pool_state.max_holder_deposit {
+        return Err(PaladinRewardsError::HolderDepositCapExceeded.into());
+    }
+
+    destination_state.deposited = destination_deposited;
+    if amount > 0 {
+        if destination_state.first_deposit_slot == 0 {
+            destination_state.first_deposit_slot = clock.slot;
+        }
+        destination_state.last_deposit_slot = clock.slot;
     }
+    destination_state.last_activity_slot = clock.slot;
+    record_stake_checkpoint(
+        program_id,
+        &destination_owner,
+        destination_state,
+        (destination_state.stake_checkpoints != 0).then_some(destination_stake_checkpoints_info),
+        clock.slot,
+    )?;
+
+    PaladinRewardsEvent::ReceiptsTransferred {
+        holder_rewards_pool: *holder_rewards_pool_info.key,
+        source_owner,
+        destination_owner,
+        amount,
+    }
+    .emit();
+
     Ok(())
 }
 
-/// Processes an
-/// [InitializeHolderRewards](enum.PaladinRewardsInstruction.html)
+/// Processes a
+/// [SetSlashingConfig](enum.PaladinRewardsInstruction.html)
 /// instruction.
-fn process_initialize_holder_rewards(
-    program_id: &Pubkey,
-    accounts: &[AccountInfo],
-) -> ProgramResult {
+fn process_set_slashing_config(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
 
     let holder_rewards_pool_info = next_account_info(accounts_iter)?;
-    let holder_rewards_pool_token_account_info = next_account_info(accounts_iter)?;
-    let owner = next_account_info(accounts_iter)?;
-    let holder_rewards_info = next_account_info(accounts_iter)?;
     let mint_info = next_account_info(accounts_iter)?;
-    let duna_document_info = next_account_info(accounts_iter)?;
-    let _system_program = next_account_info(accounts_iter)?;
-
-    validate_token_account(
-        holder_rewards_pool_token_account_info,
-        holder_rewards_pool_info.key,
-        mint_info.key,
-    )?;
-
-    // Confirm owner is the signer
-    if !owner.is_signer {
-        return Err(PaladinRewardsError::OwnerNotSigner.into());
-    }
+    let authority_info = next_account_info(accounts_iter)?;
+    let slashing_authority_info = next_account_info(accounts_iter)?;
+    let slash_destination_info = next_account_info(accounts_iter)?;
 
-    // Run checks on the pool account.
     check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
     let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
     let pool_state = bytemuck::try_from_bytes_mut::<HolderRewardsPool>(&mut pool_data)
         .map_err(|_| ProgramError::InvalidAccountData)?;
 
-    // Check duna is signed
-    check_duna_document_signed(
-        owner.key,
-        duna_document_info,
-        &pool_state.duna_document_hash,
-    )?;
-
-    // Process any received lamports.
-    update_accumulated_rewards_per_token(
-        mint_info,
-        holder_rewards_pool_info,
-        holder_rewards_pool_token_account_info,
-        pool_state,
-    )?;
-
-    // Initialize the holder rewards account.
-    {
-        let (holder_rewards_address, bump_seed) =
-            get_holder_rewards_address_and_bump_seed(owner.key, program_id);
-        let bump_seed = [bump_seed];
-        let holder_rewards_signer_seeds =
-            collect_holder_rewards_signer_seeds(owner.key, &bump_seed);
-
-        // Ensure the provided holder rewards address is the correct address
-        // derived from the token account.
-        if holder_rewards_info.key != &holder_rewards_address {
-            return Err(PaladinRewardsError::IncorrectHolderRewardsAddress.into());
-        }
+    // Ensure the pool authority is the signer.
+    if authority_info.key != &pool_state.authority {
+        return Err(PaladinRewardsError::IncorrectPoolAuthority.into());
+    }
 
-        // Ensure the holder rewards account has not already been initialized.
-        if holder_rewards_info.data.borrow().len() != 0 {
-            return Err(ProgramError::AccountAlreadyInitialized);
-        }
+    // Slashed tokens must be receivable, unless slashing is being disabled.
+    if slashing_authority_info.key != &Pubkey::default() {
+        validate_token_account(slash_destination_info, None, mint_info.key)?;
+    }
 
-        // Allocate & assign.
-        invoke_signed(
-            &system_instruction::allocate(&holder_rewards_address, HolderRewards::LEN as u64),
-            &[holder_rewards_info.clone()],
-            &[&holder_rewards_signer_seeds],
-        )?;
-        invoke_signed(
-            &system_instruction::assign(&holder_rewards_address, program_id),
-            &[holder_rewards_info.clone()],
-            &[&holder_rewards_signer_seeds],
-        )?;
-        assert_rent_exempt(holder_rewards_info);
+    pool_state.slashing_authority = *slashing_authority_info.key;
+    pool_state.slash_destination = *slash_destination_info.key;
 
-        // Write the data.
-        let mut data = holder_rewards_info.try_borrow_mut_data()?;
-        *bytemuck::try_from_bytes_mut(&mut data).map_err(|_| ProgramError::InvalidAccountData)? =
-            HolderRewards {
-                last_accumulated_rewards_per_token: pool_state.accumulated_rewards_per_token,
-                deposited: 0,
-                _padding: 0,
-            };
+    PaladinRewardsEvent::SlashingConfigSet {
+        holder_rewards_pool: *holder_rewards_pool_info.key,
+        slashing_authority: *slashing_authority_info.key,
+        slash_destination: *slash_destination_info.key,
     }
+    .emit();
 
     Ok(())
 }
 
-/// Processes a [HarvestRewards](enum.PaladinRewardsInstruction.html)
+/// Processes a
+/// [Slash](enum.PaladinRewardsInstruction.html)
 /// instruction.
-fn process_harvest_rewards(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+fn process_slash(program_id: &Pubkey, accounts: &[AccountInfo], amount: u64) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
 
     let holder_rewards_pool_info = next_account_info(accounts_iter)?;
     let holder_rewards_pool_token_account_info = next_account_info(accounts_iter)?;
     let holder_rewards_info = next_account_info(accounts_iter)?;
+    let slash_destination_info = next_account_info(accounts_iter)?;
     let mint_info = next_account_info(accounts_iter)?;
     let owner = next_account_info(accounts_iter)?;
-
-    // Ensure signer is the owner and can close this account
-    if !owner.is_signer {
-        return Err(PaladinRewardsError::OwnerNotSigner.into());
-    }
+    let slashing_authority_info = next_account_info(accounts_iter)?;
+    let token_program = next_account_info(accounts_iter)?;
+    let rate_history_info = next_optional_account_info(program_id, accounts_iter);
+    let stake_checkpoints_info = next_optional_account_info(program_id, accounts_iter);
+    let vote_escrow_info = next_optional_account_info(program_id, accounts_iter);
+    let epoch_ledger_info = next_optional_account_info(program_id, accounts_iter);
 
     validate_token_account(
         holder_rewards_pool_token_account_info,
-        holder_rewards_pool_info.key,
+        Some(holder_rewards_pool_info.key),
         mint_info.key,
     )?;
+    validate_token_account(slash_destination_info, None, mint_info.key)?;
 
-    // Check & load the pool
+    // Load the pool, which must have slashing enabled for the signer.
     check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
     let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
     let pool_state = bytemuck::try_from_bytes_mut::<HolderRewardsPool>(&mut pool_data)
         .map_err(|_| ProgramError::InvalidAccountData)?;
+    if pool_state.slashing_authority == Pubkey::default()
+        || slashing_authority_info.key != &pool_state.slashing_authority
+    {
+        return Err(PaladinRewardsError::IncorrectSlashingAuthority.into());
+    }
+    if slash_destination_info.key != &pool_state.slash_destination {
+        return Err(PaladinRewardsError::IncorrectSlashDestination.into());
+    }
 
-    // Check & load the holder rewards.
+    // Slashing would leave receipt tokens in circulation without a deposit
+    // to redeem them for.
+    if pool_state.receipts != 0 {
+        return Err(PaladinRewardsError::ReceiptPoolNotSlashable.into());
+    }
+
+    // Load the holder rewards.
     check_holder_rewards(program_id, owner.key, holder_rewards_info)?;
     let mut holder_rewards_data = holder_rewards_info.try_borrow_mut_data()?;
     let holder_rewards_state =
         bytemuck::try_from_bytes_mut::<HolderRewards>(&mut holder_rewards_data)
             .map_err(|_| ProgramError::InvalidAccountData)?;
 
+    if amount > holder_rewards_state.deposited {
+        return Err(PaladinRewardsError::SlashExceedsDeposited.into());
+    }
+
     // Handle any lamports received since last harvest.
     update_accumulated_rewards_per_token(
-        mint_info,
+        program_id,
         holder_rewards_pool_info,
-        holder_rewards_pool_token_account_info,
         pool_state,
+        rate_history_info,
+        epoch_ledger_info,
     )?;
 
-    // Determine the amount the holder can harvest.
-    let rewards_to_harvest = calculate_rewards_to_harvest(
-        holder_rewards_state,
+    // Rewards earned before the slash are still owed to the holder.
+    settle_rewards(pool_state, holder_rewards_state)?;
+
+    let clock = Clock::get()?;
+    release_expired_lockup(
+        holder_rewards_pool_info.key,
+        owner.key,
         pool_state,
-        holder_rewards_pool_info.lamports(),
+        holder_rewards_state,
+        clock.unix_timestamp,
     )?;
 
-    if rewards_to_harvest > 0 {
-        send_rewards(
-            holder_rewards_pool_info.clone(),
-            owner.clone(),
+    holder_rewards_state.deposited = holder_rewards_state
+        .deposited
+        .checked_sub(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    holder_rewards_state.slashed = holder_rewards_state
+        .slashed
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    pool_state.total_deposited = pool_state
+        .total_deposited
+        .checked_sub(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    pool_state.total_slashed = pool_state
+        .total_slashed
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // Shrink the lockup to fit the remaining deposit, along with any voting
+    // power it counts toward.
+    if holder_rewards_state.locked_amount > holder_rewards_state.deposited {
+        let locked_amount = holder_rewards_state.deposited;
+        set_lockup(
             pool_state,
-            rewards_to_harvest,
+            holder_rewards_state,
+            locked_amount,
+            holder_rewards_state.unlock_timestamp,
+            holder_rewards_state.lockup_multiplier_bps,
         )?;
+
+        if holder_rewards_state.voting_power_locked > locked_amount {
+            match vote_escrow_info {
+                Some(vote_escrow_info) => {
+                    check_vote_escrow(program_id, holder_rewards_pool_info.key, vote_escrow_info)?;
+                    if !vote_escrow_info.is_writable {
+                        return Err(PaladinRewardsError::AccountNotWritable.into());
+                    }
+                    let mut vote_escrow_data = vote_escrow_info.try_borrow_mut_data()?;
+                    let vote_escrow_state =
+                        bytemuck::try_from_bytes_mut::<VoteEscrow>(&mut vote_escrow_data)
+                            .map_err(|_| ProgramError::InvalidAccountData)?;
+
+                    vote_escrow_state.checkpoint(clock.unix_timestamp);
+                    vote_escrow_state
+                        .remove(
+                            holder_rewards_state.voting_power_locked,
+                            holder_rewards_state.voting_power_end,
+                        )
+                        .and_then(|()| {
+                            vote_escrow_state
+                                .add(locked_amount, holder_rewards_state.voting_power_end)
+                        })
+                        .ok_or(ProgramError::ArithmeticOverflow)?;
+                    holder_rewards_state.voting_power_locked = locked_amount;
+                }
+                None if holder_rewards_state.voting_power_end > clock.unix_timestamp => {
+                    return Err(PaladinRewardsError::VoteEscrowRequired.into());
+                }
+                None => {}
+            }
+        }
+    }
+
+    holder_rewards_state.last_activity_slot = clock.slot;
+    record_stake_checkpoint(
+        program_id,
+        owner.key,
+        holder_rewards_state,
+        stake_checkpoints_info,
+        clock.slot,
+    )?;
+
+    // Get pool token account signer seeds.
+    let bump_seed = [pool_state.bump_seed];
+    let holder_rewards_pool_signer_seeds =
+        collect_holder_rewards_pool_signer_seeds(mint_info.key, &bump_seed);
+
+    // Transfer the slashed tokens from the pool to the slash destination.
+    let transfer_ix = transfer(
+        &spl_token::ID,
+        holder_rewards_pool_token_account_info.key,
+        slash_destination_info.key,
+        holder_rewards_pool_info.key,
+        &[holder_rewards_pool_info.key],
+        amount,
+    )?;
+
+    drop(pool_data);
+    invoke_signed(
+        &transfer_ix,
+        &[
+            holder_rewards_pool_token_account_info.clone(),
+            slash_destination_info.clone(),
+            holder_rewards_pool_info.clone(),
+            token_program.clone(),
+        ],
+        &[&holder_rewards_pool_signer_seeds],
+    )?;
+
+    PaladinRewardsEvent::Slashed {
+        holder_rewards_pool: *holder_rewards_pool_info.key,
+        owner: *owner.key,
+        amount,
+        deposited: holder_rewards_state.deposited,
     }
+    .emit();
 
     Ok(())
 }
 
 /// Processes a
-/// [CloseHolderRewards](enum.PaladinRewardsInstruction.html)
+/// [CreateCampaign](enum.PaladinRewardsInstruction.html)
 /// instruction.
-fn process_close_holder_rewards(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+fn process_create_campaign(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+    start_slot: u64,
+    end_slot: u64,
+) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
 
     let holder_rewards_pool_info = next_account_info(accounts_iter)?;
-    let holder_rewards_pool_token_account_info = next_account_info(accounts_iter)?;
-    let holder_rewards_info = next_account_info(accounts_iter)?;
+    let campaign_info = next_account_info(accounts_iter)?;
     let mint_info = next_account_info(accounts_iter)?;
-    let owner = next_account_info(accounts_iter)?;
+    let funder_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
 
-    validate_token_account(
-        holder_rewards_pool_token_account_info,
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let pool_data = holder_rewards_pool_info.try_borrow_data()?;
+    let pool_state = bytemuck::try_from_bytes::<HolderRewardsPool>(&pool_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Ensure the campaign unlocks over at least one slot, and has not already
+    // ended.
+    let clock = Clock::get()?;
+    if start_slot >= end_slot || end_slot <= clock.slot {
+        return Err(PaladinRewardsError::InvalidCampaignSchedule.into());
+    }
+
+    let (campaign_address, bump_seed) = get_campaign_address_and_bump_seed(
         holder_rewards_pool_info.key,
-        mint_info.key,
+        funder_info.key,
+        program_id,
+    );
+
+    // Ensure the provided campaign address is the correct address derived
+    // from the pool and the funder.
+    if campaign_info.key != &campaign_address {
+        return Err(PaladinRewardsError::IncorrectCampaignAddress.into());
+    }
+
+    // Ensure the funder's previous campaign, if any, was reclaimed.
+    if !campaign_info.data_is_empty() {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    let bump_seed = [bump_seed];
+    let campaign_signer_seeds =
+        collect_campaign_signer_seeds(holder_rewards_pool_info.key, funder_info.key, &bump_seed);
+
+    create_pda_account(
+        program_id,
+        funder_info,
+        campaign_info,
+        Campaign::LEN,
+        &campaign_signer_seeds,
+    )?;
+
+    // Escrow the rewards on top of the rent.
+    invoke(
+        &system_instruction::transfer(funder_info.key, campaign_info.key, amount),
+        &[funder_info.clone(), campaign_info.clone()],
     )?;
 
-    // Ensure signer is the owner and can close this account
-    if !owner.is_signer {
-        return Err(PaladinRewardsError::OwnerNotSigner.into());
+    // Write the data.
+    let mut data = campaign_info.try_borrow_mut_data()?;
+    *bytemuck::try_from_bytes_mut::<Campaign>(&mut data)
+        .map_err(|_| ProgramError::InvalidAccountData)? = Campaign {
+        holder_rewards_pool: *holder_rewards_pool_info.key,
+        funder: *funder_info.key,
+        amount,
+        start_slot,
+        end_slot,
+        released: 0,
+        unspent: 0,
+        last_release_slot: clock.slot,
+        zero_weight_slots: pool_state
+            .zero_weight_slots_at(clock.slot)
+            .ok_or(ProgramError::ArithmeticOverflow)?,
+        bump_seed: bump_seed[0],
+        _padding: [0; 7],
+    };
+
+    PaladinRewardsEvent::CampaignCreated {
+        holder_rewards_pool: *holder_rewards_pool_info.key,
+        campaign: *campaign_info.key,
+        funder: *funder_info.key,
+        amount,
+        start_slot,
+        end_slot,
     }
+    .emit();
+
+    Ok(())
+}
+
+/// Processes a
+/// [ReleaseCampaignRewards](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_release_campaign_rewards(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let campaign_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let rate_history_info = next_optional_account_info(program_id, accounts_iter);
+    let epoch_ledger_info = next_optional_account_info(program_id, accounts_iter);
 
-    // Load pool & holder rewards.
     check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
     let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
     let pool_state = bytemuck::try_from_bytes_mut::<HolderRewardsPool>(&mut pool_data)
         .map_err(|_| ProgramError::InvalidAccountData)?;
-    check_holder_rewards(program_id, owner.key, holder_rewards_info)?;
-    let holder_rewards_data = holder_rewards_info.try_borrow_data()?;
-    let holder_rewards_state = bytemuck::try_from_bytes::<HolderRewards>(&holder_rewards_data)
+
+    check_campaign(program_id, holder_rewards_pool_info.key, campaign_info)?;
+    let mut campaign_data = campaign_info.try_borrow_mut_data()?;
+    let campaign_state = bytemuck::try_from_bytes_mut::<Campaign>(&mut campaign_data)
         .map_err(|_| ProgramError::InvalidAccountData)?;
 
-    // Handle any lamports received since last harvest.
-    update_accumulated_rewards_per_token(
-        mint_info,
+    release_campaign_rewards(
+        program_id,
         holder_rewards_pool_info,
-        holder_rewards_pool_token_account_info,
         pool_state,
-    )?;
+        campaign_info,
+        campaign_state,
+        rate_history_info,
+        epoch_ledger_info,
+    )
+}
 
-    // Ensure holder has no unclaimed rewards.
-    if holder_rewards_state.last_accumulated_rewards_per_token
-        < pool_state.accumulated_rewards_per_token
-    {
-        return Err(PaladinRewardsError::CloseWithUnclaimedRewards.into());
+/// Processes a
+/// [ReclaimCampaignRewards](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_reclaim_campaign_rewards(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let campaign_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let funder_info = next_account_info(accounts_iter)?;
+    let rate_history_info = next_optional_account_info(program_id, accounts_iter);
+    let epoch_ledger_info = next_optional_account_info(program_id, accounts_iter);
+
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_state = bytemuck::try_from_bytes_mut::<HolderRewardsPool>(&mut pool_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    check_campaign(program_id, holder_rewards_pool_info.key, campaign_info)?;
+    let mut campaign_data = campaign_info.try_borrow_mut_data()?;
+    let campaign_state = bytemuck::try_from_bytes_mut::<Campaign>(&mut campaign_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Ensure the funder is the signer.
+    if funder_info.key != &campaign_state.funder {
+        return Err(PaladinRewardsError::IncorrectCampaignFunder.into());
     }
 
-    // Ensure holder withdrew all tokens
-    if holder_rewards_state.deposited > 0 {
-        return Err(PaladinRewardsError::CloseWithDepositedTokens.into());
+    if Clock::get()?.slot < campaign_state.end_slot {
+        return Err(PaladinRewardsError::CampaignNotEnded.into());
     }
 
-    drop(holder_rewards_data);
+    // Release the remaining rewards, leaving only the unspent rewards and
+    // the rent in the campaign account.
+    release_campaign_rewards(
+        program_id,
+        holder_rewards_pool_info,
+        pool_state,
+        campaign_info,
+        campaign_state,
+        rate_history_info,
+        epoch_ledger_info,
+    )?;
+
+    let unspent = campaign_state.unspent;
+    drop(campaign_data);
 
     // NB: If this overflows then the runtime will catch it.
     #[allow(clippy::arithmetic_side_effects)]
     {
-        **owner.lamports.borrow_mut() += holder_rewards_info.lamports();
+        **funder_info.lamports.borrow_mut() += campaign_info.lamports();
     }
 
     // Close the account.
-    **holder_rewards_info.lamports.borrow_mut() = 0;
-    holder_rewards_info.realloc(0, true)?;
-    holder_rewards_info.assign(&system_program::ID);
+    **campaign_info.lamports.borrow_mut() = 0;
+    campaign_info.realloc(0, true)?;
+    campaign_info.assign(&system_program::ID);
+
+    PaladinRewardsEvent::CampaignReclaimed {
+        holder_rewards_pool: *holder_rewards_pool_info.key,
+        campaign: *campaign_info.key,
+        funder: *funder_info.key,
+        unspent,
+    }
+    .emit();
 
     Ok(())
 }
 
-/// Processes a [Deposit](enum.PaladinRewardsInstruction.html)
+/// Processes a
+/// [DistributeRewards](enum.PaladinRewardsInstruction.html)
 /// instruction.
-fn process_deposit(program_id: &Pubkey, accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+fn process_distribute_rewards(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+    memo: [u8; 32],
+) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
 
-    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
-    let holder_rewards_pool_token_account_info = next_account_info(accounts_iter)?;
-    let holder_rewards_info = next_account_info(accounts_iter)?;
-    let token_account_info = next_account_info(accounts_iter)?;
-    let mint_info = next_account_info(accounts_iter)?;
-    let owner = next_account_info(accounts_iter)?;
-    let token_program = next_account_info(accounts_iter)?;
-
-    // Ensure signer is the owner and can close this account.
-    if !owner.is_signer {
-        return Err(PaladinRewardsError::OwnerNotSigner.into());
-    }
-
-    // Validate pool token account.
-    validate_token_account(
-        holder_rewards_pool_token_account_info,
-        holder_rewards_pool_info.key,
-        mint_info.key,
-    )?;
-
-    // Validate the owner token account.
-    validate_token_account(token_account_info, owner.key, mint_info.key)?;
-
-    // Validate user has enough tokens to deposit.
-    let owner_balance = get_token_account_balance_checked(mint_info.key, token_account_info)?;
-    if owner_balance < amount {
-        return Err(PaladinRewardsError::NotEnoughTokenToDeposit.into());
-    }
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let funder_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+    let rate_history_info = next_optional_account_info(program_id, accounts_iter);
+    let epoch_ledger_info = next_optional_account_info(program_id, accounts_iter);
 
-    // Load pool & holder rewards.
     check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
     let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
     let pool_state = bytemuck::try_from_bytes_mut::<HolderRewardsPool>(&mut pool_data)
         .map_err(|_| ProgramError::InvalidAccountData)?;
-    check_holder_rewards(program_id, owner.key, holder_rewards_info)?;
-    let mut holder_rewards_data = holder_rewards_info.try_borrow_mut_data()?;
-    let holder_rewards_state =
-        bytemuck::try_from_bytes_mut::<HolderRewards>(&mut holder_rewards_data)
-            .map_err(|_| ProgramError::InvalidAccountData)?;
 
-    // Handle any lamports received since last harvest.
+    // Rewards distributed without weight in the pool would never accrue to
+    // any holder.
+    if pool_state
+        .total_weight()
+        .ok_or(ProgramError::ArithmeticOverflow)?
+        == 0
+    {
+        return Err(PaladinRewardsError::PoolHasNoWeight.into());
+    }
+
+    // Accrue any lamports received before the distribution separately, so
+    // they are not attributed to the funder.
     update_accumulated_rewards_per_token(
-        mint_info,
+        program_id,
         holder_rewards_pool_info,
-        holder_rewards_pool_token_account_info,
-        pool_state,
-    )?;
-
-    // Calculate rewards to harvest before new deposit
-    let rewards_to_harvest = calculate_rewards_to_harvest(
-        holder_rewards_state,
         pool_state,
-        holder_rewards_pool_info.lamports(),
+        rate_history_info,
+        epoch_ledger_info,
     )?;
 
-    // Update total deposited tokens
-    holder_rewards_state.deposited = holder_rewards_state
-        .deposited
-        .checked_add(amount)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-
-    // Transfer tokens from the owner to the holder rewards pool.
-    let transfer_ix = transfer(
-        &spl_token::ID,
-        token_account_info.key,
-        holder_rewards_pool_token_account_info.key,
-        owner.key,
-        &[owner.key],
-        amount,
+    drop(pool_data);
+    invoke(
+        &system_instruction::transfer(funder_info.key, holder_rewards_pool_info.key, amount),
+        &[funder_info.clone(), holder_rewards_pool_info.clone()],
     )?;
 
-    invoke(
-        &transfer_ix,
-        &[
-            token_account_info.clone(),
-            holder_rewards_pool_token_account_info.clone(),
-            owner.clone(),
-            token_program.clone(),
-        ],
+    // Accrue the distribution immediately.
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_state = bytemuck::try_from_bytes_mut::<HolderRewardsPool>(&mut pool_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    update_accumulated_rewards_per_token(
+        program_id,
+        holder_rewards_pool_info,
+        pool_state,
+        rate_history_info,
+        epoch_ledger_info,
     )?;
 
-    // Send rewards to the owner
-    if rewards_to_harvest > 0 {
-        send_rewards(
-            holder_rewards_pool_info.clone(),
-            owner.clone(),
-            pool_state,
-            rewards_to_harvest,
-        )?;
+    PaladinRewardsEvent::RewardsDistributed {
+        holder_rewards_pool: *holder_rewards_pool_info.key,
+        funder: *funder_info.key,
+        amount,
+        memo,
     }
+    .emit();
 
     Ok(())
 }
 
-/// Processes a [Withdraw](enum.PaladinRewardsInstruction.html)
+/// Processes an
+/// [InitializeEpochLedger](enum.PaladinRewardsInstruction.html)
 /// instruction.
-fn process_withdraw(program_id: &Pubkey, accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+fn process_initialize_epoch_ledger(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
 
     let holder_rewards_pool_info = next_account_info(accounts_iter)?;
-    let holder_rewards_pool_token_account_info = next_account_info(accounts_iter)?;
-    let holder_rewards_info = next_account_info(accounts_iter)?;
-    let token_account_info = next_account_info(accounts_iter)?;
+    let epoch_ledger_info = next_account_info(accounts_iter)?;
     let mint_info = next_account_info(accounts_iter)?;
-    let owner = next_account_info(accounts_iter)?;
-    let token_program = next_account_info(accounts_iter)?;
-
-    // Ensure signer is the owner and can close this account
-    if !owner.is_signer {
-        return Err(PaladinRewardsError::OwnerNotSigner.into());
-    }
-
-    // Validate pool token account
-    validate_token_account(
-        holder_rewards_pool_token_account_info,
-        holder_rewards_pool_info.key,
-        mint_info.key,
-    )?;
-
-    // Validate the owner token account
-    validate_token_account(token_account_info, owner.key, mint_info.key)?;
+    let payer_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
 
-    // Load pool & holder rewards.
     check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
     let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
     let pool_state = bytemuck::try_from_bytes_mut::<HolderRewardsPool>(&mut pool_data)
         .map_err(|_| ProgramError::InvalidAccountData)?;
-    check_holder_rewards(program_id, owner.key, holder_rewards_info)?;
-    let mut holder_rewards_data = holder_rewards_info.try_borrow_mut_data()?;
-    let holder_rewards_state =
-        bytemuck::try_from_bytes_mut::<HolderRewards>(&mut holder_rewards_data)
-            .map_err(|_| ProgramError::InvalidAccountData)?;
 
-    // Validate that we have enough deposited tokens to withdraw
-    let pool_balance =
-        get_token_account_balance_checked(mint_info.key, holder_rewards_pool_token_account_info)?;
-    let to_withdraw = if amount == u64::MAX {
-        holder_rewards_state.deposited
-    } else {
-        amount
-    };
+    let (epoch_ledger_address, bump_seed) =
+        get_epoch_ledger_address_and_bump_seed(holder_rewards_pool_info.key, program_id);
+    let bump_seed = [bump_seed];
+    let epoch_ledger_signer_seeds =
+        collect_epoch_ledger_signer_seeds(holder_rewards_pool_info.key, &bump_seed);
 
-    if holder_rewards_state.deposited == 0 {
-        return Err(PaladinRewardsError::NoDepositedTokensToWithdraw.into());
-    } else if holder_rewards_state.deposited > pool_balance {
-        return Err(PaladinRewardsError::WithdrawExceedsPoolBalance.into());
-    } else if to_withdraw > holder_rewards_state.deposited {
-        return Err(PaladinRewardsError::WithdrawExceedsDeposited.into());
+    // Ensure the provided epoch ledger address is the correct address
+    // derived from the pool.
+    if epoch_ledger_info.key != &epoch_ledger_address {
+        return Err(PaladinRewardsError::IncorrectEpochLedgerAddress.into());
     }
 
-    // Handle any lamports received since last harvest.
-    update_accumulated_rewards_per_token(
-        mint_info,
-        holder_rewards_pool_info,
-        holder_rewards_pool_token_account_info,
-        pool_state,
+    // Ensure the epoch ledger account has not already been initialized.
+    if epoch_ledger_info.data.borrow().len() != 0 {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    // Fund, allocate & assign.
+    create_pda_account(
+        program_id,
+        payer_info,
+        epoch_ledger_info,
+        EpochLedger::LEN,
+        &epoch_ledger_signer_seeds,
     )?;
 
-    // Calculate rewards to harvest before withdrawal
-    let rewards_to_harvest = match calculate_rewards_to_harvest(
-        holder_rewards_state,
-        pool_state,
-        holder_rewards_pool_info.lamports(),
-    ) {
-        Ok(rewards) => Ok(rewards),
-        Err(ProgramError::Custom(err)) => {
-            // If the pool does not have enough lamports to cover the rewards,
-            // we set the amount to 0
-            if err == PaladinRewardsError::RewardsExcessPoolBalance as u32 {
-                Ok(0)
-            } else {
-                return Err(ProgramError::Custom(err));
-            }
-        }
-        Err(err) => Err(err),
-    }?;
+    // Write the data, seeded with the pool's current totals so only rewards
+    // accrued from now on are recorded.
+    let mut data = epoch_ledger_info.try_borrow_mut_data()?;
+    let state = bytemuck::try_from_bytes_mut::<EpochLedger>(&mut data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    state.bump_seed = bump_seed[0];
+    state.last_accumulated_rewards_per_token = pool_state.accumulated_rewards_per_token;
+    state.last_total_rewards_received = pool_state.total_rewards_received;
+    pool_state.epoch_ledger = 1;
 
-    // Update total deposited tokens
-    holder_rewards_state.deposited = holder_rewards_state
-        .deposited
-        .checked_sub(to_withdraw)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
+    PaladinRewardsEvent::EpochLedgerInitialized {
+        holder_rewards_pool: *holder_rewards_pool_info.key,
+    }
+    .emit();
 
-    // Get pool token account signer seeds.
-    let (_, bump_seed) = get_holder_rewards_pool_address_and_bump_seed(mint_info.key, program_id);
-    let bump_seed = [bump_seed];
-    let holder_rewards_pool_signer_seeds =
-        collect_holder_rewards_pool_signer_seeds(mint_info.key, &bump_seed);
+    Ok(())
+}
 
-    // Transfer tokens from the pool to the owner.
-    let transfer_ix = transfer(
-        &spl_token::ID,
-        holder_rewards_pool_token_account_info.key,
-        token_account_info.key,
-        holder_rewards_pool_info.key,
-        &[holder_rewards_pool_info.key],
-        to_withdraw,
-    )?;
+/// Processes a
+/// [MigrateHolderRewards](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_migrate_holder_rewards(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
 
-    drop(pool_data);
-    invoke_signed(
-        &transfer_ix,
-        &[
-            holder_rewards_pool_token_account_info.clone(),
-            token_account_info.clone(),
-            holder_rewards_pool_info.clone(),
-            token_program.clone(),
-        ],
-        &[&holder_rewards_pool_signer_seeds],
-    )?;
+    let holder_rewards_info = next_account_info(accounts_iter)?;
+    let owner_info = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
 
-    // re-borrow the pool data to use in `send_rewards`
-    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
-    let pool_state = bytemuck::try_from_bytes_mut::<HolderRewardsPool>(&mut pool_data)
-        .map_err(|_| ProgramError::InvalidAccountData)?;
+    // Ensure the holder rewards account is owned by the Paladin Rewards
+    // program.
+    if holder_rewards_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
 
-    // Send rewards to the owner
-    if rewards_to_harvest > 0 {
-        send_rewards(
-            holder_rewards_pool_info.clone(),
-            owner.clone(),
-            pool_state,
-            rewards_to_harvest,
-        )?;
+    // Ensure the holder rewards account still has the baseline layout.
+    let legacy_len = holder_rewards_info.data_len();
+    if legacy_len == HolderRewards::LEN {
+        return Err(PaladinRewardsError::HolderRewardsAlreadyMigrated.into());
+    }
+    if legacy_len != HolderRewards::BASELINE_LEN {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // The baseline layout is a prefix of the current layout.
+    let mut holder_rewards_state: HolderRewards = bytemuck::Zeroable::zeroed();
+    bytemuck::bytes_of_mut(&mut holder_rewards_state)[..legacy_len]
+        .copy_from_slice(&holder_rewards_info.try_borrow_data()?);
+
+    // Ensure the provided holder rewards address is the correct address
+    // derived from the owner. The baseline layout does not store the bump
+    // seed, so derive it.
+    let (holder_rewards_address, bump_seed) =
+        get_holder_rewards_address_and_bump_seed(owner_info.key, program_id);
+    if holder_rewards_info.key != &holder_rewards_address {
+        return Err(PaladinRewardsError::IncorrectHolderRewardsAddress.into());
+    }
+    holder_rewards_state.bump_seed = bump_seed;
+
+    // The baseline program refunded the owner on close.
+    holder_rewards_state.rent_payer = *owner_info.key;
+
+    // Fund the larger account, then resize it.
+    top_up_rent_exemption(payer_info, holder_rewards_info, HolderRewards::LEN)?;
+    holder_rewards_info.realloc(HolderRewards::LEN, true)?;
+
+    let mut data = holder_rewards_info.try_borrow_mut_data()?;
+    *bytemuck::try_from_bytes_mut(&mut data).map_err(|_| ProgramError::InvalidAccountData)? =
+        holder_rewards_state;
+
+    PaladinRewardsEvent::HolderRewardsMigrated {
+        holder_rewards: *holder_rewards_info.key,
     }
+    .emit();
 
     Ok(())
 }
@@ -879,7 +4682,15 @@ fn process_withdraw(program_id: &Pubkey, accounts: &[AccountInfo], amount: u64)
 /// Processes a
 /// [PaladinRewardsInstruction](enum.PaladinRewardsInstruction.html).
 pub fn process(program_id: &Pubkey, accounts: &[AccountInfo], input: &[u8]) -> ProgramResult {
+    // Receipt token transfers invoke the program as their transfer hook.
+    if let Ok(TransferHookInstruction::Execute { amount }) = TransferHookInstruction::unpack(input)
+    {
+        msg!("Instruction: Execute");
+        return process_execute(program_id, accounts, amount);
+    }
+
     let instruction = PaladinRewardsInstruction::unpack(input)?;
+    validate_accounts(&instruction, accounts)?;
     match instruction {
         PaladinRewardsInstruction::InitializeHolderRewardsPool { duna_document_hash } => {
             msg!("Instruction: InitializeHolderRewardsPool");
@@ -905,327 +4716,186 @@ pub fn process(program_id: &Pubkey, accounts: &[AccountInfo], input: &[u8]) -> P
             msg!("Instruction: Withdraw");
             process_withdraw(program_id, accounts, amount)
         }
+        PaladinRewardsInstruction::RecoverExcessTokens => {
+            msg!("Instruction: RecoverExcessTokens");
+            process_recover_excess_tokens(program_id, accounts)
+        }
+        PaladinRewardsInstruction::GetPendingRewards => {
+            msg!("Instruction: GetPendingRewards");
+            process_get_pending_rewards(program_id, accounts)
+        }
+        PaladinRewardsInstruction::MigrateHolderRewardsPool => {
+            msg!("Instruction: MigrateHolderRewardsPool");
+            process_migrate_holder_rewards_pool(program_id, accounts)
+        }
+        PaladinRewardsInstruction::InitializeRateHistory => {
+            msg!("Instruction: InitializeRateHistory");
+            process_initialize_rate_history(program_id, accounts)
+        }
+        PaladinRewardsInstruction::SetLockupTiers { tiers } => {
+            msg!("Instruction: SetLockupTiers");
+            process_set_lockup_tiers(program_id, accounts, tiers)
+        }
+        PaladinRewardsInstruction::Lock {
+            amount,
+            unlock_timestamp,
+        } => {
+            msg!("Instruction: Lock");
+            process_lock(program_id, accounts, amount, unlock_timestamp)
+        }
+        PaladinRewardsInstruction::ReleaseLockup => {
+            msg!("Instruction: ReleaseLockup");
+            process_release_lockup(program_id, accounts)
+        }
+        PaladinRewardsInstruction::InitializeVoteEscrow { reward_weighting } => {
+            msg!("Instruction: InitializeVoteEscrow");
+            process_initialize_vote_escrow(program_id, accounts, reward_weighting)
+        }
+        PaladinRewardsInstruction::CheckpointVotingPower => {
+            msg!("Instruction: CheckpointVotingPower");
+            process_checkpoint_voting_power(program_id, accounts)
+        }
+        PaladinRewardsInstruction::GetVotingPower => {
+            msg!("Instruction: GetVotingPower");
+            process_get_voting_power(program_id, accounts)
+        }
+        PaladinRewardsInstruction::InitializeStakeCheckpoints => {
+            msg!("Instruction: InitializeStakeCheckpoints");
+            process_initialize_stake_checkpoints(program_id, accounts)
+        }
+        PaladinRewardsInstruction::GetStakeAt { slot } => {
+            msg!("Instruction: GetStakeAt");
+            process_get_stake_at(program_id, accounts, slot)
+        }
+        PaladinRewardsInstruction::SetEarlyWithdrawalPenalty {
+            penalty_bps,
+            period_slots,
+        } => {
+            msg!("Instruction: SetEarlyWithdrawalPenalty");
+            process_set_early_withdrawal_penalty(program_id, accounts, penalty_bps, period_slots)
+        }
+        PaladinRewardsInstruction::SetDepositLimits {
+            max_total_deposit,
+            max_holder_deposit,
+            min_deposit,
+        } => {
+            msg!("Instruction: SetDepositLimits");
+            process_set_deposit_limits(
+                program_id,
+                accounts,
+                max_total_deposit,
+                max_holder_deposit,
+                min_deposit,
+            )
+        }
+        PaladinRewardsInstruction::SetAccessList { access_list } => {
+            msg!("Instruction: SetAccessList");
+            process_set_access_list(program_id, accounts, access_list)
+        }
+        PaladinRewardsInstruction::SetAccessEntry { status } => {
+            msg!("Instruction: SetAccessEntry");
+            process_set_access_entry(program_id, accounts, status)
+        }
+        PaladinRewardsInstruction::InitializeReceiptMint => {
+            msg!("Instruction: InitializeReceiptMint");
+            process_initialize_receipt_mint(program_id, accounts)
+        }
+        PaladinRewardsInstruction::EmergencyWithdraw { amount } => {
+            msg!("Instruction: EmergencyWithdraw");
+            process_emergency_withdraw(program_id, accounts, amount)
+        }
+        PaladinRewardsInstruction::SetSlashingConfig => {
+            msg!("Instruction: SetSlashingConfig");
+            process_set_slashing_config(program_id, accounts)
+        }
+        PaladinRewardsInstruction::Slash { amount } => {
+            msg!("Instruction: Slash");
+            process_slash(program_id, accounts, amount)
+        }
+        PaladinRewardsInstruction::CreateCampaign {
+            amount,
+            start_slot,
+            end_slot,
+        } => {
+            msg!("Instruction: CreateCampaign");
+            process_create_campaign(program_id, accounts, amount, start_slot, end_slot)
+        }
+        PaladinRewardsInstruction::ReleaseCampaignRewards => {
+            msg!("Instruction: ReleaseCampaignRewards");
+            process_release_campaign_rewards(program_id, accounts)
+        }
+        PaladinRewardsInstruction::ReclaimCampaignRewards => {
+            msg!("Instruction: ReclaimCampaignRewards");
+            process_reclaim_campaign_rewards(program_id, accounts)
+        }
+        PaladinRewardsInstruction::DistributeRewards { amount, memo } => {
+            msg!("Instruction: DistributeRewards");
+            process_distribute_rewards(program_id, accounts, amount, memo)
+        }
+        PaladinRewardsInstruction::InitializeEpochLedger => {
+            msg!("Instruction: InitializeEpochLedger");
+            process_initialize_epoch_ledger(program_id, accounts)
+        }
+        PaladinRewardsInstruction::MigrateHolderRewards => {
+            msg!("Instruction: MigrateHolderRewards");
+            process_migrate_holder_rewards(program_id, accounts)
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use {super::*, proptest::prelude::*};
-
-    const BENCH_TOKEN_SUPPLY: u64 = 1_000_000_000 * 1_000_000_000; // 1 billion with 9 decimals
-
-    #[test]
-    fn minimum_rewards_per_token() {
-        // 1 lamport (arithmetic minimum)
-        let minimum_reward = 1;
-        let result = calculate_rewards_per_token(minimum_reward, BENCH_TOKEN_SUPPLY).unwrap();
-        assert_ne!(result, 0);
-
-        // Anything below the minimum should return zero.
-        let result = calculate_rewards_per_token(minimum_reward - 1, BENCH_TOKEN_SUPPLY).unwrap();
-        assert_eq!(result, 0);
-    }
-
-    #[test]
-    fn maximum_rewards_per_token() {
-        // u64::MAX (not really practical, but shows that we're ok)
-        let maximum_reward = u64::MAX;
-        let _ = calculate_rewards_per_token(maximum_reward, BENCH_TOKEN_SUPPLY).unwrap();
-    }
-
-    #[test]
-    fn minimum_eligible_rewards() {
-        // 1 / 1e18 lamports per token
-        let minimum_marginal_rewards_per_token = 1;
-        let result = calculate_eligible_rewards(
-            minimum_marginal_rewards_per_token,
-            0,
-            BENCH_TOKEN_SUPPLY, // 100% of the supply.
-        )
-        .unwrap();
-        assert_ne!(result, 0);
-
-        // Anything below the minimum should return zero.
-        let result = calculate_eligible_rewards(
-            minimum_marginal_rewards_per_token - 1,
-            0,
-            BENCH_TOKEN_SUPPLY, // 100% of the supply.
-        )
-        .unwrap();
-        assert_eq!(result, 0);
-    }
-
-    #[test]
-    fn minimum_eligible_rewards_with_one_token() {
-        // 1 / 1e9 lamports per token
-        let minimum_marginal_rewards_per_token = 1_000_000_000;
-        let result = calculate_eligible_rewards(
-            minimum_marginal_rewards_per_token,
-            0,
-            BENCH_TOKEN_SUPPLY / 1_000_000_000, // 1 with 9 decimals.
-        )
-        .unwrap();
-        assert_ne!(result, 0);
-
-        // Anything below the minimum should return zero.
-        let result = calculate_eligible_rewards(
-            minimum_marginal_rewards_per_token - 1,
-            0,
-            BENCH_TOKEN_SUPPLY / 1_000_000_000, // 1 with 9 decimals.
-        )
-        .unwrap();
-        assert_eq!(result, 0);
-    }
-
-    #[test]
-    fn minimum_eligible_rewards_with_smallest_fractional_token() {
-        // 1 lamport per token
-        let minimum_marginal_rewards_per_token = 1_000_000_000_000_000_000;
-        let result = calculate_eligible_rewards(
-            minimum_marginal_rewards_per_token,
-            0,
-            BENCH_TOKEN_SUPPLY / 1_000_000_000_000_000_000, // .000_000_001 with 9 decimals.
-        )
-        .unwrap();
-        assert_ne!(result, 0);
-
-        // Anything below the minimum should return zero.
-        let result = calculate_eligible_rewards(
-            minimum_marginal_rewards_per_token - 1,
-            0,
-            BENCH_TOKEN_SUPPLY / 1_000_000_000_000_000_000, // .000_000_001 with 9 decimals.
-        )
-        .unwrap();
-        assert_eq!(result, 0);
+    use super::*;
+
+    // Unpacks an instruction with zeroed arguments. Unpacking checks the
+    // length of most arguments, so each length is tried in turn, longest
+    // first.
+    fn unpack_zeroed(discriminant: u8) -> Option<PaladinRewardsInstruction> {
+        (0..=64).rev().find_map(|len| {
+            let mut data = vec![0; len + 1];
+            data[0] = discriminant;
+            PaladinRewardsInstruction::unpack(&data).ok()
+        })
     }
 
     #[test]
-    fn maximum_eligible_rewards() {
-        // 1 lamport per token (not really practical, but shows that we're ok)
-        let maximum_marginal_rewards_per_token = REWARDS_PER_TOKEN_SCALING_FACTOR;
-        let _ = calculate_eligible_rewards(
-            maximum_marginal_rewards_per_token,
-            0,
-            BENCH_TOKEN_SUPPLY, // 100% of the supply.
-        )
-        .unwrap();
-    }
-
-    #[test]
-    fn wrapping_eligible_rewards() {
-        // Set up current to be less than rate, simulating a scenario where the
-        // current reward has wrapped around `u128::MAX`.
-        let current_accumulated_rewards_per_token = 0;
-        let last_accumulated_rewards_per_token = u128::MAX - 1_000_000_000_000_000_000;
-        let result = calculate_eligible_rewards(
-            current_accumulated_rewards_per_token,
-            last_accumulated_rewards_per_token,
-            BENCH_TOKEN_SUPPLY,
-        )
-        .unwrap();
-        assert_eq!(result, 1_000_000_000_000_000_001);
-
-        // Try it again at the very edge. Result should be one.
-        let current_accumulated_rewards_per_token = 0;
-        let last_accumulated_rewards_per_token = u128::MAX;
-        let result = calculate_eligible_rewards(
-            current_accumulated_rewards_per_token,
-            last_accumulated_rewards_per_token,
-            BENCH_TOKEN_SUPPLY,
-        )
-        .unwrap();
-        assert_eq!(result, 1);
-    }
-
-    proptest! {
-        #[test]
-        fn test_calculate_rewards_per_token(
-            rewards in 0u64..,
-            token_supply in 0u64..,
-        ) {
-            // Calculate.
-            //
-            // For all possible values of rewards and token_supply, the
-            // calculation should never return an error, hence the
-            // `unwrap` here.
-            //
-            // The scaling to `u128` prevents multiplication from breaking
-            // the `u64::MAX` ceiling, and the `token_supply == 0` check
-            // prevents `checked_div` returning `None` from a zero
-            // denominator.
-            let result = calculate_rewards_per_token(rewards, token_supply).unwrap();
-            // Evaluate.
-            if token_supply == 0 {
-                prop_assert_eq!(result, 0);
-            } else {
-                let expected = (rewards as u128)
-                    .checked_mul(REWARDS_PER_TOKEN_SCALING_FACTOR)
-                    .and_then(|product| product.checked_div(token_supply as u128))
-                    .unwrap();
-                prop_assert_eq!(result, expected);
-            }
-        }
-    }
-
-    // The marginal reward per token (current - last) within the
-    // `calculate_eligible_rewards` function (tested below) is expressed in
-    // terms of rewards _per token_, which is stored as a `u128` and calculated
-    // by the `calculate_rewards_per_token` function (tested above).
-    //
-    // The return type of `calculate_eligible_rewards` is limited to
-    // `u64::MAX`, but in order to determine the function's upper bounds for
-    // each input parameter, we must consider the maximum marginal reward per token
-    // (`current_accumulated_rewards_per_token`
-    //             - `last_accumulated_rewards_per_token`)
-    // and token account balance that this function can support.
-    //
-    // Since the marginal reward per token is at its maximum anytime a holder
-    // has a "last seen rate" (`last_accumulated_rewards_per_token`) of zero,
-    // we can evaluate in terms of `current_accumulated_rewards_per_token`,
-    // assuming the "last seen rate" to be zero. We will stick to this
-    // assumption in all references to `marginal_rewards_per_token` below.
-    //
-    // On its face, the maximum marginal reward per token is bound by
-    // `u128::MAX` - since both `current_accumulated_rewards_per_token` and
-    // `last_accumulated_rewards_per_token` are represented as `u128` integers.
-    // However, since the return value is capped at `u64::MAX`, we can perform
-    // the following arithmetic.
-    //
-    // Consider the original function:
-    //
-    //     eligible_rewards = (marginal_rewards_per_token * balance) / 1e18
-    //
-    // We can plug in `u64::MAX` for both `eligible_rewards` and `balance`
-    // to calculate the input `marginal_rewards_per_token` upper bound.
-    //
-    //     u64::MAX = (marginal_rewards_per_token * u64::MAX) / 1e18
-    //
-    // And evaluate to:
-    //
-    //     marginal_rewards_per_token = 1e18
-    //
-    // This means `calculate_eligible_rewards` can handle a maximum marginal
-    // reward per token of 1e18, or 1 lamport per token.
-    //
-    // But what does this mean as a constraint on the system as a whole? In
-    // other words, if a holder had 100% of the token supply and their last
-    // seen rate was zero, what's the maximum number of rewards the entire
-    // system can accumulate (in lamports) before this function would break?
-    //
-    // We can compute this value from the formula for
-    // `calculate_rewards_per_token`, which is represented below.
-    //
-    //     rewards_per_token = (reward * 1e18) / mint_supply
-    //
-    // Plugging in the values for maximum marginal reward per token and
-    // `u64::MAX` for token supply...
-    //
-    //     1e18 = (reward * 1e18) / u64::MAX
-    //
-    // ... we get:
-    //
-    //     reward = u64::MAX
-    //
-    // This means that the maximum lamports that can be paid into the system
-    // when a holder has 100% of the token supply and has never claimed is
-    // u64::MAX.
-    //
-    // This also means that the two functions - `calculate_rewards_per_token`
-    // and `calculate_eligible_rewards` share the same upper bound, since
-    // `calculate_rewards_per_token` expects a `u64` for rewards.
-    //
-    // However, since rewards can be paid into the system incrementally, and
-    // are stored as _rewards per token_ in a `u128`, it's mathematically
-    // possible for the system to receive more than `u64::MAX` over time.
-    //
-    // It's worth noting that `u64::MAX` exceeds the current circulating supply
-    // of SOL (`4.66e15`) by 386_266 %.
-    //
-    // That being said, we can pipe `u64::MAX` into `calculate_rewards_per_token`
-    // as the function's upper bound for proptesting. This will also max-out at
-    // 1e18.
-    prop_compose! {
-        fn current_last_and_balance(max_accumulated_rewards: u64)
-        (mint_supply in 0..u64::MAX)
-        (
-            current in 0..=calculate_rewards_per_token(
-                max_accumulated_rewards,
-                mint_supply,
-            ).unwrap(),
-            balance in 0..=mint_supply,
-        ) -> (u128, u128, u64) {
-            (
-                current, // Current accumulated rewards per token.
-                0,       // Last accumulated rewards per token (always 0 here for maximum margin).
-                balance, // Token account balance (up to 100% of mint supply).
-            )
-        }
-    }
-    proptest! {
-        #[test]
-        fn test_calculate_eligible_rewards(
-            (
-                current_accumulated_rewards_per_token,
-                last_accumulated_rewards_per_token,
-                token_account_balance,
-            ) in current_last_and_balance(u64::MAX),
-        ) {
-            // Calculate.
-            let result = calculate_eligible_rewards(
-                current_accumulated_rewards_per_token,
-                last_accumulated_rewards_per_token,
-                token_account_balance,
-            )
-            .unwrap();
-            // Evaluate.
-            //
-            // Since we've configured the inputs so that last never exceeds
-            // current, this subtraction never overflows, so it's safe to
-            // unwrap here.
-            let marginal_rate = current_accumulated_rewards_per_token
-                .checked_sub(last_accumulated_rewards_per_token)
-                .unwrap();
-            if marginal_rate == 0 {
-                // If the marginal rate resolves to zero, the
-                // calculation should short-circuit and return zero.
-                prop_assert_eq!(result, 0);
-            } else {
-                // The rest of the calculation consists of three steps,
-                // so evaluate each step one at a time.
-                //
-                // 1. marginal rate x token account balance
-                // 2. product / REWARDS_PER_TOKEN_SCALING_FACTOR
-                // 3. product.try_into (u64)
-
-                // Step 1.
-                //
-                // Since we've restricted the inputs within the bounds
-                // of the system, the multiplication should never exceed
-                // `u128::MAX`.
-                let marginal_rewards = marginal_rate
-                    .checked_mul(token_account_balance as u128)
-                    .unwrap();
-
-                // Step 2.
-                //
-                // Since we're always dividing by a non-zero constant,
-                // the division should never return `None`, so we can
-                // unwrap here.
-                let descaled_marginal_rewards = marginal_rewards
-                    .checked_div(REWARDS_PER_TOKEN_SCALING_FACTOR)
-                    .unwrap();
-
-                // Step 3.
-                //
-                // Since we've restricted the inputs within the bounds
-                // of the system, the conversion to `u64` should always
-                // succeed.
-                let expected_result: u64 = descaled_marginal_rewards
-                    .try_into()
-                    .unwrap();
-
-                // The calculation should return the expected value.
-                prop_assert_eq!(result, expected_result);
+    fn test_expected_accounts_match_idl() {
+        let idl: serde_json::Value = serde_json::from_str(include_str!("../idl.json")).unwrap();
+
+        for idl_instruction in idl["instructions"].as_array().unwrap() {
+            let name = idl_instruction["name"].as_str().unwrap();
+            let discriminant = idl_instruction["discriminant"]["value"].as_u64().unwrap() as u8;
+            let instruction = unpack_zeroed(discriminant)
+                .unwrap_or_else(|| panic!("{name} could not be unpacked"));
+
+            // Optional accounts follow the required ones, and are not
+            // validated up front.
+            let idl_accounts = idl_instruction["accounts"].as_array().unwrap();
+            let required = idl_accounts
+                .iter()
+                .take_while(|account| !account["isOptional"].as_bool().unwrap_or(false))
+                .collect::<Vec<_>>();
+            assert!(
+                idl_accounts[required.len()..]
+                    .iter()
+                    .all(|account| account["isOptional"].as_bool().unwrap_or(false)),
+                "{name} has a required account after an optional one"
+            );
+
+            let expected_accounts = expected_accounts(&instruction);
+            assert_eq!(expected_accounts.len(), required.len(), "{name}");
+            for (expected, account) in expected_accounts.iter().zip(required) {
+                let account_name = account["name"].as_str().unwrap();
+                assert_eq!(
+                    expected.is_writable(),
+                    account["isMut"].as_bool().unwrap(),
+                    "{name}: {account_name} writability"
+                );
+                assert_eq!(
+                    expected.is_signer(),
+                    account["isSigner"].as_bool().unwrap(),
+                    "{name}: {account_name} signer"
+                );
             }
         }
     }
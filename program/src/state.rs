@@ -144,8 +144,8 @@
 
 use {
     bytemuck::{Pod, Zeroable},
-    shank::ShankAccount,
-    solana_program::pubkey::{pubkey, Pubkey},
+    shank::{ShankAccount, ShankType},
+    solana_program::pubkey::{pubkey, Pubkey, PubkeyError},
 };
 
 /// The seed prefix (`"sweep"`) in bytes used to derive the address of the
@@ -160,6 +160,38 @@ pub const SEED_PREFIX_HOLDER_REWARDS: &[u8] = b"holder";
 /// the mint's holder rewards pool account.
 /// Seeds: `"holder_pool" + mint_address`.
 pub const SEED_PREFIX_HOLDER_REWARDS_POOL: &[u8] = b"holder_pool";
+/// The seed prefix (`"rate_history"`) in bytes used to derive the address of
+/// a holder rewards pool's rate history account.
+/// Seeds: `"rate_history" + holder_rewards_pool_address`.
+pub const SEED_PREFIX_RATE_HISTORY: &[u8] = b"rate_history";
+/// The seed prefix (`"lockup_tiers"`) in bytes used to derive the address of
+/// a holder rewards pool's lockup tiers account.
+/// Seeds: `"lockup_tiers" + holder_rewards_pool_address`.
+pub const SEED_PREFIX_LOCKUP_TIERS: &[u8] = b"lockup_tiers";
+/// The seed prefix (`"vote_escrow"`) in bytes used to derive the address of
+/// a holder rewards pool's vote escrow account.
+/// Seeds: `"vote_escrow" + holder_rewards_pool_address`.
+pub const SEED_PREFIX_VOTE_ESCROW: &[u8] = b"vote_escrow";
+/// The seed prefix (`"stake_checkpoints"`) in bytes used to derive the
+/// address of a holder's stake checkpoints account.
+/// Seeds: `"stake_checkpoints" + owner_address`.
+pub const SEED_PREFIX_STAKE_CHECKPOINTS: &[u8] = b"stake_checkpoints";
+/// The seed prefix (`"access_entry"`) in bytes used to derive the address of
+/// a wallet's entry in a holder rewards pool's access list.
+/// Seeds: `"access_entry" + holder_rewards_pool_address + wallet_address`.
+pub const SEED_PREFIX_ACCESS_ENTRY: &[u8] = b"access_entry";
+/// The seed prefix (`"receipt_mint"`) in bytes used to derive the address of
+/// a holder rewards pool's receipt token mint.
+/// Seeds: `"receipt_mint" + holder_rewards_pool_address`.
+pub const SEED_PREFIX_RECEIPT_MINT: &[u8] = b"receipt_mint";
+/// The seed prefix (`"campaign"`) in bytes used to derive the address of a
+/// funder's reward campaign for a holder rewards pool.
+/// Seeds: `"campaign" + holder_rewards_pool_address + funder_address`.
+pub const SEED_PREFIX_CAMPAIGN: &[u8] = b"campaign";
+/// The seed prefix (`"epoch_ledger"`) in bytes used to derive the address of
+/// a holder rewards pool's epoch ledger account.
+/// Seeds: `"epoch_ledger" + holder_rewards_pool_address`.
+pub const SEED_PREFIX_EPOCH_LEDGER: &[u8] = b"epoch_ledger";
 /// Duna program pubkey
 pub const DUNA_PROGRAM_ID: Pubkey = pubkey!("8TwDM3rkxQuFCiS2iPB1HB3Q3qnN7b6J4SCTDCpw9SS1");
 
@@ -176,6 +208,18 @@ pub fn get_holder_rewards_address_and_bump_seed(
     Pubkey::find_program_address(&collect_holder_rewards_seeds(owner_address), program_id)
 }
 
+/// Create the address of a holder rewards account from its stored bump seed.
+pub fn create_holder_rewards_address(
+    owner_address: &Pubkey,
+    bump_seed: u8,
+    program_id: &Pubkey,
+) -> Result<Pubkey, PubkeyError> {
+    Pubkey::create_program_address(
+        &collect_holder_rewards_signer_seeds(owner_address, &[bump_seed]),
+        program_id,
+    )
+}
+
 pub(crate) fn collect_holder_rewards_seeds(token_account_address: &Pubkey) -> [&[u8]; 2] {
     [SEED_PREFIX_HOLDER_REWARDS, token_account_address.as_ref()]
 }
@@ -204,6 +248,19 @@ pub fn get_holder_rewards_pool_address_and_bump_seed(
     Pubkey::find_program_address(&collect_holder_rewards_pool_seeds(mint_address), program_id)
 }
 
+/// Create the address of a holder rewards pool account from its stored bump
+/// seed.
+pub fn create_holder_rewards_pool_address(
+    mint_address: &Pubkey,
+    bump_seed: u8,
+    program_id: &Pubkey,
+) -> Result<Pubkey, PubkeyError> {
+    Pubkey::create_program_address(
+        &collect_holder_rewards_pool_signer_seeds(mint_address, &[bump_seed]),
+        program_id,
+    )
+}
+
 pub(crate) fn collect_holder_rewards_pool_seeds(mint_address: &Pubkey) -> [&[u8]; 2] {
     [SEED_PREFIX_HOLDER_REWARDS_POOL, mint_address.as_ref()]
 }
@@ -219,6 +276,423 @@ pub(crate) fn collect_holder_rewards_pool_signer_seeds<'a>(
     ]
 }
 
+/// Derive the address of a holder rewards pool's rate history account.
+pub fn get_rate_history_address(
+    holder_rewards_pool_address: &Pubkey,
+    program_id: &Pubkey,
+) -> Pubkey {
+    get_rate_history_address_and_bump_seed(holder_rewards_pool_address, program_id).0
+}
+
+/// Derive the address of a holder rewards pool's rate history account, with
+/// bump seed.
+pub fn get_rate_history_address_and_bump_seed(
+    holder_rewards_pool_address: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &collect_rate_history_seeds(holder_rewards_pool_address),
+        program_id,
+    )
+}
+
+/// Create the address of a rate history account from its stored bump seed.
+pub fn create_rate_history_address(
+    holder_rewards_pool_address: &Pubkey,
+    bump_seed: u8,
+    program_id: &Pubkey,
+) -> Result<Pubkey, PubkeyError> {
+    Pubkey::create_program_address(
+        &collect_rate_history_signer_seeds(holder_rewards_pool_address, &[bump_seed]),
+        program_id,
+    )
+}
+
+pub(crate) fn collect_rate_history_seeds(holder_rewards_pool_address: &Pubkey) -> [&[u8]; 2] {
+    [
+        SEED_PREFIX_RATE_HISTORY,
+        holder_rewards_pool_address.as_ref(),
+    ]
+}
+
+pub(crate) fn collect_rate_history_signer_seeds<'a>(
+    holder_rewards_pool_address: &'a Pubkey,
+    bump_seed: &'a [u8],
+) -> [&'a [u8]; 3] {
+    [
+        SEED_PREFIX_RATE_HISTORY,
+        holder_rewards_pool_address.as_ref(),
+        bump_seed,
+    ]
+}
+
+/// Derive the address of a holder rewards pool's lockup tiers account.
+pub fn get_lockup_tiers_address(
+    holder_rewards_pool_address: &Pubkey,
+    program_id: &Pubkey,
+) -> Pubkey {
+    get_lockup_tiers_address_and_bump_seed(holder_rewards_pool_address, program_id).0
+}
+
+/// Derive the address of a holder rewards pool's lockup tiers account, with
+/// bump seed.
+pub fn get_lockup_tiers_address_and_bump_seed(
+    holder_rewards_pool_address: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &collect_lockup_tiers_seeds(holder_rewards_pool_address),
+        program_id,
+    )
+}
+
+/// Create the address of a lockup tiers account from its stored bump seed.
+pub fn create_lockup_tiers_address(
+    holder_rewards_pool_address: &Pubkey,
+    bump_seed: u8,
+    program_id: &Pubkey,
+) -> Result<Pubkey, PubkeyError> {
+    Pubkey::create_program_address(
+        &collect_lockup_tiers_signer_seeds(holder_rewards_pool_address, &[bump_seed]),
+        program_id,
+    )
+}
+
+pub(crate) fn collect_lockup_tiers_seeds(holder_rewards_pool_address: &Pubkey) -> [&[u8]; 2] {
+    [
+        SEED_PREFIX_LOCKUP_TIERS,
+        holder_rewards_pool_address.as_ref(),
+    ]
+}
+
+pub(crate) fn collect_lockup_tiers_signer_seeds<'a>(
+    holder_rewards_pool_address: &'a Pubkey,
+    bump_seed: &'a [u8],
+) -> [&'a [u8]; 3] {
+    [
+        SEED_PREFIX_LOCKUP_TIERS,
+        holder_rewards_pool_address.as_ref(),
+        bump_seed,
+    ]
+}
+
+/// Derive the address of a holder rewards pool's vote escrow account.
+pub fn get_vote_escrow_address(
+    holder_rewards_pool_address: &Pubkey,
+    program_id: &Pubkey,
+) -> Pubkey {
+    get_vote_escrow_address_and_bump_seed(holder_rewards_pool_address, program_id).0
+}
+
+/// Derive the address of a holder rewards pool's vote escrow account, with
+/// bump seed.
+pub fn get_vote_escrow_address_and_bump_seed(
+    holder_rewards_pool_address: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &collect_vote_escrow_seeds(holder_rewards_pool_address),
+        program_id,
+    )
+}
+
+/// Create the address of a vote escrow account from its stored bump seed.
+pub fn create_vote_escrow_address(
+    holder_rewards_pool_address: &Pubkey,
+    bump_seed: u8,
+    program_id: &Pubkey,
+) -> Result<Pubkey, PubkeyError> {
+    Pubkey::create_program_address(
+        &collect_vote_escrow_signer_seeds(holder_rewards_pool_address, &[bump_seed]),
+        program_id,
+    )
+}
+
+pub(crate) fn collect_vote_escrow_seeds(holder_rewards_pool_address: &Pubkey) -> [&[u8]; 2] {
+    [
+        SEED_PREFIX_VOTE_ESCROW,
+        holder_rewards_pool_address.as_ref(),
+    ]
+}
+
+pub(crate) fn collect_vote_escrow_signer_seeds<'a>(
+    holder_rewards_pool_address: &'a Pubkey,
+    bump_seed: &'a [u8],
+) -> [&'a [u8]; 3] {
+    [
+        SEED_PREFIX_VOTE_ESCROW,
+        holder_rewards_pool_address.as_ref(),
+        bump_seed,
+    ]
+}
+
+/// Derive the address of a holder's stake checkpoints account.
+pub fn get_stake_checkpoints_address(owner_address: &Pubkey, program_id: &Pubkey) -> Pubkey {
+    get_stake_checkpoints_address_and_bump_seed(owner_address, program_id).0
+}
+
+/// Derive the address of a holder's stake checkpoints account, with bump
+/// seed.
+pub fn get_stake_checkpoints_address_and_bump_seed(
+    owner_address: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&collect_stake_checkpoints_seeds(owner_address), program_id)
+}
+
+/// Create the address of a stake checkpoints account from its stored bump
+/// seed.
+pub fn create_stake_checkpoints_address(
+    owner_address: &Pubkey,
+    bump_seed: u8,
+    program_id: &Pubkey,
+) -> Result<Pubkey, PubkeyError> {
+    Pubkey::create_program_address(
+        &collect_stake_checkpoints_signer_seeds(owner_address, &[bump_seed]),
+        program_id,
+    )
+}
+
+pub(crate) fn collect_stake_checkpoints_seeds(owner_address: &Pubkey) -> [&[u8]; 2] {
+    [SEED_PREFIX_STAKE_CHECKPOINTS, owner_address.as_ref()]
+}
+
+pub(crate) fn collect_stake_checkpoints_signer_seeds<'a>(
+    owner_address: &'a Pubkey,
+    bump_seed: &'a [u8],
+) -> [&'a [u8]; 3] {
+    [
+        SEED_PREFIX_STAKE_CHECKPOINTS,
+        owner_address.as_ref(),
+        bump_seed,
+    ]
+}
+
+/// Derive the address of a wallet's entry in a holder rewards pool's access
+/// list.
+pub fn get_access_entry_address(
+    holder_rewards_pool_address: &Pubkey,
+    wallet_address: &Pubkey,
+    program_id: &Pubkey,
+) -> Pubkey {
+    get_access_entry_address_and_bump_seed(holder_rewards_pool_address, wallet_address, program_id)
+        .0
+}
+
+/// Derive the address of a wallet's entry in a holder rewards pool's access
+/// list, with bump seed.
+pub fn get_access_entry_address_and_bump_seed(
+    holder_rewards_pool_address: &Pubkey,
+    wallet_address: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &collect_access_entry_seeds(holder_rewards_pool_address, wallet_address),
+        program_id,
+    )
+}
+
+/// Create the address of an access entry account from its stored bump seed.
+pub fn create_access_entry_address(
+    holder_rewards_pool_address: &Pubkey,
+    wallet_address: &Pubkey,
+    bump_seed: u8,
+    program_id: &Pubkey,
+) -> Result<Pubkey, PubkeyError> {
+    Pubkey::create_program_address(
+        &collect_access_entry_signer_seeds(
+            holder_rewards_pool_address,
+            wallet_address,
+            &[bump_seed],
+        ),
+        program_id,
+    )
+}
+
+pub(crate) fn collect_access_entry_seeds<'a>(
+    holder_rewards_pool_address: &'a Pubkey,
+    wallet_address: &'a Pubkey,
+) -> [&'a [u8]; 3] {
+    [
+        SEED_PREFIX_ACCESS_ENTRY,
+        holder_rewards_pool_address.as_ref(),
+        wallet_address.as_ref(),
+    ]
+}
+
+pub(crate) fn collect_access_entry_signer_seeds<'a>(
+    holder_rewards_pool_address: &'a Pubkey,
+    wallet_address: &'a Pubkey,
+    bump_seed: &'a [u8],
+) -> [&'a [u8]; 4] {
+    [
+        SEED_PREFIX_ACCESS_ENTRY,
+        holder_rewards_pool_address.as_ref(),
+        wallet_address.as_ref(),
+        bump_seed,
+    ]
+}
+
+/// Derive the address of a holder rewards pool's receipt token mint.
+pub fn get_receipt_mint_address(
+    holder_rewards_pool_address: &Pubkey,
+    program_id: &Pubkey,
+) -> Pubkey {
+    get_receipt_mint_address_and_bump_seed(holder_rewards_pool_address, program_id).0
+}
+
+/// Derive the address of a holder rewards pool's receipt token mint, with
+/// bump seed.
+pub fn get_receipt_mint_address_and_bump_seed(
+    holder_rewards_pool_address: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &collect_receipt_mint_seeds(holder_rewards_pool_address),
+        program_id,
+    )
+}
+
+/// Create the address of a receipt token mint from its stored bump seed.
+pub fn create_receipt_mint_address(
+    holder_rewards_pool_address: &Pubkey,
+    bump_seed: u8,
+    program_id: &Pubkey,
+) -> Result<Pubkey, PubkeyError> {
+    Pubkey::create_program_address(
+        &collect_receipt_mint_signer_seeds(holder_rewards_pool_address, &[bump_seed]),
+        program_id,
+    )
+}
+
+pub(crate) fn collect_receipt_mint_seeds(holder_rewards_pool_address: &Pubkey) -> [&[u8]; 2] {
+    [
+        SEED_PREFIX_RECEIPT_MINT,
+        holder_rewards_pool_address.as_ref(),
+    ]
+}
+
+pub(crate) fn collect_receipt_mint_signer_seeds<'a>(
+    holder_rewards_pool_address: &'a Pubkey,
+    bump_seed: &'a [u8],
+) -> [&'a [u8]; 3] {
+    [
+        SEED_PREFIX_RECEIPT_MINT,
+        holder_rewards_pool_address.as_ref(),
+        bump_seed,
+    ]
+}
+
+/// Derive the address of a funder's reward campaign for a holder rewards
+/// pool.
+pub fn get_campaign_address(
+    holder_rewards_pool_address: &Pubkey,
+    funder_address: &Pubkey,
+    program_id: &Pubkey,
+) -> Pubkey {
+    get_campaign_address_and_bump_seed(holder_rewards_pool_address, funder_address, program_id).0
+}
+
+/// Derive the address of a funder's reward campaign for a holder rewards
+/// pool, with bump seed.
+pub fn get_campaign_address_and_bump_seed(
+    holder_rewards_pool_address: &Pubkey,
+    funder_address: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &collect_campaign_seeds(holder_rewards_pool_address, funder_address),
+        program_id,
+    )
+}
+
+/// Create the address of a campaign account from its stored bump seed.
+pub fn create_campaign_address(
+    holder_rewards_pool_address: &Pubkey,
+    funder_address: &Pubkey,
+    bump_seed: u8,
+    program_id: &Pubkey,
+) -> Result<Pubkey, PubkeyError> {
+    Pubkey::create_program_address(
+        &collect_campaign_signer_seeds(holder_rewards_pool_address, funder_address, &[bump_seed]),
+        program_id,
+    )
+}
+
+pub(crate) fn collect_campaign_seeds<'a>(
+    holder_rewards_pool_address: &'a Pubkey,
+    funder_address: &'a Pubkey,
+) -> [&'a [u8]; 3] {
+    [
+        SEED_PREFIX_CAMPAIGN,
+        holder_rewards_pool_address.as_ref(),
+        funder_address.as_ref(),
+    ]
+}
+
+pub(crate) fn collect_campaign_signer_seeds<'a>(
+    holder_rewards_pool_address: &'a Pubkey,
+    funder_address: &'a Pubkey,
+    bump_seed: &'a [u8],
+) -> [&'a [u8]; 4] {
+    [
+        SEED_PREFIX_CAMPAIGN,
+        holder_rewards_pool_address.as_ref(),
+        funder_address.as_ref(),
+        bump_seed,
+    ]
+}
+
+/// Derive the address of a holder rewards pool's epoch ledger account.
+pub fn get_epoch_ledger_address(
+    holder_rewards_pool_address: &Pubkey,
+    program_id: &Pubkey,
+) -> Pubkey {
+    get_epoch_ledger_address_and_bump_seed(holder_rewards_pool_address, program_id).0
+}
+
+/// Derive the address of a holder rewards pool's epoch ledger account, with
+/// bump seed.
+pub fn get_epoch_ledger_address_and_bump_seed(
+    holder_rewards_pool_address: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &collect_epoch_ledger_seeds(holder_rewards_pool_address),
+        program_id,
+    )
+}
+
+/// Create the address of an epoch ledger account from its stored bump seed.
+pub fn create_epoch_ledger_address(
+    holder_rewards_pool_address: &Pubkey,
+    bump_seed: u8,
+    program_id: &Pubkey,
+) -> Result<Pubkey, PubkeyError> {
+    Pubkey::create_program_address(
+        &collect_epoch_ledger_signer_seeds(holder_rewards_pool_address, &[bump_seed]),
+        program_id,
+    )
+}
+
+pub(crate) fn collect_epoch_ledger_seeds(holder_rewards_pool_address: &Pubkey) -> [&[u8]; 2] {
+    [
+        SEED_PREFIX_EPOCH_LEDGER,
+        holder_rewards_pool_address.as_ref(),
+    ]
+}
+
+pub(crate) fn collect_epoch_ledger_signer_seeds<'a>(
+    holder_rewards_pool_address: &'a Pubkey,
+    bump_seed: &'a [u8],
+) -> [&'a [u8]; 3] {
+    [
+        SEED_PREFIX_EPOCH_LEDGER,
+        holder_rewards_pool_address.as_ref(),
+        bump_seed,
+    ]
+}
+
 /// A holder rewards account which tracks the rewards accumulated by a holder
 /// of tokens.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Pod, ShankAccount, Zeroable)]
@@ -231,11 +705,85 @@ pub struct HolderRewards {
     pub last_accumulated_rewards_per_token: u128,
     // Total amount of deposited tokens
     pub deposited: u64,
-    pub _padding: u64,
+    /// The account that paid the rent for this account, refunded on close.
+    pub rent_payer: Pubkey,
+    /// The bump seed of this account's address, stored to avoid re-deriving
+    /// it with `find_program_address`.
+    pub bump_seed: u8,
+    /// Whether the holder records stake checkpoints, which deposits and
+    /// withdrawals must then update.
+    pub stake_checkpoints: u8,
+    pub _padding: [u8; 6],
+    /// The total lamports harvested by this holder.
+    pub total_rewards_harvested: u64,
+    /// The slot of this holder's first deposit, or `0` if the holder has
+    /// never deposited.
+    pub first_deposit_slot: u64,
+    /// The slot of this holder's last deposit, withdrawal or harvest.
+    pub last_activity_slot: u64,
+    /// The total amount of tokens ever deposited by this holder.
+    pub cumulative_deposited: u64,
+    /// The total amount of tokens ever withdrawn by this holder.
+    pub cumulative_withdrawn: u64,
+    /// The amount of deposited tokens locked until `unlock_timestamp`.
+    pub locked_amount: u64,
+    /// The Unix timestamp at which locked tokens can be withdrawn.
+    pub unlock_timestamp: i64,
+    /// The reward multiplier applied to locked tokens, in basis points, or
+    /// `0` if nothing is locked.
+    pub lockup_multiplier_bps: u64,
+    /// The locked amount counted in the pool's vote escrow.
+    pub voting_power_locked: u64,
+    /// The time at which the voting power counted in the pool's vote escrow
+    /// reaches zero: `unlock_timestamp`, rounded down to a vote escrow
+    /// period.
+    pub voting_power_end: i64,
+    /// The slot of this holder's last deposit, or `0` if the holder has
+    /// never deposited.
+    pub last_deposit_slot: u64,
+    /// The total lamports of pending rewards this holder forfeited by
+    /// withdrawing early.
+    pub early_withdrawal_penalties: u64,
+    /// Rewards owed to this holder but not yet paid out, settled when their
+    /// receipt tokens were transferred or left unpaid by an emergency
+    /// withdrawal. Paid out with their next harvest.
+    pub unharvested_rewards: u64,
+    /// The total tokens slashed from this holder's deposit.
+    pub slashed: u64,
 }
 
 impl HolderRewards {
     pub const LEN: usize = std::mem::size_of::<HolderRewards>();
+    /// The length of a holder rewards account created by the first release
+    /// of the program, and not yet migrated.
+    ///
+    /// Also a prefix of the current layout, with the rent payer partly
+    /// occupying what was previously padding. The bump seed is not stored.
+    pub const BASELINE_LEN: usize = 32;
+
+    /// The weight added to the holder's deposit by their lockup.
+    pub fn lockup_bonus_weight(&self) -> Option<u64> {
+        let bonus_bps = self.lockup_multiplier_bps.saturating_sub(BASIS_POINTS);
+        (self.locked_amount as u128)
+            .checked_mul(bonus_bps as u128)
+            .map(|product| product / BASIS_POINTS as u128)
+            .and_then(|bonus| bonus.try_into().ok())
+    }
+
+    /// The weight rewards are distributed to the holder by: their deposit,
+    /// plus the bonus earned by any locked tokens.
+    pub fn weight(&self) -> Option<u64> {
+        self.deposited.checked_add(self.lockup_bonus_weight()?)
+    }
+
+    /// The holder's voting power at `unix_timestamp`.
+    pub fn voting_power(&self, unix_timestamp: i64) -> u64 {
+        voting_power(
+            self.voting_power_locked,
+            self.voting_power_end,
+            unix_timestamp,
+        )
+    }
 }
 
 /// Tracks the rewards accumulated by the system and manages the distribution
@@ -254,12 +802,640 @@ pub struct HolderRewardsPool {
     pub lamports_last: u64,
     /// Duna document hash
     pub duna_document_hash: [u8; 32],
-
-    pub _padding: u64,
+    /// The total amount of tokens deposited into the pool by holders.
+    ///
+    /// Tracked separately from the pool token account's balance, so tokens
+    /// transferred directly into the pool token account do not dilute the
+    /// rewards of depositors.
+    pub total_deposited: u64,
+    /// The authority permitted to recover tokens sent directly to the pool
+    /// token account.
+    pub authority: Pubkey,
+    /// The bump seed of this account's address, stored to avoid re-deriving
+    /// it with `find_program_address`.
+    pub bump_seed: u8,
+    /// Which wallets may open holder rewards accounts and deposit: one of
+    /// the `ACCESS_LIST_*` modes.
+    pub access_list: u8,
+    /// The share of pending rewards forfeited by a holder withdrawing within
+    /// `early_withdrawal_period_slots` of their last deposit, in basis
+    /// points, or `0` if early withdrawals are not penalized.
+    pub early_withdrawal_penalty_bps: u16,
+    /// The number of slots after a deposit during which withdrawals are
+    /// penalized.
+    pub early_withdrawal_period_slots: u32,
+    /// The slot in which the pool was last updated.
+    pub last_update_slot: u64,
+    /// The total lamports received by the pool as rewards.
+    pub total_rewards_received: u64,
+    /// The total lamports harvested from the pool by holders.
+    pub total_rewards_harvested: u64,
+    /// The number of open holder rewards accounts for the pool.
+    pub active_holder_count: u64,
+    /// The total weight added to holders' deposits by their lockups.
+    pub total_lockup_bonus_weight: u64,
+    /// The most tokens that can be deposited into the pool by all holders,
+    /// or `0` if unlimited.
+    pub max_total_deposit: u64,
+    /// The most tokens a single holder can have deposited, or `0` if
+    /// unlimited.
+    pub max_holder_deposit: u64,
+    /// The fewest tokens a single deposit can add.
+    pub min_deposit: u64,
+    /// Whether deposits mint receipt tokens, which withdrawals must burn.
+    pub receipts: u8,
+    /// The bump seed of the receipt token mint's address, stored to avoid
+    /// re-deriving it with `find_program_address`.
+    pub receipt_mint_bump_seed: u8,
+    /// Whether the pool records its rate history, which every instruction
+    /// updating the pool must then update.
+    pub rate_history: u8,
+    /// Whether the pool records its epoch ledger, which every instruction
+    /// updating the pool must then update.
+    pub epoch_ledger: u8,
+    pub _padding: [u8; 4],
+    /// The authority permitted to slash holders' deposits, or the default
+    /// pubkey if slashing is disabled.
+    pub slashing_authority: Pubkey,
+    /// The token account receiving slashed tokens.
+    pub slash_destination: Pubkey,
+    /// The total tokens slashed from holders' deposits.
+    pub total_slashed: u64,
+    /// The total slots the pool has spent without weight, counted each time
+    /// the pool is updated.
+    pub zero_weight_slots: u64,
 }
 
 impl HolderRewardsPool {
     pub const LEN: usize = std::mem::size_of::<HolderRewardsPool>();
+    /// The length of a holder rewards pool account created by the first
+    /// release of the program, and not yet migrated.
+    ///
+    /// Also a prefix of the current layout, with the total deposited
+    /// occupying what was previously padding. The bump seed is not stored.
+    pub const BASELINE_LEN: usize = 64;
+
+    /// The total weight rewards are distributed by: the total deposited,
+    /// plus the bonus weight of all lockups.
+    pub fn total_weight(&self) -> Option<u64> {
+        self.total_deposited
+            .checked_add(self.total_lockup_bonus_weight)
+    }
+
+    /// The pool's `zero_weight_slots` as of `slot`, counting the slots since
+    /// it was last updated if it has no weight.
+    pub fn zero_weight_slots_at(&self, slot: u64) -> Option<u64> {
+        if self.total_weight()? != 0 {
+            return Some(self.zero_weight_slots);
+        }
+        self.zero_weight_slots
+            .checked_add(slot.saturating_sub(self.last_update_slot))
+    }
+
+    /// The share of `rewards` forfeited by a holder withdrawing at `slot`, given
+    /// the slot of their last deposit.
+    ///
+    /// Rounds up, so rewards too small to bear a whole lamport of penalty are
+    /// still penalized, and withheld, within the period.
+    pub fn early_withdrawal_penalty(&self, rewards: u64, last_deposit_slot: u64, slot: u64) -> u64 {
+        if slot.saturating_sub(last_deposit_slot) >= self.early_withdrawal_period_slots as u64 {
+            return 0;
+        }
+        // The penalty never exceeds `BASIS_POINTS`, so the result fits.
+        ((rewards as u128) * (self.early_withdrawal_penalty_bps as u128))
+            .div_ceil(BASIS_POINTS as u128) as u64
+    }
+}
+
+/// One basis point is 1/10,000th of a unit, so a multiplier of `10_000`
+/// basis points is `1x`.
+pub const BASIS_POINTS: u64 = 10_000;
+/// The highest reward multiplier a lockup tier can apply, in basis points.
+pub const MAX_LOCKUP_MULTIPLIER_BPS: u64 = 10 * BASIS_POINTS;
+/// The longest lockup permitted, in seconds: four years.
+pub const MAX_LOCKUP_DURATION: u64 = 4 * 365 * 24 * 60 * 60;
+/// The number of tiers held by a lockup tiers account.
+pub const LOCKUP_TIERS_CAPACITY: usize = 4;
+
+/// A lockup tier, applying a reward multiplier to tokens locked for at least
+/// its duration.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, ShankType, Zeroable)]
+#[repr(C)]
+pub struct LockupTier {
+    /// The minimum lockup duration, in seconds.
+    pub duration: u64,
+    /// The reward multiplier applied to locked tokens, in basis points, or
+    /// `0` if the tier is unused.
+    pub multiplier_bps: u64,
+}
+
+/// The lockup tiers configured by a holder rewards pool's authority.
+///
+/// Changing the tiers does not affect existing lockups, which keep the
+/// multiplier they were locked with.
+#[derive(Clone, Copy, Debug, PartialEq, Pod, ShankAccount, Zeroable)]
+#[repr(C)]
+pub struct LockupTiers {
+    /// The configured tiers, ordered by increasing duration, with any unused
+    /// tiers last.
+    ///
+    /// Shank requires a literal length, which must match
+    /// `LOCKUP_TIERS_CAPACITY`.
+    pub tiers: [LockupTier; 4],
+    /// The bump seed of this account's address, stored to avoid re-deriving
+    /// it with `find_program_address`.
+    pub bump_seed: u8,
+    /// Whether the pool has a vote escrow account, which lockups must then
+    /// be recorded in.
+    pub vote_escrow: u8,
+    pub _padding: [u8; 6],
+}
+
+impl LockupTiers {
+    pub const LEN: usize = std::mem::size_of::<LockupTiers>();
+
+    /// Whether the tiers are well formed: used tiers come first, with
+    /// strictly increasing non-zero durations of at most
+    /// `MAX_LOCKUP_DURATION`, and increasing multipliers between `1x` and
+    /// `MAX_LOCKUP_MULTIPLIER_BPS`.
+    pub fn is_valid(tiers: &[LockupTier]) -> bool {
+        let used = tiers
+            .iter()
+            .take_while(|tier| tier.multiplier_bps != 0)
+            .count();
+        if tiers[used..]
+            .iter()
+            .any(|tier| *tier != LockupTier::default())
+        {
+            return false;
+        }
+
+        let mut previous = LockupTier {
+            duration: 0,
+            multiplier_bps: BASIS_POINTS,
+        };
+        for tier in &tiers[..used] {
+            if tier.duration <= previous.duration
+                || tier.duration > MAX_LOCKUP_DURATION
+                || tier.multiplier_bps < previous.multiplier_bps
+                || tier.multiplier_bps > MAX_LOCKUP_MULTIPLIER_BPS
+            {
+                return false;
+            }
+            previous = *tier;
+        }
+
+        true
+    }
+
+    /// The multiplier of the longest tier whose duration does not exceed
+    /// `duration`, if any.
+    pub fn multiplier_bps(&self, duration: u64) -> Option<u64> {
+        self.tiers
+            .iter()
+            .take_while(|tier| tier.multiplier_bps != 0)
+            .filter(|tier| tier.duration <= duration)
+            .last()
+            .map(|tier| tier.multiplier_bps)
+    }
+}
+
+/// The length of a vote escrow period, in seconds: one week.
+///
+/// Voting power ends on a period boundary, so the pool-wide total only
+/// changes slope once per period.
+pub const VOTE_ESCROW_PERIOD: i64 = 7 * 24 * 60 * 60;
+/// The number of periods a vote escrow schedules expiries for, covering the
+/// longest lockup.
+pub const VOTE_ESCROW_CAPACITY: usize = 256;
+
+/// The voting power of `amount` tokens locked until `end`, at
+/// `unix_timestamp`.
+///
+/// Voting power is proportional to the time remaining until `end`, with
+/// tokens locked for `MAX_LOCKUP_DURATION` carrying one vote each, and
+/// decays linearly to zero at `end`.
+pub fn voting_power(amount: u64, end: i64, unix_timestamp: i64) -> u64 {
+    let remaining = end.saturating_sub(unix_timestamp).max(0) as u128;
+    // Cannot overflow: the product is at most `u64::MAX * i64::MAX`, and the
+    // result is at most `amount` for lockups no longer than the maximum.
+    ((amount as u128 * remaining) / MAX_LOCKUP_DURATION as u128)
+        .try_into()
+        .unwrap_or(u64::MAX)
+}
+
+/// The time voting power for a lockup until `unlock_timestamp` ends: the
+/// start of the vote escrow period containing it.
+pub fn voting_power_end(unlock_timestamp: i64) -> i64 {
+    unlock_timestamp - unlock_timestamp.rem_euclid(VOTE_ESCROW_PERIOD)
+}
+
+/// Tracks a holder rewards pool's total voting power.
+///
+/// The total is the sum of every counted lockup's voting power, each of the
+/// form `amount * (end - now) / MAX_LOCKUP_DURATION`. Summing
+/// `amount` and `amount * end` over lockups that have not yet ended gives
+/// the total at any time until the next lockup ends. Lockups end on period
+/// boundaries, with the amounts ending at each boundary scheduled in
+/// `expiring`, and are removed from the sums as the boundary is passed.
+#[derive(Clone, Copy, Debug, PartialEq, Pod, ShankAccount, Zeroable)]
+#[repr(C)]
+pub struct VoteEscrow {
+    /// The sum of `amount * end` over counted lockups that had not ended at
+    /// `last_checkpoint`.
+    pub locked_end_sum: u128,
+    /// The time the sums were last brought up to date.
+    pub last_checkpoint: i64,
+    /// The sum of `amount` over counted lockups that had not ended at
+    /// `last_checkpoint`.
+    pub locked: u64,
+    /// The bump seed of this account's address, stored to avoid re-deriving
+    /// it with `find_program_address`.
+    pub bump_seed: u8,
+    /// Whether lockup reward multipliers follow voting power instead of the
+    /// lockup tiers.
+    pub reward_weighting: u8,
+    pub _padding: [u8; 14],
+    /// The amount ending at each period boundary, indexed by period number
+    /// modulo `VOTE_ESCROW_CAPACITY`.
+    ///
+    /// Shank requires a literal length, which must match
+    /// `VOTE_ESCROW_CAPACITY`.
+    pub expiring: [u64; 256],
+}
+
+impl VoteEscrow {
+    pub const LEN: usize = std::mem::size_of::<VoteEscrow>();
+
+    fn expiring_index(end: i64) -> usize {
+        end.div_euclid(VOTE_ESCROW_PERIOD)
+            .rem_euclid(VOTE_ESCROW_CAPACITY as i64) as usize
+    }
+
+    /// Brings the sums up to date, removing lockups that ended since the
+    /// last checkpoint.
+    pub fn checkpoint(&mut self, unix_timestamp: i64) {
+        if unix_timestamp <= self.last_checkpoint {
+            return;
+        }
+
+        // Every counted lockup ends within `VOTE_ESCROW_CAPACITY` periods of
+        // the last checkpoint, so no further periods need visiting.
+        let first_period = self.last_checkpoint.div_euclid(VOTE_ESCROW_PERIOD) + 1;
+        let last_period = unix_timestamp
+            .div_euclid(VOTE_ESCROW_PERIOD)
+            .min(first_period + VOTE_ESCROW_CAPACITY as i64 - 1);
+        for period in first_period..=last_period {
+            let end = period * VOTE_ESCROW_PERIOD;
+            let index = Self::expiring_index(end);
+            let amount = std::mem::take(&mut self.expiring[index]);
+            self.locked = self.locked.saturating_sub(amount);
+            self.locked_end_sum = self
+                .locked_end_sum
+                .saturating_sub(amount as u128 * end as u128);
+        }
+
+        self.last_checkpoint = unix_timestamp;
+    }
+
+    /// Counts a lockup of `amount` ending at `end`, if it has not already
+    /// ended.
+    ///
+    /// Must be called after checkpointing to the current time.
+    pub fn add(&mut self, amount: u64, end: i64) -> Option<()> {
+        if end <= self.last_checkpoint {
+            return Some(());
+        }
+
+        let index = Self::expiring_index(end);
+        self.expiring[index] = self.expiring[index].checked_add(amount)?;
+        self.locked = self.locked.checked_add(amount)?;
+        self.locked_end_sum = self
+            .locked_end_sum
+            .checked_add((amount as u128).checked_mul(end as u128)?)?;
+        Some(())
+    }
+
+    /// Stops counting a lockup of `amount` ending at `end`, if it has not
+    /// already ended.
+    ///
+    /// Must be called after checkpointing to the current time.
+    pub fn remove(&mut self, amount: u64, end: i64) -> Option<()> {
+        if end <= self.last_checkpoint {
+            return Some(());
+        }
+
+        let index = Self::expiring_index(end);
+        self.expiring[index] = self.expiring[index].checked_sub(amount)?;
+        self.locked = self.locked.checked_sub(amount)?;
+        self.locked_end_sum = self
+            .locked_end_sum
+            .checked_sub((amount as u128).checked_mul(end as u128)?)?;
+        Some(())
+    }
+
+    /// The pool's total voting power at `unix_timestamp`, which must not be
+    /// before the last checkpoint.
+    pub fn total_voting_power(&self, unix_timestamp: i64) -> u64 {
+        let mut vote_escrow = *self;
+        vote_escrow.checkpoint(unix_timestamp);
+
+        let remaining = vote_escrow
+            .locked_end_sum
+            .saturating_sub(vote_escrow.locked as u128 * unix_timestamp.max(0) as u128);
+        (remaining / MAX_LOCKUP_DURATION as u128)
+            .try_into()
+            .unwrap_or(u64::MAX)
+    }
+
+    /// The reward multiplier for a lockup ending at `end`, when rewards
+    /// follow voting power: `1x`, plus one voting power per locked token.
+    pub fn reward_multiplier_bps(end: i64, unix_timestamp: i64) -> u64 {
+        BASIS_POINTS + voting_power(BASIS_POINTS, end, unix_timestamp)
+    }
+}
+
+/// The number of entries held by a rate history account.
+pub const RATE_HISTORY_CAPACITY: usize = 128;
+
+/// A snapshot of a holder rewards pool's exchange rate.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, ShankType, Zeroable)]
+#[repr(C)]
+pub struct RateHistoryEntry {
+    /// The pool's rewards per token exchange rate.
+    ///
+    /// Stored as a `u128`, which includes a scaling factor of `1e18` to
+    /// represent the exchange rate with 18 decimal places of precision.
+    pub accumulated_rewards_per_token: u128,
+    /// The slot in which the rate was recorded.
+    pub slot: u64,
+    /// The epoch in which the rate was recorded.
+    pub epoch: u64,
+    /// The total amount of tokens deposited into the pool when the rate was
+    /// recorded.
+    pub total_deposited: u64,
+    pub _padding: [u8; 8],
+}
+
+/// An optional companion to a holder rewards pool, recording the pool's
+/// exchange rate each time it changes.
+///
+/// Entries are stored in a ring buffer, so once it is full each new entry
+/// overwrites the oldest one.
+#[derive(Clone, Copy, Debug, PartialEq, Pod, ShankAccount, Zeroable)]
+#[repr(C)]
+pub struct RateHistory {
+    /// The index in `entries` the next entry will be written to.
+    pub next_index: u64,
+    /// The number of entries written, up to `RATE_HISTORY_CAPACITY`.
+    pub len: u64,
+    /// The bump seed of this account's address, stored to avoid re-deriving
+    /// it with `find_program_address`.
+    pub bump_seed: u8,
+    pub _padding: [u8; 15],
+    /// The recorded entries.
+    ///
+    /// Shank requires a literal length, which must match
+    /// `RATE_HISTORY_CAPACITY`.
+    pub entries: [RateHistoryEntry; 128],
+}
+
+impl RateHistory {
+    pub const LEN: usize = std::mem::size_of::<RateHistory>();
+
+    fn latest_index(&self) -> Option<usize> {
+        (self.len > 0)
+            .then(|| (self.next_index as usize + RATE_HISTORY_CAPACITY - 1) % RATE_HISTORY_CAPACITY)
+    }
+
+    /// The most recently recorded entry, if any.
+    pub fn latest(&self) -> Option<&RateHistoryEntry> {
+        self.latest_index().map(|index| &self.entries[index])
+    }
+
+    /// Records an entry, overwriting the oldest entry if the buffer is full.
+    ///
+    /// Rates recorded within the same slot replace one another, so only the
+    /// last rate of each slot is kept.
+    pub fn push(&mut self, entry: RateHistoryEntry) {
+        if let Some(index) = self.latest_index() {
+            if self.entries[index].slot == entry.slot {
+                self.entries[index] = entry;
+                return;
+            }
+        }
+
+        self.entries[self.next_index as usize % RATE_HISTORY_CAPACITY] = entry;
+        self.next_index = (self.next_index + 1) % RATE_HISTORY_CAPACITY as u64;
+        self.len = std::cmp::min(self.len + 1, RATE_HISTORY_CAPACITY as u64);
+    }
+}
+
+/// The number of epochs held by an epoch ledger account.
+pub const EPOCH_LEDGER_CAPACITY: usize = 64;
+
+/// The rewards a holder rewards pool accrued during an epoch.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, ShankType, Zeroable)]
+#[repr(C)]
+pub struct EpochLedgerEntry {
+    /// The increase in the pool's rewards per token exchange rate during the
+    /// epoch.
+    ///
+    /// Stored as a `u128`, which includes a scaling factor of `1e18` to
+    /// represent the exchange rate with 18 decimal places of precision.
+    pub rate_delta: u128,
+    /// The epoch.
+    pub epoch: u64,
+    /// The lamports the pool received as rewards during the epoch.
+    pub lamports_received: u64,
+}
+
+/// An optional companion to a holder rewards pool, recording the rewards the
+/// pool accrued in each epoch, for reconciling per-epoch revenue.
+///
+/// Rewards are recorded in the epoch the pool accrues them, which is the
+/// epoch of the first pool update after they arrive. The ledger tracks the
+/// pool's running totals, so rewards accrued by updates that did not
+/// provide the ledger are recorded by the next update that does.
+///
+/// Entries are stored in a ring buffer, so once it is full each new epoch
+/// overwrites the oldest one. Epochs in which nothing was accrued have no
+/// entry.
+#[derive(Clone, Copy, Debug, PartialEq, Pod, ShankAccount, Zeroable)]
+#[repr(C)]
+pub struct EpochLedger {
+    /// The pool's rewards per token exchange rate when the ledger was last
+    /// updated.
+    pub last_accumulated_rewards_per_token: u128,
+    /// The pool's total rewards received when the ledger was last updated.
+    pub last_total_rewards_received: u64,
+    /// The index in `entries` the next entry will be written to.
+    pub next_index: u64,
+    /// The number of entries written, up to `EPOCH_LEDGER_CAPACITY`.
+    pub len: u64,
+    /// The bump seed of this account's address, stored to avoid re-deriving
+    /// it with `find_program_address`.
+    pub bump_seed: u8,
+    pub _padding: [u8; 7],
+    /// The recorded entries.
+    ///
+    /// Shank requires a literal length, which must match
+    /// `EPOCH_LEDGER_CAPACITY`.
+    pub entries: [EpochLedgerEntry; 64],
+}
+
+impl EpochLedger {
+    pub const LEN: usize = std::mem::size_of::<EpochLedger>();
+
+    fn latest_index(&self) -> Option<usize> {
+        (self.len > 0)
+            .then(|| (self.next_index as usize + EPOCH_LEDGER_CAPACITY - 1) % EPOCH_LEDGER_CAPACITY)
+    }
+
+    /// The most recently recorded entry, if any.
+    pub fn latest(&self) -> Option<&EpochLedgerEntry> {
+        self.latest_index().map(|index| &self.entries[index])
+    }
+
+    /// The entry recorded for `epoch`, if it is still held.
+    pub fn entry(&self, epoch: u64) -> Option<&EpochLedgerEntry> {
+        self.entries[..self.len as usize]
+            .iter()
+            .find(|entry| entry.epoch == epoch)
+    }
+
+    /// Records the pool's running totals in `epoch`, adding what the pool
+    /// accrued since the ledger was last updated to the epoch's entry.
+    ///
+    /// The pool's rate wraps on overflow, so its increase is computed with
+    /// wrapping arithmetic.
+    pub fn record(
+        &mut self,
+        epoch: u64,
+        accumulated_rewards_per_token: u128,
+        total_rewards_received: u64,
+    ) {
+        let rate_delta =
+            accumulated_rewards_per_token.wrapping_sub(self.last_accumulated_rewards_per_token);
+        let lamports_received =
+            total_rewards_received.saturating_sub(self.last_total_rewards_received);
+        self.last_accumulated_rewards_per_token = accumulated_rewards_per_token;
+        self.last_total_rewards_received = total_rewards_received;
+
+        if let Some(index) = self.latest_index() {
+            let entry = &mut self.entries[index];
+            if entry.epoch == epoch {
+                entry.rate_delta = entry.rate_delta.wrapping_add(rate_delta);
+                entry.lamports_received = entry.lamports_received.saturating_add(lamports_received);
+                return;
+            }
+        }
+
+        if rate_delta == 0 && lamports_received == 0 {
+            return;
+        }
+
+        self.entries[self.next_index as usize % EPOCH_LEDGER_CAPACITY] = EpochLedgerEntry {
+            rate_delta,
+            epoch,
+            lamports_received,
+        };
+        self.next_index = (self.next_index + 1) % EPOCH_LEDGER_CAPACITY as u64;
+        self.len = std::cmp::min(self.len + 1, EPOCH_LEDGER_CAPACITY as u64);
+    }
+}
+
+/// The number of checkpoints held by a stake checkpoints account.
+pub const STAKE_CHECKPOINTS_CAPACITY: usize = 64;
+
+/// A holder's deposited balance as of a slot.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, ShankType, Zeroable)]
+#[repr(C)]
+pub struct StakeCheckpoint {
+    /// The slot from which the balance applies.
+    pub slot: u64,
+    /// The holder's deposited balance at the end of the slot.
+    pub deposited: u64,
+}
+
+/// An optional companion to a holder rewards account, recording the holder's
+/// deposited balance each time it changes, so it can be read as of a past
+/// slot.
+///
+/// Checkpoints are compacted as they are recorded: changes within the same
+/// slot replace one another, and unchanged balances are not recorded.
+/// Checkpoints are stored in a ring buffer, so once it is full each new
+/// checkpoint overwrites the oldest one, and balances before the oldest
+/// remaining checkpoint can no longer be read.
+///
+/// The account is derived from the owner rather than the holder rewards
+/// account, so it outlives a closed holder rewards account and past
+/// balances stay readable.
+#[derive(Clone, Copy, Debug, PartialEq, Pod, ShankAccount, Zeroable)]
+#[repr(C)]
+pub struct StakeCheckpoints {
+    /// The index in `checkpoints` the next checkpoint will be written to.
+    pub next_index: u64,
+    /// The number of checkpoints written, up to
+    /// `STAKE_CHECKPOINTS_CAPACITY`.
+    pub len: u64,
+    /// The bump seed of this account's address, stored to avoid re-deriving
+    /// it with `find_program_address`.
+    pub bump_seed: u8,
+    pub _padding: [u8; 15],
+    /// The recorded checkpoints.
+    ///
+    /// Shank requires a literal length, which must match
+    /// `STAKE_CHECKPOINTS_CAPACITY`.
+    pub checkpoints: [StakeCheckpoint; 64],
+}
+
+impl StakeCheckpoints {
+    pub const LEN: usize = std::mem::size_of::<StakeCheckpoints>();
+
+    // Indices of the recorded checkpoints, newest first.
+    fn indices_newest_first(&self) -> impl Iterator<Item = usize> {
+        let next_index = self.next_index as usize;
+        (1..=self.len as usize).map(move |age| {
+            (next_index + STAKE_CHECKPOINTS_CAPACITY - age) % STAKE_CHECKPOINTS_CAPACITY
+        })
+    }
+
+    /// The most recently recorded checkpoint, if any.
+    pub fn latest(&self) -> Option<&StakeCheckpoint> {
+        self.indices_newest_first()
+            .next()
+            .map(|index| &self.checkpoints[index])
+    }
+
+    /// Records the holder's deposited balance as of `slot`, overwriting the
+    /// oldest checkpoint if the buffer is full.
+    pub fn push(&mut self, slot: u64, deposited: u64) {
+        if let Some(index) = self.indices_newest_first().next() {
+            let latest = &mut self.checkpoints[index];
+            if latest.slot == slot {
+                latest.deposited = deposited;
+                return;
+            }
+            if latest.deposited == deposited {
+                return;
+            }
+        }
+
+        self.checkpoints[self.next_index as usize % STAKE_CHECKPOINTS_CAPACITY] =
+            StakeCheckpoint { slot, deposited };
+        self.next_index = (self.next_index + 1) % STAKE_CHECKPOINTS_CAPACITY as u64;
+        self.len = std::cmp::min(self.len + 1, STAKE_CHECKPOINTS_CAPACITY as u64);
+    }
+
+    /// The holder's deposited balance at the end of `slot`, if it is covered
+    /// by the recorded checkpoints.
+    pub fn deposited_at(&self, slot: u64) -> Option<u64> {
+        self.indices_newest_first()
+            .map(|index| &self.checkpoints[index])
+            .find(|checkpoint| checkpoint.slot <= slot)
+            .map(|checkpoint| checkpoint.deposited)
+    }
 }
 
 #[inline(always)]
@@ -269,3 +1445,575 @@ pub fn find_duna_document_pda(signer: &Pubkey, doc_hash: &[u8; 32]) -> (Pubkey,
         &DUNA_PROGRAM_ID,
     )
 }
+
+/// The pool has no access list: any wallet may deposit.
+pub const ACCESS_LIST_NONE: u8 = 0;
+/// Only wallets the pool's access list allows may deposit.
+pub const ACCESS_LIST_ALLOWLIST: u8 = 1;
+/// Any wallet the pool's access list does not deny may deposit.
+pub const ACCESS_LIST_DENYLIST: u8 = 2;
+
+/// The wallet is not listed, as if it had no access entry.
+pub const ACCESS_STATUS_UNLISTED: u8 = 0;
+/// The wallet is allowed to deposit.
+pub const ACCESS_STATUS_ALLOWED: u8 = 1;
+/// The wallet is denied from depositing.
+pub const ACCESS_STATUS_DENIED: u8 = 2;
+
+/// A wallet's entry in a holder rewards pool's access list, set by the
+/// pool's authority.
+///
+/// Access lists only gate opening holder rewards accounts and depositing:
+/// a denied holder can still withdraw and harvest what they already
+/// accrued.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, ShankAccount, Zeroable)]
+#[repr(C)]
+pub struct AccessEntry {
+    /// The wallet's status: one of the `ACCESS_STATUS_*` values.
+    pub status: u8,
+    /// The bump seed of this account's address, stored to avoid re-deriving
+    /// it with `find_program_address`.
+    pub bump_seed: u8,
+    pub _padding: [u8; 6],
+}
+
+impl AccessEntry {
+    pub const LEN: usize = std::mem::size_of::<AccessEntry>();
+}
+
+/// Whether a pool with the `access_list` mode permits a wallet with the
+/// access `status` to deposit.
+pub fn access_permitted(access_list: u8, status: u8) -> bool {
+    match access_list {
+        ACCESS_LIST_ALLOWLIST => status == ACCESS_STATUS_ALLOWED,
+        ACCESS_LIST_DENYLIST => status != ACCESS_STATUS_DENIED,
+        _ => true,
+    }
+}
+
+/// A time-boxed reward campaign, escrowing lamports that unlock linearly
+/// into a holder rewards pool between its start and end slots.
+///
+/// Unlocked rewards are only released into the pool by
+/// `ReleaseCampaignRewards` and `ReclaimCampaignRewards`, so campaigns must
+/// be cranked regularly. Rewards unlocked while nothing was deposited in the
+/// pool are set aside, and returned to the funder with
+/// `ReclaimCampaignRewards` after the end slot.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, ShankAccount, Zeroable)]
+#[repr(C)]
+pub struct Campaign {
+    /// The holder rewards pool the campaign rewards.
+    pub holder_rewards_pool: Pubkey,
+    /// The account that funded the campaign, which reclaims unspent
+    /// rewards and the account's rent.
+    pub funder: Pubkey,
+    /// The total rewards escrowed by the campaign.
+    pub amount: u64,
+    /// The slot rewards start unlocking at.
+    pub start_slot: u64,
+    /// The slot all rewards are unlocked by.
+    pub end_slot: u64,
+    /// The unlocked rewards released into the pool.
+    pub released: u64,
+    /// The unlocked rewards set aside because nothing was deposited in the
+    /// pool, reclaimable by the funder.
+    pub unspent: u64,
+    /// The slot the campaign was last released at, or created at.
+    pub last_release_slot: u64,
+    /// The pool's `zero_weight_slots` as of the last release.
+    pub zero_weight_slots: u64,
+    /// The bump seed of this account's address, stored to avoid re-deriving
+    /// it with `find_program_address`.
+    pub bump_seed: u8,
+    pub _padding: [u8; 7],
+}
+
+impl Campaign {
+    pub const LEN: usize = std::mem::size_of::<Campaign>();
+
+    /// The rewards unlocked by `slot`, rounded down.
+    pub fn unlocked(&self, slot: u64) -> u64 {
+        if slot <= self.start_slot {
+            return 0;
+        }
+        if slot >= self.end_slot {
+            return self.amount;
+        }
+        // Cannot overflow, since the elapsed slots are less than the
+        // duration, and the result is less than `amount`.
+        (self.amount as u128 * (slot - self.start_slot) as u128
+            / (self.end_slot - self.start_slot) as u128) as u64
+    }
+
+    /// The unlocked rewards not yet released into the pool or set aside.
+    pub fn releasable(&self, slot: u64) -> u64 {
+        self.unlocked(slot)
+            .saturating_sub(self.released)
+            .saturating_sub(self.unspent)
+    }
+
+    /// The share of `releasable` to set aside when releasing at `slot`,
+    /// given the pool's current `zero_weight_slots` and whether it has
+    /// weight now, rounded down.
+    ///
+    /// Rewards are set aside in proportion to the slots since the last
+    /// release that the pool spent without weight, or entirely if the pool
+    /// has no weight to release them to.
+    pub fn unspendable(
+        &self,
+        releasable: u64,
+        slot: u64,
+        zero_weight_slots: u64,
+        has_weight: bool,
+    ) -> u64 {
+        let elapsed = slot.saturating_sub(self.last_release_slot);
+        if !has_weight {
+            return releasable;
+        }
+        if elapsed == 0 {
+            return 0;
+        }
+        let zero_weight_elapsed = zero_weight_slots
+            .saturating_sub(self.zero_weight_slots)
+            .min(elapsed);
+        // Cannot overflow, since the slots without weight are at most the
+        // elapsed slots, and the result is at most `releasable`.
+        (releasable as u128 * zero_weight_elapsed as u128 / elapsed as u128) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(slot: u64) -> RateHistoryEntry {
+        RateHistoryEntry {
+            slot,
+            ..RateHistoryEntry::default()
+        }
+    }
+
+    #[test]
+    fn rate_history_push() {
+        let mut history: RateHistory = Zeroable::zeroed();
+        assert_eq!(history.latest(), None);
+
+        history.push(entry(1));
+        history.push(entry(2));
+
+        assert_eq!(history.len, 2);
+        assert_eq!(history.next_index, 2);
+        assert_eq!(history.latest(), Some(&entry(2)));
+    }
+
+    #[test]
+    fn rate_history_push_same_slot_replaces_latest() {
+        let mut history: RateHistory = Zeroable::zeroed();
+        history.push(entry(1));
+        history.push(RateHistoryEntry {
+            accumulated_rewards_per_token: 1,
+            ..entry(1)
+        });
+
+        assert_eq!(history.len, 1);
+        assert_eq!(history.next_index, 1);
+        assert_eq!(history.latest().unwrap().accumulated_rewards_per_token, 1);
+    }
+
+    #[test]
+    fn rate_history_push_overwrites_oldest_when_full() {
+        let mut history: RateHistory = Zeroable::zeroed();
+        for slot in 1..=(RATE_HISTORY_CAPACITY as u64 + 2) {
+            history.push(entry(slot));
+        }
+
+        assert_eq!(history.len, RATE_HISTORY_CAPACITY as u64);
+        assert_eq!(history.next_index, 2);
+        assert_eq!(history.entries[0], entry(RATE_HISTORY_CAPACITY as u64 + 1));
+        assert_eq!(history.entries[2], entry(3));
+        assert_eq!(
+            history.latest(),
+            Some(&entry(RATE_HISTORY_CAPACITY as u64 + 2))
+        );
+    }
+
+    fn tier(duration: u64, multiplier_bps: u64) -> LockupTier {
+        LockupTier {
+            duration,
+            multiplier_bps,
+        }
+    }
+
+    #[test]
+    fn lockup_tiers_validity() {
+        let unused = LockupTier::default();
+        assert!(LockupTiers::is_valid(&[unused; 4]));
+        assert!(LockupTiers::is_valid(&[
+            tier(10, 10_000),
+            tier(20, 15_000),
+            unused,
+            unused
+        ]));
+
+        // Durations must increase.
+        assert!(!LockupTiers::is_valid(&[tier(0, 10_000), unused]));
+        assert!(!LockupTiers::is_valid(&[
+            tier(20, 15_000),
+            tier(10, 20_000)
+        ]));
+        // Multipliers must be at least 1x, increase and not exceed the max.
+        assert!(!LockupTiers::is_valid(&[tier(10, 9_999)]));
+        assert!(!LockupTiers::is_valid(&[
+            tier(10, 20_000),
+            tier(20, 15_000)
+        ]));
+        assert!(!LockupTiers::is_valid(&[tier(
+            10,
+            MAX_LOCKUP_MULTIPLIER_BPS + 1
+        )]));
+        // Unused tiers must come last.
+        assert!(!LockupTiers::is_valid(&[unused, tier(10, 10_000)]));
+        assert!(!LockupTiers::is_valid(&[tier(10, 10_000), tier(20, 0)]));
+    }
+
+    #[test]
+    fn lockup_tiers_multiplier() {
+        let tiers = LockupTiers {
+            tiers: [
+                tier(100, 12_000),
+                tier(200, 15_000),
+                LockupTier::default(),
+                LockupTier::default(),
+            ],
+            ..Zeroable::zeroed()
+        };
+        assert_eq!(tiers.multiplier_bps(0), None);
+        assert_eq!(tiers.multiplier_bps(99), None);
+        assert_eq!(tiers.multiplier_bps(100), Some(12_000));
+        assert_eq!(tiers.multiplier_bps(199), Some(12_000));
+        assert_eq!(tiers.multiplier_bps(u64::MAX), Some(15_000));
+    }
+
+    #[test]
+    fn holder_rewards_weight() {
+        let holder = HolderRewards {
+            deposited: 100,
+            locked_amount: 40,
+            lockup_multiplier_bps: 15_000,
+            ..HolderRewards::default()
+        };
+        assert_eq!(holder.lockup_bonus_weight(), Some(20));
+        assert_eq!(holder.weight(), Some(120));
+
+        let unlocked = HolderRewards {
+            deposited: 100,
+            ..HolderRewards::default()
+        };
+        assert_eq!(unlocked.lockup_bonus_weight(), Some(0));
+        assert_eq!(unlocked.weight(), Some(100));
+    }
+
+    const WEEK: i64 = VOTE_ESCROW_PERIOD;
+
+    #[test]
+    fn voting_power_decays_linearly() {
+        let end = MAX_LOCKUP_DURATION as i64;
+        assert_eq!(voting_power(1_000, end, 0), 1_000);
+        assert_eq!(voting_power(1_000, end, end / 2), 500);
+        assert_eq!(voting_power(1_000, end, end), 0);
+        assert_eq!(voting_power(1_000, end, end + 1), 0);
+    }
+
+    #[test]
+    fn voting_power_end_rounds_down_to_period() {
+        assert_eq!(voting_power_end(0), 0);
+        assert_eq!(voting_power_end(WEEK - 1), 0);
+        assert_eq!(voting_power_end(WEEK), WEEK);
+        assert_eq!(voting_power_end(3 * WEEK + 5), 3 * WEEK);
+    }
+
+    #[test]
+    fn vote_escrow_total_matches_sum_of_lockups() {
+        let lockups = [(1_000, 2 * WEEK), (3_000, 5 * WEEK), (500, 104 * WEEK)];
+
+        let mut vote_escrow: VoteEscrow = Zeroable::zeroed();
+        for (amount, end) in lockups {
+            vote_escrow.add(amount, end).unwrap();
+        }
+
+        for now in [0, WEEK, 2 * WEEK, 3 * WEEK + 1, 5 * WEEK, 104 * WEEK] {
+            let expected: u64 = lockups
+                .iter()
+                .map(|(amount, end)| voting_power(*amount, *end, now))
+                .sum();
+            let total = vote_escrow.total_voting_power(now);
+            // Each lockup's voting power is rounded down separately.
+            assert!(expected <= total && total <= expected + lockups.len() as u64);
+        }
+    }
+
+    #[test]
+    fn vote_escrow_checkpoint_removes_ended_lockups() {
+        let mut vote_escrow: VoteEscrow = Zeroable::zeroed();
+        vote_escrow.add(1_000, 2 * WEEK).unwrap();
+        vote_escrow.add(3_000, 5 * WEEK).unwrap();
+
+        vote_escrow.checkpoint(2 * WEEK);
+        assert_eq!(vote_escrow.locked, 3_000);
+        assert_eq!(vote_escrow.locked_end_sum, 3_000 * 5 * WEEK as u128);
+
+        // Lockups that have ended are no longer counted, so removing them
+        // is a no-op.
+        vote_escrow.remove(1_000, 2 * WEEK).unwrap();
+        vote_escrow.remove(3_000, 5 * WEEK).unwrap();
+        assert_eq!(
+            vote_escrow,
+            VoteEscrow {
+                last_checkpoint: 2 * WEEK,
+                ..Zeroable::zeroed()
+            }
+        );
+    }
+
+    #[test]
+    fn vote_escrow_checkpoint_after_long_gap() {
+        let mut vote_escrow: VoteEscrow = Zeroable::zeroed();
+        vote_escrow.add(1_000, 200 * WEEK).unwrap();
+
+        vote_escrow.checkpoint(1_000 * WEEK);
+        assert_eq!(vote_escrow.locked, 0);
+        assert_eq!(vote_escrow.locked_end_sum, 0);
+        assert_eq!(vote_escrow.total_voting_power(1_000 * WEEK), 0);
+    }
+
+    #[test]
+    fn vote_escrow_reward_multiplier() {
+        let end = MAX_LOCKUP_DURATION as i64;
+        assert_eq!(VoteEscrow::reward_multiplier_bps(end, 0), 2 * BASIS_POINTS);
+        assert_eq!(
+            VoteEscrow::reward_multiplier_bps(end, end / 2),
+            BASIS_POINTS + BASIS_POINTS / 2
+        );
+        assert_eq!(VoteEscrow::reward_multiplier_bps(end, end), BASIS_POINTS);
+    }
+
+    #[test]
+    fn stake_checkpoints_push_and_read() {
+        let mut checkpoints: StakeCheckpoints = bytemuck::Zeroable::zeroed();
+        assert_eq!(checkpoints.deposited_at(u64::MAX), None);
+
+        checkpoints.push(10, 100);
+        checkpoints.push(20, 50);
+        assert_eq!(checkpoints.len, 2);
+        assert_eq!(checkpoints.deposited_at(9), None);
+        assert_eq!(checkpoints.deposited_at(10), Some(100));
+        assert_eq!(checkpoints.deposited_at(19), Some(100));
+        assert_eq!(checkpoints.deposited_at(20), Some(50));
+        assert_eq!(checkpoints.deposited_at(u64::MAX), Some(50));
+    }
+
+    #[test]
+    fn stake_checkpoints_compaction() {
+        let mut checkpoints: StakeCheckpoints = bytemuck::Zeroable::zeroed();
+        checkpoints.push(10, 100);
+
+        // Changes within a slot keep only the last balance.
+        checkpoints.push(20, 50);
+        checkpoints.push(20, 75);
+        assert_eq!(checkpoints.len, 2);
+        assert_eq!(checkpoints.deposited_at(20), Some(75));
+
+        // Unchanged balances are not recorded.
+        checkpoints.push(30, 75);
+        assert_eq!(checkpoints.len, 2);
+        assert_eq!(
+            checkpoints.latest(),
+            Some(&StakeCheckpoint {
+                slot: 20,
+                deposited: 75,
+            })
+        );
+    }
+
+    #[test]
+    fn stake_checkpoints_overwrite_oldest_when_full() {
+        let mut checkpoints: StakeCheckpoints = bytemuck::Zeroable::zeroed();
+        for slot in 1..=STAKE_CHECKPOINTS_CAPACITY as u64 + 2 {
+            checkpoints.push(slot, slot * 10);
+        }
+
+        assert_eq!(checkpoints.len, STAKE_CHECKPOINTS_CAPACITY as u64);
+        assert_eq!(checkpoints.deposited_at(2), None);
+        assert_eq!(checkpoints.deposited_at(3), Some(30));
+        assert_eq!(
+            checkpoints.deposited_at(u64::MAX),
+            Some((STAKE_CHECKPOINTS_CAPACITY as u64 + 2) * 10)
+        );
+    }
+
+    #[test]
+    fn rate_history_capacity() {
+        assert_eq!(
+            std::mem::size_of::<RateHistory>(),
+            32 + RATE_HISTORY_CAPACITY * std::mem::size_of::<RateHistoryEntry>()
+        );
+    }
+
+    #[test]
+    fn early_withdrawal_penalty() {
+        let mut pool: HolderRewardsPool = Zeroable::zeroed();
+        pool.early_withdrawal_penalty_bps = 2_500;
+        pool.early_withdrawal_period_slots = 100;
+
+        // Within the period.
+        assert_eq!(pool.early_withdrawal_penalty(1_000, 50, 50), 250);
+        assert_eq!(pool.early_withdrawal_penalty(1_000, 50, 149), 250);
+        // The period has elapsed.
+        assert_eq!(pool.early_withdrawal_penalty(1_000, 50, 150), 0);
+        // Rounds up.
+        assert_eq!(pool.early_withdrawal_penalty(3, 50, 50), 1);
+        assert_eq!(pool.early_withdrawal_penalty(0, 50, 50), 0);
+
+        // Disabled.
+        pool.early_withdrawal_period_slots = 0;
+        assert_eq!(pool.early_withdrawal_penalty(1_000, 50, 50), 0);
+    }
+
+    #[test]
+    fn access_list_permits() {
+        for status in [
+            ACCESS_STATUS_UNLISTED,
+            ACCESS_STATUS_ALLOWED,
+            ACCESS_STATUS_DENIED,
+        ] {
+            assert!(access_permitted(ACCESS_LIST_NONE, status));
+        }
+
+        assert!(!access_permitted(
+            ACCESS_LIST_ALLOWLIST,
+            ACCESS_STATUS_UNLISTED
+        ));
+        assert!(access_permitted(
+            ACCESS_LIST_ALLOWLIST,
+            ACCESS_STATUS_ALLOWED
+        ));
+        assert!(!access_permitted(
+            ACCESS_LIST_ALLOWLIST,
+            ACCESS_STATUS_DENIED
+        ));
+
+        assert!(access_permitted(
+            ACCESS_LIST_DENYLIST,
+            ACCESS_STATUS_UNLISTED
+        ));
+        assert!(access_permitted(
+            ACCESS_LIST_DENYLIST,
+            ACCESS_STATUS_ALLOWED
+        ));
+        assert!(!access_permitted(
+            ACCESS_LIST_DENYLIST,
+            ACCESS_STATUS_DENIED
+        ));
+    }
+
+    #[test]
+    fn epoch_ledger_accumulates_within_epoch() {
+        let mut ledger: EpochLedger = Zeroable::zeroed();
+        assert_eq!(ledger.latest(), None);
+
+        ledger.record(5, 10, 100);
+        ledger.record(5, 25, 150);
+        assert_eq!(ledger.len, 1);
+        assert_eq!(
+            ledger.entry(5),
+            Some(&EpochLedgerEntry {
+                rate_delta: 25,
+                epoch: 5,
+                lamports_received: 150,
+            })
+        );
+
+        ledger.record(6, 30, 175);
+        assert_eq!(ledger.len, 2);
+        assert_eq!(
+            ledger.latest(),
+            Some(&EpochLedgerEntry {
+                rate_delta: 5,
+                epoch: 6,
+                lamports_received: 25,
+            })
+        );
+    }
+
+    #[test]
+    fn epoch_ledger_skips_epochs_without_rewards() {
+        let mut ledger: EpochLedger = Zeroable::zeroed();
+        ledger.record(5, 10, 100);
+        ledger.record(6, 10, 100);
+        assert_eq!(ledger.len, 1);
+        assert_eq!(ledger.entry(6), None);
+
+        // The rate wraps on overflow.
+        ledger.record(7, 4, 110);
+        assert_eq!(ledger.entry(7).unwrap().rate_delta, u128::MAX - 5);
+    }
+
+    #[test]
+    fn epoch_ledger_overwrites_oldest_when_full() {
+        let mut ledger: EpochLedger = Zeroable::zeroed();
+        for epoch in 1..=EPOCH_LEDGER_CAPACITY as u64 + 2 {
+            ledger.record(epoch, epoch as u128, epoch);
+        }
+
+        assert_eq!(ledger.len, EPOCH_LEDGER_CAPACITY as u64);
+        assert_eq!(ledger.next_index, 2);
+        assert_eq!(ledger.entry(2), None);
+        assert_eq!(ledger.entry(3).unwrap().lamports_received, 1);
+        assert_eq!(
+            ledger.latest().unwrap().epoch,
+            EPOCH_LEDGER_CAPACITY as u64 + 2
+        );
+    }
+
+    #[test]
+    fn campaign_unlocks_linearly() {
+        let mut campaign = Campaign {
+            amount: 1_000,
+            start_slot: 100,
+            end_slot: 400,
+            ..Campaign::default()
+        };
+        assert_eq!(campaign.unlocked(0), 0);
+        assert_eq!(campaign.unlocked(100), 0);
+        assert_eq!(campaign.unlocked(250), 500);
+        // Rounds down.
+        assert_eq!(campaign.unlocked(101), 3);
+        assert_eq!(campaign.unlocked(400), 1_000);
+        assert_eq!(campaign.unlocked(u64::MAX), 1_000);
+
+        campaign.released = 300;
+        campaign.unspent = 100;
+        assert_eq!(campaign.releasable(250), 100);
+        assert_eq!(campaign.releasable(400), 600);
+        assert_eq!(campaign.releasable(101), 0);
+    }
+
+    #[test]
+    fn campaign_unspendable() {
+        let campaign = Campaign {
+            last_release_slot: 100,
+            zero_weight_slots: 50,
+            ..Campaign::default()
+        };
+        // The pool had weight throughout.
+        assert_eq!(campaign.unspendable(1_000, 200, 50, true), 0);
+        // The pool had no weight for a quarter of the slots.
+        assert_eq!(campaign.unspendable(1_000, 200, 75, true), 250);
+        // Rounds down.
+        assert_eq!(campaign.unspendable(1_000, 400, 51, true), 3);
+        // The pool has no weight now.
+        assert_eq!(campaign.unspendable(1_000, 200, 50, false), 1_000);
+        // Released earlier in the same slot.
+        assert_eq!(campaign.unspendable(1_000, 100, 50, true), 0);
+    }
+}
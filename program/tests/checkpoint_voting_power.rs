@@ -0,0 +1,296 @@
+#![cfg(feature = "test-sbf")]
+
+mod execute_utils;
+mod setup;
+
+use {
+    crate::{
+        execute_utils::{execute_with_payer, execute_with_payer_err},
+        setup::{
+            setup_holder_rewards_account, setup_holder_rewards_pool_account,
+            setup_lockup_tiers_account, setup_vote_escrow_account, DEPOSIT_AMOUNT,
+        },
+    },
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        state::{
+            get_holder_rewards_address, get_holder_rewards_pool_address, get_lockup_tiers_address,
+            get_vote_escrow_address, voting_power_end, HolderRewards, HolderRewardsPool,
+            LockupTier, VoteEscrow, MAX_LOCKUP_DURATION,
+        },
+    },
+    paladin_rewards_program_client::instructions::{CheckpointVotingPowerBuilder, LockBuilder},
+    setup::setup,
+    solana_program_test::*,
+    solana_sdk::{
+        clock::Clock, instruction::InstructionError, pubkey::Pubkey, signature::Keypair,
+        signer::Signer, transaction::TransactionError,
+    },
+};
+
+const TIER: LockupTier = LockupTier {
+    duration: 7 * 24 * 60 * 60,
+    multiplier_bps: 15_000,
+};
+
+struct Accounts {
+    owner: Keypair,
+    mint: Pubkey,
+    holder_rewards_pool: Pubkey,
+    holder_rewards: Pubkey,
+    vote_escrow: Pubkey,
+}
+
+// Sets up a pool with a vote escrow and a single holder who locked all of
+// the pool's tokens until `unlock_timestamp`.
+async fn setup_accounts(
+    context: &mut ProgramTestContext,
+    reward_weighting: bool,
+    unlock_timestamp: i64,
+) -> Accounts {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let now = unix_timestamp(context).await;
+
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    setup_holder_rewards_pool_account(context, &mint, &holder_rewards_pool, 0, 0, DEPOSIT_AMOUNT)
+        .await;
+
+    let lockup_tiers =
+        get_lockup_tiers_address(&holder_rewards_pool, &paladin_rewards_program::id());
+    setup_lockup_tiers_account(
+        context,
+        &holder_rewards_pool,
+        &lockup_tiers,
+        [
+            TIER,
+            LockupTier::default(),
+            LockupTier::default(),
+            LockupTier::default(),
+        ],
+    )
+    .await;
+
+    let vote_escrow = get_vote_escrow_address(&holder_rewards_pool, &paladin_rewards_program::id());
+    setup_vote_escrow_account(
+        context,
+        &holder_rewards_pool,
+        &lockup_tiers,
+        &vote_escrow,
+        reward_weighting,
+        now,
+    )
+    .await;
+
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    setup_holder_rewards_account(
+        context,
+        &owner.pubkey(),
+        &holder_rewards,
+        DEPOSIT_AMOUNT,
+        0,
+        &owner.pubkey(),
+    )
+    .await;
+
+    let instruction = LockBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards(holder_rewards)
+        .lockup_tiers(lockup_tiers)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .vote_escrow(Some(vote_escrow))
+        .amount(DEPOSIT_AMOUNT)
+        .unlock_timestamp(unlock_timestamp)
+        .instruction();
+    execute_with_payer(context, instruction, Some(&owner)).await;
+
+    Accounts {
+        owner,
+        mint,
+        holder_rewards_pool,
+        holder_rewards,
+        vote_escrow,
+    }
+}
+
+async fn unix_timestamp(context: &mut ProgramTestContext) -> i64 {
+    context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp
+}
+
+async fn set_unix_timestamp(context: &mut ProgramTestContext, unix_timestamp: i64) {
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp = unix_timestamp;
+    context.set_sysvar(&clock);
+}
+
+#[tokio::test]
+async fn fail_vote_escrow_incorrect_address() {
+    let mut context = setup().start_with_context().await;
+    let now = unix_timestamp(&mut context).await;
+    let accounts = setup_accounts(&mut context, true, now + MAX_LOCKUP_DURATION as i64).await;
+    let other = setup_accounts(&mut context, true, now + MAX_LOCKUP_DURATION as i64).await;
+
+    // A vote escrow for another pool.
+    let instruction = CheckpointVotingPowerBuilder::new()
+        .holder_rewards_pool(accounts.holder_rewards_pool)
+        .holder_rewards(accounts.holder_rewards)
+        .vote_escrow(other.vote_escrow)
+        .mint(accounts.mint)
+        .owner(accounts.owner.pubkey())
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, None).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectVoteEscrowAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_reweights_lockup() {
+    let mut context = setup().start_with_context().await;
+    let now = unix_timestamp(&mut context).await;
+    let unlock_timestamp = now + MAX_LOCKUP_DURATION as i64 / 2;
+    let accounts = setup_accounts(&mut context, true, unlock_timestamp).await;
+
+    // Half of the voting power decays away.
+    let later = now + MAX_LOCKUP_DURATION as i64 / 4;
+    set_unix_timestamp(&mut context, later).await;
+
+    // Anyone can checkpoint the holder.
+    let instruction = CheckpointVotingPowerBuilder::new()
+        .holder_rewards_pool(accounts.holder_rewards_pool)
+        .holder_rewards(accounts.holder_rewards)
+        .vote_escrow(accounts.vote_escrow)
+        .mint(accounts.mint)
+        .owner(accounts.owner.pubkey())
+        .instruction();
+    execute_with_payer(&mut context, instruction, None).await;
+
+    // Assert the multiplier follows the decayed voting power.
+    let multiplier_bps =
+        VoteEscrow::reward_multiplier_bps(voting_power_end(unlock_timestamp), later);
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(accounts.holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    assert_eq!(holder_rewards_state.lockup_multiplier_bps, multiplier_bps);
+    assert_eq!(holder_rewards_state.locked_amount, DEPOSIT_AMOUNT);
+
+    // Assert the pool's bonus weight was replaced.
+    let pool_account = context
+        .banks_client
+        .get_account(accounts.holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state = bytemuck::from_bytes::<HolderRewardsPool>(&pool_account.data);
+    assert_eq!(
+        pool_state.total_lockup_bonus_weight,
+        holder_rewards_state.lockup_bonus_weight().unwrap()
+    );
+
+    // Assert the vote escrow was checkpointed.
+    let vote_escrow_account = context
+        .banks_client
+        .get_account(accounts.vote_escrow)
+        .await
+        .unwrap()
+        .unwrap();
+    let vote_escrow_state = bytemuck::from_bytes::<VoteEscrow>(&vote_escrow_account.data);
+    assert_eq!(vote_escrow_state.last_checkpoint, later);
+}
+
+#[tokio::test]
+async fn success_without_reward_weighting() {
+    let mut context = setup().start_with_context().await;
+    let now = unix_timestamp(&mut context).await;
+    let accounts = setup_accounts(&mut context, false, now + MAX_LOCKUP_DURATION as i64 / 2).await;
+
+    set_unix_timestamp(&mut context, now + MAX_LOCKUP_DURATION as i64 / 4).await;
+
+    let instruction = CheckpointVotingPowerBuilder::new()
+        .holder_rewards_pool(accounts.holder_rewards_pool)
+        .holder_rewards(accounts.holder_rewards)
+        .vote_escrow(accounts.vote_escrow)
+        .mint(accounts.mint)
+        .owner(accounts.owner.pubkey())
+        .instruction();
+    execute_with_payer(&mut context, instruction, None).await;
+
+    // Assert the lockup keeps its tier multiplier.
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(accounts.holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    assert_eq!(
+        holder_rewards_state.lockup_multiplier_bps,
+        TIER.multiplier_bps
+    );
+}
+
+#[tokio::test]
+async fn success_releases_expired_lockup() {
+    let mut context = setup().start_with_context().await;
+    let now = unix_timestamp(&mut context).await;
+    let unlock_timestamp = now + TIER.duration as i64 * 2;
+    let accounts = setup_accounts(&mut context, true, unlock_timestamp).await;
+
+    set_unix_timestamp(&mut context, unlock_timestamp).await;
+
+    let instruction = CheckpointVotingPowerBuilder::new()
+        .holder_rewards_pool(accounts.holder_rewards_pool)
+        .holder_rewards(accounts.holder_rewards)
+        .vote_escrow(accounts.vote_escrow)
+        .mint(accounts.mint)
+        .owner(accounts.owner.pubkey())
+        .instruction();
+    execute_with_payer(&mut context, instruction, None).await;
+
+    // Assert the lockup and its voting power are gone.
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(accounts.holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    assert_eq!(holder_rewards_state.locked_amount, 0);
+    assert_eq!(holder_rewards_state.voting_power_locked, 0);
+
+    let vote_escrow_account = context
+        .banks_client
+        .get_account(accounts.vote_escrow)
+        .await
+        .unwrap()
+        .unwrap();
+    let vote_escrow_state = bytemuck::from_bytes::<VoteEscrow>(&vote_escrow_account.data);
+    assert_eq!(vote_escrow_state.locked, 0);
+    assert_eq!(vote_escrow_state.total_voting_power(unlock_timestamp), 0);
+
+    let pool_account = context
+        .banks_client
+        .get_account(accounts.holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state = bytemuck::from_bytes::<HolderRewardsPool>(&pool_account.data);
+    assert_eq!(pool_state.total_lockup_bonus_weight, 0);
+}
@@ -7,15 +7,18 @@ use {
     crate::{
         execute_utils::{execute_with_payer, execute_with_payer_err},
         setup::{
-            setup_holder_rewards_account_with_token_account,
-            setup_holder_rewards_pool_account_with_token_account, DEPOSIT_AMOUNT,
-            INITIAL_OWNER_BALANCE,
+            setup_holder_rewards_account, setup_holder_rewards_account_with_token_account,
+            setup_holder_rewards_pool_account_with_token_account, update_holder_rewards_pool,
+            HolderRewardsSetup, DEPOSIT_AMOUNT, INITIAL_OWNER_BALANCE,
         },
     },
     paladin_rewards_program::{
         error::PaladinRewardsError,
         processor::REWARDS_PER_TOKEN_SCALING_FACTOR,
-        state::{get_holder_rewards_address, get_holder_rewards_pool_address, HolderRewards},
+        state::{
+            get_holder_rewards_address, get_holder_rewards_pool_address, HolderRewards,
+            HolderRewardsPool,
+        },
     },
     paladin_rewards_program_client::instructions::CloseHolderRewardsBuilder,
     setup::setup,
@@ -60,9 +63,11 @@ async fn fail_pending_rewards() {
         &owner.pubkey(),
         &holder_rewards,
         &owner_token,
-        DEPOSIT_AMOUNT,
-        0,
-        INITIAL_OWNER_BALANCE,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE,
+        },
     )
     .await;
 
@@ -72,6 +77,7 @@ async fn fail_pending_rewards() {
         .holder_rewards(holder_rewards)
         .mint(mint)
         .owner(owner.pubkey())
+        .rent_payer(owner.pubkey())
         .instruction();
     let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
 
@@ -117,9 +123,11 @@ async fn fail_tokens_still_deposited() {
         &owner.pubkey(),
         &holder_rewards,
         &owner_token,
-        DEPOSIT_AMOUNT,
-        0,
-        INITIAL_OWNER_BALANCE,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE,
+        },
     )
     .await;
 
@@ -129,6 +137,7 @@ async fn fail_tokens_still_deposited() {
         .holder_rewards(holder_rewards)
         .mint(mint)
         .owner(owner.pubkey())
+        .rent_payer(owner.pubkey())
         .instruction();
     let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
 
@@ -142,7 +151,7 @@ async fn fail_tokens_still_deposited() {
 }
 
 #[tokio::test]
-async fn success() {
+async fn fail_incorrect_rent_payer() {
     let owner = Keypair::new();
     let mint = Pubkey::new_unique();
 
@@ -174,18 +183,86 @@ async fn success() {
         &owner.pubkey(),
         &holder_rewards,
         &owner_token,
+        HolderRewardsSetup {
+            deposited: 0,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE,
+        },
+    )
+    .await;
+
+    let instruction = CloseHolderRewardsBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .rent_payer(Pubkey::new_unique())
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectRentPayer as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
         0,
         0,
-        INITIAL_OWNER_BALANCE,
     )
     .await;
 
+    // Setup token account for the owner.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: 0,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE,
+        },
+    )
+    .await;
+
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.active_holder_count = 1
+    })
+    .await;
+
     let instruction = CloseHolderRewardsBuilder::new()
         .holder_rewards_pool(holder_rewards_pool)
         .holder_rewards_pool_token_account(pool_token)
         .holder_rewards(holder_rewards)
         .mint(mint)
         .owner(owner.pubkey())
+        .rent_payer(owner.pubkey())
         .instruction();
     execute_with_payer(&mut context, instruction, Some(&owner)).await;
 
@@ -204,6 +281,98 @@ async fn success() {
         .unwrap()
         .minimum_balance(HolderRewards::LEN);
     assert_eq!(owner_lamports, rent_amount);
+
+    // Assert the holder is no longer counted as active.
+    let pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        bytemuck::from_bytes::<HolderRewardsPool>(&pool_account.data).active_holder_count,
+        0
+    );
+}
+
+#[tokio::test]
+async fn success_returns_rent_to_sponsor() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        0,
+    )
+    .await;
+
+    // Setup token account for the owner.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: 0,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE,
+        },
+    )
+    .await;
+
+    // The holder rewards account was funded by a sponsor.
+    let sponsor = Pubkey::new_unique();
+    setup_holder_rewards_account(
+        &mut context,
+        &owner.pubkey(),
+        &holder_rewards,
+        0,
+        0,
+        &sponsor,
+    )
+    .await;
+
+    let instruction = CloseHolderRewardsBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .rent_payer(sponsor)
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&owner)).await;
+
+    // Assert that the sponsor got the rent lamports.
+    let sponsor_lamports = context
+        .banks_client
+        .get_account(sponsor)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let rent_amount = context
+        .banks_client
+        .get_rent()
+        .await
+        .unwrap()
+        .minimum_balance(HolderRewards::LEN);
+    assert_eq!(sponsor_lamports, rent_amount);
 }
 
 // #[tokio::test]
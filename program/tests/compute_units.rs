@@ -0,0 +1,348 @@
+#![cfg(feature = "test-sbf")]
+
+//! Compute unit regression tests.
+//!
+//! Holder rewards and pool addresses are validated with the bump seed stored
+//! in their account state, rather than re-derived with
+//! `find_program_address`. Deriving an address costs one
+//! `create_program_address` (1_500 CUs) per bump seed tried, so addresses
+//! with a low bump seed used to cost several thousand more CUs to validate
+//! than addresses with the canonical `255` bump.
+//!
+//! Each test runs an instruction once with keys whose addresses have a `255`
+//! bump seed and once with keys whose addresses have a low bump seed, and
+//! asserts the compute units consumed do not depend on the bump seed.
+
+mod setup;
+
+use {
+    crate::setup::{
+        send_rewards_to_pool, setup_holder_rewards_account_with_token_account,
+        setup_holder_rewards_pool_account, setup_holder_rewards_pool_account_with_token_account,
+        setup_mint, setup_system_account, setup_token_account, update_holder_rewards_pool,
+        HolderRewardsSetup, DEPOSIT_AMOUNT, INITIAL_OWNER_BALANCE,
+    },
+    paladin_rewards_program::state::{
+        get_holder_rewards_address, get_holder_rewards_address_and_bump_seed,
+        get_holder_rewards_pool_address, get_holder_rewards_pool_address_and_bump_seed,
+    },
+    paladin_rewards_program_client::instructions::{
+        CloseHolderRewardsBuilder, DepositBuilder, GetPendingRewardsBuilder, HarvestRewardsBuilder,
+        InitializeHolderRewardsBuilder, RecoverExcessTokensBuilder, WithdrawBuilder,
+    },
+    setup::{setup, sign_duna_document},
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer,
+        transaction::Transaction,
+    },
+    spl_associated_token_account::get_associated_token_address,
+    std::future::Future,
+};
+
+/// Less than the cost of a single `create_program_address`.
+const MAX_COMPUTE_UNIT_DELTA: u64 = 1_000;
+/// Bump seeds at or below this value took at least five extra attempts to
+/// find.
+const LOW_BUMP_SEED: u8 = 250;
+const REWARDS: u64 = 1_000_000_000;
+
+fn find_mint(predicate: impl Fn(u8) -> bool) -> Pubkey {
+    loop {
+        let mint = Pubkey::new_unique();
+        let (_, bump_seed) =
+            get_holder_rewards_pool_address_and_bump_seed(&mint, &paladin_rewards_program::id());
+        if predicate(bump_seed) {
+            return mint;
+        }
+    }
+}
+
+fn find_owner(predicate: impl Fn(u8) -> bool) -> Keypair {
+    loop {
+        let owner = Keypair::new();
+        let (_, bump_seed) = get_holder_rewards_address_and_bump_seed(
+            &owner.pubkey(),
+            &paladin_rewards_program::id(),
+        );
+        if predicate(bump_seed) {
+            return owner;
+        }
+    }
+}
+
+async fn compute_units_consumed(
+    context: &mut ProgramTestContext,
+    instruction: Instruction,
+    signer: Option<&Keypair>,
+) -> u64 {
+    let signers = match signer {
+        Some(signer) => vec![&context.payer, signer],
+        None => vec![&context.payer],
+    };
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &signers,
+        context.last_blockhash,
+    );
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(transaction)
+        .await
+        .unwrap();
+    result.result.unwrap();
+
+    result.metadata.unwrap().compute_units_consumed
+}
+
+async fn assert_independent_of_bump_seed<F, Fut>(measure: F)
+where
+    F: Fn(Pubkey, Keypair) -> Fut,
+    Fut: Future<Output = u64>,
+{
+    let canonical = measure(
+        find_mint(|bump_seed| bump_seed == u8::MAX),
+        find_owner(|bump_seed| bump_seed == u8::MAX),
+    )
+    .await;
+    let low = measure(
+        find_mint(|bump_seed| bump_seed <= LOW_BUMP_SEED),
+        find_owner(|bump_seed| bump_seed <= LOW_BUMP_SEED),
+    )
+    .await;
+
+    assert!(
+        canonical.abs_diff(low) < MAX_COMPUTE_UNIT_DELTA,
+        "compute units depend on the bump seed: {canonical} (bump 255) vs {low} (low bump)"
+    );
+}
+
+// Sets up a pool with rewards pending for a holder that deposited all of the
+// pool's tokens.
+async fn setup_pool_and_holder(
+    context: &mut ProgramTestContext,
+    mint: &Pubkey,
+    owner: &Pubkey,
+    deposited: u64,
+    token_balance: u64,
+) -> (Pubkey, Pubkey, Pubkey, Pubkey) {
+    let holder_rewards_pool = get_holder_rewards_pool_address(mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, mint);
+    setup_holder_rewards_pool_account_with_token_account(
+        context,
+        mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        deposited,
+    )
+    .await;
+
+    let holder_rewards = get_holder_rewards_address(owner, &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(owner, mint);
+    setup_holder_rewards_account_with_token_account(
+        context,
+        mint,
+        owner,
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited,
+            last_accumulated_rewards_per_token: 0,
+            token_balance,
+        },
+    )
+    .await;
+    setup_system_account(context, owner, 0).await;
+
+    send_rewards_to_pool(context, &holder_rewards_pool, REWARDS).await;
+
+    (holder_rewards_pool, pool_token, holder_rewards, owner_token)
+}
+
+#[tokio::test]
+async fn initialize_holder_rewards() {
+    // The holder rewards address is still derived once on initialization, so
+    // only the pool's bump seed is varied.
+    let owner = find_owner(|bump_seed| bump_seed == u8::MAX);
+    assert_independent_of_bump_seed(|mint, _| {
+        let owner = owner.insecure_clone();
+        async move {
+            let mut context = setup().start_with_context().await;
+
+            let holder_rewards_pool =
+                get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+            let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+            setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0)
+                .await;
+            setup_token_account(&mut context, &pool_token, &holder_rewards_pool, &mint, 0).await;
+            setup_mint(&mut context, &mint, 0, None).await;
+
+            let duna_pda = sign_duna_document(&mut context, &owner.pubkey());
+            let instruction = InitializeHolderRewardsBuilder::new()
+                .holder_rewards_pool(holder_rewards_pool)
+                .holder_rewards_pool_token_account(pool_token)
+                .holder_rewards(get_holder_rewards_address(
+                    &owner.pubkey(),
+                    &paladin_rewards_program::id(),
+                ))
+                .owner(owner.pubkey())
+                .mint(mint)
+                .duna_document_pda(duna_pda)
+                .payer(context.payer.pubkey())
+                .instruction();
+
+            compute_units_consumed(&mut context, instruction, Some(&owner)).await
+        }
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn harvest_rewards() {
+    assert_independent_of_bump_seed(|mint, owner| async move {
+        let mut context = setup().start_with_context().await;
+        let (holder_rewards_pool, pool_token, holder_rewards, _) =
+            setup_pool_and_holder(&mut context, &mint, &owner.pubkey(), DEPOSIT_AMOUNT, 0).await;
+
+        let instruction = HarvestRewardsBuilder::new()
+            .holder_rewards_pool(holder_rewards_pool)
+            .holder_rewards_pool_token_account(pool_token)
+            .holder_rewards(holder_rewards)
+            .mint(mint)
+            .owner(owner.pubkey())
+            .instruction();
+
+        compute_units_consumed(&mut context, instruction, Some(&owner)).await
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn close_holder_rewards() {
+    assert_independent_of_bump_seed(|mint, owner| async move {
+        let mut context = setup().start_with_context().await;
+        let (holder_rewards_pool, pool_token, holder_rewards, _) =
+            setup_pool_and_holder(&mut context, &mint, &owner.pubkey(), 0, 0).await;
+
+        let instruction = CloseHolderRewardsBuilder::new()
+            .holder_rewards_pool(holder_rewards_pool)
+            .holder_rewards_pool_token_account(pool_token)
+            .holder_rewards(holder_rewards)
+            .mint(mint)
+            .owner(owner.pubkey())
+            .rent_payer(owner.pubkey())
+            .instruction();
+
+        compute_units_consumed(&mut context, instruction, Some(&owner)).await
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn deposit() {
+    assert_independent_of_bump_seed(|mint, owner| async move {
+        let mut context = setup().start_with_context().await;
+        let (holder_rewards_pool, pool_token, holder_rewards, owner_token) = setup_pool_and_holder(
+            &mut context,
+            &mint,
+            &owner.pubkey(),
+            DEPOSIT_AMOUNT,
+            INITIAL_OWNER_BALANCE,
+        )
+        .await;
+
+        let instruction = DepositBuilder::new()
+            .holder_rewards_pool(holder_rewards_pool)
+            .holder_rewards_pool_token_account(pool_token)
+            .holder_rewards(holder_rewards)
+            .token_account(owner_token)
+            .mint(mint)
+            .owner(owner.pubkey())
+            .amount(DEPOSIT_AMOUNT)
+            .instruction();
+
+        compute_units_consumed(&mut context, instruction, Some(&owner)).await
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn withdraw() {
+    assert_independent_of_bump_seed(|mint, owner| async move {
+        let mut context = setup().start_with_context().await;
+        let (holder_rewards_pool, pool_token, holder_rewards, owner_token) =
+            setup_pool_and_holder(&mut context, &mint, &owner.pubkey(), DEPOSIT_AMOUNT, 0).await;
+
+        let instruction = WithdrawBuilder::new()
+            .holder_rewards_pool(holder_rewards_pool)
+            .holder_rewards_pool_token_account(pool_token)
+            .holder_rewards(holder_rewards)
+            .token_account(owner_token)
+            .mint(mint)
+            .owner(owner.pubkey())
+            .amount(DEPOSIT_AMOUNT / 2)
+            .instruction();
+
+        compute_units_consumed(&mut context, instruction, Some(&owner)).await
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn recover_excess_tokens() {
+    assert_independent_of_bump_seed(|mint, authority| async move {
+        let mut context = setup().start_with_context().await;
+        let (holder_rewards_pool, pool_token, _, _) =
+            setup_pool_and_holder(&mut context, &mint, &authority.pubkey(), DEPOSIT_AMOUNT, 0)
+                .await;
+        update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+            pool_state.authority = authority.pubkey()
+        })
+        .await;
+
+        // Tokens transferred directly into the pool token account.
+        setup_token_account(
+            &mut context,
+            &pool_token,
+            &holder_rewards_pool,
+            &mint,
+            DEPOSIT_AMOUNT * 2,
+        )
+        .await;
+        let destination = Pubkey::new_unique();
+        setup_token_account(&mut context, &destination, &Pubkey::new_unique(), &mint, 0).await;
+
+        let instruction = RecoverExcessTokensBuilder::new()
+            .holder_rewards_pool(holder_rewards_pool)
+            .holder_rewards_pool_token_account(pool_token)
+            .destination_token_account(destination)
+            .mint(mint)
+            .authority(authority.pubkey())
+            .instruction();
+
+        compute_units_consumed(&mut context, instruction, Some(&authority)).await
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn get_pending_rewards() {
+    assert_independent_of_bump_seed(|mint, owner| async move {
+        let mut context = setup().start_with_context().await;
+        let (holder_rewards_pool, _, holder_rewards, _) =
+            setup_pool_and_holder(&mut context, &mint, &owner.pubkey(), DEPOSIT_AMOUNT, 0).await;
+
+        let instruction = GetPendingRewardsBuilder::new()
+            .holder_rewards_pool(holder_rewards_pool)
+            .holder_rewards(holder_rewards)
+            .mint(mint)
+            .owner(owner.pubkey())
+            .instruction();
+
+        compute_units_consumed(&mut context, instruction, None).await
+    })
+    .await;
+}
@@ -0,0 +1,263 @@
+#![cfg(feature = "test-sbf")]
+
+mod execute_utils;
+mod setup;
+
+use {
+    crate::{
+        execute_utils::{execute_with_payer, execute_with_payer_err},
+        setup::{
+            setup_campaign_account, setup_holder_rewards_pool_account, setup_system_account,
+            CampaignSetup,
+        },
+    },
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        state::{get_campaign_address, get_holder_rewards_pool_address, Campaign},
+    },
+    paladin_rewards_program_client::instructions::CreateCampaignBuilder,
+    setup::setup,
+    solana_program_test::*,
+    solana_sdk::{
+        clock::Clock,
+        instruction::{Instruction, InstructionError},
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::TransactionError,
+    },
+};
+
+const CAMPAIGN_AMOUNT: u64 = 500_000_000;
+
+fn create_campaign_instruction(
+    holder_rewards_pool: &Pubkey,
+    campaign: &Pubkey,
+    mint: &Pubkey,
+    funder: &Pubkey,
+    start_slot: u64,
+    end_slot: u64,
+) -> Instruction {
+    CreateCampaignBuilder::new()
+        .holder_rewards_pool(*holder_rewards_pool)
+        .campaign(*campaign)
+        .mint(*mint)
+        .funder(*funder)
+        .amount(CAMPAIGN_AMOUNT)
+        .start_slot(start_slot)
+        .end_slot(end_slot)
+        .instruction()
+}
+
+#[tokio::test]
+async fn fail_end_before_start() {
+    let mint = Pubkey::new_unique();
+    let funder = Keypair::new();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let campaign = get_campaign_address(
+        &holder_rewards_pool,
+        &funder.pubkey(),
+        &paladin_rewards_program::id(),
+    );
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+    setup_system_account(&mut context, &funder.pubkey(), CAMPAIGN_AMOUNT).await;
+
+    let instruction = create_campaign_instruction(
+        &holder_rewards_pool,
+        &campaign,
+        &mint,
+        &funder.pubkey(),
+        100,
+        100,
+    );
+    let err = execute_with_payer_err(&mut context, instruction, Some(&funder)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::InvalidCampaignSchedule as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_already_ended() {
+    let mint = Pubkey::new_unique();
+    let funder = Keypair::new();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let campaign = get_campaign_address(
+        &holder_rewards_pool,
+        &funder.pubkey(),
+        &paladin_rewards_program::id(),
+    );
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+    setup_system_account(&mut context, &funder.pubkey(), CAMPAIGN_AMOUNT).await;
+    context.warp_to_slot(100).unwrap();
+
+    let instruction = create_campaign_instruction(
+        &holder_rewards_pool,
+        &campaign,
+        &mint,
+        &funder.pubkey(),
+        0,
+        50,
+    );
+    let err = execute_with_payer_err(&mut context, instruction, Some(&funder)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::InvalidCampaignSchedule as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_campaign_incorrect_address() {
+    let mint = Pubkey::new_unique();
+    let funder = Keypair::new();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    // Derived from another funder.
+    let campaign = get_campaign_address(
+        &holder_rewards_pool,
+        &Pubkey::new_unique(),
+        &paladin_rewards_program::id(),
+    );
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+    setup_system_account(&mut context, &funder.pubkey(), CAMPAIGN_AMOUNT).await;
+
+    let instruction = create_campaign_instruction(
+        &holder_rewards_pool,
+        &campaign,
+        &mint,
+        &funder.pubkey(),
+        0,
+        100,
+    );
+    let err = execute_with_payer_err(&mut context, instruction, Some(&funder)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectCampaignAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_campaign_already_exists() {
+    let mint = Pubkey::new_unique();
+    let funder = Keypair::new();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let campaign = get_campaign_address(
+        &holder_rewards_pool,
+        &funder.pubkey(),
+        &paladin_rewards_program::id(),
+    );
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+    setup_system_account(&mut context, &funder.pubkey(), CAMPAIGN_AMOUNT).await;
+    setup_campaign_account(
+        &mut context,
+        &holder_rewards_pool,
+        &funder.pubkey(),
+        &campaign,
+        CampaignSetup {
+            amount: CAMPAIGN_AMOUNT,
+            start_slot: 0,
+            end_slot: 100,
+            unspent: 0,
+        },
+    )
+    .await;
+
+    let instruction = create_campaign_instruction(
+        &holder_rewards_pool,
+        &campaign,
+        &mint,
+        &funder.pubkey(),
+        0,
+        100,
+    );
+    let err = execute_with_payer_err(&mut context, instruction, Some(&funder)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::AccountAlreadyInitialized)
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let mint = Pubkey::new_unique();
+    let funder = Keypair::new();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let campaign = get_campaign_address(
+        &holder_rewards_pool,
+        &funder.pubkey(),
+        &paladin_rewards_program::id(),
+    );
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+    setup_system_account(&mut context, &funder.pubkey(), CAMPAIGN_AMOUNT * 2).await;
+
+    let instruction = create_campaign_instruction(
+        &holder_rewards_pool,
+        &campaign,
+        &mint,
+        &funder.pubkey(),
+        100,
+        1_000,
+    );
+    execute_with_payer(&mut context, instruction, Some(&funder)).await;
+
+    // Assert the campaign escrows the rewards on top of its rent.
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let campaign_account = context
+        .banks_client
+        .get_account(campaign)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(campaign_account.owner, paladin_rewards_program::id());
+    assert_eq!(
+        campaign_account.lamports,
+        rent.minimum_balance(Campaign::LEN) + CAMPAIGN_AMOUNT
+    );
+
+    let campaign_state = bytemuck::from_bytes::<Campaign>(&campaign_account.data);
+    assert_eq!(campaign_state.holder_rewards_pool, holder_rewards_pool);
+    assert_eq!(campaign_state.funder, funder.pubkey());
+    assert_eq!(campaign_state.amount, CAMPAIGN_AMOUNT);
+    assert_eq!(campaign_state.start_slot, 100);
+    assert_eq!(campaign_state.end_slot, 1_000);
+    assert_eq!(campaign_state.released, 0);
+    assert_eq!(campaign_state.unspent, 0);
+
+    // Assert the campaign counts slots without weight from its creation. The
+    // pool has had no weight since it was last updated at slot 0.
+    let slot = context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .slot;
+    assert_eq!(campaign_state.last_release_slot, slot);
+    assert_eq!(campaign_state.zero_weight_slots, slot);
+}
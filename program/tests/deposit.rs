@@ -5,30 +5,35 @@ mod setup;
 
 use {
     crate::{
-        execute_utils::{execute_with_payer, execute_with_payer_err},
+        execute_utils::{execute_with_payer, execute_with_payer_err, execute_with_payer_events},
         setup::{
-            send_rewards_to_pool, setup_holder_rewards_account_with_token_account,
-            setup_holder_rewards_pool_account_with_token_account, DEPOSIT_AMOUNT,
-            INITIAL_OWNER_BALANCE,
+            initialize_receipt_mint, send_rewards_to_pool, setup_access_entry_account,
+            setup_holder_rewards_account_with_token_account,
+            setup_holder_rewards_pool_account_with_token_account, setup_receipt_token_account,
+            setup_stake_checkpoints_account, update_holder_rewards_pool, HolderRewardsSetup,
+            DEPOSIT_AMOUNT, INITIAL_OWNER_BALANCE,
         },
     },
     paladin_rewards_program::{
         error::PaladinRewardsError,
         processor::REWARDS_PER_TOKEN_SCALING_FACTOR,
         state::{
-            get_holder_rewards_address, get_holder_rewards_pool_address, HolderRewards,
-            HolderRewardsPool,
+            get_access_entry_address, get_holder_rewards_address, get_holder_rewards_pool_address,
+            get_stake_checkpoints_address, HolderRewards, HolderRewardsPool, StakeCheckpoint,
+            StakeCheckpoints, ACCESS_LIST_ALLOWLIST, ACCESS_LIST_DENYLIST, ACCESS_STATUS_ALLOWED,
+            ACCESS_STATUS_DENIED,
         },
     },
-    paladin_rewards_program_client::instructions::DepositBuilder,
+    paladin_rewards_program_client::{events::PaladinRewardsEvent, instructions::DepositBuilder},
     setup::setup,
     solana_program_test::*,
     solana_sdk::{
-        instruction::InstructionError, program_pack::Pack, pubkey::Pubkey, signature::Keypair,
-        signer::Signer, transaction::TransactionError,
+        clock::Clock, instruction::InstructionError, program_pack::Pack, pubkey::Pubkey,
+        signature::Keypair, signer::Signer, transaction::TransactionError,
     },
     spl_associated_token_account::get_associated_token_address,
     spl_token::state::Account as TokenAccount,
+    spl_token_2022::{extension::StateWithExtensions, state::Account as ReceiptTokenAccount},
 };
 
 #[tokio::test]
@@ -64,9 +69,11 @@ async fn fail_pool_doesnt_have_enough_rewards() {
         &owner.pubkey(),
         &holder_rewards,
         &owner_token,
-        100_000_000_000,       // total deposited for holder
-        10_000,                // last rewards per token
-        INITIAL_OWNER_BALANCE, // token balance
+        HolderRewardsSetup {
+            deposited: 100_000_000_000,
+            last_accumulated_rewards_per_token: 10_000,
+            token_balance: INITIAL_OWNER_BALANCE,
+        },
     )
     .await;
 
@@ -123,12 +130,88 @@ async fn fail_not_enough_tokens_to_deposit() {
         &owner.pubkey(),
         &holder_rewards,
         &owner_token,
+        HolderRewardsSetup {
+            deposited: 0,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE - 1,
+        },
+    )
+    .await;
+
+    let instruction = DepositBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .amount(INITIAL_OWNER_BALANCE)
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::NotEnoughTokenToDeposit as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_token_account_invalid_token_program() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
         0,
         0,
-        INITIAL_OWNER_BALANCE - 1,
     )
     .await;
 
+    // Setup token account for the owner.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: 0,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE,
+        },
+    )
+    .await;
+
+    // Reassign the owner token account to a program other than SPL Token,
+    // which reading its balance rejects before the account is validated.
+    {
+        let mut account = context
+            .banks_client
+            .get_account(owner_token)
+            .await
+            .unwrap()
+            .unwrap();
+        account.owner = Pubkey::new_unique();
+        context.set_account(&owner_token, &account.into());
+    }
+
     let instruction = DepositBuilder::new()
         .holder_rewards_pool(holder_rewards_pool)
         .holder_rewards_pool_token_account(pool_token)
@@ -144,13 +227,138 @@ async fn fail_not_enough_tokens_to_deposit() {
         err,
         TransactionError::InstructionError(
             0,
-            InstructionError::Custom(PaladinRewardsError::NotEnoughTokenToDeposit as u32)
+            InstructionError::Custom(PaladinRewardsError::InvalidTokenProgram as u32)
         )
     );
 }
 
 #[tokio::test]
-async fn success() {
+async fn fail_incorrect_token_program() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        0,
+    )
+    .await;
+
+    // Setup token account for the owner.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: 0,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE,
+        },
+    )
+    .await;
+
+    // Swap in a fake token program.
+    let instruction = DepositBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .token_program(Pubkey::new_unique())
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectTokenProgram as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_owner_not_signer() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        0,
+    )
+    .await;
+
+    // Setup token account for the owner.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: 0,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE,
+        },
+    )
+    .await;
+
+    let mut instruction = DepositBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    instruction.accounts[5].is_signer = false;
+    let err = execute_with_payer_err(&mut context, instruction, None).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::OwnerNotSigner as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_holder_rewards_pool_not_writable() {
     let owner = Keypair::new();
     let mint = Pubkey::new_unique();
 
@@ -182,9 +390,73 @@ async fn success() {
         &owner.pubkey(),
         &holder_rewards,
         &owner_token,
+        HolderRewardsSetup {
+            deposited: 0,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE,
+        },
+    )
+    .await;
+
+    let mut instruction = DepositBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    instruction.accounts[0].is_writable = false;
+    let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::AccountNotWritable as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
         0,
         0,
-        INITIAL_OWNER_BALANCE,
+        0,
+    )
+    .await;
+
+    // Setup token account for the owner.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: 0,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE,
+        },
     )
     .await;
 
@@ -232,9 +504,20 @@ async fn success() {
         .await
         .unwrap()
         .unwrap();
-    let holder_deposited =
-        bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data).deposited;
-    assert_eq!(holder_deposited, DEPOSIT_AMOUNT);
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    assert_eq!(holder_rewards_state.deposited, DEPOSIT_AMOUNT);
+
+    // Assert the holder's first deposit was recorded.
+    let first_deposit_slot = context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .slot;
+    assert_eq!(holder_rewards_state.first_deposit_slot, first_deposit_slot);
+    assert_eq!(holder_rewards_state.last_activity_slot, first_deposit_slot);
+    assert_eq!(holder_rewards_state.last_deposit_slot, first_deposit_slot);
+    assert_eq!(holder_rewards_state.cumulative_deposited, DEPOSIT_AMOUNT);
 
     // Confirm that rewards are being sent on 2nd deposit.
     let rewards_amount = 1_000_000_000;
@@ -305,6 +588,11 @@ async fn success() {
         .unwrap()
         .unwrap();
     let pool_state = bytemuck::from_bytes::<HolderRewardsPool>(&holder_rewards_pool_account.data);
+    assert_eq!(
+        pool_state.total_deposited,
+        DEPOSIT_AMOUNT + DEPOSIT_AMOUNT / 2
+    );
+    assert_eq!(pool_state.total_rewards_received, rewards_amount);
     let holder_reward_account = context
         .banks_client
         .get_account(holder_rewards)
@@ -316,6 +604,12 @@ async fn success() {
         holder_rewards_state.last_accumulated_rewards_per_token,
         pool_state.accumulated_rewards_per_token
     );
+    assert_eq!(holder_rewards_state.first_deposit_slot, first_deposit_slot);
+    assert_eq!(
+        holder_rewards_state.cumulative_deposited,
+        DEPOSIT_AMOUNT + DEPOSIT_AMOUNT / 2
+    );
+    assert_eq!(holder_rewards_state.total_rewards_harvested, rewards_amount);
 
     // Assert pool sent all rewards to holder (single holder)
     let current_pool_lamports = context
@@ -337,3 +631,1020 @@ async fn success() {
         .lamports;
     assert_eq!(current_owner_lamports, rewards_amount);
 }
+
+#[tokio::test]
+async fn fail_stake_checkpoints_required() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        0,
+    )
+    .await;
+
+    // Setup token account for the owner.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: 0,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE,
+        },
+    )
+    .await;
+
+    // The holder records stake checkpoints.
+    let stake_checkpoints =
+        get_stake_checkpoints_address(&owner.pubkey(), &paladin_rewards_program::id());
+    setup_stake_checkpoints_account(
+        &mut context,
+        &owner.pubkey(),
+        &holder_rewards,
+        &stake_checkpoints,
+        &[(0, 0)],
+    )
+    .await;
+
+    // Stake checkpoints omitted.
+    let instruction = DepositBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::StakeCheckpointsRequired as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_emits_event() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        0,
+    )
+    .await;
+
+    // Setup token account for the owner.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: 0,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE,
+        },
+    )
+    .await;
+
+    let instruction = DepositBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    let events = execute_with_payer_events(&mut context, instruction, Some(&owner)).await;
+
+    // Assert the deposit was logged, with no rewards to accrue or harvest.
+    assert_eq!(
+        events,
+        vec![PaladinRewardsEvent::Deposited {
+            holder_rewards_pool,
+            owner: owner.pubkey(),
+            amount: DEPOSIT_AMOUNT,
+            deposited: DEPOSIT_AMOUNT,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn success_records_stake_checkpoint() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        0,
+    )
+    .await;
+
+    // Setup token account for the owner.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: 0,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE,
+        },
+    )
+    .await;
+
+    // The holder records stake checkpoints.
+    let stake_checkpoints =
+        get_stake_checkpoints_address(&owner.pubkey(), &paladin_rewards_program::id());
+    setup_stake_checkpoints_account(
+        &mut context,
+        &owner.pubkey(),
+        &holder_rewards,
+        &stake_checkpoints,
+        &[(0, 0)],
+    )
+    .await;
+
+    let instruction = DepositBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .stake_checkpoints(Some(stake_checkpoints))
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&owner)).await;
+
+    // Assert the new balance was checkpointed at the current slot.
+    let slot = context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .slot;
+    let stake_checkpoints_account = context
+        .banks_client
+        .get_account(stake_checkpoints)
+        .await
+        .unwrap()
+        .unwrap();
+    let stake_checkpoints_state =
+        bytemuck::from_bytes::<StakeCheckpoints>(&stake_checkpoints_account.data);
+    assert_eq!(
+        stake_checkpoints_state.latest(),
+        Some(&StakeCheckpoint {
+            slot,
+            deposited: DEPOSIT_AMOUNT,
+        })
+    );
+}
+
+#[tokio::test]
+async fn fail_deposit_below_minimum() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.min_deposit = DEPOSIT_AMOUNT
+    })
+    .await;
+
+    // Setup token account for the owner.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        },
+    )
+    .await;
+
+    let instruction = DepositBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .amount(DEPOSIT_AMOUNT - 1)
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::DepositBelowMinimum as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_holder_deposit_cap_exceeded() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.max_holder_deposit = DEPOSIT_AMOUNT * 2 - 1
+    })
+    .await;
+
+    // Setup token account for the owner.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        },
+    )
+    .await;
+
+    let instruction = DepositBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::HolderDepositCapExceeded as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_pool_deposit_cap_exceeded() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.max_total_deposit = DEPOSIT_AMOUNT * 2 - 1
+    })
+    .await;
+
+    // Setup token account for the owner.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        },
+    )
+    .await;
+
+    let instruction = DepositBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::PoolDepositCapExceeded as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_within_deposit_limits() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.max_total_deposit = DEPOSIT_AMOUNT * 2;
+        pool_state.max_holder_deposit = DEPOSIT_AMOUNT * 2;
+        pool_state.min_deposit = DEPOSIT_AMOUNT;
+    })
+    .await;
+
+    // Setup token account for the owner.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        },
+    )
+    .await;
+
+    let instruction = DepositBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&owner)).await;
+
+    // Assert the deposit reached both caps.
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    assert_eq!(holder_rewards_state.deposited, DEPOSIT_AMOUNT * 2);
+
+    let pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state = bytemuck::from_bytes::<HolderRewardsPool>(&pool_account.data);
+    assert_eq!(pool_state.total_deposited, DEPOSIT_AMOUNT * 2);
+}
+
+#[tokio::test]
+async fn fail_access_entry_required() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.access_list = ACCESS_LIST_ALLOWLIST
+    })
+    .await;
+
+    // Setup token account for the owner.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        },
+    )
+    .await;
+
+    let instruction = DepositBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .access_entry(None)
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::AccessEntryRequired as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_access_denied() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.access_list = ACCESS_LIST_DENYLIST
+    })
+    .await;
+
+    // Setup the owner's access entry.
+    let access_entry = get_access_entry_address(
+        &holder_rewards_pool,
+        &owner.pubkey(),
+        &paladin_rewards_program::id(),
+    );
+    setup_access_entry_account(
+        &mut context,
+        &holder_rewards_pool,
+        &owner.pubkey(),
+        &access_entry,
+        ACCESS_STATUS_DENIED,
+    )
+    .await;
+
+    // Setup token account for the owner.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        },
+    )
+    .await;
+
+    let instruction = DepositBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .access_entry(Some(access_entry))
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::AccessDenied as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_unlisted_on_allowlist() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.access_list = ACCESS_LIST_ALLOWLIST
+    })
+    .await;
+
+    // The owner has no access entry, so its empty address is passed.
+    let access_entry = get_access_entry_address(
+        &holder_rewards_pool,
+        &owner.pubkey(),
+        &paladin_rewards_program::id(),
+    );
+
+    // Setup token account for the owner.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        },
+    )
+    .await;
+
+    let instruction = DepositBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .access_entry(Some(access_entry))
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::AccessDenied as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_allowlisted() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.access_list = ACCESS_LIST_ALLOWLIST
+    })
+    .await;
+
+    // Setup the owner's access entry.
+    let access_entry = get_access_entry_address(
+        &holder_rewards_pool,
+        &owner.pubkey(),
+        &paladin_rewards_program::id(),
+    );
+    setup_access_entry_account(
+        &mut context,
+        &holder_rewards_pool,
+        &owner.pubkey(),
+        &access_entry,
+        ACCESS_STATUS_ALLOWED,
+    )
+    .await;
+
+    // Setup token account for the owner.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        },
+    )
+    .await;
+
+    let instruction = DepositBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .access_entry(Some(access_entry))
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&owner)).await;
+
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    assert_eq!(holder_rewards_state.deposited, DEPOSIT_AMOUNT * 2);
+}
+
+#[tokio::test]
+async fn success_unlisted_on_denylist() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.access_list = ACCESS_LIST_DENYLIST
+    })
+    .await;
+
+    // The owner has no access entry, so its empty address is passed.
+    let access_entry = get_access_entry_address(
+        &holder_rewards_pool,
+        &owner.pubkey(),
+        &paladin_rewards_program::id(),
+    );
+
+    // Setup token account for the owner.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        },
+    )
+    .await;
+
+    let instruction = DepositBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .access_entry(Some(access_entry))
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&owner)).await;
+
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    assert_eq!(holder_rewards_state.deposited, DEPOSIT_AMOUNT * 2);
+}
+
+#[tokio::test]
+async fn fail_receipt_accounts_required() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        0,
+    )
+    .await;
+    initialize_receipt_mint(&mut context, &mint, &holder_rewards_pool).await;
+
+    // Setup token account for the owner.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: 0,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE,
+        },
+    )
+    .await;
+
+    let instruction = DepositBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::ReceiptAccountsRequired as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_mints_receipts() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        0,
+    )
+    .await;
+    let receipt_mint = initialize_receipt_mint(&mut context, &mint, &holder_rewards_pool).await;
+
+    // Setup token accounts for the owner.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: 0,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE,
+        },
+    )
+    .await;
+    let receipt_token =
+        setup_receipt_token_account(&mut context, &receipt_mint, &owner.pubkey()).await;
+
+    let instruction = DepositBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .receipt_mint(Some(receipt_mint))
+        .receipt_token_account(Some(receipt_token))
+        .receipt_token_program(Some(spl_token_2022::id()))
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&owner)).await;
+
+    // Assert the owner received one receipt token per deposited token.
+    let receipt_token_account = context
+        .banks_client
+        .get_account(receipt_token)
+        .await
+        .unwrap()
+        .unwrap();
+    let receipt_token_state =
+        StateWithExtensions::<ReceiptTokenAccount>::unpack(&receipt_token_account.data).unwrap();
+    assert_eq!(receipt_token_state.base.amount, DEPOSIT_AMOUNT);
+
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    assert_eq!(holder_rewards_state.deposited, DEPOSIT_AMOUNT);
+}
@@ -0,0 +1,282 @@
+#![cfg(feature = "test-sbf")]
+
+mod execute_utils;
+mod setup;
+
+use {
+    crate::{
+        execute_utils::{execute_with_payer, execute_with_payer_err},
+        setup::{
+            send_rewards_to_pool, setup_epoch_ledger_account, setup_holder_rewards_pool_account,
+            setup_system_account,
+        },
+    },
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        processor::REWARDS_PER_TOKEN_SCALING_FACTOR,
+        state::{
+            get_epoch_ledger_address, get_holder_rewards_pool_address, EpochLedger,
+            EpochLedgerEntry, HolderRewardsPool,
+        },
+    },
+    paladin_rewards_program_client::instructions::DistributeRewardsBuilder,
+    setup::setup,
+    solana_program_test::*,
+    solana_sdk::{
+        clock::Clock, instruction::InstructionError, pubkey::Pubkey, signature::Keypair,
+        signer::Signer, transaction::TransactionError,
+    },
+};
+
+const DISTRIBUTION_AMOUNT: u64 = 500_000_000;
+const TOTAL_DEPOSITED: u64 = 250_000_000;
+
+#[tokio::test]
+async fn fail_holder_rewards_pool_incorrect_address() {
+    let mint = Pubkey::new_unique();
+    let funder = Keypair::new();
+    // Derived from another mint.
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&Pubkey::new_unique(), &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        0,
+        0,
+        TOTAL_DEPOSITED,
+    )
+    .await;
+    setup_system_account(&mut context, &funder.pubkey(), DISTRIBUTION_AMOUNT).await;
+
+    let instruction = DistributeRewardsBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .mint(mint)
+        .funder(funder.pubkey())
+        .amount(DISTRIBUTION_AMOUNT)
+        .memo([0; 32])
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&funder)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectHolderRewardsPoolAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_epoch_ledger_incorrect_address() {
+    let mint = Pubkey::new_unique();
+    let funder = Keypair::new();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        0,
+        0,
+        TOTAL_DEPOSITED,
+    )
+    .await;
+    setup_system_account(&mut context, &funder.pubkey(), DISTRIBUTION_AMOUNT).await;
+
+    // Epoch ledger for another pool.
+    let other_holder_rewards_pool = Pubkey::new_unique();
+    let epoch_ledger =
+        get_epoch_ledger_address(&other_holder_rewards_pool, &paladin_rewards_program::id());
+    setup_epoch_ledger_account(&mut context, &other_holder_rewards_pool, &epoch_ledger).await;
+
+    let instruction = DistributeRewardsBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .mint(mint)
+        .funder(funder.pubkey())
+        .epoch_ledger(Some(epoch_ledger))
+        .amount(DISTRIBUTION_AMOUNT)
+        .memo([0; 32])
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&funder)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectEpochLedgerAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_pool_has_no_weight() {
+    let mint = Pubkey::new_unique();
+    let funder = Keypair::new();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+    setup_system_account(&mut context, &funder.pubkey(), DISTRIBUTION_AMOUNT).await;
+
+    let instruction = DistributeRewardsBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .mint(mint)
+        .funder(funder.pubkey())
+        .amount(DISTRIBUTION_AMOUNT)
+        .memo([0; 32])
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&funder)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::PoolHasNoWeight as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let mint = Pubkey::new_unique();
+    let funder = Keypair::new();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        0,
+        0,
+        TOTAL_DEPOSITED,
+    )
+    .await;
+    setup_system_account(&mut context, &funder.pubkey(), DISTRIBUTION_AMOUNT).await;
+
+    // Anonymous rewards received before the distribution.
+    let anonymous_amount = DISTRIBUTION_AMOUNT / 5;
+    send_rewards_to_pool(&mut context, &holder_rewards_pool, anonymous_amount).await;
+
+    let pool_lamports_before = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let instruction = DistributeRewardsBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .mint(mint)
+        .funder(funder.pubkey())
+        .amount(DISTRIBUTION_AMOUNT)
+        .memo([7; 32])
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&funder)).await;
+
+    // Assert the distribution moved from the funder to the pool.
+    let funder_account = context
+        .banks_client
+        .get_account(funder.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    assert_eq!(funder_account.lamports, rent.minimum_balance(0));
+
+    let pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        pool_account.lamports,
+        pool_lamports_before + DISTRIBUTION_AMOUNT
+    );
+
+    // Assert both the anonymous rewards and the distribution were accrued.
+    let pool_state = bytemuck::from_bytes::<HolderRewardsPool>(&pool_account.data);
+    assert_eq!(pool_state.lamports_last, pool_account.lamports);
+    assert_eq!(
+        pool_state.total_rewards_received,
+        anonymous_amount + DISTRIBUTION_AMOUNT
+    );
+    assert_eq!(
+        pool_state.accumulated_rewards_per_token,
+        anonymous_amount as u128 * REWARDS_PER_TOKEN_SCALING_FACTOR / TOTAL_DEPOSITED as u128
+            + DISTRIBUTION_AMOUNT as u128 * REWARDS_PER_TOKEN_SCALING_FACTOR
+                / TOTAL_DEPOSITED as u128
+    );
+}
+
+#[tokio::test]
+async fn success_records_epoch_ledger() {
+    let mint = Pubkey::new_unique();
+    let funder = Keypair::new();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let epoch_ledger =
+        get_epoch_ledger_address(&holder_rewards_pool, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        0,
+        0,
+        TOTAL_DEPOSITED,
+    )
+    .await;
+    setup_system_account(&mut context, &funder.pubkey(), DISTRIBUTION_AMOUNT).await;
+    setup_epoch_ledger_account(&mut context, &holder_rewards_pool, &epoch_ledger).await;
+
+    let instruction = DistributeRewardsBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .mint(mint)
+        .funder(funder.pubkey())
+        .epoch_ledger(Some(epoch_ledger))
+        .amount(DISTRIBUTION_AMOUNT)
+        .memo([0; 32])
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&funder)).await;
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    // Assert the distribution was recorded in the current epoch.
+    let epoch_ledger_account = context
+        .banks_client
+        .get_account(epoch_ledger)
+        .await
+        .unwrap()
+        .unwrap();
+    let epoch_ledger_state = bytemuck::from_bytes::<EpochLedger>(&epoch_ledger_account.data);
+    let rate_delta =
+        DISTRIBUTION_AMOUNT as u128 * REWARDS_PER_TOKEN_SCALING_FACTOR / TOTAL_DEPOSITED as u128;
+    assert_eq!(epoch_ledger_state.len, 1);
+    assert_eq!(
+        epoch_ledger_state.latest(),
+        Some(&EpochLedgerEntry {
+            rate_delta,
+            epoch: clock.epoch,
+            lamports_received: DISTRIBUTION_AMOUNT,
+        })
+    );
+    assert_eq!(
+        epoch_ledger_state.last_accumulated_rewards_per_token,
+        rate_delta
+    );
+    assert_eq!(
+        epoch_ledger_state.last_total_rewards_received,
+        DISTRIBUTION_AMOUNT
+    );
+}
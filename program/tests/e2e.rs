@@ -11,15 +11,16 @@ use {
         setup::{
             send_rewards_to_pool, setup_holder_rewards_account,
             setup_holder_rewards_account_with_token_account,
-            setup_holder_rewards_pool_account_with_token_account, setup_owner,
+            setup_holder_rewards_pool_account_with_token_account, setup_owner, HolderRewardsSetup,
             INITIAL_OWNER_BALANCE,
         },
     },
     paladin_rewards_program::{
         processor::REWARDS_PER_TOKEN_SCALING_FACTOR,
         state::{
-            get_holder_rewards_address, get_holder_rewards_pool_address, HolderRewards,
-            HolderRewardsPool,
+            get_holder_rewards_address, get_holder_rewards_address_and_bump_seed,
+            get_holder_rewards_pool_address, get_holder_rewards_pool_address_and_bump_seed,
+            HolderRewards, HolderRewardsPool,
         },
     },
     paladin_rewards_program_client::instructions::{
@@ -91,7 +92,8 @@ async fn validate_state(
 ) {
     // First evaluate the pool.
     {
-        let pool_address = get_holder_rewards_pool_address(mint, &paladin_rewards_program::id());
+        let (pool_address, pool_bump_seed) =
+            get_holder_rewards_pool_address_and_bump_seed(mint, &paladin_rewards_program::id());
         let pool_account = get_account(context, &pool_address).await;
         let pool_token = get_associated_token_address(&pool_address, mint);
         let pool_token_account = get_account(context, &pool_token).await;
@@ -109,7 +111,10 @@ async fn validate_state(
                 accumulated_rewards_per_token: pool.accumulated_rewards_per_token,
                 lamports_last: pool.lamports_last + pool_rent_exempt_lamports,
                 duna_document_hash: [1; 32],
-                _padding: 0,
+                total_deposited: pool.total_deposited,
+                authority: Pubkey::default(),
+                bump_seed: pool_bump_seed,
+                ..*pool_state
             }
         );
     }
@@ -120,8 +125,8 @@ async fn validate_state(
     for (owner, checks) in holder_rewards {
         let owner_lamports = get_account(context, owner).await.lamports;
 
-        let holder_rewards_address =
-            get_holder_rewards_address(owner, &paladin_rewards_program::id());
+        let (holder_rewards_address, holder_rewards_bump_seed) =
+            get_holder_rewards_address_and_bump_seed(owner, &paladin_rewards_program::id());
         let holder_rewards_account = get_account(context, &holder_rewards_address).await;
         let holder_rewards_state =
             bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
@@ -130,7 +135,11 @@ async fn validate_state(
             &HolderRewards {
                 last_accumulated_rewards_per_token: checks.last_accumulated_rewards_per_token,
                 deposited: checks.deposited,
-                _padding: 0,
+                rent_payer: **owner,
+                bump_seed: holder_rewards_bump_seed,
+                stake_checkpoints: 0,
+                _padding: [0; 6],
+                ..*holder_rewards_state
             }
         );
 
@@ -182,9 +191,11 @@ async fn test_e2e() {
         &alice.pubkey(),
         &alice_holder_rewards,
         &alice_token,
-        0,
-        0,
-        INITIAL_OWNER_BALANCE, // token balance
+        HolderRewardsSetup {
+            deposited: 0,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE,
+        },
     )
     .await;
 
@@ -201,9 +212,11 @@ async fn test_e2e() {
         &bob.pubkey(),
         &bob_holder_rewards,
         &bob_token,
-        0,
-        0,
-        INITIAL_OWNER_BALANCE, // token balance
+        HolderRewardsSetup {
+            deposited: 0,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE,
+        },
     )
     .await;
 
@@ -220,9 +233,11 @@ async fn test_e2e() {
         &carol.pubkey(),
         &carol_holder_rewards,
         &carol_token,
-        0,
-        0,
-        INITIAL_OWNER_BALANCE, // token balance
+        HolderRewardsSetup {
+            deposited: 0,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE,
+        },
     )
     .await;
 
@@ -239,9 +254,11 @@ async fn test_e2e() {
         &dave.pubkey(),
         &dave_holder_rewards,
         &dave_token,
-        0,
-        0,
-        INITIAL_OWNER_BALANCE, // token balance
+        HolderRewardsSetup {
+            deposited: 0,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE,
+        },
     )
     .await;
 
@@ -668,6 +685,7 @@ async fn test_e2e() {
         .holder_rewards(bob_holder_rewards)
         .mint(mint)
         .owner(bob.pubkey())
+        .rent_payer(bob.pubkey())
         .instruction();
     execute_with_payer(&mut context, instruction, Some(&bob)).await;
 
@@ -785,6 +803,7 @@ async fn test_e2e() {
         .holder_rewards(alice_holder_rewards)
         .mint(mint)
         .owner(alice.pubkey())
+        .rent_payer(alice.pubkey())
         .instruction();
     execute_with_payer(&mut context, instruction, Some(&alice)).await;
 
@@ -807,11 +826,20 @@ async fn test_e2e() {
         .holder_rewards(carol_holder_rewards)
         .mint(mint)
         .owner(carol.pubkey())
+        .rent_payer(carol.pubkey())
         .instruction();
     execute_with_payer(&mut context, instruction, Some(&carol)).await;
 
     // Bob creates new holder account
-    setup_holder_rewards_account(&mut context, &bob_holder_rewards, 0, 0).await;
+    setup_holder_rewards_account(
+        &mut context,
+        &bob.pubkey(),
+        &bob_holder_rewards,
+        0,
+        0,
+        &bob.pubkey(),
+    )
+    .await;
 
     // Bob deposits
     let instruction = DepositBuilder::new()
@@ -922,6 +950,7 @@ async fn test_e2e() {
         .holder_rewards(bob_holder_rewards)
         .mint(mint)
         .owner(bob.pubkey())
+        .rent_payer(bob.pubkey())
         .instruction();
     execute_with_payer(&mut context, instruction, Some(&bob)).await;
 
@@ -0,0 +1,339 @@
+#![cfg(feature = "test-sbf")]
+
+mod execute_utils;
+mod setup;
+
+use {
+    crate::{
+        execute_utils::{execute_with_payer, execute_with_payer_err},
+        setup::{
+            send_rewards_to_pool, set_holder_rewards_lockup,
+            setup_holder_rewards_account_with_token_account,
+            setup_holder_rewards_pool_account_with_token_account, update_holder_rewards_pool,
+            HolderRewardsSetup, DEPOSIT_AMOUNT, INITIAL_OWNER_BALANCE,
+        },
+    },
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        processor::REWARDS_PER_TOKEN_SCALING_FACTOR,
+        state::{get_holder_rewards_address, get_holder_rewards_pool_address, HolderRewards},
+    },
+    paladin_rewards_program_client::instructions::{
+        EmergencyWithdrawBuilder, HarvestRewardsBuilder,
+    },
+    setup::setup,
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::InstructionError, program_pack::Pack, pubkey::Pubkey, signature::Keypair,
+        signer::Signer, transaction::TransactionError,
+    },
+    spl_associated_token_account::get_associated_token_address,
+    spl_token::state::Account as TokenAccount,
+};
+
+#[tokio::test]
+async fn fail_withdraw_exceeds_unlocked() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+
+    // Setup a holder with half of their deposit locked.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        },
+    )
+    .await;
+    set_holder_rewards_lockup(
+        &mut context,
+        &holder_rewards_pool,
+        &holder_rewards,
+        DEPOSIT_AMOUNT / 2,
+        i64::MAX,
+        20_000,
+    )
+    .await;
+
+    // Withdraw more than the unlocked half.
+    let instruction = EmergencyWithdrawBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .amount(DEPOSIT_AMOUNT / 2 + 1)
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::WithdrawExceedsUnlocked as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_records_unpaid_rewards() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool, owing one lamport per token but holding no excess lamports.
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        REWARDS_PER_TOKEN_SCALING_FACTOR,
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+
+    // Setup token account for the owner.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        },
+    )
+    .await;
+
+    let instruction = EmergencyWithdrawBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .amount(u64::MAX)
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&owner)).await;
+
+    // Assert the owner got their tokens back.
+    let owner_token_account = context
+        .banks_client
+        .get_account(owner_token)
+        .await
+        .unwrap()
+        .unwrap();
+    let owner_token_state = TokenAccount::unpack(&owner_token_account.data).unwrap();
+    assert_eq!(owner_token_state.amount, INITIAL_OWNER_BALANCE);
+
+    // Assert the unpaid rewards are owed to the owner.
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    assert_eq!(holder_rewards_state.deposited, 0);
+    assert_eq!(holder_rewards_state.unharvested_rewards, DEPOSIT_AMOUNT);
+    assert_eq!(
+        holder_rewards_state.last_accumulated_rewards_per_token,
+        REWARDS_PER_TOKEN_SCALING_FACTOR
+    );
+    assert_eq!(holder_rewards_state.total_rewards_harvested, 0);
+}
+
+#[tokio::test]
+async fn success_early_withdrawal_penalty() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool, with another holder depositing as much as the owner.
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        REWARDS_PER_TOKEN_SCALING_FACTOR,
+        DEPOSIT_AMOUNT * 2,
+    )
+    .await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.early_withdrawal_penalty_bps = 5_000;
+        pool_state.early_withdrawal_period_slots = 100;
+    })
+    .await;
+
+    // Setup token account for the owner, who last deposited at slot 0.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        },
+    )
+    .await;
+
+    let instruction = EmergencyWithdrawBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&owner)).await;
+
+    // Assert half of the unpaid rewards were forfeited.
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    assert_eq!(holder_rewards_state.unharvested_rewards, DEPOSIT_AMOUNT / 2);
+    assert_eq!(
+        holder_rewards_state.early_withdrawal_penalties,
+        DEPOSIT_AMOUNT / 2
+    );
+}
+
+#[tokio::test]
+async fn success_unpaid_rewards_harvested_after_top_up() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool, owing one lamport per token but holding no excess lamports.
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        REWARDS_PER_TOKEN_SCALING_FACTOR,
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+
+    // Setup token account for the owner.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        },
+    )
+    .await;
+
+    let instruction = EmergencyWithdrawBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .amount(u64::MAX)
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&owner)).await;
+
+    // Top the pool up, then harvest the unpaid rewards.
+    send_rewards_to_pool(&mut context, &holder_rewards_pool, DEPOSIT_AMOUNT).await;
+
+    let instruction = HarvestRewardsBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&owner)).await;
+
+    // Assert the owner was paid.
+    let owner_lamports = context
+        .banks_client
+        .get_account(owner.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(owner_lamports, DEPOSIT_AMOUNT);
+
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    assert_eq!(holder_rewards_state.unharvested_rewards, 0);
+    assert_eq!(holder_rewards_state.total_rewards_harvested, DEPOSIT_AMOUNT);
+}
@@ -1,4 +1,8 @@
+#![allow(dead_code)]
+
 use {
+    base64::{prelude::BASE64_STANDARD, Engine},
+    paladin_rewards_program_client::events::PaladinRewardsEvent,
     solana_program_test::ProgramTestContext,
     solana_sdk::{
         instruction::Instruction,
@@ -52,3 +56,28 @@ pub async fn execute_with_payer_err(
         .unwrap_err()
         .unwrap()
 }
+
+// Executes the instruction, returning the events it logged.
+pub async fn execute_with_payer_events(
+    context: &mut ProgramTestContext,
+    instruction: Instruction,
+    signer: Option<&Keypair>,
+) -> Vec<PaladinRewardsEvent> {
+    let transaction = get_transaction(context, instruction, signer);
+
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(transaction)
+        .await
+        .unwrap();
+    result.result.unwrap();
+
+    result
+        .metadata
+        .unwrap()
+        .log_messages
+        .iter()
+        .filter_map(|log| log.strip_prefix("Program data: "))
+        .map(|data| PaladinRewardsEvent::decode(&BASE64_STANDARD.decode(data).unwrap()).unwrap())
+        .collect()
+}
@@ -0,0 +1,324 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    crate::setup::{
+        send_rewards_to_pool, setup_holder_rewards_account_with_token_account,
+        setup_holder_rewards_pool_account, setup_holder_rewards_pool_account_with_token_account,
+        setup_token_account, HolderRewardsSetup, DEPOSIT_AMOUNT,
+    },
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        processor::REWARDS_PER_TOKEN_SCALING_FACTOR,
+        state::{
+            get_holder_rewards_address, get_holder_rewards_pool_address, HolderRewards,
+            HolderRewardsPool,
+        },
+    },
+    paladin_rewards_program_client::{
+        instructions::GetPendingRewardsBuilder,
+        math::{project_pending_rewards, HolderRewardsState, PoolRewardsState},
+    },
+    setup::setup,
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::{Instruction, InstructionError},
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    spl_associated_token_account::get_associated_token_address,
+};
+
+async fn simulate_pending_rewards(
+    context: &mut ProgramTestContext,
+    instruction: Instruction,
+) -> Result<u64, TransactionError> {
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let simulation = context
+        .banks_client
+        .simulate_transaction(transaction)
+        .await
+        .unwrap();
+    simulation.result.unwrap()?;
+
+    let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+    assert_eq!(return_data.program_id, paladin_rewards_program::id());
+    Ok(u64::from_le_bytes(return_data.data.try_into().unwrap()))
+}
+
+#[tokio::test]
+async fn fail_holder_rewards_incorrect_address() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+
+    // Holder rewards account derived from a different owner.
+    let holder_rewards =
+        get_holder_rewards_address(&Pubkey::new_unique(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: 0,
+        },
+    )
+    .await;
+
+    let instruction = GetPendingRewardsBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards(holder_rewards)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .instruction();
+    let err = simulate_pending_rewards(&mut context, instruction)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectHolderRewardsAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_without_pool_inflow() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let rewards = 1_000_000;
+
+    let mut context = setup().start_with_context().await;
+
+    // Pool has already accounted for the rewards in its rate.
+    let accumulated_rewards_per_token =
+        rewards as u128 * REWARDS_PER_TOKEN_SCALING_FACTOR / DEPOSIT_AMOUNT as u128;
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        rewards,
+        accumulated_rewards_per_token,
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: 0,
+        },
+    )
+    .await;
+
+    let instruction = GetPendingRewardsBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards(holder_rewards)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .instruction();
+    let pending = simulate_pending_rewards(&mut context, instruction)
+        .await
+        .unwrap();
+
+    assert_eq!(pending, rewards);
+}
+
+#[tokio::test]
+async fn success_with_pool_inflow() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let rewards = 1_000_000;
+
+    let mut context = setup().start_with_context().await;
+
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT / 2,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: 0,
+        },
+    )
+    .await;
+
+    // Rewards not yet accounted for in the pool's rate.
+    send_rewards_to_pool(&mut context, &holder_rewards_pool, rewards).await;
+
+    let instruction = GetPendingRewardsBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards(holder_rewards)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .instruction();
+    let pending = simulate_pending_rewards(&mut context, instruction)
+        .await
+        .unwrap();
+
+    // Holder owns half of the deposits.
+    assert_eq!(pending, rewards / 2);
+}
+
+#[tokio::test]
+async fn success_matches_client_projection() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let rewards = 1_000_000;
+
+    let mut context = setup().start_with_context().await;
+
+    // The pool token account holds tokens donated on top of the deposits,
+    // which earn no rewards.
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+    setup_holder_rewards_pool_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        0,
+        0,
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+    setup_token_account(
+        &mut context,
+        &pool_token,
+        &holder_rewards_pool,
+        &mint,
+        DEPOSIT_AMOUNT * 2,
+    )
+    .await;
+
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT / 4,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: 0,
+        },
+    )
+    .await;
+
+    // Rewards not yet accounted for in the pool's rate.
+    send_rewards_to_pool(&mut context, &holder_rewards_pool, rewards).await;
+
+    let instruction = GetPendingRewardsBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards(holder_rewards)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .instruction();
+    let pending = simulate_pending_rewards(&mut context, instruction)
+        .await
+        .unwrap();
+
+    // Project the rewards from the accounts, as a client would.
+    let pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state = bytemuck::from_bytes::<HolderRewardsPool>(&pool_account.data);
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let projected = project_pending_rewards(
+        &PoolRewardsState {
+            accumulated_rewards_per_token: pool_state.accumulated_rewards_per_token,
+            lamports_last: pool_state.lamports_last,
+            total_deposited: pool_state.total_deposited,
+            total_lockup_bonus_weight: pool_state.total_lockup_bonus_weight,
+        },
+        &HolderRewardsState {
+            last_accumulated_rewards_per_token: holder_rewards_state
+                .last_accumulated_rewards_per_token,
+            weight: holder_rewards_state.weight().unwrap(),
+            unharvested_rewards: holder_rewards_state.unharvested_rewards,
+        },
+        pool_account.lamports,
+        rent.minimum_balance(pool_account.data.len()),
+    );
+
+    // Holder owns a quarter of the deposits, regardless of donated tokens.
+    assert_eq!(projected, Some(pending));
+    assert_eq!(pending, rewards / 4);
+}
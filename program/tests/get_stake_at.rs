@@ -0,0 +1,173 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    crate::setup::{setup_holder_rewards_account, setup_stake_checkpoints_account, DEPOSIT_AMOUNT},
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        state::{get_holder_rewards_address, get_stake_checkpoints_address},
+    },
+    paladin_rewards_program_client::instructions::GetStakeAtBuilder,
+    setup::setup,
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::{Instruction, InstructionError},
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+};
+
+async fn simulate_stake_at(
+    context: &mut ProgramTestContext,
+    instruction: Instruction,
+) -> Result<u64, TransactionError> {
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let simulation = context
+        .banks_client
+        .simulate_transaction(transaction)
+        .await
+        .unwrap();
+    simulation.result.unwrap()?;
+
+    let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+    assert_eq!(return_data.program_id, paladin_rewards_program::id());
+    Ok(u64::from_le_bytes(return_data.data.try_into().unwrap()))
+}
+
+// Sets up a holder who deposited at slot 10 and withdrew half at slot 20,
+// with the bank at slot 30.
+async fn setup_checkpoints(context: &mut ProgramTestContext, owner: &Pubkey) -> Pubkey {
+    let holder_rewards = get_holder_rewards_address(owner, &paladin_rewards_program::id());
+    setup_holder_rewards_account(
+        context,
+        owner,
+        &holder_rewards,
+        DEPOSIT_AMOUNT / 2,
+        0,
+        owner,
+    )
+    .await;
+
+    let stake_checkpoints = get_stake_checkpoints_address(owner, &paladin_rewards_program::id());
+    setup_stake_checkpoints_account(
+        context,
+        owner,
+        &holder_rewards,
+        &stake_checkpoints,
+        &[(10, DEPOSIT_AMOUNT), (20, DEPOSIT_AMOUNT / 2)],
+    )
+    .await;
+
+    context.warp_to_slot(30).unwrap();
+
+    stake_checkpoints
+}
+
+#[tokio::test]
+async fn fail_stake_checkpoints_incorrect_address() {
+    let owner = Keypair::new();
+
+    let mut context = setup().start_with_context().await;
+    let stake_checkpoints = setup_checkpoints(&mut context, &owner.pubkey()).await;
+
+    // Checkpoints read for a different owner.
+    let instruction = GetStakeAtBuilder::new()
+        .stake_checkpoints(stake_checkpoints)
+        .owner(Pubkey::new_unique())
+        .slot(15)
+        .instruction();
+    let err = simulate_stake_at(&mut context, instruction)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectStakeCheckpointsAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_snapshot_slot_not_past() {
+    let owner = Keypair::new();
+
+    let mut context = setup().start_with_context().await;
+    let stake_checkpoints = setup_checkpoints(&mut context, &owner.pubkey()).await;
+
+    let instruction = GetStakeAtBuilder::new()
+        .stake_checkpoints(stake_checkpoints)
+        .owner(owner.pubkey())
+        .slot(30)
+        .instruction();
+    let err = simulate_stake_at(&mut context, instruction)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::SnapshotSlotNotPast as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_stake_checkpoint_not_found() {
+    let owner = Keypair::new();
+
+    let mut context = setup().start_with_context().await;
+    let stake_checkpoints = setup_checkpoints(&mut context, &owner.pubkey()).await;
+
+    // Before the first checkpoint.
+    let instruction = GetStakeAtBuilder::new()
+        .stake_checkpoints(stake_checkpoints)
+        .owner(owner.pubkey())
+        .slot(9)
+        .instruction();
+    let err = simulate_stake_at(&mut context, instruction)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::StakeCheckpointNotFound as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let owner = Keypair::new();
+
+    let mut context = setup().start_with_context().await;
+    let stake_checkpoints = setup_checkpoints(&mut context, &owner.pubkey()).await;
+
+    for (slot, expected) in [
+        (10, DEPOSIT_AMOUNT),
+        (19, DEPOSIT_AMOUNT),
+        (20, DEPOSIT_AMOUNT / 2),
+        (29, DEPOSIT_AMOUNT / 2),
+    ] {
+        let instruction = GetStakeAtBuilder::new()
+            .stake_checkpoints(stake_checkpoints)
+            .owner(owner.pubkey())
+            .slot(slot)
+            .instruction();
+        let deposited = simulate_stake_at(&mut context, instruction).await.unwrap();
+
+        assert_eq!(deposited, expected);
+    }
+}
@@ -0,0 +1,241 @@
+#![cfg(feature = "test-sbf")]
+
+mod execute_utils;
+mod setup;
+
+use {
+    crate::{
+        execute_utils::execute_with_payer,
+        setup::{
+            setup_holder_rewards_account, setup_holder_rewards_pool_account,
+            setup_lockup_tiers_account, setup_vote_escrow_account, DEPOSIT_AMOUNT,
+        },
+    },
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        state::{
+            get_holder_rewards_address, get_holder_rewards_pool_address, get_lockup_tiers_address,
+            get_vote_escrow_address, voting_power, voting_power_end, LockupTier,
+            MAX_LOCKUP_DURATION,
+        },
+    },
+    paladin_rewards_program_client::instructions::{GetVotingPowerBuilder, LockBuilder},
+    setup::setup,
+    solana_program_test::*,
+    solana_sdk::{
+        clock::Clock,
+        instruction::{Instruction, InstructionError},
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+};
+
+const TIER: LockupTier = LockupTier {
+    duration: 7 * 24 * 60 * 60,
+    multiplier_bps: 15_000,
+};
+
+async fn simulate_voting_power(
+    context: &mut ProgramTestContext,
+    instruction: Instruction,
+) -> Result<(u64, u64), TransactionError> {
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let simulation = context
+        .banks_client
+        .simulate_transaction(transaction)
+        .await
+        .unwrap();
+    simulation.result.unwrap()?;
+
+    let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+    assert_eq!(return_data.program_id, paladin_rewards_program::id());
+    let (holder, total) = return_data.data.split_at(8);
+    Ok((
+        u64::from_le_bytes(holder.try_into().unwrap()),
+        u64::from_le_bytes(total.try_into().unwrap()),
+    ))
+}
+
+struct Accounts {
+    mint: Pubkey,
+    holder_rewards_pool: Pubkey,
+    lockup_tiers: Pubkey,
+    vote_escrow: Pubkey,
+}
+
+// Sets up a pool with a vote escrow, for two holders.
+async fn setup_accounts(context: &mut ProgramTestContext, now: i64) -> Accounts {
+    let mint = Pubkey::new_unique();
+
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    setup_holder_rewards_pool_account(
+        context,
+        &mint,
+        &holder_rewards_pool,
+        0,
+        0,
+        2 * DEPOSIT_AMOUNT,
+    )
+    .await;
+
+    let lockup_tiers =
+        get_lockup_tiers_address(&holder_rewards_pool, &paladin_rewards_program::id());
+    setup_lockup_tiers_account(
+        context,
+        &holder_rewards_pool,
+        &lockup_tiers,
+        [
+            TIER,
+            LockupTier::default(),
+            LockupTier::default(),
+            LockupTier::default(),
+        ],
+    )
+    .await;
+
+    let vote_escrow = get_vote_escrow_address(&holder_rewards_pool, &paladin_rewards_program::id());
+    setup_vote_escrow_account(
+        context,
+        &holder_rewards_pool,
+        &lockup_tiers,
+        &vote_escrow,
+        false,
+        now,
+    )
+    .await;
+
+    Accounts {
+        mint,
+        holder_rewards_pool,
+        lockup_tiers,
+        vote_escrow,
+    }
+}
+
+// Sets up a holder who locks their whole deposit until `unlock_timestamp`.
+async fn setup_locked_holder(
+    context: &mut ProgramTestContext,
+    accounts: &Accounts,
+    unlock_timestamp: i64,
+) -> (Pubkey, Pubkey) {
+    let owner = Keypair::new();
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    setup_holder_rewards_account(
+        context,
+        &owner.pubkey(),
+        &holder_rewards,
+        DEPOSIT_AMOUNT,
+        0,
+        &owner.pubkey(),
+    )
+    .await;
+
+    let instruction = LockBuilder::new()
+        .holder_rewards_pool(accounts.holder_rewards_pool)
+        .holder_rewards(holder_rewards)
+        .lockup_tiers(accounts.lockup_tiers)
+        .mint(accounts.mint)
+        .owner(owner.pubkey())
+        .vote_escrow(Some(accounts.vote_escrow))
+        .amount(DEPOSIT_AMOUNT)
+        .unlock_timestamp(unlock_timestamp)
+        .instruction();
+    execute_with_payer(context, instruction, Some(&owner)).await;
+
+    (owner.pubkey(), holder_rewards)
+}
+
+async fn unix_timestamp(context: &mut ProgramTestContext) -> i64 {
+    context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp
+}
+
+#[tokio::test]
+async fn fail_vote_escrow_incorrect_address() {
+    let mut context = setup().start_with_context().await;
+    let now = unix_timestamp(&mut context).await;
+    let accounts = setup_accounts(&mut context, now).await;
+    let (owner, holder_rewards) =
+        setup_locked_holder(&mut context, &accounts, now + MAX_LOCKUP_DURATION as i64).await;
+
+    let instruction = GetVotingPowerBuilder::new()
+        .holder_rewards_pool(accounts.holder_rewards_pool)
+        .vote_escrow(accounts.lockup_tiers) // Incorrect vote escrow address.
+        .holder_rewards(holder_rewards)
+        .mint(accounts.mint)
+        .owner(owner)
+        .instruction();
+    let err = simulate_voting_power(&mut context, instruction)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountData)
+    );
+
+    // A vote escrow for another pool.
+    let other = setup_accounts(&mut context, now).await;
+    let instruction = GetVotingPowerBuilder::new()
+        .holder_rewards_pool(accounts.holder_rewards_pool)
+        .vote_escrow(other.vote_escrow)
+        .holder_rewards(holder_rewards)
+        .mint(accounts.mint)
+        .owner(owner)
+        .instruction();
+    let err = simulate_voting_power(&mut context, instruction)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectVoteEscrowAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let mut context = setup().start_with_context().await;
+    let now = unix_timestamp(&mut context).await;
+    let accounts = setup_accounts(&mut context, now).await;
+
+    let long_unlock = now + MAX_LOCKUP_DURATION as i64;
+    let short_unlock = now + MAX_LOCKUP_DURATION as i64 / 4;
+    let (owner, holder_rewards) = setup_locked_holder(&mut context, &accounts, long_unlock).await;
+    setup_locked_holder(&mut context, &accounts, short_unlock).await;
+
+    let instruction = GetVotingPowerBuilder::new()
+        .holder_rewards_pool(accounts.holder_rewards_pool)
+        .vote_escrow(accounts.vote_escrow)
+        .holder_rewards(holder_rewards)
+        .mint(accounts.mint)
+        .owner(owner)
+        .instruction();
+    let (holder_voting_power, total_voting_power) =
+        simulate_voting_power(&mut context, instruction)
+            .await
+            .unwrap();
+
+    let long_voting_power = voting_power(DEPOSIT_AMOUNT, voting_power_end(long_unlock), now);
+    let short_voting_power = voting_power(DEPOSIT_AMOUNT, voting_power_end(short_unlock), now);
+    assert_eq!(holder_voting_power, long_voting_power);
+    // The total may round down by at most one vote per lockup.
+    assert!(total_voting_power <= long_voting_power + short_voting_power);
+    assert!(total_voting_power + 2 >= long_voting_power + short_voting_power);
+}
@@ -5,21 +5,29 @@ mod setup;
 
 use {
     crate::{
-        execute_utils::execute_with_payer_err,
+        execute_utils::{execute_with_payer, execute_with_payer_err, execute_with_payer_events},
         setup::{
-            setup_holder_rewards_account_with_token_account,
-            setup_holder_rewards_pool_account_with_token_account, DEPOSIT_AMOUNT,
+            send_rewards_to_pool, setup_holder_rewards_account_with_token_account,
+            setup_holder_rewards_pool_account_with_token_account, setup_rate_history_account,
+            update_holder_rewards_pool, HolderRewardsSetup, DEPOSIT_AMOUNT,
         },
     },
     paladin_rewards_program::{
         error::PaladinRewardsError,
         processor::REWARDS_PER_TOKEN_SCALING_FACTOR,
-        state::{get_holder_rewards_address, get_holder_rewards_pool_address, HolderRewards},
+        state::{
+            get_holder_rewards_address, get_holder_rewards_address_and_bump_seed,
+            get_holder_rewards_pool_address, get_rate_history_address, HolderRewards,
+            HolderRewardsPool, RateHistory, RateHistoryEntry,
+        },
+    },
+    paladin_rewards_program_client::{
+        events::PaladinRewardsEvent, instructions::HarvestRewardsBuilder,
     },
-    paladin_rewards_program_client::instructions::HarvestRewardsBuilder,
     setup::setup,
     solana_program_test::*,
     solana_sdk::{
+        clock::Clock,
         instruction::InstructionError,
         pubkey::Pubkey,
         signature::Keypair,
@@ -63,9 +71,11 @@ async fn fail_not_enough_lamports() {
         &owner.pubkey(),
         &holder_rewards,
         &owner_token,
-        DEPOSIT_AMOUNT,                        // total deposited for holder
-        REWARDS_PER_TOKEN_SCALING_FACTOR / 50, // last rewards per token
-        0,                                     // token balance
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: REWARDS_PER_TOKEN_SCALING_FACTOR / 50,
+            token_balance: 0,
+        },
     )
     .await;
 
@@ -246,9 +256,11 @@ async fn success(pool: Pool, holder: Holder, expected_harvested_rewards: u64) {
         &owner.pubkey(),
         &holder_rewards,
         &owner_token,
-        deposited,
-        last_accumulated_rewards_per_token,
-        0,
+        HolderRewardsSetup {
+            deposited,
+            last_accumulated_rewards_per_token,
+            token_balance: 0,
+        },
     )
     .await;
 
@@ -284,6 +296,12 @@ async fn success(pool: Pool, holder: Holder, expected_harvested_rewards: u64) {
         .unwrap();
 
     // Assert the holder rewards account state was updated.
+    let slot = context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .slot;
     let holder_rewards_account = context
         .banks_client
         .get_account(holder_rewards)
@@ -295,24 +313,48 @@ async fn success(pool: Pool, holder: Holder, expected_harvested_rewards: u64) {
         &HolderRewards {
             last_accumulated_rewards_per_token: accumulated_rewards_per_token,
             deposited,
-            _padding: 0,
+            rent_payer: owner.pubkey(),
+            bump_seed: get_holder_rewards_address_and_bump_seed(
+                &owner.pubkey(),
+                &paladin_rewards_program::id()
+            )
+            .1,
+            stake_checkpoints: 0,
+            _padding: [0; 6],
+            total_rewards_harvested: expected_harvested_rewards,
+            first_deposit_slot: 0,
+            last_activity_slot: slot,
+            cumulative_deposited: 0,
+            cumulative_withdrawn: 0,
+            locked_amount: 0,
+            unlock_timestamp: 0,
+            lockup_multiplier_bps: 0,
+            voting_power_locked: 0,
+            voting_power_end: 0,
+            last_deposit_slot: 0,
+            early_withdrawal_penalties: 0,
+            unharvested_rewards: 0,
+            slashed: 0,
         }
     );
 
     // Assert the holder rewards pool's balance was debited.
-    let pool_resulting_lamports = context
+    let pool_account = context
         .banks_client
         .get_account(holder_rewards_pool)
         .await
         .unwrap()
-        .unwrap()
-        .lamports;
+        .unwrap();
     assert_eq!(
-        pool_resulting_lamports,
+        pool_account.lamports,
         pool_beginning_lamports
             .checked_sub(expected_harvested_rewards)
             .unwrap(),
     );
+    assert_eq!(
+        bytemuck::from_bytes::<HolderRewardsPool>(&pool_account.data).total_rewards_harvested,
+        expected_harvested_rewards
+    );
 
     // Assert the token account's balance was credited.
     let owner_resulting_lamports = if expected_harvested_rewards > 0 {
@@ -340,3 +382,349 @@ async fn success(pool: Pool, holder: Holder, expected_harvested_rewards: u64) {
             .unwrap(),
     );
 }
+
+#[tokio::test]
+async fn fail_rate_history_incorrect_address() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+
+    // Set up the rate history of a different pool.
+    let other_holder_rewards_pool = Pubkey::new_unique();
+    let rate_history =
+        get_rate_history_address(&other_holder_rewards_pool, &paladin_rewards_program::id());
+    setup_rate_history_account(&mut context, &other_holder_rewards_pool, &rate_history).await;
+
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: 0,
+        },
+    )
+    .await;
+
+    let instruction = HarvestRewardsBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .rate_history(Some(rate_history))
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectRateHistoryAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_rate_history_required() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+
+    // The pool records its rate history.
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.rate_history = 1
+    })
+    .await;
+
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: 0,
+        },
+    )
+    .await;
+
+    // Harvest without the rate history.
+    let instruction = HarvestRewardsBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::RateHistoryRequired as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_epoch_ledger_required() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+
+    // The pool records its epoch ledger.
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.epoch_ledger = 1
+    })
+    .await;
+
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: 0,
+        },
+    )
+    .await;
+
+    // Harvest without the epoch ledger.
+    let instruction = HarvestRewardsBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::EpochLedgerRequired as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_emits_events() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: 0,
+        },
+    )
+    .await;
+
+    // Rewards received by the pool are accrued on the next harvest.
+    send_rewards_to_pool(&mut context, &holder_rewards_pool, DEPOSIT_AMOUNT).await;
+
+    let instruction = HarvestRewardsBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .instruction();
+    let events = execute_with_payer_events(&mut context, instruction, Some(&owner)).await;
+
+    // Assert the accrual and the harvest were logged.
+    assert_eq!(
+        events,
+        vec![
+            PaladinRewardsEvent::RewardsAccrued {
+                holder_rewards_pool,
+                inflow: DEPOSIT_AMOUNT,
+                accumulated_rewards_per_token: REWARDS_PER_TOKEN_SCALING_FACTOR,
+            },
+            PaladinRewardsEvent::Harvested {
+                holder_rewards_pool,
+                owner: owner.pubkey(),
+                amount: DEPOSIT_AMOUNT,
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn success_records_rate_history() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+
+    let rate_history =
+        get_rate_history_address(&holder_rewards_pool, &paladin_rewards_program::id());
+    setup_rate_history_account(&mut context, &holder_rewards_pool, &rate_history).await;
+
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: 0,
+        },
+    )
+    .await;
+
+    // Rewards received by the pool change its rate on the next harvest.
+    send_rewards_to_pool(&mut context, &holder_rewards_pool, DEPOSIT_AMOUNT).await;
+
+    let instruction = HarvestRewardsBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .rate_history(Some(rate_history))
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&owner)).await;
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    let pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state = bytemuck::from_bytes::<HolderRewardsPool>(&pool_account.data);
+    assert_eq!(
+        pool_state.accumulated_rewards_per_token,
+        REWARDS_PER_TOKEN_SCALING_FACTOR
+    );
+
+    // Assert the new rate was appended to the rate history.
+    let rate_history_account = context
+        .banks_client
+        .get_account(rate_history)
+        .await
+        .unwrap()
+        .unwrap();
+    let rate_history_state = bytemuck::from_bytes::<RateHistory>(&rate_history_account.data);
+    assert_eq!(rate_history_state.len, 1);
+    assert_eq!(
+        rate_history_state.latest(),
+        Some(&RateHistoryEntry {
+            accumulated_rewards_per_token: pool_state.accumulated_rewards_per_token,
+            slot: clock.slot,
+            epoch: clock.epoch,
+            total_deposited: DEPOSIT_AMOUNT,
+            _padding: [0; 8],
+        })
+    );
+}
@@ -0,0 +1,184 @@
+#![cfg(feature = "test-sbf")]
+
+mod execute_utils;
+mod setup;
+
+use {
+    crate::{
+        execute_utils::{execute_with_payer, execute_with_payer_err},
+        setup::{
+            setup_epoch_ledger_account, setup_holder_rewards_pool_account,
+            update_holder_rewards_pool,
+        },
+    },
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        state::{
+            get_epoch_ledger_address, get_epoch_ledger_address_and_bump_seed,
+            get_holder_rewards_pool_address, EpochLedger, HolderRewardsPool,
+        },
+    },
+    paladin_rewards_program_client::instructions::InitializeEpochLedgerBuilder,
+    setup::setup,
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::InstructionError, pubkey::Pubkey, signer::Signer,
+        transaction::TransactionError,
+    },
+};
+
+#[tokio::test]
+async fn fail_holder_rewards_pool_incorrect_address() {
+    let mint = Pubkey::new_unique();
+    let holder_rewards_pool = Pubkey::new_unique(); // Incorrect holder rewards pool address.
+    let epoch_ledger =
+        get_epoch_ledger_address(&holder_rewards_pool, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+
+    let instruction = InitializeEpochLedgerBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .epoch_ledger(epoch_ledger)
+        .mint(mint)
+        .payer(context.payer.pubkey())
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, None).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectHolderRewardsPoolAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_epoch_ledger_incorrect_address() {
+    let mint = Pubkey::new_unique();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let epoch_ledger = Pubkey::new_unique(); // Incorrect epoch ledger address.
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+
+    let instruction = InitializeEpochLedgerBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .epoch_ledger(epoch_ledger)
+        .mint(mint)
+        .payer(context.payer.pubkey())
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, None).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectEpochLedgerAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_epoch_ledger_already_initialized() {
+    let mint = Pubkey::new_unique();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let epoch_ledger =
+        get_epoch_ledger_address(&holder_rewards_pool, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+    setup_epoch_ledger_account(&mut context, &holder_rewards_pool, &epoch_ledger).await;
+
+    let instruction = InitializeEpochLedgerBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .epoch_ledger(epoch_ledger)
+        .mint(mint)
+        .payer(context.payer.pubkey())
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, None).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::AccountAlreadyInitialized)
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let mint = Pubkey::new_unique();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let (epoch_ledger, bump_seed) = get_epoch_ledger_address_and_bump_seed(
+        &holder_rewards_pool,
+        &paladin_rewards_program::id(),
+    );
+
+    let accumulated_rewards_per_token = 1_000_000;
+    let total_rewards_received = 500;
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        0,
+        accumulated_rewards_per_token,
+        250,
+    )
+    .await;
+
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.total_rewards_received = total_rewards_received
+    })
+    .await;
+
+    let instruction = InitializeEpochLedgerBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .epoch_ledger(epoch_ledger)
+        .mint(mint)
+        .payer(context.payer.pubkey())
+        .instruction();
+    execute_with_payer(&mut context, instruction, None).await;
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    // Assert the epoch ledger was created and seeded with the pool's totals.
+    let epoch_ledger_account = context
+        .banks_client
+        .get_account(epoch_ledger)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(epoch_ledger_account.owner, paladin_rewards_program::id());
+    assert_eq!(
+        epoch_ledger_account.lamports,
+        rent.minimum_balance(EpochLedger::LEN)
+    );
+
+    let epoch_ledger_state = bytemuck::from_bytes::<EpochLedger>(&epoch_ledger_account.data);
+    assert_eq!(epoch_ledger_state.bump_seed, bump_seed);
+    assert_eq!(
+        epoch_ledger_state.last_accumulated_rewards_per_token,
+        accumulated_rewards_per_token
+    );
+    assert_eq!(
+        epoch_ledger_state.last_total_rewards_received,
+        total_rewards_received
+    );
+    assert_eq!(epoch_ledger_state.len, 0);
+    assert_eq!(epoch_ledger_state.latest(), None);
+
+    // Assert the pool now requires its epoch ledger.
+    let pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state = bytemuck::from_bytes::<HolderRewardsPool>(&pool_account.data);
+    assert_eq!(pool_state.epoch_ledger, 1);
+}
@@ -6,13 +6,18 @@ mod setup;
 use {
     crate::{
         execute_utils::{execute_with_payer, execute_with_payer_err},
-        setup::{sign_duna_document, sign_duna_document_with_data},
+        setup::{
+            setup_system_account, sign_duna_document, sign_duna_document_with_data,
+            update_holder_rewards_pool,
+        },
     },
     paladin_rewards_program::{
         error::PaladinRewardsError,
         state::{
-            get_holder_rewards_address, get_holder_rewards_pool_address, HolderRewards,
-            HolderRewardsPool,
+            get_access_entry_address, get_holder_rewards_address,
+            get_holder_rewards_address_and_bump_seed, get_holder_rewards_pool_address,
+            get_holder_rewards_pool_address_and_bump_seed, HolderRewards, HolderRewardsPool,
+            ACCESS_LIST_ALLOWLIST,
         },
     },
     paladin_rewards_program_client::instructions::InitializeHolderRewardsBuilder,
@@ -20,12 +25,13 @@ use {
     solana_program_test::*,
     solana_sdk::{
         account::{Account, AccountSharedData},
+        clock::Clock,
         instruction::InstructionError,
         pubkey::Pubkey,
         signature::Keypair,
         signer::Signer,
         system_program,
-        transaction::TransactionError,
+        transaction::{Transaction, TransactionError},
     },
     spl_associated_token_account::get_associated_token_address,
 };
@@ -70,6 +76,7 @@ async fn fail_holder_rewards_pool_incorrect_owner() {
         .owner(owner.pubkey())
         .mint(mint)
         .duna_document_pda(duna_pda)
+        .payer(context.payer.pubkey())
         .instruction();
 
     let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
@@ -92,7 +99,7 @@ async fn fail_holder_rewards_pool_incorrect_address() {
     let pool_token_account = get_associated_token_address(&holder_rewards_pool, &mint);
 
     let mut context = setup().start_with_context().await;
-    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
     setup_token_account(&mut context, &token_account, &owner.pubkey(), &mint, 0).await;
     setup_token_account(
         &mut context,
@@ -112,6 +119,7 @@ async fn fail_holder_rewards_pool_incorrect_address() {
         .owner(owner.pubkey())
         .mint(mint)
         .duna_document_pda(duna_pda)
+        .payer(context.payer.pubkey())
         .instruction();
 
     let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
@@ -139,7 +147,7 @@ async fn fail_holder_rewards_pool_token_incorrect_address() {
     let pool_token_account = get_associated_token_address(&rand, &mint); // Incorrect token account address.
 
     let mut context = setup().start_with_context().await;
-    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
     setup_token_account(&mut context, &token_account, &owner.pubkey(), &mint, 0).await;
     setup_token_account(&mut context, &pool_token_account, &rand, &mint, 0).await;
     setup_mint(&mut context, &mint, 0, None).await;
@@ -152,6 +160,7 @@ async fn fail_holder_rewards_pool_token_incorrect_address() {
         .owner(owner.pubkey())
         .mint(mint)
         .duna_document_pda(duna_pda)
+        .payer(context.payer.pubkey())
         .instruction();
 
     let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
@@ -210,6 +219,7 @@ async fn fail_holder_rewards_pool_invalid_data() {
         .owner(owner.pubkey())
         .mint(mint)
         .duna_document_pda(duna_pda)
+        .payer(context.payer.pubkey())
         .instruction();
 
     let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
@@ -232,7 +242,7 @@ async fn fail_holder_rewards_incorrect_address() {
     let pool_token_account = get_associated_token_address(&holder_rewards_pool, &mint);
 
     let mut context = setup().start_with_context().await;
-    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
     setup_token_account(
         &mut context,
         &pool_token_account,
@@ -252,6 +262,7 @@ async fn fail_holder_rewards_incorrect_address() {
         .owner(owner.pubkey())
         .mint(mint)
         .duna_document_pda(duna_pda)
+        .payer(context.payer.pubkey())
         .instruction();
 
     let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
@@ -278,7 +289,7 @@ async fn fail_holder_rewards_account_initialized() {
     let pool_token_account = get_associated_token_address(&holder_rewards_pool, &mint);
 
     let mut context = setup().start_with_context().await;
-    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
     setup_token_account(
         &mut context,
         &pool_token_account,
@@ -311,6 +322,7 @@ async fn fail_holder_rewards_account_initialized() {
         .owner(owner.pubkey())
         .mint(mint)
         .duna_document_pda(duna_pda)
+        .payer(context.payer.pubkey())
         .instruction();
 
     let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
@@ -334,7 +346,7 @@ async fn fail_wrong_duna_pda() {
     let pool_token_account = get_associated_token_address(&holder_rewards_pool, &mint);
 
     let mut context = setup().start_with_context().await;
-    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
     setup_token_account(
         &mut context,
         &pool_token_account,
@@ -364,6 +376,7 @@ async fn fail_wrong_duna_pda() {
         .owner(owner.pubkey())
         .mint(mint)
         .duna_document_pda(duna_pda)
+        .payer(context.payer.pubkey())
         .instruction();
 
     let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
@@ -390,7 +403,7 @@ async fn fail_not_signed_duna_pda() {
     let pool_token_account = get_associated_token_address(&holder_rewards_pool, &mint);
 
     let mut context = setup().start_with_context().await;
-    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
     setup_token_account(
         &mut context,
         &pool_token_account,
@@ -420,6 +433,7 @@ async fn fail_not_signed_duna_pda() {
         .owner(owner.pubkey())
         .mint(mint)
         .duna_document_pda(duna_pda)
+        .payer(context.payer.pubkey())
         .instruction();
 
     let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
@@ -433,6 +447,193 @@ async fn fail_not_signed_duna_pda() {
     );
 }
 
+#[tokio::test]
+async fn success_sponsor_tops_up_holder_rewards() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token_account = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+    setup_token_account(
+        &mut context,
+        &pool_token_account,
+        &holder_rewards_pool,
+        &mint,
+        0,
+    )
+    .await;
+    setup_token_account(&mut context, &token_account, &owner.pubkey(), &mint, 0).await;
+    setup_mint(&mut context, &mint, 0, None).await;
+
+    let sponsor = Keypair::new();
+    setup_system_account(&mut context, &sponsor.pubkey(), 1_000_000_000).await;
+
+    // Fund the holder rewards account, one lamport short of rent exempt.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let lamports = rent.minimum_balance(std::mem::size_of::<HolderRewards>()) - 1;
+        context.set_account(
+            &holder_rewards,
+            &AccountSharedData::new(lamports, 0, &system_program::id()),
+        );
+    }
+
+    let duna_pda = sign_duna_document(&mut context, &owner.pubkey());
+    let instruction = InitializeHolderRewardsBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token_account)
+        .holder_rewards(holder_rewards)
+        .owner(owner.pubkey())
+        .mint(mint)
+        .duna_document_pda(duna_pda)
+        .payer(sponsor.pubkey())
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner, &sponsor],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the sponsor covered the missing lamport and is recorded as the
+    // rent payer.
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    assert_eq!(
+        holder_rewards_account.lamports,
+        rent.minimum_balance(HolderRewards::LEN)
+    );
+    assert_eq!(
+        bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data).rent_payer,
+        sponsor.pubkey()
+    );
+}
+
+#[tokio::test]
+async fn fail_unlisted_on_allowlist() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token_account = get_associated_token_address(&holder_rewards_pool, &mint);
+    let access_entry = get_access_entry_address(
+        &holder_rewards_pool,
+        &owner.pubkey(),
+        &paladin_rewards_program::id(),
+    );
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.access_list = ACCESS_LIST_ALLOWLIST
+    })
+    .await;
+    setup_token_account(
+        &mut context,
+        &pool_token_account,
+        &holder_rewards_pool,
+        &mint,
+        0,
+    )
+    .await;
+    setup_token_account(&mut context, &token_account, &owner.pubkey(), &mint, 100).await;
+    setup_mint(&mut context, &mint, 100_000, None).await;
+
+    let duna_pda = sign_duna_document(&mut context, &owner.pubkey());
+    let instruction = InitializeHolderRewardsBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token_account)
+        .holder_rewards(holder_rewards)
+        .owner(owner.pubkey())
+        .mint(mint)
+        .duna_document_pda(duna_pda)
+        .payer(context.payer.pubkey())
+        .access_entry(Some(access_entry))
+        .instruction();
+
+    let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::AccessDenied as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_incorrect_system_program() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token_account = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+    setup_token_account(
+        &mut context,
+        &pool_token_account,
+        &holder_rewards_pool,
+        &mint,
+        0,
+    )
+    .await;
+    setup_token_account(&mut context, &token_account, &owner.pubkey(), &mint, 0).await;
+    setup_mint(&mut context, &mint, 0, None).await;
+
+    let duna_pda = sign_duna_document(&mut context, &owner.pubkey());
+
+    // Swap in a fake system program.
+    let instruction = InitializeHolderRewardsBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token_account)
+        .holder_rewards(holder_rewards)
+        .owner(owner.pubkey())
+        .mint(mint)
+        .duna_document_pda(duna_pda)
+        .system_program(Pubkey::new_unique())
+        .payer(context.payer.pubkey())
+        .instruction();
+
+    let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectSystemProgram as u32)
+        )
+    );
+}
+
 #[tokio::test]
 async fn success() {
     // Since there's no math involved here, we just need to assert that the
@@ -452,9 +653,11 @@ async fn success() {
     let mut context = setup().start_with_context().await;
     setup_holder_rewards_pool_account(
         &mut context,
+        &mint,
         &holder_rewards_pool,
         0, // Excess lamports (not used here).
         accumulated_rewards_per_token,
+        0,
     )
     .await;
     setup_token_account(
@@ -500,6 +703,7 @@ async fn success() {
         .owner(owner.pubkey())
         .mint(mint)
         .duna_document_pda(duna_pda)
+        .payer(context.payer.pubkey())
         .instruction();
 
     execute_with_payer(&mut context, instruction, Some(&owner)).await;
@@ -517,11 +721,38 @@ async fn success() {
         &HolderRewards {
             last_accumulated_rewards_per_token: accumulated_rewards_per_token,
             deposited: 0,
-            _padding: 0,
+            rent_payer: context.payer.pubkey(),
+            bump_seed: get_holder_rewards_address_and_bump_seed(
+                &owner.pubkey(),
+                &paladin_rewards_program::id()
+            )
+            .1,
+            stake_checkpoints: 0,
+            _padding: [0; 6],
+            total_rewards_harvested: 0,
+            first_deposit_slot: 0,
+            last_activity_slot: 0,
+            cumulative_deposited: 0,
+            cumulative_withdrawn: 0,
+            locked_amount: 0,
+            unlock_timestamp: 0,
+            lockup_multiplier_bps: 0,
+            voting_power_locked: 0,
+            voting_power_end: 0,
+            last_deposit_slot: 0,
+            early_withdrawal_penalties: 0,
+            unharvested_rewards: 0,
+            slashed: 0,
         }
     );
 
     // Assert - Eligible tokens is updated.
+    let slot = context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .slot;
     let holder_rewards_pool_account = context
         .banks_client
         .get_account(holder_rewards_pool)
@@ -536,7 +767,33 @@ async fn success() {
             accumulated_rewards_per_token,
             lamports_last: holder_rewards_pool_account.lamports,
             duna_document_hash: [1; 32],
-            _padding: 0,
+            total_deposited: 0,
+            authority: Pubkey::default(),
+            bump_seed: get_holder_rewards_pool_address_and_bump_seed(
+                &mint,
+                &paladin_rewards_program::id()
+            )
+            .1,
+            access_list: 0,
+            early_withdrawal_penalty_bps: 0,
+            early_withdrawal_period_slots: 0,
+            last_update_slot: slot,
+            total_rewards_received: 0,
+            total_rewards_harvested: 0,
+            active_holder_count: 1,
+            total_lockup_bonus_weight: 0,
+            max_total_deposit: 0,
+            max_holder_deposit: 0,
+            min_deposit: 0,
+            receipts: 0,
+            receipt_mint_bump_seed: 0,
+            rate_history: 0,
+            epoch_ledger: 0,
+            _padding: [0; 4],
+            slashing_authority: Pubkey::default(),
+            slash_destination: Pubkey::default(),
+            total_slashed: 0,
+            zero_weight_slots: 0,
         }
     );
 }
@@ -11,8 +11,9 @@ use {
     paladin_rewards_program::{
         error::PaladinRewardsError,
         state::{
-            get_holder_rewards_address, get_holder_rewards_pool_address, HolderRewards,
-            HolderRewardsPool,
+            get_holder_rewards_address, get_holder_rewards_address_and_bump_seed,
+            get_holder_rewards_pool_address, get_holder_rewards_pool_address_and_bump_seed,
+            HolderRewards, HolderRewardsPool,
         },
     },
     paladin_rewards_program_client::instructions::InitializeHolderRewardsPoolBuilder,
@@ -20,17 +21,23 @@ use {
     solana_program_test::*,
     solana_sdk::{
         account::{Account, AccountSharedData},
+        clock::Clock,
         instruction::InstructionError,
+        program_pack::Pack,
         pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
         system_program,
         transaction::TransactionError,
     },
     spl_associated_token_account::get_associated_token_address,
+    spl_token::state::Mint,
 };
 
 #[tokio::test]
 async fn fail_mint_invalid_data() {
     let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
 
     let holder_rewards_pool =
         get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
@@ -65,9 +72,11 @@ async fn fail_mint_invalid_data() {
         .mint(mint)
         .stake_vault_pda(vault_pda)
         .vault_holder_rewards(vault_holder_rewards)
+        .authority(authority.pubkey())
         .duna_document_hash([1; 32])
+        .payer(context.payer.pubkey())
         .instruction();
-    let err = execute_with_payer_err(&mut context, instruction, None).await;
+    let err = execute_with_payer_err(&mut context, instruction, Some(&authority)).await;
 
     assert_eq!(
         err,
@@ -78,12 +87,13 @@ async fn fail_mint_invalid_data() {
 #[tokio::test]
 async fn fail_holder_rewards_pool_incorrect_address() {
     let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
 
     let holder_rewards_pool = Pubkey::new_unique(); // Incorrect holder rewards pool address.
     let pool_token_account = get_associated_token_address(&holder_rewards_pool, &mint);
 
     let mut context = setup().start_with_context().await;
-    setup_mint(&mut context, &mint, 0, None).await;
+    setup_mint(&mut context, &mint, 0, Some(authority.pubkey())).await;
     setup_token_account(
         &mut context,
         &pool_token_account,
@@ -111,9 +121,11 @@ async fn fail_holder_rewards_pool_incorrect_address() {
         .mint(mint)
         .stake_vault_pda(vault_pda)
         .vault_holder_rewards(vault_holder_rewards)
+        .authority(authority.pubkey())
         .duna_document_hash([1; 32])
+        .payer(context.payer.pubkey())
         .instruction();
-    let err = execute_with_payer_err(&mut context, instruction, None).await;
+    let err = execute_with_payer_err(&mut context, instruction, Some(&authority)).await;
 
     assert_eq!(
         err,
@@ -127,6 +139,7 @@ async fn fail_holder_rewards_pool_incorrect_address() {
 #[tokio::test]
 async fn fail_holder_rewards_pool_incorrect_token_address() {
     let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
     let rand = Pubkey::new_unique();
 
     let holder_rewards_pool =
@@ -134,7 +147,7 @@ async fn fail_holder_rewards_pool_incorrect_token_address() {
     let pool_token_account = get_associated_token_address(&rand, &mint); // Incorrect token account address.
 
     let mut context = setup().start_with_context().await;
-    setup_mint(&mut context, &mint, 0, None).await;
+    setup_mint(&mut context, &mint, 0, Some(authority.pubkey())).await;
     setup_token_account(&mut context, &pool_token_account, &rand, &mint, 0).await;
 
     let vault_pda = Pubkey::default();
@@ -155,9 +168,11 @@ async fn fail_holder_rewards_pool_incorrect_token_address() {
         .mint(mint)
         .stake_vault_pda(vault_pda)
         .vault_holder_rewards(vault_holder_rewards)
+        .authority(authority.pubkey())
         .duna_document_hash([1; 32])
+        .payer(context.payer.pubkey())
         .instruction();
-    let err = execute_with_payer_err(&mut context, instruction, None).await;
+    let err = execute_with_payer_err(&mut context, instruction, Some(&authority)).await;
 
     assert_eq!(
         err,
@@ -171,13 +186,14 @@ async fn fail_holder_rewards_pool_incorrect_token_address() {
 #[tokio::test]
 async fn fail_holder_rewards_pool_account_initialized() {
     let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
 
     let holder_rewards_pool =
         get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
     let pool_token_account = get_associated_token_address(&holder_rewards_pool, &mint);
 
     let mut context = setup().start_with_context().await;
-    setup_mint(&mut context, &mint, 0, None).await;
+    setup_mint(&mut context, &mint, 0, Some(authority.pubkey())).await;
     setup_token_account(
         &mut context,
         &pool_token_account,
@@ -219,9 +235,11 @@ async fn fail_holder_rewards_pool_account_initialized() {
         .mint(mint)
         .stake_vault_pda(vault_pda)
         .vault_holder_rewards(vault_holder_rewards)
+        .authority(authority.pubkey())
         .duna_document_hash([1; 32])
+        .payer(context.payer.pubkey())
         .instruction();
-    let err = execute_with_payer_err(&mut context, instruction, None).await;
+    let err = execute_with_payer_err(&mut context, instruction, Some(&authority)).await;
 
     assert_eq!(
         err,
@@ -232,13 +250,14 @@ async fn fail_holder_rewards_pool_account_initialized() {
 #[tokio::test]
 async fn fail_incorrect_vault_holder_rewards_address() {
     let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
 
     let holder_rewards_pool =
         get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
     let pool_token_account = get_associated_token_address(&holder_rewards_pool, &mint);
 
     let mut context = setup().start_with_context().await;
-    setup_mint(&mut context, &mint, 0, None).await;
+    setup_mint(&mut context, &mint, 0, Some(authority.pubkey())).await;
     setup_token_account(
         &mut context,
         &pool_token_account,
@@ -274,9 +293,11 @@ async fn fail_incorrect_vault_holder_rewards_address() {
         .mint(mint)
         .stake_vault_pda(vault_pda)
         .vault_holder_rewards(vault_holder_rewards)
+        .authority(authority.pubkey())
         .duna_document_hash([1; 32])
+        .payer(context.payer.pubkey())
         .instruction();
-    let err = execute_with_payer_err(&mut context, instruction, None).await;
+    let err = execute_with_payer_err(&mut context, instruction, Some(&authority)).await;
 
     assert_eq!(
         err,
@@ -290,13 +311,14 @@ async fn fail_incorrect_vault_holder_rewards_address() {
 #[tokio::test]
 async fn fail_initialized_vault_holder_rewards_address() {
     let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
 
     let holder_rewards_pool =
         get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
     let pool_token_account = get_associated_token_address(&holder_rewards_pool, &mint);
 
     let mut context = setup().start_with_context().await;
-    setup_mint(&mut context, &mint, 0, None).await;
+    setup_mint(&mut context, &mint, 0, Some(authority.pubkey())).await;
     setup_token_account(
         &mut context,
         &pool_token_account,
@@ -318,7 +340,15 @@ async fn fail_initialized_vault_holder_rewards_address() {
         get_holder_rewards_address(&vault_pda, &paladin_rewards_program::id());
     // Fund the holder rewards account.
     // Set vault holer rewards account
-    setup_holder_rewards_account(&mut context, &vault_holder_rewards, 0, 0).await;
+    setup_holder_rewards_account(
+        &mut context,
+        &vault_pda,
+        &vault_holder_rewards,
+        0,
+        0,
+        &Pubkey::default(),
+    )
+    .await;
 
     let instruction = InitializeHolderRewardsPoolBuilder::new()
         .holder_rewards_pool(holder_rewards_pool)
@@ -326,9 +356,11 @@ async fn fail_initialized_vault_holder_rewards_address() {
         .mint(mint)
         .stake_vault_pda(vault_pda)
         .vault_holder_rewards(vault_holder_rewards)
+        .authority(authority.pubkey())
         .duna_document_hash([1; 32])
+        .payer(context.payer.pubkey())
         .instruction();
-    let err = execute_with_payer_err(&mut context, instruction, None).await;
+    let err = execute_with_payer_err(&mut context, instruction, Some(&authority)).await;
 
     assert_eq!(
         err,
@@ -336,9 +368,319 @@ async fn fail_initialized_vault_holder_rewards_address() {
     );
 }
 
+#[tokio::test]
+async fn fail_mint_incorrect_owner() {
+    let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
+
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token_account = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    let mut context = setup().start_with_context().await;
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    // Set up a mint owned by a program other than SPL Token.
+    {
+        let mut data = vec![0; Mint::LEN];
+        Mint::pack(
+            Mint {
+                is_initialized: true,
+                ..Mint::default()
+            },
+            &mut data,
+        )
+        .unwrap();
+        context.set_account(
+            &mint,
+            &AccountSharedData::from(Account {
+                lamports: rent.minimum_balance(Mint::LEN),
+                data,
+                owner: Pubkey::new_unique(),
+                ..Account::default()
+            }),
+        );
+    }
+    setup_token_account(
+        &mut context,
+        &pool_token_account,
+        &holder_rewards_pool,
+        &mint,
+        0,
+    )
+    .await;
+
+    // Fund the holder rewards pool account.
+    context.set_account(
+        &holder_rewards_pool,
+        &AccountSharedData::new(
+            rent.minimum_balance(std::mem::size_of::<HolderRewardsPool>()),
+            0,
+            &system_program::id(),
+        ),
+    );
+
+    let vault_pda = Pubkey::default();
+    let vault_holder_rewards =
+        get_holder_rewards_address(&vault_pda, &paladin_rewards_program::id());
+    // Fund the holder rewards account.
+    context.set_account(
+        &vault_holder_rewards,
+        &AccountSharedData::new(
+            rent.minimum_balance(std::mem::size_of::<HolderRewards>()),
+            0,
+            &system_program::id(),
+        ),
+    );
+
+    let instruction = InitializeHolderRewardsPoolBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token_account)
+        .mint(mint)
+        .stake_vault_pda(vault_pda)
+        .vault_holder_rewards(vault_holder_rewards)
+        .authority(authority.pubkey())
+        .duna_document_hash([1; 32])
+        .payer(context.payer.pubkey())
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&authority)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::InvalidMintOwner as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_authority_not_signer() {
+    let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
+
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token_account = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_mint(&mut context, &mint, 0, Some(authority.pubkey())).await;
+    setup_token_account(
+        &mut context,
+        &pool_token_account,
+        &holder_rewards_pool,
+        &mint,
+        0,
+    )
+    .await;
+
+    let vault_pda = Pubkey::default();
+    let vault_holder_rewards =
+        get_holder_rewards_address(&vault_pda, &paladin_rewards_program::id());
+
+    let mut instruction = InitializeHolderRewardsPoolBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token_account)
+        .mint(mint)
+        .stake_vault_pda(vault_pda)
+        .vault_holder_rewards(vault_holder_rewards)
+        .authority(authority.pubkey())
+        .duna_document_hash([1; 32])
+        .payer(context.payer.pubkey())
+        .instruction();
+    instruction.accounts[5].is_signer = false; // Authority not signer.
+    let err = execute_with_payer_err(&mut context, instruction, None).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_authority_not_mint_authority() {
+    let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
+
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token_account = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    let mut context = setup().start_with_context().await;
+    // A different mint authority.
+    setup_mint(&mut context, &mint, 0, Some(Pubkey::new_unique())).await;
+    setup_token_account(
+        &mut context,
+        &pool_token_account,
+        &holder_rewards_pool,
+        &mint,
+        0,
+    )
+    .await;
+
+    let vault_pda = Pubkey::default();
+    let vault_holder_rewards =
+        get_holder_rewards_address(&vault_pda, &paladin_rewards_program::id());
+
+    let instruction = InitializeHolderRewardsPoolBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token_account)
+        .mint(mint)
+        .stake_vault_pda(vault_pda)
+        .vault_holder_rewards(vault_holder_rewards)
+        .authority(authority.pubkey())
+        .duna_document_hash([1; 32])
+        .payer(context.payer.pubkey())
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&authority)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectPoolAuthority as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_payer_tops_up_holder_rewards_pool() {
+    let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
+
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token_account = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    let mut context = setup().start_with_context().await;
+    let rent = context.banks_client.get_rent().await.unwrap();
+    setup_mint(&mut context, &mint, 0, Some(authority.pubkey())).await;
+    setup_token_account(
+        &mut context,
+        &pool_token_account,
+        &holder_rewards_pool,
+        &mint,
+        0,
+    )
+    .await;
+
+    // Fund the holder rewards pool account, one lamport short of rent exempt.
+    context.set_account(
+        &holder_rewards_pool,
+        &AccountSharedData::new(
+            rent.minimum_balance(std::mem::size_of::<HolderRewardsPool>()) - 1,
+            0,
+            &system_program::id(),
+        ),
+    );
+
+    let vault_pda = Pubkey::default();
+    let vault_holder_rewards =
+        get_holder_rewards_address(&vault_pda, &paladin_rewards_program::id());
+    // Fund the holder rewards account.
+    context.set_account(
+        &vault_holder_rewards,
+        &AccountSharedData::new(
+            rent.minimum_balance(std::mem::size_of::<HolderRewards>()),
+            0,
+            &system_program::id(),
+        ),
+    );
+
+    let instruction = InitializeHolderRewardsPoolBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token_account)
+        .mint(mint)
+        .stake_vault_pda(vault_pda)
+        .vault_holder_rewards(vault_holder_rewards)
+        .authority(authority.pubkey())
+        .duna_document_hash([1; 32])
+        .payer(context.payer.pubkey())
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&authority)).await;
+
+    // Assert the payer covered the missing lamport.
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        holder_rewards_pool_account.lamports,
+        rent.minimum_balance(HolderRewardsPool::LEN)
+    );
+}
+
+#[tokio::test]
+async fn success_payer_funds_vault_holder_rewards() {
+    let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
+
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token_account = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    let mut context = setup().start_with_context().await;
+    let rent = context.banks_client.get_rent().await.unwrap();
+    setup_mint(&mut context, &mint, 0, Some(authority.pubkey())).await;
+    setup_token_account(
+        &mut context,
+        &pool_token_account,
+        &holder_rewards_pool,
+        &mint,
+        0,
+    )
+    .await;
+
+    // Fund the holder rewards pool account.
+    context.set_account(
+        &holder_rewards_pool,
+        &AccountSharedData::new(
+            rent.minimum_balance(std::mem::size_of::<HolderRewardsPool>()),
+            0,
+            &system_program::id(),
+        ),
+    );
+
+    let vault_pda = Pubkey::default();
+    let vault_holder_rewards =
+        get_holder_rewards_address(&vault_pda, &paladin_rewards_program::id());
+    // Leave the holder rewards account unfunded.
+
+    let instruction = InitializeHolderRewardsPoolBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token_account)
+        .mint(mint)
+        .stake_vault_pda(vault_pda)
+        .vault_holder_rewards(vault_holder_rewards)
+        .authority(authority.pubkey())
+        .duna_document_hash([1; 32])
+        .payer(context.payer.pubkey())
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&authority)).await;
+
+    // Assert the payer funded the account and was recorded as its rent payer.
+    let vault_holder_rewards_account = context
+        .banks_client
+        .get_account(vault_holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        vault_holder_rewards_account.lamports,
+        rent.minimum_balance(HolderRewards::LEN)
+    );
+    assert_eq!(
+        bytemuck::from_bytes::<HolderRewards>(&vault_holder_rewards_account.data).rent_payer,
+        context.payer.pubkey()
+    );
+}
+
 #[tokio::test]
 async fn success() {
     let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
 
     let holder_rewards_pool =
         get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
@@ -346,7 +688,7 @@ async fn success() {
 
     let mut context = setup().start_with_context().await;
     let rent = context.banks_client.get_rent().await.unwrap();
-    setup_mint(&mut context, &mint, 0, None).await;
+    setup_mint(&mut context, &mint, 0, Some(authority.pubkey())).await;
     setup_token_account(
         &mut context,
         &pool_token_account,
@@ -382,11 +724,19 @@ async fn success() {
         .mint(mint)
         .stake_vault_pda(vault_pda)
         .vault_holder_rewards(vault_holder_rewards)
+        .authority(authority.pubkey())
         .duna_document_hash([1; 32])
+        .payer(context.payer.pubkey())
         .instruction();
-    execute_with_payer(&mut context, instruction, None).await;
+    execute_with_payer(&mut context, instruction, Some(&authority)).await;
 
     // Check the holder rewards pool account.
+    let slot = context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .slot;
     let holder_rewards_pool_account = context
         .banks_client
         .get_account(holder_rewards_pool)
@@ -399,7 +749,33 @@ async fn success() {
             accumulated_rewards_per_token: 0,
             lamports_last: rent.minimum_balance(HolderRewardsPool::LEN),
             duna_document_hash: [1; 32],
-            _padding: 0,
+            total_deposited: 0,
+            authority: authority.pubkey(),
+            bump_seed: get_holder_rewards_pool_address_and_bump_seed(
+                &mint,
+                &paladin_rewards_program::id()
+            )
+            .1,
+            access_list: 0,
+            early_withdrawal_penalty_bps: 0,
+            early_withdrawal_period_slots: 0,
+            last_update_slot: slot,
+            total_rewards_received: 0,
+            total_rewards_harvested: 0,
+            active_holder_count: 1,
+            total_lockup_bonus_weight: 0,
+            max_total_deposit: 0,
+            max_holder_deposit: 0,
+            min_deposit: 0,
+            receipts: 0,
+            receipt_mint_bump_seed: 0,
+            rate_history: 0,
+            epoch_ledger: 0,
+            _padding: [0; 4],
+            slashing_authority: Pubkey::default(),
+            slash_destination: Pubkey::default(),
+            total_slashed: 0,
+            zero_weight_slots: 0,
         }
     );
 
@@ -415,7 +791,28 @@ async fn success() {
         &HolderRewards {
             last_accumulated_rewards_per_token: 0,
             deposited: 0,
-            _padding: 0,
+            rent_payer: context.payer.pubkey(),
+            bump_seed: get_holder_rewards_address_and_bump_seed(
+                &vault_pda,
+                &paladin_rewards_program::id()
+            )
+            .1,
+            stake_checkpoints: 0,
+            _padding: [0; 6],
+            total_rewards_harvested: 0,
+            first_deposit_slot: 0,
+            last_activity_slot: 0,
+            cumulative_deposited: 0,
+            cumulative_withdrawn: 0,
+            locked_amount: 0,
+            unlock_timestamp: 0,
+            lockup_multiplier_bps: 0,
+            voting_power_locked: 0,
+            voting_power_end: 0,
+            last_deposit_slot: 0,
+            early_withdrawal_penalties: 0,
+            unharvested_rewards: 0,
+            slashed: 0,
         }
     );
 }
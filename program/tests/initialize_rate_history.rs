@@ -0,0 +1,179 @@
+#![cfg(feature = "test-sbf")]
+
+mod execute_utils;
+mod setup;
+
+use {
+    crate::{
+        execute_utils::{execute_with_payer, execute_with_payer_err},
+        setup::{setup_holder_rewards_pool_account, setup_rate_history_account},
+    },
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        state::{
+            get_holder_rewards_pool_address, get_rate_history_address,
+            get_rate_history_address_and_bump_seed, HolderRewardsPool, RateHistory,
+            RateHistoryEntry,
+        },
+    },
+    paladin_rewards_program_client::instructions::InitializeRateHistoryBuilder,
+    setup::setup,
+    solana_program_test::*,
+    solana_sdk::{
+        clock::Clock, instruction::InstructionError, pubkey::Pubkey, signer::Signer,
+        transaction::TransactionError,
+    },
+};
+
+#[tokio::test]
+async fn fail_holder_rewards_pool_incorrect_address() {
+    let mint = Pubkey::new_unique();
+    let holder_rewards_pool = Pubkey::new_unique(); // Incorrect holder rewards pool address.
+    let rate_history =
+        get_rate_history_address(&holder_rewards_pool, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+
+    let instruction = InitializeRateHistoryBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .rate_history(rate_history)
+        .mint(mint)
+        .payer(context.payer.pubkey())
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, None).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectHolderRewardsPoolAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_rate_history_incorrect_address() {
+    let mint = Pubkey::new_unique();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let rate_history = Pubkey::new_unique(); // Incorrect rate history address.
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+
+    let instruction = InitializeRateHistoryBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .rate_history(rate_history)
+        .mint(mint)
+        .payer(context.payer.pubkey())
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, None).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectRateHistoryAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_rate_history_already_initialized() {
+    let mint = Pubkey::new_unique();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let rate_history =
+        get_rate_history_address(&holder_rewards_pool, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+    setup_rate_history_account(&mut context, &holder_rewards_pool, &rate_history).await;
+
+    let instruction = InitializeRateHistoryBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .rate_history(rate_history)
+        .mint(mint)
+        .payer(context.payer.pubkey())
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, None).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::AccountAlreadyInitialized)
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let mint = Pubkey::new_unique();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let (rate_history, bump_seed) = get_rate_history_address_and_bump_seed(
+        &holder_rewards_pool,
+        &paladin_rewards_program::id(),
+    );
+
+    let accumulated_rewards_per_token = 1_000_000;
+    let total_deposited = 250;
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        0,
+        accumulated_rewards_per_token,
+        total_deposited,
+    )
+    .await;
+
+    let instruction = InitializeRateHistoryBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .rate_history(rate_history)
+        .mint(mint)
+        .payer(context.payer.pubkey())
+        .instruction();
+    execute_with_payer(&mut context, instruction, None).await;
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    // Assert the rate history was created and seeded with the pool's rate.
+    let rate_history_account = context
+        .banks_client
+        .get_account(rate_history)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(rate_history_account.owner, paladin_rewards_program::id());
+    assert_eq!(
+        rate_history_account.lamports,
+        rent.minimum_balance(RateHistory::LEN)
+    );
+
+    let rate_history_state = bytemuck::from_bytes::<RateHistory>(&rate_history_account.data);
+    assert_eq!(rate_history_state.bump_seed, bump_seed);
+    assert_eq!(rate_history_state.len, 1);
+    assert_eq!(rate_history_state.next_index, 1);
+    assert_eq!(
+        rate_history_state.latest(),
+        Some(&RateHistoryEntry {
+            accumulated_rewards_per_token,
+            slot: clock.slot,
+            epoch: clock.epoch,
+            total_deposited,
+            _padding: [0; 8],
+        })
+    );
+    // Assert the pool now requires its rate history.
+    let pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state = bytemuck::from_bytes::<HolderRewardsPool>(&pool_account.data);
+    assert_eq!(pool_state.rate_history, 1);
+}
@@ -0,0 +1,281 @@
+#![cfg(feature = "test-sbf")]
+
+mod execute_utils;
+mod setup;
+
+use {
+    crate::{
+        execute_utils::{execute_with_payer, execute_with_payer_err},
+        setup::{
+            initialize_receipt_mint, setup_holder_rewards_pool_account, setup_mint,
+            update_holder_rewards_pool,
+        },
+    },
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        state::{
+            get_holder_rewards_pool_address, get_receipt_mint_address,
+            get_receipt_mint_address_and_bump_seed, HolderRewardsPool,
+        },
+    },
+    paladin_rewards_program_client::instructions::InitializeReceiptMintBuilder,
+    setup::setup,
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::InstructionError, program_option::COption, pubkey::Pubkey, signature::Keypair,
+        signer::Signer, transaction::TransactionError,
+    },
+    spl_token_2022::{
+        extension::{transfer_hook::TransferHook, BaseStateWithExtensions, StateWithExtensions},
+        state::Mint,
+    },
+    spl_transfer_hook_interface::get_extra_account_metas_address,
+};
+
+async fn initialize_receipt_mint_err(
+    context: &mut ProgramTestContext,
+    mint: &Pubkey,
+    holder_rewards_pool: &Pubkey,
+    receipt_mint: &Pubkey,
+    authority: &Keypair,
+) -> TransactionError {
+    let instruction = InitializeReceiptMintBuilder::new()
+        .holder_rewards_pool(*holder_rewards_pool)
+        .receipt_mint(*receipt_mint)
+        .extra_account_metas(get_extra_account_metas_address(
+            receipt_mint,
+            &paladin_rewards_program::id(),
+        ))
+        .mint(*mint)
+        .authority(authority.pubkey())
+        .payer(context.payer.pubkey())
+        .receipt_token_program(spl_token_2022::id())
+        .instruction();
+    execute_with_payer_err(context, instruction, Some(authority)).await
+}
+
+#[tokio::test]
+async fn fail_incorrect_authority() {
+    let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let receipt_mint =
+        get_receipt_mint_address(&holder_rewards_pool, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.authority = Pubkey::new_unique()
+    })
+    .await;
+    setup_mint(&mut context, &mint, 0, None).await;
+
+    let err = initialize_receipt_mint_err(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &receipt_mint,
+        &authority,
+    )
+    .await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectPoolAuthority as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_pool_not_empty() {
+    let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let receipt_mint =
+        get_receipt_mint_address(&holder_rewards_pool, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        0,
+        0,
+        100, // Total deposited.
+    )
+    .await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.authority = authority.pubkey()
+    })
+    .await;
+    setup_mint(&mut context, &mint, 0, None).await;
+
+    let err = initialize_receipt_mint_err(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &receipt_mint,
+        &authority,
+    )
+    .await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::ReceiptMintRequiresEmptyPool as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_receipt_mint_incorrect_address() {
+    let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let receipt_mint = Pubkey::new_unique(); // Incorrect receipt mint address.
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.authority = authority.pubkey()
+    })
+    .await;
+    setup_mint(&mut context, &mint, 0, None).await;
+
+    let err = initialize_receipt_mint_err(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &receipt_mint,
+        &authority,
+    )
+    .await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectReceiptMintAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_already_initialized() {
+    let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+    let receipt_mint = initialize_receipt_mint(&mut context, &mint, &holder_rewards_pool).await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.authority = authority.pubkey()
+    })
+    .await;
+
+    let err = initialize_receipt_mint_err(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &receipt_mint,
+        &authority,
+    )
+    .await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::ReceiptMintAlreadyInitialized as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let (receipt_mint, receipt_mint_bump_seed) = get_receipt_mint_address_and_bump_seed(
+        &holder_rewards_pool,
+        &paladin_rewards_program::id(),
+    );
+    let extra_account_metas =
+        get_extra_account_metas_address(&receipt_mint, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.authority = authority.pubkey()
+    })
+    .await;
+    setup_mint(&mut context, &mint, 0, None).await;
+
+    let instruction = InitializeReceiptMintBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .receipt_mint(receipt_mint)
+        .extra_account_metas(extra_account_metas)
+        .mint(mint)
+        .authority(authority.pubkey())
+        .payer(context.payer.pubkey())
+        .receipt_token_program(spl_token_2022::id())
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&authority)).await;
+
+    // Assert the receipt mint is minted by the pool and hooked to the program.
+    let receipt_mint_account = context
+        .banks_client
+        .get_account(receipt_mint)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(receipt_mint_account.owner, spl_token_2022::id());
+    let receipt_mint_state =
+        StateWithExtensions::<Mint>::unpack(&receipt_mint_account.data).unwrap();
+    assert_eq!(
+        receipt_mint_state.base.mint_authority,
+        COption::Some(holder_rewards_pool)
+    );
+    assert_eq!(receipt_mint_state.base.supply, 0);
+    assert_eq!(
+        Option::<Pubkey>::from(
+            receipt_mint_state
+                .get_extension::<TransferHook>()
+                .unwrap()
+                .program_id
+        ),
+        Some(paladin_rewards_program::id())
+    );
+
+    // Assert the extra account metas account was created.
+    let extra_account_metas_account = context
+        .banks_client
+        .get_account(extra_account_metas)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        extra_account_metas_account.owner,
+        paladin_rewards_program::id()
+    );
+
+    // Assert the pool mints receipts.
+    let pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state = bytemuck::from_bytes::<HolderRewardsPool>(&pool_account.data);
+    assert_eq!(pool_state.receipts, 1);
+    assert_eq!(pool_state.receipt_mint_bump_seed, receipt_mint_bump_seed);
+}
@@ -0,0 +1,233 @@
+#![cfg(feature = "test-sbf")]
+
+mod execute_utils;
+mod setup;
+
+use {
+    crate::{
+        execute_utils::{execute_with_payer, execute_with_payer_err},
+        setup::{setup_holder_rewards_account, setup_stake_checkpoints_account, DEPOSIT_AMOUNT},
+    },
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        state::{
+            get_holder_rewards_address, get_stake_checkpoints_address,
+            get_stake_checkpoints_address_and_bump_seed, HolderRewards, StakeCheckpoint,
+            StakeCheckpoints,
+        },
+    },
+    paladin_rewards_program_client::instructions::InitializeStakeCheckpointsBuilder,
+    setup::setup,
+    solana_program_test::*,
+    solana_sdk::{
+        account::{Account, AccountSharedData},
+        clock::Clock,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::TransactionError,
+    },
+};
+
+async fn setup_holder(context: &mut ProgramTestContext, owner: &Keypair) -> Pubkey {
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    setup_holder_rewards_account(
+        context,
+        &owner.pubkey(),
+        &holder_rewards,
+        DEPOSIT_AMOUNT,
+        0,
+        &owner.pubkey(),
+    )
+    .await;
+    holder_rewards
+}
+
+async fn slot(context: &mut ProgramTestContext) -> u64 {
+    context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .slot
+}
+
+#[tokio::test]
+async fn fail_stake_checkpoints_incorrect_address() {
+    let owner = Keypair::new();
+
+    let mut context = setup().start_with_context().await;
+    let holder_rewards = setup_holder(&mut context, &owner).await;
+
+    // Stake checkpoints derived from a different owner.
+    let stake_checkpoints =
+        get_stake_checkpoints_address(&Pubkey::new_unique(), &paladin_rewards_program::id());
+
+    let instruction = InitializeStakeCheckpointsBuilder::new()
+        .holder_rewards(holder_rewards)
+        .stake_checkpoints(stake_checkpoints)
+        .owner(owner.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectStakeCheckpointsAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_stake_checkpoints_already_initialized() {
+    let owner = Keypair::new();
+
+    let mut context = setup().start_with_context().await;
+    let holder_rewards = setup_holder(&mut context, &owner).await;
+    let stake_checkpoints =
+        get_stake_checkpoints_address(&owner.pubkey(), &paladin_rewards_program::id());
+    setup_stake_checkpoints_account(
+        &mut context,
+        &owner.pubkey(),
+        &holder_rewards,
+        &stake_checkpoints,
+        &[(0, DEPOSIT_AMOUNT)],
+    )
+    .await;
+
+    let instruction = InitializeStakeCheckpointsBuilder::new()
+        .holder_rewards(holder_rewards)
+        .stake_checkpoints(stake_checkpoints)
+        .owner(owner.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::AccountAlreadyInitialized)
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let owner = Keypair::new();
+
+    let mut context = setup().start_with_context().await;
+    let holder_rewards = setup_holder(&mut context, &owner).await;
+    let (stake_checkpoints, bump_seed) = get_stake_checkpoints_address_and_bump_seed(
+        &owner.pubkey(),
+        &paladin_rewards_program::id(),
+    );
+
+    let instruction = InitializeStakeCheckpointsBuilder::new()
+        .holder_rewards(holder_rewards)
+        .stake_checkpoints(stake_checkpoints)
+        .owner(owner.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&owner)).await;
+
+    // Assert the account was created, seeded with the current balance.
+    let slot = slot(&mut context).await;
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let stake_checkpoints_account = context
+        .banks_client
+        .get_account(stake_checkpoints)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        stake_checkpoints_account.owner,
+        paladin_rewards_program::id()
+    );
+    assert_eq!(
+        stake_checkpoints_account.lamports,
+        rent.minimum_balance(StakeCheckpoints::LEN)
+    );
+    let stake_checkpoints_state =
+        bytemuck::from_bytes::<StakeCheckpoints>(&stake_checkpoints_account.data);
+    assert_eq!(stake_checkpoints_state.bump_seed, bump_seed);
+    assert_eq!(stake_checkpoints_state.len, 1);
+    assert_eq!(
+        stake_checkpoints_state.latest(),
+        Some(&StakeCheckpoint {
+            slot,
+            deposited: DEPOSIT_AMOUNT,
+        })
+    );
+
+    // Assert deposits and withdrawals must now record checkpoints.
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    assert_eq!(holder_rewards_state.stake_checkpoints, 1);
+}
+
+#[tokio::test]
+async fn success_resumes_existing_checkpoints() {
+    let owner = Keypair::new();
+
+    let mut context = setup().start_with_context().await;
+    let holder_rewards = setup_holder(&mut context, &owner).await;
+
+    // Checkpoints kept from a closed holder rewards account, which withdrew
+    // everything at slot 5.
+    let (stake_checkpoints, bump_seed) = get_stake_checkpoints_address_and_bump_seed(
+        &owner.pubkey(),
+        &paladin_rewards_program::id(),
+    );
+    let mut state: StakeCheckpoints = bytemuck::Zeroable::zeroed();
+    state.bump_seed = bump_seed;
+    state.push(1, DEPOSIT_AMOUNT / 2);
+    state.push(5, 0);
+    let data = bytemuck::bytes_of(&state).to_vec();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    context.set_account(
+        &stake_checkpoints,
+        &AccountSharedData::from(Account {
+            lamports: rent.minimum_balance(data.len()),
+            data,
+            owner: paladin_rewards_program::id(),
+            ..Account::default()
+        }),
+    );
+    context.warp_to_slot(10).unwrap();
+
+    let instruction = InitializeStakeCheckpointsBuilder::new()
+        .holder_rewards(holder_rewards)
+        .stake_checkpoints(stake_checkpoints)
+        .owner(owner.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&owner)).await;
+
+    // Assert the past balances were kept.
+    let slot = slot(&mut context).await;
+    let stake_checkpoints_account = context
+        .banks_client
+        .get_account(stake_checkpoints)
+        .await
+        .unwrap()
+        .unwrap();
+    let stake_checkpoints_state =
+        bytemuck::from_bytes::<StakeCheckpoints>(&stake_checkpoints_account.data);
+    assert_eq!(stake_checkpoints_state.len, 3);
+    assert_eq!(
+        stake_checkpoints_state.deposited_at(1),
+        Some(DEPOSIT_AMOUNT / 2)
+    );
+    assert_eq!(stake_checkpoints_state.deposited_at(5), Some(0));
+    assert_eq!(
+        stake_checkpoints_state.deposited_at(slot),
+        Some(DEPOSIT_AMOUNT)
+    );
+}
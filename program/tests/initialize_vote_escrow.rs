@@ -0,0 +1,228 @@
+#![cfg(feature = "test-sbf")]
+
+mod execute_utils;
+mod setup;
+
+use {
+    crate::{
+        execute_utils::{execute_with_payer, execute_with_payer_err},
+        setup::{
+            setup_holder_rewards_pool_account, setup_lockup_tiers_account,
+            setup_vote_escrow_account, update_holder_rewards_pool,
+        },
+    },
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        state::{
+            get_holder_rewards_pool_address, get_lockup_tiers_address, get_vote_escrow_address,
+            get_vote_escrow_address_and_bump_seed, LockupTier, LockupTiers, VoteEscrow,
+        },
+    },
+    paladin_rewards_program_client::instructions::InitializeVoteEscrowBuilder,
+    setup::setup,
+    solana_program_test::*,
+    solana_sdk::{
+        clock::Clock, instruction::InstructionError, pubkey::Pubkey, signature::Keypair,
+        signer::Signer, transaction::TransactionError,
+    },
+};
+
+const TIERS: [LockupTier; 4] = [
+    LockupTier {
+        duration: 30 * 24 * 60 * 60,
+        multiplier_bps: 12_500,
+    },
+    LockupTier {
+        duration: 365 * 24 * 60 * 60,
+        multiplier_bps: 20_000,
+    },
+    LockupTier {
+        duration: 0,
+        multiplier_bps: 0,
+    },
+    LockupTier {
+        duration: 0,
+        multiplier_bps: 0,
+    },
+];
+
+struct Accounts {
+    mint: Pubkey,
+    authority: Keypair,
+    holder_rewards_pool: Pubkey,
+    lockup_tiers: Pubkey,
+}
+
+// Sets up a pool with lockup tiers.
+async fn setup_accounts(context: &mut ProgramTestContext) -> Accounts {
+    let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
+
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    setup_holder_rewards_pool_account(context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+    update_holder_rewards_pool(context, &holder_rewards_pool, |pool_state| {
+        pool_state.authority = authority.pubkey()
+    })
+    .await;
+
+    let lockup_tiers =
+        get_lockup_tiers_address(&holder_rewards_pool, &paladin_rewards_program::id());
+    setup_lockup_tiers_account(context, &holder_rewards_pool, &lockup_tiers, TIERS).await;
+
+    Accounts {
+        mint,
+        authority,
+        holder_rewards_pool,
+        lockup_tiers,
+    }
+}
+
+#[tokio::test]
+async fn fail_incorrect_authority() {
+    let mut context = setup().start_with_context().await;
+    let accounts = setup_accounts(&mut context).await;
+    let vote_escrow = get_vote_escrow_address(
+        &accounts.holder_rewards_pool,
+        &paladin_rewards_program::id(),
+    );
+
+    let authority = Keypair::new(); // Not the pool authority.
+    let instruction = InitializeVoteEscrowBuilder::new()
+        .holder_rewards_pool(accounts.holder_rewards_pool)
+        .lockup_tiers(accounts.lockup_tiers)
+        .vote_escrow(vote_escrow)
+        .mint(accounts.mint)
+        .authority(authority.pubkey())
+        .payer(context.payer.pubkey())
+        .reward_weighting(false)
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&authority)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectPoolAuthority as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_vote_escrow_incorrect_address() {
+    let mut context = setup().start_with_context().await;
+    let accounts = setup_accounts(&mut context).await;
+    let vote_escrow = Pubkey::new_unique(); // Incorrect vote escrow address.
+
+    let instruction = InitializeVoteEscrowBuilder::new()
+        .holder_rewards_pool(accounts.holder_rewards_pool)
+        .lockup_tiers(accounts.lockup_tiers)
+        .vote_escrow(vote_escrow)
+        .mint(accounts.mint)
+        .authority(accounts.authority.pubkey())
+        .payer(context.payer.pubkey())
+        .reward_weighting(false)
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&accounts.authority)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectVoteEscrowAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_vote_escrow_already_initialized() {
+    let mut context = setup().start_with_context().await;
+    let accounts = setup_accounts(&mut context).await;
+    let vote_escrow = get_vote_escrow_address(
+        &accounts.holder_rewards_pool,
+        &paladin_rewards_program::id(),
+    );
+    setup_vote_escrow_account(
+        &mut context,
+        &accounts.holder_rewards_pool,
+        &accounts.lockup_tiers,
+        &vote_escrow,
+        false,
+        0,
+    )
+    .await;
+
+    let instruction = InitializeVoteEscrowBuilder::new()
+        .holder_rewards_pool(accounts.holder_rewards_pool)
+        .lockup_tiers(accounts.lockup_tiers)
+        .vote_escrow(vote_escrow)
+        .mint(accounts.mint)
+        .authority(accounts.authority.pubkey())
+        .payer(context.payer.pubkey())
+        .reward_weighting(false)
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&accounts.authority)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::AccountAlreadyInitialized)
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let mut context = setup().start_with_context().await;
+    let accounts = setup_accounts(&mut context).await;
+    let (vote_escrow, bump_seed) = get_vote_escrow_address_and_bump_seed(
+        &accounts.holder_rewards_pool,
+        &paladin_rewards_program::id(),
+    );
+
+    let instruction = InitializeVoteEscrowBuilder::new()
+        .holder_rewards_pool(accounts.holder_rewards_pool)
+        .lockup_tiers(accounts.lockup_tiers)
+        .vote_escrow(vote_escrow)
+        .mint(accounts.mint)
+        .authority(accounts.authority.pubkey())
+        .payer(context.payer.pubkey())
+        .reward_weighting(true)
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&accounts.authority)).await;
+
+    let now = context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp;
+
+    // Assert the vote escrow account was created, checkpointed now.
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let vote_escrow_account = context
+        .banks_client
+        .get_account(vote_escrow)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(vote_escrow_account.owner, paladin_rewards_program::id());
+    assert_eq!(
+        vote_escrow_account.lamports,
+        rent.minimum_balance(VoteEscrow::LEN)
+    );
+    let vote_escrow_state = bytemuck::from_bytes::<VoteEscrow>(&vote_escrow_account.data);
+    assert_eq!(vote_escrow_state.last_checkpoint, now);
+    assert_eq!(vote_escrow_state.locked, 0);
+    assert_eq!(vote_escrow_state.bump_seed, bump_seed);
+    assert_eq!(vote_escrow_state.reward_weighting, 1);
+
+    // Assert lockups must now be recorded in the vote escrow.
+    let lockup_tiers_account = context
+        .banks_client
+        .get_account(accounts.lockup_tiers)
+        .await
+        .unwrap()
+        .unwrap();
+    let lockup_tiers_state = bytemuck::from_bytes::<LockupTiers>(&lockup_tiers_account.data);
+    assert_eq!(lockup_tiers_state.tiers, TIERS);
+    assert_eq!(lockup_tiers_state.vote_escrow, 1);
+}
@@ -0,0 +1,511 @@
+#![cfg(feature = "test-sbf")]
+
+mod execute_utils;
+mod setup;
+
+use {
+    crate::{
+        execute_utils::{execute_with_payer, execute_with_payer_err},
+        setup::{
+            send_rewards_to_pool, set_holder_rewards_lockup, setup_holder_rewards_account,
+            setup_holder_rewards_pool_account, setup_lockup_tiers_account,
+            setup_vote_escrow_account, DEPOSIT_AMOUNT,
+        },
+    },
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        state::{
+            get_holder_rewards_address, get_holder_rewards_pool_address, get_lockup_tiers_address,
+            get_vote_escrow_address, voting_power, voting_power_end, HolderRewards,
+            HolderRewardsPool, LockupTier, VoteEscrow, MAX_LOCKUP_DURATION,
+        },
+    },
+    paladin_rewards_program_client::instructions::LockBuilder,
+    setup::setup,
+    solana_program_test::*,
+    solana_sdk::{
+        clock::Clock, instruction::InstructionError, pubkey::Pubkey, signature::Keypair,
+        signer::Signer, transaction::TransactionError,
+    },
+};
+
+const SHORT_TIER: LockupTier = LockupTier {
+    duration: 1_000,
+    multiplier_bps: 15_000,
+};
+const LONG_TIER: LockupTier = LockupTier {
+    duration: 10_000,
+    multiplier_bps: 20_000,
+};
+const REWARDS_AMOUNT: u64 = 100_000_000_000_000;
+
+struct Accounts {
+    owner: Keypair,
+    mint: Pubkey,
+    holder_rewards_pool: Pubkey,
+    holder_rewards: Pubkey,
+    lockup_tiers: Pubkey,
+}
+
+// Sets up a pool with lockup tiers and a single holder who deposited all of
+// the pool's tokens.
+async fn setup_accounts(context: &mut ProgramTestContext) -> Accounts {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    setup_holder_rewards_pool_account(context, &mint, &holder_rewards_pool, 0, 0, DEPOSIT_AMOUNT)
+        .await;
+
+    let lockup_tiers =
+        get_lockup_tiers_address(&holder_rewards_pool, &paladin_rewards_program::id());
+    setup_lockup_tiers_account(
+        context,
+        &holder_rewards_pool,
+        &lockup_tiers,
+        [
+            SHORT_TIER,
+            LONG_TIER,
+            LockupTier::default(),
+            LockupTier::default(),
+        ],
+    )
+    .await;
+
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    setup_holder_rewards_account(
+        context,
+        &owner.pubkey(),
+        &holder_rewards,
+        DEPOSIT_AMOUNT,
+        0,
+        &owner.pubkey(),
+    )
+    .await;
+
+    Accounts {
+        owner,
+        mint,
+        holder_rewards_pool,
+        holder_rewards,
+        lockup_tiers,
+    }
+}
+
+async fn unix_timestamp(context: &mut ProgramTestContext) -> i64 {
+    context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp
+}
+
+#[tokio::test]
+async fn fail_lockup_duration_too_short() {
+    let mut context = setup().start_with_context().await;
+    let accounts = setup_accounts(&mut context).await;
+    let now = unix_timestamp(&mut context).await;
+
+    let instruction = LockBuilder::new()
+        .holder_rewards_pool(accounts.holder_rewards_pool)
+        .holder_rewards(accounts.holder_rewards)
+        .lockup_tiers(accounts.lockup_tiers)
+        .mint(accounts.mint)
+        .owner(accounts.owner.pubkey())
+        .amount(DEPOSIT_AMOUNT)
+        .unlock_timestamp(now + SHORT_TIER.duration as i64 - 1)
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&accounts.owner)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::LockupDurationTooShort as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_lockup_cannot_be_shortened() {
+    let mut context = setup().start_with_context().await;
+    let accounts = setup_accounts(&mut context).await;
+    let now = unix_timestamp(&mut context).await;
+
+    let unlock_timestamp = now + LONG_TIER.duration as i64;
+    set_holder_rewards_lockup(
+        &mut context,
+        &accounts.holder_rewards_pool,
+        &accounts.holder_rewards,
+        DEPOSIT_AMOUNT / 2,
+        unlock_timestamp,
+        LONG_TIER.multiplier_bps,
+    )
+    .await;
+
+    let instruction = LockBuilder::new()
+        .holder_rewards_pool(accounts.holder_rewards_pool)
+        .holder_rewards(accounts.holder_rewards)
+        .lockup_tiers(accounts.lockup_tiers)
+        .mint(accounts.mint)
+        .owner(accounts.owner.pubkey())
+        .amount(DEPOSIT_AMOUNT / 2)
+        .unlock_timestamp(unlock_timestamp - 1)
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&accounts.owner)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::LockupCannotBeShortened as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_lock_exceeds_unlocked() {
+    let mut context = setup().start_with_context().await;
+    let accounts = setup_accounts(&mut context).await;
+    let now = unix_timestamp(&mut context).await;
+
+    let unlock_timestamp = now + LONG_TIER.duration as i64;
+    set_holder_rewards_lockup(
+        &mut context,
+        &accounts.holder_rewards_pool,
+        &accounts.holder_rewards,
+        DEPOSIT_AMOUNT / 2,
+        unlock_timestamp,
+        LONG_TIER.multiplier_bps,
+    )
+    .await;
+
+    let instruction = LockBuilder::new()
+        .holder_rewards_pool(accounts.holder_rewards_pool)
+        .holder_rewards(accounts.holder_rewards)
+        .lockup_tiers(accounts.lockup_tiers)
+        .mint(accounts.mint)
+        .owner(accounts.owner.pubkey())
+        .amount(DEPOSIT_AMOUNT / 2 + 1)
+        .unlock_timestamp(unlock_timestamp)
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&accounts.owner)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::LockExceedsUnlocked as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let mut context = setup().start_with_context().await;
+    let accounts = setup_accounts(&mut context).await;
+    let now = unix_timestamp(&mut context).await;
+
+    // Rewards received before the lockup are earned at the unlocked weight.
+    send_rewards_to_pool(&mut context, &accounts.holder_rewards_pool, REWARDS_AMOUNT).await;
+
+    // Qualifies for the short tier only.
+    let unlock_timestamp = now + LONG_TIER.duration as i64 - 1;
+    let instruction = LockBuilder::new()
+        .holder_rewards_pool(accounts.holder_rewards_pool)
+        .holder_rewards(accounts.holder_rewards)
+        .lockup_tiers(accounts.lockup_tiers)
+        .mint(accounts.mint)
+        .owner(accounts.owner.pubkey())
+        .amount(DEPOSIT_AMOUNT / 2)
+        .unlock_timestamp(unlock_timestamp)
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&accounts.owner)).await;
+
+    // Assert the pending rewards were harvested.
+    let owner_lamports = context
+        .banks_client
+        .get_account(accounts.owner.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(owner_lamports, REWARDS_AMOUNT);
+
+    // Assert the lockup was recorded with the short tier's multiplier.
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(accounts.holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    assert_eq!(holder_rewards_state.locked_amount, DEPOSIT_AMOUNT / 2);
+    assert_eq!(holder_rewards_state.unlock_timestamp, unlock_timestamp);
+    assert_eq!(
+        holder_rewards_state.lockup_multiplier_bps,
+        SHORT_TIER.multiplier_bps
+    );
+
+    // Assert the pool's weight includes the lockup's bonus: half of the
+    // deposit at 1.5x.
+    let pool_account = context
+        .banks_client
+        .get_account(accounts.holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state = bytemuck::from_bytes::<HolderRewardsPool>(&pool_account.data);
+    assert_eq!(pool_state.total_lockup_bonus_weight, DEPOSIT_AMOUNT / 4);
+    assert_eq!(
+        pool_state.total_weight(),
+        Some(DEPOSIT_AMOUNT + DEPOSIT_AMOUNT / 4)
+    );
+}
+
+#[tokio::test]
+async fn success_extend() {
+    let mut context = setup().start_with_context().await;
+    let accounts = setup_accounts(&mut context).await;
+    let now = unix_timestamp(&mut context).await;
+
+    set_holder_rewards_lockup(
+        &mut context,
+        &accounts.holder_rewards_pool,
+        &accounts.holder_rewards,
+        DEPOSIT_AMOUNT / 2,
+        now + SHORT_TIER.duration as i64,
+        SHORT_TIER.multiplier_bps,
+    )
+    .await;
+
+    // Lock the rest of the deposit, extending into the long tier.
+    let unlock_timestamp = now + LONG_TIER.duration as i64;
+    let instruction = LockBuilder::new()
+        .holder_rewards_pool(accounts.holder_rewards_pool)
+        .holder_rewards(accounts.holder_rewards)
+        .lockup_tiers(accounts.lockup_tiers)
+        .mint(accounts.mint)
+        .owner(accounts.owner.pubkey())
+        .amount(DEPOSIT_AMOUNT / 2)
+        .unlock_timestamp(unlock_timestamp)
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&accounts.owner)).await;
+
+    // Assert the whole deposit is now locked at 2x.
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(accounts.holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    assert_eq!(holder_rewards_state.locked_amount, DEPOSIT_AMOUNT);
+    assert_eq!(holder_rewards_state.unlock_timestamp, unlock_timestamp);
+    assert_eq!(
+        holder_rewards_state.lockup_multiplier_bps,
+        LONG_TIER.multiplier_bps
+    );
+
+    // Assert the previous lockup's bonus was replaced, not added to.
+    let pool_account = context
+        .banks_client
+        .get_account(accounts.holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state = bytemuck::from_bytes::<HolderRewardsPool>(&pool_account.data);
+    assert_eq!(pool_state.total_lockup_bonus_weight, DEPOSIT_AMOUNT);
+}
+
+#[tokio::test]
+async fn fail_lockup_duration_too_long() {
+    let mut context = setup().start_with_context().await;
+    let accounts = setup_accounts(&mut context).await;
+    let now = unix_timestamp(&mut context).await;
+
+    let instruction = LockBuilder::new()
+        .holder_rewards_pool(accounts.holder_rewards_pool)
+        .holder_rewards(accounts.holder_rewards)
+        .lockup_tiers(accounts.lockup_tiers)
+        .mint(accounts.mint)
+        .owner(accounts.owner.pubkey())
+        .amount(DEPOSIT_AMOUNT)
+        .unlock_timestamp(now + MAX_LOCKUP_DURATION as i64 + 1)
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&accounts.owner)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::LockupDurationTooLong as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_vote_escrow_required() {
+    let mut context = setup().start_with_context().await;
+    let accounts = setup_accounts(&mut context).await;
+    let now = unix_timestamp(&mut context).await;
+
+    let vote_escrow = get_vote_escrow_address(
+        &accounts.holder_rewards_pool,
+        &paladin_rewards_program::id(),
+    );
+    setup_vote_escrow_account(
+        &mut context,
+        &accounts.holder_rewards_pool,
+        &accounts.lockup_tiers,
+        &vote_escrow,
+        false,
+        now,
+    )
+    .await;
+
+    // Vote escrow omitted.
+    let instruction = LockBuilder::new()
+        .holder_rewards_pool(accounts.holder_rewards_pool)
+        .holder_rewards(accounts.holder_rewards)
+        .lockup_tiers(accounts.lockup_tiers)
+        .mint(accounts.mint)
+        .owner(accounts.owner.pubkey())
+        .amount(DEPOSIT_AMOUNT)
+        .unlock_timestamp(now + LONG_TIER.duration as i64)
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&accounts.owner)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::VoteEscrowRequired as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_records_voting_power() {
+    let mut context = setup().start_with_context().await;
+    let accounts = setup_accounts(&mut context).await;
+    let now = unix_timestamp(&mut context).await;
+
+    let vote_escrow = get_vote_escrow_address(
+        &accounts.holder_rewards_pool,
+        &paladin_rewards_program::id(),
+    );
+    setup_vote_escrow_account(
+        &mut context,
+        &accounts.holder_rewards_pool,
+        &accounts.lockup_tiers,
+        &vote_escrow,
+        false,
+        now,
+    )
+    .await;
+
+    let unlock_timestamp = now + MAX_LOCKUP_DURATION as i64 / 2;
+    let instruction = LockBuilder::new()
+        .holder_rewards_pool(accounts.holder_rewards_pool)
+        .holder_rewards(accounts.holder_rewards)
+        .lockup_tiers(accounts.lockup_tiers)
+        .mint(accounts.mint)
+        .owner(accounts.owner.pubkey())
+        .vote_escrow(Some(vote_escrow))
+        .amount(DEPOSIT_AMOUNT)
+        .unlock_timestamp(unlock_timestamp)
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&accounts.owner)).await;
+
+    // Assert the lockup keeps its tier multiplier and records its voting
+    // power, ending at the start of the unlock's period.
+    let end = voting_power_end(unlock_timestamp);
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(accounts.holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    assert_eq!(
+        holder_rewards_state.lockup_multiplier_bps,
+        LONG_TIER.multiplier_bps
+    );
+    assert_eq!(holder_rewards_state.voting_power_locked, DEPOSIT_AMOUNT);
+    assert_eq!(holder_rewards_state.voting_power_end, end);
+
+    // Assert the pool's total voting power is the holder's.
+    let vote_escrow_account = context
+        .banks_client
+        .get_account(vote_escrow)
+        .await
+        .unwrap()
+        .unwrap();
+    let vote_escrow_state = bytemuck::from_bytes::<VoteEscrow>(&vote_escrow_account.data);
+    assert_eq!(vote_escrow_state.locked, DEPOSIT_AMOUNT);
+    assert_eq!(
+        vote_escrow_state.total_voting_power(now),
+        voting_power(DEPOSIT_AMOUNT, end, now)
+    );
+}
+
+#[tokio::test]
+async fn success_reward_weighting() {
+    let mut context = setup().start_with_context().await;
+    let accounts = setup_accounts(&mut context).await;
+    let now = unix_timestamp(&mut context).await;
+
+    let vote_escrow = get_vote_escrow_address(
+        &accounts.holder_rewards_pool,
+        &paladin_rewards_program::id(),
+    );
+    setup_vote_escrow_account(
+        &mut context,
+        &accounts.holder_rewards_pool,
+        &accounts.lockup_tiers,
+        &vote_escrow,
+        true,
+        now,
+    )
+    .await;
+
+    let unlock_timestamp = now + MAX_LOCKUP_DURATION as i64 / 2;
+    let instruction = LockBuilder::new()
+        .holder_rewards_pool(accounts.holder_rewards_pool)
+        .holder_rewards(accounts.holder_rewards)
+        .lockup_tiers(accounts.lockup_tiers)
+        .mint(accounts.mint)
+        .owner(accounts.owner.pubkey())
+        .vote_escrow(Some(vote_escrow))
+        .amount(DEPOSIT_AMOUNT)
+        .unlock_timestamp(unlock_timestamp)
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&accounts.owner)).await;
+
+    // Assert the multiplier follows voting power rather than the tiers.
+    let multiplier_bps = VoteEscrow::reward_multiplier_bps(voting_power_end(unlock_timestamp), now);
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(accounts.holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    assert_eq!(holder_rewards_state.lockup_multiplier_bps, multiplier_bps);
+
+    let pool_account = context
+        .banks_client
+        .get_account(accounts.holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state = bytemuck::from_bytes::<HolderRewardsPool>(&pool_account.data);
+    assert_eq!(
+        pool_state.total_lockup_bonus_weight,
+        holder_rewards_state.lockup_bonus_weight().unwrap()
+    );
+}
@@ -0,0 +1,185 @@
+#![cfg(feature = "test-sbf")]
+
+mod execute_utils;
+mod setup;
+
+use {
+    crate::{
+        execute_utils::{execute_with_payer, execute_with_payer_err},
+        setup::setup_holder_rewards_account,
+    },
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        state::{get_holder_rewards_address_and_bump_seed, HolderRewards},
+    },
+    paladin_rewards_program_client::instructions::MigrateHolderRewardsBuilder,
+    setup::setup,
+    solana_program_test::*,
+    solana_sdk::{
+        account::{Account, AccountSharedData},
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signer::Signer,
+        transaction::TransactionError,
+    },
+};
+
+const DEPOSITED: u64 = 100_000;
+const LAST_ACCUMULATED_REWARDS_PER_TOKEN: u128 = 1_000_000;
+
+// Sets up a holder rewards account with the baseline layout: the rate it
+// last harvested at, its deposit and padding.
+async fn setup_baseline_holder_rewards_account(
+    context: &mut ProgramTestContext,
+    holder_rewards: &Pubkey,
+) -> HolderRewards {
+    let mut data = Vec::with_capacity(HolderRewards::BASELINE_LEN);
+    data.extend_from_slice(&LAST_ACCUMULATED_REWARDS_PER_TOKEN.to_le_bytes());
+    data.extend_from_slice(&DEPOSITED.to_le_bytes());
+    data.extend_from_slice(&[0; 8]);
+    assert_eq!(data.len(), HolderRewards::BASELINE_LEN);
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    context.set_account(
+        holder_rewards,
+        &AccountSharedData::from(Account {
+            lamports: rent.minimum_balance(HolderRewards::BASELINE_LEN),
+            data,
+            owner: paladin_rewards_program::id(),
+            ..Account::default()
+        }),
+    );
+
+    HolderRewards {
+        last_accumulated_rewards_per_token: LAST_ACCUMULATED_REWARDS_PER_TOKEN,
+        deposited: DEPOSITED,
+        ..HolderRewards::default()
+    }
+}
+
+#[tokio::test]
+async fn fail_holder_rewards_incorrect_owner() {
+    let owner = Pubkey::new_unique();
+    let (holder_rewards, _) =
+        get_holder_rewards_address_and_bump_seed(&owner, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_baseline_holder_rewards_account(&mut context, &holder_rewards).await;
+
+    // Reassign the holder rewards account to a different program.
+    {
+        let mut account = context
+            .banks_client
+            .get_account(holder_rewards)
+            .await
+            .unwrap()
+            .unwrap();
+        account.owner = Pubkey::new_unique();
+        context.set_account(&holder_rewards, &account.into());
+    }
+
+    let instruction = MigrateHolderRewardsBuilder::new()
+        .holder_rewards(holder_rewards)
+        .owner(owner)
+        .payer(context.payer.pubkey())
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, None).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountOwner)
+    );
+}
+
+#[tokio::test]
+async fn fail_holder_rewards_incorrect_address() {
+    let owner = Pubkey::new_unique();
+    let (holder_rewards, _) =
+        get_holder_rewards_address_and_bump_seed(&owner, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_baseline_holder_rewards_account(&mut context, &holder_rewards).await;
+
+    let instruction = MigrateHolderRewardsBuilder::new()
+        .holder_rewards(holder_rewards)
+        .owner(Pubkey::new_unique()) // Incorrect owner.
+        .payer(context.payer.pubkey())
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, None).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectHolderRewardsAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_holder_rewards_already_migrated() {
+    let owner = Pubkey::new_unique();
+    let (holder_rewards, _) =
+        get_holder_rewards_address_and_bump_seed(&owner, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_account(&mut context, &owner, &holder_rewards, 0, 0, &owner).await;
+
+    let instruction = MigrateHolderRewardsBuilder::new()
+        .holder_rewards(holder_rewards)
+        .owner(owner)
+        .payer(context.payer.pubkey())
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, None).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::HolderRewardsAlreadyMigrated as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let owner = Pubkey::new_unique();
+    let (holder_rewards, bump_seed) =
+        get_holder_rewards_address_and_bump_seed(&owner, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    let baseline_state = setup_baseline_holder_rewards_account(&mut context, &holder_rewards).await;
+
+    let instruction = MigrateHolderRewardsBuilder::new()
+        .holder_rewards(holder_rewards)
+        .owner(owner)
+        .payer(context.payer.pubkey())
+        .instruction();
+    execute_with_payer(&mut context, instruction, None).await;
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // Assert the account was resized and topped up to rent exemption.
+    assert_eq!(holder_rewards_account.data.len(), HolderRewards::LEN);
+    assert_eq!(
+        holder_rewards_account.lamports,
+        rent.minimum_balance(HolderRewards::LEN)
+    );
+
+    // Assert the existing state was kept, the fields the baseline layout
+    // lacks were backfilled, and the new fields were zero-filled.
+    assert_eq!(
+        bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data),
+        &HolderRewards {
+            rent_payer: owner,
+            bump_seed,
+            ..baseline_state
+        }
+    );
+}
@@ -0,0 +1,360 @@
+#![cfg(feature = "test-sbf")]
+
+mod execute_utils;
+mod setup;
+
+use {
+    crate::{
+        execute_utils::{execute_with_payer, execute_with_payer_err},
+        setup::{setup_holder_rewards_pool_account, setup_mint, setup_token_account},
+    },
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        state::{get_holder_rewards_pool_address_and_bump_seed, HolderRewardsPool},
+    },
+    paladin_rewards_program_client::instructions::MigrateHolderRewardsPoolBuilder,
+    setup::setup,
+    solana_program_test::*,
+    solana_sdk::{
+        account::{Account, AccountSharedData},
+        clock::Clock,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::TransactionError,
+    },
+    spl_associated_token_account::get_associated_token_address,
+};
+
+const UNPROCESSED_REWARDS: u64 = 500_000;
+const DEPOSITED: u64 = 100_000;
+
+// Sets up a pool account with the baseline layout, holding rewards not yet
+// accounted for in its rate.
+async fn setup_baseline_holder_rewards_pool_account(
+    context: &mut ProgramTestContext,
+    holder_rewards_pool_address: &Pubkey,
+) -> HolderRewardsPool {
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports_last = rent.minimum_balance(HolderRewardsPool::BASELINE_LEN);
+    let legacy_state = HolderRewardsPool {
+        accumulated_rewards_per_token: 1_000,
+        lamports_last,
+        duna_document_hash: [1; 32],
+        ..bytemuck::Zeroable::zeroed()
+    };
+    let data = bytemuck::bytes_of(&legacy_state)[..HolderRewardsPool::BASELINE_LEN].to_vec();
+
+    context.set_account(
+        holder_rewards_pool_address,
+        &AccountSharedData::from(Account {
+            lamports: lamports_last + UNPROCESSED_REWARDS,
+            data,
+            owner: paladin_rewards_program::id(),
+            ..Account::default()
+        }),
+    );
+
+    legacy_state
+}
+
+#[tokio::test]
+async fn fail_holder_rewards_pool_incorrect_owner() {
+    let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let (holder_rewards_pool, _) =
+        get_holder_rewards_pool_address_and_bump_seed(&mint, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_baseline_holder_rewards_pool_account(&mut context, &holder_rewards_pool).await;
+
+    // Reassign the pool to a different program.
+    {
+        let mut account = context
+            .banks_client
+            .get_account(holder_rewards_pool)
+            .await
+            .unwrap()
+            .unwrap();
+        account.owner = Pubkey::new_unique();
+        context.set_account(&holder_rewards_pool, &account.into());
+    }
+
+    let instruction = MigrateHolderRewardsPoolBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(Pubkey::new_unique())
+        .mint(mint)
+        .authority(authority.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&authority)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountOwner)
+    );
+}
+
+#[tokio::test]
+async fn fail_holder_rewards_pool_incorrect_address() {
+    let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let (holder_rewards_pool, _) =
+        get_holder_rewards_pool_address_and_bump_seed(&mint, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_baseline_holder_rewards_pool_account(&mut context, &holder_rewards_pool).await;
+
+    let instruction = MigrateHolderRewardsPoolBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(Pubkey::new_unique())
+        .mint(Pubkey::new_unique()) // Incorrect mint.
+        .authority(authority.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&authority)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectHolderRewardsPoolAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_authority_not_signer() {
+    let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let (holder_rewards_pool, _) =
+        get_holder_rewards_pool_address_and_bump_seed(&mint, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_baseline_holder_rewards_pool_account(&mut context, &holder_rewards_pool).await;
+    setup_mint(&mut context, &mint, DEPOSITED, Some(authority.pubkey())).await;
+
+    let mut instruction = MigrateHolderRewardsPoolBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(get_associated_token_address(
+            &holder_rewards_pool,
+            &mint,
+        ))
+        .mint(mint)
+        .authority(authority.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+    instruction.accounts[3].is_signer = false; // Authority not signer.
+    let err = execute_with_payer_err(&mut context, instruction, None).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_authority_not_mint_authority() {
+    let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let (holder_rewards_pool, _) =
+        get_holder_rewards_pool_address_and_bump_seed(&mint, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_baseline_holder_rewards_pool_account(&mut context, &holder_rewards_pool).await;
+    // A different mint authority.
+    setup_mint(&mut context, &mint, DEPOSITED, Some(Pubkey::new_unique())).await;
+
+    let instruction = MigrateHolderRewardsPoolBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(get_associated_token_address(
+            &holder_rewards_pool,
+            &mint,
+        ))
+        .mint(mint)
+        .authority(authority.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&authority)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectPoolAuthority as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_holder_rewards_pool_token_account_incorrect_address() {
+    let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let (holder_rewards_pool, _) =
+        get_holder_rewards_pool_address_and_bump_seed(&mint, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_baseline_holder_rewards_pool_account(&mut context, &holder_rewards_pool).await;
+    setup_mint(&mut context, &mint, DEPOSITED, Some(authority.pubkey())).await;
+    // A pool-owned token account that is not the pool's associated token
+    // account.
+    let pool_token_account = Pubkey::new_unique();
+    setup_token_account(
+        &mut context,
+        &pool_token_account,
+        &holder_rewards_pool,
+        &mint,
+        DEPOSITED,
+    )
+    .await;
+
+    let instruction = MigrateHolderRewardsPoolBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token_account)
+        .mint(mint)
+        .authority(authority.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&authority)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectPoolTokenAccountAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_holder_rewards_pool_token_account_incorrect_owner() {
+    let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let (holder_rewards_pool, _) =
+        get_holder_rewards_pool_address_and_bump_seed(&mint, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_baseline_holder_rewards_pool_account(&mut context, &holder_rewards_pool).await;
+    setup_mint(&mut context, &mint, DEPOSITED, Some(authority.pubkey())).await;
+    let pool_token_account = get_associated_token_address(&holder_rewards_pool, &mint);
+    setup_token_account(
+        &mut context,
+        &pool_token_account,
+        &Pubkey::new_unique(), // Incorrect owner.
+        &mint,
+        DEPOSITED,
+    )
+    .await;
+
+    let instruction = MigrateHolderRewardsPoolBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token_account)
+        .mint(mint)
+        .authority(authority.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&authority)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::TokenAccountOwnerMissmatch as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_holder_rewards_pool_already_migrated() {
+    let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let (holder_rewards_pool, _) =
+        get_holder_rewards_pool_address_and_bump_seed(&mint, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+
+    let instruction = MigrateHolderRewardsPoolBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(Pubkey::new_unique())
+        .mint(mint)
+        .authority(authority.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&authority)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::PoolAlreadyMigrated as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let (holder_rewards_pool, bump_seed) =
+        get_holder_rewards_pool_address_and_bump_seed(&mint, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    let legacy_state =
+        setup_baseline_holder_rewards_pool_account(&mut context, &holder_rewards_pool).await;
+    setup_mint(&mut context, &mint, DEPOSITED, Some(authority.pubkey())).await;
+    let pool_token_account = get_associated_token_address(&holder_rewards_pool, &mint);
+    setup_token_account(
+        &mut context,
+        &pool_token_account,
+        &holder_rewards_pool,
+        &mint,
+        DEPOSITED,
+    )
+    .await;
+
+    let instruction = MigrateHolderRewardsPoolBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token_account)
+        .mint(mint)
+        .authority(authority.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&authority)).await;
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let slot = context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .slot;
+    let pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // Assert the pool was resized and topped up to rent exemption.
+    assert_eq!(pool_account.data.len(), HolderRewardsPool::LEN);
+    assert_eq!(
+        pool_account.lamports,
+        rent.minimum_balance(HolderRewardsPool::LEN) + UNPROCESSED_REWARDS
+    );
+
+    // Assert the existing state was kept, the rent top-up was not counted as
+    // rewards, and the fields the baseline layout lacks were backfilled.
+    assert_eq!(
+        bytemuck::from_bytes::<HolderRewardsPool>(&pool_account.data),
+        &HolderRewardsPool {
+            lamports_last: rent.minimum_balance(HolderRewardsPool::LEN),
+            total_deposited: DEPOSITED,
+            authority: authority.pubkey(),
+            bump_seed,
+            last_update_slot: slot,
+            ..legacy_state
+        }
+    );
+}
@@ -0,0 +1,183 @@
+#![cfg(feature = "test-sbf")]
+
+mod execute_utils;
+mod setup;
+
+use {
+    crate::{
+        execute_utils::{execute_with_payer, execute_with_payer_err},
+        setup::{
+            setup_campaign_account, setup_holder_rewards_pool_account, setup_system_account,
+            CampaignSetup,
+        },
+    },
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        state::{
+            get_campaign_address, get_holder_rewards_pool_address, Campaign, HolderRewardsPool,
+        },
+    },
+    paladin_rewards_program_client::instructions::ReclaimCampaignRewardsBuilder,
+    setup::setup,
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::InstructionError, pubkey::Pubkey, signature::Keypair, signer::Signer,
+        transaction::TransactionError,
+    },
+};
+
+const CAMPAIGN_AMOUNT: u64 = 500_000_000;
+const UNSPENT: u64 = 100_000_000;
+const TOTAL_DEPOSITED: u64 = 250_000_000;
+
+async fn setup_campaign(
+    context: &mut ProgramTestContext,
+    mint: &Pubkey,
+    funder: &Pubkey,
+) -> (Pubkey, Pubkey) {
+    let holder_rewards_pool = get_holder_rewards_pool_address(mint, &paladin_rewards_program::id());
+    let campaign =
+        get_campaign_address(&holder_rewards_pool, funder, &paladin_rewards_program::id());
+
+    setup_holder_rewards_pool_account(context, mint, &holder_rewards_pool, 0, 0, TOTAL_DEPOSITED)
+        .await;
+    setup_system_account(context, funder, 0).await;
+    setup_campaign_account(
+        context,
+        &holder_rewards_pool,
+        funder,
+        &campaign,
+        CampaignSetup {
+            amount: CAMPAIGN_AMOUNT,
+            start_slot: 0,
+            end_slot: 100,
+            unspent: UNSPENT,
+        },
+    )
+    .await;
+
+    (holder_rewards_pool, campaign)
+}
+
+#[tokio::test]
+async fn fail_incorrect_funder() {
+    let mint = Pubkey::new_unique();
+    let funder = Keypair::new();
+    let other = Keypair::new();
+
+    let mut context = setup().start_with_context().await;
+    let (holder_rewards_pool, campaign) =
+        setup_campaign(&mut context, &mint, &funder.pubkey()).await;
+    context.warp_to_slot(200).unwrap();
+
+    let instruction = ReclaimCampaignRewardsBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .campaign(campaign)
+        .mint(mint)
+        .funder(other.pubkey())
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&other)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectCampaignFunder as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_campaign_not_ended() {
+    let mint = Pubkey::new_unique();
+    let funder = Keypair::new();
+
+    let mut context = setup().start_with_context().await;
+    let (holder_rewards_pool, campaign) =
+        setup_campaign(&mut context, &mint, &funder.pubkey()).await;
+    context.warp_to_slot(50).unwrap();
+
+    let instruction = ReclaimCampaignRewardsBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .campaign(campaign)
+        .mint(mint)
+        .funder(funder.pubkey())
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&funder)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::CampaignNotEnded as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let mint = Pubkey::new_unique();
+    let funder = Keypair::new();
+
+    let mut context = setup().start_with_context().await;
+    let (holder_rewards_pool, campaign) =
+        setup_campaign(&mut context, &mint, &funder.pubkey()).await;
+    context.warp_to_slot(200).unwrap();
+
+    let funder_lamports_before = context
+        .banks_client
+        .get_account(funder.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let pool_lamports_before = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let instruction = ReclaimCampaignRewardsBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .campaign(campaign)
+        .mint(mint)
+        .funder(funder.pubkey())
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&funder)).await;
+
+    // Assert the remaining rewards were released into the pool.
+    let pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        pool_account.lamports,
+        pool_lamports_before + CAMPAIGN_AMOUNT - UNSPENT
+    );
+    let pool_state = bytemuck::from_bytes::<HolderRewardsPool>(&pool_account.data);
+    assert_eq!(pool_state.total_rewards_received, CAMPAIGN_AMOUNT - UNSPENT);
+
+    // Assert the unspent rewards and the rent were returned to the funder,
+    // and the campaign was closed.
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let funder_account = context
+        .banks_client
+        .get_account(funder.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        funder_account.lamports,
+        funder_lamports_before + rent.minimum_balance(Campaign::LEN) + UNSPENT
+    );
+    assert!(context
+        .banks_client
+        .get_account(campaign)
+        .await
+        .unwrap()
+        .is_none());
+}
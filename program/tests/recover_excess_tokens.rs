@@ -0,0 +1,206 @@
+#![cfg(feature = "test-sbf")]
+
+mod execute_utils;
+mod setup;
+
+use {
+    crate::{
+        execute_utils::{execute_with_payer, execute_with_payer_err},
+        setup::{
+            setup_holder_rewards_pool_account_with_token_account, setup_token_account,
+            update_holder_rewards_pool, DEPOSIT_AMOUNT,
+        },
+    },
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        state::{get_holder_rewards_pool_address, HolderRewardsPool},
+    },
+    paladin_rewards_program_client::instructions::RecoverExcessTokensBuilder,
+    setup::setup,
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::InstructionError, program_pack::Pack, pubkey::Pubkey, signature::Keypair,
+        signer::Signer, transaction::TransactionError,
+    },
+    spl_associated_token_account::get_associated_token_address,
+    spl_token::state::Account as TokenAccount,
+};
+
+const EXCESS_AMOUNT: u64 = 50_000_000;
+
+async fn setup_pool_with_excess(
+    context: &mut ProgramTestContext,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    excess: u64,
+) -> (Pubkey, Pubkey) {
+    let holder_rewards_pool = get_holder_rewards_pool_address(mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        context,
+        mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+    update_holder_rewards_pool(context, &holder_rewards_pool, |pool_state| {
+        pool_state.authority = *authority
+    })
+    .await;
+
+    // Simulate tokens transferred directly into the pool token account.
+    setup_token_account(
+        context,
+        &pool_token,
+        &holder_rewards_pool,
+        mint,
+        DEPOSIT_AMOUNT + excess,
+    )
+    .await;
+
+    (holder_rewards_pool, pool_token)
+}
+
+#[tokio::test]
+async fn fail_incorrect_authority() {
+    let authority = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    let (holder_rewards_pool, pool_token) =
+        setup_pool_with_excess(&mut context, &mint, &authority.pubkey(), EXCESS_AMOUNT).await;
+
+    let destination = Pubkey::new_unique();
+    setup_token_account(&mut context, &destination, &Pubkey::new_unique(), &mint, 0).await;
+
+    let not_authority = Keypair::new();
+    let instruction = RecoverExcessTokensBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .destination_token_account(destination)
+        .mint(mint)
+        .authority(not_authority.pubkey())
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&not_authority)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectPoolAuthority as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_authority_not_signer() {
+    let authority = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    let (holder_rewards_pool, pool_token) =
+        setup_pool_with_excess(&mut context, &mint, &authority.pubkey(), EXCESS_AMOUNT).await;
+
+    let destination = Pubkey::new_unique();
+    setup_token_account(&mut context, &destination, &Pubkey::new_unique(), &mint, 0).await;
+
+    let mut instruction = RecoverExcessTokensBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .destination_token_account(destination)
+        .mint(mint)
+        .authority(authority.pubkey())
+        .instruction();
+    instruction.accounts[4].is_signer = false;
+    let err = execute_with_payer_err(&mut context, instruction, None).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_no_excess_tokens() {
+    let authority = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    let (holder_rewards_pool, pool_token) =
+        setup_pool_with_excess(&mut context, &mint, &authority.pubkey(), 0).await;
+
+    let destination = Pubkey::new_unique();
+    setup_token_account(&mut context, &destination, &Pubkey::new_unique(), &mint, 0).await;
+
+    let instruction = RecoverExcessTokensBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .destination_token_account(destination)
+        .mint(mint)
+        .authority(authority.pubkey())
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&authority)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::NoExcessTokens as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let authority = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    let (holder_rewards_pool, pool_token) =
+        setup_pool_with_excess(&mut context, &mint, &authority.pubkey(), EXCESS_AMOUNT).await;
+
+    let destination = Pubkey::new_unique();
+    setup_token_account(&mut context, &destination, &Pubkey::new_unique(), &mint, 0).await;
+
+    let instruction = RecoverExcessTokensBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .destination_token_account(destination)
+        .mint(mint)
+        .authority(authority.pubkey())
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&authority)).await;
+
+    // Assert only the excess tokens were moved.
+    let pool_token_account = context
+        .banks_client
+        .get_account(pool_token)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_token_state = TokenAccount::unpack(&pool_token_account.data).unwrap();
+    assert_eq!(pool_token_state.amount, DEPOSIT_AMOUNT);
+
+    let destination_account = context
+        .banks_client
+        .get_account(destination)
+        .await
+        .unwrap()
+        .unwrap();
+    let destination_state = TokenAccount::unpack(&destination_account.data).unwrap();
+    assert_eq!(destination_state.amount, EXCESS_AMOUNT);
+
+    // Assert the pool's deposit accounting is untouched.
+    let pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state = bytemuck::from_bytes::<HolderRewardsPool>(&pool_account.data);
+    assert_eq!(pool_state.total_deposited, DEPOSIT_AMOUNT);
+}
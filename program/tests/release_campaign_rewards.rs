@@ -0,0 +1,326 @@
+#![cfg(feature = "test-sbf")]
+
+mod execute_utils;
+mod setup;
+
+use {
+    crate::{
+        execute_utils::{execute_with_payer, execute_with_payer_err},
+        setup::{
+            setup_campaign_account, setup_holder_rewards_pool_account, update_holder_rewards_pool,
+            CampaignSetup,
+        },
+    },
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        processor::REWARDS_PER_TOKEN_SCALING_FACTOR,
+        state::{
+            get_campaign_address, get_holder_rewards_pool_address, Campaign, HolderRewardsPool,
+        },
+    },
+    paladin_rewards_program_client::instructions::ReleaseCampaignRewardsBuilder,
+    setup::setup,
+    solana_program_test::*,
+    solana_sdk::{
+        clock::Clock, instruction::InstructionError, pubkey::Pubkey, transaction::TransactionError,
+    },
+};
+
+const CAMPAIGN_AMOUNT: u64 = 500_000_000;
+const TOTAL_DEPOSITED: u64 = 250_000_000;
+
+#[tokio::test]
+async fn fail_campaign_incorrect_address() {
+    let mint = Pubkey::new_unique();
+    let funder = Pubkey::new_unique();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let campaign = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        0,
+        0,
+        TOTAL_DEPOSITED,
+    )
+    .await;
+    setup_campaign_account(
+        &mut context,
+        &holder_rewards_pool,
+        &funder,
+        &campaign,
+        CampaignSetup {
+            amount: CAMPAIGN_AMOUNT,
+            start_slot: 0,
+            end_slot: 100,
+            unspent: 0,
+        },
+    )
+    .await;
+
+    let instruction = ReleaseCampaignRewardsBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .campaign(campaign)
+        .mint(mint)
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, None).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectCampaignAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_releases_unlocked_rewards() {
+    let mint = Pubkey::new_unique();
+    let funder = Pubkey::new_unique();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let campaign = get_campaign_address(
+        &holder_rewards_pool,
+        &funder,
+        &paladin_rewards_program::id(),
+    );
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        0,
+        0,
+        TOTAL_DEPOSITED,
+    )
+    .await;
+    setup_campaign_account(
+        &mut context,
+        &holder_rewards_pool,
+        &funder,
+        &campaign,
+        CampaignSetup {
+            amount: CAMPAIGN_AMOUNT,
+            start_slot: 0,
+            end_slot: 1_000,
+            unspent: 0,
+        },
+    )
+    .await;
+    context.warp_to_slot(100).unwrap();
+
+    let pool_lamports_before = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let instruction = ReleaseCampaignRewardsBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .campaign(campaign)
+        .mint(mint)
+        .instruction();
+    execute_with_payer(&mut context, instruction, None).await;
+
+    // Assert the rewards unlocked by the current slot were released.
+    let slot = context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .slot;
+    let released = CAMPAIGN_AMOUNT * slot / 1_000;
+
+    let campaign_account = context
+        .banks_client
+        .get_account(campaign)
+        .await
+        .unwrap()
+        .unwrap();
+    let campaign_state = bytemuck::from_bytes::<Campaign>(&campaign_account.data);
+    assert_eq!(campaign_state.released, released);
+    assert_eq!(campaign_state.unspent, 0);
+    assert_eq!(campaign_state.last_release_slot, slot);
+
+    // Assert the pool accrued the released rewards.
+    let pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(pool_account.lamports, pool_lamports_before + released);
+
+    let pool_state = bytemuck::from_bytes::<HolderRewardsPool>(&pool_account.data);
+    assert_eq!(pool_state.lamports_last, pool_account.lamports);
+    assert_eq!(pool_state.total_rewards_received, released);
+    assert_eq!(
+        pool_state.accumulated_rewards_per_token,
+        released as u128 * REWARDS_PER_TOKEN_SCALING_FACTOR / TOTAL_DEPOSITED as u128
+    );
+}
+
+#[tokio::test]
+async fn success_sets_aside_rewards_when_nothing_deposited() {
+    let mint = Pubkey::new_unique();
+    let funder = Pubkey::new_unique();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let campaign = get_campaign_address(
+        &holder_rewards_pool,
+        &funder,
+        &paladin_rewards_program::id(),
+    );
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+    setup_campaign_account(
+        &mut context,
+        &holder_rewards_pool,
+        &funder,
+        &campaign,
+        CampaignSetup {
+            amount: CAMPAIGN_AMOUNT,
+            start_slot: 0,
+            end_slot: 100,
+            unspent: 0,
+        },
+    )
+    .await;
+    context.warp_to_slot(200).unwrap();
+
+    let pool_lamports_before = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let instruction = ReleaseCampaignRewardsBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .campaign(campaign)
+        .mint(mint)
+        .instruction();
+    execute_with_payer(&mut context, instruction, None).await;
+
+    // Assert the rewards were set aside for the funder, rather than sent to
+    // the pool where no holder could earn them.
+    let campaign_account = context
+        .banks_client
+        .get_account(campaign)
+        .await
+        .unwrap()
+        .unwrap();
+    let campaign_state = bytemuck::from_bytes::<Campaign>(&campaign_account.data);
+    assert_eq!(campaign_state.released, 0);
+    assert_eq!(campaign_state.unspent, CAMPAIGN_AMOUNT);
+
+    let pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(pool_account.lamports, pool_lamports_before);
+}
+
+#[tokio::test]
+async fn success_sets_aside_rewards_for_slots_without_weight() {
+    let mint = Pubkey::new_unique();
+    let funder = Pubkey::new_unique();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let campaign = get_campaign_address(
+        &holder_rewards_pool,
+        &funder,
+        &paladin_rewards_program::id(),
+    );
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        0,
+        0,
+        TOTAL_DEPOSITED,
+    )
+    .await;
+    setup_campaign_account(
+        &mut context,
+        &holder_rewards_pool,
+        &funder,
+        &campaign,
+        CampaignSetup {
+            amount: CAMPAIGN_AMOUNT,
+            start_slot: 0,
+            end_slot: 1_000,
+            unspent: 0,
+        },
+    )
+    .await;
+
+    // Record that the pool spent 25 slots since the campaign started without
+    // weight.
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.zero_weight_slots = 25
+    })
+    .await;
+    let pool_lamports_before = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    context.warp_to_slot(100).unwrap();
+
+    let instruction = ReleaseCampaignRewardsBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .campaign(campaign)
+        .mint(mint)
+        .instruction();
+    execute_with_payer(&mut context, instruction, None).await;
+
+    // Assert the rewards unlocked while the pool had no weight were set
+    // aside, and the rest released.
+    let slot = context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .slot;
+    let releasable = CAMPAIGN_AMOUNT * slot / 1_000;
+    let unspent = releasable * 25 / slot;
+
+    let campaign_account = context
+        .banks_client
+        .get_account(campaign)
+        .await
+        .unwrap()
+        .unwrap();
+    let campaign_state = bytemuck::from_bytes::<Campaign>(&campaign_account.data);
+    assert_eq!(campaign_state.released, releasable - unspent);
+    assert_eq!(campaign_state.unspent, unspent);
+    assert_eq!(campaign_state.last_release_slot, slot);
+    assert_eq!(campaign_state.zero_weight_slots, 25);
+
+    let pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        pool_account.lamports,
+        pool_lamports_before + releasable - unspent
+    );
+}
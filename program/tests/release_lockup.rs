@@ -0,0 +1,142 @@
+#![cfg(feature = "test-sbf")]
+
+mod execute_utils;
+mod setup;
+
+use {
+    crate::{
+        execute_utils::{execute_with_payer, execute_with_payer_err},
+        setup::{
+            send_rewards_to_pool, set_holder_rewards_lockup, setup_holder_rewards_account,
+            setup_holder_rewards_pool_account, DEPOSIT_AMOUNT,
+        },
+    },
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        state::{
+            get_holder_rewards_address, get_holder_rewards_pool_address, HolderRewards,
+            HolderRewardsPool,
+        },
+    },
+    paladin_rewards_program_client::instructions::ReleaseLockupBuilder,
+    setup::setup,
+    solana_program_test::*,
+    solana_sdk::{
+        clock::Clock, instruction::InstructionError, pubkey::Pubkey, transaction::TransactionError,
+    },
+};
+
+const REWARDS_AMOUNT: u64 = 100_000_000_000_000;
+
+// Sets up a pool with a single holder who locked all of their deposit at 2x
+// until `unlock_timestamp`.
+async fn setup_locked_holder(
+    context: &mut ProgramTestContext,
+    mint: &Pubkey,
+    owner: &Pubkey,
+    unlock_timestamp: i64,
+) -> (Pubkey, Pubkey) {
+    let holder_rewards_pool = get_holder_rewards_pool_address(mint, &paladin_rewards_program::id());
+    setup_holder_rewards_pool_account(context, mint, &holder_rewards_pool, 0, 0, DEPOSIT_AMOUNT)
+        .await;
+
+    let holder_rewards = get_holder_rewards_address(owner, &paladin_rewards_program::id());
+    setup_holder_rewards_account(context, owner, &holder_rewards, DEPOSIT_AMOUNT, 0, owner).await;
+    set_holder_rewards_lockup(
+        context,
+        &holder_rewards_pool,
+        &holder_rewards,
+        DEPOSIT_AMOUNT,
+        unlock_timestamp,
+        20_000,
+    )
+    .await;
+
+    (holder_rewards_pool, holder_rewards)
+}
+
+#[tokio::test]
+async fn fail_lockup_not_expired() {
+    let mint = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    let (holder_rewards_pool, holder_rewards) =
+        setup_locked_holder(&mut context, &mint, &owner, i64::MAX).await;
+
+    let instruction = ReleaseLockupBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards(holder_rewards)
+        .mint(mint)
+        .owner(owner)
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, None).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::LockupNotExpired as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let mint = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    let now = context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp;
+    let (holder_rewards_pool, holder_rewards) =
+        setup_locked_holder(&mut context, &mint, &owner, now).await;
+
+    send_rewards_to_pool(&mut context, &holder_rewards_pool, REWARDS_AMOUNT).await;
+
+    // Released by anyone, without the owner's signature.
+    let instruction = ReleaseLockupBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards(holder_rewards)
+        .mint(mint)
+        .owner(owner)
+        .instruction();
+    execute_with_payer(&mut context, instruction, None).await;
+
+    // Assert the rewards earned while locked were harvested to the owner.
+    let owner_lamports = context
+        .banks_client
+        .get_account(owner)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(owner_lamports, REWARDS_AMOUNT);
+
+    // Assert the lockup was released from both the holder and the pool.
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    assert_eq!(holder_rewards_state.deposited, DEPOSIT_AMOUNT);
+    assert_eq!(holder_rewards_state.locked_amount, 0);
+    assert_eq!(holder_rewards_state.unlock_timestamp, 0);
+    assert_eq!(holder_rewards_state.lockup_multiplier_bps, 0);
+
+    let pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state = bytemuck::from_bytes::<HolderRewardsPool>(&pool_account.data);
+    assert_eq!(pool_state.total_lockup_bonus_weight, 0);
+    assert_eq!(pool_state.total_weight(), Some(DEPOSIT_AMOUNT));
+}
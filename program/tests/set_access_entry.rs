@@ -0,0 +1,254 @@
+#![cfg(feature = "test-sbf")]
+
+mod execute_utils;
+mod setup;
+
+use {
+    crate::{
+        execute_utils::{execute_with_payer, execute_with_payer_err},
+        setup::{
+            setup_access_entry_account, setup_holder_rewards_pool_account,
+            update_holder_rewards_pool,
+        },
+    },
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        state::{
+            get_access_entry_address, get_access_entry_address_and_bump_seed,
+            get_holder_rewards_pool_address, AccessEntry, ACCESS_STATUS_ALLOWED,
+            ACCESS_STATUS_DENIED,
+        },
+    },
+    paladin_rewards_program_client::instructions::SetAccessEntryBuilder,
+    setup::setup,
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::InstructionError, pubkey::Pubkey, signature::Keypair, signer::Signer,
+        transaction::TransactionError,
+    },
+};
+
+#[tokio::test]
+async fn fail_incorrect_authority() {
+    let mint = Pubkey::new_unique();
+    let wallet = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let access_entry = get_access_entry_address(
+        &holder_rewards_pool,
+        &wallet,
+        &paladin_rewards_program::id(),
+    );
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.authority = Pubkey::new_unique()
+    })
+    .await;
+
+    let instruction = SetAccessEntryBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .access_entry(access_entry)
+        .wallet(wallet)
+        .mint(mint)
+        .authority(authority.pubkey())
+        .payer(context.payer.pubkey())
+        .status(ACCESS_STATUS_ALLOWED)
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&authority)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectPoolAuthority as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_invalid_status() {
+    let mint = Pubkey::new_unique();
+    let wallet = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let access_entry = get_access_entry_address(
+        &holder_rewards_pool,
+        &wallet,
+        &paladin_rewards_program::id(),
+    );
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.authority = authority.pubkey()
+    })
+    .await;
+
+    let instruction = SetAccessEntryBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .access_entry(access_entry)
+        .wallet(wallet)
+        .mint(mint)
+        .authority(authority.pubkey())
+        .payer(context.payer.pubkey())
+        .status(3)
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&authority)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::InvalidAccessStatus as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_access_entry_incorrect_address() {
+    let mint = Pubkey::new_unique();
+    let wallet = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let access_entry = Pubkey::new_unique(); // Incorrect access entry address.
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.authority = authority.pubkey()
+    })
+    .await;
+
+    let instruction = SetAccessEntryBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .access_entry(access_entry)
+        .wallet(wallet)
+        .mint(mint)
+        .authority(authority.pubkey())
+        .payer(context.payer.pubkey())
+        .status(ACCESS_STATUS_ALLOWED)
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&authority)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectAccessEntryAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_create() {
+    let mint = Pubkey::new_unique();
+    let wallet = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let (access_entry, bump_seed) = get_access_entry_address_and_bump_seed(
+        &holder_rewards_pool,
+        &wallet,
+        &paladin_rewards_program::id(),
+    );
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.authority = authority.pubkey()
+    })
+    .await;
+
+    let instruction = SetAccessEntryBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .access_entry(access_entry)
+        .wallet(wallet)
+        .mint(mint)
+        .authority(authority.pubkey())
+        .payer(context.payer.pubkey())
+        .status(ACCESS_STATUS_ALLOWED)
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&authority)).await;
+
+    // Assert the access entry account was created with the status.
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let access_entry_account = context
+        .banks_client
+        .get_account(access_entry)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(access_entry_account.owner, paladin_rewards_program::id());
+    assert_eq!(
+        access_entry_account.lamports,
+        rent.minimum_balance(AccessEntry::LEN)
+    );
+    assert_eq!(
+        bytemuck::from_bytes::<AccessEntry>(&access_entry_account.data),
+        &AccessEntry {
+            status: ACCESS_STATUS_ALLOWED,
+            bump_seed,
+            _padding: [0; 6],
+        }
+    );
+}
+
+#[tokio::test]
+async fn success_update() {
+    let mint = Pubkey::new_unique();
+    let wallet = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let (access_entry, bump_seed) = get_access_entry_address_and_bump_seed(
+        &holder_rewards_pool,
+        &wallet,
+        &paladin_rewards_program::id(),
+    );
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.authority = authority.pubkey()
+    })
+    .await;
+    setup_access_entry_account(
+        &mut context,
+        &holder_rewards_pool,
+        &wallet,
+        &access_entry,
+        ACCESS_STATUS_ALLOWED,
+    )
+    .await;
+
+    let instruction = SetAccessEntryBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .access_entry(access_entry)
+        .wallet(wallet)
+        .mint(mint)
+        .authority(authority.pubkey())
+        .payer(context.payer.pubkey())
+        .status(ACCESS_STATUS_DENIED)
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&authority)).await;
+
+    let access_entry_account = context
+        .banks_client
+        .get_account(access_entry)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        bytemuck::from_bytes::<AccessEntry>(&access_entry_account.data),
+        &AccessEntry {
+            status: ACCESS_STATUS_DENIED,
+            bump_seed,
+            _padding: [0; 6],
+        }
+    );
+}
@@ -0,0 +1,266 @@
+#![cfg(feature = "test-sbf")]
+
+mod execute_utils;
+mod setup;
+
+use {
+    crate::{
+        execute_utils::{execute_with_payer, execute_with_payer_err},
+        setup::{
+            setup_holder_rewards_pool_account, setup_lockup_tiers_account,
+            update_holder_rewards_pool,
+        },
+    },
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        state::{
+            get_holder_rewards_pool_address, get_lockup_tiers_address,
+            get_lockup_tiers_address_and_bump_seed, LockupTier, LockupTiers,
+        },
+    },
+    paladin_rewards_program_client::{
+        instructions::SetLockupTiersBuilder, types::LockupTier as ClientLockupTier,
+    },
+    setup::setup,
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::InstructionError, pubkey::Pubkey, signature::Keypair, signer::Signer,
+        transaction::TransactionError,
+    },
+};
+
+const TIERS: [LockupTier; 4] = [
+    LockupTier {
+        duration: 30 * 24 * 60 * 60,
+        multiplier_bps: 12_500,
+    },
+    LockupTier {
+        duration: 365 * 24 * 60 * 60,
+        multiplier_bps: 20_000,
+    },
+    LockupTier {
+        duration: 0,
+        multiplier_bps: 0,
+    },
+    LockupTier {
+        duration: 0,
+        multiplier_bps: 0,
+    },
+];
+
+fn client_tiers(tiers: &[LockupTier; 4]) -> [ClientLockupTier; 4] {
+    tiers.map(|tier| ClientLockupTier {
+        duration: tier.duration,
+        multiplier_bps: tier.multiplier_bps,
+    })
+}
+
+#[tokio::test]
+async fn fail_incorrect_authority() {
+    let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let lockup_tiers =
+        get_lockup_tiers_address(&holder_rewards_pool, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.authority = Pubkey::new_unique()
+    })
+    .await;
+
+    let instruction = SetLockupTiersBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .lockup_tiers(lockup_tiers)
+        .mint(mint)
+        .authority(authority.pubkey())
+        .payer(context.payer.pubkey())
+        .tiers(client_tiers(&TIERS))
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&authority)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectPoolAuthority as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_invalid_tiers() {
+    let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let lockup_tiers =
+        get_lockup_tiers_address(&holder_rewards_pool, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.authority = authority.pubkey()
+    })
+    .await;
+
+    // Tiers out of order.
+    let mut tiers = TIERS;
+    tiers.swap(0, 1);
+
+    let instruction = SetLockupTiersBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .lockup_tiers(lockup_tiers)
+        .mint(mint)
+        .authority(authority.pubkey())
+        .payer(context.payer.pubkey())
+        .tiers(client_tiers(&tiers))
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&authority)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::InvalidLockupTiers as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_lockup_tiers_incorrect_address() {
+    let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let lockup_tiers = Pubkey::new_unique(); // Incorrect lockup tiers address.
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.authority = authority.pubkey()
+    })
+    .await;
+
+    let instruction = SetLockupTiersBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .lockup_tiers(lockup_tiers)
+        .mint(mint)
+        .authority(authority.pubkey())
+        .payer(context.payer.pubkey())
+        .tiers(client_tiers(&TIERS))
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&authority)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectLockupTiersAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_create() {
+    let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let (lockup_tiers, bump_seed) = get_lockup_tiers_address_and_bump_seed(
+        &holder_rewards_pool,
+        &paladin_rewards_program::id(),
+    );
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.authority = authority.pubkey()
+    })
+    .await;
+
+    let instruction = SetLockupTiersBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .lockup_tiers(lockup_tiers)
+        .mint(mint)
+        .authority(authority.pubkey())
+        .payer(context.payer.pubkey())
+        .tiers(client_tiers(&TIERS))
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&authority)).await;
+
+    // Assert the lockup tiers account was created with the tiers.
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lockup_tiers_account = context
+        .banks_client
+        .get_account(lockup_tiers)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(lockup_tiers_account.owner, paladin_rewards_program::id());
+    assert_eq!(
+        lockup_tiers_account.lamports,
+        rent.minimum_balance(LockupTiers::LEN)
+    );
+    assert_eq!(
+        bytemuck::from_bytes::<LockupTiers>(&lockup_tiers_account.data),
+        &LockupTiers {
+            tiers: TIERS,
+            bump_seed,
+            vote_escrow: 0,
+            _padding: [0; 6],
+        }
+    );
+}
+
+#[tokio::test]
+async fn success_update() {
+    let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let (lockup_tiers, bump_seed) = get_lockup_tiers_address_and_bump_seed(
+        &holder_rewards_pool,
+        &paladin_rewards_program::id(),
+    );
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.authority = authority.pubkey()
+    })
+    .await;
+    setup_lockup_tiers_account(&mut context, &holder_rewards_pool, &lockup_tiers, TIERS).await;
+
+    // Remove the longest tier.
+    let mut tiers = TIERS;
+    tiers[1] = LockupTier::default();
+
+    let instruction = SetLockupTiersBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .lockup_tiers(lockup_tiers)
+        .mint(mint)
+        .authority(authority.pubkey())
+        .payer(context.payer.pubkey())
+        .tiers(client_tiers(&tiers))
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&authority)).await;
+
+    let lockup_tiers_account = context
+        .banks_client
+        .get_account(lockup_tiers)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        bytemuck::from_bytes::<LockupTiers>(&lockup_tiers_account.data),
+        &LockupTiers {
+            tiers,
+            bump_seed,
+            vote_escrow: 0,
+            _padding: [0; 6],
+        }
+    );
+}
@@ -0,0 +1,152 @@
+#![cfg(feature = "test-sbf")]
+
+mod execute_utils;
+mod setup;
+
+use {
+    crate::{
+        execute_utils::{execute_with_payer, execute_with_payer_err},
+        setup::{
+            setup_holder_rewards_pool_account, setup_token_account, update_holder_rewards_pool,
+        },
+    },
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        state::{get_holder_rewards_pool_address, HolderRewardsPool},
+    },
+    paladin_rewards_program_client::instructions::SetSlashingConfigBuilder,
+    setup::setup,
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::InstructionError, pubkey::Pubkey, signature::Keypair, signer::Signer,
+        transaction::TransactionError,
+    },
+};
+
+#[tokio::test]
+async fn fail_incorrect_authority() {
+    let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let slashing_authority = Pubkey::new_unique();
+    let slash_destination = Pubkey::new_unique();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.authority = Pubkey::new_unique()
+    })
+    .await;
+    setup_token_account(
+        &mut context,
+        &slash_destination,
+        &Pubkey::new_unique(),
+        &mint,
+        0,
+    )
+    .await;
+
+    let instruction = SetSlashingConfigBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .mint(mint)
+        .authority(authority.pubkey())
+        .slashing_authority(slashing_authority)
+        .slash_destination(slash_destination)
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&authority)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectPoolAuthority as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_slash_destination_mint_mismatch() {
+    let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let slashing_authority = Pubkey::new_unique();
+    let slash_destination = Pubkey::new_unique();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.authority = authority.pubkey()
+    })
+    .await;
+    setup_token_account(
+        &mut context,
+        &slash_destination,
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(), // Incorrect mint.
+        0,
+    )
+    .await;
+
+    let instruction = SetSlashingConfigBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .mint(mint)
+        .authority(authority.pubkey())
+        .slashing_authority(slashing_authority)
+        .slash_destination(slash_destination)
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&authority)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::TokenAccountMintMismatch as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let mint = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let slashing_authority = Pubkey::new_unique();
+    let slash_destination = Pubkey::new_unique();
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &mint, &holder_rewards_pool, 0, 0, 0).await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.authority = authority.pubkey()
+    })
+    .await;
+    setup_token_account(
+        &mut context,
+        &slash_destination,
+        &Pubkey::new_unique(),
+        &mint,
+        0,
+    )
+    .await;
+
+    let instruction = SetSlashingConfigBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .mint(mint)
+        .authority(authority.pubkey())
+        .slashing_authority(slashing_authority)
+        .slash_destination(slash_destination)
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&authority)).await;
+
+    let pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state = bytemuck::from_bytes::<HolderRewardsPool>(&pool_account.data);
+    assert_eq!(pool_state.slashing_authority, slashing_authority);
+    assert_eq!(pool_state.slash_destination, slash_destination);
+}
@@ -3,16 +3,34 @@
 
 use {
     paladin_rewards_program::state::{
-        find_duna_document_pda, HolderRewards, HolderRewardsPool, DUNA_PROGRAM_ID,
+        find_duna_document_pda, get_access_entry_address, get_access_entry_address_and_bump_seed,
+        get_campaign_address_and_bump_seed, get_epoch_ledger_address,
+        get_epoch_ledger_address_and_bump_seed, get_holder_rewards_address,
+        get_holder_rewards_address_and_bump_seed, get_holder_rewards_pool_address_and_bump_seed,
+        get_lockup_tiers_address_and_bump_seed, get_rate_history_address,
+        get_rate_history_address_and_bump_seed, get_receipt_mint_address,
+        get_stake_checkpoints_address, get_stake_checkpoints_address_and_bump_seed,
+        get_vote_escrow_address_and_bump_seed, AccessEntry, Campaign, EpochLedger, HolderRewards,
+        HolderRewardsPool, LockupTier, LockupTiers, RateHistory, StakeCheckpoints, VoteEscrow,
+        DUNA_PROGRAM_ID, LOCKUP_TIERS_CAPACITY,
     },
+    paladin_rewards_program_client::instructions::InitializeReceiptMintBuilder,
     solana_program_test::*,
     solana_sdk::{
         account::{Account, AccountSharedData},
+        instruction::{AccountMeta, Instruction},
         program_pack::Pack,
         pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
         system_program,
+        transaction::Transaction,
+    },
+    spl_associated_token_account::{
+        get_associated_token_address_with_program_id, instruction::create_associated_token_account,
     },
     spl_token::state::{Account as TokenAccount, AccountState, Mint},
+    spl_transfer_hook_interface::get_extra_account_metas_address,
 };
 
 pub const DEPOSIT_AMOUNT: u64 = 250_000_000;
@@ -113,9 +131,11 @@ pub async fn setup_system_account(
 
 pub async fn setup_holder_rewards_pool_account(
     context: &mut ProgramTestContext,
+    mint: &Pubkey,
     holder_rewards_pool_address: &Pubkey,
     excess_lamports: u64,
     accumulated_rewards_per_token: u128,
+    total_deposited: u64,
 ) {
     let rent = context.banks_client.get_rent().await.unwrap();
     let lamports = rent.minimum_balance(HolderRewardsPool::LEN) + excess_lamports;
@@ -123,7 +143,33 @@ pub async fn setup_holder_rewards_pool_account(
         accumulated_rewards_per_token,
         lamports_last: lamports,
         duna_document_hash: [1; 32],
-        _padding: 0,
+        total_deposited,
+        authority: Pubkey::default(),
+        bump_seed: get_holder_rewards_pool_address_and_bump_seed(
+            mint,
+            &paladin_rewards_program::id(),
+        )
+        .1,
+        access_list: 0,
+        early_withdrawal_penalty_bps: 0,
+        early_withdrawal_period_slots: 0,
+        last_update_slot: 0,
+        total_rewards_received: 0,
+        total_rewards_harvested: 0,
+        active_holder_count: 0,
+        total_lockup_bonus_weight: 0,
+        max_total_deposit: 0,
+        max_holder_deposit: 0,
+        min_deposit: 0,
+        receipts: 0,
+        receipt_mint_bump_seed: 0,
+        rate_history: 0,
+        epoch_ledger: 0,
+        _padding: [0; 4],
+        slashing_authority: Pubkey::default(),
+        slash_destination: Pubkey::default(),
+        total_slashed: 0,
+        zero_weight_slots: 0,
     };
     let data = bytemuck::bytes_of(&state).to_vec();
 
@@ -149,9 +195,11 @@ pub async fn setup_holder_rewards_pool_account_with_token_account(
 ) {
     setup_holder_rewards_pool_account(
         context,
+        mint,
         holder_rewards_pool_address,
         excess_lamports,
         accumulated_rewards_per_token,
+        token_balance,
     )
     .await;
 
@@ -165,17 +213,39 @@ pub async fn setup_holder_rewards_pool_account_with_token_account(
     .await;
 }
 
+pub async fn update_holder_rewards_pool(
+    context: &mut ProgramTestContext,
+    holder_rewards_pool_address: &Pubkey,
+    update: impl FnOnce(&mut HolderRewardsPool),
+) {
+    let mut pool_account = context
+        .banks_client
+        .get_account(*holder_rewards_pool_address)
+        .await
+        .unwrap()
+        .unwrap();
+    update(bytemuck::from_bytes_mut::<HolderRewardsPool>(
+        &mut pool_account.data,
+    ));
+    context.set_account(holder_rewards_pool_address, &pool_account.into());
+}
+
 #[allow(clippy::arithmetic_side_effects)]
 pub async fn setup_holder_rewards_account(
     context: &mut ProgramTestContext,
+    owner: &Pubkey,
     holder_rewards: &Pubkey,
     deposited: u64,
     last_accumulated_rewards_per_token: u128,
+    rent_payer: &Pubkey,
 ) {
     let state = HolderRewards {
         last_accumulated_rewards_per_token,
         deposited,
-        _padding: 0,
+        rent_payer: *rent_payer,
+        bump_seed: get_holder_rewards_address_and_bump_seed(owner, &paladin_rewards_program::id())
+            .1,
+        ..HolderRewards::default()
     };
     let data = bytemuck::bytes_of(&state).to_vec();
 
@@ -193,28 +263,337 @@ pub async fn setup_holder_rewards_account(
     );
 }
 
+/// The holder's state for `setup_holder_rewards_account_with_token_account`.
+pub struct HolderRewardsSetup {
+    /// The tokens the holder has deposited.
+    pub deposited: u64,
+    /// The pool's rate the holder was last updated at.
+    pub last_accumulated_rewards_per_token: u128,
+    /// The balance of the holder's token account.
+    pub token_balance: u64,
+}
+
 pub async fn setup_holder_rewards_account_with_token_account(
     context: &mut ProgramTestContext,
     mint: &Pubkey,
     owner: &Pubkey,
     holder_rewards: &Pubkey,
     owner_token_account: &Pubkey,
-    deposited: u64,
-    last_accumulated_rewards_per_token: u128,
-    token_balance: u64,
+    holder: HolderRewardsSetup,
 ) {
+    let HolderRewardsSetup {
+        deposited,
+        last_accumulated_rewards_per_token,
+        token_balance,
+    } = holder;
+
     sign_duna_document(context, owner);
     setup_holder_rewards_account(
         context,
+        owner,
         holder_rewards,
         deposited,
         last_accumulated_rewards_per_token,
+        owner,
     )
     .await;
 
     setup_token_account(context, owner_token_account, owner, mint, token_balance).await;
 }
 
+pub async fn setup_rate_history_account(
+    context: &mut ProgramTestContext,
+    holder_rewards_pool: &Pubkey,
+    rate_history: &Pubkey,
+) {
+    let mut state: RateHistory = bytemuck::Zeroable::zeroed();
+    state.bump_seed =
+        get_rate_history_address_and_bump_seed(holder_rewards_pool, &paladin_rewards_program::id())
+            .1;
+    let data = bytemuck::bytes_of(&state).to_vec();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(data.len());
+
+    context.set_account(
+        rate_history,
+        &AccountSharedData::from(Account {
+            lamports,
+            data,
+            owner: paladin_rewards_program::id(),
+            ..Account::default()
+        }),
+    );
+}
+
+pub async fn setup_epoch_ledger_account(
+    context: &mut ProgramTestContext,
+    holder_rewards_pool: &Pubkey,
+    epoch_ledger: &Pubkey,
+) {
+    let mut state: EpochLedger = bytemuck::Zeroable::zeroed();
+    state.bump_seed =
+        get_epoch_ledger_address_and_bump_seed(holder_rewards_pool, &paladin_rewards_program::id())
+            .1;
+    let data = bytemuck::bytes_of(&state).to_vec();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(data.len());
+
+    context.set_account(
+        epoch_ledger,
+        &AccountSharedData::from(Account {
+            lamports,
+            data,
+            owner: paladin_rewards_program::id(),
+            ..Account::default()
+        }),
+    );
+}
+
+pub async fn setup_lockup_tiers_account(
+    context: &mut ProgramTestContext,
+    holder_rewards_pool: &Pubkey,
+    lockup_tiers: &Pubkey,
+    tiers: [LockupTier; LOCKUP_TIERS_CAPACITY],
+) {
+    let state = LockupTiers {
+        tiers,
+        bump_seed: get_lockup_tiers_address_and_bump_seed(
+            holder_rewards_pool,
+            &paladin_rewards_program::id(),
+        )
+        .1,
+        vote_escrow: 0,
+        _padding: [0; 6],
+    };
+    let data = bytemuck::bytes_of(&state).to_vec();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(data.len());
+
+    context.set_account(
+        lockup_tiers,
+        &AccountSharedData::from(Account {
+            lamports,
+            data,
+            owner: paladin_rewards_program::id(),
+            ..Account::default()
+        }),
+    );
+}
+
+/// Creates the pool's vote escrow, checkpointed at `last_checkpoint`, and
+/// flags it on the pool's lockup tiers.
+pub async fn setup_vote_escrow_account(
+    context: &mut ProgramTestContext,
+    holder_rewards_pool: &Pubkey,
+    lockup_tiers: &Pubkey,
+    vote_escrow: &Pubkey,
+    reward_weighting: bool,
+    last_checkpoint: i64,
+) {
+    let mut state: VoteEscrow = bytemuck::Zeroable::zeroed();
+    state.last_checkpoint = last_checkpoint;
+    state.bump_seed =
+        get_vote_escrow_address_and_bump_seed(holder_rewards_pool, &paladin_rewards_program::id())
+            .1;
+    state.reward_weighting = reward_weighting.into();
+    let data = bytemuck::bytes_of(&state).to_vec();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(data.len());
+
+    context.set_account(
+        vote_escrow,
+        &AccountSharedData::from(Account {
+            lamports,
+            data,
+            owner: paladin_rewards_program::id(),
+            ..Account::default()
+        }),
+    );
+
+    let mut lockup_tiers_account = context
+        .banks_client
+        .get_account(*lockup_tiers)
+        .await
+        .unwrap()
+        .unwrap();
+    bytemuck::from_bytes_mut::<LockupTiers>(&mut lockup_tiers_account.data).vote_escrow = 1;
+    context.set_account(lockup_tiers, &lockup_tiers_account.into());
+}
+
+/// Creates the owner's stake checkpoints with the given checkpoints, and
+/// flags them on the holder rewards account.
+pub async fn setup_stake_checkpoints_account(
+    context: &mut ProgramTestContext,
+    owner: &Pubkey,
+    holder_rewards: &Pubkey,
+    stake_checkpoints: &Pubkey,
+    checkpoints: &[(u64, u64)],
+) {
+    let mut state: StakeCheckpoints = bytemuck::Zeroable::zeroed();
+    state.bump_seed =
+        get_stake_checkpoints_address_and_bump_seed(owner, &paladin_rewards_program::id()).1;
+    for (slot, deposited) in checkpoints {
+        state.push(*slot, *deposited);
+    }
+    let data = bytemuck::bytes_of(&state).to_vec();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(data.len());
+
+    context.set_account(
+        stake_checkpoints,
+        &AccountSharedData::from(Account {
+            lamports,
+            data,
+            owner: paladin_rewards_program::id(),
+            ..Account::default()
+        }),
+    );
+
+    let mut holder_rewards_account = context
+        .banks_client
+        .get_account(*holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    bytemuck::from_bytes_mut::<HolderRewards>(&mut holder_rewards_account.data).stake_checkpoints =
+        1;
+    context.set_account(holder_rewards, &holder_rewards_account.into());
+}
+
+pub async fn setup_access_entry_account(
+    context: &mut ProgramTestContext,
+    holder_rewards_pool: &Pubkey,
+    wallet: &Pubkey,
+    access_entry: &Pubkey,
+    status: u8,
+) {
+    let state = AccessEntry {
+        status,
+        bump_seed: get_access_entry_address_and_bump_seed(
+            holder_rewards_pool,
+            wallet,
+            &paladin_rewards_program::id(),
+        )
+        .1,
+        _padding: [0; 6],
+    };
+    let data = bytemuck::bytes_of(&state).to_vec();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(data.len());
+
+    context.set_account(
+        access_entry,
+        &AccountSharedData::from(Account {
+            lamports,
+            data,
+            owner: paladin_rewards_program::id(),
+            ..Account::default()
+        }),
+    );
+}
+
+/// The campaign's state for `setup_campaign_account`.
+pub struct CampaignSetup {
+    /// The rewards escrowed by the campaign.
+    pub amount: u64,
+    /// The slot the rewards start unlocking at.
+    pub start_slot: u64,
+    /// The slot all rewards are unlocked at.
+    pub end_slot: u64,
+    /// The rewards already set aside for the funder.
+    pub unspent: u64,
+}
+
+/// Sets up a campaign escrowing `amount`, of which `unspent` was already set
+/// aside for the funder and nothing was released.
+pub async fn setup_campaign_account(
+    context: &mut ProgramTestContext,
+    holder_rewards_pool: &Pubkey,
+    funder: &Pubkey,
+    campaign: &Pubkey,
+    campaign_setup: CampaignSetup,
+) {
+    let CampaignSetup {
+        amount,
+        start_slot,
+        end_slot,
+        unspent,
+    } = campaign_setup;
+
+    let state = Campaign {
+        holder_rewards_pool: *holder_rewards_pool,
+        funder: *funder,
+        amount,
+        start_slot,
+        end_slot,
+        released: 0,
+        unspent,
+        last_release_slot: start_slot,
+        zero_weight_slots: 0,
+        bump_seed: get_campaign_address_and_bump_seed(
+            holder_rewards_pool,
+            funder,
+            &paladin_rewards_program::id(),
+        )
+        .1,
+        _padding: [0; 7],
+    };
+    let data = bytemuck::bytes_of(&state).to_vec();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(data.len()) + amount;
+
+    context.set_account(
+        campaign,
+        &AccountSharedData::from(Account {
+            lamports,
+            data,
+            owner: paladin_rewards_program::id(),
+            ..Account::default()
+        }),
+    );
+}
+
+/// Locks the holder's tokens, adding the lockup's bonus weight to the pool.
+pub async fn set_holder_rewards_lockup(
+    context: &mut ProgramTestContext,
+    holder_rewards_pool_address: &Pubkey,
+    holder_rewards_address: &Pubkey,
+    locked_amount: u64,
+    unlock_timestamp: i64,
+    multiplier_bps: u64,
+) {
+    let mut holder_rewards_account = context
+        .banks_client
+        .get_account(*holder_rewards_address)
+        .await
+        .unwrap()
+        .unwrap();
+    let holder_rewards_state =
+        bytemuck::from_bytes_mut::<HolderRewards>(&mut holder_rewards_account.data);
+    holder_rewards_state.locked_amount = locked_amount;
+    holder_rewards_state.unlock_timestamp = unlock_timestamp;
+    holder_rewards_state.lockup_multiplier_bps = multiplier_bps;
+    let bonus_weight = holder_rewards_state.lockup_bonus_weight().unwrap();
+    context.set_account(holder_rewards_address, &holder_rewards_account.into());
+
+    let mut pool_account = context
+        .banks_client
+        .get_account(*holder_rewards_pool_address)
+        .await
+        .unwrap()
+        .unwrap();
+    bytemuck::from_bytes_mut::<HolderRewardsPool>(&mut pool_account.data)
+        .total_lockup_bonus_weight += bonus_weight;
+    context.set_account(holder_rewards_pool_address, &pool_account.into());
+}
+
 /// Send lamports to the pool
 pub async fn send_rewards_to_pool(
     context: &mut ProgramTestContext,
@@ -267,3 +646,143 @@ pub fn sign_duna_document_with_data(
 
     duna_acc
 }
+
+/// Sets up the mint and initializes the pool's receipt token mint, returning
+/// its address.
+pub async fn initialize_receipt_mint(
+    context: &mut ProgramTestContext,
+    mint: &Pubkey,
+    holder_rewards_pool: &Pubkey,
+) -> Pubkey {
+    let authority = Keypair::new();
+    update_holder_rewards_pool(context, holder_rewards_pool, |pool_state| {
+        pool_state.authority = authority.pubkey()
+    })
+    .await;
+    setup_mint(context, mint, 0, None).await;
+
+    let receipt_mint =
+        get_receipt_mint_address(holder_rewards_pool, &paladin_rewards_program::id());
+    let instruction = InitializeReceiptMintBuilder::new()
+        .holder_rewards_pool(*holder_rewards_pool)
+        .receipt_mint(receipt_mint)
+        .extra_account_metas(get_extra_account_metas_address(
+            &receipt_mint,
+            &paladin_rewards_program::id(),
+        ))
+        .mint(*mint)
+        .authority(authority.pubkey())
+        .payer(context.payer.pubkey())
+        .receipt_token_program(spl_token_2022::id())
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &authority],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    receipt_mint
+}
+
+/// Creates the owner's receipt token account, returning its address.
+pub async fn setup_receipt_token_account(
+    context: &mut ProgramTestContext,
+    receipt_mint: &Pubkey,
+    owner: &Pubkey,
+) -> Pubkey {
+    let instruction = create_associated_token_account(
+        &context.payer.pubkey(),
+        owner,
+        receipt_mint,
+        &spl_token_2022::id(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    get_associated_token_address_with_program_id(owner, receipt_mint, &spl_token_2022::id())
+}
+
+/// Builds a transfer of receipt tokens between the owners' receipt token
+/// accounts, with the accounts resolved for the receipt mint's transfer hook.
+pub fn receipt_transfer_instruction(
+    holder_rewards_pool: &Pubkey,
+    receipt_mint: &Pubkey,
+    source_owner: &Pubkey,
+    destination_owner: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let program_id = paladin_rewards_program::id();
+    let source = get_associated_token_address_with_program_id(
+        source_owner,
+        receipt_mint,
+        &spl_token_2022::id(),
+    );
+    let destination = get_associated_token_address_with_program_id(
+        destination_owner,
+        receipt_mint,
+        &spl_token_2022::id(),
+    );
+
+    let mut instruction = spl_token_2022::instruction::transfer_checked(
+        &spl_token_2022::id(),
+        &source,
+        receipt_mint,
+        &destination,
+        source_owner,
+        &[],
+        amount,
+        0,
+    )
+    .unwrap();
+    instruction.accounts.extend([
+        AccountMeta::new(*holder_rewards_pool, false),
+        AccountMeta::new(get_holder_rewards_address(source_owner, &program_id), false),
+        AccountMeta::new(
+            get_holder_rewards_address(destination_owner, &program_id),
+            false,
+        ),
+        AccountMeta::new(
+            get_stake_checkpoints_address(source_owner, &program_id),
+            false,
+        ),
+        AccountMeta::new(
+            get_stake_checkpoints_address(destination_owner, &program_id),
+            false,
+        ),
+        AccountMeta::new_readonly(
+            get_access_entry_address(holder_rewards_pool, destination_owner, &program_id),
+            false,
+        ),
+        AccountMeta::new(
+            get_rate_history_address(holder_rewards_pool, &program_id),
+            false,
+        ),
+        AccountMeta::new(
+            get_epoch_ledger_address(holder_rewards_pool, &program_id),
+            false,
+        ),
+        AccountMeta::new_readonly(program_id, false),
+        AccountMeta::new_readonly(
+            get_extra_account_metas_address(receipt_mint, &program_id),
+            false,
+        ),
+    ]);
+    instruction
+}
@@ -0,0 +1,389 @@
+#![cfg(feature = "test-sbf")]
+
+mod execute_utils;
+mod setup;
+
+use {
+    crate::{
+        execute_utils::{execute_with_payer, execute_with_payer_err},
+        setup::{
+            send_rewards_to_pool, set_holder_rewards_lockup,
+            setup_holder_rewards_account_with_token_account,
+            setup_holder_rewards_pool_account_with_token_account, setup_token_account,
+            update_holder_rewards_pool, HolderRewardsSetup, DEPOSIT_AMOUNT, INITIAL_OWNER_BALANCE,
+        },
+    },
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        state::{
+            get_holder_rewards_address, get_holder_rewards_pool_address, HolderRewards,
+            HolderRewardsPool,
+        },
+    },
+    paladin_rewards_program_client::instructions::SlashBuilder,
+    setup::setup,
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::{Instruction, InstructionError},
+        program_pack::Pack,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::TransactionError,
+    },
+    spl_associated_token_account::get_associated_token_address,
+    spl_token::state::Account as TokenAccount,
+};
+
+const REWARDS_AMOUNT: u64 = 100_000_000;
+
+struct Slashing {
+    holder_rewards_pool: Pubkey,
+    pool_token: Pubkey,
+    holder_rewards: Pubkey,
+    slash_destination: Pubkey,
+    slashing_authority: Keypair,
+}
+
+// Sets up a pool with slashing enabled, and a holder with `DEPOSIT_AMOUNT`
+// deposited.
+async fn setup_slashing(
+    context: &mut ProgramTestContext,
+    mint: &Pubkey,
+    owner: &Pubkey,
+) -> Slashing {
+    let holder_rewards_pool = get_holder_rewards_pool_address(mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, mint);
+    setup_holder_rewards_pool_account_with_token_account(
+        context,
+        mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+
+    let holder_rewards = get_holder_rewards_address(owner, &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(owner, mint);
+    setup_holder_rewards_account_with_token_account(
+        context,
+        mint,
+        owner,
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        },
+    )
+    .await;
+
+    let slashing_authority = Keypair::new();
+    let slash_destination = Pubkey::new_unique();
+    setup_token_account(context, &slash_destination, &Pubkey::new_unique(), mint, 0).await;
+    update_holder_rewards_pool(context, &holder_rewards_pool, |pool_state| {
+        pool_state.slashing_authority = slashing_authority.pubkey();
+        pool_state.slash_destination = slash_destination;
+    })
+    .await;
+
+    Slashing {
+        holder_rewards_pool,
+        pool_token,
+        holder_rewards,
+        slash_destination,
+        slashing_authority,
+    }
+}
+
+fn slash_instruction(
+    slashing: &Slashing,
+    mint: &Pubkey,
+    owner: &Pubkey,
+    slashing_authority: &Pubkey,
+    slash_destination: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    SlashBuilder::new()
+        .holder_rewards_pool(slashing.holder_rewards_pool)
+        .holder_rewards_pool_token_account(slashing.pool_token)
+        .holder_rewards(slashing.holder_rewards)
+        .slash_destination(*slash_destination)
+        .mint(*mint)
+        .owner(*owner)
+        .slashing_authority(*slashing_authority)
+        .amount(amount)
+        .instruction()
+}
+
+#[tokio::test]
+async fn fail_incorrect_slashing_authority() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    let slashing = setup_slashing(&mut context, &mint, &owner).await;
+
+    let slashing_authority = Keypair::new();
+    let instruction = slash_instruction(
+        &slashing,
+        &mint,
+        &owner,
+        &slashing_authority.pubkey(),
+        &slashing.slash_destination,
+        DEPOSIT_AMOUNT,
+    );
+    let err = execute_with_payer_err(&mut context, instruction, Some(&slashing_authority)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectSlashingAuthority as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_incorrect_slash_destination() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    let slashing = setup_slashing(&mut context, &mint, &owner).await;
+
+    let slash_destination = Pubkey::new_unique();
+    setup_token_account(
+        &mut context,
+        &slash_destination,
+        &Pubkey::new_unique(),
+        &mint,
+        0,
+    )
+    .await;
+
+    let instruction = slash_instruction(
+        &slashing,
+        &mint,
+        &owner,
+        &slashing.slashing_authority.pubkey(),
+        &slash_destination,
+        DEPOSIT_AMOUNT,
+    );
+    let err = execute_with_payer_err(
+        &mut context,
+        instruction,
+        Some(&slashing.slashing_authority),
+    )
+    .await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectSlashDestination as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_slash_exceeds_deposited() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    let slashing = setup_slashing(&mut context, &mint, &owner).await;
+
+    let instruction = slash_instruction(
+        &slashing,
+        &mint,
+        &owner,
+        &slashing.slashing_authority.pubkey(),
+        &slashing.slash_destination,
+        DEPOSIT_AMOUNT + 1,
+    );
+    let err = execute_with_payer_err(
+        &mut context,
+        instruction,
+        Some(&slashing.slashing_authority),
+    )
+    .await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::SlashExceedsDeposited as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_receipt_pool() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    let slashing = setup_slashing(&mut context, &mint, &owner).await;
+
+    // Give the pool a receipt mint.
+    update_holder_rewards_pool(&mut context, &slashing.holder_rewards_pool, |pool_state| {
+        pool_state.receipts = 1
+    })
+    .await;
+
+    let instruction = slash_instruction(
+        &slashing,
+        &mint,
+        &owner,
+        &slashing.slashing_authority.pubkey(),
+        &slashing.slash_destination,
+        DEPOSIT_AMOUNT,
+    );
+    let err = execute_with_payer_err(
+        &mut context,
+        instruction,
+        Some(&slashing.slashing_authority),
+    )
+    .await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::ReceiptPoolNotSlashable as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    let slashing = setup_slashing(&mut context, &mint, &owner).await;
+
+    // Rewards accrue to the holder before they are slashed.
+    send_rewards_to_pool(&mut context, &slashing.holder_rewards_pool, REWARDS_AMOUNT).await;
+
+    let instruction = slash_instruction(
+        &slashing,
+        &mint,
+        &owner,
+        &slashing.slashing_authority.pubkey(),
+        &slashing.slash_destination,
+        DEPOSIT_AMOUNT / 2,
+    );
+    execute_with_payer(
+        &mut context,
+        instruction,
+        Some(&slashing.slashing_authority),
+    )
+    .await;
+
+    // Assert the slashed tokens moved to the slash destination.
+    let slash_destination_account = context
+        .banks_client
+        .get_account(slashing.slash_destination)
+        .await
+        .unwrap()
+        .unwrap();
+    let slash_destination_state = TokenAccount::unpack(&slash_destination_account.data).unwrap();
+    assert_eq!(slash_destination_state.amount, DEPOSIT_AMOUNT / 2);
+
+    let pool_token_account = context
+        .banks_client
+        .get_account(slashing.pool_token)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_token_state = TokenAccount::unpack(&pool_token_account.data).unwrap();
+    assert_eq!(pool_token_state.amount, DEPOSIT_AMOUNT / 2);
+
+    // Assert the pool totals were updated.
+    let pool_account = context
+        .banks_client
+        .get_account(slashing.holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state = bytemuck::from_bytes::<HolderRewardsPool>(&pool_account.data);
+    assert_eq!(pool_state.total_deposited, DEPOSIT_AMOUNT / 2);
+    assert_eq!(pool_state.total_slashed, DEPOSIT_AMOUNT / 2);
+
+    // Assert the holder keeps the rewards earned before the slash.
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(slashing.holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    assert_eq!(holder_rewards_state.deposited, DEPOSIT_AMOUNT / 2);
+    assert_eq!(holder_rewards_state.slashed, DEPOSIT_AMOUNT / 2);
+    assert_eq!(holder_rewards_state.unharvested_rewards, REWARDS_AMOUNT);
+    assert_eq!(
+        holder_rewards_state.last_accumulated_rewards_per_token,
+        pool_state.accumulated_rewards_per_token
+    );
+}
+
+#[tokio::test]
+async fn success_shrinks_lockup() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    let slashing = setup_slashing(&mut context, &mint, &owner).await;
+
+    // Lock the holder's entire deposit at 2x.
+    set_holder_rewards_lockup(
+        &mut context,
+        &slashing.holder_rewards_pool,
+        &slashing.holder_rewards,
+        DEPOSIT_AMOUNT,
+        i64::MAX,
+        20_000,
+    )
+    .await;
+
+    let instruction = slash_instruction(
+        &slashing,
+        &mint,
+        &owner,
+        &slashing.slashing_authority.pubkey(),
+        &slashing.slash_destination,
+        DEPOSIT_AMOUNT / 2,
+    );
+    execute_with_payer(
+        &mut context,
+        instruction,
+        Some(&slashing.slashing_authority),
+    )
+    .await;
+
+    // Assert the lockup shrank to the remaining deposit, along with its bonus
+    // weight.
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(slashing.holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    assert_eq!(holder_rewards_state.deposited, DEPOSIT_AMOUNT / 2);
+    assert_eq!(holder_rewards_state.locked_amount, DEPOSIT_AMOUNT / 2);
+
+    let pool_account = context
+        .banks_client
+        .get_account(slashing.holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state = bytemuck::from_bytes::<HolderRewardsPool>(&pool_account.data);
+    assert_eq!(pool_state.total_lockup_bonus_weight, DEPOSIT_AMOUNT / 2);
+}
@@ -0,0 +1,448 @@
+#![cfg(feature = "test-sbf")]
+
+mod execute_utils;
+mod setup;
+
+use {
+    crate::{
+        execute_utils::{execute_with_payer, execute_with_payer_err},
+        setup::{
+            initialize_receipt_mint, receipt_transfer_instruction, send_rewards_to_pool,
+            set_holder_rewards_lockup, setup_holder_rewards_account,
+            setup_holder_rewards_account_with_token_account,
+            setup_holder_rewards_pool_account_with_token_account, setup_receipt_token_account,
+            HolderRewardsSetup, DEPOSIT_AMOUNT, INITIAL_OWNER_BALANCE,
+        },
+    },
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        processor::REWARDS_PER_TOKEN_SCALING_FACTOR,
+        state::{
+            get_access_entry_address, get_epoch_ledger_address, get_holder_rewards_address,
+            get_holder_rewards_pool_address, get_rate_history_address,
+            get_stake_checkpoints_address, EpochLedger, HolderRewards, HolderRewardsPool,
+            RateHistory,
+        },
+    },
+    paladin_rewards_program_client::instructions::{
+        DepositBuilder, InitializeEpochLedgerBuilder, InitializeRateHistoryBuilder,
+    },
+    setup::setup,
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::{AccountMeta, InstructionError},
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::TransactionError,
+    },
+    spl_associated_token_account::{
+        get_associated_token_address, get_associated_token_address_with_program_id,
+    },
+    spl_token_2022::{extension::StateWithExtensions, state::Account as ReceiptTokenAccount},
+    spl_transfer_hook_interface::get_extra_account_metas_address,
+};
+
+const REWARDS_AMOUNT: u64 = 100_000_000;
+
+// Sets up a pool minting receipt tokens, with the owner holding
+// `DEPOSIT_AMOUNT` of them, returning the pool and receipt mint addresses.
+async fn setup_pool_with_receipts(
+    context: &mut ProgramTestContext,
+    mint: &Pubkey,
+    owner: &Keypair,
+) -> (Pubkey, Pubkey) {
+    let holder_rewards_pool = get_holder_rewards_pool_address(mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, mint);
+    setup_holder_rewards_pool_account_with_token_account(
+        context,
+        mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        0,
+    )
+    .await;
+    let receipt_mint = initialize_receipt_mint(context, mint, &holder_rewards_pool).await;
+
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), mint);
+    setup_holder_rewards_account_with_token_account(
+        context,
+        mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: 0,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE,
+        },
+    )
+    .await;
+    let receipt_token = setup_receipt_token_account(context, &receipt_mint, &owner.pubkey()).await;
+
+    let instruction = DepositBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(*mint)
+        .owner(owner.pubkey())
+        .receipt_mint(Some(receipt_mint))
+        .receipt_token_account(Some(receipt_token))
+        .receipt_token_program(Some(spl_token_2022::id()))
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    execute_with_payer(context, instruction, Some(owner)).await;
+
+    (holder_rewards_pool, receipt_mint)
+}
+
+// Sets up an empty holder rewards account and receipt token account for the
+// owner.
+async fn setup_receipt_holder(
+    context: &mut ProgramTestContext,
+    receipt_mint: &Pubkey,
+    owner: &Pubkey,
+) {
+    let holder_rewards = get_holder_rewards_address(owner, &paladin_rewards_program::id());
+    setup_holder_rewards_account(context, owner, &holder_rewards, 0, 0, owner).await;
+    setup_receipt_token_account(context, receipt_mint, owner).await;
+}
+
+#[tokio::test]
+async fn fail_destination_without_holder_rewards() {
+    let alice = Keypair::new();
+    let bob = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    let (holder_rewards_pool, receipt_mint) =
+        setup_pool_with_receipts(&mut context, &mint, &alice).await;
+
+    // Bob has a receipt token account, but no holder rewards account.
+    setup_receipt_token_account(&mut context, &receipt_mint, &bob).await;
+
+    let instruction = receipt_transfer_instruction(
+        &holder_rewards_pool,
+        &receipt_mint,
+        &alice.pubkey(),
+        &bob,
+        DEPOSIT_AMOUNT,
+    );
+    let err = execute_with_payer_err(&mut context, instruction, Some(&alice)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::ReceiptRecipientNotInitialized as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_exceeds_unlocked() {
+    let alice = Keypair::new();
+    let bob = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    let (holder_rewards_pool, receipt_mint) =
+        setup_pool_with_receipts(&mut context, &mint, &alice).await;
+    setup_receipt_holder(&mut context, &receipt_mint, &bob).await;
+
+    // Lock half of Alice's deposit.
+    set_holder_rewards_lockup(
+        &mut context,
+        &holder_rewards_pool,
+        &get_holder_rewards_address(&alice.pubkey(), &paladin_rewards_program::id()),
+        DEPOSIT_AMOUNT / 2,
+        i64::MAX,
+        10_000,
+    )
+    .await;
+
+    let instruction = receipt_transfer_instruction(
+        &holder_rewards_pool,
+        &receipt_mint,
+        &alice.pubkey(),
+        &bob,
+        DEPOSIT_AMOUNT,
+    );
+    let err = execute_with_payer_err(&mut context, instruction, Some(&alice)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::ReceiptTransferExceedsUnlocked as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_not_transferring() {
+    let alice = Keypair::new();
+    let bob = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    let (holder_rewards_pool, receipt_mint) =
+        setup_pool_with_receipts(&mut context, &mint, &alice).await;
+    setup_receipt_holder(&mut context, &receipt_mint, &bob).await;
+
+    // Invoke the transfer hook directly, without transferring any receipts.
+    let program_id = paladin_rewards_program::id();
+    let mut instruction = spl_transfer_hook_interface::instruction::execute(
+        &program_id,
+        &get_associated_token_address_with_program_id(
+            &alice.pubkey(),
+            &receipt_mint,
+            &spl_token_2022::id(),
+        ),
+        &receipt_mint,
+        &get_associated_token_address_with_program_id(&bob, &receipt_mint, &spl_token_2022::id()),
+        &alice.pubkey(),
+        &get_extra_account_metas_address(&receipt_mint, &program_id),
+        DEPOSIT_AMOUNT,
+    );
+    instruction.accounts.extend([
+        AccountMeta::new(holder_rewards_pool, false),
+        AccountMeta::new(
+            get_holder_rewards_address(&alice.pubkey(), &program_id),
+            false,
+        ),
+        AccountMeta::new(get_holder_rewards_address(&bob, &program_id), false),
+        AccountMeta::new(
+            get_stake_checkpoints_address(&alice.pubkey(), &program_id),
+            false,
+        ),
+        AccountMeta::new(get_stake_checkpoints_address(&bob, &program_id), false),
+        AccountMeta::new_readonly(
+            get_access_entry_address(&holder_rewards_pool, &bob, &program_id),
+            false,
+        ),
+        AccountMeta::new(
+            get_rate_history_address(&holder_rewards_pool, &program_id),
+            false,
+        ),
+        AccountMeta::new(
+            get_epoch_ledger_address(&holder_rewards_pool, &program_id),
+            false,
+        ),
+    ]);
+    let err = execute_with_payer_err(&mut context, instruction, Some(&alice)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::ReceiptNotTransferring as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let alice = Keypair::new();
+    let bob = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    let (holder_rewards_pool, receipt_mint) =
+        setup_pool_with_receipts(&mut context, &mint, &alice).await;
+    setup_receipt_holder(&mut context, &receipt_mint, &bob).await;
+
+    // Rewards accrue to Alice before she transfers half her receipts.
+    send_rewards_to_pool(&mut context, &holder_rewards_pool, REWARDS_AMOUNT).await;
+
+    let instruction = receipt_transfer_instruction(
+        &holder_rewards_pool,
+        &receipt_mint,
+        &alice.pubkey(),
+        &bob,
+        DEPOSIT_AMOUNT / 2,
+    );
+    execute_with_payer(&mut context, instruction, Some(&alice)).await;
+
+    let pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state = bytemuck::from_bytes::<HolderRewardsPool>(&pool_account.data);
+    assert_eq!(pool_state.total_deposited, DEPOSIT_AMOUNT);
+
+    // Assert Alice's rewards were settled before her deposit moved.
+    let alice_holder_rewards_account = context
+        .banks_client
+        .get_account(get_holder_rewards_address(
+            &alice.pubkey(),
+            &paladin_rewards_program::id(),
+        ))
+        .await
+        .unwrap()
+        .unwrap();
+    let alice_holder_rewards_state =
+        bytemuck::from_bytes::<HolderRewards>(&alice_holder_rewards_account.data);
+    assert_eq!(alice_holder_rewards_state.deposited, DEPOSIT_AMOUNT / 2);
+    assert_eq!(
+        alice_holder_rewards_state.unharvested_rewards,
+        (pool_state.accumulated_rewards_per_token * DEPOSIT_AMOUNT as u128
+            / REWARDS_PER_TOKEN_SCALING_FACTOR) as u64
+    );
+    assert_eq!(
+        alice_holder_rewards_state.last_accumulated_rewards_per_token,
+        pool_state.accumulated_rewards_per_token
+    );
+
+    // Assert Bob only earns rewards from now on.
+    let bob_holder_rewards_account = context
+        .banks_client
+        .get_account(get_holder_rewards_address(
+            &bob,
+            &paladin_rewards_program::id(),
+        ))
+        .await
+        .unwrap()
+        .unwrap();
+    let bob_holder_rewards_state =
+        bytemuck::from_bytes::<HolderRewards>(&bob_holder_rewards_account.data);
+    assert_eq!(bob_holder_rewards_state.deposited, DEPOSIT_AMOUNT / 2);
+    assert_eq!(bob_holder_rewards_state.unharvested_rewards, 0);
+    assert_eq!(
+        bob_holder_rewards_state.last_accumulated_rewards_per_token,
+        pool_state.accumulated_rewards_per_token
+    );
+
+    // Assert the receipt tokens moved.
+    let bob_receipt_token_account = context
+        .banks_client
+        .get_account(get_associated_token_address_with_program_id(
+            &bob,
+            &receipt_mint,
+            &spl_token_2022::id(),
+        ))
+        .await
+        .unwrap()
+        .unwrap();
+    let bob_receipt_token_state =
+        StateWithExtensions::<ReceiptTokenAccount>::unpack(&bob_receipt_token_account.data)
+            .unwrap();
+    assert_eq!(bob_receipt_token_state.base.amount, DEPOSIT_AMOUNT / 2);
+}
+
+#[tokio::test]
+async fn success_records_rate_history() {
+    let alice = Keypair::new();
+    let bob = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    let (holder_rewards_pool, receipt_mint) =
+        setup_pool_with_receipts(&mut context, &mint, &alice).await;
+    setup_receipt_holder(&mut context, &receipt_mint, &bob).await;
+
+    let rate_history =
+        get_rate_history_address(&holder_rewards_pool, &paladin_rewards_program::id());
+    let instruction = InitializeRateHistoryBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .rate_history(rate_history)
+        .mint(mint)
+        .payer(context.payer.pubkey())
+        .instruction();
+    execute_with_payer(&mut context, instruction, None).await;
+
+    send_rewards_to_pool(&mut context, &holder_rewards_pool, REWARDS_AMOUNT).await;
+
+    let instruction = receipt_transfer_instruction(
+        &holder_rewards_pool,
+        &receipt_mint,
+        &alice.pubkey(),
+        &bob,
+        DEPOSIT_AMOUNT / 2,
+    );
+    execute_with_payer(&mut context, instruction, Some(&alice)).await;
+
+    // Assert the rewards accrued by the transfer were recorded.
+    let pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state = bytemuck::from_bytes::<HolderRewardsPool>(&pool_account.data);
+    let rate_history_account = context
+        .banks_client
+        .get_account(rate_history)
+        .await
+        .unwrap()
+        .unwrap();
+    let rate_history_state = bytemuck::from_bytes::<RateHistory>(&rate_history_account.data);
+    assert_eq!(rate_history_state.len, 2);
+    assert_eq!(
+        rate_history_state
+            .latest()
+            .unwrap()
+            .accumulated_rewards_per_token,
+        pool_state.accumulated_rewards_per_token
+    );
+}
+
+#[tokio::test]
+async fn success_records_epoch_ledger() {
+    let alice = Keypair::new();
+    let bob = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    let (holder_rewards_pool, receipt_mint) =
+        setup_pool_with_receipts(&mut context, &mint, &alice).await;
+    setup_receipt_holder(&mut context, &receipt_mint, &bob).await;
+
+    let epoch_ledger =
+        get_epoch_ledger_address(&holder_rewards_pool, &paladin_rewards_program::id());
+    let instruction = InitializeEpochLedgerBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .epoch_ledger(epoch_ledger)
+        .mint(mint)
+        .payer(context.payer.pubkey())
+        .instruction();
+    execute_with_payer(&mut context, instruction, None).await;
+
+    send_rewards_to_pool(&mut context, &holder_rewards_pool, REWARDS_AMOUNT).await;
+
+    let instruction = receipt_transfer_instruction(
+        &holder_rewards_pool,
+        &receipt_mint,
+        &alice.pubkey(),
+        &bob,
+        DEPOSIT_AMOUNT / 2,
+    );
+    execute_with_payer(&mut context, instruction, Some(&alice)).await;
+
+    // Assert the rewards accrued by the transfer were recorded.
+    let pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state = bytemuck::from_bytes::<HolderRewardsPool>(&pool_account.data);
+    let epoch_ledger_account = context
+        .banks_client
+        .get_account(epoch_ledger)
+        .await
+        .unwrap()
+        .unwrap();
+    let epoch_ledger_state = bytemuck::from_bytes::<EpochLedger>(&epoch_ledger_account.data);
+    assert_eq!(
+        epoch_ledger_state.last_accumulated_rewards_per_token,
+        pool_state.accumulated_rewards_per_token
+    );
+}
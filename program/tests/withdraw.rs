@@ -5,28 +5,41 @@ mod setup;
 
 use {
     crate::{
-        execute_utils::{execute_with_payer, execute_with_payer_err},
+        execute_utils::{execute_with_payer, execute_with_payer_err, execute_with_payer_events},
         setup::{
-            send_rewards_to_pool, setup_holder_rewards_account_with_token_account,
-            setup_holder_rewards_pool_account_with_token_account, DEPOSIT_AMOUNT,
-            INITIAL_OWNER_BALANCE,
+            initialize_receipt_mint, send_rewards_to_pool, set_holder_rewards_lockup,
+            setup_access_entry_account, setup_epoch_ledger_account, setup_holder_rewards_account,
+            setup_holder_rewards_account_with_token_account,
+            setup_holder_rewards_pool_account_with_token_account, setup_owner,
+            setup_rate_history_account, setup_receipt_token_account,
+            setup_stake_checkpoints_account, setup_token_account, update_holder_rewards_pool,
+            HolderRewardsSetup, DEPOSIT_AMOUNT, INITIAL_OWNER_BALANCE,
         },
     },
     paladin_rewards_program::{
         error::PaladinRewardsError,
         processor::REWARDS_PER_TOKEN_SCALING_FACTOR,
-        state::{get_holder_rewards_address, get_holder_rewards_pool_address},
+        state::{
+            get_access_entry_address, get_epoch_ledger_address, get_holder_rewards_address,
+            get_holder_rewards_pool_address, get_rate_history_address,
+            get_stake_checkpoints_address, EpochLedger, HolderRewards, HolderRewardsPool,
+            RateHistory, StakeCheckpoint, StakeCheckpoints, ACCESS_LIST_DENYLIST,
+            ACCESS_STATUS_DENIED,
+        },
+    },
+    paladin_rewards_program_client::{
+        events::PaladinRewardsEvent,
+        instructions::{DepositBuilder, HarvestRewardsBuilder, WithdrawBuilder},
     },
-    paladin_rewards_program_client::instructions::WithdrawBuilder,
     setup::setup,
     solana_program_test::*,
     solana_sdk::{
-        instruction::InstructionError, program_pack::Pack, pubkey::Pubkey, signature::Keypair,
-        signer::Signer, transaction::TransactionError,
+        clock::Clock, instruction::InstructionError, program_pack::Pack, pubkey::Pubkey,
+        signature::Keypair, signer::Signer, transaction::TransactionError,
     },
     spl_associated_token_account::get_associated_token_address,
     spl_token::state::Account as TokenAccount,
-    std::u64,
+    spl_token_2022::{extension::StateWithExtensions, state::Account as ReceiptTokenAccount},
 };
 
 pub const REWARDS_AMOUNT: u64 = 100_000_000_000_000;
@@ -64,9 +77,11 @@ async fn fail_empty_pool() {
         &owner.pubkey(),
         &holder_rewards,
         &owner_token,
-        INITIAL_OWNER_BALANCE,
-        0,
-        0,
+        HolderRewardsSetup {
+            deposited: INITIAL_OWNER_BALANCE,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: 0,
+        },
     )
     .await;
 
@@ -124,9 +139,11 @@ async fn fail_no_deposited_tokens() {
         &owner.pubkey(),
         &holder_rewards,
         &owner_token,
-        0,
-        0,
-        INITIAL_OWNER_BALANCE,
+        HolderRewardsSetup {
+            deposited: 0,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE,
+        },
     )
     .await;
 
@@ -152,7 +169,7 @@ async fn fail_no_deposited_tokens() {
 }
 
 #[tokio::test]
-async fn success_with_rewards() {
+async fn fail_incorrect_token_program() {
     let owner = Keypair::new();
     let mint = Pubkey::new_unique();
 
@@ -174,9 +191,6 @@ async fn success_with_rewards() {
     )
     .await;
 
-    // Send rewards to the pool to update rates
-    send_rewards_to_pool(&mut context, &holder_rewards_pool, REWARDS_AMOUNT).await;
-
     // Setup token account for the owner.
     let holder_rewards =
         get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
@@ -187,13 +201,161 @@ async fn success_with_rewards() {
         &owner.pubkey(),
         &holder_rewards,
         &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: 0,
+        },
+    )
+    .await;
+
+    // Swap in a fake token program.
+    let instruction = WithdrawBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .token_program(Pubkey::new_unique())
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectTokenProgram as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_withdraw_exceeds_unlocked() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
         DEPOSIT_AMOUNT,
+    )
+    .await;
+
+    // Setup a holder with half of their deposit locked.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        },
+    )
+    .await;
+    set_holder_rewards_lockup(
+        &mut context,
+        &holder_rewards_pool,
+        &holder_rewards,
+        DEPOSIT_AMOUNT / 2,
+        i64::MAX,
+        20_000,
+    )
+    .await;
+
+    // Withdraw more than the unlocked half.
+    let instruction = WithdrawBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .amount(DEPOSIT_AMOUNT / 2 + 1)
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::WithdrawExceedsUnlocked as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_releases_expired_lockup() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
         0,
-        INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        0,
+        DEPOSIT_AMOUNT,
     )
     .await;
 
-    // Do withdraw
+    // Setup a holder whose whole deposit was locked, with a lockup that has
+    // already expired.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        },
+    )
+    .await;
+    set_holder_rewards_lockup(
+        &mut context,
+        &holder_rewards_pool,
+        &holder_rewards,
+        DEPOSIT_AMOUNT,
+        1,
+        20_000,
+    )
+    .await;
+
+    // Send rewards to the pool, distributed by the locked weight.
+    send_rewards_to_pool(&mut context, &holder_rewards_pool, REWARDS_AMOUNT).await;
+
     let instruction = WithdrawBuilder::new()
         .holder_rewards_pool(holder_rewards_pool)
         .holder_rewards_pool_token_account(pool_token)
@@ -205,43 +367,41 @@ async fn success_with_rewards() {
         .instruction();
     execute_with_payer(&mut context, instruction, Some(&owner)).await;
 
-    // Assert pool balance is 0 (single depositor withdrews all).
-    let pool_token_account = context
+    // Assert the holder, as the only depositor, received all the rewards.
+    let owner_lamports = context
         .banks_client
-        .get_account(pool_token)
+        .get_account(owner.pubkey())
         .await
         .unwrap()
-        .unwrap();
-    let pool_token_account_balance = TokenAccount::unpack(&pool_token_account.data)
         .unwrap()
-        .amount;
-    assert_eq!(pool_token_account_balance, 0);
+        .lamports;
+    assert_eq!(owner_lamports, REWARDS_AMOUNT);
 
-    // Assert owner got all the tokens back to INITIAL_OWNER_BALANCE
-    let owner_token_account = context
+    // Assert the lockup was released from both the holder and the pool.
+    let holder_rewards_account = context
         .banks_client
-        .get_account(owner_token)
+        .get_account(holder_rewards)
         .await
         .unwrap()
         .unwrap();
-    let owner_token_account_balance = TokenAccount::unpack(&owner_token_account.data)
-        .unwrap()
-        .amount;
-    assert_eq!(owner_token_account_balance, INITIAL_OWNER_BALANCE);
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    assert_eq!(holder_rewards_state.deposited, 0);
+    assert_eq!(holder_rewards_state.locked_amount, 0);
+    assert_eq!(holder_rewards_state.lockup_multiplier_bps, 0);
 
-    // Get owner account lamports
-    let owner_lamports = context
+    let pool_account = context
         .banks_client
-        .get_account(owner.pubkey())
+        .get_account(holder_rewards_pool)
         .await
         .unwrap()
-        .unwrap()
-        .lamports;
-    assert_eq!(owner_lamports, REWARDS_AMOUNT);
+        .unwrap();
+    let pool_state = bytemuck::from_bytes::<HolderRewardsPool>(&pool_account.data);
+    assert_eq!(pool_state.total_deposited, 0);
+    assert_eq!(pool_state.total_lockup_bonus_weight, 0);
 }
 
 #[tokio::test]
-async fn success_without_rewards() {
+async fn success_with_rewards() {
     let owner = Keypair::new();
     let mint = Pubkey::new_unique();
 
@@ -257,13 +417,15 @@ async fn success_without_rewards() {
         &mint,
         &holder_rewards_pool,
         &pool_token,
-        1_000_000,
-        REWARDS_PER_TOKEN_SCALING_FACTOR.checked_mul(10).unwrap(), /* unaccounted rewards more
-                                                                    * then current lamport */
+        0,
+        0,
         DEPOSIT_AMOUNT,
     )
     .await;
 
+    // Send rewards to the pool to update rates
+    send_rewards_to_pool(&mut context, &holder_rewards_pool, REWARDS_AMOUNT).await;
+
     // Setup token account for the owner.
     let holder_rewards =
         get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
@@ -274,13 +436,15 @@ async fn success_without_rewards() {
         &owner.pubkey(),
         &holder_rewards,
         &owner_token,
-        DEPOSIT_AMOUNT,
-        0,
-        INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        },
     )
     .await;
 
-    // Do wihthdraw
+    // Do withdraw
     let instruction = WithdrawBuilder::new()
         .holder_rewards_pool(holder_rewards_pool)
         .holder_rewards_pool_token_account(pool_token)
@@ -316,26 +480,37 @@ async fn success_without_rewards() {
         .amount;
     assert_eq!(owner_token_account_balance, INITIAL_OWNER_BALANCE);
 
-    // Assert that the pool still have the REWARDS_AMOUNT lamports
-    let holder_rewards_pool_account = context
+    // Get owner account lamports
+    let owner_lamports = context
         .banks_client
-        .get_account(holder_rewards_pool)
+        .get_account(owner.pubkey())
         .await
         .unwrap()
-        .unwrap();
-    assert!(holder_rewards_pool_account.lamports > 1_000_000);
+        .unwrap()
+        .lamports;
+    assert_eq!(owner_lamports, REWARDS_AMOUNT);
 
-    // Assert the owner didn't get any lamports (non initialized yet)
-    let owner_account = context
+    // Assert the holder's lifetime accounting was updated.
+    let slot = context
         .banks_client
-        .get_account(owner.pubkey())
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .slot;
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
         .await
+        .unwrap()
         .unwrap();
-    assert!(owner_account.is_none());
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    assert_eq!(holder_rewards_state.cumulative_withdrawn, DEPOSIT_AMOUNT);
+    assert_eq!(holder_rewards_state.total_rewards_harvested, REWARDS_AMOUNT);
+    assert_eq!(holder_rewards_state.last_activity_slot, slot);
 }
 
 #[tokio::test]
-async fn success_withdraw_half() {
+async fn success_emits_events() {
     let owner = Keypair::new();
     let mint = Pubkey::new_unique();
 
@@ -370,13 +545,95 @@ async fn success_withdraw_half() {
         &owner.pubkey(),
         &holder_rewards,
         &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        },
+    )
+    .await;
+
+    let instruction = WithdrawBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    let events = execute_with_payer_events(&mut context, instruction, Some(&owner)).await;
+
+    // Assert the rewards were accrued, then the withdrawal and the harvest
+    // of the holder's rewards were logged.
+    assert_eq!(
+        events,
+        vec![
+            PaladinRewardsEvent::RewardsAccrued {
+                holder_rewards_pool,
+                inflow: REWARDS_AMOUNT,
+                accumulated_rewards_per_token: REWARDS_AMOUNT as u128
+                    * REWARDS_PER_TOKEN_SCALING_FACTOR
+                    / DEPOSIT_AMOUNT as u128,
+            },
+            PaladinRewardsEvent::Withdrawn {
+                holder_rewards_pool,
+                owner: owner.pubkey(),
+                amount: DEPOSIT_AMOUNT,
+                deposited: 0,
+            },
+            PaladinRewardsEvent::Harvested {
+                holder_rewards_pool,
+                owner: owner.pubkey(),
+                amount: REWARDS_AMOUNT,
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn success_without_rewards() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        1_000_000,
+        REWARDS_PER_TOKEN_SCALING_FACTOR.checked_mul(10).unwrap(), /* unaccounted rewards more
+                                                                    * then current lamport */
         DEPOSIT_AMOUNT,
-        0,
-        INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
     )
     .await;
 
-    // Do withdraw
+    // Setup token account for the owner.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        },
+    )
+    .await;
+
+    // Do wihthdraw
     let instruction = WithdrawBuilder::new()
         .holder_rewards_pool(holder_rewards_pool)
         .holder_rewards_pool_token_account(pool_token)
@@ -384,11 +641,11 @@ async fn success_withdraw_half() {
         .token_account(owner_token)
         .mint(mint)
         .owner(owner.pubkey())
-        .amount(DEPOSIT_AMOUNT / 2)
+        .amount(DEPOSIT_AMOUNT)
         .instruction();
     execute_with_payer(&mut context, instruction, Some(&owner)).await;
 
-    // Assert pool balance is DEPOSIT_AMOUNT / 2 (single depositor withdrews half).
+    // Assert pool balance is 0 (single depositor withdrews all).
     let pool_token_account = context
         .banks_client
         .get_account(pool_token)
@@ -398,9 +655,127 @@ async fn success_withdraw_half() {
     let pool_token_account_balance = TokenAccount::unpack(&pool_token_account.data)
         .unwrap()
         .amount;
-    assert_eq!(pool_token_account_balance, DEPOSIT_AMOUNT / 2);
+    assert_eq!(pool_token_account_balance, 0);
 
-    // Assert owner got half the tokens back
+    // Assert owner got all the tokens back to INITIAL_OWNER_BALANCE
+    let owner_token_account = context
+        .banks_client
+        .get_account(owner_token)
+        .await
+        .unwrap()
+        .unwrap();
+    let owner_token_account_balance = TokenAccount::unpack(&owner_token_account.data)
+        .unwrap()
+        .amount;
+    assert_eq!(owner_token_account_balance, INITIAL_OWNER_BALANCE);
+
+    // Assert that the pool still have the REWARDS_AMOUNT lamports
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(holder_rewards_pool_account.lamports > 1_000_000);
+
+    // Assert the owner didn't get any lamports (non initialized yet)
+    let owner_account = context
+        .banks_client
+        .get_account(owner.pubkey())
+        .await
+        .unwrap();
+    assert!(owner_account.is_none());
+
+    // Assert the rewards the pool could not cover were settled for a later
+    // harvest.
+    let pool_state = bytemuck::from_bytes::<HolderRewardsPool>(&holder_rewards_pool_account.data);
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    assert_eq!(
+        holder_rewards_state.unharvested_rewards,
+        (pool_state.accumulated_rewards_per_token * DEPOSIT_AMOUNT as u128
+            / REWARDS_PER_TOKEN_SCALING_FACTOR) as u64
+    );
+    assert_eq!(
+        holder_rewards_state.last_accumulated_rewards_per_token,
+        pool_state.accumulated_rewards_per_token
+    );
+}
+
+#[tokio::test]
+async fn success_withdraw_half() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+
+    // Send rewards to the pool to update rates
+    send_rewards_to_pool(&mut context, &holder_rewards_pool, REWARDS_AMOUNT).await;
+
+    // Setup token account for the owner.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        },
+    )
+    .await;
+
+    // Do withdraw
+    let instruction = WithdrawBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .amount(DEPOSIT_AMOUNT / 2)
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&owner)).await;
+
+    // Assert pool balance is DEPOSIT_AMOUNT / 2 (single depositor withdrews half).
+    let pool_token_account = context
+        .banks_client
+        .get_account(pool_token)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_token_account_balance = TokenAccount::unpack(&pool_token_account.data)
+        .unwrap()
+        .amount;
+    assert_eq!(pool_token_account_balance, DEPOSIT_AMOUNT / 2);
+
+    // Assert owner got half the tokens back
     let owner_token_account = context
         .banks_client
         .get_account(owner_token)
@@ -425,3 +800,1047 @@ async fn success_withdraw_half() {
         .lamports;
     assert_eq!(owner_lamports, REWARDS_AMOUNT);
 }
+
+#[tokio::test]
+async fn success_records_stake_checkpoint() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+
+    // Setup token account for the owner.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        },
+    )
+    .await;
+
+    // The holder records stake checkpoints.
+    let stake_checkpoints =
+        get_stake_checkpoints_address(&owner.pubkey(), &paladin_rewards_program::id());
+    setup_stake_checkpoints_account(
+        &mut context,
+        &owner.pubkey(),
+        &holder_rewards,
+        &stake_checkpoints,
+        &[(0, DEPOSIT_AMOUNT)],
+    )
+    .await;
+    context.warp_to_slot(10).unwrap();
+
+    let instruction = WithdrawBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .stake_checkpoints(Some(stake_checkpoints))
+        .amount(DEPOSIT_AMOUNT / 2)
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&owner)).await;
+
+    // Assert the new balance was checkpointed at the current slot, keeping
+    // the previous balance.
+    let slot = context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .slot;
+    let stake_checkpoints_account = context
+        .banks_client
+        .get_account(stake_checkpoints)
+        .await
+        .unwrap()
+        .unwrap();
+    let stake_checkpoints_state =
+        bytemuck::from_bytes::<StakeCheckpoints>(&stake_checkpoints_account.data);
+    assert_eq!(
+        stake_checkpoints_state.latest(),
+        Some(&StakeCheckpoint {
+            slot,
+            deposited: DEPOSIT_AMOUNT / 2,
+        })
+    );
+    assert_eq!(
+        stake_checkpoints_state.deposited_at(slot - 1),
+        Some(DEPOSIT_AMOUNT)
+    );
+}
+
+#[tokio::test]
+async fn success_early_withdrawal_penalty() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool, with another holder depositing as much as the owner.
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        DEPOSIT_AMOUNT * 2,
+    )
+    .await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.early_withdrawal_penalty_bps = 5_000;
+        pool_state.early_withdrawal_period_slots = 100;
+    })
+    .await;
+
+    // Send rewards to the pool to update rates
+    send_rewards_to_pool(&mut context, &holder_rewards_pool, REWARDS_AMOUNT).await;
+
+    // Setup token account for the owner, who last deposited at slot 0.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        },
+    )
+    .await;
+
+    let instruction = WithdrawBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&owner)).await;
+
+    // Assert the owner received half of their share of the rewards.
+    let owner_lamports = context
+        .banks_client
+        .get_account(owner.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(owner_lamports, REWARDS_AMOUNT / 4);
+
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    assert_eq!(
+        holder_rewards_state.early_withdrawal_penalties,
+        REWARDS_AMOUNT / 4
+    );
+
+    // Assert the forfeited rewards were added to the other holder's share.
+    let pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state = bytemuck::from_bytes::<HolderRewardsPool>(&pool_account.data);
+    assert_eq!(
+        pool_state.accumulated_rewards_per_token,
+        (REWARDS_AMOUNT / 2 + REWARDS_AMOUNT / 4) as u128 * REWARDS_PER_TOKEN_SCALING_FACTOR
+            / DEPOSIT_AMOUNT as u128
+    );
+    assert_eq!(
+        holder_rewards_state.last_accumulated_rewards_per_token,
+        pool_state.accumulated_rewards_per_token
+    );
+}
+
+#[tokio::test]
+async fn success_early_withdrawal_penalty_records_rate() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool, with another holder depositing as much as the owner.
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        DEPOSIT_AMOUNT * 2,
+    )
+    .await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.early_withdrawal_penalty_bps = 5_000;
+        pool_state.early_withdrawal_period_slots = 100;
+    })
+    .await;
+
+    let rate_history =
+        get_rate_history_address(&holder_rewards_pool, &paladin_rewards_program::id());
+    setup_rate_history_account(&mut context, &holder_rewards_pool, &rate_history).await;
+    let epoch_ledger =
+        get_epoch_ledger_address(&holder_rewards_pool, &paladin_rewards_program::id());
+    setup_epoch_ledger_account(&mut context, &holder_rewards_pool, &epoch_ledger).await;
+
+    // Send rewards to the pool to update rates
+    send_rewards_to_pool(&mut context, &holder_rewards_pool, REWARDS_AMOUNT).await;
+
+    // Setup token account for the owner, who last deposited at slot 0.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        },
+    )
+    .await;
+
+    let instruction = WithdrawBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .rate_history(Some(rate_history))
+        .epoch_ledger(Some(epoch_ledger))
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&owner)).await;
+
+    // Assert the rate raised by the forfeited rewards was recorded.
+    let pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state = bytemuck::from_bytes::<HolderRewardsPool>(&pool_account.data);
+    assert_eq!(
+        pool_state.accumulated_rewards_per_token,
+        (REWARDS_AMOUNT / 2 + REWARDS_AMOUNT / 4) as u128 * REWARDS_PER_TOKEN_SCALING_FACTOR
+            / DEPOSIT_AMOUNT as u128
+    );
+
+    let rate_history_account = context
+        .banks_client
+        .get_account(rate_history)
+        .await
+        .unwrap()
+        .unwrap();
+    let rate_history_state = bytemuck::from_bytes::<RateHistory>(&rate_history_account.data);
+    assert_eq!(
+        rate_history_state
+            .latest()
+            .unwrap()
+            .accumulated_rewards_per_token,
+        pool_state.accumulated_rewards_per_token
+    );
+
+    let epoch_ledger_account = context
+        .banks_client
+        .get_account(epoch_ledger)
+        .await
+        .unwrap()
+        .unwrap();
+    let epoch_ledger_state = bytemuck::from_bytes::<EpochLedger>(&epoch_ledger_account.data);
+    assert_eq!(
+        epoch_ledger_state.last_accumulated_rewards_per_token,
+        pool_state.accumulated_rewards_per_token
+    );
+    assert_eq!(
+        epoch_ledger_state.latest().unwrap().rate_delta,
+        pool_state.accumulated_rewards_per_token
+    );
+    assert_eq!(
+        epoch_ledger_state.latest().unwrap().lamports_received,
+        REWARDS_AMOUNT
+    );
+}
+
+#[tokio::test]
+async fn success_early_withdrawal_penalty_after_harvest() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool, with another holder depositing as much as the owner.
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        DEPOSIT_AMOUNT * 2,
+    )
+    .await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.early_withdrawal_penalty_bps = 5_000;
+        pool_state.early_withdrawal_period_slots = 100;
+    })
+    .await;
+
+    // Send rewards to the pool to update rates
+    send_rewards_to_pool(&mut context, &holder_rewards_pool, REWARDS_AMOUNT).await;
+
+    // Setup token account for the owner, who last deposited at slot 0.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        },
+    )
+    .await;
+
+    // Harvest within the period, which withholds the rewards.
+    let instruction = HarvestRewardsBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&owner)).await;
+
+    let owner_account = context
+        .banks_client
+        .get_account(owner.pubkey())
+        .await
+        .unwrap();
+    assert!(owner_account.is_none());
+
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    assert_eq!(holder_rewards_state.unharvested_rewards, REWARDS_AMOUNT / 2);
+
+    let instruction = WithdrawBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&owner)).await;
+
+    // Assert the withdrawal still forfeited half of the owner's share.
+    let owner_lamports = context
+        .banks_client
+        .get_account(owner.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(owner_lamports, REWARDS_AMOUNT / 4);
+
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    assert_eq!(
+        holder_rewards_state.early_withdrawal_penalties,
+        REWARDS_AMOUNT / 4
+    );
+    assert_eq!(holder_rewards_state.unharvested_rewards, 0);
+}
+
+#[tokio::test]
+async fn success_early_withdrawal_penalty_withholds_tiny_harvest() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    // Rewards too small to bear a whole lamport of a 0.01% penalty.
+    let rewards_amount = 10_000;
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool, with another holder depositing as much as the owner.
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        DEPOSIT_AMOUNT * 2,
+    )
+    .await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.early_withdrawal_penalty_bps = 1;
+        pool_state.early_withdrawal_period_slots = 100;
+    })
+    .await;
+
+    // Send rewards to the pool to update rates
+    send_rewards_to_pool(&mut context, &holder_rewards_pool, rewards_amount).await;
+
+    // Setup token account for the owner, who last deposited at slot 0. The
+    // owner is funded, so the rewards could be paid to them.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        },
+    )
+    .await;
+    setup_owner(&mut context, &owner.pubkey()).await;
+    let owner_balance = context
+        .banks_client
+        .get_account(owner.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    // Harvest within the period, which withholds the rewards.
+    let instruction = HarvestRewardsBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&owner)).await;
+
+    let owner_lamports = context
+        .banks_client
+        .get_account(owner.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(owner_lamports, owner_balance);
+
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    assert_eq!(holder_rewards_state.unharvested_rewards, rewards_amount / 2);
+
+    let instruction = WithdrawBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&owner)).await;
+
+    // Assert the withdrawal forfeited the penalty, rounded up.
+    let owner_lamports = context
+        .banks_client
+        .get_account(owner.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(owner_lamports, owner_balance + rewards_amount / 2 - 1);
+
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    assert_eq!(holder_rewards_state.early_withdrawal_penalties, 1);
+    assert_eq!(holder_rewards_state.unharvested_rewards, 0);
+}
+
+#[tokio::test]
+async fn success_early_withdrawal_period_elapsed() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool, with another holder depositing as much as the owner.
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        DEPOSIT_AMOUNT * 2,
+    )
+    .await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.early_withdrawal_penalty_bps = 5_000;
+        pool_state.early_withdrawal_period_slots = 100;
+    })
+    .await;
+
+    // Send rewards to the pool to update rates
+    send_rewards_to_pool(&mut context, &holder_rewards_pool, REWARDS_AMOUNT).await;
+
+    // Setup token account for the owner, who last deposited at slot 0.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        },
+    )
+    .await;
+    context.warp_to_slot(200).unwrap();
+
+    let instruction = WithdrawBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&owner)).await;
+
+    // Assert the owner received their full share of the rewards.
+    let owner_lamports = context
+        .banks_client
+        .get_account(owner.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(owner_lamports, REWARDS_AMOUNT / 2);
+
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    assert_eq!(holder_rewards_state.early_withdrawal_penalties, 0);
+}
+
+#[tokio::test]
+async fn success_denied_holder_can_withdraw() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+    update_holder_rewards_pool(&mut context, &holder_rewards_pool, |pool_state| {
+        pool_state.access_list = ACCESS_LIST_DENYLIST
+    })
+    .await;
+
+    // Deny the owner after they deposited.
+    let access_entry = get_access_entry_address(
+        &holder_rewards_pool,
+        &owner.pubkey(),
+        &paladin_rewards_program::id(),
+    );
+    setup_access_entry_account(
+        &mut context,
+        &holder_rewards_pool,
+        &owner.pubkey(),
+        &access_entry,
+        ACCESS_STATUS_DENIED,
+    )
+    .await;
+
+    // Setup token account for the owner.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        },
+    )
+    .await;
+
+    let instruction = WithdrawBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&owner)).await;
+
+    // Assert the owner got their tokens back.
+    let owner_token_account = context
+        .banks_client
+        .get_account(owner_token)
+        .await
+        .unwrap()
+        .unwrap();
+    let owner_token_state = TokenAccount::unpack(&owner_token_account.data).unwrap();
+    assert_eq!(owner_token_state.amount, INITIAL_OWNER_BALANCE);
+}
+
+#[tokio::test]
+async fn success_burns_receipts() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        0,
+    )
+    .await;
+    let receipt_mint = initialize_receipt_mint(&mut context, &mint, &holder_rewards_pool).await;
+
+    // Setup token accounts for the owner.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: 0,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE,
+        },
+    )
+    .await;
+    let receipt_token =
+        setup_receipt_token_account(&mut context, &receipt_mint, &owner.pubkey()).await;
+
+    // Deposit to receive the receipt tokens.
+    let instruction = DepositBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .receipt_mint(Some(receipt_mint))
+        .receipt_token_account(Some(receipt_token))
+        .receipt_token_program(Some(spl_token_2022::id()))
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&owner)).await;
+
+    let instruction = WithdrawBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .receipt_mint(Some(receipt_mint))
+        .receipt_token_account(Some(receipt_token))
+        .receipt_token_program(Some(spl_token_2022::id()))
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&owner)).await;
+
+    // Assert the receipt tokens were burned.
+    let receipt_token_account = context
+        .banks_client
+        .get_account(receipt_token)
+        .await
+        .unwrap()
+        .unwrap();
+    let receipt_token_state =
+        StateWithExtensions::<ReceiptTokenAccount>::unpack(&receipt_token_account.data).unwrap();
+    assert_eq!(receipt_token_state.base.amount, 0);
+
+    // Assert the owner got their tokens back.
+    let owner_token_account = context
+        .banks_client
+        .get_account(owner_token)
+        .await
+        .unwrap()
+        .unwrap();
+    let owner_token_state = TokenAccount::unpack(&owner_token_account.data).unwrap();
+    assert_eq!(owner_token_state.amount, INITIAL_OWNER_BALANCE);
+}
+
+#[tokio::test]
+async fn fail_destination_token_account_mint_mismatch() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+
+    // Setup token account for the owner.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        },
+    )
+    .await;
+
+    // Setup a destination token account for a different mint.
+    let destination_token = Pubkey::new_unique();
+    setup_token_account(
+        &mut context,
+        &destination_token,
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        0,
+    )
+    .await;
+
+    let instruction = WithdrawBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .destination_token_account(Some(destination_token))
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    let err = execute_with_payer_err(&mut context, instruction, Some(&owner)).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::TokenAccountMintMismatch as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_to_destination_token_account() {
+    let owner = Keypair::new();
+    let custodian = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+
+    // Setup token account for the owner.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    let owner_token = get_associated_token_address(&owner.pubkey(), &mint);
+    setup_holder_rewards_account_with_token_account(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards,
+        &owner_token,
+        HolderRewardsSetup {
+            deposited: DEPOSIT_AMOUNT,
+            last_accumulated_rewards_per_token: 0,
+            token_balance: INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT,
+        },
+    )
+    .await;
+
+    // Setup the custodian's token account as the destination.
+    let destination_token = get_associated_token_address(&custodian, &mint);
+    setup_token_account(&mut context, &destination_token, &custodian, &mint, 0).await;
+
+    let instruction = WithdrawBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(owner_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .destination_token_account(Some(destination_token))
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&owner)).await;
+
+    // Assert the custodian received the tokens.
+    let destination_token_account = context
+        .banks_client
+        .get_account(destination_token)
+        .await
+        .unwrap()
+        .unwrap();
+    let destination_token_state = TokenAccount::unpack(&destination_token_account.data).unwrap();
+    assert_eq!(destination_token_state.amount, DEPOSIT_AMOUNT);
+
+    // Assert the owner's token account is untouched.
+    let owner_token_account = context
+        .banks_client
+        .get_account(owner_token)
+        .await
+        .unwrap()
+        .unwrap();
+    let owner_token_state = TokenAccount::unpack(&owner_token_account.data).unwrap();
+    assert_eq!(
+        owner_token_state.amount,
+        INITIAL_OWNER_BALANCE - DEPOSIT_AMOUNT
+    );
+
+    // Assert the holder's deposit was withdrawn.
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    assert_eq!(holder_rewards_state.deposited, 0);
+}
+
+#[tokio::test]
+async fn success_to_destination_token_account_without_owner_token_account() {
+    let owner = Keypair::new();
+    let custodian = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup pool
+    let holder_rewards_pool =
+        get_holder_rewards_pool_address(&mint, &paladin_rewards_program::id());
+    let pool_token = get_associated_token_address(&holder_rewards_pool, &mint);
+
+    setup_holder_rewards_pool_account_with_token_account(
+        &mut context,
+        &mint,
+        &holder_rewards_pool,
+        &pool_token,
+        0,
+        0,
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+
+    // Setup the owner's holder rewards, without a token account.
+    let holder_rewards =
+        get_holder_rewards_address(&owner.pubkey(), &paladin_rewards_program::id());
+    setup_holder_rewards_account(
+        &mut context,
+        &owner.pubkey(),
+        &holder_rewards,
+        DEPOSIT_AMOUNT,
+        0,
+        &owner.pubkey(),
+    )
+    .await;
+
+    // Setup the custodian's token account as the destination.
+    let destination_token = get_associated_token_address(&custodian, &mint);
+    setup_token_account(&mut context, &destination_token, &custodian, &mint, 0).await;
+
+    // The destination stands in for the owner's token account.
+    let instruction = WithdrawBuilder::new()
+        .holder_rewards_pool(holder_rewards_pool)
+        .holder_rewards_pool_token_account(pool_token)
+        .holder_rewards(holder_rewards)
+        .token_account(destination_token)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .destination_token_account(Some(destination_token))
+        .amount(DEPOSIT_AMOUNT)
+        .instruction();
+    execute_with_payer(&mut context, instruction, Some(&owner)).await;
+
+    // Assert the custodian received the tokens.
+    let destination_token_account = context
+        .banks_client
+        .get_account(destination_token)
+        .await
+        .unwrap()
+        .unwrap();
+    let destination_token_state = TokenAccount::unpack(&destination_token_account.data).unwrap();
+    assert_eq!(destination_token_state.amount, DEPOSIT_AMOUNT);
+
+    // Assert the holder's deposit was withdrawn.
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    assert_eq!(holder_rewards_state.deposited, 0);
+}
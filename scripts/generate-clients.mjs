@@ -42,6 +42,86 @@ kinobi.update(
         ),
       ],
     },
+    rateHistory: {
+      seeds: [
+        k.constantPdaSeedNodeFromString("utf8", "rate_history"),
+        k.variablePdaSeedNode(
+          "holderRewardsPool",
+          k.publicKeyTypeNode(),
+          "Holder rewards pool"
+        ),
+      ],
+    },
+    lockupTiers: {
+      seeds: [
+        k.constantPdaSeedNodeFromString("utf8", "lockup_tiers"),
+        k.variablePdaSeedNode(
+          "holderRewardsPool",
+          k.publicKeyTypeNode(),
+          "Holder rewards pool"
+        ),
+      ],
+    },
+    voteEscrow: {
+      seeds: [
+        k.constantPdaSeedNodeFromString("utf8", "vote_escrow"),
+        k.variablePdaSeedNode(
+          "holderRewardsPool",
+          k.publicKeyTypeNode(),
+          "Holder rewards pool"
+        ),
+      ],
+    },
+    stakeCheckpoints: {
+      seeds: [
+        k.constantPdaSeedNodeFromString("utf8", "stake_checkpoints"),
+        k.variablePdaSeedNode(
+          "owner",
+          k.publicKeyTypeNode(),
+          "Owner"
+        ),
+      ],
+    },
+    accessEntry: {
+      seeds: [
+        k.constantPdaSeedNodeFromString("utf8", "access_entry"),
+        k.variablePdaSeedNode(
+          "holderRewardsPool",
+          k.publicKeyTypeNode(),
+          "Holder rewards pool"
+        ),
+        k.variablePdaSeedNode(
+          "wallet",
+          k.publicKeyTypeNode(),
+          "Wallet"
+        ),
+      ],
+    },
+    campaign: {
+      seeds: [
+        k.constantPdaSeedNodeFromString("utf8", "campaign"),
+        k.variablePdaSeedNode(
+          "holderRewardsPool",
+          k.publicKeyTypeNode(),
+          "Holder rewards pool"
+        ),
+        k.variablePdaSeedNode(
+          "funder",
+          k.publicKeyTypeNode(),
+          "Funder"
+        ),
+      ],
+    },
+    epochLedger: {
+      seeds: [
+        k.constantPdaSeedNodeFromString("utf8", "epoch_ledger"),
+        k.variablePdaSeedNode(
+          "holderRewardsPool",
+          k.publicKeyTypeNode(),
+          "Holder rewards pool"
+        ),
+      ],
+    },
   })
 );
 